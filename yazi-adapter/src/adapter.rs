@@ -3,9 +3,10 @@ use std::{env, fmt::Display, path::Path};
 use anyhow::Result;
 use ratatui::layout::Rect;
 use tracing::warn;
+use yazi_config::{PREVIEW, plugin::Limits};
 use yazi_shared::env_exists;
 
-use crate::{Brand, Emulator, SHOWN, TMUX, WSL, drivers};
+use crate::{Brand, Emulator, Image, ImageCompare, NESTED, REGIONS, SHOWN, TMUX, WSL, drivers};
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Adapter {
@@ -35,19 +36,41 @@ impl Display for Adapter {
 }
 
 impl Adapter {
-	pub async fn image_show(self, path: &Path, max: Rect) -> Result<Rect> {
+	pub async fn image_show(self, path: &Path, max: Rect, limits: Limits) -> Result<Rect> {
 		if max.is_empty() {
 			return Ok(Rect::default());
 		}
 
-		match self {
-			Self::Kgp => drivers::Kgp::image_show(path, max).await,
-			Self::KgpOld => drivers::KgpOld::image_show(path, max).await,
-			Self::Iip => drivers::Iip::image_show(path, max).await,
-			Self::Sixel => drivers::Sixel::image_show(path, max).await,
-			Self::X11 | Self::Wayland => drivers::Ueberzug::image_show(path, max).await,
+		let area = match self {
+			Self::Kgp => drivers::Kgp::image_show(path, max, limits).await,
+			Self::KgpOld => drivers::KgpOld::image_show(path, max, limits).await,
+			Self::Iip => drivers::Iip::image_show(path, max, limits).await,
+			Self::Sixel => drivers::Sixel::image_show(path, max, limits).await,
+			Self::X11 | Self::Wayland => drivers::Ueberzug::image_show(path, max, limits).await,
 			Self::Chafa => drivers::Chafa::image_show(path, max).await,
+		}?;
+
+		REGIONS.lock().drawn(area);
+		Ok(area)
+	}
+
+	/// Shows the images at `a` and `b` together to compare them, see
+	/// [`ImageCompare`].
+	pub async fn image_compare(
+		self,
+		a: &Path,
+		b: &Path,
+		max: Rect,
+		limits: Limits,
+		mode: ImageCompare,
+	) -> Result<Rect> {
+		if max.is_empty() {
+			return Ok(Rect::default());
 		}
+
+		let tmp = PREVIEW.load().cache_dir.join(format!("compare-{}.png", std::process::id()));
+		Image::compare(a, b, &tmp, max, limits, mode).await?;
+		self.image_show(&tmp, max, limits).await
 	}
 
 	pub fn image_hide(self) -> Result<()> {
@@ -74,20 +97,18 @@ impl Adapter {
 	pub(super) fn start(self) { drivers::Ueberzug::start(self); }
 
 	#[inline]
-	pub(super) fn needs_ueberzug(self) -> bool {
-		!matches!(self, Self::Kgp | Self::KgpOld | Self::Iip | Self::Sixel)
-	}
+	pub(super) fn needs_ueberzug(self) -> bool { matches!(self, Self::X11 | Self::Wayland) }
 }
 
 impl Adapter {
 	pub fn matches(emulator: Emulator) -> Self {
-		if emulator.kind.is_left_and(|&b| b == Brand::Microsoft) {
+		let mut protocols = emulator.adapters();
+		if emulator.kind.is_left_and(|&b| b == Brand::Microsoft) && protocols.contains(&Self::Sixel) {
 			return Self::Sixel;
 		} else if WSL.get() && emulator.kind.is_left_and(|&b| b == Brand::WezTerm) {
 			return Self::KgpOld;
 		}
 
-		let mut protocols = emulator.adapters().to_owned();
 		#[cfg(windows)]
 		protocols.retain(|p| *p == Self::Iip);
 		if env_exists("ZELLIJ_SESSION_NAME") {
@@ -95,6 +116,11 @@ impl Adapter {
 		} else if TMUX.get() {
 			protocols.retain(|p| *p != Self::KgpOld);
 		}
+		// tmux ignores the passthrough of popups, which aren't panes, and Neovim
+		// supports none of the protocols in its terminal
+		if NESTED.get().is_some() {
+			protocols.clear();
+		}
 		if let Some(p) = protocols.first() {
 			return *p;
 		}
@@ -24,10 +24,11 @@ pub enum Brand {
 	Apple,
 	Urxvt,
 	Bobcat,
+	Warp,
 }
 
 impl Brand {
-	pub(super) fn from_csi(resp: &str) -> Option<Self> {
+	pub fn from_csi(resp: &str) -> Option<Self> {
 		let names = [
 			("kitty", Self::Kitty),
 			("Konsole", Self::Konsole),
@@ -38,6 +39,11 @@ impl Brand {
 			("tmux ", Self::Tmux),
 			("libvterm", Self::VTerm),
 			("Bobcat", Self::Bobcat),
+			("rio ", Self::Rio),
+			("Rio ", Self::Rio),
+			("mintty", Self::Mintty),
+			("Tabby", Self::Tabby),
+			("Warp", Self::Warp),
 		];
 		names.into_iter().find(|&(n, _)| resp.contains(n)).map(|(_, b)| b)
 	}
@@ -55,6 +61,7 @@ impl Brand {
 			("ALACRITTY_WINDOW_ID", B::Alacritty),
 			("VSCODE_INJECTION", B::VSCode),
 			("TABBY_CONFIG_DIRECTORY", B::Tabby),
+			("WARP_IS_LOCAL_SHELL_SESSION", B::Warp),
 		];
 		match vars.into_iter().find(|&(s, _)| env_exists(s)) {
 			Some((_, brand)) => return Some(brand),
@@ -73,6 +80,8 @@ impl Brand {
 			"Hyper" => return Some(B::Hyper),
 			"mintty" => return Some(B::Mintty),
 			"Apple_Terminal" => return Some(B::Apple),
+			"WarpTerminal" => return Some(B::Warp),
+			"Bobcat" => return Some(B::Bobcat),
 			_ => warn!("[Adapter] Unknown TERM_PROGRAM: {program}"),
 		}
 		match term.as_str() {
@@ -113,9 +122,28 @@ impl Brand {
 			B::Apple => &[],
 			B::Urxvt => &[],
 			B::Bobcat => &[A::Iip, A::Sixel],
+			B::Warp => &[A::Iip],
 		}
 	}
 
+	/// Whether it's known to support synchronized output (DEC mode 2026), for
+	/// those that can't be asked, e.g. behind a multiplexer that eats the reply.
+	pub fn synchronized(self) -> bool {
+		use Brand as B;
+
+		matches!(
+			self,
+			B::Kitty
+				| B::Iterm2
+				| B::WezTerm
+				| B::Foot
+				| B::Ghostty
+				| B::Microsoft
+				| B::Rio
+				| B::Alacritty
+		)
+	}
+
 	fn env() -> (String, String) {
 		let (term, program) = Mux::term_program();
 		(
@@ -5,17 +5,17 @@ use base64::{Engine, engine::{Config, general_purpose::STANDARD}};
 use crossterm::{cursor::MoveTo, queue};
 use image::{DynamicImage, ExtendedColorType, ImageEncoder, codecs::{jpeg::JpegEncoder, png::PngEncoder}};
 use ratatui::layout::Rect;
-use yazi_config::PREVIEW;
+use yazi_config::plugin::Limits;
 
 use crate::{CLOSE, Emulator, Image, START, adapter::Adapter};
 
 pub(crate) struct Iip;
 
 impl Iip {
-	pub(crate) async fn image_show(path: &Path, max: Rect) -> Result<Rect> {
-		let img = Image::downscale(path, max).await?;
+	pub(crate) async fn image_show(path: &Path, max: Rect, limits: Limits) -> Result<Rect> {
+		let img = Image::downscale(path, max, limits).await?;
 		let area = Image::pixel_area((img.width(), img.height()), max);
-		let b = Self::encode(img).await?;
+		let b = Self::encode(img, limits.image_quality()).await?;
 
 		Adapter::Iip.image_hide()?;
 		Adapter::shown_store(area);
@@ -36,7 +36,7 @@ impl Iip {
 		})
 	}
 
-	async fn encode(img: DynamicImage) -> Result<Vec<u8>> {
+	async fn encode(img: DynamicImage, quality: u8) -> Result<Vec<u8>> {
 		tokio::task::spawn_blocking(move || {
 			let (w, h) = (img.width(), img.height());
 
@@ -44,7 +44,7 @@ impl Iip {
 			if img.color().has_alpha() {
 				PngEncoder::new(&mut b).write_image(&img.into_rgba8(), w, h, ExtendedColorType::Rgba8)?;
 			} else {
-				JpegEncoder::new_with_quality(&mut b, PREVIEW.image_quality).encode_image(&img)?;
+				JpegEncoder::new_with_quality(&mut b, quality).encode_image(&img)?;
 			};
 
 			let mut buf = String::with_capacity(
@@ -1,14 +1,27 @@
 use core::str;
-use std::{io::Write, path::Path};
+use std::{hash::{BuildHasher, RandomState}, io::Write, path::Path, sync::LazyLock};
 
 use anyhow::Result;
 use base64::{Engine, engine::general_purpose};
 use crossterm::{cursor::MoveTo, queue};
 use image::DynamicImage;
 use ratatui::layout::Rect;
+use yazi_config::plugin::Limits;
 
 use crate::{CLOSE, ESCAPE, Emulator, START, adapter::Adapter, image::Image};
 
+/// The ID of the images of this instance, random so the ones sharing a
+/// terminal, e.g. in tmux panes, don't replace or delete each other's image.
+///
+/// The least significant byte is carried by the color of the placeholders, and
+/// kept in the 256 colors since tmux approximates true colors to them if the
+/// outer terminal doesn't advertise them, and the most significant one by their
+/// third diacritic, see [`Kgp::place`].
+static ID: LazyLock<u32> = LazyLock::new(|| {
+	let r = RandomState::new().hash_one(std::process::id());
+	((r >> 8 & 0xff) as u32) << 24 | (r % 255 + 1) as u32
+});
+
 static DIACRITICS: [char; 297] = [
 	'\u{0305}',
 	'\u{030D}',
@@ -312,8 +325,8 @@ static DIACRITICS: [char; 297] = [
 pub(crate) struct Kgp;
 
 impl Kgp {
-	pub(crate) async fn image_show(path: &Path, max: Rect) -> Result<Rect> {
-		let img = Image::downscale(path, max).await?;
+	pub(crate) async fn image_show(path: &Path, max: Rect, limits: Limits) -> Result<Rect> {
+		let img = Image::downscale(path, max, limits).await?;
 		let area = Image::pixel_area((img.width(), img.height()), max);
 
 		let b1 = Self::encode(img).await?;
@@ -336,7 +349,7 @@ impl Kgp {
 				write!(stderr, "{s}")?;
 			}
 
-			write!(stderr, "{START}_Gq=2,a=d,d=A{ESCAPE}\\{CLOSE}")?;
+			write!(stderr, "{START}_Gq=2,a=d,d=I,i={}{ESCAPE}\\{CLOSE}", *ID)?;
 			Ok(())
 		})
 	}
@@ -350,7 +363,8 @@ impl Kgp {
 			if let Some(first) = it.next() {
 				write!(
 					buf,
-					"{START}_Gq=2,a=T,i=1,C=1,U=1,f={format},s={},v={},m={};{}{ESCAPE}\\{CLOSE}",
+					"{START}_Gq=2,a=T,i={},C=1,U=1,f={format},s={},v={},m={};{}{ESCAPE}\\{CLOSE}",
+					*ID,
 					size.0,
 					size.1,
 					it.peek().is_some() as u8,
@@ -378,13 +392,14 @@ impl Kgp {
 	}
 
 	fn place(area: &Rect) -> Result<Vec<u8>> {
-		let mut buf = Vec::with_capacity(area.width as usize * area.height as usize * 3 + 50);
+		let mut buf = Vec::with_capacity(area.width as usize * area.height as usize * 4 + 50);
 		for y in 0..area.height {
-			write!(buf, "\x1b[{};{}H\x1b[38;5;1m", area.y + y + 1, area.x + 1)?;
+			write!(buf, "\x1b[{};{}H\x1b[38;5;{}m", area.y + y + 1, area.x + 1, *ID & 0xff)?;
 			for x in 0..area.width {
 				write!(buf, "\u{10EEEE}")?;
 				write!(buf, "{}", *DIACRITICS.get(y as usize).unwrap_or(&DIACRITICS[0]))?;
 				write!(buf, "{}", *DIACRITICS.get(x as usize).unwrap_or(&DIACRITICS[0]))?;
+				write!(buf, "{}", DIACRITICS[(*ID >> 24) as usize])?;
 			}
 			write!(buf, "\x1b[0m")?;
 		}
@@ -5,15 +5,15 @@ use color_quant::NeuQuant;
 use crossterm::{cursor::MoveTo, queue};
 use image::DynamicImage;
 use ratatui::layout::Rect;
-use yazi_config::PREVIEW;
+use yazi_config::{PREVIEW, plugin::Limits};
 
 use crate::{CLOSE, ESCAPE, Emulator, Image, START, adapter::Adapter};
 
 pub(crate) struct Sixel;
 
 impl Sixel {
-	pub(crate) async fn image_show(path: &Path, max: Rect) -> Result<Rect> {
-		let img = Image::downscale(path, max).await?;
+	pub(crate) async fn image_show(path: &Path, max: Rect, limits: Limits) -> Result<Rect> {
+		let img = Image::downscale(path, max, limits).await?;
 		let area = Image::pixel_area((img.width(), img.height()), max);
 		let b = Self::encode(img).await?;
 
@@ -44,7 +44,7 @@ impl Sixel {
 
 		tokio::task::spawn_blocking(move || {
 			let img = img.into_rgba8();
-			let nq = NeuQuant::new(PREVIEW.sixel_fraction as i32, 256 - alpha as usize, &img);
+			let nq = NeuQuant::new(PREVIEW.load().sixel_fraction as i32, 256 - alpha as usize, &img);
 
 			let mut buf: Vec<u8> = Vec::with_capacity(1 << 16);
 			write!(buf, "{START}P0;1;8q\"1;1;{};{}", img.width(), img.height())?;
@@ -1,14 +1,15 @@
-use std::{path::{Path, PathBuf}, process::Stdio};
+use std::{collections::VecDeque, path::{Path, PathBuf}, process::Stdio, time::{Duration, Instant}};
 
 use anyhow::{Result, bail};
 use image::ImageReader;
 use ratatui::layout::Rect;
-use tokio::{io::AsyncWriteExt, process::{Child, Command}, sync::mpsc::{self, UnboundedSender}};
+use tokio::{io::AsyncWriteExt, process::{ChildStdin, Command}, select, sync::mpsc::{self, UnboundedSender}, task::AbortHandle};
 use tracing::{debug, warn};
-use yazi_config::PREVIEW;
-use yazi_shared::{LOG_LEVEL, RoCell, env_exists};
+use yazi_config::{PREVIEW, plugin::Limits};
+use yazi_macro::emit;
+use yazi_shared::{LOG_LEVEL, Layer, RoCell, env_exists};
 
-use crate::{Adapter, Dimension};
+use crate::{Adapter, Dimension, NESTED};
 
 type Cmd = Option<(PathBuf, Rect)>;
 
@@ -22,27 +23,22 @@ impl Ueberzug {
 			return DEMON.init(None);
 		}
 
-		let mut child = Self::create_demon(adapter).ok();
 		let (tx, mut rx) = mpsc::unbounded_channel();
-
+		let (exits_tx, mut exits_rx) = mpsc::unbounded_channel();
 		tokio::spawn(async move {
-			while let Some(cmd) = rx.recv().await {
-				let exit = child.as_mut().and_then(|c| c.try_wait().ok());
-				if exit != Some(None) {
-					child = None;
-				}
-				if child.is_none() {
-					child = Self::create_demon(adapter).ok();
-				}
-				if let Some(c) = &mut child {
-					Self::send_command(adapter, c, cmd).await.ok();
+			let mut demon = Demon::new(adapter, exits_tx);
+			loop {
+				select! {
+					Some(cmd) = rx.recv() => demon.send(cmd).await,
+					Some(gen) = exits_rx.recv() => demon.exited(gen).await,
+					else => break,
 				}
 			}
 		});
 		DEMON.init(Some(tx))
 	}
 
-	pub(crate) async fn image_show(path: &Path, max: Rect) -> Result<Rect> {
+	pub(crate) async fn image_show(path: &Path, max: Rect, limits: Limits) -> Result<Rect> {
 		let Some(tx) = &*DEMON else {
 			bail!("uninitialized ueberzugpp");
 		};
@@ -57,8 +53,8 @@ impl Ueberzug {
 			.map(|(r1, r2)| Rect {
 				x:      max.x,
 				y:      max.y,
-				width:  max.width.min((w.min(PREVIEW.max_width as _) as f64 / r1).ceil() as _),
-				height: max.height.min((h.min(PREVIEW.max_height as _) as f64 / r2).ceil() as _),
+				width:  max.width.min((w.min(limits.max_width() as _) as f64 / r1).ceil() as _),
+				height: max.height.min((h.min(limits.max_height() as _) as f64 / r2).ceil() as _),
 			})
 			.unwrap_or(max);
 
@@ -84,37 +80,22 @@ impl Ueberzug {
 			|| env_exists("WAYFIRE_SOCKET")
 	}
 
-	fn create_demon(adapter: Adapter) -> Result<Child> {
-		let result = Command::new("ueberzugpp")
-			.args(["layer", "-so", &adapter.to_string()])
-			.env("SPDLOG_LEVEL", if LOG_LEVEL.get().is_none() { "" } else { "debug" })
-			.kill_on_drop(true)
-			.stdin(Stdio::piped())
-			.stdout(Stdio::null())
-			.stderr(Stdio::null())
-			.spawn();
-
-		if let Err(ref e) = result {
-			warn!("Failed to start ueberzugpp: {e}");
-		}
-		Ok(result?)
-	}
-
 	fn adjust_rect(mut rect: Rect) -> Rect {
-		let scale = PREVIEW.ueberzug_scale;
-		let (x, y, w, h) = PREVIEW.ueberzug_offset;
+		let scale = PREVIEW.load().ueberzug_scale;
+		let (x, y, w, h) = PREVIEW.load().ueberzug_offset;
+		let (nx, ny) = NESTED.get().map_or((0, 0), |n| n.offset());
 
-		rect.x = 0f32.max(rect.x as f32 * scale + x) as u16;
-		rect.y = 0f32.max(rect.y as f32 * scale + y) as u16;
+		rect.x = 0f32.max((rect.x as i16 + nx) as f32 * scale + x) as u16;
+		rect.y = 0f32.max((rect.y as i16 + ny) as f32 * scale + y) as u16;
 		rect.width = 0f32.max(rect.width as f32 * scale + w) as u16;
 		rect.height = 0f32.max(rect.height as f32 * scale + h) as u16;
 		rect
 	}
 
-	async fn send_command(adapter: Adapter, child: &mut Child, cmd: Cmd) -> Result<()> {
-		let s = if let Some((path, rect)) = cmd {
+	fn serialize(cmd: &Cmd) -> String {
+		if let Some((path, rect)) = cmd {
 			debug!("ueberzugpp rect before adjustment: {:?}", rect);
-			let rect = Self::adjust_rect(rect);
+			let rect = Self::adjust_rect(*rect);
 			debug!("ueberzugpp rect after adjustment: {:?}", rect);
 
 			format!(
@@ -128,11 +109,135 @@ impl Ueberzug {
 			)
 		} else {
 			format!(r#"{{"action":"remove","identifier":"yazi"}}{}"#, '\n')
-		};
+		}
+	}
+}
+
+/// The Überzug++ child process, which is spawned on demand with the first
+/// output that works, and restarted if it exits.
+///
+/// An output that doesn't work in the current environment makes it exit right
+/// away, so it's given up on once it has exited several times in a row.
+struct Demon {
+	outputs: VecDeque<&'static str>,
+	child:   Option<(ChildStdin, AbortHandle)>,
+	/// Generation of the child, so the exit of a replaced one is ignored.
+	gen:     u64,
+	exits:   UnboundedSender<u64>,
+	crashes: VecDeque<Instant>,
+	last:    Cmd,
+}
+
+impl Demon {
+	fn new(adapter: Adapter, exits: UnboundedSender<u64>) -> Self {
+		let mut outputs = VecDeque::from([if adapter == Adapter::Wayland { "wayland" } else { "x11" }]);
+
+		// XWayland is the last resort for the compositors that aren't supported
+		if adapter == Adapter::Wayland && env_exists("DISPLAY") {
+			outputs.push_back("x11");
+		}
+		Self { outputs, child: None, gen: 0, exits, crashes: Default::default(), last: None }
+	}
+
+	async fn send(&mut self, cmd: Cmd) {
+		// Nothing to remove if it's not running
+		if cmd.is_none() && self.child.is_none() {
+			self.last = None;
+			return;
+		}
+
+		// Kept even if it fails to be written, so it's brought back once restarted
+		self.last = cmd;
+		self.write().await;
+	}
+
+	async fn exited(&mut self, gen: u64) {
+		if gen != self.gen {
+			return;
+		}
+
+		warn!("ueberzugpp exited unexpectedly");
+		self.child = None;
+		self.crashed();
 
-		debug!("`ueberzugpp layer -so {adapter}` command: {s}");
-		child.stdin.as_mut().unwrap().write_all(s.as_bytes()).await?;
+		// Bring the image back
+		if self.last.is_some() {
+			self.write().await;
+		}
+	}
+
+	async fn write(&mut self) {
+		let s = Ueberzug::serialize(&self.last);
+		let Some(stdin) = self.stdin() else { return };
+
+		// A failed write means it's exiting, which is handled once it's gone
+		debug!("ueberzugpp command: {s}");
+		stdin.write_all(s.as_bytes()).await.ok();
+	}
 
-		Ok(())
+	fn stdin(&mut self) -> Option<&mut ChildStdin> {
+		while self.child.is_none() {
+			let output = *self.outputs.front()?;
+			match self.spawn(output) {
+				Ok(child) => self.child = Some(child),
+				Err(e) => {
+					warn!("ueberzugpp output `{output}` is unavailable: {e}");
+					self.fallback();
+				}
+			}
+		}
+		self.child.as_mut().map(|(stdin, _)| stdin)
+	}
+
+	fn spawn(&mut self, output: &str) -> Result<(ChildStdin, AbortHandle)> {
+		let mut child = Command::new("ueberzugpp")
+			.args(["layer", "-so", output])
+			.env("SPDLOG_LEVEL", if LOG_LEVEL.get().is_none() { "" } else { "debug" })
+			.kill_on_drop(true)
+			.stdin(Stdio::piped())
+			.stdout(Stdio::null())
+			.stderr(Stdio::null())
+			.spawn()?;
+
+		// Taken out, since `wait()` closes it
+		let stdin = child.stdin.take().unwrap();
+
+		self.gen += 1;
+		let (gen, exits) = (self.gen, self.exits.clone());
+		let watch = tokio::spawn(async move {
+			child.wait().await.ok();
+			exits.send(gen).ok();
+		});
+		Ok((stdin, watch.abort_handle()))
+	}
+
+	fn crashed(&mut self) {
+		let now = Instant::now();
+		self.crashes.retain(|&t| now.duration_since(t) < Duration::from_secs(10));
+		self.crashes.push_back(now);
+
+		if self.crashes.len() >= 3 {
+			warn!("ueberzugpp exited {} times in a row", self.crashes.len());
+			self.crashes.clear();
+			self.fallback();
+		}
+	}
+
+	/// Move on to the next output, or hand it over to the main thread to fall
+	/// back to chafa once there's none left.
+	fn fallback(&mut self) {
+		self.outputs.pop_front();
+		if self.outputs.is_empty() {
+			warn!("No ueberzugpp output is available, falling back to chafa");
+			emit!(Call(yazi_shared::event::Cmd::new("fallback"), Layer::App));
+		}
+	}
+}
+
+impl Drop for Demon {
+	fn drop(&mut self) {
+		if let Some((_, watch)) = self.child.take() {
+			watch.abort();
+		}
 	}
 }
@@ -7,11 +7,12 @@ use tokio::{io::{AsyncReadExt, BufReader}, time::{sleep, timeout}};
 use tracing::{debug, error, warn};
 use yazi_shared::Either;
 
-use crate::{Adapter, Brand, Mux, TMUX, Unknown};
+use crate::{Adapter, Brand, CURSOR, Gate, Mux, TMUX, Unknown, Version};
 
 #[derive(Clone, Copy, Debug)]
 pub struct Emulator {
 	pub kind:      Either<Brand, Unknown>,
+	pub version:   Option<Version>,
 	pub light:     bool,
 	pub cell_size: Option<(u16, u16)>,
 }
@@ -21,12 +22,17 @@ impl Default for Emulator {
 }
 
 impl Emulator {
-	pub fn detect() -> Result<Self> {
+	#[inline]
+	pub fn detect() -> Result<Self> { Self::detect_verbose().map(|(emulator, _)| emulator) }
+
+	/// Same as [`Self::detect`], but also returns the raw response from the
+	/// terminal, for diagnosing.
+	pub fn detect_verbose() -> Result<(Self, String)> {
 		defer! { disable_raw_mode().ok(); }
 		enable_raw_mode()?;
 
 		let resort = Brand::from_env();
-		let kgp_seq = if resort.is_none() {
+		let kgp_seq = if resort.is_none() || TMUX.get() {
 			Mux::csi("\x1b_Gi=31,s=1,v=1,a=q,t=d,f=24;AAAA\x1b\\")
 		} else {
 			"".into()
@@ -35,41 +41,68 @@ impl Emulator {
 		execute!(
 			LineWriter::new(stderr()),
 			SavePosition,
-			Print(kgp_seq),             // Detect KGP
-			Print(Mux::csi("\x1b[>q")), // Request terminal version
-			Print("\x1b[16t"),          // Request cell size
-			Print("\x1b]11;?\x07"),     // Request background color
-			Print(Mux::csi("\x1b[0c")), // Request device attributes
+			Print(kgp_seq),                // Detect KGP
+			Print(Mux::csi("\x1b[>q")),    // Request terminal version
+			Print("\x1b[16t"),             // Request cell size
+			Print("\x1b]11;?\x07"),        // Request background color
+			Print(Mux::csi("\x1b[?996n")), // Request color scheme preference
+			Print(Mux::csi("\x1b[0c")),    // Request device attributes
 			RestorePosition
 		)?;
 
 		let resp = futures::executor::block_on(Self::read_until_da1());
 		Mux::tmux_drain()?;
 
-		let kind = if let Some(b) = Brand::from_csi(&resp).or(resort) {
-			Either::Left(b)
-		} else {
-			Either::Right(Unknown {
-				kgp:   resp.contains("\x1b_Gi=31;OK"),
+		let kgp = resp.contains("\x1b_Gi=31;OK");
+		let kind = match Brand::from_csi(&resp).or(resort) {
+			// The environment of tmux can be left by another terminal that attached first,
+			// so the outer terminal advertising KGP wins over a brand that has no protocol
+			Some(b) if !(kgp && TMUX.get() && b.adapters().is_empty()) => Either::Left(b),
+			_ => Either::Right(Unknown {
+				kgp,
 				sixel: ["?4;", "?4c", ";4;", ";4c"].iter().any(|s| resp.contains(s)),
-			})
+			}),
 		};
 
-		Ok(Self {
+		let emulator = Self {
 			kind,
-			light: Self::light_bg(&resp).unwrap_or_default(),
+			version: Version::from_csi(&resp).or_else(Version::from_env),
+			light: Self::light_scheme(&resp).unwrap_or_else(|| Self::light_bg(&resp).unwrap_or_default()),
 			cell_size: Self::cell_size(&resp),
-		})
+		};
+		Ok((emulator, resp))
+	}
+
+	/// Re-query the color scheme of the system and the background color of the
+	/// terminal, for live theme switching.
+	pub async fn query_light() -> Option<bool> {
+		execute!(
+			LineWriter::new(stderr()),
+			Print("\x1b]11;?\x07"),
+			Print(Mux::csi("\x1b[?996n")),
+			Print("\x1b[5n")
+		)
+		.ok()?;
+
+		let resp = Self::read_until_dsr().await;
+		Self::light_scheme(&resp)
+			.or_else(|| resp.contains("]11;rgb:").then(|| Self::light_bg(&resp).ok()).flatten())
 	}
 
 	pub const fn unknown() -> Self {
-		Self { kind: Either::Right(Unknown::default()), light: false, cell_size: None }
+		Self {
+			kind:      Either::Right(Unknown::default()),
+			version:   None,
+			light:     false,
+			cell_size: None,
+		}
 	}
 
-	pub fn adapters(self) -> &'static [Adapter] {
+	/// The protocols it supports, with the version gates applied, see [`Gate`].
+	pub fn adapters(self) -> Vec<Adapter> {
 		match self.kind {
-			Either::Left(brand) => brand.adapters(),
-			Either::Right(unknown) => unknown.adapters(),
+			Either::Left(brand) => Gate::apply(brand, self.version),
+			Either::Right(unknown) => unknown.adapters().to_vec(),
 		}
 	}
 
@@ -95,7 +128,9 @@ impl Emulator {
 		}
 
 		let result = cb(&mut buf);
-		if TMUX.get() || cfg!(windows) {
+		if (TMUX.get() || cfg!(windows)) && CURSOR.get() {
+			queue!(buf, RestorePosition)?;
+		} else if TMUX.get() || cfg!(windows) {
 			queue!(buf, Hide, RestorePosition)?;
 		} else {
 			queue!(buf, RestorePosition)?;
@@ -195,6 +230,16 @@ impl Emulator {
 		Some((w.parse().ok()?, h.parse().ok()?))
 	}
 
+	/// The light or dark preference of the system, as the terminals following it
+	/// report in reply to `CSI ? 996 n`, which wins over the background color.
+	fn light_scheme(resp: &str) -> Option<bool> {
+		match resp.rsplit_once("\x1b[?997;")?.1.get(..2)? {
+			"1n" => Some(false),
+			"2n" => Some(true),
+			_ => None,
+		}
+	}
+
 	fn light_bg(resp: &str) -> Result<bool> {
 		match resp.split_once("]11;rgb:") {
 			Some((_, s)) if s.len() >= 14 => {
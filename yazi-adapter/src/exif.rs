@@ -0,0 +1,119 @@
+use chrono::NaiveDateTime;
+
+const DATE_TIME: u16 = 0x0132;
+const EXIF_IFD: u16 = 0x8769;
+const DATE_TIME_ORIGINAL: u16 = 0x9003;
+const DATE_TIME_DIGITIZED: u16 = 0x9004;
+
+/// When the photo was taken according to its EXIF chunk, i.e. the TIFF
+/// structure in it, falling back to when it was digitized or last changed.
+pub(crate) fn date_taken(chunk: &[u8]) -> Option<NaiveDateTime> {
+	let tiff = Tiff::new(chunk)?;
+	let ifd0 = tiff.u32(4)? as usize;
+
+	let exif = tiff.entry(ifd0, EXIF_IFD).and_then(|e| tiff.u32(e + 8)).map(|n| n as usize);
+	exif
+		.and_then(|ifd| {
+			tiff.ascii(ifd, DATE_TIME_ORIGINAL).or_else(|| tiff.ascii(ifd, DATE_TIME_DIGITIZED))
+		})
+		.or_else(|| tiff.ascii(ifd0, DATE_TIME))
+		.and_then(|s| NaiveDateTime::parse_from_str(s, "%Y:%m:%d %H:%M:%S").ok())
+}
+
+struct Tiff<'a> {
+	data: &'a [u8],
+	le:   bool,
+}
+
+impl<'a> Tiff<'a> {
+	fn new(data: &'a [u8]) -> Option<Self> {
+		match data.get(..4)? {
+			[b'I', b'I', 42, 0] => Some(Self { data, le: true }),
+			[b'M', b'M', 0, 42] => Some(Self { data, le: false }),
+			_ => None,
+		}
+	}
+
+	fn u16(&self, at: usize) -> Option<u16> {
+		let b = self.data.get(at..at + 2)?.try_into().ok()?;
+		Some(if self.le { u16::from_le_bytes(b) } else { u16::from_be_bytes(b) })
+	}
+
+	fn u32(&self, at: usize) -> Option<u32> {
+		let b = self.data.get(at..at + 4)?.try_into().ok()?;
+		Some(if self.le { u32::from_le_bytes(b) } else { u32::from_be_bytes(b) })
+	}
+
+	/// The offset of the 12-byte entry of `tag` in the IFD at `ifd`.
+	fn entry(&self, ifd: usize, tag: u16) -> Option<usize> {
+		let n = self.u16(ifd)? as usize;
+		(0..n).map(|i| ifd + 2 + i * 12).find(|&e| self.u16(e) == Some(tag))
+	}
+
+	/// The value of an ASCII entry, which is always stored elsewhere for a date.
+	fn ascii(&self, ifd: usize, tag: u16) -> Option<&'a str> {
+		let e = self.entry(ifd, tag)?;
+		if self.u16(e + 2)? != 2 {
+			return None;
+		}
+
+		let (len, at) = (self.u32(e + 4)? as usize, self.u32(e + 8)? as usize);
+		let s = self.data.get(at..at.checked_add(len)?)?;
+		std::str::from_utf8(s).ok().map(|s| s.trim_end_matches('\0'))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn chunk(le: bool, tags: &[(u16, &str)]) -> Vec<u8> {
+		let u16b = |n: u16| if le { n.to_le_bytes() } else { n.to_be_bytes() };
+		let u32b = |n: u32| if le { n.to_le_bytes() } else { n.to_be_bytes() };
+
+		let mut data = if le { b"II*\0".to_vec() } else { b"MM\0*".to_vec() };
+		data.extend(u32b(8));
+
+		// IFD0 with only a pointer to the EXIF IFD, which follows right after it
+		let exif = 8 + 2 + 12 + 4;
+		data.extend(u16b(1));
+		data.extend(u16b(EXIF_IFD));
+		data.extend(u16b(4));
+		data.extend(u32b(1));
+		data.extend(u32b(exif));
+		data.extend(u32b(0));
+
+		let mut values = exif as usize + 2 + tags.len() * 12 + 4;
+		data.extend(u16b(tags.len() as u16));
+		for &(tag, s) in tags {
+			data.extend(u16b(tag));
+			data.extend(u16b(2));
+			data.extend(u32b(s.len() as u32 + 1));
+			data.extend(u32b(values as u32));
+			values += s.len() + 1;
+		}
+		data.extend(u32b(0));
+		for &(_, s) in tags {
+			data.extend(s.as_bytes());
+			data.push(0);
+		}
+		data
+	}
+
+	#[test]
+	fn test_date_taken() {
+		let dt = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").ok();
+
+		let c = chunk(true, &[(DATE_TIME_ORIGINAL, "2024:05:03 10:11:12")]);
+		assert_eq!(date_taken(&c), dt("2024-05-03 10:11:12"));
+
+		let c = chunk(false, &[(DATE_TIME_DIGITIZED, "2023:01:02 03:04:05")]);
+		assert_eq!(date_taken(&c), dt("2023-01-02 03:04:05"));
+
+		let c = chunk(true, &[(DATE_TIME_ORIGINAL, "0000:00:00 00:00:00")]);
+		assert_eq!(date_taken(&c), None);
+
+		assert_eq!(date_taken(b"II*\0\xff\xff\xff\xff"), None);
+		assert_eq!(date_taken(b"not a tiff"), None);
+	}
+}
@@ -0,0 +1,82 @@
+use crate::{Adapter, Brand, Version};
+
+/// A protocol that a terminal only supports well since a version, which is
+/// replaced with the `fallback` one for the older versions, or dropped if
+/// there's none. Terminals that don't report their version are let through.
+#[derive(Clone, Copy, Debug)]
+pub struct Gate {
+	pub brand:    Brand,
+	pub adapter:  Adapter,
+	pub since:    Version,
+	pub fallback: Option<Adapter>,
+	pub reason:   &'static str,
+}
+
+pub const GATES: &[Gate] = &[
+	Gate {
+		brand:    Brand::Kitty,
+		adapter:  Adapter::Kgp,
+		since:    Version(0, 28, 0),
+		fallback: Some(Adapter::KgpOld),
+		reason:   "Unicode placeholders",
+	},
+	Gate {
+		brand:    Brand::Konsole,
+		adapter:  Adapter::KgpOld,
+		since:    Version(22, 4, 0),
+		fallback: None,
+		reason:   "Kitty graphics protocol",
+	},
+	Gate {
+		brand:    Brand::WezTerm,
+		adapter:  Adapter::Sixel,
+		since:    Version(20220319, 0, 0),
+		fallback: None,
+		reason:   "Sixel graphics",
+	},
+	Gate {
+		brand:    Brand::Iterm2,
+		adapter:  Adapter::Sixel,
+		since:    Version(3, 3, 0),
+		fallback: None,
+		reason:   "Sixel graphics",
+	},
+	Gate {
+		brand:    Brand::Foot,
+		adapter:  Adapter::Sixel,
+		since:    Version(1, 2, 0),
+		fallback: None,
+		reason:   "Sixel graphics",
+	},
+	Gate {
+		brand:    Brand::Microsoft,
+		adapter:  Adapter::Sixel,
+		since:    Version(1, 22, 0),
+		fallback: None,
+		reason:   "Sixel graphics",
+	},
+];
+
+impl Gate {
+	/// The protocols of the `brand` at the `version`, with the gates applied.
+	pub fn apply(brand: Brand, version: Option<Version>) -> Vec<Adapter> {
+		brand
+			.adapters()
+			.iter()
+			.filter_map(|&a| match Self::find(brand, a) {
+				Some(g) if version.is_some_and(|v| v < g.since) => g.fallback,
+				_ => Some(a),
+			})
+			.collect()
+	}
+
+	/// Whether the `version` passes the gate, `None` if it's unknown.
+	#[inline]
+	pub fn passes(&self, version: Option<Version>) -> Option<bool> {
+		version.map(|v| v >= self.since)
+	}
+
+	fn find(brand: Brand, adapter: Adapter) -> Option<&'static Self> {
+		GATES.iter().find(|g| g.brand == brand && g.adapter == adapter)
+	}
+}
@@ -1,18 +1,35 @@
-use std::path::{Path, PathBuf};
+use std::{io::Cursor, path::{Path, PathBuf}};
 
-use anyhow::Result;
-use image::{DynamicImage, ExtendedColorType, ImageDecoder, ImageEncoder, ImageError, ImageReader, ImageResult, Limits, codecs::{jpeg::JpegEncoder, png::PngEncoder}, imageops::FilterType, metadata::Orientation};
+use anyhow::{Result, anyhow};
+use image::{DynamicImage, ExtendedColorType, ImageDecoder, ImageEncoder, ImageError, ImageFormat, ImageReader, ImageResult, Rgba, RgbaImage, codecs::{jpeg::JpegEncoder, png::PngEncoder}, imageops::{self, FilterType}, metadata::Orientation};
 use ratatui::layout::Rect;
-use yazi_config::{PREVIEW, TASKS};
+use yazi_config::{PREVIEW, TASKS, plugin::Limits};
 
-use crate::Dimension;
+use crate::{Dimension, Transform};
+
+/// The JPEG quality of the transformed images, which are meant to be kept
+/// unlike the cached ones.
+const TRANSFORM_QUALITY: u8 = 90;
+
+/// The gap in pixels between two images shown side by side.
+const COMPARE_GAP: u32 = 8;
 
 pub struct Image;
 
+/// How two images are put together to compare them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageCompare {
+	SideBySide,
+	/// The first image on the left of the percentage of the width, and the
+	/// second on the right of it, lined up.
+	Wipe(u8),
+}
+
 impl Image {
-	pub async fn precache(path: &Path, cache: PathBuf) -> Result<()> {
+	pub async fn precache(path: &Path, cache: PathBuf, limits: Limits) -> Result<()> {
 		let (mut img, orientation, icc) = Self::decode_from(path).await?;
-		let (w, h) = Self::flip_size(orientation, (PREVIEW.max_width, PREVIEW.max_height));
+		let (w, h) = Self::flip_size(orientation, (limits.max_width(), limits.max_height()));
+		let quality = limits.image_quality();
 
 		let buf = tokio::task::spawn_blocking(move || {
 			if img.width() > w || img.height() > h {
@@ -29,7 +46,7 @@ impl Image {
 				icc.map(|b| encoder.set_icc_profile(b));
 				encoder.write_image(&rgba, rgba.width(), rgba.height(), ExtendedColorType::Rgba8)?;
 			} else {
-				let mut encoder = JpegEncoder::new_with_quality(&mut buf, PREVIEW.image_quality);
+				let mut encoder = JpegEncoder::new_with_quality(&mut buf, quality);
 				icc.map(|b| encoder.set_icc_profile(b));
 				encoder.encode_image(&img.into_rgb8())?;
 			}
@@ -41,9 +58,9 @@ impl Image {
 		Ok(tokio::fs::write(cache, buf).await?)
 	}
 
-	pub(super) async fn downscale(path: &Path, rect: Rect) -> Result<DynamicImage> {
+	pub(super) async fn downscale(path: &Path, rect: Rect, limits: Limits) -> Result<DynamicImage> {
 		let (mut img, orientation, _) = Self::decode_from(path).await?;
-		let (w, h) = Self::flip_size(orientation, Self::max_pixel(rect));
+		let (w, h) = Self::flip_size(orientation, Self::max_pixel(rect, limits));
 
 		// Fast path.
 		if img.width() <= w && img.height() <= h && orientation == Orientation::NoTransforms {
@@ -64,13 +81,152 @@ impl Image {
 		Ok(img)
 	}
 
-	pub(super) fn max_pixel(rect: Rect) -> (u32, u32) {
+	/// Apply `transform` to the image at `from`, writing it to `to`, which can
+	/// be the same path, as it's replaced atomically.
+	///
+	/// Returns `false` if there's nothing to do, e.g. orienting an upright image.
+	pub async fn transform(from: &Path, to: &Path, transform: Transform) -> Result<bool> {
+		let (mut img, orientation, icc) = Self::decode_from(from).await?;
+		let format = match transform {
+			Transform::Convert(f) => f,
+			_ => match ImageFormat::from_path(from) {
+				Ok(f) => f,
+				Err(_) => ImageReader::open(from)?
+					.with_guessed_format()?
+					.format()
+					.ok_or_else(|| anyhow!("Unknown image format: {from:?}"))?,
+			},
+		};
+
+		match transform {
+			Transform::Orient if orientation == Orientation::NoTransforms => return Ok(false),
+			Transform::Resize(max)
+				if img.width() <= max
+					&& img.height() <= max
+					&& orientation == Orientation::NoTransforms =>
+			{
+				return Ok(false);
+			}
+			_ => {}
+		}
+
+		let buf = tokio::task::spawn_blocking(move || {
+			if let Transform::Resize(max) = transform {
+				let (w, h) = Self::flip_size(orientation, (max, max));
+				if img.width() > w || img.height() > h {
+					img = img.resize(w, h, Self::filter());
+				}
+			}
+			if orientation != Orientation::NoTransforms {
+				img.apply_orientation(orientation);
+			}
+
+			let mut buf = Vec::new();
+			match format {
+				ImageFormat::Jpeg => {
+					let mut encoder = JpegEncoder::new_with_quality(&mut buf, TRANSFORM_QUALITY);
+					icc.map(|b| encoder.set_icc_profile(b));
+					encoder.encode_image(&img.into_rgb8())?;
+				}
+				ImageFormat::Png => {
+					let mut encoder = PngEncoder::new(&mut buf);
+					icc.map(|b| encoder.set_icc_profile(b));
+					let (w, h, color) = (img.width(), img.height(), img.color().into());
+					encoder.write_image(img.as_bytes(), w, h, color)?;
+				}
+				_ if img.color().has_alpha() => {
+					DynamicImage::from(img.into_rgba8()).write_to(&mut Cursor::new(&mut buf), format)?
+				}
+				_ => DynamicImage::from(img.into_rgb8()).write_to(&mut Cursor::new(&mut buf), format)?,
+			}
+
+			Ok::<_, ImageError>(buf)
+		})
+		.await??;
+
+		let tmp = to.with_file_name(format!(
+			".{}.yazi-{}",
+			to.file_name().ok_or_else(|| anyhow!("Invalid path: {to:?}"))?.to_string_lossy(),
+			std::process::id()
+		));
+		tokio::fs::write(&tmp, buf).await?;
+		if let Err(e) = tokio::fs::rename(&tmp, to).await {
+			tokio::fs::remove_file(&tmp).await.ok();
+			Err(e)?;
+		}
+		Ok(true)
+	}
+
+	/// Put the images at `a` and `b` together into one, fitting in `rect`, saved
+	/// to `to`, since only one image can be shown at a time.
+	pub(super) async fn compare(
+		a: &Path,
+		b: &Path,
+		to: &Path,
+		rect: Rect,
+		limits: Limits,
+		mode: ImageCompare,
+	) -> Result<()> {
+		let ((a, oa, _), (b, ob, _)) = tokio::try_join!(Self::decode_from(a), Self::decode_from(b))?;
+		let (w, h) = Self::max_pixel(rect, limits);
+
+		let to = to.to_owned();
+		tokio::task::spawn_blocking(move || {
+			let fit = |mut img: DynamicImage, orientation, (w, h): (u32, u32)| {
+				img.apply_orientation(orientation);
+				if img.width() > w || img.height() > h { img.resize(w, h, Self::filter()) } else { img }
+			};
+
+			let canvas = match mode {
+				ImageCompare::SideBySide => {
+					let half = (w.saturating_sub(COMPARE_GAP) / 2, h);
+					let (a, b) = (fit(a, oa, half).into_rgba8(), fit(b, ob, half).into_rgba8());
+
+					let height = a.height().max(b.height());
+					let mut canvas = RgbaImage::new(a.width() + COMPARE_GAP + b.width(), height);
+					imageops::overlay(&mut canvas, &a, 0, ((height - a.height()) / 2) as i64);
+					imageops::overlay(
+						&mut canvas,
+						&b,
+						(a.width() + COMPARE_GAP) as i64,
+						((height - b.height()) / 2) as i64,
+					);
+					canvas
+				}
+				ImageCompare::Wipe(percent) => {
+					// The second is stretched to the first, to line them up
+					let a = fit(a, oa, (w, h)).into_rgba8();
+					let mut b = b;
+					b.apply_orientation(ob);
+					let mut canvas = b.resize_exact(a.width(), a.height(), Self::filter()).into_rgba8();
+
+					let split = a.width() * percent.min(100) as u32 / 100;
+					for (x, y, p) in canvas.enumerate_pixels_mut() {
+						if x < split {
+							*p = *a.get_pixel(x, y);
+						} else if x < split + 2 {
+							*p = Rgba([255, 255, 255, 255]);
+						}
+					}
+					canvas
+				}
+			};
+
+			canvas.save_with_format(to, ImageFormat::Png)
+		})
+		.await??;
+
+		Ok(())
+	}
+
+	pub(super) fn max_pixel(rect: Rect, limits: Limits) -> (u32, u32) {
+		let (max_w, max_h) = (limits.max_width(), limits.max_height());
 		Dimension::ratio()
 			.map(|(r1, r2)| {
 				let (w, h) = ((rect.width as f64 * r1) as u32, (rect.height as f64 * r2) as u32);
-				(w.min(PREVIEW.max_width), h.min(PREVIEW.max_height))
+				(w.min(max_w), h.min(max_h))
 			})
-			.unwrap_or((PREVIEW.max_width, PREVIEW.max_height))
+			.unwrap_or((max_w, max_h))
 	}
 
 	pub(super) fn pixel_area(size: (u32, u32), rect: Rect) -> Rect {
@@ -86,7 +242,7 @@ impl Image {
 
 	#[inline]
 	fn filter() -> FilterType {
-		match PREVIEW.image_filter.as_str() {
+		match PREVIEW.load().image_filter.as_str() {
 			"nearest" => FilterType::Nearest,
 			"triangle" => FilterType::Triangle,
 			"catmull-rom" => FilterType::CatmullRom,
@@ -97,7 +253,7 @@ impl Image {
 	}
 
 	async fn decode_from(path: &Path) -> ImageResult<(DynamicImage, Orientation, Option<Vec<u8>>)> {
-		let mut limits = Limits::no_limits();
+		let mut limits = image::Limits::no_limits();
 		if TASKS.image_alloc > 0 {
 			limits.max_alloc = Some(TASKS.image_alloc as u64);
 		}
@@ -1,7 +1,10 @@
 use std::path::Path;
 
+use chrono::NaiveDateTime;
 use image::{ImageDecoder, ImageError};
 
+use crate::date_taken;
+
 pub type ImageFormat = image::ImageFormat;
 pub type ImageColor = image::ColorType;
 pub type ImageOrientation = image::metadata::Orientation;
@@ -13,6 +16,7 @@ pub struct ImageInfo {
 	pub height:      u32,
 	pub color:       ImageColor,
 	pub orientation: Option<ImageOrientation>,
+	pub taken:       Option<NaiveDateTime>,
 }
 
 impl ImageInfo {
@@ -30,12 +34,14 @@ impl ImageInfo {
 
 			let mut decoder = reader.into_decoder()?;
 			let (width, height) = decoder.dimensions();
+			let exif = decoder.exif_metadata().ok().flatten();
 			Ok(Self {
 				format,
 				width,
 				height,
 				color: decoder.color_type(),
 				orientation: decoder.orientation().ok(),
+				taken: exif.as_deref().and_then(date_taken),
 			})
 		})
 		.await
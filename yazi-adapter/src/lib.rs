@@ -2,7 +2,7 @@
 
 yazi_macro::mod_pub!(drivers);
 
-yazi_macro::mod_flat!(adapter brand dimension emulator image info mux unknown);
+yazi_macro::mod_flat!(adapter brand dimension emulator exif gate image info mux nested probe region transform unknown version);
 
 use yazi_shared::{SyncCell, in_wsl};
 
@@ -12,6 +12,9 @@ pub static ADAPTOR: SyncCell<Adapter> = SyncCell::new(Adapter::Chafa);
 // Image state
 static SHOWN: SyncCell<Option<ratatui::layout::Rect>> = SyncCell::new(None);
 
+// Whether the cursor is shown, e.g. in the input, where an IME puts its preedit
+pub static CURSOR: SyncCell<bool> = SyncCell::new(false);
+
 // WSL support
 pub static WSL: SyncCell<bool> = SyncCell::new(false);
 
@@ -21,6 +24,9 @@ static ESCAPE: SyncCell<&'static str> = SyncCell::new("\x1b");
 static START: SyncCell<&'static str> = SyncCell::new("\x1b");
 static CLOSE: SyncCell<&'static str> = SyncCell::new("");
 
+// Nested terminal support, e.g. tmux popups and Neovim terminal windows
+pub static NESTED: SyncCell<Option<Nested>> = SyncCell::new(None);
+
 pub fn init() -> anyhow::Result<()> {
 	// WSL support
 	WSL.set(in_wsl());
@@ -40,6 +46,7 @@ pub fn init() -> anyhow::Result<()> {
 
 	yazi_config::init_flavor(EMULATOR.get().light)?;
 
+	NESTED.set(Nested::detect());
 	ADAPTOR.set(Adapter::matches(EMULATOR.get()));
 	ADAPTOR.get().start();
 	Ok(())
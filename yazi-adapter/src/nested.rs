@@ -0,0 +1,103 @@
+use std::process::{Command, Stdio};
+
+use yazi_shared::env_exists;
+
+use crate::{NESTED, TMUX};
+
+/// The terminal that yazi runs in is nested in another one without being a
+/// regular pane of it, so images drawn with absolute coordinates, i.e. by
+/// Überzug++, have to be shifted by where it's placed on the screen, and the
+/// ones drawn with escape sequences may not get through to the outer terminal.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Nested {
+	/// A `tmux display-popup`, which is not a pane, so Überzug++ would place
+	/// the image relative to the active pane beneath it instead.
+	TmuxPopup(i16, i16),
+	/// A Neovim terminal window, either a split or a floating one.
+	NeovimWindow(i16, i16),
+}
+
+impl Nested {
+	pub(super) fn detect() -> Option<Self> {
+		if env_exists("NVIM") {
+			Self::neovim_window()
+		} else if TMUX.get() {
+			Self::tmux_popup()
+		} else {
+			None
+		}
+	}
+
+	/// Detect it again, since it can be moved or resized, e.g. a Neovim floating
+	/// window, where the adapter stays as chosen at startup.
+	pub fn refresh() {
+		if NESTED.get().is_some() {
+			NESTED.set(Self::detect());
+		}
+	}
+
+	#[inline]
+	pub fn offset(self) -> (i16, i16) {
+		match self {
+			Self::TmuxPopup(x, y) | Self::NeovimWindow(x, y) => (x, y),
+		}
+	}
+
+	fn tmux_popup() -> Option<Self> {
+		let (w, h) = crossterm::terminal::size().ok()?;
+
+		// Popups don't get a `$TMUX_PANE`, Überzug++ falls back to the active pane then
+		let mut cmd = Command::new("tmux");
+		cmd.args(["display", "-p"]);
+		if let Ok(pane) = std::env::var("TMUX_PANE") {
+			cmd.args(["-t", &pane]);
+		}
+
+		let s = Self::output(cmd.arg(
+			"#{pane_left} #{pane_top} #{pane_width} #{pane_height} #{client_width} #{client_height}",
+		))?;
+		let v: Vec<i16> = s.split_whitespace().filter_map(|s| s.parse().ok()).collect();
+		let &[left, top, pw, ph, cw, ch] = v.as_slice() else { return None };
+
+		if (w as i16, h as i16) == (pw, ph) {
+			return None;
+		}
+
+		// Popups are centered with a border by default
+		let x = (cw - w as i16 - 2) / 2 + 1;
+		let y = (ch - h as i16 - 2) / 2 + 1;
+		Some(Self::TmuxPopup(x - left, y - top))
+	}
+
+	fn neovim_window() -> Option<Self> {
+		const LUA: &str = "local pids = {...} \
+			for _, w in ipairs(vim.api.nvim_list_wins()) do \
+				local ok, pid = pcall(vim.api.nvim_buf_get_var, vim.api.nvim_win_get_buf(w), 'terminal_job_pid') \
+				if ok and vim.tbl_contains(pids, pid) then \
+					local p = vim.fn.win_screenpos(w) \
+					return (p[2] - 1) .. ' ' .. (p[1] - 1) \
+				end \
+			end \
+			return ''";
+
+		#[cfg(unix)]
+		let pids = format!("{}, {}", std::process::id(), std::os::unix::process::parent_id());
+		#[cfg(not(unix))]
+		let pids = std::process::id().to_string();
+
+		let s = Self::output(Command::new("nvim").args([
+			"--server",
+			&std::env::var("NVIM").ok()?,
+			"--remote-expr",
+			&format!("luaeval(\"(function(...) {LUA} end)({pids})\")"),
+		]))?;
+
+		let (x, y) = s.trim().split_once(' ')?;
+		Some(Self::NeovimWindow(x.parse().ok()?, y.parse().ok()?))
+	}
+
+	fn output(cmd: &mut Command) -> Option<String> {
+		let output = cmd.stdin(Stdio::null()).stderr(Stdio::null()).output().ok()?;
+		output.status.success().then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+	}
+}
@@ -0,0 +1,51 @@
+use std::io::stderr;
+
+use anyhow::Result;
+use crossterm::{cursor::MoveTo, execute, style::Print, terminal::{Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen}};
+use ratatui::layout::Rect;
+use yazi_config::plugin::Limits;
+
+use crate::{Adapter, SHOWN};
+
+/// Draw a sample image with each of the protocols side by side, so that it's
+/// obvious which of them actually work in the current terminal.
+pub struct Probe;
+
+impl Probe {
+	pub async fn run(adapters: &[Adapter]) -> Result<Vec<(Adapter, Result<Rect>)>> {
+		let path = std::env::temp_dir().join("yazi-probe.png");
+		Self::sample().save(&path)?;
+
+		execute!(stderr(), EnterAlternateScreen, Clear(ClearType::All))?;
+
+		let mut results = Vec::with_capacity(adapters.len());
+		for (i, &adapter) in adapters.iter().enumerate() {
+			let (x, y) = (i as u16 % 3 * 26 + 1, i as u16 / 3 * 10 + 1);
+			execute!(stderr(), MoveTo(x, y), Print(format!("{}. {adapter}", i + 1)))?;
+
+			let result = adapter
+				.image_show(&path, Rect { x, y: y + 1, width: 24, height: 8 }, Limits::default())
+				.await;
+			SHOWN.set(None); // Keep it while the next one is being shown
+			results.push((adapter, result));
+		}
+
+		let bottom = (adapters.len() as u16).div_ceil(3) * 10 + 1;
+		execute!(stderr(), MoveTo(1, bottom), Print("Press <Enter> to continue..."))?;
+		std::io::stdin().read_line(&mut String::new())?;
+
+		for (adapter, result) in &results {
+			if let Ok(area) = result {
+				adapter.image_erase(*area).ok();
+			}
+		}
+
+		execute!(stderr(), LeaveAlternateScreen)?;
+		std::fs::remove_file(path).ok();
+		Ok(results)
+	}
+
+	fn sample() -> image::RgbImage {
+		image::RgbImage::from_fn(128, 128, |x, y| image::Rgb([(x * 2) as u8, (y * 2) as u8, 160]))
+	}
+}
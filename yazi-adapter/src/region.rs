@@ -0,0 +1,68 @@
+use parking_lot::Mutex;
+use ratatui::layout::Rect;
+use yazi_macro::emit;
+
+use crate::ADAPTOR;
+
+pub static REGIONS: Mutex<Regions> =
+	Mutex::new(Regions { covers: vec![], last: vec![], partial: false });
+
+/// The overlays drawn over the image, e.g. popups, which always sit above it.
+/// The image is erased under those that have just opened, and drawn again once
+/// any part of it they covered is revealed.
+pub struct Regions {
+	/// The overlays on screen, and whether they're from a partial frame, which is
+	/// drawn on top of the full one.
+	covers:  Vec<(Rect, bool)>,
+	/// The parts of the image covered as of the last frame.
+	last:    Vec<Rect>,
+	partial: bool,
+}
+
+impl Regions {
+	/// Starts a frame, where the overlays it draws again are dropped, i.e. all of
+	/// them for a full frame, or only those of the last partial one.
+	pub fn begin(&mut self, partial: bool) {
+		self.partial = partial;
+		self.covers.retain(|&(_, p)| partial && !p);
+	}
+
+	/// Puts an overlay over the image, returning the part of the image under it,
+	/// which is erased unless it was covered already.
+	pub fn cover(&mut self, area: Rect) -> Option<Rect> {
+		self.covers.push((area, self.partial));
+
+		let r = area.intersection(ADAPTOR.get().shown_load()?);
+		if r.is_empty() {
+			return None;
+		} else if !self.last.iter().any(|&l| l.intersection(r) == r) {
+			ADAPTOR.get().image_erase(r).ok();
+		}
+		Some(r)
+	}
+
+	/// Ends a frame, returning whether a part of the image covered before is
+	/// revealed now, so it needs to be drawn again.
+	pub fn settle(&mut self) -> bool {
+		let Some(shown) = ADAPTOR.get().shown_load() else {
+			self.last.clear();
+			return false;
+		};
+
+		let now: Vec<_> =
+			self.covers.iter().map(|&(r, _)| r.intersection(shown)).filter(|r| !r.is_empty()).collect();
+		let revealed =
+			self.last.iter().any(|l| l.positions().any(|p| !now.iter().any(|r| r.contains(p))));
+		self.last = now;
+		revealed
+	}
+
+	/// An image is drawn at `area`, over any overlay on it, which has it erased
+	/// by the next frame.
+	pub(super) fn drawn(&mut self, area: Rect) {
+		if self.covers.iter().any(|&(r, _)| r.intersects(area)) {
+			self.last.clear();
+			emit!(Render);
+		}
+	}
+}
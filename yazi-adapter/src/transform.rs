@@ -0,0 +1,49 @@
+use std::fmt::{self, Display};
+
+use anyhow::{Result, bail};
+use image::ImageFormat;
+
+/// A bulk operation on image files, which re-encodes them without the EXIF
+/// metadata, so the orientation it carries is always applied to the pixels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Transform {
+	/// Shrink to fit within a square of the given size, keeping the aspect ratio.
+	Resize(u32),
+	/// Convert to another format, written next to the original.
+	Convert(ImageFormat),
+	/// Strip the EXIF metadata.
+	Strip,
+	/// Rotate according to the orientation tag, leaving upright ones untouched.
+	Orient,
+}
+
+impl Transform {
+	pub fn convert(to: &str) -> Result<Self> {
+		Ok(Self::Convert(match to.to_ascii_lowercase().as_str() {
+			"png" => ImageFormat::Png,
+			"webp" => ImageFormat::WebP,
+			"jpg" | "jpeg" => ImageFormat::Jpeg,
+			_ => bail!("Unsupported format `{to}`, expected one of `png`, `webp` or `jpeg`"),
+		}))
+	}
+
+	/// The extension of the converted file, if it's a conversion.
+	#[inline]
+	pub fn extension(self) -> Option<&'static str> {
+		match self {
+			Self::Convert(f) => f.extensions_str().first().copied(),
+			_ => None,
+		}
+	}
+}
+
+impl Display for Transform {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Resize(max) => write!(f, "Resize to {max}px"),
+			Self::Convert(to) => write!(f, "Convert to {}", to.extensions_str()[0].to_uppercase()),
+			Self::Strip => write!(f, "Strip EXIF"),
+			Self::Orient => write!(f, "Auto-orient"),
+		}
+	}
+}
@@ -0,0 +1,63 @@
+use std::fmt::{self, Display};
+
+use crate::TMUX;
+
+/// The version of a terminal, as reported by XTVERSION or in
+/// `TERM_PROGRAM_VERSION`, where a date-based one like WezTerm's
+/// `20240203-110809-5046fc22` is taken as `20240203.110809.5046`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version(pub u32, pub u32, pub u32);
+
+impl Version {
+	pub fn parse(s: &str) -> Option<Self> {
+		let s = s.trim_start_matches(|c: char| !c.is_ascii_digit());
+		let mut it = s.split(['.', '-']).map(|p| {
+			p.bytes()
+				.take_while(u8::is_ascii_digit)
+				.fold(0u32, |n, b| n.saturating_mul(10).saturating_add((b - b'0') as u32))
+		});
+
+		let major = it.next().filter(|_| !s.is_empty())?;
+		Some(Self(major, it.next().unwrap_or(0), it.next().unwrap_or(0)))
+	}
+
+	/// From the XTVERSION reply, e.g. `\x1bP>|kitty(0.35.2)\x1b\\`, where the
+	/// name before the version can contain digits, like `iTerm2 3.5.0`.
+	pub fn from_csi(resp: &str) -> Option<Self> {
+		let s = resp.split_once("\x1bP>|")?.1;
+		let s = s.split_once('\x1b').map_or(s, |(s, _)| s);
+		Self::parse(s.split_once([' ', '(']).map_or("", |(_, v)| v))
+	}
+
+	/// From `TERM_PROGRAM_VERSION`, which inside tmux is the version of tmux
+	/// rather than the terminal, so it's not used there.
+	pub fn from_env() -> Option<Self> {
+		if TMUX.get() {
+			return None;
+		}
+		Self::parse(&std::env::var("TERM_PROGRAM_VERSION").ok()?)
+	}
+}
+
+impl Display for Version {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}.{}.{}", self.0, self.1, self.2)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_from_csi() {
+		let v = |s: &str| Version::from_csi(&format!("\x1b[?62c\x1bP>|{s}\x1b\\"));
+
+		assert_eq!(v("kitty(0.35.2)"), Some(Version(0, 35, 2)));
+		assert_eq!(v("iTerm2 3.5.0beta1"), Some(Version(3, 5, 0)));
+		assert_eq!(v("WezTerm 20240203-110809-5046fc22"), Some(Version(20240203, 110809, 5046)));
+		assert_eq!(v("tmux 3.4"), Some(Version(3, 4, 0)));
+		assert_eq!(v("Bobcat"), None);
+		assert_eq!(Version::from_csi("\x1b[?62c"), None);
+	}
+}
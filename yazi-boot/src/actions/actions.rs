@@ -9,6 +9,14 @@ impl Actions {
 			process::exit(0);
 		}
 
+		if args.debug_graphics {
+			if let Err(e) = Self::debug_graphics() {
+				eprintln!("Failed to diagnose graphics: {e}");
+				process::exit(1);
+			}
+			process::exit(0);
+		}
+
 		if args.version {
 			println!("Yazi {}", Self::version());
 			process::exit(0);
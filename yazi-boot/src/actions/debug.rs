@@ -56,21 +56,22 @@ impl Actions {
 		writeln!(
 			s,
 			"    default     : {:?}",
-			yazi_config::OPEN.openers("f75a.txt", "text/plain").and_then(|a| a.first().cloned())
+			yazi_config::OPEN.load().openers("f75a.txt", "text/plain").and_then(|a| a.first().cloned())
 		)?;
 		writeln!(
 			s,
 			"    block-create: {:?}",
-			yazi_config::OPEN.block_opener("bulk-create.txt", "text/plain")
+			yazi_config::OPEN.load().block_opener("bulk-create.txt", "text/plain")
 		)?;
 		writeln!(
 			s,
 			"    block-rename: {:?}",
-			yazi_config::OPEN.block_opener("bulk-rename.txt", "text/plain")
+			yazi_config::OPEN.load().block_opener("bulk-rename.txt", "text/plain")
 		)?;
 
 		writeln!(s, "\nMultiplexers")?;
 		writeln!(s, "    TMUX               : {}", yazi_adapter::TMUX.get())?;
+		writeln!(s, "    Nested             : {:?}", yazi_adapter::NESTED.get())?;
 		writeln!(s, "    tmux version       : {}", Self::process_output("tmux", "-V"))?;
 		writeln!(s, "    tmux build flags   : enable-sixel={}", Mux::tmux_sixel_flag())?;
 		writeln!(s, "    ZELLIJ_SESSION_NAME: {:?}", env::var_os("ZELLIJ_SESSION_NAME"))?;
@@ -0,0 +1,79 @@
+use std::{env, fmt::Write};
+
+use yazi_adapter::{ADAPTOR, Adapter, Brand, Dimension, EMULATOR, Emulator, GATES, Mux, NESTED, Probe, TMUX, Version, WSL};
+
+use super::Actions;
+
+impl Actions {
+	pub(super) fn debug_graphics() -> anyhow::Result<()> {
+		let (emulator, resp) = Emulator::detect_verbose()?;
+		let size = Dimension::available();
+
+		let mut s = String::new();
+		writeln!(s, "\nDetection")?;
+		writeln!(s, "    TERM                : {:?}", env::var_os("TERM"))?;
+		writeln!(s, "    TERM_PROGRAM        : {:?}", env::var_os("TERM_PROGRAM"))?;
+		writeln!(s, "    TERM_PROGRAM_VERSION: {:?}", env::var_os("TERM_PROGRAM_VERSION"))?;
+		writeln!(s, "    Response            : {resp:?}")?;
+		writeln!(s, "    Brand.from_csi      : {:?}", Brand::from_csi(&resp))?;
+		writeln!(s, "    Brand.from_env      : {:?}", Brand::from_env())?;
+		writeln!(s, "    Version.from_csi    : {:?}", Version::from_csi(&resp))?;
+		writeln!(s, "    Version.from_env    : {:?}", Version::from_env())?;
+		writeln!(s, "    Emulator.detect     : {emulator:?}")?;
+		writeln!(s, "    Emulator.adapters   : {:?}", emulator.adapters())?;
+
+		writeln!(s, "\nVersion gates")?;
+		for g in GATES {
+			let verdict = match g.passes(emulator.version) {
+				_ if !emulator.kind.is_left_and(|&b| b == g.brand) => "not this terminal",
+				None => "passed, version unknown",
+				Some(true) => "passed",
+				Some(false) => "failed",
+			};
+			let fallback = g.fallback.map_or("none".to_owned(), |a| a.to_string());
+			#[rustfmt::skip]
+			writeln!(s, "    {:<9} {:<7} since {:<12} ({}, else {fallback}): {verdict}", format!("{:?}", g.brand), g.adapter.to_string(), g.since.to_string(), g.reason)?;
+		}
+
+		writeln!(s, "\nDimension")?;
+		writeln!(
+			s,
+			"    Window    : {}x{} cells, {}x{} pixels",
+			size.columns, size.rows, size.width, size.height
+		)?;
+		writeln!(s, "    Cell size : {:?}", EMULATOR.get().cell_size)?;
+		writeln!(s, "    Cell ratio: {:?}", Dimension::ratio())?;
+
+		writeln!(s, "\nMultiplexers")?;
+		writeln!(s, "    TMUX               : {}", TMUX.get())?;
+		writeln!(s, "    tmux build flags   : enable-sixel={}", Mux::tmux_sixel_flag())?;
+		writeln!(s, "    ZELLIJ_SESSION_NAME: {:?}", env::var_os("ZELLIJ_SESSION_NAME"))?;
+		writeln!(s, "    Nested             : {:?}", NESTED.get())?;
+		writeln!(s, "    WSL                : {}", WSL.get())?;
+		writeln!(s, "    SSH                : {}", yazi_shared::in_ssh_connection())?;
+
+		writeln!(s, "\nAdapter")?;
+		writeln!(s, "    Adapter.matches: {:?}", Adapter::matches(emulator))?;
+		writeln!(s, "    In use         : {:?}", ADAPTOR.get())?;
+		println!("{s}");
+
+		let mut adapters = vec![Adapter::Kgp, Adapter::KgpOld, Adapter::Iip, Adapter::Sixel];
+		if matches!(ADAPTOR.get(), Adapter::X11 | Adapter::Wayland) {
+			adapters.push(ADAPTOR.get());
+		}
+		adapters.push(Adapter::Chafa);
+
+		let results = futures::executor::block_on(Probe::run(&adapters))?;
+
+		println!("Protocols");
+		for (i, (adapter, result)) in results.iter().enumerate() {
+			let name = adapter.to_string();
+			match result {
+				Ok(_) => println!("    {}. {name:<7}: drawn", i + 1),
+				Err(e) => println!("    {}. {name:<7}: failed, {e}", i + 1),
+			}
+		}
+		println!("\nThe ones drawn but not visible aren't supported by your terminal.");
+		Ok(())
+	}
+}
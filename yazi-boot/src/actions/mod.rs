@@ -1,3 +1,3 @@
 #![allow(clippy::module_inception)]
 
-yazi_macro::mod_flat!(actions clear_cache debug rustc triple version);
+yazi_macro::mod_flat!(actions clear_cache debug debug_graphics rustc triple version);
@@ -1,20 +1,45 @@
 use std::path::PathBuf;
 
-use clap::{Parser, command};
+use clap::{Parser, ValueEnum, command};
 
 #[derive(Debug, Default, Parser)]
 #[command(name = "yazi")]
 pub struct Args {
-	/// Set the current working entry
-	#[arg(index = 1, num_args = 1..=9)]
-	pub entries: Vec<PathBuf>,
+	/// Set the current working entry, each of them is opened in its own tab
+	#[arg(index = 1, num_args = 1..)]
+	pub entries:    Vec<PathBuf>,
+	/// Set the layout of each tab, separated by commas, or a single one for all
+	#[arg(long, value_delimiter = ',')]
+	pub tab_layout: Vec<String>,
 
 	/// Write the cwd on exit to this file
 	#[arg(long)]
 	pub cwd_file:     Option<PathBuf>,
-	/// Write the selected files to this file on open fired
+	/// Write the selected files to this file on open fired, and exit with 0 if
+	/// anything was chosen, 130 if quit without choosing, or 1 on failure
 	#[arg(long)]
 	pub chooser_file: Option<PathBuf>,
+	/// What to choose in the chooser mode
+	#[arg(long, value_enum, default_value_t, requires = "chooser_file")]
+	pub chooser_mode: ChooserMode,
+	/// Separate the chosen paths with NUL instead of newline
+	#[arg(long, requires = "chooser_file")]
+	pub chooser_null: bool,
+
+	/// Select this file on startup, relative to the first entry, repeat it to
+	/// select more
+	#[arg(long, alias = "chooser-select")]
+	pub select: Vec<PathBuf>,
+	/// Filter the first entry with a glob on startup, e.g. "*.rs"
+	#[arg(long)]
+	pub filter: Option<String>,
+
+	/// Disable all commands that modify the filesystem
+	#[arg(long)]
+	pub read_only:  bool,
+	/// Disallow spawning processes, and stay within the starting directory
+	#[arg(long)]
+	pub restricted: bool,
 
 	/// Clear the cache directory
 	#[arg(long)]
@@ -30,11 +55,29 @@ pub struct Args {
 	#[arg(long)]
 	pub remote_events: Option<String>,
 
+	/// Report the time spent on each startup stage on exit
+	#[arg(long)]
+	pub start_profile: bool,
+
 	/// Print debug information
 	#[arg(long)]
-	pub debug: bool,
+	pub debug:          bool,
+	/// Print the graphics detection transcript, and test each image protocol
+	#[arg(long)]
+	pub debug_graphics: bool,
 
 	/// Print version
 	#[arg(short = 'V', long)]
 	pub version: bool,
 }
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum ChooserMode {
+	/// Choose the selected or hovered files
+	#[default]
+	Files,
+	/// Choose the selected or hovered directories, or the current directory
+	Dir,
+	/// Prompt for a filename to save as in the current directory
+	Save,
+}
@@ -1,12 +1,20 @@
-use std::{collections::HashSet, ffi::OsString, path::PathBuf};
+use std::{collections::HashSet, ffi::OsString, path::{Path, PathBuf}};
 
+use anyhow::{Result, bail};
 use serde::Serialize;
-use yazi_fs::{CWD, Xdg, expand_path};
+use yazi_config::MANAGER;
+use yazi_fs::{CWD, Filter, Xdg, expand_path};
 
 #[derive(Debug, Default, Serialize)]
 pub struct Boot {
 	pub cwds:  Vec<PathBuf>,
 	pub files: Vec<OsString>,
+	pub root:  Option<PathBuf>,
+
+	pub layouts: Vec<Option<String>>,
+
+	pub selected: Vec<PathBuf>,
+	pub filter:   Option<String>,
 
 	pub local_events:  HashSet<String>,
 	pub remote_events: HashSet<String>,
@@ -37,10 +45,39 @@ impl Boot {
 
 		(cwds, files)
 	}
+
+	fn resolve(cwd: &Path, p: &Path) -> PathBuf {
+		if p.is_relative() && !p.starts_with("~") { expand_path(cwd.join(p)) } else { expand_path(p) }
+	}
+
+	fn parse_layouts(names: &[String], len: usize) -> Result<Vec<Option<String>>> {
+		let layouts = names
+			.iter()
+			.map(|s| match s.as_str() {
+				"" | "reset" => Ok(None),
+				s if MANAGER.load().layouts.contains_key(s) => Ok(Some(s.to_owned())),
+				s => bail!("Unknown layout `{s}` in --tab-layout"),
+			})
+			.collect::<Result<Vec<_>>>()?;
+
+		Ok(match layouts.as_slice() {
+			[one] => vec![one.clone(); len],
+			_ => (0..len).map(|i| layouts.get(i).cloned().flatten()).collect(),
+		})
+	}
+
+	fn validate_filter(glob: &str) -> Result<()> {
+		if let Err(e) = Filter::glob(glob) {
+			bail!("Invalid filter {glob:?} in --filter: {e}");
+		}
+		Ok(())
+	}
 }
 
-impl From<&crate::Args> for Boot {
-	fn from(args: &crate::Args) -> Self {
+impl TryFrom<&crate::Args> for Boot {
+	type Error = anyhow::Error;
+
+	fn try_from(args: &crate::Args) -> Result<Self> {
 		let config_dir = Xdg::config_dir();
 		let (cwds, files) = Self::parse_entries(&args.entries);
 
@@ -55,7 +92,17 @@ impl From<&crate::Args> for Boot {
 			.map(|s| s.split(',').map(|s| s.to_owned()).collect())
 			.unwrap_or_default();
 
-		Self {
+		if let Some(glob) = &args.filter {
+			Self::validate_filter(glob)?;
+		}
+
+		Ok(Self {
+			root: args
+				.restricted
+				.then(|| std::fs::canonicalize(&cwds[0]).unwrap_or_else(|_| cwds[0].clone())),
+			layouts: Self::parse_layouts(&args.tab_layout, cwds.len())?,
+			selected: args.select.iter().map(|p| Self::resolve(&cwds[0], p)).collect(),
+			filter: args.filter.clone(),
 			cwds,
 			files,
 
@@ -66,6 +113,6 @@ impl From<&crate::Args> for Boot {
 			plugin_dir: config_dir.join("plugins"),
 			config_dir,
 			state_dir: Xdg::state_dir(),
-		}
+		})
 	}
 }
@@ -8,11 +8,12 @@ use yazi_shared::RoCell;
 pub static ARGS: RoCell<Args> = RoCell::new();
 pub static BOOT: RoCell<Boot> = RoCell::new();
 
-pub fn init() {
+pub fn init() -> anyhow::Result<()> {
 	ARGS.with(<_>::parse);
-	BOOT.init(<_>::from(&*ARGS));
+	BOOT.init(<_>::try_from(&*ARGS)?);
 
 	actions::Actions::act(&ARGS);
+	Ok(())
 }
 
 pub fn init_default() {
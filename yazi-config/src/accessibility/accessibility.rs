@@ -0,0 +1,31 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Accessibility {
+	/// Describe images in text, skip the animations, hide the icons, and announce
+	/// the changes in a dedicated line at the bottom.
+	pub enabled: bool,
+	/// A command each announcement is written to as a line on its stdin, e.g.
+	/// `spd-say -e` for a screen reader.
+	pub bridge:  String,
+}
+
+impl FromStr for Accessibility {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			accessibility: Accessibility,
+		}
+
+		Ok(
+			toml::from_str::<Outer>(s)
+				.context("Failed to parse the [accessibility] section in your yazi.toml")?
+				.accessibility,
+		)
+	}
+}
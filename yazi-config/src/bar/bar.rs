@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use super::{BarInfo, BarSegment};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Bar {
+	#[serde(default)]
+	pub left:      Vec<BarSegment>,
+	#[serde(default)]
+	pub center:    Vec<BarSegment>,
+	#[serde(default)]
+	pub right:     Vec<BarSegment>,
+	#[serde(default)]
+	pub separator: String,
+	/// The fields shown by the `info` segment.
+	#[serde(default)]
+	pub info:      Vec<BarInfo>,
+}
+
+impl Bar {
+	#[inline]
+	pub fn has(&self, name: &str) -> bool {
+		self.left.iter().chain(&self.center).chain(&self.right).any(|s| s.name.as_deref() == Some(name))
+	}
+}
@@ -0,0 +1,20 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use super::Bar;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Bars {
+	pub header: Bar,
+	pub status: Bar,
+}
+
+impl FromStr for Bars {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		toml::from_str(s).context("Failed to parse the [header] or [status] section in your yazi.toml")
+	}
+}
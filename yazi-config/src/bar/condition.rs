@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum BarCondition {
+	// Mode
+	Normal,
+	Select,
+	Unset,
+
+	// State
+	Hovered,
+	Selected,
+	Yanked,
+	Filter,
+	Find,
+	Search,
+	Tasks,
+}
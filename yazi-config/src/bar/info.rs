@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// A field of the `info` segment, about the hovered file.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum BarInfo {
+	Perm,
+	Owner,
+	Nlink,
+	Ino,
+	Target,
+}
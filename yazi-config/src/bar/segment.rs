@@ -0,0 +1,41 @@
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+
+use super::BarCondition;
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(try_from = "Shadow")]
+pub struct BarSegment {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name:   Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub plugin: Option<String>,
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	pub when:   Vec<BarCondition>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Shadow {
+	Name(String),
+	Full {
+		name:   Option<String>,
+		plugin: Option<String>,
+		#[serde(default)]
+		when:   Vec<BarCondition>,
+	},
+}
+
+impl TryFrom<Shadow> for BarSegment {
+	type Error = anyhow::Error;
+
+	fn try_from(value: Shadow) -> Result<Self, Self::Error> {
+		Ok(match value {
+			Shadow::Name(name) => Self { name: Some(name), plugin: None, when: vec![] },
+			Shadow::Full { name: None, plugin: None, .. } => {
+				bail!("a bar segment must have either a `name` or a `plugin`")
+			}
+			Shadow::Full { name, plugin, when } => Self { name, plugin, when },
+		})
+	}
+}
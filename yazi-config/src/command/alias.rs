@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Deserializer};
+use yazi_shared::event::Cmd;
+
+use crate::{Diagnostic, keymap::Chord};
+
+/// A named command made of built-in ones, which can be run in place of any of
+/// them, e.g. from the keymap with `run = "<name>"`.
+#[derive(Debug)]
+pub struct Alias {
+	pub name:  String,
+	pub run:   Vec<AliasStep>,
+	pub desc:  Option<String>,
+	/// How it's listed in the help menu, which has no keys to trigger it.
+	pub chord: Arc<Chord>,
+}
+
+#[derive(Debug)]
+pub struct AliasStep {
+	pub run:  Arc<Cmd>,
+	/// Contexts it only runs in, like the `when` of the chords in keymap.toml.
+	pub when: Vec<String>,
+}
+
+impl Alias {
+	/// The 1-based line it's defined at in `toml`, i.e. the yazi.toml.
+	pub fn locate(&self, toml: &str) -> Option<usize> {
+		Diagnostic::line_of(toml, "command", "name", |v| v.as_str() == Some(&self.name))
+	}
+}
+
+impl AliasStep {
+	/// Whether it runs, given whether each of the contexts holds.
+	pub fn active(&self, f: impl Fn(&str) -> bool) -> bool {
+		self.when.iter().all(|w| match w.strip_prefix('!') {
+			Some(w) => !f(w),
+			None => f(w),
+		})
+	}
+}
+
+impl<'de> Deserialize<'de> for Alias {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		#[derive(Deserialize)]
+		struct Shadow {
+			name: String,
+			run:  Steps,
+			desc: Option<String>,
+		}
+		#[derive(Deserialize)]
+		#[serde(untagged)]
+		enum Steps {
+			One(Step),
+			Many(Vec<Step>),
+		}
+		#[derive(Deserialize)]
+		#[serde(untagged)]
+		enum Step {
+			Plain(Cmd),
+			Conditional {
+				run:  Cmd,
+				#[serde(default, deserialize_with = "crate::keymap::deserialize_when")]
+				when: Vec<String>,
+			},
+		}
+
+		let shadow = Shadow::deserialize(deserializer)?;
+		let steps = match shadow.run {
+			Steps::One(s) => vec![s],
+			Steps::Many(v) => v,
+		};
+
+		let chord = Arc::new(Chord {
+			run: vec![Arc::new(Cmd::new(&shadow.name))],
+			desc: shadow.desc.clone(),
+			..Default::default()
+		});
+		Ok(Self {
+			name: shadow.name,
+			run: steps
+				.into_iter()
+				.map(|s| match s {
+					Step::Plain(run) => AliasStep { run: Arc::new(run), when: vec![] },
+					Step::Conditional { run, when } => AliasStep { run: Arc::new(run), when },
+				})
+				.collect(),
+			desc: shadow.desc,
+			chord,
+		})
+	}
+}
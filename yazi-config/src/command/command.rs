@@ -0,0 +1,94 @@
+use std::{collections::HashSet, str::FromStr};
+
+use anyhow::{Context, bail};
+use serde::Deserialize;
+
+use super::Alias;
+
+#[derive(Debug, Deserialize)]
+pub struct Command {
+	pub aliases: Vec<Alias>,
+}
+
+impl Command {
+	#[inline]
+	pub fn get(&self, name: &str) -> Option<&Alias> { self.aliases.iter().find(|a| a.name == name) }
+
+	/// The path of names back to `name`, if it ends up calling itself.
+	fn cycle<'a>(&'a self, name: &'a str, path: &mut Vec<&'a str>) -> Option<String> {
+		if path.contains(&name) {
+			path.push(name);
+			return Some(path.join(" -> "));
+		}
+
+		let alias = self.get(name)?;
+		path.push(name);
+		for step in &alias.run {
+			if let Some(s) = self.cycle(&step.run.name, path) {
+				return Some(s);
+			}
+		}
+		path.pop();
+		None
+	}
+}
+
+impl FromStr for Command {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			command: Command,
+		}
+
+		let outer = toml::from_str::<Outer>(s)
+			.context("Failed to parse the [command] section in your yazi.toml")?;
+
+		let mut seen = HashSet::new();
+		for alias in &outer.command.aliases {
+			if alias.name.is_empty() || alias.name.contains(char::is_whitespace) {
+				bail!("Command alias names must be non-empty and cannot contain spaces: {:?}", alias.name);
+			} else if !seen.insert(&alias.name) {
+				bail!("Duplicate command alias: {}", alias.name);
+			} else if let Some(path) = outer.command.cycle(&alias.name, &mut vec![]) {
+				bail!("Command alias `{}` calls itself: {path}", alias.name);
+			}
+		}
+
+		Ok(outer.command)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_aliases() {
+		let command = Command::from_str(
+			r#"
+			[command]
+			aliases = [
+				{ name = "a", run = [ "escape --select", { run = "open", when = "!filter" } ], desc = "A" },
+				{ name = "b", run = "a" },
+			]
+			"#,
+		)
+		.unwrap();
+
+		let a = command.get("a").unwrap();
+		assert_eq!(a.run.len(), 2);
+		assert!(a.run[0].active(|_| false));
+		assert!(!a.run[1].active(|w| w == "filter"));
+		assert_eq!(command.get("b").unwrap().run[0].run.name, "a");
+
+		assert!(Command::from_str("[command]\naliases = [ { name = \"a\", run = \"a\" } ]").is_err());
+		assert!(
+			Command::from_str(
+				"[command]\naliases = [ { name = \"a\", run = \"b\" }, { name = \"b\", run = [ \"c\", \"a\" ] } ]"
+			)
+			.is_err()
+		);
+	}
+}
@@ -0,0 +1,257 @@
+use std::path::Path;
+
+use anyhow::anyhow;
+use toml_edit::{ImDocument, Item, Table, TableLike, Value};
+
+/// Points a schema error found in the merged config back to the user's file,
+/// by the path of keys and indices it's at in the merged one, where the same
+/// path leads to it in the user's file, since a value the user sets replaces
+/// the preset one as a whole.
+pub(crate) struct Diagnostic;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Seg<'a> {
+	Key(&'a str),
+	Index(usize),
+}
+
+#[derive(Clone, Copy)]
+enum Node<'a> {
+	Item(&'a Item),
+	Table(&'a Table),
+	Value(&'a Value),
+}
+
+impl Diagnostic {
+	pub(crate) fn locate(e: anyhow::Error, merged: &str, user: &Path) -> anyhow::Error {
+		let Some(de) = e.downcast_ref::<toml::de::Error>() else { return e };
+		let Some(span) = de.span() else { return e };
+		let Ok(s) = std::fs::read_to_string(user) else { return e };
+		let Some(at) = Self::relocate(merged, &s, span.start) else { return e };
+
+		Self::pointed(format!("{e}: {}", de.message()), &s, at, user)
+	}
+
+	/// Fails on the first key in the `user` file that isn't in the `preset`, down
+	/// to the keys of each section, except in the `free` ones, whose keys are
+	/// named by the user, where a `prepend_*` or `append_*` key is known if the
+	/// key it prepends or appends to is.
+	pub(crate) fn unknown(user: &Path, preset: &str, free: &[&str]) -> anyhow::Result<()> {
+		let Ok(s) = std::fs::read_to_string(user) else { return Ok(()) };
+		let (Ok(doc), Ok(preset)) = (ImDocument::parse(s.as_str()), preset.parse::<toml::Table>())
+		else {
+			return Ok(());
+		};
+
+		let known = |t: &toml::Table, k: &str| {
+			t.contains_key(k)
+				|| [k.strip_prefix("prepend_"), k.strip_prefix("append_")]
+					.into_iter()
+					.flatten()
+					.any(|k| t.get(k).is_some_and(|v| v.is_array()))
+		};
+
+		let unknown = doc.iter().find_map(|(section, item)| {
+			let Some(fields) = preset.get(section) else { return Some((section.to_owned(), section)) };
+			let (Some(fields), Some(t)) = (fields.as_table(), item.as_table_like()) else { return None };
+			if free.contains(&section) {
+				return None;
+			}
+			t.iter().find(|&(k, _)| !known(fields, k)).map(|(k, _)| (format!("{section}.{k}"), section))
+		});
+
+		let Some((name, section)) = unknown else { return Ok(()) };
+		let key = name.rsplit('.').next().unwrap_or(&name);
+		let at = match doc.as_table().get(section).and_then(|i| i.as_table_like()) {
+			Some(t) if key != section => t.key(key).and_then(|k| k.span()),
+			_ => doc.as_table().key(section).and_then(|k| k.span()),
+		};
+
+		let msg = format!("unknown key `{name}`");
+		match at {
+			Some(r) => Err(Self::pointed(msg, &s, r.start, user)),
+			None => Err(anyhow!("{msg} in {}", user.display())),
+		}
+	}
+
+	fn pointed(msg: String, s: &str, at: usize, path: &Path) -> anyhow::Error {
+		let ln = s[..at].matches('\n').count() + 1;
+		let col = s[..at].rfind('\n').map_or(at, |i| at - i - 1) + 1;
+		let line = s.lines().nth(ln - 1).unwrap_or_default();
+		anyhow!("{msg}\n  --> {}:{ln}:{col}\n   |\n   | {line}\n   | {:>col$}", path.display(), "^")
+	}
+
+	/// The offset in `user` of the key or value at `pos` in `merged`.
+	fn relocate(merged: &str, user: &str, pos: usize) -> Option<usize> {
+		let merged = ImDocument::parse(merged).ok()?;
+		let mut path = vec![];
+		let key = Self::path_in_table(merged.as_table(), pos, &mut path)?;
+
+		let user = ImDocument::parse(user).ok()?;
+		Self::span_of(Node::Table(user.as_table()), &path, key)
+	}
+
+	/// Pushes the path to the innermost key or value at `pos` in `t`, and returns
+	/// whether it's a key.
+	fn path_in_table<'a>(t: &'a dyn TableLike, pos: usize, path: &mut Vec<Seg<'a>>) -> Option<bool> {
+		for (k, item) in t.iter() {
+			path.push(Seg::Key(k));
+			if t.key(k).and_then(|k| k.span()).is_some_and(|r| r.contains(&pos)) {
+				return Some(true);
+			}
+			let found = match item {
+				Item::None => None,
+				Item::Value(v) => Self::path_in_value(v, pos, path),
+				Item::Table(t) => Self::path_in_table(t, pos, path),
+				Item::ArrayOfTables(a) => a.iter().enumerate().find_map(|(i, t)| {
+					path.push(Seg::Index(i));
+					Self::path_in_table(t, pos, path).or_else(|| path.pop().and(None))
+				}),
+			};
+			if found.is_some() {
+				return found;
+			}
+			path.pop();
+		}
+		None
+	}
+
+	fn path_in_value<'a>(v: &'a Value, pos: usize, path: &mut Vec<Seg<'a>>) -> Option<bool> {
+		if !v.span()?.contains(&pos) {
+			return None;
+		}
+		let found = match v {
+			Value::Array(a) => a.iter().enumerate().find_map(|(i, v)| {
+				path.push(Seg::Index(i));
+				Self::path_in_value(v, pos, path).or_else(|| path.pop().and(None))
+			}),
+			Value::InlineTable(t) => Self::path_in_table(t, pos, path),
+			_ => None,
+		};
+		found.or(Some(false))
+	}
+
+	/// The offset where the key, or the value, at `path` starts.
+	fn span_of(mut node: Node, path: &[Seg], key: bool) -> Option<usize> {
+		for (i, seg) in path.iter().enumerate() {
+			node = match *seg {
+				Seg::Key(k) => {
+					let t = node.as_table_like()?;
+					if key && i + 1 == path.len() {
+						return t.key(k)?.span().map(|r| r.start);
+					}
+					Node::Item(t.get(k)?)
+				}
+				Seg::Index(n) => node.get(n)?,
+			};
+		}
+		node.span().map(|r| r.start)
+	}
+
+	/// The 1-based line of the first `key = value` under the `section` in `s`,
+	/// whose value satisfies `f`.
+	pub(crate) fn line_of(
+		s: &str,
+		section: &str,
+		key: &str,
+		f: impl Fn(&toml::Value) -> bool,
+	) -> Option<usize> {
+		fn find<'a>(node: Node<'a>, key: &str, f: &dyn Fn(&toml::Value) -> bool) -> Option<Node<'a>> {
+			if let Some(t) = node.as_table_like() {
+				let matched = t.get(key).and_then(|v| v.as_value()).is_some_and(|v| {
+					format!("v = {}", v.to_string().trim())
+						.parse::<toml::Table>()
+						.is_ok_and(|t| t.get("v").is_some_and(f))
+				});
+				if matched {
+					return t.get(key).map(Node::Item);
+				}
+				return t.iter().find_map(|(_, item)| find(Node::Item(item), key, f));
+			}
+			(0..).map_while(|i| node.get(i)).find_map(|n| find(n, key, f))
+		}
+
+		let doc = ImDocument::parse(s).ok()?;
+		let at = find(Node::Item(doc.as_table().get(section)?), key, &f)?.span()?.start;
+		Some(s[..at].matches('\n').count() + 1)
+	}
+}
+
+impl<'a> Node<'a> {
+	fn as_table_like(self) -> Option<&'a dyn TableLike> {
+		match self {
+			Self::Item(i) => i.as_table_like(),
+			Self::Table(t) => Some(t),
+			Self::Value(v) => v.as_inline_table().map(|t| t as &dyn TableLike),
+		}
+	}
+
+	fn get(self, n: usize) -> Option<Self> {
+		match self {
+			Self::Item(Item::ArrayOfTables(a)) => a.get(n).map(Self::Table),
+			Self::Item(Item::Value(Value::Array(a))) | Self::Value(Value::Array(a)) => {
+				a.get(n).map(Self::Value)
+			}
+			_ => None,
+		}
+	}
+
+	fn span(self) -> Option<std::ops::Range<usize>> {
+		match self {
+			Self::Item(i) => i.span(),
+			Self::Table(t) => t.span(),
+			Self::Value(v) => v.span(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_relocate() {
+		let merged = "[manager]\nratio = [1, 4, 3]\nsort_by = \"bogus\"\n";
+		let user = "# comment\n[manager]\n  sort_by  = 'bogus'\n";
+		let at = Diagnostic::relocate(merged, user, merged.find("\"bogus").unwrap());
+		assert_eq!(at, user.find("'bogus"));
+
+		let merged = "[mgr]\nkeymap = [{ on = \"x\", run = \"a\" }, { on = \"y\", run = 1 }]";
+		let user = "[[mgr.keymap]]\non = 'x'\nrun = 'a'\n\n[[mgr.keymap]]\non = 'y'\nrun = 1\n";
+		let at = Diagnostic::relocate(merged, user, merged.find("1 }").unwrap());
+		assert_eq!(at, user.rfind('1'));
+
+		// An unknown key
+		let merged = "[manager]\nbogus = 1\n";
+		let user = "[manager]\nshow_hidden = true\nbogus = 1\n";
+		assert_eq!(
+			Diagnostic::relocate(merged, user, merged.find("bogus").unwrap()),
+			user.find("bogus")
+		);
+
+		// A value only in the preset can't be pointed at
+		assert_eq!(Diagnostic::relocate(merged, "", merged.find("bogus").unwrap()), None);
+	}
+
+	#[test]
+	fn test_line_of() {
+		let s = r#"
+[manager]
+keymap = [
+	{ on = "]", run = "a" },
+	{ on = [ "g", "," ], run = "b" }, # comment
+]
+[[input.prepend_keymap]]
+on = "\\"
+"#;
+		let on = |v: &toml::Value, k: &[&str]| match v {
+			toml::Value::String(s) => k == [s.as_str()],
+			toml::Value::Array(a) => a.iter().map(|v| v.as_str()).eq(k.iter().map(|&k| Some(k))),
+			_ => false,
+		};
+		assert_eq!(Diagnostic::line_of(s, "manager", "on", |v| on(v, &["]"])), Some(4));
+		assert_eq!(Diagnostic::line_of(s, "manager", "on", |v| on(v, &["g", ","])), Some(5));
+		assert_eq!(Diagnostic::line_of(s, "input", "on", |v| on(v, &["\\"])), Some(8));
+		assert_eq!(Diagnostic::line_of(s, "input", "on", |v| on(v, &["]"])), None);
+	}
+}
@@ -0,0 +1,134 @@
+use std::{collections::HashMap, path::PathBuf, str::FromStr};
+
+use anyhow::Context;
+use serde::Deserialize;
+use yazi_fs::Xdg;
+
+/// Translations of the built-in UI strings, gettext-style, where each message
+/// is looked up by its English text in the catalogs of the languages in the
+/// chain, and the English itself is the last resort.
+///
+/// A catalog is a `<lang>.toml` file of `"English" = "Translation"` pairs,
+/// found under `locales` of the config directory, or `yazi/locales` of any of
+/// the `$XDG_DATA_DIRS` for the ones shipped with the system.
+#[derive(Debug, Deserialize)]
+pub struct I18n {
+	/// Detected from `LC_ALL`, `LC_MESSAGES` and `LANG` if empty.
+	pub lang:     String,
+	/// Languages to try in order for the messages `lang` doesn't translate.
+	pub fallback: Vec<String>,
+
+	#[serde(skip)]
+	messages: HashMap<String, String>,
+}
+
+impl I18n {
+	/// The translation of `msg`, or itself if there is none.
+	#[inline]
+	pub fn get<'a>(&'a self, msg: &'a str) -> &'a str {
+		self.messages.get(msg).map_or(msg, String::as_str)
+	}
+
+	/// The languages to look up in order, where each is followed by its less
+	/// specific forms, e.g. `zh-Hant-TW`, `zh-Hant` and `zh`.
+	pub fn chain(&self) -> Vec<String> {
+		let lang = if self.lang.is_empty() { Self::detect() } else { Some(self.lang.clone()) };
+
+		let mut chain: Vec<String> = vec![];
+		for mut tag in lang.iter().chain(&self.fallback).map(String::as_str) {
+			loop {
+				if !chain.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+					chain.push(tag.to_owned());
+				}
+				match tag.rsplit_once('-') {
+					Some((s, _)) => tag = s,
+					None => break,
+				}
+			}
+		}
+		chain
+	}
+
+	/// The language of the locale, e.g. `pt-BR` from `pt_BR.UTF-8`.
+	fn detect() -> Option<String> {
+		let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+			.into_iter()
+			.find_map(|k| std::env::var(k).ok().filter(|v| !v.is_empty()))?;
+
+		match locale.split(['.', '@']).next()? {
+			"" | "C" | "POSIX" => None,
+			s => Some(s.replace('_', "-")),
+		}
+	}
+
+	fn dirs() -> Vec<PathBuf> {
+		let mut dirs = vec![Xdg::config_dir().join("locales")];
+		if cfg!(unix) {
+			let data = std::env::var("XDG_DATA_DIRS").ok().filter(|s| !s.is_empty());
+			dirs.extend(
+				data
+					.as_deref()
+					.unwrap_or("/usr/local/share:/usr/share")
+					.split(':')
+					.map(|s| PathBuf::from(s).join("yazi/locales")),
+			);
+		}
+		dirs
+	}
+
+	/// Merge the catalogs of the chain, where the earlier languages and the
+	/// user's own catalogs take precedence.
+	fn load(&mut self) -> anyhow::Result<()> {
+		let dirs = Self::dirs();
+		for lang in self.chain() {
+			// English is what the messages are written in
+			if lang.eq_ignore_ascii_case("en") {
+				break;
+			}
+
+			for path in dirs.iter().map(|d| d.join(format!("{lang}.toml"))) {
+				let Ok(s) = std::fs::read_to_string(&path) else { continue };
+				let catalog: HashMap<String, String> = toml::from_str(&s)
+					.with_context(|| format!("Failed to parse the translations in {}", path.display()))?;
+
+				for (msg, translation) in catalog {
+					self.messages.entry(msg).or_insert(translation);
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+impl FromStr for I18n {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			i18n: I18n,
+		}
+
+		let mut i18n = toml::from_str::<Outer>(s)
+			.context("Failed to parse the [i18n] section in your yazi.toml")?
+			.i18n;
+
+		i18n.load()?;
+		Ok(i18n)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_chain() {
+		let i18n = I18n {
+			lang:     "zh-Hant-TW".to_owned(),
+			fallback: vec!["zh-Hans".to_owned(), "en".to_owned()],
+			messages: Default::default(),
+		};
+		assert_eq!(i18n.chain(), ["zh-Hant-TW", "zh-Hant", "zh", "zh-Hans", "en"]);
+	}
+}
@@ -0,0 +1,74 @@
+use std::{path::{Path, PathBuf}, str::FromStr};
+
+use anyhow::Context;
+use serde::Deserialize;
+use yazi_fs::expand_path;
+
+#[derive(Debug, Deserialize)]
+pub struct Indexer {
+	pub enabled:  bool,
+	pub roots:    Vec<PathBuf>,
+	/// Names of the entries to skip, along with everything under them.
+	pub exclude:  Vec<String>,
+	pub hidden:   bool,
+	/// Seconds between two full rescans of the roots, `0` to only scan on
+	/// startup.
+	pub interval: u64,
+}
+
+impl Indexer {
+	/// Whether `path` is under one of the roots and not excluded.
+	pub fn covers(&self, path: &Path) -> bool {
+		let Some(root) = self.roots.iter().find(|&r| path.starts_with(r)) else {
+			return false;
+		};
+
+		path.strip_prefix(root).unwrap().iter().all(|name| {
+			!self.exclude.iter().any(|e| name == e.as_str())
+				&& (self.hidden || !name.as_encoded_bytes().starts_with(b"."))
+		})
+	}
+}
+
+impl FromStr for Indexer {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			indexer: Indexer,
+		}
+
+		let mut indexer = toml::from_str::<Outer>(s)
+			.context("Failed to parse the [indexer] section in your yazi.toml")?
+			.indexer;
+
+		indexer.roots = indexer.roots.iter().map(expand_path).collect();
+		Ok(indexer)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_covers() {
+		let indexer = Indexer::from_str(
+			r#"
+			[indexer]
+			enabled  = true
+			roots    = [ "/root/code" ]
+			exclude  = [ "target" ]
+			hidden   = false
+			interval = 0
+			"#,
+		)
+		.unwrap();
+
+		assert!(indexer.covers(Path::new("/root/code/yazi/Cargo.toml")));
+		assert!(!indexer.covers(Path::new("/root/other")));
+		assert!(!indexer.covers(Path::new("/root/code/yazi/target/debug")));
+		assert!(!indexer.covers(Path::new("/root/code/.git/HEAD")));
+	}
+}
@@ -1,10 +1,11 @@
-use std::{borrow::Cow, hash::{Hash, Hasher}, sync::OnceLock};
+use std::{borrow::Cow, hash::{Hash, Hasher}, str::FromStr, sync::{Arc, OnceLock}};
 
 use regex::Regex;
 use serde::Deserialize;
 use yazi_shared::event::Cmd;
 
 use super::Key;
+use crate::Diagnostic;
 
 static RE: OnceLock<Regex> = OnceLock::new();
 
@@ -13,18 +14,25 @@ pub struct Chord {
 	#[serde(deserialize_with = "super::deserialize_on")]
 	pub on:   Vec<Key>,
 	#[serde(deserialize_with = "super::deserialize_run")]
-	pub run:  Vec<Cmd>,
+	pub run:  Vec<Arc<Cmd>>,
 	pub desc: Option<String>,
+	/// Contexts it's only active in, all of which must hold, or must not with a
+	/// leading `!`, e.g. `["git", "!filter"]`.
+	#[serde(default, deserialize_with = "super::deserialize_when")]
+	pub when: Vec<String>,
 }
 
 impl PartialEq for Chord {
-	fn eq(&self, other: &Self) -> bool { self.on == other.on }
+	fn eq(&self, other: &Self) -> bool { self.on == other.on && self.when == other.when }
 }
 
 impl Eq for Chord {}
 
 impl Hash for Chord {
-	fn hash<H: Hasher>(&self, state: &mut H) { self.on.hash(state) }
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.on.hash(state);
+		self.when.hash(state);
+	}
 }
 
 impl Chord {
@@ -42,15 +50,69 @@ impl Chord {
 
 	pub fn desc_or_run(&self) -> Cow<str> { self.desc().unwrap_or_else(|| self.run().into()) }
 
+	/// Whether it's active, given whether each of the contexts holds.
+	pub fn active(&self, f: impl Fn(&str) -> bool) -> bool {
+		self.when.iter().all(|w| match w.strip_prefix('!') {
+			Some(w) => !f(w),
+			None => f(w),
+		})
+	}
+
 	#[inline]
 	pub fn noop(&self) -> bool {
 		self.run.len() == 1 && self.run[0].name == "noop" && self.run[0].args.is_empty()
 	}
 
-	pub fn contains(&self, s: &str) -> bool {
+	/// How well `s` fuzzily matches its keys, commands or description, lower is
+	/// better, where substrings come first by position, then subsequences by the
+	/// gaps between their chars.
+	pub fn score(&self, s: &str) -> Option<(bool, usize)> {
 		let s = s.to_lowercase();
-		self.desc().map(|d| d.to_lowercase().contains(&s)) == Some(true)
-			|| self.run().to_lowercase().contains(&s)
-			|| self.on().to_lowercase().contains(&s)
+		[self.on(), self.run(), self.desc().unwrap_or_default().into_owned()]
+			.iter()
+			.filter_map(|h| fuzzy(&h.to_lowercase(), &s))
+			.min()
+	}
+
+	/// The 1-based line it's defined at in `toml`, under the `section` of it.
+	pub fn locate(&self, toml: &str, section: &str) -> Option<usize> {
+		Diagnostic::line_of(toml, section, "on", |v| {
+			let keys: Vec<_> = match v {
+				toml::Value::String(s) => vec![s.as_str()],
+				toml::Value::Array(a) => a.iter().filter_map(|v| v.as_str()).collect(),
+				_ => return false,
+			};
+			keys.len() == self.on.len()
+				&& keys.iter().zip(&self.on).all(|(k, on)| Key::from_str(k).is_ok_and(|k| k == *on))
+		})
+	}
+}
+
+fn fuzzy(haystack: &str, needle: &str) -> Option<(bool, usize)> {
+	if let Some(i) = haystack.find(needle) {
+		return Some((false, i));
+	}
+
+	let (mut it, mut gaps) = (haystack.chars(), 0);
+	for c in needle.chars() {
+		gaps += it.by_ref().position(|h| h == c)?;
+	}
+	Some((true, gaps))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_score() {
+		let chord = Chord {
+			on: vec![Key::from_str("g").unwrap()],
+			desc: Some("Go home".to_owned()),
+			..Default::default()
+		};
+		assert_eq!(chord.score("HOME"), Some((false, 3)));
+		assert_eq!(chord.score("gme"), Some((true, 4)));
+		assert_eq!(chord.score("x"), None);
 	}
 }
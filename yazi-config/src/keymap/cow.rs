@@ -1,4 +1,4 @@
-use std::ops::Deref;
+use std::{ops::Deref, sync::Arc};
 
 use yazi_shared::event::CmdCow;
 
@@ -7,15 +7,15 @@ use super::Chord;
 #[derive(Debug)]
 pub enum ChordCow {
 	Owned(Chord),
-	Borrowed(&'static Chord),
+	Shared(Arc<Chord>),
 }
 
 impl From<Chord> for ChordCow {
 	fn from(c: Chord) -> Self { Self::Owned(c) }
 }
 
-impl From<&'static Chord> for ChordCow {
-	fn from(c: &'static Chord) -> Self { Self::Borrowed(c) }
+impl From<Arc<Chord>> for ChordCow {
+	fn from(c: Arc<Chord>) -> Self { Self::Shared(c) }
 }
 
 impl Deref for ChordCow {
@@ -24,7 +24,7 @@ impl Deref for ChordCow {
 	fn deref(&self) -> &Self::Target {
 		match self {
 			Self::Owned(c) => c,
-			Self::Borrowed(c) => c,
+			Self::Shared(c) => c,
 		}
 	}
 }
@@ -36,8 +36,13 @@ impl Default for ChordCow {
 impl ChordCow {
 	pub fn into_seq(self) -> Vec<CmdCow> {
 		match self {
-			Self::Owned(c) => c.run.into_iter().rev().map(|c| c.into()).collect(),
-			Self::Borrowed(c) => c.run.iter().rev().map(|c| c.into()).collect(),
+			Self::Owned(c) => c
+				.run
+				.into_iter()
+				.rev()
+				.map(|c| Arc::try_unwrap(c).map_or_else(CmdCow::Shared, CmdCow::Owned))
+				.collect(),
+			Self::Shared(c) => c.run.iter().rev().map(|c| c.clone().into()).collect(),
 		}
 	}
 }
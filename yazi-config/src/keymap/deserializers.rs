@@ -1,4 +1,4 @@
-use std::{fmt, str::FromStr};
+use std::{fmt, str::FromStr, sync::Arc};
 
 use anyhow::Result;
 use serde::{Deserializer, de::{self, Visitor}};
@@ -44,14 +44,14 @@ where
 	deserializer.deserialize_any(OnVisitor)
 }
 
-pub(super) fn deserialize_run<'de, D>(deserializer: D) -> Result<Vec<Cmd>, D::Error>
+pub(super) fn deserialize_run<'de, D>(deserializer: D) -> Result<Vec<Arc<Cmd>>, D::Error>
 where
 	D: Deserializer<'de>,
 {
 	struct RunVisitor;
 
 	impl<'de> Visitor<'de> for RunVisitor {
-		type Value = Vec<Cmd>;
+		type Value = Vec<Arc<Cmd>>;
 
 		fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
 			formatter.write_str("a `run` string or array of strings within keymap.toml")
@@ -63,7 +63,7 @@ where
 		{
 			let mut cmds = vec![];
 			while let Some(value) = &seq.next_element::<String>()? {
-				cmds.push(Cmd::from_str(value).map_err(de::Error::custom)?);
+				cmds.push(Arc::new(Cmd::from_str(value).map_err(de::Error::custom)?));
 			}
 			if cmds.is_empty() {
 				return Err(de::Error::custom("`run` within keymap.toml cannot be empty"));
@@ -75,9 +75,47 @@ where
 		where
 			E: de::Error,
 		{
-			Ok(vec![Cmd::from_str(value).map_err(de::Error::custom)?])
+			Ok(vec![Arc::new(Cmd::from_str(value).map_err(de::Error::custom)?)])
 		}
 	}
 
 	deserializer.deserialize_any(RunVisitor)
 }
+
+pub(crate) fn deserialize_when<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	struct WhenVisitor;
+
+	impl<'de> Visitor<'de> for WhenVisitor {
+		type Value = Vec<String>;
+
+		fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+			formatter.write_str("a `when` string or array of strings within keymap.toml")
+		}
+
+		fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+		where
+			A: de::SeqAccess<'de>,
+		{
+			let mut conds = vec![];
+			while let Some(value) = seq.next_element::<String>()? {
+				conds.push(WhenVisitor.visit_str(&value)?.remove(0));
+			}
+			Ok(conds)
+		}
+
+		fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+		where
+			E: de::Error,
+		{
+			match value.trim_start_matches('!') {
+				"" => Err(de::Error::custom("`when` within keymap.toml cannot be empty")),
+				_ => Ok(vec![value.to_owned()]),
+			}
+		}
+	}
+
+	deserializer.deserialize_any(WhenVisitor)
+}
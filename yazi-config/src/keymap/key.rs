@@ -1,22 +1,30 @@
 use std::{fmt::{Display, Write}, str::FromStr};
 
 use anyhow::bail;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Key {
-	pub code:   KeyCode,
-	pub shift:  bool,
-	pub ctrl:   bool,
-	pub alt:    bool,
-	pub super_: bool,
+	pub code:    KeyCode,
+	pub shift:   bool,
+	pub ctrl:    bool,
+	pub alt:     bool,
+	pub super_:  bool,
+	pub hyper:   bool,
+	/// Whether it's released rather than pressed, only reported by the terminals
+	/// with the Kitty keyboard protocol, and only asked for if it's bound.
+	pub release: bool,
 }
 
 impl Key {
 	#[inline]
 	pub fn plain(&self) -> Option<char> {
 		match self.code {
-			KeyCode::Char(c) if !self.ctrl && !self.alt && !self.super_ => Some(c),
+			KeyCode::Char(c)
+				if !self.ctrl && !self.alt && !self.super_ && !self.hyper && !self.release =>
+			{
+				Some(c)
+			}
 			_ => None,
 		}
 	}
@@ -24,7 +32,15 @@ impl Key {
 
 impl Default for Key {
 	fn default() -> Self {
-		Self { code: KeyCode::Null, shift: false, ctrl: false, alt: false, super_: false }
+		Self {
+			code:    KeyCode::Null,
+			shift:   false,
+			ctrl:    false,
+			alt:     false,
+			super_:  false,
+			hyper:   false,
+			release: false,
+		}
 	}
 }
 
@@ -53,6 +69,8 @@ impl From<KeyEvent> for Key {
 			ctrl: value.modifiers.contains(KeyModifiers::CONTROL),
 			alt: value.modifiers.contains(KeyModifiers::ALT),
 			super_: value.modifiers.contains(KeyModifiers::SUPER),
+			hyper: value.modifiers.contains(KeyModifiers::HYPER),
+			release: value.kind == KeyEventKind::Release,
 		}
 	}
 }
@@ -79,6 +97,8 @@ impl FromStr for Key {
 				"c-" => key.ctrl = true,
 				"a-" => key.alt = true,
 				"d-" => key.super_ = true,
+				"h-" => key.hyper = true,
+				"release-" => key.release = true,
 
 				"space" => key.code = KeyCode::Char(' '),
 				"backspace" => key.code = KeyCode::Backspace,
@@ -141,6 +161,12 @@ impl Display for Key {
 		}
 
 		write!(f, "<")?;
+		if self.release {
+			write!(f, "Release-")?;
+		}
+		if self.hyper {
+			write!(f, "H-")?;
+		}
 		if self.super_ {
 			write!(f, "D-")?;
 		}
@@ -201,3 +227,24 @@ impl Display for Key {
 		write!(f, "{}>", code)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_from_str() {
+		let key = Key::from_str("<Release-H-C-i>").unwrap();
+		assert_eq!(key, Key {
+			code: KeyCode::Char('i'),
+			ctrl: true,
+			hyper: true,
+			release: true,
+			..Default::default()
+		});
+		assert_eq!(key.to_string(), "<Release-H-C-i>");
+
+		assert_ne!(Key::from_str("<C-i>").unwrap(), Key::from_str("<Tab>").unwrap());
+		assert_eq!(Key::from_str("<Release-a>").unwrap().plain(), None);
+	}
+}
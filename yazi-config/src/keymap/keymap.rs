@@ -1,4 +1,4 @@
-use std::{collections::HashSet, str::FromStr};
+use std::{collections::HashSet, str::FromStr, sync::Arc};
 
 use anyhow::Context;
 use indexmap::IndexSet;
@@ -10,23 +10,35 @@ use crate::{Preset, keymap::Key};
 
 #[derive(Debug)]
 pub struct Keymap {
-	pub manager:    Vec<Chord>,
-	pub tasks:      Vec<Chord>,
-	pub spot:       Vec<Chord>,
-	pub pick:       Vec<Chord>,
-	pub input:      Vec<Chord>,
-	pub confirm:    Vec<Chord>,
-	pub help:       Vec<Chord>,
-	pub completion: Vec<Chord>,
+	pub manager:    Vec<Arc<Chord>>,
+	pub tasks:      Vec<Arc<Chord>>,
+	pub notify:     Vec<Arc<Chord>>,
+	pub watch:      Vec<Arc<Chord>>,
+	pub output:     Vec<Arc<Chord>>,
+	pub excerpt:    Vec<Arc<Chord>>,
+	pub terminal:   Vec<Arc<Chord>>,
+	pub quicklook:  Vec<Arc<Chord>>,
+	pub spot:       Vec<Arc<Chord>>,
+	pub pick:       Vec<Arc<Chord>>,
+	pub input:      Vec<Arc<Chord>>,
+	pub confirm:    Vec<Arc<Chord>>,
+	pub help:       Vec<Arc<Chord>>,
+	pub completion: Vec<Arc<Chord>>,
 }
 
 impl Keymap {
 	#[inline]
-	pub fn get(&self, layer: Layer) -> &[Chord] {
+	pub fn get(&self, layer: Layer) -> &[Arc<Chord>] {
 		match layer {
 			Layer::App => unreachable!(),
 			Layer::Manager => &self.manager,
 			Layer::Tasks => &self.tasks,
+			Layer::Notify => &self.notify,
+			Layer::Watch => &self.watch,
+			Layer::Output => &self.output,
+			Layer::Excerpt => &self.excerpt,
+			Layer::Terminal => &self.terminal,
+			Layer::Quicklook => &self.quicklook,
 			Layer::Spot => &self.spot,
 			Layer::Pick => &self.pick,
 			Layer::Input => &self.input,
@@ -36,6 +48,30 @@ impl Keymap {
 			Layer::Which => unreachable!(),
 		}
 	}
+
+	/// Whether any key release is bound, which the terminal is only asked to
+	/// report then.
+	pub fn releases(&self) -> bool {
+		[
+			&self.manager,
+			&self.tasks,
+			&self.notify,
+			&self.watch,
+			&self.output,
+			&self.excerpt,
+			&self.terminal,
+			&self.quicklook,
+			&self.spot,
+			&self.pick,
+			&self.input,
+			&self.confirm,
+			&self.help,
+			&self.completion,
+		]
+		.into_iter()
+		.flatten()
+		.any(|c| c.on.iter().any(|k| k.release))
+	}
 }
 
 impl FromStr for Keymap {
@@ -55,6 +91,12 @@ impl<'de> Deserialize<'de> for Keymap {
 		struct Shadow {
 			manager:    Inner,
 			tasks:      Inner,
+			notify:     Inner,
+			watch:      Inner,
+			output:     Inner,
+			excerpt:    Inner,
+			terminal:   Inner,
+			quicklook:  Inner,
 			spot:       Inner,
 			pick:       Inner,
 			input:      Inner,
@@ -71,10 +113,12 @@ impl<'de> Deserialize<'de> for Keymap {
 			append_keymap:  IndexSet<Chord>,
 		}
 
-		fn mix(a: IndexSet<Chord>, b: IndexSet<Chord>, c: IndexSet<Chord>) -> Vec<Chord> {
+		fn mix(a: IndexSet<Chord>, b: IndexSet<Chord>, c: IndexSet<Chord>) -> Vec<Arc<Chord>> {
 			#[inline]
-			fn on(Chord { on, .. }: &Chord) -> [Key; 2] {
-				[on.first().copied().unwrap_or_default(), on.get(1).copied().unwrap_or_default()]
+			fn on(Chord { on, when, .. }: &Chord) -> ([Key; 2], Vec<String>) {
+				let keys =
+					[on.first().copied().unwrap_or_default(), on.get(1).copied().unwrap_or_default()];
+				(keys, when.clone())
 			}
 
 			let a_seen: HashSet<_> = a.iter().map(on).collect();
@@ -86,6 +130,7 @@ impl<'de> Deserialize<'de> for Keymap {
 				c.into_iter().filter(|v| !b_seen.contains(&on(v))),
 			)
 			.filter(|chord| !chord.noop())
+			.map(Arc::new)
 			.collect()
 		}
 
@@ -96,6 +141,18 @@ impl<'de> Deserialize<'de> for Keymap {
 			#[rustfmt::skip]
 			tasks:      mix(shadow.tasks.prepend_keymap, shadow.tasks.keymap, shadow.tasks.append_keymap),
 			#[rustfmt::skip]
+			notify:     mix(shadow.notify.prepend_keymap, shadow.notify.keymap, shadow.notify.append_keymap),
+			#[rustfmt::skip]
+			watch:      mix(shadow.watch.prepend_keymap, shadow.watch.keymap, shadow.watch.append_keymap),
+			#[rustfmt::skip]
+			output:     mix(shadow.output.prepend_keymap, shadow.output.keymap, shadow.output.append_keymap),
+			#[rustfmt::skip]
+			excerpt:    mix(shadow.excerpt.prepend_keymap, shadow.excerpt.keymap, shadow.excerpt.append_keymap),
+			#[rustfmt::skip]
+			terminal:   mix(shadow.terminal.prepend_keymap, shadow.terminal.keymap, shadow.terminal.append_keymap),
+			#[rustfmt::skip]
+			quicklook:  mix(shadow.quicklook.prepend_keymap, shadow.quicklook.keymap, shadow.quicklook.append_keymap),
+			#[rustfmt::skip]
 			spot:       mix(shadow.spot.prepend_keymap, shadow.spot.keymap, shadow.spot.append_keymap),
 			#[rustfmt::skip]
 			pick:       mix(shadow.pick.prepend_keymap, shadow.pick.keymap, shadow.pick.append_keymap),
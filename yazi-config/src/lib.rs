@@ -1,24 +1,40 @@
 #![allow(clippy::module_inception)]
 
-yazi_macro::mod_pub!(keymap manager open plugin popup preview tasks theme which);
+mod macros;
 
-yazi_macro::mod_flat!(layout pattern preset priority);
+yazi_macro::mod_pub!(accessibility bar command i18n indexer keymap manager memory network open plugin popup preview project recent register remote tag tasks template theme trash watcher which);
 
-use std::str::FromStr;
+yazi_macro::mod_flat!(diagnostic layout pattern preset priority);
 
-use yazi_shared::{RoCell, SyncCell};
+use std::str::FromStr;
 
-pub static KEYMAP: RoCell<keymap::Keymap> = RoCell::new();
-pub static MANAGER: RoCell<manager::Manager> = RoCell::new();
-pub static OPEN: RoCell<open::Open> = RoCell::new();
+use yazi_shared::{RoCell, SwapCell, SyncCell};
+
+pub static ACCESSIBILITY: RoCell<accessibility::Accessibility> = RoCell::new();
+pub static BARS: RoCell<bar::Bars> = RoCell::new();
+pub static COMMAND: SwapCell<command::Command> = SwapCell::new();
+pub static I18N: SwapCell<i18n::I18n> = SwapCell::new();
+pub static INDEXER: RoCell<indexer::Indexer> = RoCell::new();
+pub static KEYMAP: SwapCell<keymap::Keymap> = SwapCell::new();
+pub static MANAGER: SwapCell<manager::Manager> = SwapCell::new();
+pub static MEMORY: SwapCell<memory::Memory> = SwapCell::new();
+pub static NETWORK: SwapCell<network::Network> = SwapCell::new();
+pub static OPEN: SwapCell<open::Open> = SwapCell::new();
 pub static PLUGIN: RoCell<plugin::Plugin> = RoCell::new();
-pub static PREVIEW: RoCell<preview::Preview> = RoCell::new();
+pub static PREVIEW: SwapCell<preview::Preview> = SwapCell::new();
+pub static RECENT: SwapCell<recent::Recent> = SwapCell::new();
+pub static REGISTER: SwapCell<register::Register> = SwapCell::new();
+pub static REMOTES: SwapCell<remote::Remotes> = SwapCell::new();
+pub static TAG: RoCell<tag::Tag> = RoCell::new();
 pub static TASKS: RoCell<tasks::Tasks> = RoCell::new();
-pub static THEME: RoCell<theme::Theme> = RoCell::new();
-pub static INPUT: RoCell<popup::Input> = RoCell::new();
-pub static CONFIRM: RoCell<popup::Confirm> = RoCell::new();
-pub static PICK: RoCell<popup::Pick> = RoCell::new();
-pub static WHICH: RoCell<which::Which> = RoCell::new();
+pub static TEMPLATE: RoCell<template::Template> = RoCell::new();
+pub static THEME: SwapCell<theme::Theme> = SwapCell::new();
+pub static TRASH: SwapCell<trash::Trash> = SwapCell::new();
+pub static WATCHER: RoCell<watcher::Watcher> = RoCell::new();
+pub static INPUT: SwapCell<popup::Input> = SwapCell::new();
+pub static CONFIRM: SwapCell<popup::Confirm> = SwapCell::new();
+pub static PICK: SwapCell<popup::Pick> = SwapCell::new();
+pub static WHICH: SwapCell<which::Which> = SwapCell::new();
 
 pub static LAYOUT: SyncCell<Layout> = SyncCell::new(Layout::default());
 
@@ -29,7 +45,7 @@ pub fn init() -> anyhow::Result<()> {
 	}
 
 	// TODO: remove this
-	for c in KEYMAP.manager.iter().flat_map(|c| c.run.iter()) {
+	for c in KEYMAP.load().manager.iter().flat_map(|c| c.run.iter()) {
 		if c.name == "arrow"
 			&& c.first_str().unwrap_or_default().parse::<isize>().is_ok_and(|n| n <= -999 || n >= 999)
 		{
@@ -43,20 +59,85 @@ See #2294 for more details: https://github.com/sxyazi/yazi/pull/2294");
 }
 
 pub fn init_flavor(light: bool) -> anyhow::Result<()> {
-	let mut flavor_toml = Preset::flavor(light, true);
+	let mut flavor_toml = Preset::flavor(light, true, None);
 	if let Err(e) = flavor_toml {
 		wait_for_key(e)?;
-		flavor_toml = Preset::flavor(light, false);
+		flavor_toml = Preset::flavor(light, false, None);
 	}
 
-	let mut theme: theme::Theme = <_>::from_str(&flavor_toml.unwrap())?;
+	THEME.init(load_theme(&flavor_toml.unwrap(), light, None)?);
+	Ok(())
+}
+
+/// Reload the theme from disk, optionally previewing the flavor `name` in place
+/// of the configured one.
+pub fn reload_flavor(light: bool, name: Option<&str>) -> anyhow::Result<()> {
+	THEME.store(load_theme(&Preset::flavor(light, true, name)?, light, name)?);
+	Ok(())
+}
+
+/// Reload `yazi.toml`, `keymap.toml` and `remotes.toml`, where nothing is
+/// applied unless all of them are valid.
+///
+/// The previous configs are dropped once the last of their readers is done
+/// with them, and the ones read only at startup, e.g. `[plugin]` and `[tasks]`,
+/// still need a restart to change.
+pub fn reload() -> anyhow::Result<()> {
+	let p = yazi_fs::Xdg::config_dir();
+	let (yazi_toml, keymap_toml) = (Preset::yazi(&p)?, Preset::keymap(&p)?);
+
+	let yazi = |e| Diagnostic::locate(e, &yazi_toml, &p.join("yazi.toml"));
+	let command: command::Command = yazi_toml.parse().map_err(yazi)?;
+	let i18n: i18n::I18n = yazi_toml.parse().map_err(yazi)?;
+	let keymap: keymap::Keymap =
+		keymap_toml.parse().map_err(|e| Diagnostic::locate(e, &keymap_toml, &p.join("keymap.toml")))?;
+	let manager: manager::Manager = yazi_toml.parse().map_err(yazi)?;
+	let memory: memory::Memory = yazi_toml.parse().map_err(yazi)?;
+	let network: network::Network = yazi_toml.parse().map_err(yazi)?;
+	let open: open::Open = yazi_toml.parse().map_err(yazi)?;
+	let preview: preview::Preview = yazi_toml.parse().map_err(yazi)?;
+	let recent: recent::Recent = yazi_toml.parse().map_err(yazi)?;
+	let register: register::Register = yazi_toml.parse().map_err(yazi)?;
+	let trash: trash::Trash = yazi_toml.parse().map_err(yazi)?;
+	let input: popup::Input = yazi_toml.parse().map_err(yazi)?;
+	let confirm: popup::Confirm = yazi_toml.parse().map_err(yazi)?;
+	let pick: popup::Pick = yazi_toml.parse().map_err(yazi)?;
+	let which: which::Which = yazi_toml.parse().map_err(yazi)?;
+	let remotes = remote::Remotes::load(&p)?;
+
+	Diagnostic::unknown(&p.join("yazi.toml"), yazi_macro::config_preset!("yazi").as_ref(), &[
+		"opener",
+	])?;
+	Diagnostic::unknown(&p.join("keymap.toml"), yazi_macro::config_preset!("keymap").as_ref(), &[])?;
+
+	COMMAND.store(command);
+	I18N.store(i18n);
+	KEYMAP.store(keymap);
+	MANAGER.store(manager);
+	MEMORY.store(memory);
+	NETWORK.store(network);
+	OPEN.store(open);
+	PREVIEW.store(preview);
+	RECENT.store(recent);
+	REGISTER.store(register);
+	REMOTES.store(remotes);
+	TRASH.store(trash);
+	INPUT.store(input);
+	CONFIRM.store(confirm);
+	PICK.store(pick);
+	WHICH.store(which);
+	Ok(())
+}
+
+fn load_theme(flavor_toml: &str, light: bool, name: Option<&str>) -> anyhow::Result<theme::Theme> {
+	let mut theme: theme::Theme = <_>::from_str(flavor_toml)?;
+	theme.flavor = theme.flavor.with(light, name);
 	theme.manager.syntect_theme = theme
 		.flavor
 		.syntect_path(light)
 		.unwrap_or_else(|| yazi_fs::expand_path(&theme.manager.syntect_theme));
 
-	THEME.init(theme);
-	Ok(())
+	Ok(theme)
 }
 
 fn try_init(merge: bool) -> anyhow::Result<()> {
@@ -67,23 +148,52 @@ fn try_init(merge: bool) -> anyhow::Result<()> {
 		(yazi_macro::config_preset!("yazi"), yazi_macro::config_preset!("keymap"))
 	};
 
+	let accessibility = <_>::from_str(&yazi_toml)?;
+	let bars = <_>::from_str(&yazi_toml)?;
+	let command = <_>::from_str(&yazi_toml)?;
+	let i18n = <_>::from_str(&yazi_toml)?;
+	let indexer = <_>::from_str(&yazi_toml)?;
 	let keymap = <_>::from_str(&keymap_toml)?;
 	let manager = <_>::from_str(&yazi_toml)?;
+	let memory = <_>::from_str(&yazi_toml)?;
+	let network = <_>::from_str(&yazi_toml)?;
 	let open = <_>::from_str(&yazi_toml)?;
 	let plugin = <_>::from_str(&yazi_toml)?;
 	let preview = <_>::from_str(&yazi_toml)?;
+	let recent = <_>::from_str(&yazi_toml)?;
+	let register = <_>::from_str(&yazi_toml)?;
+	let remotes =
+		if merge { remote::Remotes::load(&yazi_fs::Xdg::config_dir())? } else { Default::default() };
+	let tag = <_>::from_str(&yazi_toml)?;
 	let tasks = <_>::from_str(&yazi_toml)?;
+	let template = <_>::from_str(&yazi_toml)?;
+	let trash = <_>::from_str(&yazi_toml)?;
+	let watcher = <_>::from_str(&yazi_toml)?;
 	let input = <_>::from_str(&yazi_toml)?;
 	let confirm = <_>::from_str(&yazi_toml)?;
 	let pick = <_>::from_str(&yazi_toml)?;
 	let which = <_>::from_str(&yazi_toml)?;
 
+	ACCESSIBILITY.init(accessibility);
+	BARS.init(bars);
+	COMMAND.init(command);
+	I18N.init(i18n);
+	INDEXER.init(indexer);
 	KEYMAP.init(keymap);
 	MANAGER.init(manager);
+	MEMORY.init(memory);
+	NETWORK.init(network);
 	OPEN.init(open);
 	PLUGIN.init(plugin);
 	PREVIEW.init(preview);
+	RECENT.init(recent);
+	REGISTER.init(register);
+	REMOTES.init(remotes);
+	TAG.init(tag);
 	TASKS.init(tasks);
+	TEMPLATE.init(template);
+	TRASH.init(trash);
+	WATCHER.init(watcher);
 	INPUT.init(input);
 	CONFIRM.init(confirm);
 	PICK.init(pick);
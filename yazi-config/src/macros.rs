@@ -0,0 +1,13 @@
+/// Translate a message by its English text, where each `{name}` in it is
+/// replaced with the value given, e.g. `t!("Unknown layout `{s}`", s = name)`.
+#[macro_export]
+macro_rules! t {
+	($msg:expr) => {
+		$crate::I18N.load().get($msg).to_owned()
+	};
+	($msg:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+		let mut s = $crate::I18N.load().get($msg).to_owned();
+		$(s = s.replace(concat!("{", stringify!($name), "}"), &$value.to_string());)+
+		s
+	}};
+}
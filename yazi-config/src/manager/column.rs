@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use super::LinemodeAlign;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ManagerColumn {
+	pub name:   String,
+	pub title:  Option<String>,
+	/// Fixed width of the column, or `None` to fill the remaining space.
+	pub width:  Option<u16>,
+	#[serde(default)]
+	pub align:  LinemodeAlign,
+	/// A `strftime` format for time columns, passed through to other fields.
+	pub format: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ManagerView {
+	#[default]
+	List,
+	Details,
+	/// The list grouped by the modified date, newest first.
+	Timeline,
+}
+
+impl ManagerView {
+	#[inline]
+	pub fn toggle(self) -> Self {
+		match self {
+			Self::List => Self::Details,
+			Self::Details | Self::Timeline => Self::List,
+		}
+	}
+}
@@ -0,0 +1,157 @@
+use std::str::FromStr;
+
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(try_from = "String")]
+pub struct LinemodeTemplate(pub Vec<LinemodeSegment>);
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case", tag = "type")]
+pub enum LinemodeSegment {
+	Text { text: String },
+	Field { name: String, align: LinemodeAlign, width: Option<usize>, format: Option<String> },
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LinemodeAlign {
+	#[default]
+	Left,
+	Center,
+	Right,
+}
+
+impl TryFrom<String> for LinemodeTemplate {
+	type Error = anyhow::Error;
+
+	fn try_from(value: String) -> Result<Self, Self::Error> { value.parse() }
+}
+
+impl FromStr for LinemodeTemplate {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut segments = vec![];
+		let mut text = String::new();
+
+		let mut chars = s.chars();
+		while let Some(c) = chars.next() {
+			match c {
+				'{' if chars.as_str().starts_with('{') => {
+					chars.next();
+					text.push('{');
+				}
+				'}' if chars.as_str().starts_with('}') => {
+					chars.next();
+					text.push('}');
+				}
+				'{' => {
+					let Some((field, rest)) = chars.as_str().split_once('}') else {
+						bail!("unclosed `{{` in linemode template: {s:?}");
+					};
+					if !text.is_empty() {
+						segments.push(LinemodeSegment::Text { text: std::mem::take(&mut text) });
+					}
+					segments.push(Self::parse_field(field)?);
+					chars = rest.chars();
+				}
+				'}' => bail!("unmatched `}}` in linemode template: {s:?}"),
+				c => text.push(c),
+			}
+		}
+
+		if !text.is_empty() {
+			segments.push(LinemodeSegment::Text { text });
+		}
+		Ok(Self(segments))
+	}
+}
+
+impl LinemodeTemplate {
+	// Parses `name`, `name:spec`, `name:%fmt` or `name:spec:%fmt`,
+	// where `spec` is an optional alignment (`<`, `^` or `>`) followed by a width.
+	fn parse_field(s: &str) -> Result<LinemodeSegment> {
+		let (name, rest) = s.split_once(':').unwrap_or((s, ""));
+		if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+			bail!("invalid field name in linemode template: {name:?}");
+		}
+
+		let (spec, format) = if rest.starts_with('%') {
+			("", Some(rest))
+		} else {
+			match rest.split_once(':') {
+				Some((spec, format)) => (spec, Some(format)),
+				None => (rest, None),
+			}
+		};
+
+		let (align, width) = match spec.chars().next() {
+			Some('<') => (LinemodeAlign::Left, &spec[1..]),
+			Some('^') => (LinemodeAlign::Center, &spec[1..]),
+			Some('>') => (LinemodeAlign::Right, &spec[1..]),
+			_ => (LinemodeAlign::Left, spec),
+		};
+
+		let width = match width {
+			"" => None,
+			w => Some(w.parse().map_err(|_| anyhow::anyhow!("invalid width in linemode field: {s:?}"))?),
+		};
+
+		Ok(LinemodeSegment::Field {
+			name: name.to_owned(),
+			align,
+			width,
+			format: format.filter(|f| !f.is_empty()).map(ToOwned::to_owned),
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn field(
+		name: &str,
+		align: LinemodeAlign,
+		width: Option<usize>,
+		format: Option<&str>,
+	) -> LinemodeSegment {
+		LinemodeSegment::Field {
+			name: name.to_owned(),
+			align,
+			width,
+			format: format.map(ToOwned::to_owned),
+		}
+	}
+
+	fn text(s: &str) -> LinemodeSegment { LinemodeSegment::Text { text: s.to_owned() } }
+
+	#[test]
+	fn test_parse() {
+		let t: LinemodeTemplate = "{size:>8} {mtime:%Y-%m-%d} {owner}".parse().unwrap();
+		assert_eq!(t.0, vec![
+			field("size", LinemodeAlign::Right, Some(8), None),
+			text(" "),
+			field("mtime", LinemodeAlign::Left, None, Some("%Y-%m-%d")),
+			text(" "),
+			field("owner", LinemodeAlign::Left, None, None),
+		]);
+
+		let t: LinemodeTemplate = "[{{{btime:^12:%H:%M}}}]".parse().unwrap();
+		assert_eq!(t.0, vec![
+			text("[{"),
+			field("btime", LinemodeAlign::Center, Some(12), Some("%H:%M")),
+			text("}]"),
+		]);
+	}
+
+	#[test]
+	fn test_parse_invalid() {
+		assert!("{size".parse::<LinemodeTemplate>().is_err());
+		assert!("size}".parse::<LinemodeTemplate>().is_err());
+		assert!("{}".parse::<LinemodeTemplate>().is_err());
+		assert!("{size:>x}".parse::<LinemodeTemplate>().is_err());
+	}
+}
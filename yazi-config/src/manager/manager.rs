@@ -1,15 +1,16 @@
-use std::str::FromStr;
+use std::{collections::HashMap, path::{Component, Path}, str::FromStr, time::Duration};
 
-use anyhow::Context;
+use anyhow::{Context, bail};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
-use yazi_fs::SortBy;
+use yazi_fs::{Conflict, GroupBy, SortBy};
 
-use super::{ManagerRatio, MouseEvents};
+use super::{LinemodeTemplate, ManagerColumn, ManagerPrelist, ManagerRatio, ManagerView, ManagerZen, MouseEvents};
 
 #[derive(Debug, Deserialize, Serialize, Validate)]
 pub struct Manager {
-	pub ratio: ManagerRatio,
+	pub ratio:   ManagerRatio,
+	pub layouts: HashMap<String, ManagerRatio>,
 
 	// Sorting
 	pub sort_by:        SortBy,
@@ -17,15 +18,46 @@ pub struct Manager {
 	pub sort_reverse:   bool,
 	pub sort_dir_first: bool,
 	pub sort_translit:  bool,
+	pub group_by:       GroupBy,
+	pub group_headers:  bool,
 
 	// Display
 	#[validate(length(min = 1, max = 20, message = "must be between 1 and 20 characters"))]
-	pub linemode:     String,
-	pub show_hidden:  bool,
-	pub show_symlink: bool,
-	pub scrolloff:    u8,
-	pub mouse_events: MouseEvents,
-	pub title_format: String,
+	pub linemode:       String,
+	pub linemodes:      HashMap<String, LinemodeTemplate>,
+	pub linemode_cycle: Vec<String>,
+	pub show_hidden:    bool,
+	pub show_symlink:   bool,
+	pub bidi:           bool,
+	pub scrolloff:      u8,
+	pub history_limit:  usize,
+	pub prelist:        ManagerPrelist,
+	pub mouse_events:   MouseEvents,
+	pub scrollbar:      bool,
+	pub title_format:   String,
+	/// The most frames drawn in a second, 0 for no limit.
+	pub max_fps:        u16,
+
+	// Details view
+	pub view:    ManagerView,
+	pub columns: Vec<ManagerColumn>,
+
+	// Zen mode
+	pub zen: Vec<ManagerZen>,
+
+	/// What to do when pasting or creating a file where there's one already.
+	pub conflict: Conflict,
+
+	/// Where `organize_photos` puts a photo under the destination, a strftime
+	/// format of the date it was taken.
+	pub organize_template: String,
+}
+
+impl Manager {
+	#[inline]
+	pub fn frame_interval(&self) -> Option<Duration> {
+		Some(self.max_fps).filter(|&n| n > 0).map(|n| Duration::from_secs(1) / n as u32)
+	}
 }
 
 impl FromStr for Manager {
@@ -41,6 +73,23 @@ impl FromStr for Manager {
 			.context("Failed to parse the [manager] section in your yazi.toml")?;
 		outer.manager.validate()?;
 
+		if outer.manager.columns.iter().filter(|c| c.width.is_none()).count() > 1 {
+			bail!("At most one column in `columns` can omit the `width`");
+		}
+		if let Some(name) = outer.manager.linemodes.keys().find(|k| k.is_empty() || k.len() > 20) {
+			bail!("Linemode name {name:?} must be between 1 and 20 characters");
+		}
+		if let Some(name) = outer.manager.layouts.keys().find(|k| k.is_empty() || k.len() > 20) {
+			bail!("Layout name {name:?} must be between 1 and 20 characters");
+		}
+
+		if !Path::new(&outer.manager.organize_template)
+			.components()
+			.all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+		{
+			bail!("`organize_template` must be a relative path without `..`");
+		}
+
 		Ok(outer.manager)
 	}
 }
@@ -1 +1 @@
-yazi_macro::mod_flat!(manager mouse ratio);
+yazi_macro::mod_flat!(column linemode manager mouse prelist ratio zen);
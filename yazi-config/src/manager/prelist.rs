@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// Where the directories around the hovered one are listed in the background.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ManagerPrelist {
+	All,
+	/// Except on network filesystems, where it'd be slow and costly.
+	Local,
+	None,
+}
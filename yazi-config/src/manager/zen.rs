@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// An element hidden while the zen mode is on.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ManagerZen {
+	Header,
+	Status,
+	Parent,
+	Preview,
+	Borders,
+}
+
+impl TryFrom<&str> for ManagerZen {
+	type Error = serde::de::value::Error;
+
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		Self::deserialize(serde::de::value::StrDeserializer::new(s))
+	}
+}
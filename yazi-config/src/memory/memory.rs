@@ -0,0 +1,40 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Memory {
+	/// The MB that the folders cached by each tab can take, beyond which the
+	/// largest ones are evicted, 0 for no limit.
+	pub folders: u64,
+	/// The MB that the Lua state can take, beyond which its garbage is
+	/// collected right away, 0 for no limit.
+	pub lua:     u64,
+}
+
+impl Memory {
+	#[inline]
+	pub fn folders_bytes(&self) -> Option<usize> {
+		Some((self.folders << 20) as usize).filter(|&n| n > 0)
+	}
+
+	#[inline]
+	pub fn lua_bytes(&self) -> Option<usize> { Some((self.lua << 20) as usize).filter(|&n| n > 0) }
+}
+
+impl FromStr for Memory {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			memory: Memory,
+		}
+
+		let outer = toml::from_str::<Outer>(s)
+			.context("Failed to parse the [memory] section in your yazi.toml")?;
+
+		Ok(outer.memory)
+	}
+}
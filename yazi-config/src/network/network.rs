@@ -0,0 +1,122 @@
+use std::{path::{Path, PathBuf}, str::FromStr, time::Duration};
+
+use anyhow::Context;
+use serde::{Deserialize, Deserializer};
+use validator::Validate;
+use yazi_fs::{expand_path, mounts::PARTITIONS};
+
+/// What's relaxed on network filesystems, where anything that touches the
+/// files is slow and costly.
+#[derive(Debug, Deserialize, Validate)]
+pub struct Network {
+	/// Watch the directories on them for changes.
+	pub watch:   bool,
+	/// Read the files on them to tell their mimetype, rather than only going by
+	/// the extension.
+	pub sniff:   bool,
+	/// How many times longer the previews of files on them may take.
+	#[validate(range(min = 1.0, message = "Cannot be less than 1"))]
+	pub timeout: f64,
+
+	/// How many times a transfer from or to them is retried on transient errors.
+	pub retry:     u8,
+	/// Milliseconds to wait before the first retry, doubled for each one after.
+	pub backoff:   u64,
+	/// Bytes per second shared by all the transfers from or to them, 0 for no
+	/// limit.
+	pub bandwidth: u64,
+
+	/// Overrides for the mounts at, or under, a path.
+	#[validate(nested)]
+	pub rules: Vec<NetworkRule>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct NetworkRule {
+	#[serde(deserialize_with = "deserialize_mount")]
+	pub mount:     PathBuf,
+	/// Whether it's treated as a network filesystem, regardless of what's
+	/// detected.
+	pub remote:    Option<bool>,
+	pub watch:     Option<bool>,
+	pub sniff:     Option<bool>,
+	#[validate(range(min = 1.0, message = "Cannot be less than 1"))]
+	pub timeout:   Option<f64>,
+	/// Bytes per second shared by all the transfers from or to the mount, on
+	/// top of the global limit.
+	pub bandwidth: Option<u64>,
+}
+
+/// How the network filesystem a path is on is treated.
+#[derive(Clone, Debug)]
+pub struct NetworkMount {
+	/// The type of the filesystem, e.g. `nfs`.
+	pub kind:    String,
+	pub watch:   bool,
+	pub sniff:   bool,
+	pub timeout: f64,
+	/// The mount of the rule that applies, and its bandwidth limit.
+	pub limit:   Option<(PathBuf, u64)>,
+}
+
+impl Network {
+	/// The network filesystem `path` is on, or `None` if it's a local one.
+	pub fn at(&self, path: &Path) -> Option<NetworkMount> {
+		let rule = self
+			.rules
+			.iter()
+			.filter(|r| path.starts_with(&r.mount))
+			.max_by_key(|r| r.mount.as_os_str().len());
+
+		let kind = PARTITIONS.read().network(path).map(|s| s.into_owned());
+		let kind = match rule.and_then(|r| r.remote) {
+			Some(false) => None,
+			Some(true) => Some(kind.unwrap_or_else(|| "network".to_owned())),
+			None => kind,
+		}?;
+
+		Some(NetworkMount {
+			kind,
+			watch: rule.and_then(|r| r.watch).unwrap_or(self.watch),
+			sniff: rule.and_then(|r| r.sniff).unwrap_or(self.sniff),
+			timeout: rule.and_then(|r| r.timeout).unwrap_or(self.timeout),
+			limit: rule.and_then(|r| Some((r.mount.clone(), r.bandwidth.filter(|&b| b > 0)?))),
+		})
+	}
+
+	/// The `timeout` of a preview, longer if the file is on a network filesystem.
+	pub fn timeout(&self, path: &Path, timeout: Option<Duration>) -> Option<Duration> {
+		let t = timeout?;
+		Some(self.at(path).map_or(t, |m| t.mul_f64(m.timeout)))
+	}
+
+	/// How long to wait before the `n`-th retry of a transfer, capped at a
+	/// minute.
+	pub fn backoff(&self, n: u8) -> Duration {
+		Duration::from_millis(self.backoff.saturating_mul(1 << n.min(16))).min(Duration::from_secs(60))
+	}
+}
+
+impl FromStr for Network {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			network: Network,
+		}
+
+		let outer = toml::from_str::<Outer>(s)
+			.context("Failed to parse the [network] section in your yazi.toml")?;
+		outer.network.validate()?;
+
+		Ok(outer.network)
+	}
+}
+
+fn deserialize_mount<'de, D>(deserializer: D) -> Result<PathBuf, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	Ok(expand_path(String::deserialize(deserializer)?))
+}
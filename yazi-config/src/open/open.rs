@@ -15,13 +15,28 @@ pub struct Open {
 }
 
 impl Open {
-	pub fn openers<P, M>(&self, path: P, mime: M) -> Option<IndexSet<&Opener>>
+	#[inline]
+	pub fn openers<P, M>(&self, path: P, mime: M) -> Option<IndexSet<Opener>>
+	where
+		P: AsRef<Path>,
+		M: AsRef<str>,
+	{
+		self.match_rules(&self.rules, path, mime)
+	}
+
+	/// Openers of the first rule in `rules` matching the file.
+	pub(crate) fn match_rules<P, M>(
+		&self,
+		rules: &[OpenRule],
+		path: P,
+		mime: M,
+	) -> Option<IndexSet<Opener>>
 	where
 		P: AsRef<Path>,
 		M: AsRef<str>,
 	{
 		let is_dir = mime.as_ref() == MIME_DIR;
-		self.rules.iter().find_map(|rule| {
+		rules.iter().filter(|rule| rule.match_dir(path.as_ref())).find_map(|rule| {
 			if rule.mime.as_ref().is_some_and(|p| p.match_mime(&mime))
 				|| rule.name.as_ref().is_some_and(|p| p.match_path(&path, is_dir))
 			{
@@ -30,6 +45,7 @@ impl Open {
 					.iter()
 					.filter_map(|use_| self.openers.get(use_))
 					.flatten()
+					.cloned()
 					.collect::<IndexSet<_>>();
 
 				if openers.is_empty() { None } else { Some(openers) }
@@ -39,19 +55,25 @@ impl Open {
 		})
 	}
 
+	/// The first opener of the `[opener]` named `name` for this platform.
+	#[inline]
+	pub fn opener(&self, name: &str) -> Option<Opener> {
+		self.openers.get(name).and_then(|o| o.first()).cloned()
+	}
+
 	#[inline]
-	pub fn block_opener<P, M>(&self, path: P, mime: M) -> Option<&Opener>
+	pub fn block_opener<P, M>(&self, path: P, mime: M) -> Option<Opener>
 	where
 		P: AsRef<Path>,
 		M: AsRef<str>,
 	{
-		self.openers(path, mime).and_then(|o| o.into_iter().find(|o| o.block))
+		self.openers(path, mime).and_then(|o| o.into_iter().find(|o| o.is_block()))
 	}
 
-	pub fn common_openers(&self, targets: &[(impl AsRef<Path>, impl AsRef<str>)]) -> Vec<&Opener> {
+	pub fn common_openers(&self, targets: &[(impl AsRef<Path>, impl AsRef<str>)]) -> Vec<Opener> {
 		let grouped: Vec<_> = targets.iter().filter_map(|(p, m)| self.openers(p, m)).collect();
-		let flat: IndexSet<_> = grouped.iter().flatten().copied().collect();
-		flat.into_iter().filter(|&o| grouped.iter().all(|g| g.contains(o))).collect()
+		let flat: IndexSet<_> = grouped.iter().flatten().collect();
+		flat.into_iter().filter(|&o| grouped.iter().all(|g| g.contains(o))).cloned().collect()
 	}
 }
 
@@ -1,13 +1,51 @@
+use std::{collections::BTreeMap, path::{Path, PathBuf}};
+
 use serde::{Deserialize, Deserializer};
+use yazi_fs::{clean_path, expand_path};
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Opener {
-	pub run:    String,
-	pub block:  bool,
-	pub orphan: bool,
-	pub desc:   String,
-	pub for_:   Option<String>,
-	pub spread: bool,
+	pub run:     String,
+	pub mode:    OpenerMode,
+	pub input:   OpenerInput,
+	pub env:     BTreeMap<String, String>,
+	pub cwd:     Option<String>,
+	pub desc:    String,
+	pub for_:    Option<String>,
+	pub spread:  bool,
+	pub resolve: bool,
+}
+
+/// How the process of an opener runs.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum OpenerMode {
+	/// Take over the terminal until it exits.
+	Block,
+	/// Take over the terminal until it exits, keeping what it printed to be read
+	/// afterward.
+	BlockWithOutput,
+	/// Detach from Yazi, so it keeps running after Yazi exits.
+	Orphan,
+	/// Run as a task, discarding its output.
+	Background,
+	/// Run as a task, capturing its output into the task log.
+	#[default]
+	BackgroundWithLog,
+}
+
+/// How the targets are passed to the process of an opener.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum OpenerInput {
+	/// As the arguments, i.e. `$1`, `$2`, ... or `$@`.
+	#[default]
+	Args,
+	/// Written to the stdin, each of them ending with a NUL, as `xargs -0` reads.
+	Stdin,
+	/// Written to a temporary file like [`Self::Stdin`], whose path is passed as
+	/// `$1`.
+	Tempfile,
 }
 
 impl Opener {
@@ -21,6 +59,20 @@ impl Opener {
 		}
 		Some(self)
 	}
+
+	#[inline]
+	pub fn is_block(&self) -> bool { self.mode == OpenerMode::Block }
+
+	/// The working directory to run in, where a relative `cwd` is resolved
+	/// against `base`, the directory the opener is run from.
+	pub fn cwd(&self, base: &Path) -> Option<PathBuf> {
+		let s = self.cwd.as_deref()?;
+		Some(if s.starts_with(['~', '$', '%']) || Path::new(s).is_absolute() {
+			expand_path(s)
+		} else {
+			clean_path(base.join(s))
+		})
+	}
 }
 
 impl<'de> Deserialize<'de> for Opener {
@@ -30,14 +82,23 @@ impl<'de> Deserialize<'de> for Opener {
 	{
 		#[derive(Deserialize)]
 		pub struct Shadow {
-			run:    String,
+			run:     String,
+			mode:    Option<OpenerMode>,
+			#[serde(default)]
+			block:   bool,
 			#[serde(default)]
-			block:  bool,
+			orphan:  bool,
 			#[serde(default)]
-			orphan: bool,
-			desc:   Option<String>,
+			input:   OpenerInput,
+			#[serde(default)]
+			env:     BTreeMap<String, String>,
+			cwd:     Option<String>,
+			desc:    Option<String>,
 			#[serde(rename = "for")]
-			for_:   Option<String>,
+			for_:    Option<String>,
+			#[serde(default)]
+			resolve: bool,
+			multi:   Option<bool>,
 		}
 
 		let shadow = Shadow::deserialize(deserializer)?;
@@ -49,7 +110,38 @@ impl<'de> Deserialize<'de> for Opener {
 
 		let desc = shadow.desc.unwrap_or_else(|| run.split_whitespace().next().unwrap().to_string());
 
-		let spread = run.contains("$@") || run.contains("%*") || run.contains("$*");
-		Ok(Self { run, block: shadow.block, orphan: shadow.orphan, desc, for_: shadow.for_, spread })
+		// `block` and `orphan` are shorthands for the corresponding `mode`
+		let mode = match (shadow.mode, shadow.block, shadow.orphan) {
+			(Some(m), false, false) => m,
+			(None, true, false) => OpenerMode::Block,
+			(None, false, true) => OpenerMode::Orphan,
+			(None, false, false) => OpenerMode::default(),
+			_ => {
+				return Err(serde::de::Error::custom(
+					"only one of `mode`, `block` and `orphan` can be set",
+				));
+			}
+		};
+
+		// Targets piped through stdin or a tempfile are always passed all at once,
+		// unless `multi` says otherwise
+		let spread = shadow.multi.unwrap_or_else(|| {
+			shadow.input != OpenerInput::Args
+				|| run.contains("$@")
+				|| run.contains("%*")
+				|| run.contains("$*")
+		});
+
+		Ok(Self {
+			run,
+			mode,
+			input: shadow.input,
+			env: shadow.env,
+			cwd: shadow.cwd.filter(|s| !s.is_empty()),
+			desc,
+			for_: shadow.for_,
+			spread,
+			resolve: shadow.resolve,
+		})
 	}
 }
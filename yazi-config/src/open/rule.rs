@@ -1,13 +1,14 @@
-use std::fmt;
+use std::{fmt, path::Path};
 
 use serde::{Deserialize, Deserializer, de::{self, Visitor}};
 
 use crate::pattern::Pattern;
 
 #[derive(Debug, Deserialize)]
-pub(super) struct OpenRule {
+pub(crate) struct OpenRule {
 	pub(super) name: Option<Pattern>,
 	pub(super) mime: Option<Pattern>,
+	pub(super) dir:  Option<Pattern>,
 	#[serde(rename = "use")]
 	#[serde(deserialize_with = "OpenRule::deserialize")]
 	pub(super) use_: Vec<String>,
@@ -19,6 +20,12 @@ impl OpenRule {
 
 	#[inline]
 	pub fn any_dir(&self) -> bool { self.name.as_ref().is_some_and(|p| p.any_dir()) }
+
+	#[inline]
+	pub fn match_dir(&self, path: &Path) -> bool {
+		let Some(dir) = &self.dir else { return true };
+		path.parent().is_some_and(|p| dir.match_path(p, false))
+	}
 }
 
 impl OpenRule {
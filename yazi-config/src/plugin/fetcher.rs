@@ -10,12 +10,15 @@ pub struct Fetcher {
 	#[serde(skip)]
 	pub idx: u8,
 
-	pub id:   String,
-	pub name: Option<Pattern>,
-	pub mime: Option<Pattern>,
-	pub run:  Cmd,
+	pub id:      String,
+	pub name:    Option<Pattern>,
+	pub mime:    Option<Pattern>,
+	pub run:     Cmd,
 	#[serde(default)]
-	pub prio: Priority,
+	pub prio:    Priority,
+	/// Confine the processes it spawns, see `Sandbox` of `yazi-plugin`.
+	#[serde(default)]
+	pub sandbox: bool,
 }
 
 impl Fetcher {
@@ -0,0 +1,35 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::PREVIEW;
+
+/// Limits of a single preloader or previewer rule, the ones not set fall back
+/// to those in `[preview]`, so a rule for huge images can be tuned down without
+/// affecting the others.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Validate)]
+pub struct Limits {
+	pub max_width:     Option<u32>,
+	pub max_height:    Option<u32>,
+	#[validate(range(min = 50, max = 90))]
+	pub image_quality: Option<u8>,
+	/// Maximum number of bytes to read for a text preview.
+	pub max_bytes:     Option<u64>,
+	/// Timeout in milliseconds, after which the job is cancelled.
+	pub timeout:       Option<u64>,
+}
+
+impl Limits {
+	#[inline]
+	pub fn max_width(&self) -> u32 { self.max_width.unwrap_or(PREVIEW.load().max_width) }
+
+	#[inline]
+	pub fn max_height(&self) -> u32 { self.max_height.unwrap_or(PREVIEW.load().max_height) }
+
+	#[inline]
+	pub fn image_quality(&self) -> u8 { self.image_quality.unwrap_or(PREVIEW.load().image_quality) }
+
+	#[inline]
+	pub fn timeout(&self) -> Option<Duration> { self.timeout.map(Duration::from_millis) }
+}
@@ -3,6 +3,7 @@ use std::{collections::HashSet, path::Path, str::FromStr};
 use anyhow::Context;
 use serde::{Deserialize, Deserializer};
 use tracing::warn;
+use validator::Validate;
 use yazi_fs::File;
 
 use super::{Fetcher, Preloader, Previewer, Spotter};
@@ -13,6 +14,8 @@ pub struct Plugin {
 	pub spotters:   Vec<Spotter>,
 	pub preloaders: Vec<Preloader>,
 	pub previewers: Vec<Previewer>,
+	/// The plugins allowed to use the secrets in the keyring.
+	pub secrets:    HashSet<String>,
 }
 
 impl Plugin {
@@ -114,6 +117,9 @@ impl<'de> Deserialize<'de> for Plugin {
 			prepend_previewers: Vec<Previewer>,
 			#[serde(default)]
 			append_previewers:  Vec<Previewer>,
+
+			#[serde(default)]
+			secrets: HashSet<String>,
 		}
 
 		let mut shadow = Outer::deserialize(deserializer)?.plugin;
@@ -139,6 +145,16 @@ impl<'de> Deserialize<'de> for Plugin {
 		shadow.previewers =
 			Preset::mix(shadow.prepend_previewers, shadow.previewers, shadow.append_previewers).collect();
 
+		if shadow.previewers.iter().any(|p| p.run.name.is_empty() && p.exec.is_none()) {
+			return Err(serde::de::Error::custom("Each previewer needs either a `run` or an `exec`"));
+		}
+
+		for limits in
+			shadow.preloaders.iter().map(|p| &p.limits).chain(shadow.previewers.iter().map(|p| &p.limits))
+		{
+			limits.validate().map_err(serde::de::Error::custom)?;
+		}
+
 		if shadow.fetchers.len() + shadow.preloaders.len() > MAX_PREWORKERS as usize {
 			panic!("Fetchers and preloaders exceed the limit of {MAX_PREWORKERS}");
 		}
@@ -155,6 +171,7 @@ impl<'de> Deserialize<'de> for Plugin {
 			spotters:   shadow.spotters,
 			preloaders: shadow.preloaders,
 			previewers: shadow.previewers,
+			secrets:    shadow.secrets,
 		})
 	}
 }
@@ -3,6 +3,7 @@ use std::path::Path;
 use serde::Deserialize;
 use yazi_shared::{MIME_DIR, event::Cmd};
 
+use super::Limits;
 use crate::{Pattern, Priority};
 
 #[derive(Debug, Deserialize)]
@@ -17,6 +18,13 @@ pub struct Preloader {
 	pub next: bool,
 	#[serde(default)]
 	pub prio: Priority,
+
+	/// Confine the processes it spawns, see `Sandbox` of `yazi-plugin`.
+	#[serde(default)]
+	pub sandbox: bool,
+
+	#[serde(flatten)]
+	pub limits: Limits,
 }
 
 impl Preloader {
@@ -3,15 +3,42 @@ use std::path::Path;
 use serde::Deserialize;
 use yazi_shared::{MIME_DIR, event::Cmd};
 
+use super::Limits;
 use crate::Pattern;
 
 #[derive(Debug, Deserialize)]
 pub struct Previewer {
-	pub name: Option<Pattern>,
-	pub mime: Option<Pattern>,
-	pub run:  Cmd,
+	pub name:   Option<Pattern>,
+	pub mime:   Option<Pattern>,
 	#[serde(default)]
-	pub sync: bool,
+	pub run:    Cmd,
+	/// A shell command to preview with in place of a `run` plugin, getting the
+	/// file as `$1`, see `PreviewOutput` for what it's expected to produce.
+	pub exec:   Option<String>,
+	#[serde(default)]
+	pub output: PreviewOutput,
+	#[serde(default)]
+	pub sync:   bool,
+
+	/// Confine the processes it spawns, see `Sandbox` of `yazi-plugin`.
+	#[serde(default)]
+	pub sandbox: bool,
+
+	#[serde(flatten)]
+	pub limits: Limits,
+}
+
+/// What the `exec` command of a previewer produces.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum PreviewOutput {
+	/// Text on its stdout, ANSI colors included.
+	#[default]
+	Text,
+	/// An image, written to the path given as `$2`.
+	Image,
+	/// A JSON object on its stdout, shown as a list of its fields.
+	Json,
 }
 
 impl Previewer {
@@ -3,7 +3,8 @@ use std::str::FromStr;
 use anyhow::Context;
 use serde::Deserialize;
 
-use super::{Offset, Origin};
+use super::{Offset, Origin, Policy};
+use crate::Pattern;
 
 #[derive(Deserialize)]
 pub struct Confirm {
@@ -17,6 +18,12 @@ pub struct Confirm {
 	pub delete_origin: Origin,
 	pub delete_offset: Offset,
 
+	// shred
+	pub shred_title:   String,
+	pub shred_content: String,
+	pub shred_origin:  Origin,
+	pub shred_offset:  Offset,
+
 	// overwrite
 	pub overwrite_title:   String,
 	pub overwrite_content: String,
@@ -28,6 +35,70 @@ pub struct Confirm {
 	pub quit_content: String,
 	pub quit_origin:  Origin,
 	pub quit_offset:  Offset,
+
+	// cancel_tab
+	pub cancel_tab_title:   String,
+	pub cancel_tab_content: String,
+	pub cancel_tab_origin:  Origin,
+	pub cancel_tab_offset:  Offset,
+
+	// paste
+	pub paste_title:  String,
+	pub paste_origin: Origin,
+	pub paste_offset: Offset,
+
+	// untrusted
+	pub untrusted_title:   String,
+	pub untrusted_content: String,
+	pub untrusted_origin:  Origin,
+	pub untrusted_offset:  Offset,
+
+	// project
+	pub project_title:   String,
+	pub project_content: String,
+	pub project_origin:  Origin,
+	pub project_offset:  Offset,
+
+	// cleanup
+	pub cleanup_title:  String,
+	pub cleanup_origin: Origin,
+	pub cleanup_offset: Offset,
+
+	// organize
+	pub organize_title:  String,
+	pub organize_origin: Origin,
+	pub organize_offset: Offset,
+
+	// expand
+	pub expand_title:  String,
+	pub expand_origin: Origin,
+	pub expand_offset: Offset,
+
+	// sync
+	pub sync_title:   [String; 2],
+	pub sync_content: String,
+	pub sync_origin:  Origin,
+	pub sync_offset:  Offset,
+
+	// empty_trash
+	pub empty_trash_title:  String,
+	pub empty_trash_origin: Origin,
+	pub empty_trash_offset: Offset,
+
+	// recover
+	pub recover_title:   String,
+	pub recover_content: String,
+	pub recover_origin:  Origin,
+	pub recover_offset:  Offset,
+
+	// policy
+	pub trash_policy:     Policy,
+	pub delete_policy:    Policy,
+	pub overwrite_policy: Policy,
+	pub paste_policy:     Policy,
+	pub expand_policy:    Policy,
+	pub mass_threshold:   usize,
+	pub untrusted:        Vec<Pattern>,
 }
 
 impl FromStr for Confirm {
@@ -48,4 +119,10 @@ impl FromStr for Confirm {
 
 impl Confirm {
 	pub const fn border(&self) -> u16 { 2 }
+
+	/// Whether the file is in one of the untrusted locations, where running
+	/// openers on it needs to be confirmed.
+	pub fn untrusted(&self, path: &std::path::Path) -> bool {
+		self.untrusted.iter().any(|p| p.match_path(path, false) || p.match_path(path, true))
+	}
 }
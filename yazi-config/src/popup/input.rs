@@ -24,6 +24,11 @@ pub struct Input {
 	pub rename_origin: Origin,
 	pub rename_offset: Offset,
 
+	// retarget
+	pub retarget_title:  String,
+	pub retarget_origin: Origin,
+	pub retarget_offset: Offset,
+
 	// filter
 	pub filter_title:  String,
 	pub filter_origin: Origin,
@@ -43,6 +48,21 @@ pub struct Input {
 	pub shell_title:  [String; 2],
 	pub shell_origin: Origin,
 	pub shell_offset: Offset,
+
+	// save
+	pub save_title:  String,
+	pub save_origin: Origin,
+	pub save_offset: Offset,
+
+	// paste_to
+	pub paste_to_title:  String,
+	pub paste_to_origin: Origin,
+	pub paste_to_offset: Offset,
+
+	// count
+	pub count_title:  String,
+	pub count_origin: Origin,
+	pub count_offset: Offset,
 }
 
 impl Input {
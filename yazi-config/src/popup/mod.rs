@@ -1 +1 @@
-yazi_macro::mod_flat!(confirm input offset options origin pick position);
+yazi_macro::mod_flat!(confirm input offset options origin pick policy position);
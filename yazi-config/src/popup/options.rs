@@ -1,8 +1,10 @@
+use std::path::Path;
+
 use ratatui::{text::{Line, Text}, widgets::{Paragraph, Wrap}};
 use yazi_shared::url::Url;
 
 use super::{Offset, Origin, Position};
-use crate::{CONFIRM, INPUT, PICK};
+use crate::{CONFIRM, INPUT, PICK, t};
 
 #[derive(Default)]
 pub struct InputCfg {
@@ -33,8 +35,8 @@ pub struct ConfirmCfg {
 impl InputCfg {
 	pub fn cd() -> Self {
 		Self {
-			title: INPUT.cd_title.to_owned(),
-			position: Position::new(INPUT.cd_origin, INPUT.cd_offset),
+			title: t!(&INPUT.load().cd_title),
+			position: Position::new(INPUT.load().cd_origin, INPUT.load().cd_offset),
 			completion: true,
 			..Default::default()
 		}
@@ -42,24 +44,48 @@ impl InputCfg {
 
 	pub fn create(dir: bool) -> Self {
 		Self {
-			title: INPUT.create_title[dir as usize].to_owned(),
-			position: Position::new(INPUT.create_origin, INPUT.create_offset),
+			title: t!(&INPUT.load().create_title[dir as usize]),
+			position: Position::new(INPUT.load().create_origin, INPUT.load().create_offset),
+			..Default::default()
+		}
+	}
+
+	pub fn template(var: &str) -> Self {
+		Self {
+			title: t!("Template variable `{var}`:", var = var),
+			position: Position::new(INPUT.load().create_origin, INPUT.load().create_offset),
 			..Default::default()
 		}
 	}
 
 	pub fn rename() -> Self {
 		Self {
-			title: INPUT.rename_title.to_owned(),
-			position: Position::new(INPUT.rename_origin, INPUT.rename_offset),
+			title: t!(&INPUT.load().rename_title),
+			position: Position::new(INPUT.load().rename_origin, INPUT.load().rename_offset),
+			..Default::default()
+		}
+	}
+
+	pub fn conflict(name: &str) -> Self {
+		Self {
+			title: t!("`{name}` already exists, paste as:", name = name),
+			position: Position::new(INPUT.load().create_origin, INPUT.load().create_offset),
+			..Default::default()
+		}
+	}
+
+	pub fn retarget() -> Self {
+		Self {
+			title: t!(&INPUT.load().retarget_title),
+			position: Position::new(INPUT.load().retarget_origin, INPUT.load().retarget_offset),
 			..Default::default()
 		}
 	}
 
 	pub fn filter() -> Self {
 		Self {
-			title: INPUT.filter_title.to_owned(),
-			position: Position::new(INPUT.filter_origin, INPUT.filter_offset),
+			title: t!(&INPUT.load().filter_title),
+			position: Position::new(INPUT.load().filter_origin, INPUT.load().filter_offset),
 			realtime: true,
 			..Default::default()
 		}
@@ -67,8 +93,8 @@ impl InputCfg {
 
 	pub fn find(prev: bool) -> Self {
 		Self {
-			title: INPUT.find_title[prev as usize].to_owned(),
-			position: Position::new(INPUT.find_origin, INPUT.find_offset),
+			title: t!(&INPUT.load().find_title[prev as usize]),
+			position: Position::new(INPUT.load().find_origin, INPUT.load().find_offset),
 			realtime: true,
 			..Default::default()
 		}
@@ -76,21 +102,46 @@ impl InputCfg {
 
 	pub fn search(name: &str) -> Self {
 		Self {
-			title: INPUT.search_title.replace("{n}", name),
-			position: Position::new(INPUT.search_origin, INPUT.search_offset),
+			title: t!(&INPUT.load().search_title).replace("{n}", name),
+			position: Position::new(INPUT.load().search_origin, INPUT.load().search_offset),
 			..Default::default()
 		}
 	}
 
 	pub fn shell(block: bool) -> Self {
 		Self {
-			title: INPUT.shell_title[block as usize].to_owned(),
-			position: Position::new(INPUT.shell_origin, INPUT.shell_offset),
+			title: t!(&INPUT.load().shell_title[block as usize]),
+			position: Position::new(INPUT.load().shell_origin, INPUT.load().shell_offset),
 			highlight: true,
 			..Default::default()
 		}
 	}
 
+	pub fn save() -> Self {
+		Self {
+			title: t!(&INPUT.load().save_title),
+			position: Position::new(INPUT.load().save_origin, INPUT.load().save_offset),
+			..Default::default()
+		}
+	}
+
+	pub fn paste_to() -> Self {
+		Self {
+			title: t!(&INPUT.load().paste_to_title),
+			position: Position::new(INPUT.load().paste_to_origin, INPUT.load().paste_to_offset),
+			completion: true,
+			..Default::default()
+		}
+	}
+
+	pub fn count(n: usize) -> Self {
+		Self {
+			title: t!(&INPUT.load().count_title).replace("{n}", &n.to_string()),
+			position: Position::new(INPUT.load().count_origin, INPUT.load().count_offset),
+			..Default::default()
+		}
+	}
+
 	#[inline]
 	pub fn with_value(mut self, value: impl Into<String>) -> Self {
 		self.value = value.into();
@@ -121,8 +172,8 @@ impl ConfirmCfg {
 
 	pub fn trash(urls: &[yazi_shared::url::Url]) -> Self {
 		Self::new(
-			Self::replace_number(&CONFIRM.trash_title, urls.len()),
-			(CONFIRM.trash_origin, CONFIRM.trash_offset),
+			Self::replace_number(&t!(&CONFIRM.load().trash_title), urls.len()),
+			(CONFIRM.load().trash_origin, CONFIRM.load().trash_offset),
 			None,
 			Self::truncate_list(urls.iter(), urls.len(), 100),
 		)
@@ -130,31 +181,148 @@ impl ConfirmCfg {
 
 	pub fn delete(urls: &[yazi_shared::url::Url]) -> Self {
 		Self::new(
-			Self::replace_number(&CONFIRM.delete_title, urls.len()),
-			(CONFIRM.delete_origin, CONFIRM.delete_offset),
+			Self::replace_number(&t!(&CONFIRM.load().delete_title), urls.len()),
+			(CONFIRM.load().delete_origin, CONFIRM.load().delete_offset),
 			None,
 			Self::truncate_list(urls.iter(), urls.len(), 100),
 		)
 	}
 
+	pub fn shred(urls: &[yazi_shared::url::Url]) -> Self {
+		Self::new(
+			Self::replace_number(&t!(&CONFIRM.load().shred_title), urls.len()),
+			(CONFIRM.load().shred_origin, CONFIRM.load().shred_offset),
+			Some(Text::raw(t!(&CONFIRM.load().shred_content))),
+			Self::truncate_list(urls.iter(), urls.len(), 100),
+		)
+	}
+
 	pub fn overwrite(url: &Url) -> Self {
 		Self::new(
-			CONFIRM.overwrite_title.to_owned(),
-			(CONFIRM.overwrite_origin, CONFIRM.overwrite_offset),
-			Some(Text::raw(&CONFIRM.overwrite_content)),
+			t!(&CONFIRM.load().overwrite_title),
+			(CONFIRM.load().overwrite_origin, CONFIRM.load().overwrite_offset),
+			Some(Text::raw(t!(&CONFIRM.load().overwrite_content))),
 			Some(url.to_string().into()),
 		)
 	}
 
+	pub fn overwrite_many(urls: &[Url]) -> Self {
+		Self::new(
+			t!(&CONFIRM.load().overwrite_title),
+			(CONFIRM.load().overwrite_origin, CONFIRM.load().overwrite_offset),
+			Some(Text::raw(t!(&CONFIRM.load().overwrite_content))),
+			Self::truncate_list(urls.iter(), urls.len(), 100),
+		)
+	}
+
+	pub fn paste(urls: &[&Url]) -> Self {
+		Self::new(
+			Self::replace_number(&t!(&CONFIRM.load().paste_title), urls.len()),
+			(CONFIRM.load().paste_origin, CONFIRM.load().paste_offset),
+			None,
+			Self::truncate_list(urls.iter().copied(), urls.len(), 100),
+		)
+	}
+
+	pub fn untrusted(urls: &[&Url]) -> Self {
+		Self::new(
+			Self::replace_number(&t!(&CONFIRM.load().untrusted_title), urls.len()),
+			(CONFIRM.load().untrusted_origin, CONFIRM.load().untrusted_offset),
+			Some(Text::raw(t!(&CONFIRM.load().untrusted_content))),
+			Self::truncate_list(urls.iter().copied(), urls.len(), 100),
+		)
+	}
+
+	pub fn project(path: &Path) -> Self {
+		Self::new(
+			t!(&CONFIRM.load().project_title),
+			(CONFIRM.load().project_origin, CONFIRM.load().project_offset),
+			Some(Text::raw(t!(&CONFIRM.load().project_content))),
+			Some(Text::raw(path.to_string_lossy().into_owned())),
+		)
+	}
+
 	pub fn quit(len: usize, names: Vec<String>) -> Self {
 		Self::new(
-			Self::replace_number(&CONFIRM.quit_title, len),
-			(CONFIRM.quit_origin, CONFIRM.quit_offset),
-			Some(Text::raw(&CONFIRM.quit_content)),
+			Self::replace_number(&t!(&CONFIRM.load().quit_title), len),
+			(CONFIRM.load().quit_origin, CONFIRM.load().quit_offset),
+			Some(Text::raw(t!(&CONFIRM.load().quit_content))),
+			Self::truncate_list(names.into_iter(), len, 10),
+		)
+	}
+
+	pub fn cancel_tab(names: Vec<String>) -> Self {
+		let len = names.len();
+		Self::new(
+			Self::replace_number(&t!(&CONFIRM.load().cancel_tab_title), len),
+			(CONFIRM.load().cancel_tab_origin, CONFIRM.load().cancel_tab_offset),
+			Some(Text::raw(t!(&CONFIRM.load().cancel_tab_content))),
 			Self::truncate_list(names.into_iter(), len, 10),
 		)
 	}
 
+	pub fn cleanup(len: usize, lines: Vec<String>) -> Self {
+		let n = lines.len();
+		Self::new(
+			Self::replace_number(&t!(&CONFIRM.load().cleanup_title), len),
+			(CONFIRM.load().cleanup_origin, CONFIRM.load().cleanup_offset),
+			None,
+			Self::truncate_list(lines.into_iter(), n, 100),
+		)
+	}
+
+	pub fn organize(len: usize, lines: Vec<String>) -> Self {
+		let n = lines.len();
+		Self::new(
+			Self::replace_number(&t!(&CONFIRM.load().organize_title), len),
+			(CONFIRM.load().organize_origin, CONFIRM.load().organize_offset),
+			None,
+			Self::truncate_list(lines.into_iter(), n, 100),
+		)
+	}
+
+	pub fn expand(urls: &[Url]) -> Self {
+		Self::new(
+			Self::replace_number(&t!(&CONFIRM.load().expand_title), urls.len()),
+			(CONFIRM.load().expand_origin, CONFIRM.load().expand_offset),
+			None,
+			Self::truncate_list(urls.iter(), urls.len(), 100),
+		)
+	}
+
+	pub fn sync(from: &Path, to: &Path, lines: Vec<String>, resumed: bool) -> Self {
+		let n = lines.len();
+		let content = t!(&CONFIRM.load().sync_content)
+			.replace("{from}", &from.to_string_lossy())
+			.replace("{to}", &to.to_string_lossy());
+
+		Self::new(
+			Self::replace_number(&t!(&CONFIRM.load().sync_title[resumed as usize]), n),
+			(CONFIRM.load().sync_origin, CONFIRM.load().sync_offset),
+			Some(Text::raw(content)),
+			Self::truncate_list(lines.into_iter(), n, 100),
+		)
+	}
+
+	pub fn empty_trash(n: usize) -> Self {
+		Self::new(
+			Self::replace_number(&t!(&CONFIRM.load().empty_trash_title), n),
+			(CONFIRM.load().empty_trash_origin, CONFIRM.load().empty_trash_offset),
+			None,
+			None,
+		)
+	}
+
+	pub fn recover(lines: Vec<String>) -> Self {
+		let n = lines.len();
+		Self::new(
+			Self::replace_number(&t!(&CONFIRM.load().recover_title), n),
+			(CONFIRM.load().recover_origin, CONFIRM.load().recover_offset),
+			Some(Text::raw(t!(&CONFIRM.load().recover_content))),
+			Self::truncate_list(lines.into_iter(), n, 100),
+		)
+	}
+
 	fn replace_number(tpl: &str, n: usize) -> String {
 		tpl.replace("{n}", &n.to_string()).replace("{s}", if n > 1 { "s" } else { "" })
 	}
@@ -167,7 +335,7 @@ impl ConfirmCfg {
 		let mut lines = Vec::with_capacity(len.min(max + 1));
 		for (i, s) in it.enumerate() {
 			if i >= max {
-				lines.push(format!("... and {} more", len - max));
+				lines.push(t!("... and {n} more", n = len - max));
 				break;
 			}
 			lines.push(s.into());
@@ -179,15 +347,62 @@ impl ConfirmCfg {
 impl PickCfg {
 	#[inline]
 	fn max_height(len: usize) -> u16 {
-		PICK.open_offset.height.min(PICK.border().saturating_add(len as u16))
+		PICK.load().open_offset.height.min(PICK.load().border().saturating_add(len as u16))
 	}
 
 	pub fn open(items: Vec<String>) -> Self {
 		let max_height = Self::max_height(items.len());
 		Self {
-			title: PICK.open_title.to_owned(),
+			title: t!(&PICK.load().open_title),
+			items,
+			position: Position::new(PICK.load().open_origin, Offset {
+				height: max_height,
+				..PICK.load().open_offset
+			}),
+		}
+	}
+
+	pub fn cleanup(items: Vec<String>) -> Self {
+		Self {
+			title: t!(&PICK.load().cleanup_title),
 			items,
-			position: Position::new(PICK.open_origin, Offset { height: max_height, ..PICK.open_offset }),
+			position: Position::new(PICK.load().cleanup_origin, PICK.load().cleanup_offset),
 		}
 	}
+
+	pub fn refs(items: Vec<String>) -> Self {
+		Self {
+			title: ConfirmCfg::replace_number(&t!(&PICK.load().refs_title), items.len()),
+			items,
+			position: Position::new(PICK.load().refs_origin, PICK.load().refs_offset),
+		}
+	}
+
+	pub fn register(items: Vec<String>) -> Self {
+		Self {
+			title: t!(&PICK.load().register_title),
+			items,
+			position: Position::new(PICK.load().register_origin, PICK.load().register_offset),
+		}
+	}
+
+	pub fn paste_to(n: usize, items: Vec<String>) -> Self {
+		Self {
+			title: ConfirmCfg::replace_number(&t!(&PICK.load().paste_to_title), n),
+			items,
+			position: Position::new(PICK.load().paste_to_origin, PICK.load().paste_to_offset),
+		}
+	}
+
+	pub fn remotes(items: Vec<String>) -> Self {
+		Self {
+			title: t!(&PICK.load().remotes_title),
+			items,
+			position: Position::new(PICK.load().remotes_origin, PICK.load().remotes_offset),
+		}
+	}
+
+	pub fn flavor(items: Vec<String>) -> Self {
+		Self { title: t!("Preview flavor:"), ..Self::open(items) }
+	}
 }
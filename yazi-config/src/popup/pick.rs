@@ -11,6 +11,31 @@ pub struct Pick {
 	pub open_title:  String,
 	pub open_origin: Origin,
 	pub open_offset: Offset,
+
+	// cleanup
+	pub cleanup_title:  String,
+	pub cleanup_origin: Origin,
+	pub cleanup_offset: Offset,
+
+	// refs
+	pub refs_title:  String,
+	pub refs_origin: Origin,
+	pub refs_offset: Offset,
+
+	// register
+	pub register_title:  String,
+	pub register_origin: Origin,
+	pub register_offset: Offset,
+
+	// paste_to
+	pub paste_to_title:  String,
+	pub paste_to_origin: Origin,
+	pub paste_to_offset: Offset,
+
+	// remotes
+	pub remotes_title:  String,
+	pub remotes_origin: Origin,
+	pub remotes_offset: Offset,
 }
 
 impl Pick {
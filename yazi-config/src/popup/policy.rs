@@ -0,0 +1,32 @@
+use serde::Deserialize;
+
+use crate::CONFIRM;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Policy {
+	/// Always ask for confirmation.
+	Always,
+	/// Never ask for confirmation.
+	Never,
+	/// Ask only when operating on more than `mass_threshold` files.
+	Mass,
+	/// Always ask, and additionally require typing the number of files
+	/// when operating on more than `mass_threshold` files.
+	Typed,
+}
+
+impl Policy {
+	/// Whether to ask for confirmation when operating on `n` files.
+	pub fn ask(self, n: usize) -> bool {
+		match self {
+			Self::Always | Self::Typed => true,
+			Self::Never => false,
+			Self::Mass => n > CONFIRM.load().mass_threshold,
+		}
+	}
+
+	/// Whether the number of files must be typed to confirm the operation.
+	#[inline]
+	pub fn typed(self, n: usize) -> bool { self == Self::Typed && n > CONFIRM.load().mass_threshold }
+}
@@ -17,14 +17,14 @@ impl Preset {
 		Self::merge_path(p.join("keymap.toml"), yazi_macro::config_preset!("keymap"))
 	}
 
-	pub(crate) fn flavor(light: bool, merge: bool) -> Result<Cow<'static, str>> {
+	pub(crate) fn flavor(light: bool, merge: bool, name: Option<&str>) -> Result<Cow<'static, str>> {
 		let theme = if merge {
 			std::fs::read_to_string(Xdg::config_dir().join("theme.toml")).unwrap_or_default()
 		} else {
 			Default::default()
 		};
 
-		let flavor = Flavor::from_str(&theme)?;
+		let flavor = Flavor::from_str(&theme)?.with(light, name);
 
 		let preset =
 			if light { yazi_macro::theme_preset!("light") } else { yazi_macro::theme_preset!("dark") };
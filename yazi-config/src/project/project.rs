@@ -0,0 +1,96 @@
+use std::{path::{Path, PathBuf}, str::FromStr, sync::Arc};
+
+use anyhow::Context;
+use indexmap::IndexSet;
+use serde::Deserialize;
+use yazi_fs::{GroupBy, SortBy};
+
+use crate::{OPEN, keymap::Chord, open::{OpenRule, Opener}};
+
+/// Overrides for everything under a project, read from the `.yazi/config.toml`
+/// at its root.
+#[derive(Debug)]
+pub struct Project {
+	pub root:    PathBuf,
+	pub manager: ProjectManager,
+	pub keymap:  Vec<Arc<Chord>>,
+	rules:       Vec<OpenRule>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ProjectManager {
+	pub sort_by:        Option<SortBy>,
+	pub sort_sensitive: Option<bool>,
+	pub sort_reverse:   Option<bool>,
+	pub sort_dir_first: Option<bool>,
+	pub sort_translit:  Option<bool>,
+	pub group_by:       Option<GroupBy>,
+	pub linemode:       Option<String>,
+}
+
+impl Project {
+	pub const FILE: &str = ".yazi/config.toml";
+
+	/// The root of the closest project at or above `dir`.
+	pub fn find(dir: &Path) -> Option<PathBuf> {
+		dir.ancestors().find(|p| p.join(Self::FILE).is_file()).map(ToOwned::to_owned)
+	}
+
+	/// The config of the project at `root` as it is, so what's trusted is the
+	/// very content that's loaded.
+	pub fn read(root: &Path) -> std::io::Result<String> {
+		std::fs::read_to_string(root.join(Self::FILE))
+	}
+
+	pub fn load(root: PathBuf, s: &str) -> anyhow::Result<Self> {
+		let mut project: Self = s.parse()?;
+		project.root = root;
+		Ok(project)
+	}
+
+	/// Openers of the project's rules matching the file, before the global ones.
+	#[inline]
+	pub fn openers<P, M>(&self, path: P, mime: M) -> Option<IndexSet<Opener>>
+	where
+		P: AsRef<Path>,
+		M: AsRef<str>,
+	{
+		OPEN.load().match_rules(&self.rules, path, mime)
+	}
+}
+
+impl FromStr for Project {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			#[serde(default)]
+			manager: ProjectManager,
+			#[serde(default)]
+			open:    OuterOpen,
+			#[serde(default)]
+			keymap:  OuterKeymap,
+		}
+		#[derive(Default, Deserialize)]
+		struct OuterOpen {
+			#[serde(default)]
+			rules: Vec<OpenRule>,
+		}
+		#[derive(Default, Deserialize)]
+		struct OuterKeymap {
+			#[serde(default)]
+			manager: Vec<Chord>,
+		}
+
+		let outer = toml::from_str::<Outer>(s)
+			.with_context(|| format!("Failed to parse the project config `{}`", Self::FILE))?;
+
+		Ok(Self {
+			root:    PathBuf::new(),
+			manager: outer.manager,
+			keymap:  outer.keymap.manager.into_iter().map(Arc::new).collect(),
+			rules:   outer.open.rules,
+		})
+	}
+}
@@ -0,0 +1 @@
+yazi_macro::mod_flat!(recent);
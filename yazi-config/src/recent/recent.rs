@@ -0,0 +1,32 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Recent {
+	/// Number of the recently opened files to remember, 0 to not track them.
+	pub max_files: usize,
+	/// Number of the recently visited directories to remember, 0 to not track
+	/// them.
+	pub max_dirs:  usize,
+	/// Share the opened files with other applications through the XDG
+	/// `recently-used.xbel`, and list theirs along with them.
+	pub xbel:      bool,
+}
+
+impl FromStr for Recent {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			recent: Recent,
+		}
+
+		let outer = toml::from_str::<Outer>(s)
+			.context("Failed to parse the [recent] section in your yazi.toml")?;
+
+		Ok(outer.recent)
+	}
+}
@@ -0,0 +1,27 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Register {
+	/// Keep the named yank registers across sessions, rather than only for the
+	/// current one.
+	pub persist: bool,
+}
+
+impl FromStr for Register {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			register: Register,
+		}
+
+		let outer = toml::from_str::<Outer>(s)
+			.context("Failed to parse the [register] section in your yazi.toml")?;
+
+		Ok(outer.register)
+	}
+}
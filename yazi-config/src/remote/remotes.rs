@@ -0,0 +1,69 @@
+use std::{path::{Path, PathBuf}, str::FromStr};
+
+use anyhow::Context;
+use serde::{Deserialize, Deserializer};
+use yazi_fs::{expand_path, mounts::PARTITIONS};
+
+/// The named remotes in `remotes.toml`, each a network filesystem that's
+/// browsed where it's mounted.
+#[derive(Debug, Default, Deserialize)]
+pub struct Remotes {
+	#[serde(default, rename = "remote")]
+	pub list: Vec<Remote>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Remote {
+	pub name:   String,
+	/// What it's served over, e.g. `sftp`, `s3`, `webdav` or `ftp`.
+	#[serde(default)]
+	pub kind:   String,
+	/// The directory it's mounted at.
+	#[serde(deserialize_with = "deserialize_mount")]
+	pub mount:  PathBuf,
+	/// The shell command to mount it when it isn't, e.g. `sshfs nas:/srv ~/nas`.
+	pub run:    Option<String>,
+	/// Pass the secret stored in the keyring for `remote/<name>` to the stdin of
+	/// `run`, e.g. for `sshfs -o password_stdin`.
+	#[serde(default)]
+	pub secret: bool,
+}
+
+impl Remotes {
+	/// Reads `remotes.toml` under `dir`, where a missing one has no remotes.
+	pub fn load(dir: &Path) -> anyhow::Result<Self> {
+		let p = dir.join("remotes.toml");
+		match std::fs::read_to_string(&p) {
+			Ok(s) => s.parse(),
+			Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+			Err(e) => Err(e).with_context(|| format!("Failed to read {}", p.display())),
+		}
+	}
+
+	#[inline]
+	pub fn get(&self, name: &str) -> Option<&Remote> { self.list.iter().find(|r| r.name == name) }
+}
+
+impl FromStr for Remotes {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		toml::from_str(s).context("Failed to parse your remotes.toml")
+	}
+}
+
+impl Remote {
+	/// The type of the filesystem mounted at `mount`, or `None` if nothing is.
+	pub fn mounted(&self) -> Option<String> {
+		let partitions = PARTITIONS.read();
+		let p = partitions.by_path(&self.mount).filter(|p| p.dist.as_ref() == Some(&self.mount))?;
+		Some(p.fstype.as_ref().map_or_else(String::new, |s| s.to_string_lossy().into_owned()))
+	}
+}
+
+fn deserialize_mount<'de, D>(deserializer: D) -> Result<PathBuf, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	Ok(expand_path(String::deserialize(deserializer)?))
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use yazi_shared::theme::Style;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TagLabel {
+	pub name:  String,
+	#[serde(default)]
+	pub style: Style,
+}
@@ -0,0 +1,71 @@
+use std::{collections::HashSet, str::FromStr};
+
+use anyhow::{Context, bail};
+use serde::{Deserialize, Serialize};
+
+use super::TagLabel;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Tag {
+	/// Mirror tags to the `user.xdg.tags` extended attribute of each file.
+	pub xattr:  bool,
+	pub labels: Vec<TagLabel>,
+}
+
+impl Tag {
+	#[inline]
+	pub fn label(&self, name: &str) -> Option<&TagLabel> {
+		self.labels.iter().find(|l| l.name == name)
+	}
+}
+
+impl FromStr for Tag {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			tag: Tag,
+		}
+
+		let outer =
+			toml::from_str::<Outer>(s).context("Failed to parse the [tag] section in your yazi.toml")?;
+
+		let mut seen = HashSet::new();
+		for label in &outer.tag.labels {
+			if label.name.is_empty() || label.name.contains(',') {
+				bail!("Tag label names must be non-empty and cannot contain commas: {:?}", label.name);
+			} else if !seen.insert(&label.name) {
+				bail!("Duplicate tag label: {}", label.name);
+			}
+		}
+
+		Ok(outer.tag)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_labels() {
+		let tag = Tag::from_str(
+			r#"
+			[tag]
+			xattr  = false
+			labels = [ { name = "red", style = { fg = "red" } }, { name = "work" } ]
+			"#,
+		)
+		.unwrap();
+		assert!(tag.label("red").is_some_and(|l| l.style.fg.is_some()));
+		assert!(tag.label("work").is_some_and(|l| l.style.fg.is_none()));
+		assert!(tag.label("blue").is_none());
+
+		assert!(
+			Tag::from_str("[tag]\nxattr = false\nlabels = [ { name = \"a\" }, { name = \"a\" } ]")
+				.is_err()
+		);
+		assert!(Tag::from_str("[tag]\nxattr = false\nlabels = [ { name = \"a,b\" } ]").is_err());
+	}
+}
@@ -12,11 +12,17 @@ pub struct Tasks {
 	pub macro_workers: u8,
 	#[validate(range(min = 1, message = "Cannot be less than 1"))]
 	pub bizarre_retry: u8,
+	#[validate(range(min = 1, message = "Cannot be less than 1"))]
+	pub fetch_batch:   usize,
+	#[validate(range(min = 1, message = "Cannot be less than 1"))]
+	pub shred_passes:  u8,
 
 	pub image_alloc: u32,
 	pub image_bound: [u16; 2],
 
 	pub suppress_preload: bool,
+	/// Copy the xattrs and ACLs along with the files, only on Linux.
+	pub preserve_xattrs:  bool,
 }
 
 impl FromStr for Tasks {
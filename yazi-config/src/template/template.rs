@@ -0,0 +1,62 @@
+use std::{path::{Path, PathBuf}, str::FromStr};
+
+use anyhow::Context;
+use serde::Deserialize;
+use yazi_fs::{Xdg, expand_path};
+
+use crate::Pattern;
+
+#[derive(Debug, Deserialize)]
+pub struct Template {
+	/// Where the templates live, `templates` under the config directory if empty.
+	pub dir:   PathBuf,
+	pub rules: Vec<TemplateRule>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TemplateRule {
+	/// The ID to pick it with, i.e. `create --template=<id>`.
+	pub id:     Option<String>,
+	/// New entries matching it are created from it, unless another one is picked.
+	pub name:   Option<Pattern>,
+	/// A file or a directory, relative to `dir` unless it's absolute.
+	pub source: PathBuf,
+}
+
+impl Template {
+	/// The source mapped to `id` by the rules.
+	pub fn by_id(&self, id: &str) -> Option<PathBuf> {
+		self.rules.iter().find(|r| r.id.as_deref() == Some(id)).map(|r| self.dir.join(&r.source))
+	}
+
+	/// The source of the first rule matching the new `path`.
+	pub fn by_path(&self, path: &Path, is_dir: bool) -> Option<PathBuf> {
+		self
+			.rules
+			.iter()
+			.find(|r| r.name.as_ref().is_some_and(|p| p.match_path(path, is_dir)))
+			.map(|r| self.dir.join(&r.source))
+	}
+}
+
+impl FromStr for Template {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			template: Template,
+		}
+
+		let mut template = toml::from_str::<Outer>(s)
+			.context("Failed to parse the [template] section in your yazi.toml")?
+			.template;
+
+		template.dir = if template.dir.as_os_str().is_empty() {
+			Xdg::config_dir().join("templates")
+		} else {
+			expand_path(&template.dir)
+		};
+		Ok(template)
+	}
+}
@@ -0,0 +1,151 @@
+use std::{str::FromStr, time::{Duration, SystemTime}};
+
+use anyhow::{Result, bail};
+use serde::Deserialize;
+use yazi_fs::Cha;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CmpOp {
+	Lt,
+	Le,
+	Eq,
+	Ge,
+	Gt,
+}
+
+impl CmpOp {
+	fn split(s: &str) -> (Self, &str) {
+		let s = s.trim();
+		if let Some(s) = s.strip_prefix("<=") {
+			(Self::Le, s)
+		} else if let Some(s) = s.strip_prefix(">=") {
+			(Self::Ge, s)
+		} else if let Some(s) = s.strip_prefix('<') {
+			(Self::Lt, s)
+		} else if let Some(s) = s.strip_prefix('>') {
+			(Self::Gt, s)
+		} else if let Some(s) = s.strip_prefix('=') {
+			(Self::Eq, s)
+		} else {
+			(Self::Ge, s)
+		}
+	}
+
+	#[inline]
+	fn check(self, lhs: u64, rhs: u64) -> bool {
+		match self {
+			Self::Lt => lhs < rhs,
+			Self::Le => lhs <= rhs,
+			Self::Eq => lhs == rhs,
+			Self::Ge => lhs >= rhs,
+			Self::Gt => lhs > rhs,
+		}
+	}
+}
+
+fn split_unit(s: &str) -> Result<(u64, &str)> {
+	let s = s.trim();
+	let i = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+	if i == 0 {
+		bail!("missing number in `{s}`");
+	}
+	Ok((s[..i].parse()?, s[i..].trim()))
+}
+
+// --- Size
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "String")]
+pub struct SizeCmp {
+	op:    CmpOp,
+	bytes: u64,
+}
+
+impl SizeCmp {
+	#[inline]
+	pub fn check(&self, cha: &Cha) -> bool { !cha.is_dir() && self.op.check(cha.len, self.bytes) }
+}
+
+impl TryFrom<String> for SizeCmp {
+	type Error = anyhow::Error;
+
+	fn try_from(value: String) -> Result<Self, Self::Error> { value.parse() }
+}
+
+impl FromStr for SizeCmp {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (op, s) = CmpOp::split(s);
+		let (n, unit) = split_unit(s)?;
+
+		let exp = match unit.to_ascii_uppercase().trim_end_matches('B') {
+			"" => 0,
+			"K" => 1,
+			"M" => 2,
+			"G" => 3,
+			"T" => 4,
+			_ => bail!("unknown size unit `{unit}`, expected one of B, K, M, G, T"),
+		};
+		Ok(Self { op, bytes: n.saturating_mul(1024u64.pow(exp)) })
+	}
+}
+
+// --- Age
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "String")]
+pub struct AgeCmp {
+	op:   CmpOp,
+	secs: u64,
+}
+
+impl AgeCmp {
+	pub fn check(&self, cha: &Cha) -> bool {
+		let Some(mtime) = cha.mtime else { return false };
+		let age = SystemTime::now().duration_since(mtime).unwrap_or(Duration::ZERO);
+		self.op.check(age.as_secs(), self.secs)
+	}
+}
+
+impl TryFrom<String> for AgeCmp {
+	type Error = anyhow::Error;
+
+	fn try_from(value: String) -> Result<Self, Self::Error> { value.parse() }
+}
+
+impl FromStr for AgeCmp {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (op, s) = CmpOp::split(s);
+		let (n, unit) = split_unit(s)?;
+
+		let secs = match unit {
+			"s" => 1,
+			"m" => 60,
+			"h" => 3600,
+			"d" => 86400,
+			"w" => 604800,
+			"y" => 31536000,
+			_ => bail!("unknown age unit `{unit}`, expected one of s, m, h, d, w, y"),
+		};
+		Ok(Self { op, secs: n.saturating_mul(secs) })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		assert_eq!("<1k".parse::<SizeCmp>().unwrap(), SizeCmp { op: CmpOp::Lt, bytes: 1024 });
+		assert_eq!(">= 2MB".parse::<SizeCmp>().unwrap(), SizeCmp { op: CmpOp::Ge, bytes: 2 << 20 });
+		assert_eq!("0".parse::<SizeCmp>().unwrap(), SizeCmp { op: CmpOp::Ge, bytes: 0 });
+		assert_eq!(">1y".parse::<AgeCmp>().unwrap(), AgeCmp { op: CmpOp::Gt, secs: 31536000 });
+		assert_eq!("<=3d".parse::<AgeCmp>().unwrap(), AgeCmp { op: CmpOp::Le, secs: 259200 });
+
+		assert!(">".parse::<SizeCmp>().is_err());
+		assert!("1X".parse::<SizeCmp>().is_err());
+		assert!("1".parse::<AgeCmp>().is_err());
+	}
+}
@@ -2,22 +2,31 @@ use serde::{Deserialize, Deserializer};
 use yazi_fs::File;
 use yazi_shared::theme::{Color, Style, StyleShadow};
 
-use super::Is;
+use super::{AgeCmp, Is, SizeCmp};
 use crate::Pattern;
 
 pub struct Filetype {
 	pub is:    Is,
 	pub name:  Option<Pattern>,
 	pub mime:  Option<Pattern>,
+	pub size:  Option<SizeCmp>,
+	pub age:   Option<AgeCmp>,
 	pub style: Style,
 }
 
 impl Filetype {
 	pub fn matches(&self, file: &File, mime: &str) -> bool {
-		if !self.is.check(&file.cha) {
+		if !self.is.check(&file.cha)
+			|| self.size.is_some_and(|s| !s.check(&file.cha))
+			|| self.age.is_some_and(|a| !a.check(&file.cha))
+		{
 			return false;
 		}
 
+		// Without a `name` or `mime`, only a rule by `size` or `age` matches
+		if self.mime.is_none() && self.name.is_none() {
+			return self.size.is_some() || self.age.is_some();
+		}
 		self.mime.as_ref().is_some_and(|p| p.match_mime(mime))
 			|| self.name.as_ref().is_some_and(|n| n.match_path(&file.url, file.is_dir()))
 	}
@@ -38,6 +47,8 @@ impl Filetype {
 			is:   Is,
 			name: Option<Pattern>,
 			mime: Option<Pattern>,
+			size: Option<SizeCmp>,
+			age:  Option<AgeCmp>,
 
 			fg:          Option<Color>,
 			bg:          Option<Color>,
@@ -69,6 +80,8 @@ impl Filetype {
 					is:    r.is,
 					name:  r.name,
 					mime:  r.mime,
+					size:  r.size,
+					age:   r.age,
 					style: StyleShadow {
 						fg:          r.fg,
 						bg:          r.bg,
@@ -88,3 +101,36 @@ impl Filetype {
 		)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use std::path::Path;
+
+	use yazi_shared::url::Url;
+
+	use super::*;
+
+	fn rule(is: Is, size: Option<&str>) -> Filetype {
+		Filetype {
+			is,
+			name: None,
+			mime: None,
+			size: size.map(|s| s.parse().unwrap()),
+			age: None,
+			style: Default::default(),
+		}
+	}
+
+	#[test]
+	fn test_without_name_or_mime() {
+		let file = File::from_dummy(Url::from(Path::new("/a")), None);
+
+		assert!(!rule(Is::None, None).matches(&file, ""));
+		assert!(!rule(Is::Dummy, None).matches(&file, ""));
+
+		assert!(rule(Is::None, Some("<1k")).matches(&file, ""));
+		assert!(rule(Is::Dummy, Some("<1k")).matches(&file, ""));
+		assert!(!rule(Is::Dummy, Some(">1k")).matches(&file, ""));
+		assert!(!rule(Is::Exec, Some("<1k")).matches(&file, ""));
+	}
+}
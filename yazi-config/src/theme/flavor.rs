@@ -31,6 +31,29 @@ impl FromStr for Flavor {
 }
 
 impl Flavor {
+	/// Override the flavor used for the `light` or dark variant, if `name` is
+	/// given.
+	pub(crate) fn with(mut self, light: bool, name: Option<&str>) -> Self {
+		if let Some(name) = name {
+			*if light { &mut self.light } else { &mut self.dark } = name.to_owned();
+		}
+		self
+	}
+
+	/// Names of the flavors installed under the `flavors` config directory.
+	pub fn installed() -> Vec<String> {
+		let Ok(dir) = std::fs::read_dir(Xdg::config_dir().join("flavors")) else {
+			return vec![];
+		};
+
+		let mut names: Vec<_> = dir
+			.flatten()
+			.filter_map(|e| e.file_name().to_str()?.strip_suffix(".yazi").map(ToOwned::to_owned))
+			.collect();
+		names.sort_unstable();
+		names
+	}
+
 	pub(crate) fn read(&self, light: bool) -> Result<Cow<'static, str>> {
 		Ok(match if light { self.light.as_str() } else { self.dark.as_str() } {
 			"" => Cow::Borrowed(""),
@@ -1,22 +1,97 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, env, sync::atomic::{AtomicU32, Ordering}};
 
 use anyhow::Result;
 use serde::{Deserialize, Deserializer};
 use yazi_fs::File;
-use yazi_shared::{Condition, theme::{Color, Icon, Style}};
+use yazi_shared::{Condition, theme::{Color, Icon, IconCache, Style}};
 
 use crate::{Pattern, Preset};
 
 pub struct Icons {
-	globs: Vec<(Pattern, Icon)>,
-	dirs:  HashMap<String, Icon>,
-	files: HashMap<String, Icon>,
-	exts:  HashMap<String, Icon>,
-	conds: Vec<(Condition, Icon)>,
+	pub set: IconSet,
+
+	id:        u32,
+	all:       Vec<Icon>,
+	overrides: IconRules,
+	nerd:      IconRules,
+	unicode:   Vec<(Condition, u32)>,
+	ascii:     Vec<(Condition, u32)>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum IconSet {
+	#[default]
+	Auto,
+	Nerd,
+	Unicode,
+	Ascii,
+}
+
+// The rules refer to the icons by their index in `Icons::all`
+struct IconRules {
+	globs: Vec<(Pattern, u32)>,
+	dirs:  HashMap<String, u32>,
+	files: HashMap<String, u32>,
+	exts:  HashMap<String, u32>,
+	conds: Vec<(Condition, u32)>,
 }
 
 impl Icons {
+	/// The icon of the file, which is cached on it until the theme is reloaded.
 	pub fn matches(&self, file: &File) -> Option<&Icon> {
+		let index = match file.icon.get() {
+			IconCache::Icon(id, i) if id == self.id => Some(i),
+			IconCache::Undefined(id) if id == self.id => None,
+			_ => {
+				let i = self.index(file);
+				file.icon.set(i.map_or(IconCache::Undefined(self.id), |i| IconCache::Icon(self.id, i)));
+				i
+			}
+		};
+		index.and_then(|i| self.all.get(i as usize))
+	}
+
+	fn index(&self, file: &File) -> Option<u32> {
+		if let Some(i) = self.overrides.matches(file) {
+			return Some(i);
+		}
+
+		match self.set {
+			IconSet::Auto | IconSet::Nerd => self.nerd.matches(file),
+			IconSet::Unicode => IconRules::match_by_cond(&self.unicode, file),
+			IconSet::Ascii => IconRules::match_by_cond(&self.ascii, file),
+		}
+	}
+}
+
+impl IconSet {
+	/// Resolve `auto` to a concrete set, based on what the terminal is likely to
+	/// be able to display: the Linux console and non-UTF-8 locales get ASCII,
+	/// everything else gets Nerd Font glyphs.
+	pub fn detect(self) -> Self {
+		if self != Self::Auto {
+			return self;
+		}
+
+		if env::var_os("TERM").is_some_and(|t| t == "linux") {
+			return Self::Ascii;
+		}
+
+		let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+			.into_iter()
+			.find_map(|k| env::var(k).ok().filter(|s| !s.is_empty()))
+			.map(|s| s.to_ascii_lowercase());
+
+		match locale {
+			Some(s) if !s.contains("utf-8") && !s.contains("utf8") => Self::Ascii,
+			_ => Self::Nerd,
+		}
+	}
+}
+
+impl IconRules {
+	fn matches(&self, file: &File) -> Option<u32> {
 		if let Some(i) = self.match_by_glob(file) {
 			return Some(i);
 		}
@@ -25,46 +100,51 @@ impl Icons {
 			return Some(i);
 		}
 
-		let f = |s: &str| match s {
-			"dir" => file.is_dir(),
-			"hidden" => file.is_hidden(),
-			"link" => file.is_link(),
-			"orphan" => file.is_orphan(),
-			"dummy" => file.is_dummy(),
-			"block" => file.is_block(),
-			"char" => file.is_char(),
-			"fifo" => file.is_fifo(),
-			"sock" => file.is_sock(),
-			"exec" => file.is_exec(),
-			"sticky" => file.is_sticky(),
-			_ => false,
-		};
-		self.conds.iter().find(|(c, _)| c.eval(f) == Some(true)).map(|(_, i)| i)
+		Self::match_by_cond(&self.conds, file)
 	}
 
 	#[inline]
-	fn match_by_glob(&self, file: &File) -> Option<&Icon> {
-		self.globs.iter().find(|(p, _)| p.match_path(&file.url, file.is_dir())).map(|(_, i)| i)
+	fn match_by_glob(&self, file: &File) -> Option<u32> {
+		self.globs.iter().find(|(p, _)| p.match_path(&file.url, file.is_dir())).map(|&(_, i)| i)
 	}
 
 	#[inline]
-	fn match_by_name(&self, file: &File) -> Option<&Icon> {
+	fn match_by_name(&self, file: &File) -> Option<u32> {
 		let name = file.name().to_str()?;
 		if file.is_dir() {
-			self.dirs.get(name).or_else(|| self.dirs.get(&name.to_ascii_lowercase()))
+			self.dirs.get(name).or_else(|| self.dirs.get(&name.to_ascii_lowercase())).copied()
 		} else {
 			self
 				.files
 				.get(name)
 				.or_else(|| self.files.get(&name.to_ascii_lowercase()))
+				.copied()
 				.or_else(|| self.match_by_ext(file))
 		}
 	}
 
 	#[inline]
-	fn match_by_ext(&self, file: &File) -> Option<&Icon> {
+	fn match_by_ext(&self, file: &File) -> Option<u32> {
 		let ext = file.url.extension()?.to_str()?;
-		self.exts.get(ext).or_else(|| self.exts.get(&ext.to_ascii_lowercase()))
+		self.exts.get(ext).or_else(|| self.exts.get(&ext.to_ascii_lowercase())).copied()
+	}
+
+	fn match_by_cond(conds: &[(Condition, u32)], file: &File) -> Option<u32> {
+		let f = |s: &str| match s {
+			"dir" => file.is_dir(),
+			"hidden" => file.is_hidden(),
+			"link" => file.is_link(),
+			"orphan" => file.is_orphan(),
+			"dummy" => file.is_dummy(),
+			"block" => file.is_block(),
+			"char" => file.is_char(),
+			"fifo" => file.is_fifo(),
+			"sock" => file.is_sock(),
+			"exec" => file.is_exec(),
+			"sticky" => file.is_sticky(),
+			_ => false,
+		};
+		conds.iter().find(|(c, _)| c.eval(f) == Some(true)).map(|&(_, i)| i)
 	}
 }
 
@@ -75,6 +155,9 @@ impl<'de> Deserialize<'de> for Icons {
 	{
 		#[derive(Deserialize)]
 		pub struct Shadow {
+			#[serde(default)]
+			set: IconSet,
+
 			globs:         Vec<ShadowPat>,
 			#[serde(default)]
 			prepend_globs: Vec<ShadowPat>,
@@ -104,6 +187,11 @@ impl<'de> Deserialize<'de> for Icons {
 			prepend_conds: Vec<ShadowCond>,
 			#[serde(default)]
 			append_conds:  Vec<ShadowCond>,
+
+			#[serde(default)]
+			unicode: Vec<ShadowCond>,
+			#[serde(default)]
+			ascii:   Vec<ShadowCond>,
 		}
 		#[derive(Deserialize)]
 		pub struct ShadowPat {
@@ -125,33 +213,116 @@ impl<'de> Deserialize<'de> for Icons {
 			fg:   Option<Color>,
 		}
 
-		let shadow = Shadow::deserialize(deserializer)?;
-
-		let globs = Preset::mix(shadow.prepend_globs, shadow.globs, shadow.append_globs)
-			.map(|v| (v.name, Icon { text: v.text, style: Style { fg: v.fg, ..Default::default() } }))
-			.collect();
-
-		let conds = Preset::mix(shadow.prepend_conds, shadow.conds, shadow.append_conds)
-			.map(|v| (v.if_, Icon { text: v.text, style: Style { fg: v.fg, ..Default::default() } }))
-			.collect();
+		#[inline]
+		fn icon(all: &mut Vec<Icon>, text: String, fg: Option<Color>) -> u32 {
+			all.push(Icon { text, style: Style { fg, ..Default::default() } });
+			all.len() as u32 - 1
+		}
 
-		fn as_map(it: impl Iterator<Item = ShadowStr>) -> HashMap<String, Icon> {
+		fn as_map(
+			all: &mut Vec<Icon>,
+			it: impl IntoIterator<Item = ShadowStr>,
+		) -> HashMap<String, u32> {
+			let it = it.into_iter();
 			let mut map = HashMap::with_capacity(it.size_hint().0);
 			for v in it {
-				map
-					.entry(v.name)
-					.or_insert(Icon { text: v.text, style: Style { fg: v.fg, ..Default::default() } });
+				map.entry(v.name).or_insert_with(|| icon(all, v.text, v.fg));
 			}
 			map.shrink_to_fit();
 			map
 		}
 
+		fn as_pats(
+			all: &mut Vec<Icon>,
+			it: impl IntoIterator<Item = ShadowPat>,
+		) -> Vec<(Pattern, u32)> {
+			it.into_iter().map(|v| (v.name, icon(all, v.text, v.fg))).collect()
+		}
+
+		fn as_conds(
+			all: &mut Vec<Icon>,
+			it: impl IntoIterator<Item = ShadowCond>,
+		) -> Vec<(Condition, u32)> {
+			it.into_iter().map(|v| (v.if_, icon(all, v.text, v.fg))).collect()
+		}
+
+		// Tells the icons cached on files by an earlier theme from this one's
+		static ID: AtomicU32 = AtomicU32::new(0);
+
+		let shadow = Shadow::deserialize(deserializer)?;
+		let mut all = vec![];
+
+		// The `prepend_*` rules are user overrides and apply to every icon set,
+		// while the rest only make up the Nerd Font set.
+		let overrides = IconRules {
+			globs: as_pats(&mut all, shadow.prepend_globs),
+			dirs:  as_map(&mut all, shadow.prepend_dirs),
+			files: as_map(&mut all, shadow.prepend_files),
+			exts:  as_map(&mut all, shadow.prepend_exts),
+			conds: as_conds(&mut all, shadow.prepend_conds),
+		};
+
+		let nerd = IconRules {
+			globs: as_pats(&mut all, Preset::mix([], shadow.globs, shadow.append_globs)),
+			dirs:  as_map(&mut all, Preset::mix([], shadow.dirs, shadow.append_dirs)),
+			files: as_map(&mut all, Preset::mix([], shadow.files, shadow.append_files)),
+			exts:  as_map(&mut all, Preset::mix([], shadow.exts, shadow.append_exts)),
+			conds: as_conds(&mut all, Preset::mix([], shadow.conds, shadow.append_conds)),
+		};
+
+		let (unicode, ascii) = (as_conds(&mut all, shadow.unicode), as_conds(&mut all, shadow.ascii));
+		all.shrink_to_fit();
+
 		Ok(Self {
-			globs,
-			dirs: as_map(Preset::mix(shadow.prepend_dirs, shadow.dirs, shadow.append_dirs)),
-			files: as_map(Preset::mix(shadow.prepend_files, shadow.files, shadow.append_files)),
-			exts: as_map(Preset::mix(shadow.prepend_exts, shadow.exts, shadow.append_exts)),
-			conds,
+			set: shadow.set.detect(),
+
+			id: ID.fetch_add(1, Ordering::Relaxed),
+			all,
+			overrides,
+			nerd,
+			unicode,
+			ascii,
 		})
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use yazi_shared::url::Url;
+
+	use super::*;
+
+	fn icons(set: &str) -> Icons {
+		toml::from_str(&format!(
+			r#"
+			set           = "{set}"
+			globs         = []
+			dirs          = []
+			files         = []
+			exts          = [ {{ name = "rs", text = "R" }} ]
+			prepend_exts  = [ {{ name = "md", text = "M" }} ]
+			conds         = [ {{ if = "!dir", text = "F" }} ]
+			ascii         = [ {{ if = "!dir", text = "-" }} ]
+			"#
+		))
+		.unwrap()
+	}
+
+	#[test]
+	fn test_layers() {
+		let text = |icons: &Icons, path: &str| {
+			icons.matches(&File::from_dummy(Url::from(path), None)).map(|i| i.text.clone())
+		};
+
+		let nerd = icons("nerd");
+		assert_eq!(text(&nerd, "/a.rs").as_deref(), Some("R"));
+		assert_eq!(text(&nerd, "/a.md").as_deref(), Some("M"));
+		assert_eq!(text(&nerd, "/a.txt").as_deref(), Some("F"));
+
+		let ascii = icons("ascii");
+		assert_eq!(text(&ascii, "/a.rs").as_deref(), Some("-"));
+		assert_eq!(text(&ascii, "/a.md").as_deref(), Some("M"));
+
+		assert_eq!(text(&icons("unicode"), "/a.rs"), None);
+	}
+}
@@ -47,12 +47,12 @@ pub struct Manager {
 	cwd: Style,
 
 	// Hovered
-	hovered:         Style,
-	preview_hovered: Style,
+	pub hovered:         Style,
+	pub preview_hovered: Style,
 
 	// Find
-	find_keyword:  Style,
-	find_position: Style,
+	pub find_keyword: Style,
+	find_position:    Style,
 
 	// Marker
 	marker_copied:   Style,
@@ -71,6 +71,26 @@ pub struct Manager {
 	count_cut:      Style,
 	count_selected: Style,
 
+	// Columns
+	column_header: Style,
+	group_header:  Style,
+
+	// Scrollbar
+	scrollbar_track:    Style,
+	scrollbar_thumb:    Style,
+	scrollbar_selected: Style,
+	scrollbar_found:    Style,
+
+	// Diff
+	diff_unique:  Style,
+	diff_changed: Style,
+	diff_same:    Style,
+
+	pub diff_insert:      Style,
+	pub diff_delete:      Style,
+	pub diff_insert_word: Style,
+	pub diff_delete_word: Style,
+
 	// Border
 	pub border_symbol: String,
 	pub border_style:  Style,
@@ -196,6 +216,7 @@ pub struct Help {
 	pub run:  Style,
 	pub desc: Style,
 
-	pub hovered: Style,
-	pub footer:  Style,
+	pub shadowed: Style,
+	pub hovered:  Style,
+	pub footer:   Style,
 }
@@ -0,0 +1,44 @@
+use std::{str::FromStr, time::Duration};
+
+use anyhow::Context;
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct Trash {
+	/// Purge the items trashed more than this many days ago, 0 to keep them.
+	pub max_days: u64,
+	/// Purge the oldest items while the trash is larger than this many GB, 0
+	/// for no limit.
+	#[validate(range(min = 0.0, message = "Cannot be negative"))]
+	pub max_size: f64,
+}
+
+impl Trash {
+	#[inline]
+	pub fn max_age(&self) -> Option<Duration> {
+		Some(Duration::from_secs(self.max_days * 86400)).filter(|d| !d.is_zero())
+	}
+
+	#[inline]
+	pub fn max_bytes(&self) -> Option<u64> {
+		Some((self.max_size * 1024.0 * 1024.0 * 1024.0) as u64).filter(|&n| n > 0)
+	}
+}
+
+impl FromStr for Trash {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			trash: Trash,
+		}
+
+		let outer = toml::from_str::<Outer>(s)
+			.context("Failed to parse the [trash] section in your yazi.toml")?;
+		outer.trash.validate()?;
+
+		Ok(outer.trash)
+	}
+}
@@ -0,0 +1,43 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct Watcher {
+	pub backend:       WatcherBackend,
+	#[validate(range(min = 100, message = "Cannot be less than 100"))]
+	pub poll_interval: u64,
+
+	pub debounce: u64,
+	#[validate(range(min = 1, message = "Cannot be less than 1"))]
+	pub batch:    usize,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum WatcherBackend {
+	/// Native events, falling back to polling for remote filesystems and when
+	/// the native watches run out.
+	Auto,
+	Native,
+	Poll,
+}
+
+impl FromStr for Watcher {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		#[derive(Deserialize)]
+		struct Outer {
+			watcher: Watcher,
+		}
+
+		let outer = toml::from_str::<Outer>(s)
+			.context("Failed to parse the [watcher] section in your yazi.toml")?;
+		outer.watcher.validate()?;
+
+		Ok(outer.watcher)
+	}
+}
@@ -19,6 +19,7 @@ impl Completion {
 		} else {
 			self.prev(opt.step.unsigned_abs());
 		}
+		self.preload();
 	}
 
 	fn next(&mut self, step: usize) {
@@ -0,0 +1,34 @@
+use std::path::PathBuf;
+
+use yazi_macro::render;
+use yazi_shared::event::CmdCow;
+
+use crate::completion::Completion;
+
+struct Opt {
+	cache:      Vec<String>,
+	cache_name: PathBuf,
+	sensitive:  bool,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(mut c: CmdCow) -> Self {
+		Self {
+			cache:      c.take_any("cache").unwrap_or_default(),
+			cache_name: c.take_any("cache-name").unwrap_or_default(),
+			sensitive:  c.bool("sensitive"),
+		}
+	}
+}
+
+impl Completion {
+	#[yazi_codegen::command]
+	pub fn load(&mut self, opt: Opt) {
+		if !self.visible {
+			return;
+		}
+
+		self.caches.insert(opt.cache_name, (opt.cache, opt.sensitive));
+		render!(self.peek().is_some());
+	}
+}
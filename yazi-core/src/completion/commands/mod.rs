@@ -1 +1 @@
-yazi_macro::mod_flat!(arrow close show trigger);
+yazi_macro::mod_flat!(arrow close load show trigger);
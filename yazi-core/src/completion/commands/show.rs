@@ -10,6 +10,7 @@ const LIMIT: usize = 30;
 struct Opt {
 	cache:      Vec<String>,
 	cache_name: PathBuf,
+	sensitive:  bool,
 	word:       Cow<'static, str>,
 	ticket:     usize,
 }
@@ -19,6 +20,7 @@ impl From<CmdCow> for Opt {
 		Self {
 			cache:      c.take_any("cache").unwrap_or_default(),
 			cache_name: c.take_any("cache-name").unwrap_or_default(),
+			sensitive:  c.bool("sensitive"),
 			word:       c.take_str("word").unwrap_or_default(),
 			ticket:     c.get("ticket").and_then(Data::as_usize).unwrap_or(0),
 		}
@@ -37,14 +39,15 @@ impl Completion {
 		}
 
 		if !opt.cache.is_empty() {
-			self.caches.insert(opt.cache_name.clone(), opt.cache);
+			self.caches.insert(opt.cache_name.clone(), (opt.cache, opt.sensitive));
 		}
-		let Some(cache) = self.caches.get(&opt.cache_name) else {
+		let Some((cache, sensitive)) = self.caches.get(&opt.cache_name) else {
 			return;
 		};
 
 		self.ticket = opt.ticket;
-		self.cands = Self::match_candidates(&opt.word, cache);
+		self.parent = opt.cache_name;
+		self.cands = Self::match_candidates(&opt.word, cache, *sensitive);
 		if self.cands.is_empty() {
 			return render!(mem::replace(&mut self.visible, false));
 		}
@@ -52,37 +55,70 @@ impl Completion {
 		self.offset = 0;
 		self.cursor = 0;
 		self.visible = true;
+		self.preload();
 		render!();
 	}
 
-	fn match_candidates(word: &str, cache: &[String]) -> Vec<String> {
-		let smart = !word.bytes().any(|c| c.is_ascii_uppercase());
+	fn match_candidates(word: &str, cache: &[String], sensitive: bool) -> Vec<String> {
+		// Always match case-insensitively in case-insensitive directories, as the
+		// paths can be entered in any case anyway
+		let smart = !sensitive || !word.bytes().any(|c| c.is_ascii_uppercase());
+		let prefix = if smart { Cow::Owned(word.to_lowercase()) } else { Cow::Borrowed(word) };
 
 		let flow = cache.iter().try_fold(
-			(Vec::with_capacity(LIMIT), Vec::with_capacity(LIMIT)),
-			|(mut prefixed, mut fuzzy), s| {
-				if (smart && s.to_lowercase().starts_with(word)) || (!smart && s.starts_with(word)) {
+			(Vec::with_capacity(LIMIT), Vec::with_capacity(LIMIT), Vec::with_capacity(LIMIT)),
+			|(mut prefixed, mut fuzzy, mut scattered), s| {
+				let lower = if smart { Cow::Owned(s.to_lowercase()) } else { Cow::Borrowed(s.as_str()) };
+				if lower.starts_with(&*prefix) {
 					if s != word {
 						prefixed.push(s);
 						if prefixed.len() >= LIMIT {
-							return ControlFlow::Break((prefixed, fuzzy));
+							return ControlFlow::Break((prefixed, fuzzy, scattered));
 						}
 					}
-				} else if fuzzy.len() < LIMIT - prefixed.len() && s.contains(word) {
+				} else if fuzzy.len() < LIMIT - prefixed.len() && lower.contains(&*prefix) {
 					// here we don't break the control flow, since we want more exact matching.
 					fuzzy.push(s)
+				} else if scattered.len() < LIMIT - prefixed.len() && Self::scattered(&lower, &prefix) {
+					// and the ones that only have the characters in order, e.g. `dcm` for
+					// `documents`
+					scattered.push(s)
 				}
-				ControlFlow::Continue((prefixed, fuzzy))
+				ControlFlow::Continue((prefixed, fuzzy, scattered))
 			},
 		);
 
-		let (mut prefixed, fuzzy) = match flow {
+		let (mut prefixed, fuzzy, scattered) = match flow {
 			ControlFlow::Continue(v) => v,
 			ControlFlow::Break(v) => v,
 		};
-		if prefixed.len() < LIMIT {
-			prefixed.extend(fuzzy.into_iter().take(LIMIT - prefixed.len()))
+		for more in [fuzzy, scattered] {
+			let n = LIMIT.saturating_sub(prefixed.len());
+			prefixed.extend(more.into_iter().take(n));
 		}
 		prefixed.into_iter().map(ToOwned::to_owned).collect()
 	}
+
+	fn scattered(s: &str, word: &str) -> bool {
+		let mut it = s.chars();
+		word.chars().all(|w| it.any(|c| c == w))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_match_candidates() {
+		let cache: Vec<_> =
+			["Documents/", "Downloads/", "docs.md", "Music/", "mdc"].map(String::from).into();
+		let matches = |word, sensitive| Completion::match_candidates(word, &cache, sensitive);
+
+		assert_eq!(matches("do", true), ["Documents/", "Downloads/", "docs.md"]);
+		assert_eq!(matches("Do", true), ["Documents/", "Downloads/"]);
+		assert_eq!(matches("dc", false), ["mdc", "Documents/", "docs.md"]);
+		assert_eq!(matches("mc", true), ["Music/", "mdc"]);
+		assert_eq!(matches("dls", true), ["Downloads/"]);
+	}
 }
@@ -1,7 +1,6 @@
 use std::{borrow::Cow, mem, path::{MAIN_SEPARATOR_STR, PathBuf}};
 
-use tokio::fs;
-use yazi_fs::{CWD, expand_path};
+use yazi_fs::{CWD, case_sensitive, expand_path};
 use yazi_macro::{emit, render};
 use yazi_shared::{Layer, event::{Cmd, CmdCow, Data}};
 
@@ -41,23 +40,14 @@ impl Completion {
 
 		let ticket = self.ticket;
 		tokio::spawn(async move {
-			let mut dir = fs::read_dir(&parent).await?;
-			let mut cache = vec![];
-			while let Ok(Some(f)) = dir.next_entry().await {
-				let Ok(meta) = f.metadata().await else { continue };
-
-				cache.push(format!(
-					"{}{}",
-					f.file_name().to_string_lossy(),
-					if meta.is_dir() { MAIN_SEPARATOR_STR } else { "" },
-				));
-			}
-
+			let cache = Self::read(&parent).await?;
 			if !cache.is_empty() {
+				let sensitive = case_sensitive(&parent).await;
 				emit!(Call(
 					Cmd::new("show")
 						.with_any("cache", cache)
 						.with_any("cache-name", parent)
+						.with_bool("sensitive", sensitive)
 						.with("word", word)
 						.with("ticket", ticket),
 					Layer::Completion
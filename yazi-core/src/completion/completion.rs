@@ -1,8 +1,14 @@
-use std::{collections::HashMap, path::PathBuf};
+use std::{collections::HashMap, path::{MAIN_SEPARATOR, MAIN_SEPARATOR_STR, Path, PathBuf}};
+
+use tokio::fs;
+use yazi_fs::case_sensitive;
+use yazi_macro::emit;
+use yazi_shared::{Layer, event::Cmd};
 
 #[derive(Default)]
 pub struct Completion {
-	pub(super) caches: HashMap<PathBuf, Vec<String>>,
+	pub(super) caches: HashMap<PathBuf, (Vec<String>, bool)>,
+	pub(super) parent: PathBuf,
 	pub(super) cands:  Vec<String>,
 	pub(super) offset: usize,
 	pub cursor:        usize,
@@ -25,6 +31,51 @@ impl Completion {
 	#[inline]
 	pub fn selected(&self) -> Option<&String> { self.cands.get(self.cursor) }
 
+	/// Entries of the selected candidate if it's a directory, once they're read.
+	pub fn peek(&self) -> Option<&[String]> {
+		let dir = self.selected().filter(|s| s.ends_with(MAIN_SEPARATOR))?;
+		self.caches.get(&self.parent.join(dir)).map(|(v, _)| v.as_slice())
+	}
+
+	/// Read the selected candidate ahead if it's a directory, for [`Self::peek`].
+	pub(super) fn preload(&self) {
+		let Some(dir) = self.selected().filter(|s| s.ends_with(MAIN_SEPARATOR)) else { return };
+
+		let dir = self.parent.join(dir);
+		if self.caches.contains_key(&dir) {
+			return;
+		}
+
+		tokio::spawn(async move {
+			let cache = Self::read(&dir).await?;
+			let sensitive = case_sensitive(&dir).await;
+			emit!(Call(
+				Cmd::new("load")
+					.with_any("cache", cache)
+					.with_any("cache-name", dir)
+					.with_bool("sensitive", sensitive),
+				Layer::Completion
+			));
+			Ok::<_, anyhow::Error>(())
+		});
+	}
+
+	/// Names of the entries in `dir`, with a trailing separator for directories.
+	pub(super) async fn read(dir: &Path) -> std::io::Result<Vec<String>> {
+		let mut it = fs::read_dir(dir).await?;
+		let mut cache = vec![];
+		while let Ok(Some(f)) = it.next_entry().await {
+			let Ok(meta) = f.metadata().await else { continue };
+
+			cache.push(format!(
+				"{}{}",
+				f.file_name().to_string_lossy(),
+				if meta.is_dir() { MAIN_SEPARATOR_STR } else { "" },
+			));
+		}
+		Ok(cache)
+	}
+
 	// --- Cursor
 	#[inline]
 	pub fn rel_cursor(&self) -> usize { self.cursor - self.offset }
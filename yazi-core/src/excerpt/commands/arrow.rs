@@ -0,0 +1,25 @@
+use yazi_fs::Step;
+use yazi_macro::render;
+use yazi_shared::event::CmdCow;
+
+use crate::excerpt::Excerpt;
+
+struct Opt {
+	step: Step,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self {
+		Self { step: c.first().and_then(|d| d.try_into().ok()).unwrap_or_default() }
+	}
+}
+
+impl Excerpt {
+	#[yazi_codegen::command]
+	pub fn arrow(&mut self, opt: Opt) {
+		let old = (self.cursor, self.offset);
+		self.cursor = opt.step.add(self.cursor, Self::limit()).min(self.lines.len().saturating_sub(1));
+		self.follow();
+		render!((self.cursor, self.offset) != old);
+	}
+}
@@ -0,0 +1,25 @@
+use yazi_macro::render;
+use yazi_shared::event::CmdCow;
+
+use crate::excerpt::Excerpt;
+
+struct Opt;
+
+impl From<CmdCow> for Opt {
+	fn from(_: CmdCow) -> Self { Self }
+}
+impl From<()> for Opt {
+	fn from(_: ()) -> Self { Self }
+}
+
+impl Excerpt {
+	#[yazi_codegen::command]
+	pub fn close(&mut self, _: Opt) {
+		if !self.visible {
+			return;
+		}
+
+		*self = Self { numbers: self.numbers, ..Default::default() };
+		render!();
+	}
+}
@@ -0,0 +1,22 @@
+use yazi_macro::render;
+use yazi_shared::event::CmdCow;
+
+use crate::excerpt::Excerpt;
+
+struct Opt;
+
+impl From<CmdCow> for Opt {
+	fn from(_: CmdCow) -> Self { Self }
+}
+
+impl Excerpt {
+	/// Cancels the selection, or closes the excerpt if there's none.
+	#[yazi_codegen::command]
+	pub fn escape(&mut self, _: Opt) {
+		if self.anchor.take().is_some() {
+			render!();
+		} else {
+			self.close(());
+		}
+	}
+}
@@ -0,0 +1,29 @@
+use yazi_macro::render;
+use yazi_shared::event::CmdCow;
+
+use crate::excerpt::Excerpt;
+
+struct Opt {
+	state: Option<bool>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self {
+		Self {
+			state: match c.first_str() {
+				Some("on") => Some(true),
+				Some("off") => Some(false),
+				_ => None,
+			},
+		}
+	}
+}
+
+impl Excerpt {
+	#[yazi_codegen::command]
+	pub fn numbers(&mut self, opt: Opt) {
+		let numbers = opt.state.unwrap_or(!self.numbers);
+		render!(numbers != self.numbers);
+		self.numbers = numbers;
+	}
+}
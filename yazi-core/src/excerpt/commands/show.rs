@@ -0,0 +1,63 @@
+use anyhow::{Result, bail};
+use tokio::io::AsyncReadExt;
+use yazi_config::LAYOUT;
+use yazi_fs::File;
+use yazi_macro::{emit, render};
+use yazi_proxy::AppProxy;
+use yazi_shared::{Layer, event::{Cmd, CmdCow}, url::Url};
+
+use crate::excerpt::{EXCERPT_BYTES, Excerpt};
+
+struct Opt {
+	loaded: Option<(Url, Vec<String>, usize)>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(mut c: CmdCow) -> Self { Self { loaded: c.take_any("loaded") } }
+}
+
+impl Excerpt {
+	/// Opens the hovered file from where its preview is scrolled to, which is
+	/// read in the background and shown once it's `loaded`.
+	#[yazi_codegen::command]
+	pub fn show(&mut self, opt: Opt, hovered: Option<&File>, skip: usize) {
+		if let Some((url, lines, cursor)) = opt.loaded {
+			if lines.is_empty() {
+				return AppProxy::notify_warn("Excerpt", "The file is empty");
+			}
+
+			*self = Self { visible: true, url, lines, numbers: self.numbers, ..Default::default() };
+			self.cursor = cursor.min(self.lines.len() - 1);
+			self.offset = self.cursor;
+			return render!();
+		}
+
+		let Some(file) = hovered else {
+			return AppProxy::notify_warn("Excerpt", "Nothing is hovered");
+		};
+		if file.is_dir() {
+			return AppProxy::notify_warn("Excerpt", "A directory has no text to select");
+		} else if LAYOUT.get().preview.is_empty() {
+			return AppProxy::notify_warn("Excerpt", "The preview pane is hidden");
+		}
+
+		let url = file.url.clone();
+		tokio::spawn(async move {
+			match Self::load(&url).await {
+				Ok(lines) => {
+					emit!(Call(Cmd::new("excerpt").with_any("loaded", (url, lines, skip)), Layer::Manager))
+				}
+				Err(e) => AppProxy::notify_warn("Excerpt", e),
+			}
+		});
+	}
+
+	async fn load(url: &Url) -> Result<Vec<String>> {
+		let mut buf = vec![];
+		tokio::fs::File::open(url).await?.take(EXCERPT_BYTES).read_to_end(&mut buf).await?;
+		if buf[..buf.len().min(8192)].contains(&0) {
+			bail!("It's not a text file");
+		}
+		Ok(String::from_utf8_lossy(&buf).lines().map(ToOwned::to_owned).collect())
+	}
+}
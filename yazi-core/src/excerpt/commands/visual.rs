@@ -0,0 +1,19 @@
+use yazi_macro::render;
+use yazi_shared::event::CmdCow;
+
+use crate::excerpt::Excerpt;
+
+struct Opt;
+
+impl From<CmdCow> for Opt {
+	fn from(_: CmdCow) -> Self { Self }
+}
+
+impl Excerpt {
+	/// Starts selecting lines from the cursor, or stops it.
+	#[yazi_codegen::command]
+	pub fn visual(&mut self, _: Opt) {
+		self.anchor = if self.anchor.is_some() { None } else { Some(self.cursor) };
+		render!();
+	}
+}
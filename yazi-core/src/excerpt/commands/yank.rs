@@ -0,0 +1,34 @@
+use std::fmt::Write;
+
+use yazi_macro::render;
+use yazi_plugin::CLIPBOARD;
+use yazi_shared::event::CmdCow;
+
+use crate::excerpt::Excerpt;
+
+struct Opt;
+
+impl From<CmdCow> for Opt {
+	fn from(_: CmdCow) -> Self { Self }
+}
+
+impl Excerpt {
+	/// Copies the selected lines, or the one under the cursor, with their line
+	/// numbers if they're shown.
+	#[yazi_codegen::command]
+	pub fn yank(&mut self, _: Opt) {
+		let gutter = self.gutter();
+		let mut s = String::new();
+		for i in self.selected() {
+			let Some(line) = self.lines.get(i) else { break };
+			if gutter > 0 {
+				_ = write!(s, "{:>1$} ", i + 1, gutter - 1);
+			}
+			s.push_str(line);
+			s.push('\n');
+		}
+
+		futures::executor::block_on(CLIPBOARD.set(s));
+		render!(self.anchor.take().is_some());
+	}
+}
@@ -0,0 +1,50 @@
+use std::{borrow::Cow, ops::RangeInclusive};
+
+use yazi_config::LAYOUT;
+use yazi_shared::url::Url;
+
+/// A text file opened in the preview pane, to move a cursor through and copy
+/// the lines selected with it.
+#[derive(Default)]
+pub struct Excerpt {
+	pub visible: bool,
+	pub url:     Url,
+	pub lines:   Vec<String>,
+	pub cursor:  usize,
+	pub offset:  usize,
+	/// Where the selection starts, which ends at the cursor.
+	pub anchor:  Option<usize>,
+	/// Whether the line numbers are shown, and copied along with the lines.
+	pub numbers: bool,
+}
+
+impl Excerpt {
+	#[inline]
+	pub fn limit() -> usize { LAYOUT.get().preview.height as usize }
+
+	#[inline]
+	pub fn name(&self) -> Cow<'_, str> { self.url.name().to_string_lossy() }
+
+	/// The selected lines, or the one under the cursor if there's no selection.
+	#[inline]
+	pub fn selected(&self) -> RangeInclusive<usize> {
+		let anchor = self.anchor.unwrap_or(self.cursor);
+		anchor.min(self.cursor)..=anchor.max(self.cursor)
+	}
+
+	/// The width of the line numbers, including the space after them.
+	#[inline]
+	pub fn gutter(&self) -> usize {
+		if self.numbers { self.lines.len().to_string().len() + 1 } else { 0 }
+	}
+
+	/// Scrolls the cursor into sight.
+	pub(super) fn follow(&mut self) {
+		let limit = Self::limit().max(1);
+		if self.cursor < self.offset {
+			self.offset = self.cursor;
+		} else if self.cursor >= self.offset + limit {
+			self.offset = self.cursor + 1 - limit;
+		}
+	}
+}
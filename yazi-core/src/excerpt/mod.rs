@@ -0,0 +1,6 @@
+yazi_macro::mod_pub!(commands);
+
+yazi_macro::mod_flat!(excerpt);
+
+/// The most bytes of a file read into an excerpt.
+pub const EXCERPT_BYTES: u64 = 4 << 20;
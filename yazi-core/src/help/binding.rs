@@ -0,0 +1,36 @@
+use std::{ops::Deref, sync::Arc};
+
+use yazi_config::keymap::Chord;
+
+/// A chord listed in the help, along with the one that shadows it, if any,
+/// which makes it unreachable by its keys.
+pub struct HelpBinding {
+	pub chord:    Arc<Chord>,
+	pub shadowed: Option<Arc<Chord>>,
+}
+
+impl Deref for HelpBinding {
+	type Target = Chord;
+
+	fn deref(&self) -> &Self::Target { &self.chord }
+}
+
+impl HelpBinding {
+	/// Resolve the chords in the order they're matched in, where a chord wins
+	/// all the keys it starts with if it's a single key, otherwise the ones
+	/// with a single key, or the very same keys.
+	pub(super) fn resolve(chords: Vec<Arc<Chord>>) -> Vec<Self> {
+		let by = |i: usize| {
+			let c = &chords[i];
+			let first = chords.iter().position(|d| d.on.first() == c.on.first())?;
+			if first == i {
+				return None;
+			} else if chords[first].on.len() == 1 || c.on.len() == 1 {
+				return Some(chords[first].clone());
+			}
+			chords.iter().position(|d| d.on == c.on).filter(|&j| j != i).map(|j| chords[j].clone())
+		};
+
+		(0..chords.len()).map(|i| Self { chord: chords[i].clone(), shadowed: by(i) }).collect()
+	}
+}
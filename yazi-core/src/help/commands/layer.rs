@@ -0,0 +1,55 @@
+use yazi_macro::render;
+use yazi_shared::{Layer, event::CmdCow};
+
+use crate::help::Help;
+
+const LAYERS: [Layer; 14] = [
+	Layer::Manager,
+	Layer::Tasks,
+	Layer::Notify,
+	Layer::Watch,
+	Layer::Output,
+	Layer::Excerpt,
+	Layer::Terminal,
+	Layer::Quicklook,
+	Layer::Spot,
+	Layer::Pick,
+	Layer::Input,
+	Layer::Confirm,
+	Layer::Help,
+	Layer::Completion,
+];
+
+struct Opt {
+	layer: Option<Layer>,
+	step:  isize,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self {
+		match c.first_str() {
+			Some("next") => Self { layer: None, step: 1 },
+			Some("prev") => Self { layer: None, step: -1 },
+			s => Self { layer: s.and_then(|s| s.parse().ok()), step: 0 },
+		}
+	}
+}
+
+impl Help {
+	#[yazi_codegen::command]
+	pub fn layer(&mut self, opt: Opt) {
+		let layer = opt.layer.filter(|l| LAYERS.contains(l)).unwrap_or_else(|| {
+			let i = LAYERS.iter().position(|&l| l == self.layer).unwrap_or(0) as isize;
+			LAYERS[(i + opt.step).rem_euclid(LAYERS.len() as isize) as usize]
+		});
+		if layer == self.layer {
+			return;
+		}
+
+		self.layer = layer;
+		self.bindings = self.collect(&self.keyword);
+		self.offset = 0;
+		self.cursor = 0;
+		render!();
+	}
+}
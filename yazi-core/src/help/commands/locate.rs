@@ -0,0 +1,58 @@
+use std::{path::PathBuf, sync::Arc};
+
+use yazi_config::{COMMAND, project::Project, t};
+use yazi_fs::Xdg;
+use yazi_macro::emit;
+use yazi_proxy::AppProxy;
+use yazi_shared::{Layer, event::{Cmd, CmdCow}};
+
+use crate::{help::Help, manager::PROJECTS};
+
+impl Help {
+	/// Edit the config file at the line the hovered item is defined at.
+	pub fn locate(&mut self, _: CmdCow) {
+		let Some(chord) = self.hovered().map(|b| b.chord.clone()) else { return };
+
+		let command = COMMAND.load();
+		let alias = command.aliases.iter().find(|a| Arc::ptr_eq(&a.chord, &chord));
+		let project =
+			PROJECTS.read().active.clone().filter(|p| p.keymap.iter().any(|c| Arc::ptr_eq(c, &chord)));
+		let path: PathBuf = match (alias, &project) {
+			(Some(_), _) => Xdg::config_dir().join("yazi.toml"),
+			(_, Some(p)) => p.root.join(Project::FILE),
+			_ => Xdg::config_dir().join("keymap.toml"),
+		};
+
+		let section = if project.is_some() { "keymap".to_owned() } else { self.layer.to_string() };
+		let line = std::fs::read_to_string(&path).ok().and_then(|s| match alias {
+			Some(a) => a.locate(&s),
+			None => chord.locate(&s, &section),
+		});
+
+		let Some(line) = line else {
+			return AppProxy::notify_warn(
+				"Help",
+				t!(
+					"`{chord}` is built in, rather than defined in `{path}`",
+					chord = chord.desc_or_run(),
+					path = path.display()
+				),
+			);
+		};
+
+		self.toggle(self.layer);
+		emit!(Call(Cmd::args("shell", &[editor(&path, line)]).with("block", true), Layer::Manager));
+	}
+}
+
+#[cfg(unix)]
+fn editor(path: &std::path::Path, line: usize) -> String {
+	let path = path.to_string_lossy();
+	format!("${{EDITOR:-vi}} +{line} {}", yazi_shared::shell::escape_unix(&path))
+}
+
+#[cfg(windows)]
+fn editor(path: &std::path::Path, line: usize) -> String {
+	let path = format!("{}:{line}", path.to_string_lossy());
+	format!("code -g {}", yazi_shared::shell::escape_windows(&path))
+}
@@ -0,0 +1,22 @@
+use yazi_config::{keymap::ChordCow, t};
+use yazi_macro::emit;
+use yazi_proxy::AppProxy;
+use yazi_shared::event::CmdCow;
+
+use crate::help::Help;
+
+impl Help {
+	pub fn run(&mut self, _: CmdCow) {
+		let Some(chord) = self.hovered().map(|b| b.chord.clone()) else { return };
+		if self.layer != self.origin {
+			return AppProxy::notify_warn(
+				"Help",
+				t!("Only the items of `{layer}` can be run", layer = self.origin),
+			);
+		}
+
+		let layer = self.layer;
+		self.toggle(layer);
+		emit!(Seq(ChordCow::from(chord).into_seq(), layer));
+	}
+}
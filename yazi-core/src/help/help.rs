@@ -1,18 +1,18 @@
 use crossterm::event::KeyCode;
-use unicode_width::UnicodeWidthStr;
 use yazi_adapter::Dimension;
-use yazi_config::{KEYMAP, keymap::{Chord, Key}};
+use yazi_config::{COMMAND, keymap::Key};
 use yazi_macro::{render, render_and};
-use yazi_shared::Layer;
+use yazi_shared::{Layer, Widths};
 
-use super::HELP_MARGIN;
-use crate::input::Input;
+use super::{HELP_MARGIN, HelpBinding};
+use crate::{input::Input, manager::CONTEXTS};
 
 #[derive(Default)]
 pub struct Help {
 	pub visible:         bool,
 	pub layer:           Layer,
-	pub(super) bindings: Vec<&'static Chord>,
+	pub(super) origin:   Layer,
+	pub(super) bindings: Vec<HelpBinding>,
 
 	// Filter
 	pub(super) keyword:   String,
@@ -29,6 +29,7 @@ impl Help {
 	pub fn toggle(&mut self, layer: Layer) {
 		self.visible = !self.visible;
 		self.layer = layer;
+		self.origin = layer;
 
 		self.keyword = String::new();
 		self.in_filter = None;
@@ -45,15 +46,39 @@ impl Help {
 		};
 
 		match key {
-			Key { code: KeyCode::Esc, shift: false, ctrl: false, alt: false, super_: false } => {
+			Key {
+				code: KeyCode::Esc,
+				shift: false,
+				ctrl: false,
+				alt: false,
+				super_: false,
+				hyper: false,
+				release: false,
+			} => {
 				self.in_filter = None;
 				render!();
 			}
-			Key { code: KeyCode::Enter, shift: false, ctrl: false, alt: false, super_: false } => {
+			Key {
+				code: KeyCode::Enter,
+				shift: false,
+				ctrl: false,
+				alt: false,
+				super_: false,
+				hyper: false,
+				release: false,
+			} => {
 				self.in_filter = None;
 				return render_and!(true); // Don't do the `filter_apply` below, since we already have the filtered results.
 			}
-			Key { code: KeyCode::Backspace, shift: false, ctrl: false, alt: false, super_: false } => {
+			Key {
+				code: KeyCode::Backspace,
+				shift: false,
+				ctrl: false,
+				alt: false,
+				super_: false,
+				hyper: false,
+				release: false,
+			} => {
 				input.backspace(false);
 			}
 			_ => {
@@ -65,19 +90,48 @@ impl Help {
 		true
 	}
 
+	pub fn paste_str(&mut self, s: &str) {
+		let Some(input) = &mut self.in_filter else { return };
+		input.paste_str(s);
+		self.filter_apply();
+	}
+
 	pub(super) fn filter_apply(&mut self) {
 		let kw = self.in_filter.as_ref().map_or("", |i| i.value());
 
 		if kw.is_empty() {
 			self.keyword = String::new();
-			self.bindings = KEYMAP.get(self.layer).iter().collect();
+			self.bindings = self.collect("");
 		} else if self.keyword != kw {
 			self.keyword = kw.to_owned();
-			self.bindings = KEYMAP.get(self.layer).iter().filter(|&c| c.contains(kw)).collect();
+			self.bindings = self.collect(kw);
 		}
 
 		self.arrow(0);
 	}
+
+	/// Chords of the layer, including the shadowed ones, followed by the command
+	/// aliases for the manager, which can only be run from here if they aren't
+	/// bound to any keys. Sorted by how well they match `kw` if it's not empty.
+	pub(super) fn collect(&self, kw: &str) -> Vec<HelpBinding> {
+		let mut bindings = HelpBinding::resolve(CONTEXTS.read().all(self.layer));
+		if self.layer == Layer::Manager {
+			bindings.extend(
+				COMMAND
+					.load()
+					.aliases
+					.iter()
+					.map(|a| HelpBinding { chord: a.chord.clone(), shadowed: None }),
+			);
+		}
+		if kw.is_empty() {
+			return bindings;
+		}
+
+		let mut scored: Vec<_> = bindings.into_iter().filter_map(|b| Some((b.score(kw)?, b))).collect();
+		scored.sort_by_key(|&(score, _)| score);
+		scored.into_iter().map(|(_, b)| b).collect()
+	}
 }
 
 impl Help {
@@ -94,7 +148,7 @@ impl Help {
 
 	// --- Bindings
 	#[inline]
-	pub fn window(&self) -> &[&Chord] {
+	pub fn window(&self) -> &[HelpBinding] {
 		let end = (self.offset + Self::limit()).min(self.bindings.len());
 		&self.bindings[self.offset..end]
 	}
@@ -106,11 +160,14 @@ impl Help {
 			return None;
 		}
 		if let Some(kw) = self.keyword() {
-			return Some((kw.width() as u16, Dimension::available().rows));
+			return Some((Widths::str(&kw) as u16, Dimension::available().rows));
 		}
 		None
 	}
 
 	#[inline]
 	pub fn rel_cursor(&self) -> usize { self.cursor - self.offset }
+
+	#[inline]
+	pub fn hovered(&self) -> Option<&HelpBinding> { self.bindings.get(self.cursor) }
 }
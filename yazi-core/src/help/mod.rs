@@ -1,5 +1,5 @@
 yazi_macro::mod_pub!(commands);
 
-yazi_macro::mod_flat!(help);
+yazi_macro::mod_flat!(binding help);
 
 pub const HELP_MARGIN: u16 = 1;
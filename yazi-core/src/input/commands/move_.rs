@@ -1,8 +1,7 @@
 use std::{num::ParseIntError, str::FromStr};
 
-use unicode_width::UnicodeWidthStr;
 use yazi_macro::render;
-use yazi_shared::event::{CmdCow, Data};
+use yazi_shared::{Widths, event::{CmdCow, Data}};
 
 use crate::input::{Input, op::InputOp, snap::InputSnap};
 
@@ -41,7 +40,7 @@ impl Input {
 		} else {
 			let delta = snap.mode.delta();
 			let s = snap.slice(snap.offset..snap.cursor + delta);
-			if s.width() >= limit {
+			if Widths::str(s) >= limit {
 				let s = s.chars().rev().collect::<String>();
 				snap.offset = snap.cursor - InputSnap::find_window(&s, 0, limit).end.saturating_sub(delta);
 			}
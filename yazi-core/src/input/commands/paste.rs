@@ -2,7 +2,7 @@ use yazi_macro::render;
 use yazi_plugin::CLIPBOARD;
 use yazi_shared::event::CmdCow;
 
-use crate::input::{Input, op::InputOp};
+use crate::input::{Input, InputMode, op::InputOp};
 
 struct Opt {
 	before: bool,
@@ -30,4 +30,41 @@ impl Input {
 		self.escape(());
 		render!();
 	}
+
+	/// Pastes the text from the terminal (bracketed paste) as a single edit,
+	/// rather than as keys, replacing the selection in the normal mode like `p`.
+	pub fn paste_str(&mut self, s: &str) {
+		let s = single_line(s);
+		if s.is_empty() {
+			return;
+		}
+
+		match self.mode() {
+			InputMode::Normal => {
+				if let Some(start) = self.snap().op.start() {
+					self.snap_mut().op = InputOp::Delete(false, false, start);
+					self.handle_op(self.snap().cursor, true);
+				}
+				self.insert(true);
+				self.type_str(&s);
+				self.escape(());
+			}
+			InputMode::Insert => self.type_str(&s),
+			InputMode::Replace => self.replace_str(&s),
+		}
+	}
+}
+
+/// The input is a single line, so the trailing line break that comes with a
+/// copied line is dropped, and the others become spaces.
+fn single_line(s: &str) -> String {
+	s.trim_end_matches(['\r', '\n'])
+		.replace("\r\n", "\n")
+		.chars()
+		.filter_map(|c| match c {
+			'\n' | '\r' | '\t' => Some(' '),
+			c if c.is_control() => None,
+			c => Some(c),
+		})
+		.collect()
 }
@@ -1,10 +1,9 @@
 use std::ops::Range;
 
 use tokio::sync::mpsc::UnboundedSender;
-use unicode_width::UnicodeWidthStr;
 use yazi_config::{INPUT, popup::Position};
 use yazi_plugin::CLIPBOARD;
-use yazi_shared::errors::InputError;
+use yazi_shared::{Widths, errors::InputError};
 
 use super::{InputSnap, InputSnaps, mode::InputMode, op::InputOp};
 
@@ -29,7 +28,7 @@ pub struct Input {
 impl Input {
 	#[inline]
 	pub(super) fn limit(&self) -> usize {
-		self.position.offset.width.saturating_sub(INPUT.border()) as usize
+		self.position.offset.width.saturating_sub(INPUT.load().border()) as usize
 	}
 
 	pub(super) fn handle_op(&mut self, cursor: usize, include: bool) -> bool {
@@ -99,7 +98,7 @@ impl Input {
 	#[inline]
 	pub fn cursor(&self) -> u16 {
 		let snap = self.snap();
-		snap.slice(snap.offset..snap.cursor).width() as u16
+		Widths::str(snap.slice(snap.offset..snap.cursor)) as u16
 	}
 
 	pub fn selected(&self) -> Option<Range<u16>> {
@@ -112,8 +111,8 @@ impl Input {
 		let win = snap.window(self.limit());
 		let Range { start, end } = start.max(win.start)..end.min(win.end);
 
-		let s = snap.slice(snap.offset..start).width() as u16;
-		Some(s..s + snap.slice(start..end).width() as u16)
+		let s = Widths::str(snap.slice(snap.offset..start)) as u16;
+		Some(s..s + Widths::str(snap.slice(start..end)) as u16)
 	}
 
 	#[inline]
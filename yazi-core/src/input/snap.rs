@@ -1,6 +1,6 @@
 use std::ops::Range;
 
-use unicode_width::UnicodeWidthChar;
+use yazi_shared::Widths;
 
 use super::{InputMode, InputOp};
 
@@ -77,7 +77,7 @@ impl InputSnap {
 			.enumerate()
 			.skip(offset)
 			.map_while(|(i, c)| {
-				width += c.width().unwrap_or(0);
+				width += Widths::char(c);
 				if width < limit { Some(i) } else { None }
 			})
 			.collect();
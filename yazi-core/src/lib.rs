@@ -6,9 +6,15 @@
 	clippy::unit_arg
 )]
 
-yazi_macro::mod_pub!(completion confirm help input manager notify pick spot tab tasks which);
+yazi_macro::mod_pub!(completion confirm excerpt help input manager notify output pick spot tab tasks terminal watch which);
 
 pub fn init() {
 	manager::WATCHED.with(<_>::default);
 	manager::LINKED.with(<_>::default);
+	manager::INDEX.with(<_>::default);
+	manager::CONTEXTS.with(<_>::default);
+	manager::CHOICES.with(|| parking_lot::RwLock::new(manager::Choices::load()));
+	manager::PROJECTS.with(|| parking_lot::RwLock::new(manager::Projects::load()));
+	manager::RECENTS.with(|| parking_lot::RwLock::new(manager::Recents::load()));
+	manager::JOURNAL.with(<_>::default);
 }
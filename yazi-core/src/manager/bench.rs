@@ -0,0 +1,164 @@
+use std::{fmt::Write, path::{Path, PathBuf}, time::{Duration, Instant}};
+
+use anyhow::{Result, bail};
+use ratatui::layout::Size;
+use tokio::fs;
+use yazi_boot::actions::Actions;
+use yazi_fs::{Files, FilesSorter, SortBy};
+use yazi_plugin::external::Highlighter;
+use yazi_shared::{errors::PeekError, url::Url};
+
+/// How many times each step is run, where the median of them is reported.
+const RUNS: usize = 5;
+
+/// The area that a preview is rendered in, about a half of a usual terminal.
+const AREA: Size = Size { width: 80, height: 40 };
+
+/// The source of each kind of text file to preview, repeated to the lines
+/// wanted.
+const SOURCES: [(&str, &str); 5] = [
+	(
+		"rs",
+		"fn main() {\n\tlet v: Vec<u64> = (0..10).map(|i| i * 2).collect();\n\tprintln!(\"{v:?}\");\n}\n",
+	),
+	("py", "def main():\n    v = [i * 2 for i in range(10)]\n    print(f\"{v}\")\n\n"),
+	("json", "{\"id\": 1, \"name\": \"yazi\", \"tags\": [\"a\", \"b\"], \"ok\": true},\n"),
+	("md", "## Heading\n\nSome *emphasis*, a [link](https://example.com) and `code`.\n\n"),
+	("txt", "The quick brown fox jumps over the lazy dog, 0123456789.\n"),
+];
+
+/// A synthetic directory to measure the throughput of listing, sorting and
+/// previewing in, which is removed once it's dropped.
+pub struct Bench {
+	dir: PathBuf,
+	out: String,
+}
+
+impl Bench {
+	pub async fn new(name: &str) -> Result<Self> {
+		let dir = std::env::temp_dir().join(format!("yazi-bench-{name}-{}", std::process::id()));
+		fs::remove_dir_all(&dir).await.ok();
+		fs::create_dir_all(&dir).await?;
+
+		let mut out = String::new();
+		writeln!(out, "Yazi {}", Actions::version())?;
+		writeln!(out, "Build: {}", if cfg!(debug_assertions) { "debug" } else { "release" })?;
+		writeln!(out, "OS   : {}-{}", std::env::consts::OS, std::env::consts::ARCH)?;
+		writeln!(out, "Dir  : {}\n", dir.display())?;
+		Ok(Self { dir, out })
+	}
+
+	/// Lists a directory of `n` files, and sorts them by each of the methods.
+	pub async fn list(mut self, n: usize) -> Result<String> {
+		let at = Instant::now();
+		for i in 0..n {
+			if i % 20 == 0 {
+				fs::create_dir(self.dir.join(format!("dir-{i}"))).await?;
+				continue;
+			}
+
+			let ext = ["txt", "rs", "jpg", "md", "tar.gz"][i % 5];
+			let name = match i % 3 {
+				0 => format!("file-{i}.{ext}"),
+				1 => format!("File {} v{}.{ext}", n - i, i % 17),
+				_ => format!(".hidden-{i}.{ext}"),
+			};
+			fs::File::create(self.dir.join(name)).await?.set_len((i * 7919 % 65536) as u64).await?;
+		}
+		self.row(&format!("create {n} files"), at.elapsed(), None);
+
+		let url = Url::from(self.dir.clone());
+		let mut taken = Vec::with_capacity(RUNS);
+		let mut items = vec![];
+		for _ in 0..RUNS {
+			let at = Instant::now();
+			items = Files::from_dir_bulk(&url).await?;
+			taken.push(at.elapsed());
+		}
+		self.row("read", median(taken), Some(n));
+
+		let mut files = Files::new(true);
+		files.update_full(items);
+		for by in [
+			SortBy::Natural,
+			SortBy::Alphabetical,
+			SortBy::Extension,
+			SortBy::Mtime,
+			SortBy::Size,
+			SortBy::Random,
+		] {
+			let mut taken = Vec::with_capacity(RUNS);
+			for reverse in [false, true].into_iter().cycle().take(RUNS) {
+				files.set_sorter(FilesSorter { by, reverse, dir_first: true, ..Default::default() });
+				let at = Instant::now();
+				files.catchup_revision();
+				taken.push(at.elapsed());
+			}
+			self.row(&format!("sort by {by}"), median(taken), Some(n));
+		}
+		Ok(self.finish())
+	}
+
+	/// Previews `n` text files of `lines` lines of each kind, from the top and
+	/// scrolled to the end.
+	pub async fn preview(mut self, n: usize, lines: usize) -> Result<String> {
+		let at = Instant::now();
+		let mut paths = Vec::with_capacity(SOURCES.len());
+		for (ext, src) in SOURCES {
+			let content = src.repeat(lines.div_ceil(src.lines().count()));
+			let mut v = Vec::with_capacity(n);
+			for i in 0..n {
+				let p = self.dir.join(format!("{i}.{ext}"));
+				fs::write(&p, &content).await?;
+				v.push(p);
+			}
+			paths.push((ext, v));
+		}
+		self.row(&format!("create {} files", n * SOURCES.len()), at.elapsed(), None);
+
+		for (ext, v) in paths {
+			for (what, skip) in [("top", 0), ("end", lines.saturating_sub(AREA.height as usize))] {
+				let mut taken = Vec::with_capacity(n);
+				for p in &v {
+					taken.push(Self::highlight(p, skip).await?);
+				}
+				self.row(&format!("preview .{ext} {what}"), median(taken), Some(1));
+			}
+		}
+		Ok(self.finish())
+	}
+
+	async fn highlight(path: &Path, skip: usize) -> Result<Duration> {
+		// It's cancelled whenever the preview changes, e.g. by the files created here
+		for _ in 0..RUNS {
+			let at = Instant::now();
+			match Highlighter::new(path).highlight(skip, AREA).await {
+				Ok(_) => return Ok(at.elapsed()),
+				Err(PeekError::Unexpected(s)) if s == "Highlighting cancelled" => continue,
+				Err(e) => bail!("{e}"),
+			}
+		}
+		bail!("Highlighting was cancelled by the preview every time")
+	}
+
+	/// A row of the report, with the throughput of `n` items if any.
+	fn row(&mut self, step: &str, taken: Duration, n: Option<usize>) {
+		let ms = taken.as_secs_f64() * 1000.0;
+		let rate = match n {
+			Some(n) if ms > 0.0 => format!("{:>12.0} /s", n as f64 / taken.as_secs_f64()),
+			_ => String::new(),
+		};
+		_ = writeln!(self.out, "  {step:<24} {ms:>10.2}ms {rate}");
+	}
+
+	fn finish(mut self) -> String { std::mem::take(&mut self.out) }
+}
+
+impl Drop for Bench {
+	fn drop(&mut self) { std::fs::remove_dir_all(&self.dir).ok(); }
+}
+
+fn median(mut v: Vec<Duration>) -> Duration {
+	v.sort_unstable();
+	v.get(v.len() / 2).copied().unwrap_or_default()
+}
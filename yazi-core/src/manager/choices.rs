@@ -0,0 +1,54 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use parking_lot::RwLock;
+use yazi_boot::BOOT;
+use yazi_config::open::Opener;
+use yazi_shared::{RoCell, url::Url};
+
+use super::Persist;
+
+pub static CHOICES: RoCell<RwLock<Choices>> = RoCell::new();
+
+#[derive(Default)]
+pub struct Choices(HashMap<String, String> /* extension ==> opener's `run` */);
+
+impl Choices {
+	pub(crate) fn load() -> Self {
+		let Ok(s) = std::fs::read_to_string(Self::path()) else { return Self::default() };
+		Self(toml::from_str(&s).unwrap_or_default())
+	}
+
+	pub fn find<'a>(&self, url: &Url, openers: &'a [Opener]) -> Option<&'a Opener> {
+		let run = self.0.get(&Self::ext(url)?)?;
+		openers.iter().find(|o| o.run == *run)
+	}
+
+	pub fn rank(&self, urls: &[Url], openers: &mut [Opener]) {
+		let Some(run) = Self::common_ext(urls).and_then(|e| self.0.get(&e)) else { return };
+		if let Some(i) = openers.iter().position(|o| o.run == *run) {
+			openers[..=i].rotate_right(1);
+		}
+	}
+
+	pub fn remember(&mut self, urls: &[Url], opener: &Opener) {
+		let Some(ext) = Self::common_ext(urls) else { return };
+		if self.0.get(&ext).is_some_and(|r| *r == opener.run) {
+			return;
+		}
+
+		self.0.insert(ext, opener.run.clone());
+		let Ok(s) = toml::to_string(&self.0) else { return };
+		Persist { path: Self::path(), content: Some(s), what: "opener choices" }.queue();
+	}
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join("choices.toml") }
+
+	#[inline]
+	fn ext(url: &Url) -> Option<String> { Some(url.extension()?.to_str()?.to_lowercase()) }
+
+	fn common_ext(urls: &[Url]) -> Option<String> {
+		let first = Self::ext(urls.first()?)?;
+		urls.iter().skip(1).all(|u| Self::ext(u).as_ref() == Some(&first)).then_some(first)
+	}
+}
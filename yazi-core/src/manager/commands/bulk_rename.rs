@@ -1,19 +1,22 @@
-use std::{borrow::Cow, collections::HashMap, ffi::{OsStr, OsString}, io::{BufWriter, Write, stderr}, path::PathBuf};
+use std::{borrow::Cow, collections::{HashMap, HashSet}, ffi::{OsStr, OsString}, io::{BufWriter, Write, stderr}, path::{Path, PathBuf}, time::{SystemTime, UNIX_EPOCH}};
 
 use anyhow::{Result, anyhow};
 use scopeguard::defer;
 use tokio::{fs::{self, OpenOptions}, io::{AsyncReadExt, AsyncWriteExt, stdin}};
 use yazi_config::{OPEN, PREVIEW};
 use yazi_dds::Pubsub;
-use yazi_fs::{File, FilesOp, max_common_root, maybe_exists, paths_to_same_file};
-use yazi_proxy::{AppProxy, HIDER, TasksProxy, WATCHER};
-use yazi_shared::{terminal_clear, url::Url};
+use yazi_fs::{File, FilesOp, max_common_root, maybe_exists, paths_to_same_file, same_name_in};
+use yazi_proxy::{AppProxy, HIDER, ManagerProxy, TasksProxy, WATCHER};
+use yazi_shared::{bytes_to_os_str, terminal_clear, url::Url};
 
-use crate::manager::Manager;
+use crate::{manager::Manager, tab::Tab};
+
+/// A rename that failed, as from and to, and why.
+type Failed = (PathBuf, PathBuf, anyhow::Error);
 
 impl Manager {
 	pub(super) fn bulk_rename(&self) {
-		let Some(opener) = OPEN.block_opener("bulk-rename.txt", "text/plain") else {
+		let Some(opener) = OPEN.load().block_opener("bulk-rename.txt", "text/plain") else {
 			return AppProxy::notify_warn("Bulk rename", "No text opener found");
 		};
 
@@ -24,7 +27,7 @@ impl Manager {
 
 		let cwd = self.cwd().clone();
 		tokio::spawn(async move {
-			let tmp = PREVIEW.tmpfile("bulk");
+			let tmp = PREVIEW.load().tmpfile("bulk");
 			let s = old.iter().map(|o| o.as_os_str()).collect::<Vec<_>>().join(OsStr::new("\n"));
 			OpenOptions::new()
 				.write(true)
@@ -35,7 +38,7 @@ impl Manager {
 				.await?;
 
 			defer! { tokio::spawn(fs::remove_file(tmp.clone())); }
-			TasksProxy::process_exec(Cow::Borrowed(opener), cwd, vec![
+			TasksProxy::process_exec(Cow::Owned(opener), cwd, vec![
 				OsString::new(),
 				tmp.to_owned().into(),
 			])
@@ -45,7 +48,15 @@ impl Manager {
 			defer!(AppProxy::resume());
 			AppProxy::stop().await;
 
-			let new: Vec<_> = fs::read_to_string(&tmp).await?.lines().map(PathBuf::from).collect();
+			let buf = fs::read(&tmp).await?;
+			let new: Vec<_> = buf
+				.strip_suffix(b"\n")
+				.unwrap_or(&buf)
+				.split(|&b| b == b'\n')
+				.filter(|_| !buf.is_empty())
+				.map(|l| l.strip_suffix(b"\r").unwrap_or(l))
+				.map(|l| bytes_to_os_str(l).map(PathBuf::from))
+				.collect::<Result<_, _>>()?;
 			Self::bulk_rename_do(root, old, new).await
 		});
 	}
@@ -79,43 +90,156 @@ impl Manager {
 			return Ok(());
 		}
 
+		if !Tab::confine(todo.iter().map(|(_, n)| root.join(n))) {
+			return Ok(());
+		} else if let Err(failed) = Self::bulk_rename_check(&root, &todo).await {
+			return Self::output_failed(failed, vec![]).await;
+		}
+
 		let permit = WATCHER.acquire().await.unwrap();
-		let (mut failed, mut succeeded) = (Vec::new(), HashMap::with_capacity(todo.len()));
+		let result = Self::bulk_rename_apply(&root, &todo).await;
+
+		let (failed, stranded) = match result {
+			Ok(succeeded) => {
+				if !succeeded.is_empty() {
+					Pubsub::pub_from_bulk(succeeded.iter().map(|(o, n)| (o, &n.url)).collect());
+					ManagerProxy::update_tags(
+						succeeded.iter().map(|(o, n)| (o.clone(), n.url_owned())).collect(),
+					);
+					FilesOp::rename(succeeded);
+				}
+				return Ok(());
+			}
+			Err(e) => e,
+		};
+
+		drop(permit);
+		Self::output_failed(vec![failed], stranded).await
+	}
+
+	/// Finds what would fail before renaming anything: the destinations that are
+	/// taken by files not being renamed away, or by more than one of them.
+	async fn bulk_rename_check(root: &Path, todo: &[(PathBuf, PathBuf)]) -> Result<(), Vec<Failed>> {
+		let olds: HashSet<_> = todo.iter().map(|(o, _)| root.join(o)).collect();
+		let mut news = HashSet::with_capacity(todo.len());
+
+		let mut failed = vec![];
 		for (o, n) in todo {
-			let (old, new) = (root.join(&o), root.join(&n));
-
-			if maybe_exists(&new).await && !paths_to_same_file(&old, &new).await {
-				failed.push((o, n, anyhow!("Destination already exists")));
-			} else if let Err(e) = fs::rename(&old, &new).await {
-				failed.push((o, n, e.into()));
-			} else if let Ok(f) = File::from(new.into()).await {
-				succeeded.insert(Url::from(old), f);
-			} else {
-				failed.push((o, n, anyhow!("Failed to retrieve file info")));
+			let (old, new) = (root.join(o), root.join(n));
+			if !news.insert(new.clone()) {
+				failed.push((o.clone(), n.clone(), anyhow!("Destination is duplicated")));
+			} else if maybe_exists(&new).await
+				&& !olds.contains(&new)
+				&& !Self::same_name(&old, &new).await
+				&& !Self::taken_by_any(&olds, &new).await
+			{
+				failed.push((o.clone(), n.clone(), anyhow!("Destination already exists")));
 			}
 		}
 
-		if !succeeded.is_empty() {
-			Pubsub::pub_from_bulk(succeeded.iter().map(|(o, n)| (o, &n.url)).collect());
-			FilesOp::rename(succeeded);
+		if failed.is_empty() { Ok(()) } else { Err(failed) }
+	}
+
+	/// Renames in two phases, all to temporary names first and then to the new
+	/// ones, so swaps and cycles just work, and on the first failure undoes the
+	/// done ones, so it's never left half renamed.
+	///
+	/// Returns the files renamed, or what failed and what couldn't be restored.
+	async fn bulk_rename_apply(
+		root: &Path,
+		todo: &[(PathBuf, PathBuf)],
+	) -> Result<HashMap<Url, File>, (Failed, Vec<Failed>)> {
+		let mut temps = Vec::with_capacity(todo.len());
+		for (o, n) in todo {
+			let old = root.join(o);
+			let tmp = Self::temp_name(&old).await;
+			if let Err(e) = fs::rename(&old, &tmp).await {
+				let moved = todo.iter().zip(temps).map(|((o, _), t)| (t, root.join(o)));
+				return Err(((o.clone(), n.clone(), e.into()), Self::rollback(moved).await));
+			}
+			temps.push(tmp);
+		}
+
+		for (i, (o, n)) in todo.iter().enumerate() {
+			if let Err(e) = fs::rename(&temps[i], root.join(n)).await {
+				let renamed = todo[..i].iter().zip(&temps).map(|((_, n), t)| (root.join(n), t.clone()));
+				let mut stranded = Self::rollback(renamed).await;
+
+				// Those still at their new names can't be moved back from the temporary ones
+				let moved = todo.iter().zip(temps).map(|((o, _), t)| (t, root.join(o)));
+				let moved: Vec<_> =
+					moved.filter(|(t, _)| stranded.iter().all(|(_, to, _)| to != t)).collect();
+				stranded.extend(Self::rollback(moved.into_iter()).await);
+				return Err(((o.clone(), n.clone(), e.into()), stranded));
+			}
 		}
-		drop(permit);
 
-		if !failed.is_empty() {
-			Self::output_failed(failed).await?;
+		let mut succeeded = HashMap::with_capacity(todo.len());
+		for (o, n) in todo {
+			if let Ok(f) = File::from(root.join(n).into()).await {
+				succeeded.insert(Url::from(root.join(o)), f);
+			}
 		}
-		Ok(())
+		Ok(succeeded)
 	}
 
-	async fn output_failed(failed: Vec<(PathBuf, PathBuf, anyhow::Error)>) -> Result<()> {
+	/// Renames each back, returning the ones that failed, as where they are left
+	/// and where they were.
+	async fn rollback(moves: impl Iterator<Item = (PathBuf, PathBuf)>) -> Vec<Failed> {
+		let mut stranded = vec![];
+		for (from, to) in moves {
+			if let Err(e) = fs::rename(&from, &to).await {
+				stranded.push((from, to, e.into()));
+			}
+		}
+		stranded
+	}
+
+	async fn temp_name(p: &Path) -> PathBuf {
+		let name = p.file_name().unwrap_or_default().to_string_lossy();
+		let nonce = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.subsec_nanos());
+		for i in 0.. {
+			let tmp = p.with_file_name(format!(".{name}.{nonce:x}{i}.yazi-rename"));
+			if !maybe_exists(&tmp).await {
+				return tmp;
+			}
+		}
+		unreachable!()
+	}
+
+	/// Whether `new` is taken by one of the files being renamed, through another
+	/// path or name, like on a case-insensitive filesystem.
+	async fn taken_by_any(olds: &HashSet<PathBuf>, new: &Path) -> bool {
+		for old in olds {
+			if paths_to_same_file(old, new).await {
+				return true;
+			}
+		}
+		false
+	}
+
+	async fn same_name(old: &Path, new: &Path) -> bool {
+		match (old.parent(), old.file_name(), new.file_name()) {
+			(Some(p), Some(a), Some(b)) if Some(p) == new.parent() => same_name_in(p, a, b).await,
+			_ => false,
+		}
+	}
+
+	async fn output_failed(failed: Vec<Failed>, stranded: Vec<Failed>) -> Result<()> {
 		terminal_clear(&mut stderr())?;
 
 		{
 			let mut stderr = BufWriter::new(stderr().lock());
-			writeln!(stderr, "Failed to rename:")?;
+			writeln!(stderr, "Failed to rename, so nothing is renamed:")?;
 			for (o, n, e) in failed {
 				writeln!(stderr, "{} -> {}: {e}", o.display(), n.display())?;
 			}
+			if !stranded.is_empty() {
+				writeln!(stderr, "\nBut these couldn't be renamed back, and are left where they are:")?;
+				for (at, was, e) in stranded {
+					writeln!(stderr, "{} (was {}): {e}", at.display(), was.display())?;
+				}
+			}
 			writeln!(stderr, "\nPress ENTER to exit")?;
 			stderr.flush()?;
 		}
@@ -165,6 +289,8 @@ impl Manager {
 
 #[cfg(test)]
 mod tests {
+	use tokio::fs;
+
 	use super::*;
 
 	#[test]
@@ -209,4 +335,49 @@ mod tests {
 			&[("b", "b_"), ("a", "a_"), ("c", "c_")],
 		);
 	}
+
+	#[tokio::test]
+	async fn test_rollback() {
+		let root = Path::new("/tmp/yazi-bulk-rename");
+		fs::remove_dir_all(root).await.ok();
+		fs::create_dir_all(root).await.unwrap();
+		for (name, content) in [("a", "a"), ("b", "b"), ("c", "c")] {
+			fs::write(root.join(name), content).await.unwrap();
+		}
+
+		async fn read(root: &Path) -> Vec<(String, String)> {
+			let mut it = fs::read_dir(root).await.unwrap();
+			let mut files = vec![];
+			while let Some(entry) = it.next_entry().await.unwrap() {
+				let content = fs::read_to_string(entry.path()).await.unwrap();
+				files.push((entry.file_name().to_string_lossy().into_owned(), content));
+			}
+			files.sort();
+			files
+		}
+		let todo =
+			|v: &[(&str, &str)]| v.iter().map(|&(o, n)| (o.into(), n.into())).collect::<Vec<_>>();
+
+		// Swapped through the temporary names
+		let done = Manager::bulk_rename_apply(root, &todo(&[("a", "b"), ("b", "a")])).await.unwrap();
+		assert_eq!(done.len(), 2);
+		assert_eq!(read(root).await, [
+			("a".into(), "b".into()),
+			("b".into(), "a".into()),
+			("c".into(), "c".into())
+		]);
+
+		// The last one fails, so all of them are undone
+		let (failed, stranded) =
+			Manager::bulk_rename_apply(root, &todo(&[("a", "x"), ("b", "y"), ("c", "missing/z")]))
+				.await
+				.unwrap_err();
+		assert_eq!(failed.0, Path::new("c"));
+		assert!(stranded.is_empty());
+		assert_eq!(read(root).await, [
+			("a".into(), "b".into()),
+			("b".into(), "a".into()),
+			("c".into(), "c".into())
+		]);
+	}
 }
@@ -0,0 +1,135 @@
+use std::{fs, path::{Path, PathBuf}};
+
+use yazi_config::popup::{ConfirmCfg, PickCfg};
+use yazi_proxy::{AppProxy, ConfirmProxy, ManagerProxy, PickProxy};
+use yazi_shared::{event::CmdCow, url::Url};
+
+use crate::manager::Manager;
+
+struct Opt {
+	permanently: bool,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self { Self { permanently: c.bool("permanently") } }
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn cleanup(&mut self, opt: Opt) {
+		let cwd = self.cwd().clone();
+		if !cwd.is_regular() {
+			return AppProxy::notify_warn("Clean up", "Only regular directories can be cleaned up");
+		}
+
+		let hidden = self.active().pref.show_hidden;
+		tokio::spawn(async move {
+			let root = cwd.to_path();
+			let Ok(junk) = tokio::task::spawn_blocking(move || Junk::scan(&root, hidden)).await else {
+				return;
+			};
+
+			let groups: Vec<_> = junk.groups().into_iter().filter(|(_, v)| !v.is_empty()).collect();
+			if groups.is_empty() {
+				return AppProxy::notify_warn("Clean up", "Nothing to clean up");
+			}
+
+			let mut items: Vec<_> = groups.iter().map(|(t, v)| format!("{t} ({})", v.len())).collect();
+			if groups.len() > 1 {
+				items.push(format!(
+					"All of the above ({})",
+					groups.iter().map(|(_, v)| v.len()).sum::<usize>()
+				));
+			}
+
+			let Ok(choice) = PickProxy::show(PickCfg::cleanup(items)).await else { return };
+			let chosen = if choice < groups.len() { &groups[choice..=choice] } else { &groups[..] };
+
+			let mut lines = vec![];
+			for (title, entries) in chosen {
+				lines.push(format!("{title}:"));
+				lines.extend(entries.iter().map(|e| format!("  {}", e.display(&cwd))));
+			}
+
+			let targets: Vec<_> =
+				chosen.iter().flat_map(|(_, v)| v.iter()).map(|e| Url::from(&e.path)).collect();
+			if ConfirmProxy::show(ConfirmCfg::cleanup(targets.len(), lines)).await {
+				ManagerProxy::remove_do(targets, opt.permanently, false);
+			}
+		});
+	}
+}
+
+// --- Junk
+#[derive(Default)]
+struct Junk {
+	broken: Vec<Entry>,
+	dirs:   Vec<Entry>,
+	files:  Vec<Entry>,
+}
+
+struct Entry {
+	path:   PathBuf,
+	target: Option<PathBuf>,
+	dir:    bool,
+}
+
+impl Junk {
+	fn scan(root: &Path, hidden: bool) -> Self {
+		let mut junk = Self::default();
+		let mut stack = vec![root.to_owned()];
+
+		while let Some(dir) = stack.pop() {
+			let Ok(it) = fs::read_dir(&dir) else { continue };
+			let mut empty = true;
+
+			for entry in it.flatten() {
+				empty = false;
+				if !hidden && entry.file_name().as_encoded_bytes().starts_with(b".") {
+					continue;
+				}
+
+				let path = entry.path();
+				let Ok(meta) = entry.metadata() else { continue };
+				if meta.is_symlink() {
+					if fs::metadata(&path).is_err() {
+						let target = fs::read_link(&path).ok();
+						junk.broken.push(Entry { path, target, dir: false });
+					}
+				} else if meta.is_dir() {
+					stack.push(path);
+				} else if meta.is_file() && meta.len() == 0 {
+					junk.files.push(Entry { path, target: None, dir: false });
+				}
+			}
+
+			if empty && dir != root {
+				junk.dirs.push(Entry { path: dir, target: None, dir: true });
+			}
+		}
+
+		junk.broken.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+		junk.dirs.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+		junk.files.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+		junk
+	}
+
+	fn groups(self) -> [(&'static str, Vec<Entry>); 3] {
+		[
+			("Broken symlinks", self.broken),
+			("Empty directories", self.dirs),
+			("Zero-byte files", self.files),
+		]
+	}
+}
+
+impl Entry {
+	fn display(&self, cwd: &Url) -> String {
+		let rel = self.path.strip_prefix(cwd).unwrap_or(&self.path).display();
+		match &self.target {
+			Some(t) => format!("{rel} -> {}", t.display()),
+			None if self.dir => format!("{rel}/"),
+			None => rel.to_string(),
+		}
+	}
+}
@@ -0,0 +1,60 @@
+use yazi_config::{MANAGER, t};
+use yazi_proxy::AppProxy;
+use yazi_shared::event::{CmdCow, Data};
+
+use crate::manager::Manager;
+
+struct Opt {
+	column: String,
+	width:  OptWidth,
+}
+
+enum OptWidth {
+	Reset,
+	Set(u16),
+	Adjust(i16),
+}
+
+impl From<CmdCow> for Opt {
+	fn from(mut c: CmdCow) -> Self {
+		let width = match c.second() {
+			Some(Data::String(s)) if s.starts_with(['+', '-']) => {
+				s.parse().map_or(OptWidth::Reset, OptWidth::Adjust)
+			}
+			Some(d) => d.as_usize().map_or(OptWidth::Reset, |n| OptWidth::Set(n.min(u16::MAX as _) as _)),
+			None => OptWidth::Reset,
+		};
+
+		Self { column: c.take_first_str().unwrap_or_default().into_owned(), width }
+	}
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn column_width(&mut self, opt: Opt) {
+		let manager = MANAGER.load();
+		let Some(column) = manager.columns.iter().find(|c| c.name == opt.column) else {
+			return AppProxy::notify_warn(
+				"`column_width` command",
+				t!("No such column: {column}", column = opt.column),
+			);
+		};
+		let Some(base) = column.width else {
+			return AppProxy::notify_warn(
+				"`column_width` command",
+				"The filling column cannot be resized",
+			);
+		};
+
+		let current = self.panes.widths.get(&column.name).copied().unwrap_or(base);
+		let width = match opt.width {
+			OptWidth::Reset => None,
+			OptWidth::Set(n) => Some(n.max(1)),
+			OptWidth::Adjust(d) => Some(current.saturating_add_signed(d).max(1)),
+		}
+		.filter(|&w| w != base);
+		if self.panes.set_width(&column.name, width) {
+			AppProxy::resize();
+		}
+	}
+}
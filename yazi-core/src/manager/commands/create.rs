@@ -1,48 +1,112 @@
-use std::collections::{HashMap, HashSet};
+use std::{collections::{HashMap, HashSet}, path::PathBuf};
 
 use anyhow::Result;
 use tokio::fs;
-use yazi_config::popup::{ConfirmCfg, InputCfg};
-use yazi_fs::{File, FilesOp, maybe_exists, ok_or_not_found, realname};
-use yazi_proxy::{ConfirmProxy, InputProxy, TabProxy, WATCHER};
-use yazi_shared::{event::CmdCow, url::{Url, UrnBuf}};
+use yazi_config::{CONFIRM, MANAGER, TEMPLATE, popup::{ConfirmCfg, InputCfg}, t};
+use yazi_fs::{Conflict, File, FilesOp, expand_input, maybe_exists, ok_or_not_found, realname};
+use yazi_proxy::{AppProxy, ConfirmProxy, InputProxy, TabProxy, WATCHER};
+use yazi_shared::{errors::ErrorReport, event::CmdCow, url::{Url, UrnBuf}};
 
-use crate::manager::Manager;
+use crate::{manager::{Manager, Skeleton}, tab::Tab};
 
 struct Opt {
-	dir:   bool,
-	force: bool,
+	name:     Option<String>,
+	dir:      bool,
+	force:    bool,
+	template: Option<String>,
 }
 
 impl From<CmdCow> for Opt {
-	fn from(c: CmdCow) -> Self { Self { dir: c.bool("dir"), force: c.bool("force") } }
+	fn from(mut c: CmdCow) -> Self {
+		Self {
+			name:     c.take_first_str().map(|s| s.into_owned()),
+			dir:      c.bool("dir"),
+			force:    c.bool("force"),
+			template: c.take_str("template").map(|s| s.into_owned()),
+		}
+	}
 }
 
 impl Manager {
 	#[yazi_codegen::command]
-	pub fn create(&self, opt: Opt) {
+	pub fn create(&self, mut opt: Opt) {
 		let cwd = self.cwd().to_owned();
 		tokio::spawn(async move {
-			let mut result = InputProxy::show(InputCfg::create(opt.dir));
-			let Some(Ok(name)) = result.recv().await else {
-				return Ok(());
+			let name = match opt.name.take() {
+				Some(name) => name,
+				None => {
+					let mut result = InputProxy::show(InputCfg::create(opt.dir));
+					let Some(Ok(name)) = result.recv().await else {
+						return Ok(());
+					};
+					name
+				}
 			};
 			if name.is_empty() {
 				return Ok(());
 			}
 
-			let new = cwd.join(&name);
-			if !opt.force
-				&& maybe_exists(&new).await
-				&& !ConfirmProxy::show(ConfirmCfg::overwrite(&new)).await
-			{
+			let Some(news) = Self::expand_input(&cwd, &name).await else { return Ok(()) };
+			if !Tab::confine(&news) {
 				return Ok(());
 			}
 
-			Self::create_do(new, opt.dir || name.ends_with('/') || name.ends_with('\\')).await
+			let dir = opt.dir || name.ends_with('/') || name.ends_with('\\');
+			for new in news {
+				if let Err(e) = Self::create_one(new.clone(), dir, &opt).await {
+					AppProxy::notify_report("Create", ErrorReport::from(&e).with_path(&new));
+					break;
+				}
+			}
+			anyhow::Ok(())
 		});
 	}
 
+	async fn create_one(mut new: Url, dir: bool, opt: &Opt) -> Result<()> {
+		if !opt.force && maybe_exists(&new).await {
+			match MANAGER.load().conflict {
+				Conflict::Ask => {
+					if CONFIRM.load().overwrite_policy.ask(1)
+						&& !ConfirmProxy::show(ConfirmCfg::overwrite(&new)).await
+					{
+						return Ok(());
+					}
+				}
+				Conflict::Overwrite => {}
+				Conflict::Skip => return Ok(()),
+				c => new = c.rename(new, async { dir }).await?,
+			}
+		}
+
+		let source = match &opt.template {
+			Some(id) => match Skeleton::find(id).await {
+				Some(p) => Some(p),
+				None => {
+					return Ok(AppProxy::notify_warn("Create", t!("Template `{id}` not found", id = id)));
+				}
+			},
+			None => TEMPLATE.by_path(&new, dir),
+		};
+
+		match source {
+			Some(source) => Self::create_from(new, source).await,
+			None => Self::create_do(new, dir).await,
+		}
+	}
+
+	/// The paths `input` expands to, relative to `base`, after they're confirmed
+	/// if anything was expanded at all.
+	pub(super) async fn expand_input(base: &Url, input: &str) -> Option<Vec<Url>> {
+		let urls: Vec<_> = expand_input(input).into_iter().map(|p| base.join(p)).collect();
+		if urls.len() == 1 && urls[0] == base.join(input) {
+			return Some(urls);
+		}
+
+		let go = !CONFIRM.load().expand_policy.ask(urls.len())
+			|| ConfirmProxy::show(ConfirmCfg::expand(&urls)).await;
+		Some(urls).filter(|_| go)
+	}
+
 	async fn create_do(new: Url, dir: bool) -> Result<()> {
 		let Some(parent) = new.parent_url() else { return Ok(()) };
 		let _permit = WATCHER.acquire().await.unwrap();
@@ -59,10 +123,42 @@ impl Manager {
 			fs::File::create(&new).await?;
 		}
 
+		Self::create_reveal(parent, new).await;
+		Ok(())
+	}
+
+	async fn create_from(new: Url, source: PathBuf) -> Result<()> {
+		let Some(parent) = new.parent_url() else { return Ok(()) };
+		let skeleton = match Skeleton::load(source).await {
+			Ok(s) => s,
+			Err(e) => {
+				return Ok(AppProxy::notify_warn("Create", t!("Failed to load the template: {e}", e = e)));
+			}
+		};
+
+		let mut vars = HashMap::new();
+		for var in skeleton.variables() {
+			let mut result = InputProxy::show(InputCfg::template(&var));
+			let Some(Ok(value)) = result.recv().await else {
+				return Ok(());
+			};
+			vars.insert(var, value);
+		}
+
+		let _permit = WATCHER.acquire().await.unwrap();
+		if !skeleton.is_dir() {
+			ok_or_not_found(fs::remove_file(&new).await)?;
+		}
+		skeleton.write(&new, vars).await?;
+
+		Self::create_reveal(parent, new).await;
+		Ok(())
+	}
+
+	async fn create_reveal(parent: Url, new: Url) {
 		if let Ok(f) = File::from(new.clone()).await {
 			FilesOp::Upserting(parent, HashMap::from_iter([(f.urn_owned(), f)])).emit();
 			TabProxy::reveal(&new)
 		}
-		Ok(())
 	}
 }
@@ -0,0 +1,141 @@
+use std::{fmt::Write, mem};
+
+use yazi_config::MEMORY;
+use yazi_plugin::{LUA, elements::Renderable};
+use yazi_proxy::{AppProxy, options::OutputOpt};
+use yazi_shared::{event::CmdCow, theme::IconCache};
+
+use crate::manager::{Bench, Manager};
+
+struct Opt {
+	memory: bool,
+	bench:  Option<Kind>,
+	files:  Option<usize>,
+	lines:  Option<usize>,
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+	List,
+	Preview,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self {
+		Self {
+			memory: c.first_str() == Some("memory"),
+			bench:  match c.first_str() {
+				Some("bench-list") => Some(Kind::List),
+				Some("bench-preview") => Some(Kind::Preview),
+				_ => None,
+			},
+			files:  c.str("files").and_then(|s| s.parse().ok()),
+			lines:  c.str("lines").and_then(|s| s.parse().ok()),
+		}
+	}
+}
+
+impl Manager {
+	/// Shows what the caches take in memory with `memory`, or measures how fast
+	/// directories are listed and sorted, and files are previewed, in a synthetic
+	/// directory, for the numbers to attach to a performance report.
+	///
+	/// Not bound to any key, but run with e.g. `ya emit debug bench-list
+	/// --files=50000`.
+	#[yazi_codegen::command]
+	pub fn debug(&mut self, opt: Opt) {
+		if opt.memory {
+			let content = self.memory_report().into_bytes();
+			return AppProxy::output(OutputOpt { title: "Memory".to_owned(), content, status: Some(0) });
+		}
+
+		let Some(kind) = opt.bench else {
+			return AppProxy::notify_warn(
+				"Debug",
+				"Unknown subcommand, use `memory`, `bench-list` or `bench-preview`",
+			);
+		};
+
+		let title = match kind {
+			Kind::List => "Benchmark: list",
+			Kind::Preview => "Benchmark: preview",
+		};
+		AppProxy::notify_info(title, "Running, the report will show once it's done");
+
+		tokio::spawn(async move {
+			let result = match kind {
+				Kind::List => match Bench::new("list").await {
+					Ok(b) => b.list(opt.files.unwrap_or(10_000)).await,
+					Err(e) => Err(e),
+				},
+				Kind::Preview => match Bench::new("preview").await {
+					Ok(b) => b.preview(opt.files.unwrap_or(20), opt.lines.unwrap_or(2_000)).await,
+					Err(e) => Err(e),
+				},
+			};
+
+			match result {
+				Ok(report) => AppProxy::output(OutputOpt {
+					title:   title.to_owned(),
+					content: report.into_bytes(),
+					status:  Some(0),
+				}),
+				Err(e) => AppProxy::notify_error(title, format!("Failed to run: {e}")),
+			}
+		});
+	}
+}
+
+impl Manager {
+	fn memory_report(&self) -> String {
+		let mut s = String::new();
+		let cap = |n: Option<usize>| n.map_or("no cap".to_owned(), |n| format!("cap {}", mb(n)));
+
+		_ = writeln!(s, "Folders ({} per tab)", cap(MEMORY.load().folders_bytes()));
+		let (mut files, mut icons) = (0, 0);
+		for (i, tab) in self.tabs.iter().enumerate() {
+			let folders = [&tab.current].into_iter().chain(&tab.parent).chain(tab.history.values());
+			let (mut n, mut bytes) = (0, 0);
+			for f in folders {
+				n += f.files.all().count();
+				bytes += f.files.bytes();
+				icons += f.files.all().filter(|f| !matches!(f.icon.get(), IconCache::Missing)).count();
+			}
+			files += n;
+
+			let folders = 1 + tab.parent.is_some() as usize + tab.history.len();
+			_ = writeln!(s, "  Tab {:<3} {folders:>5} folders {n:>9} files {:>12}", i + 1, mb(bytes));
+		}
+
+		_ = writeln!(s, "\nIcons (cached on each file)");
+		_ = writeln!(s, "  {icons} of {files} files, {}", mb(icons * mem::size_of::<IconCache>()));
+
+		_ = writeln!(s, "\nPreviews");
+		for (i, tab) in self.tabs.iter().enumerate() {
+			let (n, max) = tab.preview.positions();
+			let data = tab.preview.lock.iter().flat_map(|l| &l.data);
+			let lines: usize =
+				data.map(|r| if let Renderable::Text(t) = r { t.inner.lines.len() } else { 0 }).sum();
+			_ = writeln!(s, "  Tab {:<3} {n} of {max} scroll positions, {lines} lines shown", i + 1);
+		}
+
+		_ = writeln!(s, "\nLua ({})", cap(MEMORY.load().lua_bytes()));
+		_ = writeln!(s, "  {}", mb(LUA.used_memory()));
+
+		#[cfg(target_os = "linux")]
+		if let Some(rss) = resident() {
+			_ = writeln!(s, "\nProcess\n  {} resident", mb(rss));
+		}
+		s
+	}
+}
+
+fn mb(bytes: usize) -> String { format!("{:.2} MB", bytes as f64 / 1024.0 / 1024.0) }
+
+/// The resident memory of this process, from `/proc/self/statm`.
+#[cfg(target_os = "linux")]
+fn resident() -> Option<usize> {
+	let s = std::fs::read_to_string("/proc/self/statm").ok()?;
+	let pages: usize = s.split_whitespace().nth(1)?.parse().ok()?;
+	Some(pages * unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize)
+}
@@ -0,0 +1,66 @@
+use yazi_macro::{emit, render};
+use yazi_proxy::AppProxy;
+use yazi_shared::{Layer, event::{Cmd, CmdCow}};
+
+use crate::manager::{Diff, Manager};
+
+struct Opt {
+	hash: bool,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self { Self { hash: c.bool("hash") } }
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn diff_dirs(&mut self, opt: Opt) {
+		if let Some(mut diff) = self.diff.take() {
+			diff.abort();
+			return render!();
+		} else if self.tabs.len() < 2 {
+			return AppProxy::notify_warn("Diff", "Two tabs are required to compare directories");
+		}
+
+		// Compare with the next tab
+		let (a, b) = (self.active(), &self.tabs[(self.tabs.cursor + 1) % self.tabs.len()]);
+		if !a.cwd().is_regular() || !b.cwd().is_regular() {
+			return AppProxy::notify_warn("Diff", "Only regular directories can be compared");
+		}
+
+		self.diff = Some(Diff::new([a.id, b.id], [a.cwd().clone(), b.cwd().clone()], opt.hash));
+		render!();
+	}
+
+	/// Keep the other side of the comparison in sync with the active tab, i.e.
+	/// entering the same directory, and hovering the same file.
+	pub(super) fn diff_follow(&mut self) {
+		let Some(diff) = &mut self.diff else { return };
+		let Some(side) = diff.side(self.tabs.active().id) else { return };
+		let Some(other) = self.tabs.iter().position(|t| t.id == diff.tabs[1 - side]) else {
+			self.diff.take().map(|mut d| d.abort());
+			return render!();
+		};
+
+		let cwd = self.tabs.active().cwd();
+		if *cwd != diff.cwds[side] {
+			let Ok(rel) = cwd.strip_prefix(&diff.roots[side]) else { return };
+			let target = diff.roots[1 - side].join(rel);
+			if *self.tabs[other].cwd() != target {
+				let id = diff.tabs[1 - side];
+				emit!(Call(Cmd::args("cd", &[&target]).with("tab", id), Layer::Manager));
+			}
+
+			diff.cwds[side] = cwd.clone();
+			diff.cwds[1 - side] = target;
+			diff.states.clear();
+			diff.compare();
+		}
+
+		if let Some(urn) = self.tabs.active().hovered().map(|h| h.urn_owned()) {
+			if *self.tabs[other].cwd() == diff.cwds[1 - side] {
+				render!(self.tabs.items[other].current.hover(urn.as_urn()));
+			}
+		}
+	}
+}
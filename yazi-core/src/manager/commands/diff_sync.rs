@@ -0,0 +1,35 @@
+use yazi_proxy::AppProxy;
+use yazi_shared::event::CmdCow;
+
+use crate::{manager::{DiffState, Manager}, tasks::Tasks};
+
+struct Opt {
+	changed: bool,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self { Self { changed: c.bool("changed") } }
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn diff_sync(&mut self, opt: Opt, tasks: &Tasks) {
+		let Some(diff) = &self.diff else {
+			return AppProxy::notify_warn("Diff", "No directories are being compared");
+		};
+		let id = self.active().id;
+		let Some(side) = diff.side(id) else {
+			return AppProxy::notify_warn("Diff", "The active tab is not being compared");
+		};
+
+		// Copy the missing ones from the active side to the other side
+		let dest = &diff.cwds[1 - side];
+		let missing = diff.entries(id, DiffState::Left);
+		tasks.file_copy(&missing.iter().collect::<Vec<_>>(), dest, false, false);
+
+		if opt.changed {
+			let changed = diff.entries(id, DiffState::Changed);
+			tasks.file_copy(&changed.iter().collect::<Vec<_>>(), dest, true, false);
+		}
+	}
+}
@@ -0,0 +1,34 @@
+use yazi_config::popup::ConfirmCfg;
+use yazi_proxy::{AppProxy, ConfirmProxy, ManagerProxy};
+use yazi_shared::event::CmdCow;
+
+use crate::{manager::Manager, tasks::Tasks};
+
+struct Opt {
+	force: bool,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self { Self { force: c.bool("force") } }
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn empty_trash(&mut self, opt: Opt, tasks: &Tasks) {
+		let Some(stat) = tasks.trash else {
+			return AppProxy::notify_warn("Empty the trash", "The trash is not available on this system");
+		};
+
+		if stat.count == 0 {
+			return AppProxy::notify_warn("Empty the trash", "The trash is already empty");
+		} else if opt.force {
+			return tasks.trash_empty();
+		}
+
+		tokio::spawn(async move {
+			if ConfirmProxy::show(ConfirmCfg::empty_trash(stat.count)).await {
+				ManagerProxy::empty_trash();
+			}
+		});
+	}
+}
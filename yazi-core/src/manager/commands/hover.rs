@@ -29,9 +29,18 @@ impl Manager {
 			self.current_or_mut(opt.tab).arrow(0);
 		}
 
+		// Keep the compared tab in sync
+		self.diff_follow();
+
 		// Repeek
 		self.peek(false);
 
+		// Fetch the info for the bars
+		self.hover_info.fetch(self.hovered().map(|h| h.url.clone()));
+
+		// Prelist the directories around
+		self.active_mut().prelist();
+
 		// Refresh watcher
 		let mut to_watch = HashSet::with_capacity(3 * self.tabs.len());
 		for tab in self.tabs.iter() {
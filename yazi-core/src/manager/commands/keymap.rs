@@ -0,0 +1,37 @@
+use yazi_proxy::AppProxy;
+use yazi_shared::event::CmdCow;
+
+use crate::manager::{CONTEXTS, Manager};
+
+enum Opt {
+	Push(String),
+	Pop(Option<String>),
+	Invalid,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(mut c: CmdCow) -> Self {
+		if c.first_str() != Some("layer") {
+			return Self::Invalid;
+		}
+		match c.second_str() {
+			Some("push") => c.take_str(2).map_or(Self::Invalid, |s| Self::Push(s.into_owned())),
+			Some("pop") => Self::Pop(c.take_str(2).map(|s| s.into_owned())),
+			_ => Self::Invalid,
+		}
+	}
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn keymap(&mut self, opt: Opt) {
+		match opt {
+			Opt::Push(layer) if !layer.is_empty() => CONTEXTS.write().push(layer),
+			Opt::Pop(layer) => _ = CONTEXTS.write().pop(layer.as_deref()),
+			_ => AppProxy::notify_warn(
+				"Keymap",
+				"Expected `keymap layer push <name>` or `keymap layer pop [<name>]`",
+			),
+		}
+	}
+}
@@ -1,6 +1,6 @@
 use yazi_shared::event::CmdCow;
 
-use crate::{manager::Manager, tasks::Tasks};
+use crate::{manager::Manager, tab::Tab, tasks::Tasks};
 
 struct Opt {
 	relative: bool,
@@ -14,7 +14,7 @@ impl From<CmdCow> for Opt {
 impl Manager {
 	#[yazi_codegen::command]
 	pub fn link(&mut self, opt: Opt, tasks: &Tasks) {
-		if self.yanked.cut {
+		if self.yanked.cut || !Tab::confine([self.cwd()]) {
 			return;
 		}
 
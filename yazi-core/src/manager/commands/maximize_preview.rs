@@ -0,0 +1,36 @@
+use yazi_proxy::AppProxy;
+use yazi_shared::event::CmdCow;
+
+use crate::manager::Manager;
+
+struct Opt {
+	state: Option<bool>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self {
+		Self {
+			state: match c.first_str() {
+				Some("on") => Some(true),
+				Some("off") => Some(false),
+				_ => None,
+			},
+		}
+	}
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn maximize_preview(&mut self, opt: Opt) {
+		let maximized = opt.state.unwrap_or(!self.panes.maximized);
+		if maximized == self.panes.maximized {
+			return;
+		}
+
+		self.panes.maximized = maximized;
+		if maximized {
+			self.panes.hidden = false;
+		}
+		AppProxy::resize();
+	}
+}
@@ -1,29 +1,58 @@
 yazi_macro::mod_flat!(
 	bulk_rename
+	cleanup
 	close
+	column_width
 	create
+	debug
+	diff_dirs
+	diff_sync
+	empty_trash
 	hardlink
 	hover
+	keymap
 	link
+	maximize_preview
 	open
+	organize_photos
 	paste
 	peek
+	preview_target
+	quicklook
 	quit
+	ratio
+	recent
+	recover
 	refresh
+	register
+	remotes
 	remove
 	rename
+	retarget
 	seek
+	slideshow
 	spot
 	suspend
+	sync
 	tab_close
 	tab_create
 	tab_swap
 	tab_switch
+	tag
+	tag_browse
+	toggle_preview
+	transform
+	untag
 	unyank
+	update_diff
 	update_files
+	update_info
+	update_journal
 	update_mimes
 	update_paged
+	update_tags
 	update_tasks
 	update_yanked
 	yank
+	zen
 );
@@ -1,25 +1,31 @@
 use std::{borrow::Cow, ffi::OsString};
 
 use tracing::error;
-use yazi_boot::ARGS;
-use yazi_config::{OPEN, PLUGIN, popup::PickCfg};
-use yazi_fs::File;
+use yazi_boot::{ARGS, ChooserMode};
+use yazi_config::{CONFIRM, OPEN, PLUGIN, popup::{ConfirmCfg, InputCfg, PickCfg}};
+use yazi_fs::{File, maybe_exists};
 use yazi_macro::emit;
 use yazi_plugin::isolate;
-use yazi_proxy::{ManagerProxy, TasksProxy, options::OpenDoOpt};
-use yazi_shared::{MIME_DIR, event::{CmdCow, EventQuit}, url::Url};
+use yazi_proxy::{AppProxy, ConfirmProxy, InputProxy, ManagerProxy, TasksProxy, options::OpenDoOpt};
+use yazi_shared::{MIME_DIR, escape_os_str, event::{CmdCow, EventQuit}, unescape_os_str, url::Url};
 
-use crate::{manager::Manager, tab::Folder, tasks::Tasks};
+use crate::{manager::{CHOICES, Manager, PROJECTS, RECENTS}, tab::Folder, tasks::Tasks};
 
-#[derive(Clone, Copy)]
 struct Opt {
 	interactive: bool,
 	hovered:     bool,
+	remember:    bool,
+	with:        Option<String>,
 }
 
 impl From<CmdCow> for Opt {
-	fn from(c: CmdCow) -> Self {
-		Self { interactive: c.bool("interactive"), hovered: c.bool("hovered") }
+	fn from(mut c: CmdCow) -> Self {
+		Self {
+			interactive: c.bool("interactive"),
+			hovered:     c.bool("hovered"),
+			remember:    c.bool("remember"),
+			with:        c.take_str("with").map(|s| s.into_owned()),
+		}
 	}
 }
 
@@ -29,16 +35,33 @@ impl Manager {
 		if !self.active_mut().try_escape_visual() {
 			return;
 		}
+
+		let selected: Vec<_> = if opt.hovered {
+			self.hovered().map(|h| &h.url).into_iter().collect()
+		} else {
+			self.selected_or_hovered().collect()
+		};
+		if self.quit_with_selected(&opt, &selected) {
+			return;
+		}
+
 		let Some(hovered) = self.hovered().map(|h| h.url_owned()) else {
 			return;
 		};
 
-		let selected = if opt.hovered { vec![&hovered] } else { self.selected_or_hovered().collect() };
-		if Self::quit_with_selected(opt, &selected) {
-			return;
+		let cwd = self.cwd().clone();
+		if let Some(name) = &opt.with {
+			let Some(opener) = OPEN.load().opener(name) else {
+				return AppProxy::notify_warn("Open", format!("No opener named `{name}`"));
+			};
+
+			let targets = selected.into_iter().map(|u| (u.clone(), Cow::Borrowed(""))).collect();
+			return self.open_do(
+				OpenDoOpt { cwd, hovered, targets, with: Some(opener), ..Default::default() },
+				tasks,
+			);
 		}
 
-		let cwd = self.cwd().clone();
 		let (mut done, mut todo) = (Vec::with_capacity(selected.len()), vec![]);
 		for u in selected {
 			if self.mimetype.contains(u) {
@@ -51,8 +74,18 @@ impl Manager {
 		}
 
 		if todo.is_empty() {
-			return self
-				.open_do(OpenDoOpt { cwd, hovered, targets: done, interactive: opt.interactive }, tasks);
+			return self.open_do(
+				OpenDoOpt {
+					cwd,
+					hovered,
+					targets: done,
+					interactive: opt.interactive,
+					remember: opt.remember,
+					trusted: false,
+					with: None,
+				},
+				tasks,
+			);
 		}
 
 		tokio::spawn(async move {
@@ -65,7 +98,7 @@ impl Manager {
 
 			done.extend(files.iter().map(|f| (f.url_owned(), Cow::Borrowed(""))));
 			for (fetcher, files) in PLUGIN.mime_fetchers(files) {
-				if let Err(e) = isolate::fetch(CmdCow::from(&fetcher.run), files).await {
+				if let Err(e) = isolate::fetch(CmdCow::from(&fetcher.run), files, fetcher.sandbox).await {
 					error!("Fetch mime failed on opening: {e}");
 				}
 			}
@@ -75,6 +108,9 @@ impl Manager {
 				hovered,
 				targets: done,
 				interactive: opt.interactive,
+				remember: opt.remember,
+				trusted: false,
+				with: None,
 			});
 		});
 	}
@@ -85,28 +121,60 @@ impl Manager {
 			.targets
 			.into_iter()
 			.filter_map(|(u, m)| {
-				Some(m).filter(|m| !m.is_empty()).or_else(|| self.mimetype.by_url_owned(&u)).map(|m| (u, m))
+				let m = Some(m).filter(|m| !m.is_empty()).or_else(|| self.mimetype.by_url_owned(&u));
+				// An explicit opener takes all the files, known the mimetype or not
+				if opt.with.is_some() { Some((u, m.unwrap_or_default())) } else { m.map(|m| (u, m)) }
 			})
 			.collect();
 
 		if targets.is_empty() {
 			return;
-		} else if !opt.interactive {
+		} else if !opt.trusted && !CONFIRM.load().untrusted.is_empty() {
+			let untrusted: Vec<_> =
+				targets.iter().map(|(u, _)| u).filter(|&u| CONFIRM.load().untrusted(u)).collect();
+
+			if !untrusted.is_empty() {
+				let result = ConfirmProxy::show(ConfirmCfg::untrusted(&untrusted));
+				tokio::spawn(async move {
+					if result.await {
+						ManagerProxy::open_do(OpenDoOpt { targets, trusted: true, ..opt });
+					}
+				});
+				return;
+			}
+		}
+
+		RECENTS.write().open(&targets);
+		if let Some(opener) = opt.with {
+			let urls = [opt.hovered].into_iter().chain(targets.into_iter().map(|(u, _)| u));
+			return tasks.process_from_opener(
+				opt.cwd,
+				Cow::Owned(opener),
+				urls.map(|u| u.into_path().into_os_string()).collect(),
+			);
+		}
+		if !opt.interactive {
 			return tasks.process_from_files(opt.cwd, opt.hovered, targets);
 		}
 
-		let openers: Vec<_> = OPEN.common_openers(&targets);
+		let mut openers: Vec<_> = PROJECTS.read().common_openers(&targets);
 		if openers.is_empty() {
 			return;
 		}
 
-		let urls = [opt.hovered].into_iter().chain(targets.into_iter().map(|(u, _)| u)).collect();
+		let urls: Vec<_> =
+			[opt.hovered].into_iter().chain(targets.into_iter().map(|(u, _)| u)).collect();
+		CHOICES.read().rank(&urls[1..], &mut openers);
+
 		tokio::spawn(async move {
 			let result = yazi_proxy::PickProxy::show(PickCfg::open(
 				openers.iter().map(|o| o.desc.clone()).collect(),
 			));
 			if let Ok(choice) = result.await {
-				TasksProxy::open_with(Cow::Borrowed(openers[choice]), opt.cwd, urls);
+				if opt.remember {
+					CHOICES.write().remember(&urls[1..], &openers[choice]);
+				}
+				TasksProxy::open_with(Cow::Owned(openers.swap_remove(choice)), opt.cwd, urls);
 			}
 		});
 	}
@@ -128,18 +196,70 @@ impl Manager {
 			|| find(self.active().history.get(&p))
 	}
 
-	fn quit_with_selected(opt: Opt, selected: &[&Url]) -> bool {
+	fn quit_with_selected(&self, opt: &Opt, selected: &[&Url]) -> bool {
 		if opt.interactive || ARGS.chooser_file.is_none() {
 			return false;
 		}
 
-		let paths = selected.iter().fold(OsString::new(), |mut s, &u| {
+		match ARGS.chooser_mode {
+			ChooserMode::Files if selected.is_empty() => {}
+			ChooserMode::Files => Self::choose(selected.iter().copied()),
+			ChooserMode::Dir => {
+				let dirs: Vec<_> = selected.iter().copied().filter(|&u| self.guess_folder(u)).collect();
+				if dirs.is_empty() { Self::choose([self.cwd()]) } else { Self::choose(dirs) }
+			}
+			ChooserMode::Save => self.choose_save(),
+		}
+		true
+	}
+
+	fn choose<'a>(urls: impl IntoIterator<Item = &'a Url>) {
+		let delimiter = if ARGS.chooser_null { "\0" } else { "\n" };
+		let paths = urls.into_iter().fold(OsString::new(), |mut s, u| {
 			s.push(u.as_os_str());
-			s.push("\n");
+			s.push(delimiter);
 			s
 		});
 
 		emit!(Quit(EventQuit { selected: Some(paths), ..Default::default() }));
-		true
+	}
+
+	fn choose_save(&self) {
+		let cwd = self.cwd().clone();
+		let name = self.hovered().filter(|h| !h.is_dir()).map(|h| h.name());
+
+		// Names that aren't valid UTF-8 are escaped for editing, turn them back
+		let escaped = name.is_some_and(|n| n.to_str().is_none());
+		let name = name.map(escape_os_str).unwrap_or_default().into_owned();
+
+		tokio::spawn(async move {
+			let mut result = InputProxy::show(InputCfg::save().with_value(name));
+			let Some(Ok(name)) = result.recv().await else { return };
+			if name.is_empty() {
+				return;
+			}
+
+			let new = if escaped {
+				cwd.join(unescape_os_str(&name))
+			} else {
+				match Self::expand_input(&cwd, &name).await.as_deref() {
+					Some([new]) => new.clone(),
+					Some(news) => {
+						return AppProxy::notify_warn(
+							"Save",
+							format!("`{name}` expands to {} paths", news.len()),
+						);
+					}
+					None => return,
+				}
+			};
+			if CONFIRM.load().overwrite_policy.ask(1)
+				&& maybe_exists(&new).await
+				&& !ConfirmProxy::show(ConfirmCfg::overwrite(&new)).await
+			{
+				return;
+			}
+			Self::choose([&new]);
+		});
 	}
 }
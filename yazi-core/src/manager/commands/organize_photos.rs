@@ -0,0 +1,165 @@
+use std::{collections::{BTreeMap, HashSet}, fmt::Write, path::{Component, Path}};
+
+use chrono::{DateTime, Local, NaiveDateTime};
+use tokio::fs;
+use yazi_adapter::ImageInfo;
+use yazi_config::{MANAGER, popup::ConfirmCfg};
+use yazi_fs::{maybe_exists, numbered};
+use yazi_proxy::{AppProxy, ConfirmProxy, ManagerProxy};
+use yazi_shared::{errors::ErrorReport, event::CmdCow, url::Url};
+
+use crate::manager::Manager;
+
+struct Opt {
+	to:       Option<Url>,
+	copy:     bool,
+	template: Option<String>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(mut c: CmdCow) -> Self {
+		Self {
+			to:       c.take_url("to"),
+			copy:     c.bool("copy"),
+			template: c.take_str("template").map(|s| s.into_owned()),
+		}
+	}
+}
+
+/// Where a photo goes, or the one it's a duplicate of.
+enum Plan {
+	Move(Url, Url),
+	Duplicate(Url, Url),
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn organize_photos(&mut self, opt: Opt) {
+		let root = opt.to.unwrap_or_else(|| self.cwd().clone());
+		if !root.is_regular() {
+			return AppProxy::notify_warn(
+				"Organize photos",
+				"Only regular directories can be organized into",
+			);
+		}
+
+		let src: Vec<_> = self
+			.selected_or_hovered()
+			.filter(|&u| self.mimetype.by_url(u).is_none_or(|m| m.starts_with("image/")))
+			.cloned()
+			.collect();
+		if src.is_empty() {
+			return AppProxy::notify_warn("Organize photos", "No photos selected");
+		}
+
+		let template = opt.template.unwrap_or_else(|| MANAGER.load().organize_template.clone());
+		tokio::spawn(async move {
+			let (mut plans, mut taken) = (Vec::with_capacity(src.len()), HashSet::new());
+			'src: for from in src {
+				let Some(name) = from.file_name().map(|s| s.to_owned()) else { continue };
+
+				let mut sub = String::new();
+				if write!(sub, "{}", date_of(&from).await.format(&template)).is_err() {
+					return AppProxy::notify_warn(
+						"Organize photos",
+						format!("Invalid template `{template}`"),
+					);
+				}
+
+				// Kept under the root, as `..` or an absolute path would take it elsewhere
+				if !Path::new(&sub)
+					.components()
+					.all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+				{
+					return AppProxy::notify_warn(
+						"Organize photos",
+						format!("Template `{template}` must stay under the destination, but got `{sub}`"),
+					);
+				}
+
+				let dir = root.join(sub);
+				let mut to = dir.join(&name);
+				for n in 1u64.. {
+					if to == from {
+						continue 'src;
+					} else if taken.contains(&to) {
+					} else if !maybe_exists(&to).await {
+						break;
+					} else if same_content(&from, &to).await {
+						plans.push(Plan::Duplicate(from, to));
+						continue 'src;
+					}
+
+					// Numbered like pasting, as it's a different photo under the same name
+					let Ok(u) = numbered(&dir.join(&name), n, false) else { continue 'src };
+					to = u;
+				}
+
+				taken.insert(to.clone());
+				plans.push(Plan::Move(from, to));
+			}
+
+			let moves = plans.iter().filter(|p| matches!(p, Plan::Move(..))).count();
+			if moves == 0 {
+				return AppProxy::notify_warn("Organize photos", "Nothing to organize");
+			}
+			if !ConfirmProxy::show(ConfirmCfg::organize(moves, tree(&root, &plans))).await {
+				return;
+			}
+
+			let mut targets = Vec::with_capacity(moves);
+			for plan in plans {
+				let Plan::Move(from, to) = plan else { continue };
+				let Some(parent) = to.parent_url() else { continue };
+				if let Err(e) = fs::create_dir_all(&parent).await {
+					return AppProxy::notify_report(
+						"Organize photos",
+						ErrorReport::from(&e).with_path(&parent),
+					);
+				}
+				targets.push((from, to));
+			}
+			ManagerProxy::paste_do(targets, !opt.copy, false, false);
+		});
+	}
+}
+
+/// When the photo was taken, or last modified if there's no EXIF date.
+async fn date_of(u: &Url) -> NaiveDateTime {
+	if let Some(taken) = ImageInfo::new(u).await.ok().and_then(|i| i.taken) {
+		return taken;
+	}
+	let mtime = fs::metadata(u).await.and_then(|m| m.modified());
+	mtime.map(DateTime::<Local>::from).unwrap_or_else(|_| Local::now()).naive_local()
+}
+
+async fn same_content(a: &Url, b: &Url) -> bool {
+	let (Ok(m1), Ok(m2)) = (fs::metadata(a).await, fs::metadata(b).await) else { return false };
+	if m1.len() != m2.len() || !m2.is_file() {
+		return false;
+	}
+	matches!((fs::read(a).await, fs::read(b).await), (Ok(x), Ok(y)) if x == y)
+}
+
+/// The resulting tree under `root`, as lines of the confirmation.
+fn tree(root: &Url, plans: &[Plan]) -> Vec<String> {
+	let name = |u: &Url| u.file_name().unwrap_or_default().to_string_lossy().into_owned();
+
+	let mut dirs: BTreeMap<_, Vec<_>> = BTreeMap::new();
+	for plan in plans {
+		let (to, line) = match plan {
+			Plan::Move(_, to) => (to, name(to)),
+			Plan::Duplicate(from, to) => (to, format!("{} (duplicate, skipped)", name(from))),
+		};
+		let dir = to.parent().and_then(|p| p.strip_prefix(root).ok()).unwrap_or(Path::new(""));
+		dirs.entry(dir.to_string_lossy().into_owned()).or_default().push(line);
+	}
+
+	let mut lines = vec![];
+	for (dir, mut names) in dirs {
+		names.sort_unstable();
+		lines.push(format!("{dir}/"));
+		lines.extend(names.into_iter().map(|n| format!("  {n}")));
+	}
+	lines
+}
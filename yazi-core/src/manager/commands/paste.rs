@@ -1,28 +1,304 @@
-use yazi_shared::event::CmdCow;
+use std::{borrow::Borrow, collections::HashSet};
 
-use crate::{manager::Manager, tasks::Tasks};
+use tokio::fs;
+use yazi_config::{CONFIRM, KEYMAP, MANAGER, popup::{ConfirmCfg, InputCfg, PickCfg}, t};
+use yazi_fs::{CWD, Conflict, expand_path, maybe_exists};
+use yazi_macro::emit;
+use yazi_proxy::{AppProxy, CompletionProxy, ConfirmProxy, InputProxy, ManagerProxy, PickProxy, TabProxy};
+use yazi_shared::{Layer, errors::InputError, escape_os_str, event::{Cmd, CmdCow}, unescape_os_str, url::Url};
+
+use crate::{manager::{Manager, Naming, RECENTS, Registers}, tab::Tab, tasks::Tasks};
 
 struct Opt {
-	force:  bool,
-	follow: bool,
+	force:     bool,
+	follow:    bool,
+	register:  Option<char>,
+	as_:       Option<String>,
+	ask:       bool,
+	confirmed: bool,
+	to:        bool,
+	dir:       Option<String>,
+	dest:      Option<Url>,
+	jump:      bool,
+	cut:       bool,
+	targets:   Vec<(Url, Url)>,
 }
 
 impl From<CmdCow> for Opt {
-	fn from(c: CmdCow) -> Self { Self { force: c.bool("force"), follow: c.bool("follow") } }
+	fn from(mut c: CmdCow) -> Self {
+		Self {
+			to:        c.bool("to") || c.str("to").is_some(),
+			dir:       c.take_str("to").map(|s| s.into_owned()),
+			dest:      c.take_url("dest"),
+			jump:      c.bool("jump"),
+			force:     c.bool("force"),
+			follow:    c.bool("follow"),
+			register:  c.str("register").and_then(Registers::name),
+			as_:       c.take_str("as").map(|s| s.into_owned()),
+			ask:       c.bool("ask"),
+			confirmed: c.bool("confirmed"),
+			cut:       c.bool("cut"),
+			targets:   c.take_any("targets").unwrap_or_default(),
+		}
+	}
 }
 
 impl Manager {
 	#[yazi_codegen::command]
-	pub fn paste(&mut self, opt: Opt, tasks: &Tasks) {
-		let (src, dest) = (self.yanked.iter().collect::<Vec<_>>(), self.cwd());
+	pub fn paste(&mut self, mut opt: Opt, tasks: &Tasks) {
+		let register = self.registers.resolve(opt.register);
+		let yanked = match register {
+			Some(name) => match self.registers.get(&name) {
+				Some(yanked) => yanked,
+				None => return,
+			},
+			None => &self.yanked,
+		};
 
-		if self.yanked.cut {
-			tasks.file_cut(&src, dest, opt.force);
+		let src = yanked.iter().collect::<Vec<_>>();
+		let dest = opt.dest.take().unwrap_or_else(|| self.cwd().clone());
+		if src.is_empty() || !Tab::confine([&dest]) {
+			return;
+		} else if opt.to {
+			let places = self.paste_places();
+			return Self::paste_to(src.len(), places, register, opt);
+		} else if opt.as_.is_some()
+			|| opt.ask
+			|| (!opt.force && MANAGER.load().conflict == Conflict::Ask)
+		{
+			let src = src.into_iter().cloned().collect();
+			return Self::paste_as(src, dest, yanked.cut, opt);
+		} else if !opt.confirmed && (opt.force || CONFIRM.load().paste_policy.ask(src.len())) {
+			let src: Vec<_> = src.into_iter().cloned().collect();
+			tokio::spawn(async move {
+				if Self::paste_confirm(&src, &dest, opt.force).await {
+					ManagerProxy::paste(opt.force, opt.follow, register, Some(dest), opt.jump);
+				}
+			});
+			return;
+		}
+
+		if opt.jump && dest != *self.cwd() {
+			TabProxy::cd(&dest);
+		}
+		if yanked.cut {
+			tasks.file_cut(&src, &dest, opt.force);
 
 			self.tabs.iter_mut().for_each(|t| _ = t.selected.remove_many(&src, false));
-			self.unyank(());
+			match register {
+				Some(name) => self.registers.set(name, Default::default()),
+				None => self.unyank(()),
+			}
 		} else {
-			tasks.file_copy(&src, dest, opt.force, opt.follow);
+			tasks.file_copy(&src, &dest, opt.force, opt.follow);
 		}
 	}
+
+	#[yazi_codegen::command]
+	pub fn paste_do(&mut self, opt: Opt, tasks: &Tasks) {
+		if !Tab::confine(opt.targets.iter().map(|(_, to)| to)) {
+			return;
+		}
+
+		if opt.cut {
+			let src: Vec<_> = opt.targets.iter().map(|(from, _)| from).collect();
+			self.tabs.iter_mut().for_each(|t| _ = t.selected.remove_many(&src, false));
+
+			for u in src {
+				self.yanked.remove(u);
+				self.registers.remove(u);
+			}
+			self.yanked.catchup_revision(false);
+		}
+
+		tasks.file_paste_as(opt.targets, opt.cut, opt.force, opt.follow);
+	}
+
+	/// Paste under the names given by the template, if any, and ask for a new
+	/// name for each file that would overwrite another, if `--ask` or it's the
+	/// conflict policy.
+	fn paste_as(mut src: Vec<Url>, dest: Url, cut: bool, opt: Opt) {
+		let naming = opt.as_.map(Naming::new);
+		let ask = opt.ask || (!opt.force && MANAGER.load().conflict == Conflict::Ask);
+		src.sort_unstable();
+
+		tokio::spawn(async move {
+			if !opt.confirmed && CONFIRM.load().paste_policy.ask(src.len()) {
+				let src: Vec<_> = src.iter().collect();
+				if !ConfirmProxy::show(ConfirmCfg::paste(&src)).await {
+					return;
+				}
+			}
+
+			let (mut targets, mut taken, mut n) = (Vec::with_capacity(src.len()), HashSet::new(), 0);
+			for from in src {
+				let mut to = loop {
+					n += 1;
+					let name = match &naming {
+						Some(naming) => naming.name(&from, n),
+						None => from.file_name().map(ToOwned::to_owned),
+					};
+					let Some(name) = name else {
+						return AppProxy::notify_warn("Paste", "The template results in an invalid file name");
+					};
+
+					// A counter moves on past the names that are already there
+					let to = dest.join(name);
+					if !naming.as_ref().is_some_and(|n| n.has_counter())
+						|| !exists(&to, &taken, opt.force).await
+					{
+						break to;
+					}
+				};
+
+				if ask && exists(&to, &taken, opt.force).await {
+					let name = escape_os_str(to.name()).into_owned();
+					let mut result = InputProxy::show(InputCfg::conflict(&name).with_value(name));
+					let Some(Ok(s)) = result.recv().await else { continue };
+
+					// Names that aren't valid UTF-8 were escaped for editing, turn them back
+					let new = if s.is_empty() {
+						continue;
+					} else if to.name().to_str().is_some() {
+						match Self::expand_input(&dest, &s).await.as_deref() {
+							Some([new]) => new.clone(),
+							_ => continue,
+						}
+					} else {
+						dest.join(unescape_os_str(&s))
+					};
+					match new.parent_url() {
+						Some(p) if p == dest => to = new,
+						_ => continue,
+					}
+				}
+
+				taken.insert(to.clone());
+				targets.push((from, to));
+			}
+
+			if opt.force && !Self::paste_overwrite(targets.iter().map(|(_, to)| to)).await {
+				return;
+			} else if !targets.is_empty() {
+				ManagerProxy::paste_do(targets, cut, opt.force, opt.follow);
+			}
+			if opt.jump {
+				TabProxy::cd(&dest);
+			}
+		});
+	}
+
+	/// Confirm pasting the files by the paste policy, and overwriting the ones
+	/// already there by the overwrite policy if it's forced.
+	async fn paste_confirm(src: &[Url], dest: &Url, force: bool) -> bool {
+		if CONFIRM.load().paste_policy.ask(src.len()) {
+			let src: Vec<_> = src.iter().collect();
+			if !ConfirmProxy::show(ConfirmCfg::paste(&src)).await {
+				return false;
+			}
+		}
+
+		!force
+			|| Self::paste_overwrite(src.iter().filter_map(|u| Some(dest.join(u.file_name()?)))).await
+	}
+
+	/// Confirm overwriting those of the `targets` that exist by the overwrite
+	/// policy.
+	async fn paste_overwrite(targets: impl Iterator<Item = impl Borrow<Url>>) -> bool {
+		let mut existing = vec![];
+		for to in targets {
+			if maybe_exists(to.borrow()).await {
+				existing.push(to.borrow().clone());
+			}
+		}
+
+		existing.is_empty()
+			|| !CONFIRM.load().overwrite_policy.ask(existing.len())
+			|| ConfirmProxy::show(ConfirmCfg::overwrite_many(&existing)).await
+	}
+
+	/// Pick where to paste the `n` files, or type it, then paste them there.
+	fn paste_to(n: usize, places: Vec<Url>, register: Option<char>, mut opt: Opt) {
+		tokio::spawn(async move {
+			let input = match opt.dir.take() {
+				Some(dir) => dir,
+				None => {
+					let mut items = vec![t!("Type a path…")];
+					items.extend(places.iter().map(|u| u.to_string()));
+
+					match PickProxy::show(PickCfg::paste_to(n, items)).await {
+						Ok(0) => match Self::paste_to_input().await {
+							Some(s) => s,
+							None => return,
+						},
+						Ok(i) => return Self::paste_to_do(places[i - 1].clone(), register, opt).await,
+						Err(_) => return,
+					}
+				}
+			};
+
+			let cwd = Url::from(CWD.load().as_path());
+			match Self::expand_input(&cwd, &input).await.as_deref() {
+				Some([dest]) => Self::paste_to_do(dest.clone(), register, opt).await,
+				Some(dests) => {
+					AppProxy::notify_warn("Paste", format!("`{input}` expands to {} paths", dests.len()))
+				}
+				None => {}
+			}
+		});
+	}
+
+	async fn paste_to_do(dest: Url, register: Option<char>, opt: Opt) {
+		if !fs::metadata(&dest).await.is_ok_and(|m| m.is_dir()) {
+			return AppProxy::notify_warn("Paste", format!("`{dest}` is not a directory"));
+		}
+
+		emit!(Call(
+			Cmd::new("paste")
+				.with_bool("force", opt.force)
+				.with_bool("follow", opt.follow)
+				.with_opt("register", register)
+				.with_opt("as", opt.as_)
+				.with_bool("ask", opt.ask)
+				.with("dest", dest)
+				.with_bool("jump", opt.jump),
+			Layer::Manager
+		));
+	}
+
+	async fn paste_to_input() -> Option<String> {
+		let mut rx = InputProxy::show(InputCfg::paste_to());
+		loop {
+			match rx.recv().await? {
+				Ok(s) => return Some(s).filter(|s| !s.is_empty()),
+				Err(InputError::Completed(before, ticket)) => CompletionProxy::trigger(&before, ticket),
+				Err(_) => return None,
+			}
+		}
+	}
+
+	/// The places to paste to: the directories that keys `cd` to, those of the
+	/// other tabs, and the recently visited ones, except the current one.
+	fn paste_places(&self) -> Vec<Url> {
+		let keymap = KEYMAP.load();
+		let bookmarks = keymap
+			.manager
+			.iter()
+			.filter_map(|c| c.run.first())
+			.filter(|c| c.name == "cd" && !c.bool("interactive"))
+			.filter_map(|c| c.first_str())
+			.map(|s| Url::from(expand_path(s)));
+
+		let tabs = self.tabs.iter().map(|t| t.cwd()).filter(|u| u.is_regular()).cloned();
+		let recents: Vec<_> = RECENTS.read().dirs().map(Url::from).collect();
+
+		let mut seen = HashSet::from([self.cwd().clone()]);
+		bookmarks.chain(tabs).chain(recents).filter(|u| u.is_dir() && seen.insert(u.clone())).collect()
+	}
+}
+
+/// Whether `to` is already taken, by an existing file unless it's forced to
+/// be overwritten, or by one pasted earlier.
+async fn exists(to: &Url, taken: &HashSet<Url>, force: bool) -> bool {
+	taken.contains(to) || (!force && maybe_exists(to).await)
 }
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use yazi_proxy::HIDER;
 use yazi_shared::{event::{CmdCow, Data}, url::Url};
 
@@ -35,11 +37,18 @@ impl Manager {
 			return self.active_mut().preview.reset_image();
 		}
 
-		let mime = self.mimetype.by_file_owned(&hovered).unwrap_or_default();
+		let mime = match &self.active().preview.diff {
+			Some(diff) => Cow::Borrowed(diff.mime()),
+			None => self.mimetype.by_file_owned(&hovered).unwrap_or_default(),
+		};
 		let folder = self.active().hovered_folder().map(|f| (f.offset, f.cha));
 
 		if !self.active().preview.same_url(&hovered.url) {
-			self.active_mut().preview.skip = folder.map(|f| f.0).unwrap_or_default();
+			let preview = &mut self.active_mut().preview;
+			preview.skip = match folder {
+				Some((offset, _)) => offset,
+				None => preview.position(&hovered.url),
+			};
 		}
 		if !self.active().preview.same_file(&hovered, &mime) {
 			self.active_mut().preview.reset();
@@ -51,14 +60,17 @@ impl Manager {
 
 		if let Some(skip) = opt.skip {
 			let preview = &mut self.active_mut().preview;
-			if opt.upper_bound {
-				preview.skip = preview.skip.min(skip);
-			} else {
+			let skip = if opt.upper_bound { preview.skip.min(skip) } else { skip };
+			if hovered.is_dir() {
 				preview.skip = skip;
+			} else {
+				preview.scroll(&hovered.url, skip);
 			}
 		}
 
-		if hovered.is_dir() {
+		if self.active().preview.diff.is_some() {
+			self.active_mut().preview.go_diff(hovered, opt.force);
+		} else if hovered.is_dir() {
 			self.active_mut().preview.go_folder(hovered, folder.map(|(_, cha)| cha), opt.force);
 		} else {
 			self.active_mut().preview.go(hovered, mime, opt.force);
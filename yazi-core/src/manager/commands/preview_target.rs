@@ -0,0 +1,53 @@
+use std::borrow::Cow;
+
+use yazi_config::OPEN;
+use yazi_macro::render;
+use yazi_proxy::{AppProxy, TabProxy};
+use yazi_shared::event::CmdCow;
+
+use crate::{manager::Manager, tab::PreviewTarget, tasks::Tasks};
+
+struct Opt {
+	step: isize,
+	open: bool,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self {
+		match c.first_str() {
+			Some("open") => Self { step: 0, open: true },
+			Some("prev") => Self { step: -1, open: false },
+			_ => Self { step: 1, open: false },
+		}
+	}
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn preview_target(&mut self, opt: Opt, tasks: &Tasks) {
+		let preview = &mut self.active_mut().preview;
+		let Some(lock) = &mut preview.lock else { return };
+
+		if !opt.open {
+			if !preview.targets.cycle(lock, opt.step) {
+				AppProxy::notify_warn("Preview", "No paths or URLs in the preview");
+			}
+			return render!();
+		}
+
+		match preview.targets.current().cloned() {
+			Some(PreviewTarget::Path(url)) if url.is_dir() => TabProxy::cd(&url),
+			Some(PreviewTarget::Path(url)) => TabProxy::reveal(&url),
+			Some(PreviewTarget::Link(link)) => {
+				let Some(opener) = OPEN.load().opener("open") else {
+					return AppProxy::notify_warn("Preview", "No opener named `open`");
+				};
+				tasks.process_from_opener(self.cwd().clone(), Cow::Owned(opener), vec![
+					link.clone().into(),
+					link.into(),
+				]);
+			}
+			None => AppProxy::notify_warn("Preview", "No path or URL is highlighted"),
+		}
+	}
+}
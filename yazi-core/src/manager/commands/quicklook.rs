@@ -0,0 +1,44 @@
+use yazi_proxy::AppProxy;
+use yazi_shared::event::CmdCow;
+
+use crate::manager::Manager;
+
+struct Opt {
+	state: Option<bool>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self {
+		Self {
+			state: match c.first_str() {
+				Some("on") => Some(true),
+				Some("off") => Some(false),
+				_ => None,
+			},
+		}
+	}
+}
+
+impl From<bool> for Opt {
+	fn from(state: bool) -> Self { Self { state: Some(state) } }
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn quicklook(&mut self, opt: Opt) {
+		let quicklook = opt.state.unwrap_or(!self.panes.quicklook);
+		if quicklook == self.panes.quicklook {
+			return;
+		}
+
+		if quicklook && self.hovered().is_none() {
+			return AppProxy::notify_warn("Quick look", "Nothing is hovered");
+		}
+
+		self.panes.quicklook = quicklook;
+		if !quicklook {
+			self.slideshow = None;
+		}
+		AppProxy::resize();
+	}
+}
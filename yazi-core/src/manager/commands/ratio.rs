@@ -0,0 +1,70 @@
+use anyhow::bail;
+use yazi_config::{MANAGER, manager::ManagerRatio};
+use yazi_proxy::AppProxy;
+use yazi_shared::event::{CmdCow, Data};
+
+use crate::manager::Manager;
+
+pub enum Opt {
+	Reset,
+	Set(Vec<u16>),
+	Adjust(usize, i16),
+}
+
+impl TryFrom<CmdCow> for Opt {
+	type Error = anyhow::Error;
+
+	fn try_from(c: CmdCow) -> Result<Self, Self::Error> {
+		let pane = match c.first_str() {
+			Some("reset") => return Ok(Self::Reset),
+			Some("parent") => 0,
+			Some("current") => 1,
+			Some("preview") => 2,
+			_ => {
+				let ratio: Option<Vec<_>> = (0..3).map(|i| c.get(i).and_then(Data::as_usize)).collect();
+				let Some(ratio) = ratio else {
+					bail!("expected `reset`, a pane name with a delta, or three numbers");
+				};
+				return Ok(Self::Set(ratio.into_iter().map(|n| n.min(u16::MAX as usize) as u16).collect()));
+			}
+		};
+
+		match c.second().and_then(Data::as_i16) {
+			Some(delta) => Ok(Self::Adjust(pane, delta)),
+			None => bail!("expected a delta like `+1` or `-1` after the pane name"),
+		}
+	}
+}
+
+impl Manager {
+	pub fn ratio(&mut self, opt: impl TryInto<Opt, Error = anyhow::Error>) {
+		let opt = match opt.try_into() {
+			Ok(opt) => opt,
+			Err(e) => return AppProxy::notify_warn("`ratio` command", e),
+		};
+
+		let layout = self.active().pref.layout.as_deref();
+		let old =
+			layout.and_then(|l| MANAGER.load().layouts.get(l).copied()).unwrap_or(self.panes.ratio);
+		let new = match opt {
+			Opt::Reset => Ok(MANAGER.load().ratio),
+			Opt::Set(v) => ManagerRatio::try_from(v),
+			Opt::Adjust(i, delta) => {
+				let mut v = vec![old.parent, old.current, old.preview];
+				v[i] = v[i].saturating_add_signed(delta);
+				ManagerRatio::try_from(v)
+			}
+		};
+
+		let new = match new {
+			Ok(new) => new,
+			Err(e) => return AppProxy::notify_warn("`ratio` command", e),
+		};
+
+		// A tab following a layout preset falls back to the adjusted global ratio
+		let detached = self.active_mut().pref.layout.take().is_some();
+		if self.panes.set_ratio(new) || detached {
+			AppProxy::resize();
+		}
+	}
+}
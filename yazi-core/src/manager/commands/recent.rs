@@ -0,0 +1,51 @@
+use yazi_fs::{Cha, File, FilesOp};
+use yazi_macro::emit;
+use yazi_proxy::TabProxy;
+use yazi_shared::{Layer, event::{Cmd, CmdCow}};
+
+use crate::manager::{Manager, RECENTS, Recents};
+
+struct Opt {
+	dirs: bool,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self { Self { dirs: c.bool("dirs") } }
+}
+
+impl Manager {
+	/// Browse the recently opened files, or visited directories, with a fuzzy
+	/// filter ready for them.
+	#[yazi_codegen::command]
+	pub fn recent(&mut self, opt: Opt) {
+		if let Some(handle) = self.active_mut().search.take() {
+			handle.abort();
+		}
+
+		let dirs: Vec<_> =
+			if opt.dirs { RECENTS.read().dirs().map(ToOwned::to_owned).collect() } else { vec![] };
+		let target =
+			self.cwd().to_regular().into_search(if opt.dirs { "recent:dirs" } else { "recent" });
+
+		tokio::spawn(async move {
+			let paths = if opt.dirs {
+				dirs
+			} else {
+				tokio::task::spawn_blocking(Recents::files).await.unwrap_or_default()
+			};
+
+			let ((), ticket) = (TabProxy::cd(&target), FilesOp::prepare(&target));
+			emit!(Call(Cmd::new("filter").with_bool("fuzzy", true), Layer::Manager));
+
+			let mut files = Vec::with_capacity(paths.len());
+			for path in paths {
+				if let Ok(file) = File::from(target.join(path)).await {
+					files.push(file);
+				}
+			}
+
+			FilesOp::Part(target.clone(), files, ticket).emit();
+			FilesOp::Done(target, Cha::dummy(), ticket).emit();
+		});
+	}
+}
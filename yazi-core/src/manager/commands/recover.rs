@@ -0,0 +1,45 @@
+use yazi_macro::render;
+use yazi_shared::{event::CmdCow, url::Url};
+
+use crate::{manager::{Journal, Manager, Yanked}, tasks::Tasks};
+
+pub struct Opt {
+	journal: Journal,
+}
+
+impl TryFrom<CmdCow> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: CmdCow) -> Result<Self, Self::Error> {
+		Ok(Self { journal: c.take_any("journal").ok_or(())? })
+	}
+}
+
+impl Manager {
+	/// Restores what a journal of an interrupted session has, where the yank
+	/// doesn't replace the current one, and the pastes left are resumed, to a
+	/// new name by the conflict policy if their destination is taken by now.
+	pub fn recover(&mut self, opt: impl TryInto<Opt>, tasks: &Tasks) {
+		let Ok(Opt { journal }) = opt.try_into() else { return };
+
+		if let Some(yank) = journal.yank.filter(|_| self.yanked.is_empty()) {
+			self.yanked = Yanked::new(yank.cut, yank.paths.into_iter().map(Url::from).collect());
+			self.yanked.catchup_revision(true);
+		}
+
+		for (i, paths) in journal.selected.into_iter().enumerate() {
+			let urls: Vec<_> = paths.into_iter().map(Url::from).collect();
+			let tab = if i < self.tabs.len() { &mut self.tabs[i] } else { self.active_mut() };
+			tab.selected.add_many(&urls, false);
+		}
+
+		let (cuts, copies): (Vec<_>, Vec<_>) = journal.pastes.into_iter().partition(|p| p.cut);
+		for (pastes, cut) in [(cuts, true), (copies, false)] {
+			if !pastes.is_empty() {
+				let targets = pastes.into_iter().map(|p| (Url::from(p.from), Url::from(p.to))).collect();
+				tasks.file_paste_as(targets, cut, false, false);
+			}
+		}
+		render!();
+	}
+}
@@ -3,15 +3,28 @@ use std::path::MAIN_SEPARATOR;
 use crossterm::{execute, terminal::SetTitle};
 use yazi_config::MANAGER;
 use yazi_fs::CWD;
-use yazi_shared::event::CmdCow;
+use yazi_macro::{emit, render};
+use yazi_shared::{Layer, event::{Cmd, CmdCow}};
 
-use crate::{manager::Manager, tasks::Tasks};
+use crate::{manager::{CONTEXTS, Manager, PROJECTS, Projects}, tasks::Tasks};
 
 impl Manager {
 	pub fn refresh(&mut self, _: CmdCow, tasks: &Tasks) {
-		if CWD.set(self.cwd()) && !MANAGER.title_format.is_empty() {
+		if CWD.set(self.cwd()) && !MANAGER.load().title_format.is_empty() {
 			execute!(std::io::stderr(), SetTitle(self.title())).ok();
 		}
+		emit!(Call(Cmd::args("follow", &[self.cwd()]), Layer::Terminal));
+		tasks.ongoing().lock().origin = Some(self.active().id);
+
+		let project = PROJECTS.read().get(self.cwd());
+		PROJECTS.write().active = project.clone();
+		if self.active_mut().enter_project(project) {
+			render!();
+		}
+		if self.cwd().is_regular() {
+			Projects::discover(self.cwd().to_path_buf());
+		}
+		CONTEXTS.write().sync(self.active());
 
 		self.active_mut().apply_files_attrs();
 
@@ -35,6 +48,6 @@ impl Manager {
 			format!("{}", self.cwd().display())
 		};
 
-		MANAGER.title_format.replace("{cwd}", &cwd)
+		MANAGER.load().title_format.replace("{cwd}", &cwd)
 	}
 }
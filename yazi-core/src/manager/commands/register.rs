@@ -0,0 +1,61 @@
+use std::collections::BTreeSet;
+
+use yazi_config::popup::PickCfg;
+use yazi_macro::emit;
+use yazi_proxy::{AppProxy, PickProxy};
+use yazi_shared::{Layer, event::{Cmd, CmdCow}};
+
+use crate::manager::{Manager, Registers, Yanked};
+
+struct Opt {
+	name: Option<String>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(mut c: CmdCow) -> Self { Self { name: c.take_first_str().map(|s| s.into_owned()) } }
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn register(&mut self, opt: Opt) {
+		if let Some(name) = opt.name {
+			match Registers::name(&name) {
+				Some(c) => self.registers.pending = Some(c),
+				None => AppProxy::notify_warn("Register", format!("Invalid register name `{name}`")),
+			}
+			return;
+		}
+
+		let names: Vec<_> = ('a'..='z')
+			.chain(self.registers.keys().copied())
+			.collect::<BTreeSet<_>>()
+			.into_iter()
+			.collect();
+		let items = names.iter().map(|&c| describe(c, self.registers.get(&c))).collect();
+
+		tokio::spawn(async move {
+			if let Ok(choice) = PickProxy::show(PickCfg::register(items)).await {
+				emit!(Call(Cmd::args("register", &[names[choice]]), Layer::Manager));
+			}
+		});
+	}
+}
+
+fn describe(name: char, yanked: Option<&Yanked>) -> String {
+	let Some(yanked) = yanked else {
+		return format!("{name}  (empty)");
+	};
+
+	let mut files: Vec<_> =
+		yanked.iter().filter_map(|u| u.file_name()).map(|s| s.to_string_lossy()).collect();
+	files.sort_unstable();
+
+	let more = files.len().saturating_sub(3);
+	files.truncate(3);
+
+	let mut s = format!("{name}  {}{}", if yanked.cut { "[cut] " } else { "" }, files.join(", "));
+	if more > 0 {
+		s.push_str(&format!(" (+{more})"));
+	}
+	s
+}
@@ -0,0 +1,116 @@
+use yazi_config::{REMOTES, popup::PickCfg, remote::Remote};
+use yazi_macro::emit;
+use yazi_plugin::Secret;
+use yazi_proxy::{AppProxy, PickProxy, TabProxy};
+use yazi_scheduler::process::{ShellOpt, shell};
+use yazi_shared::{Layer, errors::{ErrorCode, ErrorReport}, event::{Cmd, CmdCow}, url::Url};
+
+use crate::manager::Manager;
+
+struct Opt {
+	name: Option<String>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(mut c: CmdCow) -> Self { Self { name: c.take_first_str().map(|s| s.into_owned()) } }
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn remotes(&mut self, opt: Opt) {
+		if REMOTES.load().list.is_empty() {
+			return AppProxy::notify_warn("Remotes", "No remotes defined in your remotes.toml");
+		}
+
+		if let Some(name) = opt.name {
+			match REMOTES.load().get(&name) {
+				Some(r) => _ = tokio::spawn(connect(r.clone(), self.cwd().clone())),
+				None => AppProxy::notify_warn("Remotes", format!("No remote named `{name}`")),
+			}
+			return;
+		}
+
+		let names: Vec<_> = REMOTES.load().list.iter().map(|r| r.name.clone()).collect();
+		let items = REMOTES.load().list.iter().map(describe).collect();
+
+		tokio::spawn(async move {
+			if let Ok(choice) = PickProxy::show(PickCfg::remotes(items)).await {
+				emit!(Call(Cmd::args("remotes", &[&names[choice]]), Layer::Manager));
+			}
+		});
+	}
+}
+
+fn describe(remote: &Remote) -> String {
+	let status = match remote.mounted() {
+		Some(fs) if fs.is_empty() => "● mounted".to_owned(),
+		Some(fs) => format!("● mounted ({fs})"),
+		None if remote.run.is_some() => "○ not mounted".to_owned(),
+		None => "○ unavailable".to_owned(),
+	};
+
+	let kind = if remote.kind.is_empty() { String::new() } else { format!("[{}] ", remote.kind) };
+	format!("{}  {kind}{status}  {}", remote.name, remote.mount.display())
+}
+
+/// Mounts the remote if it isn't, with its `run` command, then goes into it.
+async fn connect(remote: Remote, cwd: Url) {
+	if remote.mounted().is_none() {
+		let Some(run) = remote.run else {
+			return AppProxy::notify_warn(
+				"Remotes",
+				format!("`{}` isn't mounted, and has no `run` to mount it", remote.name),
+			);
+		};
+
+		let stdin = if remote.secret {
+			match Secret::get(&format!("remote/{}", remote.name)).await {
+				Ok(Some(s)) => Some(s.into_bytes()),
+				Ok(None) => {
+					return AppProxy::notify_warn(
+						"Remotes",
+						format!("No secret stored in the keyring for `remote/{}`", remote.name),
+					);
+				}
+				Err(e) => {
+					return AppProxy::notify_error("Remotes", format!("Failed to get the secret: {e}"));
+				}
+			}
+		} else {
+			None
+		};
+
+		AppProxy::notify_info("Remotes", format!("Mounting `{}`...", remote.name));
+		let result = shell(ShellOpt {
+			cwd,
+			cmd: run.into(),
+			args: vec![],
+			env: Default::default(),
+			stdin,
+			piped: true,
+			capture: false,
+			orphan: false,
+		});
+
+		let report = |code, e: &dyn std::fmt::Display| {
+			let report = ErrorReport::new(code, format!("Failed to mount: {e}")).with_path(&remote.mount);
+			AppProxy::notify_report("Remotes", report)
+		};
+		let output = match result {
+			Ok(child) => child.wait_with_output().await,
+			Err(e) => return report(ErrorCode::Remote, &e),
+		};
+		match output {
+			Ok(o) if o.status.success() => {}
+			Ok(o) => {
+				// The shell exits with 127 if the command isn't found
+				let code =
+					if o.status.code() == Some(127) { ErrorCode::CommandNotFound } else { ErrorCode::Remote };
+				return report(code, &String::from_utf8_lossy(&o.stderr).trim());
+			}
+			Err(e) => return report(ErrorCode::Remote, &e),
+		}
+	}
+
+	TabProxy::cd(&Url::from(remote.mount));
+}
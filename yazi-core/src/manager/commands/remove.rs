@@ -1,12 +1,13 @@
-use yazi_config::popup::ConfirmCfg;
-use yazi_proxy::{ConfirmProxy, ManagerProxy};
+use yazi_config::{CONFIRM, popup::{ConfirmCfg, InputCfg}};
+use yazi_proxy::{ConfirmProxy, InputProxy, ManagerProxy};
 use yazi_shared::{event::CmdCow, url::Url};
 
-use crate::{manager::Manager, tasks::Tasks};
+use crate::{manager::Manager, tab::Tab, tasks::Tasks};
 
 struct Opt {
 	force:       bool,
 	permanently: bool,
+	secure:      bool,
 	hovered:     bool,
 	targets:     Vec<Url>,
 }
@@ -15,7 +16,8 @@ impl From<CmdCow> for Opt {
 	fn from(mut c: CmdCow) -> Self {
 		Self {
 			force:       c.bool("force"),
-			permanently: c.bool("permanently"),
+			permanently: c.bool("permanently") || c.bool("secure"),
+			secure:      c.bool("secure"),
 			hovered:     c.bool("hovered"),
 			targets:     c.take_any("targets").unwrap_or_default(),
 		}
@@ -35,36 +37,56 @@ impl Manager {
 			self.selected_or_hovered().cloned().collect()
 		};
 
+		let policy =
+			if opt.permanently { CONFIRM.load().delete_policy } else { CONFIRM.load().trash_policy };
 		if opt.targets.is_empty() {
 			return;
-		} else if opt.force {
+		} else if opt.force || (!opt.secure && !policy.ask(opt.targets.len())) {
 			return self.remove_do(opt, tasks);
 		}
 
+		// Always confirm a secure deletion, to warn about where it can't be relied on
 		tokio::spawn(async move {
-			let result = ConfirmProxy::show(if opt.permanently {
+			let result = ConfirmProxy::show(if opt.secure {
+				ConfirmCfg::shred(&opt.targets)
+			} else if opt.permanently {
 				ConfirmCfg::delete(&opt.targets)
 			} else {
 				ConfirmCfg::trash(&opt.targets)
 			});
+			if !result.await {
+				return;
+			}
 
-			if result.await {
-				ManagerProxy::remove_do(opt.targets, opt.permanently);
+			let n = opt.targets.len();
+			if policy.typed(n) {
+				let mut result = InputProxy::show(InputCfg::count(n));
+				if !matches!(result.recv().await, Some(Ok(s)) if s.trim() == n.to_string()) {
+					return;
+				}
 			}
+
+			ManagerProxy::remove_do(opt.targets, opt.permanently, opt.secure);
 		});
 	}
 
 	#[yazi_codegen::command]
 	pub fn remove_do(&mut self, opt: Opt, tasks: &Tasks) {
+		// By their parents, as removing a symlink leaves where it points to alone
+		if !Tab::confine(opt.targets.iter().map(|u| u.parent().unwrap_or(u))) {
+			return;
+		}
+
 		self.tabs.iter_mut().for_each(|t| {
 			t.selected.remove_many(&opt.targets, false);
 		});
 
 		for u in &opt.targets {
 			self.yanked.remove(u);
+			self.registers.remove(u);
 		}
 
 		self.yanked.catchup_revision(false);
-		tasks.file_remove(opt.targets, opt.permanently);
+		tasks.file_remove(opt.targets, opt.permanently, opt.secure);
 	}
 }
@@ -1,18 +1,20 @@
-use std::{borrow::Cow, collections::{HashMap, HashSet}};
+use std::{borrow::Cow, collections::{BTreeMap, HashMap, HashSet}, path::{Path, PathBuf}};
 
 use anyhow::Result;
 use tokio::fs;
-use yazi_config::popup::{ConfirmCfg, InputCfg};
+use yazi_config::{CONFIRM, popup::{ConfirmCfg, InputCfg, PickCfg}, project::Project};
 use yazi_dds::Pubsub;
-use yazi_fs::{File, FilesOp, maybe_exists, ok_or_not_found, paths_to_same_file, realname};
-use yazi_proxy::{ConfirmProxy, InputProxy, TabProxy, WATCHER};
-use yazi_shared::{Id, event::CmdCow, url::{Url, UrnBuf}};
+use yazi_fs::{File, FilesOp, clean_path, maybe_exists, ok_or_not_found, path_relative_to, paths_to_same_file, realname, same_name_in};
+use yazi_plugin::external;
+use yazi_proxy::{AppProxy, ConfirmProxy, InputProxy, ManagerProxy, PickProxy, TabProxy, WATCHER};
+use yazi_shared::{Id, errors::ErrorReport, escape_os_str, event::CmdCow, unescape_os_str, url::{Url, UrnBuf}};
 
-use crate::manager::Manager;
+use crate::{manager::Manager, tab::Tab};
 
 struct Opt {
 	hovered: bool,
 	force:   bool,
+	refs:    bool,
 	empty:   Cow<'static, str>,
 	cursor:  Cow<'static, str>,
 }
@@ -22,6 +24,7 @@ impl From<CmdCow> for Opt {
 		Self {
 			hovered: c.bool("hovered"),
 			force:   c.bool("force"),
+			refs:    c.bool("refs"),
 			empty:   c.take_str("empty").unwrap_or_default(),
 			cursor:  c.take_str("cursor").unwrap_or_default(),
 		}
@@ -63,11 +66,42 @@ impl Manager {
 				return;
 			}
 
-			let new = Url::from(old.parent().unwrap().join(name));
-			if opt.force || !maybe_exists(&new).await || paths_to_same_file(&old, &new).await {
-				Self::rename_do(tab, old, new).await.ok();
-			} else if ConfirmProxy::show(ConfirmCfg::overwrite(&new)).await {
-				Self::rename_do(tab, old, new).await.ok();
+			// Names that aren't valid UTF-8 were escaped for editing, turn them back
+			let new = if old.name().to_str().is_some() {
+				let parent = Url::from(old.parent().unwrap());
+				match Self::expand_input(&parent, &name).await.as_deref() {
+					Some([new]) => new.clone(),
+					Some(news) => {
+						return AppProxy::notify_warn(
+							"Rename",
+							format!("`{name}` expands to {} paths", news.len()),
+						);
+					}
+					None => return,
+				}
+			} else {
+				Url::from(old.parent().unwrap().join(unescape_os_str(&name)))
+			};
+			if !Tab::confine([&new]) {
+				return;
+			}
+
+			let go = opt.force
+				|| !CONFIRM.load().overwrite_policy.ask(1)
+				|| !maybe_exists(&new).await
+				|| paths_to_same_file(&old, &new).await
+				|| same_name_in(new.parent().unwrap(), old.name(), new.name()).await
+				|| ConfirmProxy::show(ConfirmCfg::overwrite(&new)).await;
+			if !go {
+				return;
+			} else if let Err(e) = Self::rename_do(tab, old.clone(), new.clone()).await {
+				return AppProxy::notify_report("Rename", ErrorReport::from(&e).with_path(&old));
+			} else if !opt.refs {
+				return;
+			}
+
+			if let Err(e) = Self::rename_refs(&old, &new).await {
+				AppProxy::notify_warn("Rename", format!("Failed to update the references: {e}"));
 			}
 		});
 	}
@@ -85,6 +119,7 @@ impl Manager {
 			FilesOp::Deleting(p_new.clone(), HashSet::from_iter([UrnBuf::from(o)])).emit();
 		}
 		Pubsub::pub_from_rename(tab, &old, &new);
+		ManagerProxy::update_tags(vec![(old.clone(), new.clone())]);
 
 		let file = File::from(new.clone()).await?;
 		if p_new == p_old {
@@ -97,6 +132,160 @@ impl Manager {
 		Ok(TabProxy::reveal(&new))
 	}
 
+	/// Offer to update the references to the old path to the new one, where
+	/// they're taken as relative to the file they're in, or to the root of the
+	/// project it's in, see [`Self::retarget_line`].
+	async fn rename_refs(old: &Path, new: &Path) -> Result<()> {
+		let Some(root) = old.parent().and_then(Self::project_root) else { return Ok(()) };
+		let Some(name) = old.file_name().and_then(|s| s.to_str()) else { return Ok(()) };
+		let rel = |p: &Path| p.strip_prefix(&root).ok().and_then(Self::slashed);
+
+		let new = clean_path(new);
+		let lines: Vec<_> = external::rg_literal(&root, name)
+			.await?
+			.into_iter()
+			.filter_map(|l| {
+				let text = Self::retarget_line(&l.text, l.path.parent()?, &root, old, &new)?;
+				Some((l, text))
+			})
+			.collect();
+		if lines.is_empty() {
+			let from = rel(old).unwrap_or_else(|| name.to_owned());
+			return Ok(AppProxy::notify_info("Rename", format!("No references to `{from}` found")));
+		}
+
+		let items = lines
+			.iter()
+			.map(|(l, _)| format!("{}:{}: {}", rel(&l.path).unwrap_or_default(), l.line, l.text.trim()))
+			.collect();
+		let Ok(chosen) = PickProxy::show_checklist(PickCfg::refs(items)).await else { return Ok(()) };
+
+		let mut files: BTreeMap<&Path, HashMap<usize, (&str, &str)>> = BTreeMap::new();
+		for (l, text) in chosen.into_iter().map(|i| &lines[i]) {
+			files.entry(&l.path).or_default().insert(l.line, (&l.text, text));
+		}
+
+		let (mut n, mut failed) = (0, vec![]);
+		for (path, changes) in &files {
+			let result = async {
+				let (s, mut done) = (fs::read_to_string(path).await?, 0);
+				let s: String = s
+					.split_inclusive('\n')
+					.enumerate()
+					.map(|(i, l)| {
+						let body = l.trim_end_matches(['\r', '\n']);
+						match changes.get(&(i + 1)) {
+							// Left as is if it's changed since it was searched
+							Some(&(before, after)) if body == before => {
+								done += 1;
+								Cow::Owned(format!("{after}{}", &l[body.len()..]))
+							}
+							_ => l.into(),
+						}
+					})
+					.collect();
+				fs::write(path, s).await.map(|_| done)
+			};
+			match result.await {
+				Ok(done) => n += done,
+				Err(e) => failed.push(format!("{}: {e}", path.display())),
+			}
+		}
+
+		if failed.is_empty() {
+			AppProxy::notify_info(
+				"Rename",
+				format!("Updated {n} reference(s) in {} file(s)", files.len()),
+			);
+		} else {
+			AppProxy::notify_warn("Rename", failed.join("\n"));
+		}
+		Ok(())
+	}
+
+	/// The line with each path in it that points to `old`, or to anything in it,
+	/// pointed to `new` instead, if there are any.
+	///
+	/// A path is a whole token of the line, with a `/` or `.` in it, and any
+	/// `#anchor` or `?query` kept as is. It's taken as relative to `dir`, the
+	/// directory of the file it's in, then to `root`, or to `root` only if it
+	/// starts with a `/` that isn't an absolute path to it, and is written back
+	/// the same way.
+	fn retarget_line(line: &str, dir: &Path, root: &Path, old: &Path, new: &Path) -> Option<String> {
+		let delimiter = |c: char| c.is_whitespace() || "\"'`()[]<>{},;=|".contains(c);
+
+		let (mut out, mut last) = (String::with_capacity(line.len()), 0);
+		let mut start = None;
+		for (i, c) in line.char_indices().chain([(line.len(), ' ')]) {
+			match (start, delimiter(c)) {
+				(None, false) => start = Some(i),
+				(Some(s), true) => {
+					start = None;
+					let Some(to) = Self::retarget_token(&line[s..i], dir, root, old, new) else { continue };
+					out.push_str(&line[last..s]);
+					out.push_str(&to);
+					last = i;
+				}
+				_ => {}
+			}
+		}
+
+		(last > 0).then(|| out + &line[last..])
+	}
+
+	fn retarget_token(
+		token: &str,
+		dir: &Path,
+		root: &Path,
+		old: &Path,
+		new: &Path,
+	) -> Option<String> {
+		let p = &token[..token.find(['#', '?']).unwrap_or(token.len())];
+		let p = p.trim_end_matches(['.', ':']);
+		if !p.contains(['/', '.']) || p.contains("://") {
+			return None;
+		}
+
+		let candidates = if let Some(s) = p.strip_prefix('/') {
+			[(PathBuf::from(p), None), (root.join(s), Some(true))]
+		} else {
+			[(dir.join(p), Some(false)), (root.join(p), Some(true))]
+		};
+		let (target, base) = candidates.into_iter().find_map(|(c, base)| {
+			let rest = clean_path(c).strip_prefix(old).ok()?.to_owned();
+			Some((if rest.as_os_str().is_empty() { new.to_owned() } else { new.join(rest) }, base))
+		})?;
+
+		let to = match base {
+			None => target.to_str()?.to_owned(),
+			Some(true) if p.starts_with('/') => {
+				format!("/{}", Self::slashed(target.strip_prefix(root).ok()?)?)
+			}
+			Some(true) => Self::slashed(target.strip_prefix(root).ok()?)?,
+			Some(false) => match Self::slashed(&path_relative_to(&target, dir))? {
+				s if p.starts_with("./") && !s.starts_with("..") => format!("./{s}"),
+				s => s,
+			},
+		};
+		let slash = if p.ends_with('/') && !to.ends_with('/') { "/" } else { "" };
+		Some(format!("{to}{slash}{}", &token[p.len()..]))
+	}
+
+	/// The path with `/` as the separator, or `.` if it's empty.
+	fn slashed(p: &Path) -> Option<String> {
+		let parts: Option<Vec<_>> = p.iter().map(|s| s.to_str()).collect();
+		Some(parts?.join("/")).map(|s| if s.is_empty() { ".".to_owned() } else { s })
+	}
+
+	/// The closest directory at or above `dir` that's a Git repository or a
+	/// project with its own `.yazi/config.toml`.
+	fn project_root(dir: &Path) -> Option<PathBuf> {
+		dir
+			.ancestors()
+			.find(|p| p.join(".git").exists() || p.join(Project::FILE).is_file())
+			.map(ToOwned::to_owned)
+	}
+
 	fn empty_url_part(url: &Url, by: &str) -> String {
 		if by == "all" {
 			return String::new();
@@ -104,10 +293,44 @@ impl Manager {
 
 		let ext = url.extension();
 		match by {
-			"stem" => ext.map_or_else(String::new, |s| format!(".{}", s.to_string_lossy())),
-			"ext" if ext.is_some() => format!("{}.", url.file_stem().unwrap().to_string_lossy()),
-			"dot_ext" if ext.is_some() => url.file_stem().unwrap().to_string_lossy().into_owned(),
-			_ => url.name().to_string_lossy().into_owned(),
+			"stem" => ext.map_or_else(String::new, |s| format!(".{}", escape_os_str(s))),
+			"ext" if ext.is_some() => format!("{}.", escape_os_str(url.file_stem().unwrap())),
+			"dot_ext" if ext.is_some() => escape_os_str(url.file_stem().unwrap()).into_owned(),
+			_ => escape_os_str(url.name()).into_owned(),
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use std::path::Path;
+
+	use super::*;
+
+	#[test]
+	fn test_retarget_line() {
+		let (dir, root) = (Path::new("/p/docs"), Path::new("/p"));
+		let (old, new) = (Path::new("/p/docs/a.md"), Path::new("/p/guide/b.md"));
+		let retarget = |l| Manager::retarget_line(l, dir, root, old, new);
+
+		assert_eq!(retarget("[x](a.md#top)").as_deref(), Some("[x](../guide/b.md#top)"));
+		assert_eq!(retarget("[x](./a.md)").as_deref(), Some("[x](../guide/b.md)"));
+		assert_eq!(retarget("see docs/a.md.").as_deref(), Some("see guide/b.md."));
+		assert_eq!(retarget("<a href=\"/docs/a.md\">").as_deref(), Some("<a href=\"/guide/b.md\">"));
+		assert_eq!(retarget("/p/docs/a.md").as_deref(), Some("/p/guide/b.md"));
+
+		assert_eq!(retarget("[x](a.mdx) mydocs/a.md docs/a.md.bak"), None);
+		assert_eq!(retarget("https://example.com/docs/a.md"), None);
+	}
+
+	#[test]
+	fn test_retarget_dir() {
+		let (dir, root) = (Path::new("/p"), Path::new("/p"));
+		let (old, new) = (Path::new("/p/src"), Path::new("/p/lib"));
+		let retarget = |l| Manager::retarget_line(l, dir, root, old, new);
+
+		assert_eq!(retarget("include \"src/a.h\"").as_deref(), Some("include \"lib/a.h\""));
+		assert_eq!(retarget("./src/ and src/"), Some("./lib/ and lib/".to_owned()));
+		assert_eq!(retarget("the src folder"), None);
+	}
+}
@@ -0,0 +1,69 @@
+use std::{io, path::PathBuf};
+
+use anyhow::Result;
+use tokio::fs;
+use yazi_config::popup::InputCfg;
+use yazi_fs::{File, FilesOp, unique_name};
+use yazi_proxy::{AppProxy, InputProxy, WATCHER};
+use yazi_shared::{errors::ErrorReport, escape_os_str, event::CmdCow, unescape_os_str, url::Url};
+
+use crate::manager::Manager;
+
+impl Manager {
+	pub fn retarget(&mut self, _: CmdCow) {
+		let Some(hovered) = self.hovered() else { return };
+		let Some(to) = hovered.link_to.clone() else {
+			return AppProxy::notify_warn("Retarget", "The hovered file is not a symlink");
+		};
+
+		let link = hovered.url_owned();
+		tokio::spawn(async move {
+			let value = escape_os_str(to.as_os_str()).into_owned();
+			let mut result = InputProxy::show(InputCfg::retarget().with_value(value));
+			let Some(Ok(s)) = result.recv().await else {
+				return;
+			};
+
+			// Targets that aren't valid UTF-8 were escaped for editing, turn them back
+			let new = if to.as_os_str().to_str().is_some() { s.into() } else { unescape_os_str(&s) };
+			if new.is_empty() || new.as_os_str() == to.as_os_str() {
+				return;
+			}
+
+			if let Err(e) = Self::retarget_do(link.clone(), Url::from(PathBuf::from(new))).await {
+				AppProxy::notify_report("Retarget", ErrorReport::from(&e).with_path(&link));
+			}
+		});
+	}
+
+	async fn retarget_do(link: Url, to: Url) -> Result<()> {
+		let Some((parent, urn)) = link.pair() else { return Ok(()) };
+		let _permit = WATCHER.acquire().await.unwrap();
+
+		// Create the new link next to the old one, then swap them atomically
+		let tmp = unique_name(parent.join(".yazi-retarget"), async { false }).await?;
+		Self::symlink(&parent.join(&to), &to, &tmp).await?;
+		if let Err(e) = fs::rename(&tmp, &link).await {
+			fs::remove_file(&tmp).await.ok();
+			Err(e)?;
+		}
+
+		let file = File::from(link).await?;
+		FilesOp::Upserting(parent, [(urn, file)].into()).emit();
+		Ok(())
+	}
+
+	#[cfg(unix)]
+	async fn symlink(_resolved: &Url, to: &Url, at: &Url) -> io::Result<()> {
+		fs::symlink(to, at).await
+	}
+
+	#[cfg(windows)]
+	async fn symlink(resolved: &Url, to: &Url, at: &Url) -> io::Result<()> {
+		if fs::metadata(resolved).await.is_ok_and(|m| m.is_dir()) {
+			fs::symlink_dir(to, at).await
+		} else {
+			fs::symlink_file(to, at).await
+		}
+	}
+}
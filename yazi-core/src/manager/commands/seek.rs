@@ -1,4 +1,4 @@
-use yazi_config::PLUGIN;
+use yazi_config::{LAYOUT, PLUGIN, plugin::PreviewOutput};
 use yazi_plugin::isolate;
 use yazi_shared::event::{CmdCow, Data};
 
@@ -16,10 +16,24 @@ impl From<CmdCow> for Opt {
 impl Manager {
 	#[yazi_codegen::command]
 	pub fn seek(&mut self, opt: Opt) {
-		let Some(hovered) = self.hovered() else {
+		if self.hovered().is_none() {
 			return self.active_mut().preview.reset();
-		};
+		}
+
+		if let Some(diff) = &mut self.active_mut().preview.diff {
+			if diff.images {
+				// Moves the wipe, by 5% of the width for each unit
+				if diff.unified {
+					diff.wipe = (diff.wipe as i16 + opt.units * 5).clamp(0, 100) as u8;
+					self.peek(true);
+				}
+				return;
+			}
+
+			return self.seek_lines(opt.units);
+		}
 
+		let Some(hovered) = self.hovered() else { return };
 		let Some(mime) = self.mimetype.by_file(hovered) else {
 			return self.active_mut().preview.reset();
 		};
@@ -28,6 +42,21 @@ impl Manager {
 			return self.active_mut().preview.reset();
 		};
 
-		isolate::seek_sync(&previewer.run, hovered.clone(), opt.units);
+		if previewer.exec.is_none() {
+			isolate::seek_sync(&previewer.run, hovered.clone(), opt.units);
+		} else if previewer.output != PreviewOutput::Image {
+			self.seek_lines(opt.units);
+		}
+	}
+
+	/// Scrolls a text preview rendered by yazi itself, by a tenth of its height
+	/// for each unit.
+	fn seek_lines(&mut self, units: i16) {
+		let step = units as isize * LAYOUT.get().preview.height as isize / 10;
+		let step = if step == 0 { units.signum() as isize } else { step };
+
+		let preview = &mut self.active_mut().preview;
+		preview.skip = preview.skip.saturating_add_signed(step);
+		self.peek(false);
 	}
 }
@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use yazi_fs::File;
+use yazi_proxy::{AppProxy, TabProxy};
+use yazi_shared::{event::{CmdCow, Data}, url::Url};
+
+use crate::{manager::{Manager, Slideshow}, tasks::Tasks};
+
+enum Opt {
+	Start { interval: Duration, shuffle: bool },
+	Step(isize),
+	Stop,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self {
+		if c.first_str() == Some("stop") {
+			return Self::Stop;
+		} else if let Some(step) = c.first().and_then(Data::as_isize) {
+			return Self::Step(step);
+		}
+
+		let secs = c.get("interval").and_then(Data::as_f64).filter(|&s| s > 0.0).unwrap_or(5.0);
+		Self::Start { interval: Duration::from_secs_f64(secs), shuffle: c.bool("shuffle") }
+	}
+}
+impl From<isize> for Opt {
+	fn from(step: isize) -> Self { Self::Step(step) }
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn slideshow(&mut self, opt: Opt, tasks: &Tasks) {
+		match opt {
+			Opt::Start { interval, shuffle } => {
+				let urls: Vec<_> = self
+					.active()
+					.selected
+					.keys()
+					.filter(|&u| self.mimetype.by_url(u).is_none_or(|m| m.starts_with("image/")))
+					.cloned()
+					.collect();
+				if urls.is_empty() {
+					return AppProxy::notify_warn("Slideshow", "No images are selected");
+				}
+
+				self.slideshow = Some(Slideshow::new(urls, interval, shuffle));
+				self.panes.quicklook = true;
+				AppProxy::resize();
+			}
+			Opt::Step(step) => {
+				let Some(slideshow) = &mut self.slideshow else { return };
+				slideshow.step(step);
+			}
+			Opt::Stop => return self.quicklook(false),
+		}
+
+		let Some(slideshow) = &self.slideshow else { return };
+		TabProxy::reveal(slideshow.current());
+
+		// Have the next one cached, so there's no waiting for it to be decoded
+		if let Some(next) = self.lookup(slideshow.next()) {
+			tasks.preload_paged(&[next], &self.mimetype);
+		}
+	}
+
+	fn lookup(&self, url: &Url) -> Option<File> {
+		let tab = self.active();
+		let parent = url.parent_url()?;
+		let folder = if parent == *tab.cwd() { &tab.current } else { tab.history.get(&parent)? };
+		folder.files.iter().find(|f| f.url == *url).cloned()
+	}
+}
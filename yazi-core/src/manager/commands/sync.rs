@@ -0,0 +1,88 @@
+use std::path::{Path, PathBuf};
+
+use yazi_config::popup::ConfirmCfg;
+use yazi_proxy::{AppProxy, ConfirmProxy, ManagerProxy};
+use yazi_shared::{event::CmdCow, url::Url};
+
+use crate::manager::{Manager, SyncJournal, plan};
+
+struct Opt {
+	hash:  bool,
+	fresh: bool,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self { Self { hash: c.bool("hash"), fresh: c.bool("fresh") } }
+}
+
+impl Manager {
+	/// Transfer what's missing or different from the directory of the active tab
+	/// to that of the other side, i.e. the tab it's compared with, or the next
+	/// one.
+	#[yazi_codegen::command]
+	pub fn sync(&mut self, opt: Opt) {
+		let id = self.active().id;
+		let (from, to) = match self.diff.as_ref().and_then(|d| Some((d, d.side(id)?))) {
+			Some((diff, side)) => (diff.cwds[side].clone(), diff.cwds[1 - side].clone()),
+			None if self.tabs.len() < 2 => {
+				return AppProxy::notify_warn("Sync", "Two tabs are required to sync directories");
+			}
+			None => {
+				(self.cwd().clone(), self.tabs[(self.tabs.cursor + 1) % self.tabs.len()].cwd().clone())
+			}
+		};
+
+		if !from.is_regular() || !to.is_regular() {
+			return AppProxy::notify_warn("Sync", "Only regular directories can be synced");
+		} else if from == to {
+			return AppProxy::notify_warn("Sync", "Cannot sync a directory with itself");
+		}
+
+		tokio::spawn(async move {
+			let (from, to) = (from.to_path_buf(), to.to_path_buf());
+			let journal = if opt.fresh { None } else { SyncJournal::load(&from, &to).await };
+
+			// A journal with nothing left is of a finished sync, which says nothing about
+			// what's changed since, so it's planned again
+			let journal = match journal {
+				Some(j) => Some(j.resume().await).filter(|j| !j.entries.is_empty()),
+				None => None,
+			};
+
+			let resumed = journal.is_some();
+			let journal = match journal {
+				Some(j) => j,
+				None => {
+					let entries = plan(&from, &to, Path::new(""), opt.hash).await;
+					SyncJournal::new(from.clone(), to.clone(), opt.hash, entries)
+				}
+			};
+
+			if journal.entries.is_empty() {
+				SyncJournal::remove(&from, &to).await;
+				return AppProxy::notify_info("Sync", "Already in sync");
+			} else if let Err(e) = journal.save().await {
+				AppProxy::notify_warn(
+					"Sync",
+					format!("Failed to save the journal, it can't be resumed: {e}"),
+				);
+			}
+
+			let lines = journal
+				.entries
+				.iter()
+				.map(|e| format!("{} {}", if e.new { '+' } else { '~' }, e.path.display()))
+				.collect();
+			if !ConfirmProxy::show(ConfirmCfg::sync(&from, &to, lines, resumed)).await {
+				return SyncJournal::remove(&from, &to).await;
+			}
+
+			let targets =
+				journal.entries.iter().map(|e| (url(&from, &e.path), url(&to, &e.path))).collect();
+			ManagerProxy::paste_do(targets, false, true, false);
+		});
+	}
+}
+
+#[inline]
+fn url(root: &Path, rel: &Path) -> Url { Url::from(PathBuf::from(root).join(rel)) }
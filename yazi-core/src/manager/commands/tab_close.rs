@@ -1,5 +1,5 @@
-use yazi_macro::render;
-use yazi_shared::event::{CmdCow, Data};
+use yazi_macro::{emit, render};
+use yazi_shared::{Layer, event::{Cmd, CmdCow, Data}};
 
 use crate::manager::Tabs;
 
@@ -23,7 +23,10 @@ impl Tabs {
 			return;
 		}
 
-		self.items.remove(opt.idx).shutdown();
+		let mut tab = self.items.remove(opt.idx);
+		tab.shutdown();
+		emit!(Call(Cmd::new("cancel").with("tab", tab.id), Layer::Tasks));
+
 		if opt.idx > self.cursor {
 			self.set_idx(self.cursor);
 		} else {
@@ -3,9 +3,7 @@ use yazi_macro::render;
 use yazi_proxy::AppProxy;
 use yazi_shared::{event::CmdCow, url::Url};
 
-use crate::{manager::Tabs, tab::Tab};
-
-const MAX_TABS: usize = 9;
+use crate::{manager::{MAX_TABS, Tabs}, tab::Tab};
 
 struct Opt {
 	url:     Url,
@@ -0,0 +1,40 @@
+use yazi_macro::render;
+use yazi_proxy::AppProxy;
+use yazi_shared::{event::CmdCow, url::Url};
+
+use crate::manager::Manager;
+
+struct Opt {
+	name:   String,
+	toggle: bool,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(mut c: CmdCow) -> Self {
+		Self { name: c.take_first_str().unwrap_or_default().into_owned(), toggle: c.bool("toggle") }
+	}
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn tag(&mut self, opt: Opt) {
+		if opt.name.is_empty() || opt.name.contains(',') {
+			return AppProxy::notify_warn(
+				"`tag` command",
+				"Tag names must be non-empty and cannot contain commas",
+			);
+		}
+
+		let urls: Vec<Url> = self.selected_or_hovered().cloned().collect();
+		if urls.is_empty() {
+			return;
+		}
+
+		let changed = if opt.toggle && urls.iter().all(|u| self.tags.has(u, &opt.name)) {
+			self.tags.remove(&urls, Some(&opt.name))
+		} else {
+			self.tags.add(&urls, &opt.name)
+		};
+		render!(changed);
+	}
+}
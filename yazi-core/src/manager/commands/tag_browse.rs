@@ -0,0 +1,48 @@
+use yazi_fs::{Cha, File, FilesOp};
+use yazi_proxy::TabProxy;
+use yazi_shared::event::CmdCow;
+
+use crate::manager::Manager;
+
+struct Opt {
+	name: Option<String>,
+	cwd:  bool,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(mut c: CmdCow) -> Self {
+		Self { name: c.take_first_str().map(|s| s.into_owned()), cwd: c.bool("cwd") }
+	}
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn tag_browse(&mut self, opt: Opt) {
+		if let Some(handle) = self.active_mut().search.take() {
+			handle.abort();
+		}
+
+		let cwd = self.cwd().to_regular();
+		let paths: Vec<_> = self
+			.tags
+			.tagged(opt.name.as_deref())
+			.filter(|&p| !opt.cwd || p.starts_with(&cwd))
+			.cloned()
+			.collect();
+
+		let target = cwd.into_search(&format!("tag:{}", opt.name.as_deref().unwrap_or("*")));
+		tokio::spawn(async move {
+			let ((), ticket) = (TabProxy::cd(&target), FilesOp::prepare(&target));
+
+			let mut files = Vec::with_capacity(paths.len());
+			for path in paths {
+				if let Ok(file) = File::from(target.join(path)).await {
+					files.push(file);
+				}
+			}
+
+			FilesOp::Part(target.clone(), files, ticket).emit();
+			FilesOp::Done(target, Cha::dummy(), ticket).emit();
+		});
+	}
+}
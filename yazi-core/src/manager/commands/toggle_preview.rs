@@ -0,0 +1,34 @@
+use yazi_proxy::AppProxy;
+use yazi_shared::event::CmdCow;
+
+use crate::manager::Manager;
+
+struct Opt {
+	state: Option<bool>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self {
+		Self {
+			state: match c.first_str() {
+				Some("show") => Some(true),
+				Some("hide") => Some(false),
+				_ => None,
+			},
+		}
+	}
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn toggle_preview(&mut self, opt: Opt) {
+		let hidden = opt.state.map_or(!self.panes.hidden, |show| !show);
+		if hidden == self.panes.hidden && !self.panes.maximized {
+			return;
+		}
+
+		self.panes.hidden = hidden;
+		self.panes.maximized = false;
+		AppProxy::resize();
+	}
+}
@@ -0,0 +1,52 @@
+use anyhow::{Result, anyhow};
+use yazi_adapter::Transform;
+use yazi_proxy::AppProxy;
+use yazi_shared::event::{CmdCow, Data};
+
+use crate::{manager::Manager, tasks::Tasks};
+
+struct Opt {
+	transform: Result<Transform>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self {
+		let transform = match c.first_str() {
+			Some("resize") => c
+				.get("max")
+				.and_then(Data::as_u32)
+				.filter(|&n| n > 0)
+				.map(Transform::Resize)
+				.ok_or_else(|| anyhow!("`resize` requires a positive `--max`")),
+			Some("convert") => Transform::convert(c.str("to").unwrap_or_default()),
+			Some("strip") => Ok(Transform::Strip),
+			Some("orient") => Ok(Transform::Orient),
+			Some(s) => Err(anyhow!("Unknown transform `{s}`")),
+			None => Err(anyhow!("No transform specified")),
+		};
+		Self { transform }
+	}
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn transform(&mut self, opt: Opt, tasks: &Tasks) {
+		let transform = match opt.transform {
+			Ok(t) => t,
+			Err(e) => return AppProxy::notify_warn("Transform", e.to_string()),
+		};
+
+		// Leave the ones whose mimetype isn't known yet to the task, which fails on
+		// non-images
+		let targets: Vec<_> = self
+			.selected_or_hovered()
+			.filter(|&u| self.mimetype.by_url(u).is_none_or(|m| m.starts_with("image/")))
+			.cloned()
+			.collect();
+
+		if targets.is_empty() {
+			return AppProxy::notify_warn("Transform", "No images selected");
+		}
+		tasks.image_transform(targets, transform);
+	}
+}
@@ -0,0 +1,20 @@
+use yazi_macro::render;
+use yazi_shared::{event::CmdCow, url::Url};
+
+use crate::manager::Manager;
+
+struct Opt {
+	name: Option<String>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(mut c: CmdCow) -> Self { Self { name: c.take_first_str().map(|s| s.into_owned()) } }
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn untag(&mut self, opt: Opt) {
+		let urls: Vec<Url> = self.selected_or_hovered().cloned().collect();
+		render!(self.tags.remove(&urls, opt.name.as_deref()));
+	}
+}
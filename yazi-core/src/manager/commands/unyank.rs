@@ -1,20 +1,27 @@
 use yazi_macro::render;
 use yazi_shared::event::CmdCow;
 
-use crate::manager::Manager;
+use crate::manager::{Manager, Registers};
 
-struct Opt;
+#[derive(Default)]
+struct Opt {
+	register: Option<char>,
+}
 
 impl From<CmdCow> for Opt {
-	fn from(_: CmdCow) -> Self { Self }
+	fn from(c: CmdCow) -> Self { Self { register: c.str("register").and_then(Registers::name) } }
 }
 impl From<()> for Opt {
-	fn from(_: ()) -> Self { Self }
+	fn from(_: ()) -> Self { Self::default() }
 }
 
 impl Manager {
 	#[yazi_codegen::command]
-	pub fn unyank(&mut self, _: Opt) {
+	pub fn unyank(&mut self, opt: Opt) {
+		if let Some(name) = self.registers.resolve(opt.register) {
+			return self.registers.set(name, Default::default());
+		}
+
 		let repeek = self.hovered().is_some_and(|f| f.is_dir() && self.yanked.contains_in(&f.url));
 		self.yanked.clear();
 
@@ -0,0 +1,31 @@
+use std::collections::HashMap;
+
+use yazi_macro::render;
+use yazi_shared::{event::CmdCow, url::{Url, UrnBuf}};
+
+use crate::manager::{DiffState, Manager};
+
+pub struct Opt {
+	cwds:   [Url; 2],
+	states: HashMap<UrnBuf, DiffState>,
+}
+
+impl TryFrom<CmdCow> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: CmdCow) -> Result<Self, Self::Error> {
+		Ok(Self { cwds: c.take_any("cwds").ok_or(())?, states: c.take_any("states").ok_or(())? })
+	}
+}
+
+impl Manager {
+	pub fn update_diff(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else { return };
+		let Some(diff) = &mut self.diff else { return };
+
+		if diff.cwds == opt.cwds {
+			diff.states = opt.states;
+			render!();
+		}
+	}
+}
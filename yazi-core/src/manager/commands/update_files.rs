@@ -5,7 +5,7 @@ use yazi_macro::render;
 use yazi_proxy::ManagerProxy;
 use yazi_shared::event::CmdCow;
 
-use crate::{manager::{LINKED, Manager}, tab::{Folder, Tab}, tasks::Tasks};
+use crate::{manager::{LINKED, Manager}, tab::Tab, tasks::Tasks};
 
 pub struct Opt {
 	op: FilesOp,
@@ -25,10 +25,25 @@ impl Manager {
 			return;
 		};
 
+		if let Some(diff) = &mut self.diff {
+			let mutated = matches!(
+				opt.op,
+				FilesOp::Creating(..)
+					| FilesOp::Deleting(..)
+					| FilesOp::Updating(..)
+					| FilesOp::Upserting(..)
+			);
+			if mutated && diff.cwds.contains(opt.op.cwd()) {
+				diff.compare();
+			}
+		}
+
 		let linked: Vec<_> = LINKED.read().from_dir(opt.op.cwd()).map(|u| opt.op.rebase(u)).collect();
 		for op in [opt.op].into_iter().chain(linked) {
 			let idx = self.tabs.cursor;
 			self.yanked.apply_op(&op);
+			self.registers.apply_op(&op);
+			render!(self.tags.apply_op(&op));
 
 			for (_, tab) in self.tabs.iter_mut().enumerate().filter(|(i, _)| *i != idx) {
 				Self::update_tab(tab, Cow::Borrowed(&op), tasks);
@@ -88,11 +103,13 @@ impl Manager {
 
 	fn update_hovered(tab: &mut Tab, op: Cow<FilesOp>) {
 		let url = op.cwd();
-		let folder = tab.history.entry(url.clone()).or_insert_with(|| Folder::from(url));
+		let folder = tab.history.get_or_insert(url);
 
 		let foreign = matches!(op, Cow::Borrowed(_));
 		if !folder.update_pub(tab.id, op.into_owned()) {
 			return;
+		} else if !folder.stage.is_loading() {
+			tab.shrink_history();
 		}
 
 		if !foreign {
@@ -105,12 +122,10 @@ impl Manager {
 			|(p, n)| matches!(*op, FilesOp::Deleting(ref parent, ref urns) if *parent == p && urns.contains(n)),
 		);
 
-		tab
-			.history
-			.entry(op.cwd().clone())
-			.or_insert_with(|| Folder::from(op.cwd()))
-			.update_pub(tab.id, op.into_owned());
-
+		let folder = tab.history.get_or_insert(op.cwd());
+		if folder.update_pub(tab.id, op.into_owned()) && !folder.stage.is_loading() {
+			tab.shrink_history();
+		}
 		if leave {
 			tab.leave(());
 		}
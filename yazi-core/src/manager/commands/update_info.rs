@@ -0,0 +1,29 @@
+use yazi_fs::Info;
+use yazi_macro::render;
+use yazi_shared::{event::CmdCow, url::Url};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	url:  Url,
+	info: Info,
+}
+
+impl TryFrom<CmdCow> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: CmdCow) -> Result<Self, Self::Error> {
+		Ok(Self { url: c.take_any("url").ok_or(())?, info: c.take_any("info").ok_or(())? })
+	}
+}
+
+impl Manager {
+	pub fn update_info(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else { return };
+		if self.hovered().is_none_or(|h| h.url != opt.url) {
+			return;
+		}
+
+		render!(self.hover_info.update(opt.url, opt.info));
+	}
+}
@@ -0,0 +1,26 @@
+use yazi_shared::{event::CmdCow, url::Url};
+
+use crate::{manager::{JOURNAL, Manager}, tasks::Tasks};
+
+pub struct Opt {
+	paste: Option<(Url, Url)>,
+	cut:   bool,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(mut c: CmdCow) -> Self { Self { paste: c.take_any("paste"), cut: c.bool("cut") } }
+}
+
+impl Manager {
+	/// Catches the journal up with the state, or records a paste once it's
+	/// settled where it goes.
+	#[yazi_codegen::command]
+	pub fn update_journal(&mut self, opt: Opt, tasks: &Tasks) {
+		if let Some((from, to)) = opt.paste {
+			JOURNAL.lock().paste(&from, &to, opt.cut);
+		} else {
+			let idle = tasks.ongoing().lock().is_empty();
+			JOURNAL.lock().update(self, idle);
+		}
+	}
+}
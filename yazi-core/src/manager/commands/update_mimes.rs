@@ -57,7 +57,7 @@ impl Manager {
 		if repeek {
 			self.peek(false);
 		}
-		tasks.fetch_paged(&affected, &self.mimetype);
+		tasks.fetch_paged(self.cwd(), &affected, &self.mimetype);
 		tasks.preload_paged(&affected, &self.mimetype);
 
 		render!();
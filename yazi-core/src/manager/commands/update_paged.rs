@@ -28,8 +28,11 @@ impl Manager {
 			return;
 		}
 
+		// Visible files go first, so they won't wait behind the rest of the page
+		tasks.fetch_visible(self.cwd(), self.current().window(), &self.mimetype);
+
 		let targets = self.current().paginate(opt.page.unwrap_or(self.current().page));
-		tasks.fetch_paged(targets, &self.mimetype);
+		tasks.fetch_paged(self.cwd(), targets, &self.mimetype);
 		tasks.preload_paged(targets, &self.mimetype);
 	}
 }
@@ -0,0 +1,28 @@
+use yazi_macro::render;
+use yazi_shared::{event::CmdCow, url::Url};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	moved: Vec<(Url, Url)>,
+}
+
+impl TryFrom<CmdCow> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: CmdCow) -> Result<Self, Self::Error> {
+		Ok(Self { moved: c.take_any("moved").ok_or(())? })
+	}
+}
+
+impl Manager {
+	/// Carries the tags over to where the files are moved, before the deletion
+	/// of the old paths is reported, which would drop them.
+	pub fn update_tags(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+
+		render!(self.tags.rename(opt.moved.iter().map(|(from, to)| (from, to))));
+	}
+}
@@ -1,14 +1,17 @@
 use yazi_macro::render;
 use yazi_shared::event::CmdCow;
 
-use crate::manager::{Manager, Yanked};
+use crate::manager::{Manager, Registers, Yanked};
 
 struct Opt {
-	cut: bool,
+	cut:      bool,
+	register: Option<char>,
 }
 
 impl From<CmdCow> for Opt {
-	fn from(c: CmdCow) -> Self { Self { cut: c.bool("cut") } }
+	fn from(c: CmdCow) -> Self {
+		Self { cut: c.bool("cut"), register: c.str("register").and_then(Registers::name) }
+	}
 }
 
 impl Manager {
@@ -18,8 +21,13 @@ impl Manager {
 			return;
 		}
 
-		self.yanked = Yanked::new(opt.cut, self.selected_or_hovered().cloned().collect());
-		render!(self.yanked.catchup_revision(true));
+		let yanked = Yanked::new(opt.cut, self.selected_or_hovered().cloned().collect());
+		if let Some(name) = self.registers.resolve(opt.register) {
+			self.registers.set(name, yanked);
+		} else {
+			self.yanked = yanked;
+			render!(self.yanked.catchup_revision(true));
+		}
 
 		self.active_mut().escape_select();
 	}
@@ -0,0 +1,33 @@
+use yazi_proxy::AppProxy;
+use yazi_shared::event::CmdCow;
+
+use crate::manager::Manager;
+
+struct Opt {
+	state: Option<bool>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self {
+		Self {
+			state: match c.first_str() {
+				Some("on") => Some(true),
+				Some("off") => Some(false),
+				_ => None,
+			},
+		}
+	}
+}
+
+impl Manager {
+	#[yazi_codegen::command]
+	pub fn zen(&mut self, opt: Opt) {
+		let zen = opt.state.unwrap_or(!self.panes.zen);
+		if zen == self.panes.zen {
+			return;
+		}
+
+		self.panes.zen = zen;
+		AppProxy::resize();
+	}
+}
@@ -0,0 +1,102 @@
+use std::{collections::HashSet, sync::Arc};
+
+use parking_lot::RwLock;
+use yazi_config::{KEYMAP, NETWORK, keymap::Chord};
+use yazi_shared::{Layer, RoCell, url::Url};
+
+use super::PROJECTS;
+use crate::tab::Tab;
+
+pub static CONTEXTS: RoCell<RwLock<Contexts>> = RoCell::new();
+
+/// What the chords in the keymap can be conditional on with `when`, i.e. the
+/// built-in `git`, `archive`, `remote`, `filter` and `selected` contexts of the
+/// active tab, and the layers pushed by plugins with `keymap layer push`.
+#[derive(Default)]
+pub struct Contexts {
+	cwd:      Url,
+	git:      bool,
+	remote:   bool,
+	filter:   bool,
+	selected: bool,
+	layers:   Vec<String>,
+}
+
+impl Contexts {
+	pub fn has(&self, name: &str) -> bool {
+		match name {
+			"git" => self.git,
+			"archive" => self.cwd.is_archive(),
+			"remote" => self.remote,
+			"filter" => self.filter,
+			"selected" => self.selected,
+			_ => self.layers.iter().any(|l| l == name),
+		}
+	}
+
+	/// Chords of the layer active in the current contexts, where the ones of the
+	/// active project come first, then the conditional ones, shadowing the rest
+	/// with the same keys.
+	pub fn chords(&self, layer: Layer) -> Vec<Arc<Chord>> {
+		let mut seen = HashSet::new();
+		self.all(layer).into_iter().filter(|c| seen.insert(c.on.clone())).collect()
+	}
+
+	/// Like [`Self::chords`], but including the shadowed ones.
+	pub fn all(&self, layer: Layer) -> Vec<Arc<Chord>> {
+		let active = PROJECTS.read().active.clone();
+		let project: &[Arc<Chord>] = match (layer, &active) {
+			(Layer::Manager, Some(p)) => &p.keymap,
+			_ => &[],
+		};
+
+		let keymap = KEYMAP.load();
+		let global = keymap.get(layer);
+		project
+			.iter()
+			.chain(global.iter().filter(|c| !c.when.is_empty()))
+			.chain(global.iter().filter(|c| c.when.is_empty()))
+			.filter(|c| c.active(|w| self.has(w)))
+			.cloned()
+			.collect()
+	}
+
+	/// Follow the active tab, where the `git` context is looked up in the
+	/// background each time the CWD changes.
+	pub fn sync(&mut self, tab: &Tab) {
+		self.filter = tab.current.files.filter().is_some();
+		self.selected = !tab.selected.is_empty();
+		if self.cwd == *tab.cwd() {
+			return;
+		}
+
+		self.cwd = tab.cwd().clone();
+		self.remote = self.cwd.is_regular() && NETWORK.load().at(&self.cwd).is_some();
+		self.git = false;
+		if !self.cwd.is_regular() {
+			return;
+		}
+
+		let cwd = self.cwd.clone();
+		tokio::task::spawn_blocking(move || {
+			let git = cwd.ancestors().any(|p| p.join(".git").exists());
+			let mut me = CONTEXTS.write();
+			if me.cwd == cwd {
+				me.git = git;
+			}
+		});
+	}
+
+	pub fn push(&mut self, layer: String) {
+		self.layers.retain(|l| *l != layer);
+		self.layers.push(layer);
+	}
+
+	/// Pop the given layer, or the top one if not given.
+	pub fn pop(&mut self, layer: Option<&str>) -> bool {
+		match layer {
+			Some(l) => self.layers.iter().rposition(|s| s == l).map(|i| self.layers.remove(i)).is_some(),
+			None => self.layers.pop().is_some(),
+		}
+	}
+}
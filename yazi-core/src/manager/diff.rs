@@ -0,0 +1,165 @@
+use std::{collections::HashMap, ffi::OsString, fs::Metadata, future::Future, io::Read, path::{Path, PathBuf}, pin::Pin};
+
+use tokio::{fs, task::JoinHandle};
+use twox_hash::XxHash3_128;
+use yazi_macro::emit;
+use yazi_shared::{Id, Layer, event::Cmd, url::{Url, UrnBuf}};
+
+/// The state of an entry, compared to the one with the same name on the other
+/// side.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffState {
+	Left,
+	Right,
+	Changed,
+	Same,
+}
+
+/// Two tabs compared side by side, following the navigation of each other
+/// under the directories they were compared from.
+pub struct Diff {
+	pub tabs:    [Id; 2],
+	pub by_hash: bool,
+	pub roots:   [Url; 2],
+	pub cwds:    [Url; 2],
+
+	pub(super) states: HashMap<UrnBuf, DiffState>,
+	handle:            Option<JoinHandle<()>>,
+}
+
+impl Diff {
+	pub fn new(tabs: [Id; 2], roots: [Url; 2], by_hash: bool) -> Self {
+		let mut diff =
+			Self { tabs, by_hash, cwds: roots.clone(), roots, states: Default::default(), handle: None };
+		diff.compare();
+		diff
+	}
+
+	#[inline]
+	pub fn side(&self, tab: Id) -> Option<usize> { self.tabs.iter().position(|&t| t == tab) }
+
+	/// The state of `url` seen from `tab`: `unique` if it only exists on that
+	/// side, `changed` or `same` if it exists on both.
+	pub fn state(&self, tab: Id, url: &Url) -> Option<&'static str> {
+		let side = self.side(tab)?;
+		if url.parent_url().as_ref() != Some(&self.cwds[side]) {
+			return None;
+		}
+
+		Some(match *self.states.get(url.urn())? {
+			DiffState::Left | DiffState::Right => "unique",
+			DiffState::Changed => "changed",
+			DiffState::Same => "same",
+		})
+	}
+
+	/// Entries of the side of `tab` in the given state, where `Left` means the
+	/// ones that only exist on that side regardless of which side it is.
+	pub fn entries(&self, tab: Id, state: DiffState) -> Vec<Url> {
+		let Some(side) = self.side(tab) else { return vec![] };
+		let state = match (side, state) {
+			(1, DiffState::Left) => DiffState::Right,
+			(_, s) => s,
+		};
+
+		self.states.iter().filter(|&(_, &s)| s == state).map(|(n, _)| self.cwds[side].join(n)).collect()
+	}
+
+	pub(super) fn compare(&mut self) {
+		self.handle.take().map(|h| h.abort());
+
+		let (cwds, by_hash) = (self.cwds.clone(), self.by_hash);
+		self.handle = Some(tokio::spawn(async move {
+			let states = compare(&cwds[0], &cwds[1], by_hash).await;
+			emit!(Call(
+				Cmd::new("update_diff").with_any("cwds", cwds).with_any("states", states),
+				Layer::Manager
+			));
+		}));
+	}
+
+	pub(super) fn abort(&mut self) { self.handle.take().map(|h| h.abort()); }
+}
+
+async fn compare(left: &Path, right: &Path, by_hash: bool) -> HashMap<UrnBuf, DiffState> {
+	let (a, b) = (entries(left).await, entries(right).await);
+
+	let mut states = HashMap::with_capacity(a.len().max(b.len()));
+	for (name, m) in &a {
+		let state = match b.get(name) {
+			None => DiffState::Left,
+			Some(n) if same(&left.join(name), &right.join(name), m, n, by_hash).await => DiffState::Same,
+			Some(_) => DiffState::Changed,
+		};
+		states.insert(UrnBuf::from(name), state);
+	}
+	for name in b.keys().filter(|&n| !a.contains_key(n)) {
+		states.insert(UrnBuf::from(name), DiffState::Right);
+	}
+	states
+}
+
+pub(super) async fn entries(dir: &Path) -> HashMap<OsString, Metadata> {
+	let mut map = HashMap::new();
+	let Ok(mut it) = fs::read_dir(dir).await else { return map };
+	while let Ok(Some(entry)) = it.next_entry().await {
+		if let Ok(meta) = fs::symlink_metadata(entry.path()).await {
+			map.insert(entry.file_name(), meta);
+		}
+	}
+	map
+}
+
+pub(super) fn same<'a>(
+	a: &'a Path,
+	b: &'a Path,
+	m: &'a Metadata,
+	n: &'a Metadata,
+	by_hash: bool,
+) -> Pin<Box<dyn Future<Output = bool> + Send + 'a>> {
+	Box::pin(async move {
+		let (t, u) = (m.file_type(), n.file_type());
+		if t.is_symlink() || u.is_symlink() {
+			return t.is_symlink() == u.is_symlink()
+				&& fs::read_link(a).await.ok() == fs::read_link(b).await.ok();
+		} else if t.is_dir() != u.is_dir() {
+			return false;
+		} else if !t.is_dir() {
+			return m.len() == n.len()
+				&& if by_hash { hash(a).await == hash(b).await } else { mtime(m) == mtime(n) };
+		}
+
+		let (x, y) = (entries(a).await, entries(b).await);
+		if x.len() != y.len() {
+			return false;
+		}
+		for (name, m) in &x {
+			let Some(n) = y.get(name) else { return false };
+			if !same(&a.join(name), &b.join(name), m, n, by_hash).await {
+				return false;
+			}
+		}
+		true
+	})
+}
+
+#[inline]
+fn mtime(m: &Metadata) -> Option<u64> {
+	m.modified().ok()?.duration_since(std::time::UNIX_EPOCH).ok().map(|d| d.as_secs())
+}
+
+async fn hash(path: &Path) -> Option<u128> {
+	let path: PathBuf = path.to_owned();
+	tokio::task::spawn_blocking(move || {
+		let mut file = std::fs::File::open(path).ok()?;
+		let (mut hasher, mut buf) = (XxHash3_128::new(), vec![0; 64 * 1024]);
+		loop {
+			match file.read(&mut buf).ok()? {
+				0 => break Some(hasher.finish_128()),
+				n => hasher.write(&buf[..n]),
+			}
+		}
+	})
+	.await
+	.ok()?
+}
@@ -0,0 +1,52 @@
+use tokio::task::JoinHandle;
+use yazi_config::BARS;
+use yazi_fs::Info;
+use yazi_macro::emit;
+use yazi_shared::{Layer, event::Cmd, url::Url};
+
+/// The [`Info`] of the hovered file, for the `info` segment of the bars.
+#[derive(Default)]
+pub struct HoverInfo {
+	pub url:  Option<Url>,
+	pub info: Option<Info>,
+
+	handle: Option<JoinHandle<()>>,
+}
+
+impl HoverInfo {
+	#[inline]
+	pub fn get(&self, url: &Url) -> Option<&Info> {
+		self.info.as_ref().filter(|_| self.url.as_ref() == Some(url))
+	}
+
+	pub(super) fn fetch(&mut self, url: Option<Url>) {
+		if !BARS.status.has("info") && !BARS.header.has("info") {
+			return;
+		}
+
+		self.handle.take().map(|h| h.abort());
+		let Some(url) = url.filter(|u| u.is_regular() || u.is_search_item()) else {
+			return;
+		};
+
+		self.handle = Some(tokio::spawn(async move {
+			if let Ok(info) = Info::fetch(&url).await {
+				emit!(Call(
+					Cmd::new("update_info").with_any("url", url).with_any("info", info),
+					Layer::Manager
+				));
+			}
+		}));
+	}
+
+	/// Returns whether it's changed.
+	pub(super) fn update(&mut self, url: Url, info: Info) -> bool {
+		if self.url.as_ref() == Some(&url) && self.info.as_ref() == Some(&info) {
+			return false;
+		}
+
+		self.url = Some(url);
+		self.info = Some(info);
+		true
+	}
+}
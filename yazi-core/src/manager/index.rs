@@ -0,0 +1,196 @@
+use std::{collections::BTreeSet, io, mem, ops::Bound, path::{Path, PathBuf}, time::{Duration, Instant}};
+
+use parking_lot::RwLock;
+use tracing::{error, info};
+use yazi_boot::BOOT;
+use yazi_config::INDEXER;
+use yazi_fs::FilesOp;
+use yazi_shared::{RoCell, escape_os_str, unescape_os_str};
+
+pub static INDEX: RoCell<RwLock<Index>> = RoCell::new();
+
+/// A persistent index of the names under the roots in `[indexer]`, kept up to
+/// date by periodic rescans and the events of the watcher in between.
+#[derive(Default)]
+pub struct Index {
+	paths: BTreeSet<PathBuf>,
+	dirty: bool,
+}
+
+impl Index {
+	pub(super) fn serve() {
+		if !INDEXER.enabled {
+			return;
+		}
+
+		tokio::spawn(async {
+			match tokio::task::spawn_blocking(Self::load).await {
+				Ok(Ok(paths)) => INDEX.write().paths = paths,
+				Ok(Err(e)) if e.kind() != io::ErrorKind::NotFound => {
+					error!("Failed to load the index: {e}")
+				}
+				_ => {}
+			}
+
+			let mut scanned: Option<Instant> = None;
+			loop {
+				let due = scanned.is_none_or(|t| {
+					INDEXER.interval > 0 && t.elapsed() >= Duration::from_secs(INDEXER.interval)
+				});
+				if due {
+					let start = Instant::now();
+					if let Ok(paths) = tokio::task::spawn_blocking(Self::scan).await {
+						info!("Indexed {} entries in {:?}", paths.len(), start.elapsed());
+						*INDEX.write() = Self { paths, dirty: true };
+					}
+					scanned = Some(Instant::now());
+				}
+
+				if mem::take(&mut INDEX.write().dirty) {
+					if let Ok(Err(e)) = tokio::task::spawn_blocking(Self::save).await {
+						error!("Failed to save the index: {e}");
+					}
+				}
+				tokio::time::sleep(Duration::from_secs(60)).await;
+			}
+		});
+	}
+
+	#[inline]
+	pub fn len(&self) -> usize { self.paths.len() }
+
+	/// Paths containing all the whitespace-separated terms of `subject`, which
+	/// are case-insensitive unless they contain an uppercase letter.
+	pub fn search(&self, subject: &str) -> Vec<PathBuf> {
+		let terms: Vec<_> =
+			subject.split_whitespace().map(|t| (t, t.chars().any(char::is_uppercase))).collect();
+		if terms.is_empty() {
+			return vec![];
+		}
+
+		let insensitive = terms.iter().any(|&(_, sensitive)| !sensitive);
+		self
+			.paths
+			.iter()
+			.filter(|p| {
+				let s = p.to_string_lossy();
+				let lower = if insensitive { s.to_lowercase() } else { String::new() };
+				terms
+					.iter()
+					.all(|&(t, sensitive)| if sensitive { s.contains(t) } else { lower.contains(t) })
+			})
+			.cloned()
+			.collect()
+	}
+
+	/// Apply the changes reported by the watcher.
+	pub(super) fn apply(&mut self, ops: &[FilesOp]) {
+		if !INDEXER.enabled {
+			return;
+		}
+
+		for op in ops {
+			let cwd = op.cwd();
+			if !cwd.is_regular() {
+				continue;
+			}
+			match op {
+				FilesOp::Creating(_, files) => files.iter().for_each(|f| self.insert(f.url.to_path())),
+				FilesOp::Deleting(_, urns) => urns.iter().for_each(|u| self.remove(&cwd.join(u))),
+				FilesOp::Updating(_, files) | FilesOp::Upserting(_, files) => {
+					files.values().for_each(|f| self.insert(f.url.to_path()))
+				}
+				_ => {}
+			}
+		}
+	}
+
+	fn insert(&mut self, path: PathBuf) {
+		if INDEXER.covers(&path) && self.paths.insert(path) {
+			self.dirty = true;
+		}
+	}
+
+	fn remove(&mut self, path: &Path) {
+		// Descendants of a path are ordered right after it, since paths are compared by
+		// components
+		let gone: Vec<_> = self
+			.paths
+			.range::<Path, _>((Bound::Included(path), Bound::Unbounded))
+			.take_while(|p| p.starts_with(path))
+			.cloned()
+			.collect();
+
+		self.dirty |= !gone.is_empty();
+		for p in gone {
+			self.paths.remove(&p);
+		}
+	}
+
+	fn scan() -> BTreeSet<PathBuf> {
+		let mut paths = BTreeSet::new();
+		let mut stack: Vec<_> = INDEXER.roots.clone();
+
+		while let Some(dir) = stack.pop() {
+			let Ok(it) = std::fs::read_dir(&dir) else { continue };
+			for entry in it.flatten() {
+				let name = entry.file_name();
+				if INDEXER.exclude.iter().any(|e| name == e.as_str())
+					|| (!INDEXER.hidden && name.as_encoded_bytes().starts_with(b"."))
+				{
+					continue;
+				}
+
+				let path = entry.path();
+				if entry.file_type().is_ok_and(|t| t.is_dir()) {
+					stack.push(path.clone());
+				}
+				paths.insert(path);
+			}
+		}
+		paths
+	}
+
+	/// Paths are sorted, so each of them only stores the part that differs from
+	/// the previous one, which makes the file several times smaller.
+	fn save() -> io::Result<()> {
+		let mut buf = Vec::new();
+		let mut prev = String::new();
+		for path in &INDEX.read().paths {
+			let s = escape_os_str(path.as_os_str());
+			let mut n = prev.bytes().zip(s.bytes()).take_while(|(a, b)| a == b).count();
+			while !s.is_char_boundary(n) {
+				n -= 1;
+			}
+
+			buf.extend_from_slice(format!("{n}\t{}\0", &s[n..]).as_bytes());
+			prev = s.into_owned();
+		}
+
+		let tmp = Self::path().with_extension("tmp");
+		std::fs::write(&tmp, buf)?;
+		std::fs::rename(tmp, Self::path())
+	}
+
+	fn load() -> io::Result<BTreeSet<PathBuf>> {
+		let buf = std::fs::read_to_string(Self::path())?;
+
+		let mut paths = BTreeSet::new();
+		let mut prev = String::new();
+		for line in buf.split_terminator('\0') {
+			let Some((n, rest)) = line.split_once('\t') else { break };
+			let Some(head) = n.parse().ok().and_then(|n: usize| prev.get(..n)) else { break };
+
+			let s = format!("{head}{rest}");
+			let path = PathBuf::from(unescape_os_str(&s));
+			if INDEXER.covers(&path) {
+				paths.insert(path);
+			}
+			prev = s;
+		}
+		Ok(paths)
+	}
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join("index") }
+}
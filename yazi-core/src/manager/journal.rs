@@ -0,0 +1,265 @@
+use std::{fs::{File, OpenOptions}, path::{Path, PathBuf}, time::Duration};
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::{fs, sync::mpsc, time::sleep};
+use tracing::error;
+use yazi_boot::BOOT;
+use yazi_config::popup::ConfirmCfg;
+use yazi_macro::emit;
+use yazi_proxy::ConfirmProxy;
+use yazi_shared::{Layer, RoCell, event::Cmd, timestamp_us, url::Url};
+
+use super::{Manager, plan, synced};
+
+pub static JOURNAL: RoCell<Mutex<Journal>> = RoCell::new();
+
+/// Whether the journal is closed, held while it's written to so a write in
+/// flight doesn't bring it back after it's removed on exit.
+static CLOSED: Mutex<bool> = parking_lot::const_mutex(false);
+
+/// The volatile state of this instance, i.e. what's yanked, selected and being
+/// pasted, written to disk as it changes, so it can be recovered by the next
+/// launch if this one crashes or loses its terminal.
+#[derive(Default, Deserialize, Serialize)]
+pub struct Journal {
+	#[serde(default)]
+	pub yank:     Option<JournalYank>,
+	/// The selected files of each tab, in the order of the tabs.
+	#[serde(default)]
+	pub selected: Vec<Vec<PathBuf>>,
+	#[serde(default)]
+	pub pastes:   Vec<JournalPaste>,
+
+	#[serde(skip)]
+	written: String,
+	#[serde(skip)]
+	path:    PathBuf,
+	#[serde(skip)]
+	tx:      Option<mpsc::UnboundedSender<String>>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct JournalYank {
+	pub cut:   bool,
+	pub paths: Vec<PathBuf>,
+}
+
+/// A file being pasted, kept until all the file tasks are done.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct JournalPaste {
+	pub from: PathBuf,
+	pub to:   PathBuf,
+	pub cut:  bool,
+}
+
+impl Journal {
+	/// Catches up with the state every second, and offers to recover those left
+	/// by the instances that are gone.
+	///
+	/// Each instance journals to a file of its own, named by when it started,
+	/// with a lock held on the `.lock` next to it for as long as it's alive.
+	pub fn serve() {
+		let path = BOOT.state_dir.join(format!("journal/{:x}.toml", timestamp_us()));
+		let lock = std::fs::create_dir_all(path.parent().unwrap())
+			.and_then(|_| {
+				OpenOptions::new().create(true).truncate(false).write(true).open(lock_of(&path))
+			})
+			.and_then(|f| Ok(f.try_lock().map(|_| f)?));
+
+		let lock = match lock {
+			Ok(f) => f,
+			Err(e) => return error!("Failed to lock the journal, it's disabled: {e}"),
+		};
+
+		let (tx, rx) = mpsc::unbounded_channel();
+		tokio::spawn(Self::write(path.clone(), rx, lock));
+		let mut journal = JOURNAL.lock();
+		(journal.path, journal.tx) = (path, Some(tx));
+		drop(journal);
+
+		tokio::spawn(async move {
+			loop {
+				sleep(Duration::from_secs(1)).await;
+				emit!(Call(Cmd::new("update_journal"), Layer::Manager));
+			}
+		});
+
+		tokio::spawn(async move {
+			let (paths, mut journal) = Self::orphans().await;
+			if journal.is_empty() {
+				return Self::remove(paths).await;
+			}
+
+			journal.pastes = Self::pending(journal.pastes).await;
+			let lines = journal.lines();
+			if !lines.is_empty() && ConfirmProxy::show(ConfirmCfg::recover(lines)).await {
+				emit!(Call(Cmd::new("recover").with_any("journal", journal), Layer::Manager));
+			}
+			Self::remove(paths).await;
+		});
+	}
+
+	pub fn update(&mut self, manager: &Manager, idle: bool) {
+		self.yank = (!manager.yanked.is_empty())
+			.then(|| JournalYank { cut: manager.yanked.cut, paths: regular(manager.yanked.iter()) });
+		self.selected = manager.tabs.iter().map(|t| regular(t.selected.keys())).collect();
+		if idle {
+			self.pastes.clear();
+		}
+		self.save();
+	}
+
+	/// Records a paste once it's settled where it goes.
+	pub fn paste(&mut self, from: &Url, to: &Url, cut: bool) {
+		if from.is_regular() && to.is_regular() {
+			self.pastes.push(JournalPaste { from: from.to_path(), to: to.to_path(), cut });
+			self.save();
+		}
+	}
+
+	/// Removes the journal on exit, unless there're pastes interrupted by it,
+	/// which are written right away as the writer is about to be gone.
+	pub fn close(&mut self, idle: bool) {
+		if self.tx.is_none() {
+			return;
+		}
+
+		let mut closed = CLOSED.lock();
+		*closed = true;
+		if idle || self.pastes.is_empty() {
+			Self::put(&self.path, "");
+			std::fs::remove_file(lock_of(&self.path)).ok();
+		} else if let Ok(s) = toml::to_string(self) {
+			Self::put(&self.path, &s);
+		}
+	}
+
+	fn save(&mut self) {
+		let Some(tx) = &self.tx else { return };
+		let s = if self.is_empty() { String::new() } else { toml::to_string(self).unwrap_or_default() };
+		if s != self.written {
+			tx.send(s.clone()).ok();
+			self.written = s;
+		}
+	}
+
+	/// Writes what's sent in order off the main thread, skipping to the latest if
+	/// several are queued, where an empty one removes the journal.
+	async fn write(path: PathBuf, mut rx: mpsc::UnboundedReceiver<String>, _lock: File) {
+		while let Some(mut s) = rx.recv().await {
+			while let Ok(next) = rx.try_recv() {
+				s = next;
+			}
+
+			let path = path.clone();
+			tokio::task::spawn_blocking(move || {
+				let closed = CLOSED.lock();
+				if !*closed {
+					Self::put(&path, &s);
+				}
+			})
+			.await
+			.ok();
+		}
+	}
+
+	fn put(path: &Path, s: &str) {
+		if s.is_empty() {
+			std::fs::remove_file(path).ok();
+			return;
+		}
+
+		// Written to a temporary file first, so a crash halfway leaves the last one
+		let tmp = path.with_extension("tmp");
+		if let Err(e) = std::fs::write(&tmp, s).and_then(|_| std::fs::rename(&tmp, path)) {
+			error!("Failed to save the journal: {e}");
+		}
+	}
+
+	async fn remove(paths: Vec<(PathBuf, File)>) {
+		for (p, lock) in paths {
+			fs::remove_file(&p).await.ok();
+			drop(lock);
+			fs::remove_file(lock_of(&p)).await.ok();
+		}
+	}
+
+	fn is_empty(&self) -> bool {
+		self.yank.is_none() && self.selected.iter().all(|s| s.is_empty()) && self.pastes.is_empty()
+	}
+
+	/// The journals of the instances that are gone, merged into one, along with
+	/// their locks, which are held so no other instance recovers them as well.
+	async fn orphans() -> (Vec<(PathBuf, File)>, Self) {
+		let (mut paths, mut merged) = (vec![], Self::default());
+		let Ok(mut it) = fs::read_dir(BOOT.state_dir.join("journal")).await else {
+			return (paths, merged);
+		};
+
+		while let Ok(Some(entry)) = it.next_entry().await {
+			let p = entry.path();
+			if p.extension().is_none_or(|e| e != "lock") {
+				continue;
+			}
+
+			// Still locked by the instance it belongs to, or by another recovering it
+			let Ok(lock) = OpenOptions::new().write(true).open(&p) else { continue };
+			if lock.try_lock().is_err() {
+				continue;
+			}
+
+			let p = p.with_extension("toml");
+			if let Some(j) =
+				fs::read_to_string(&p).await.ok().and_then(|s| toml::from_str::<Self>(&s).ok())
+			{
+				merged.yank = merged.yank.or(j.yank);
+				merged.selected.resize_with(merged.selected.len().max(j.selected.len()), Vec::new);
+				merged.selected.iter_mut().zip(j.selected).for_each(|(a, b)| a.extend(b));
+				merged.pastes.extend(j.pastes);
+			}
+			paths.push((p, lock));
+		}
+		(paths, merged)
+	}
+
+	/// What's left of the pastes. A cut is left while its source is still there,
+	/// and a copy while its destination is missing or differs, where only the
+	/// differing entries of a directory are copied again.
+	async fn pending(pastes: Vec<JournalPaste>) -> Vec<JournalPaste> {
+		let mut left = vec![];
+		for p in pastes {
+			let Ok(m) = fs::symlink_metadata(&p.from).await else { continue };
+			if p.cut || fs::symlink_metadata(&p.to).await.is_err() {
+				left.push(p);
+			} else if m.is_dir() {
+				left.extend(plan(&p.from, &p.to, Path::new(""), false).await.into_iter().map(|e| {
+					JournalPaste { from: p.from.join(&e.path), to: p.to.join(&e.path), cut: false }
+				}));
+			} else if !synced(&p.from, &p.to, false).await {
+				left.push(p);
+			}
+		}
+		left
+	}
+
+	fn lines(&self) -> Vec<String> {
+		let yank = self.yank.iter().flat_map(|y| {
+			let verb = if y.cut { "Cut" } else { "Yanked" };
+			y.paths.iter().map(move |p| format!("{verb} {}", p.display()))
+		});
+		let selected = self.selected.iter().flatten().map(|p| format!("Selected {}", p.display()));
+		let pastes = self.pastes.iter().map(|p| {
+			let verb = if p.cut { "Move" } else { "Copy" };
+			format!("{verb} {} → {}", p.from.display(), p.to.display())
+		});
+		yank.chain(selected).chain(pastes).collect()
+	}
+}
+
+fn regular<'a>(urls: impl Iterator<Item = &'a Url>) -> Vec<PathBuf> {
+	urls.filter(|u| u.is_regular()).map(|u| u.to_path()).collect()
+}
+
+#[inline]
+fn lock_of(path: &Path) -> PathBuf { path.with_extension("lock") }
@@ -4,25 +4,38 @@ use yazi_config::popup::{Origin, Position};
 use yazi_fs::File;
 use yazi_shared::{Id, url::Url};
 
-use super::{Mimetype, Tabs, Watcher, Yanked};
+use super::{Diff, HoverInfo, Mimetype, Panes, Registers, Slideshow, Tabs, Tags, Watcher, Yanked};
 use crate::tab::{Folder, Tab};
 
 pub struct Manager {
-	pub tabs:   Tabs,
-	pub yanked: Yanked,
+	pub tabs:      Tabs,
+	pub yanked:    Yanked,
+	pub registers: Registers,
+	pub tags:      Tags,
+	pub diff:      Option<Diff>,
+	pub slideshow: Option<Slideshow>,
 
 	pub(super) watcher: Watcher,
 	pub mimetype:       Mimetype,
+	pub panes:          Panes,
+	pub hover_info:     HoverInfo,
 }
 
 impl Manager {
 	pub fn make() -> Self {
 		Self {
-			tabs:   Tabs::make(),
-			yanked: Default::default(),
+			tabs:      Tabs::make(),
+			yanked:    Default::default(),
+			registers: Registers::load(),
+			tags:      Tags::load(),
+			diff:      None,
+			slideshow: None,
 
 			watcher:  Watcher::serve(),
 			mimetype: Default::default(),
+			panes:    Panes::load(),
+
+			hover_info: Default::default(),
 		}
 	}
 
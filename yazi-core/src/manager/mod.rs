@@ -1,3 +1,3 @@
 yazi_macro::mod_pub!(commands);
 
-yazi_macro::mod_flat!(linked manager mimetype tabs watcher yanked);
+yazi_macro::mod_flat!(bench choices contexts diff hover_info index journal linked manager mimetype naming panes persist projects recents registers skeleton slideshow sync tabs tags watcher xbel yanked);
@@ -0,0 +1,90 @@
+use std::{collections::HashMap, ffi::OsString, path::Path};
+
+use super::{expand, tokens};
+
+/// A template for the names of pasted files, e.g. `{{date}}-{{n:3}}.{{ext}}`.
+///
+/// Besides the dates and times of a template, `{{stem}}`, `{{ext}}` and
+/// `{{name}}` are those of the original file, and `{{n}}` is a counter
+/// starting at 1, or `{{n:3}}` for it zero-padded to 3 digits.
+pub struct Naming {
+	template: String,
+	widths:   Vec<usize>,
+}
+
+impl Naming {
+	pub fn new(template: String) -> Self {
+		let widths = tokens(&template).filter_map(|t| t.strip_prefix("n:")?.parse().ok()).collect();
+		Self { template, widths }
+	}
+
+	/// Whether the names tell the files apart, i.e. it has a counter.
+	pub fn has_counter(&self) -> bool {
+		tokens(&self.template).any(|t| t == "n" || t.starts_with("n:"))
+	}
+
+	/// The name of the `n`th file, which was originally at `from`, or `None`
+	/// if it's not a valid one, e.g. empty or with a separator in it.
+	///
+	/// `{{name}}`, `{{stem}}` and `{{ext}}` are kept as they are, so names that
+	/// aren't valid UTF-8 stay the same.
+	pub fn name(&self, from: &Path, n: usize) -> Option<OsString> {
+		let mut vars = HashMap::from([("n".to_owned(), n.to_string())]);
+		for &w in &self.widths {
+			vars.insert(format!("n:{w}"), format!("{n:0w$}"));
+		}
+
+		let (mut name, mut rest) = (OsString::new(), self.template.as_str());
+		while let Some(start) = rest.find("{{") {
+			let Some(len) = rest[start + 2..].find("}}") else { break };
+			let token = &rest[start..start + len + 4];
+
+			name.push(&rest[..start]);
+			match token[2..token.len() - 2].trim() {
+				"name" => name.push(from.file_name().unwrap_or_default()),
+				"stem" => name.push(from.file_stem().unwrap_or_default()),
+				"ext" => name.push(from.extension().unwrap_or_default()),
+				_ => name.push(expand(token, &vars)),
+			}
+			rest = &rest[start + len + 4..];
+		}
+		name.push(rest);
+
+		let b = name.as_encoded_bytes();
+		if matches!(b, b"" | b"." | b"..") || b.iter().any(|&c| c == b'/' || c == b'\\') {
+			return None;
+		}
+		Some(name)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_name() {
+		let naming = Naming::new("trip-{{n:3}}.{{ext}}".to_owned());
+		assert!(naming.has_counter());
+		assert_eq!(naming.name(Path::new("/a/IMG_1.JPG"), 7).as_deref(), Some("trip-007.JPG".as_ref()));
+
+		let naming = Naming::new("{{stem}}-{{n}}".to_owned());
+		assert_eq!(naming.name(Path::new("/a/b.tar.gz"), 12).as_deref(), Some("b.tar-12".as_ref()));
+
+		assert!(!Naming::new("{{name}}".to_owned()).has_counter());
+		assert_eq!(Naming::new("{{stem}}/{{n}}".to_owned()).name(Path::new("a"), 1), None);
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn test_name_non_utf8() {
+		use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+		let from = Path::new(OsStr::from_bytes(b"/a/caf\xe9.txt"));
+		let naming = Naming::new("{{ stem }}-{{n:2}}.{{ext}}".to_owned());
+		assert_eq!(naming.name(from, 3).unwrap().as_bytes(), b"caf\xe9-03.txt");
+
+		let naming = Naming::new("{{date:%Y}}_{{name}}".to_owned());
+		assert!(naming.name(from, 1).unwrap().as_bytes().ends_with(b"_caf\xe9.txt"));
+	}
+}
@@ -0,0 +1,166 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+use yazi_boot::BOOT;
+use yazi_config::{MANAGER, manager::{ManagerRatio, ManagerZen}};
+
+use super::Persist;
+
+#[derive(Clone)]
+pub struct Panes {
+	pub ratio:     ManagerRatio,
+	pub hidden:    bool,
+	pub maximized: bool,
+	pub quicklook: bool,
+	pub zen:       bool,
+	pub widths:    HashMap<String, u16>,
+}
+
+impl Default for Panes {
+	fn default() -> Self {
+		Self {
+			ratio:     MANAGER.load().ratio,
+			hidden:    false,
+			maximized: false,
+			quicklook: false,
+			zen:       false,
+			widths:    Default::default(),
+		}
+	}
+}
+
+impl Panes {
+	pub(super) fn load() -> Self {
+		let Some(saved) =
+			std::fs::read_to_string(Self::path()).ok().and_then(|s| toml::from_str::<Saved>(&s).ok())
+		else {
+			return Self::default();
+		};
+
+		Self {
+			ratio: saved
+				.ratio
+				.and_then(|r| ManagerRatio::try_from(r).ok())
+				.unwrap_or(MANAGER.load().ratio),
+			widths: saved.widths,
+			..Default::default()
+		}
+	}
+
+	pub fn effective(&self, layout: Option<&str>) -> ManagerRatio {
+		let mut r = layout.and_then(|l| MANAGER.load().layouts.get(l).copied()).unwrap_or(self.ratio);
+		if self.maximized || self.quicklook {
+			return ManagerRatio { parent: 0, current: 0, preview: 1, all: 1 };
+		}
+
+		if self.hides(ManagerZen::Parent) && r.current + r.preview > 0 {
+			r = ManagerRatio { parent: 0, all: r.current + r.preview, ..r };
+		}
+		if (self.hidden || self.hides(ManagerZen::Preview)) && r.parent + r.current > 0 {
+			r = ManagerRatio { preview: 0, all: r.parent + r.current, ..r };
+		}
+		r
+	}
+
+	/// Whether `element` is hidden, which is everything but the preview while
+	/// quick-looking.
+	#[inline]
+	pub fn hides(&self, element: ManagerZen) -> bool {
+		if self.quicklook {
+			return element != ManagerZen::Preview;
+		}
+		self.zen && MANAGER.load().zen.contains(&element)
+	}
+
+	pub fn set_ratio(&mut self, ratio: ManagerRatio) -> bool {
+		if ratio == self.ratio {
+			return false;
+		}
+
+		self.ratio = ratio;
+		self.save();
+		true
+	}
+
+	pub fn set_width(&mut self, column: &str, width: Option<u16>) -> bool {
+		let changed = match width {
+			Some(w) => self.widths.insert(column.to_owned(), w) != Some(w),
+			None => self.widths.remove(column).is_some(),
+		};
+
+		if changed {
+			self.save();
+		}
+		changed
+	}
+
+	fn save(&self) {
+		let saved = Saved {
+			ratio:  (self.ratio != MANAGER.load().ratio)
+				.then(|| vec![self.ratio.parent, self.ratio.current, self.ratio.preview]),
+			widths: self.widths.clone(),
+		};
+		let content = if saved.ratio.is_none() && saved.widths.is_empty() {
+			None
+		} else {
+			toml::to_string(&saved).ok()
+		};
+		Persist { path: Self::path(), content, what: "pane layout" }.queue();
+	}
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join("panes.toml") }
+}
+
+#[derive(Deserialize, Serialize)]
+struct Saved {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	ratio:  Option<Vec<u16>>,
+	#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+	widths: HashMap<String, u16>,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_effective() {
+		let ratio = ManagerRatio { parent: 1, current: 4, preview: 3, all: 8 };
+		let mut panes = Panes {
+			ratio,
+			hidden: false,
+			maximized: false,
+			quicklook: false,
+			zen: false,
+			widths: Default::default(),
+		};
+		assert_eq!(panes.effective(None), ratio);
+
+		panes.hidden = true;
+		assert_eq!(panes.effective(None), ManagerRatio {
+			parent:  1,
+			current: 4,
+			preview: 0,
+			all:     5,
+		});
+
+		panes.maximized = true;
+		assert_eq!(panes.effective(None), ManagerRatio {
+			parent:  0,
+			current: 0,
+			preview: 1,
+			all:     1,
+		});
+
+		panes.maximized = false;
+		panes.quicklook = true;
+		assert_eq!(panes.effective(None), ManagerRatio {
+			parent:  0,
+			current: 0,
+			preview: 1,
+			all:     1,
+		});
+		assert!(panes.hides(ManagerZen::Status) && !panes.hides(ManagerZen::Preview));
+	}
+}
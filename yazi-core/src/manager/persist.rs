@@ -0,0 +1,65 @@
+use std::{io, path::{Path, PathBuf}, sync::OnceLock};
+
+use indexmap::IndexMap;
+use tokio::sync::mpsc;
+use tracing::error;
+
+static TX: OnceLock<mpsc::UnboundedSender<Persist>> = OnceLock::new();
+
+/// A state file to be saved, or removed if `content` is `None`.
+pub(crate) struct Persist {
+	pub(crate) path:    PathBuf,
+	pub(crate) content: Option<String>,
+	/// What's in it, for the error logged if it fails.
+	pub(crate) what:    &'static str,
+}
+
+impl Persist {
+	/// Saves it off the main thread, where the files are written one at a time in
+	/// the order they're queued, skipping to the latest of each if several are.
+	///
+	/// Each of them is written to a temporary file renamed over it, so it's never
+	/// left half-written, or older than the last one queued.
+	pub(crate) fn queue(self) {
+		let tx = TX.get_or_init(|| {
+			let (tx, rx) = mpsc::unbounded_channel();
+			tokio::spawn(Self::write(rx));
+			tx
+		});
+		tx.send(self).ok();
+	}
+
+	async fn write(mut rx: mpsc::UnboundedReceiver<Self>) {
+		while let Some(first) = rx.recv().await {
+			let mut queued = IndexMap::from([(first.path.clone(), first)]);
+			while let Ok(next) = rx.try_recv() {
+				queued.insert(next.path.clone(), next);
+			}
+
+			tokio::task::spawn_blocking(move || {
+				for p in queued.into_values() {
+					if let Err(e) = Self::put(&p.path, p.content.as_deref()) {
+						error!("Failed to save the {}: {e}", p.what);
+					}
+				}
+			})
+			.await
+			.ok();
+		}
+	}
+
+	fn put(path: &Path, content: Option<&str>) -> io::Result<()> {
+		let Some(s) = content else {
+			return yazi_fs::ok_or_not_found(std::fs::remove_file(path));
+		};
+
+		// Named by the process, so another instance saving it at the same time
+		// doesn't write to the same temporary file
+		let mut tmp = path.as_os_str().to_owned();
+		tmp.push(format!(".{}.tmp", std::process::id()));
+
+		std::fs::write(&tmp, s).and_then(|_| std::fs::rename(&tmp, path)).inspect_err(|_| {
+			std::fs::remove_file(&tmp).ok();
+		})
+	}
+}
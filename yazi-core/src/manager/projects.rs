@@ -0,0 +1,125 @@
+use std::{collections::HashMap, path::{Path, PathBuf}, sync::Arc};
+
+use indexmap::IndexSet;
+use parking_lot::RwLock;
+use twox_hash::XxHash3_128;
+use yazi_boot::BOOT;
+use yazi_config::{OPEN, open::Opener, popup::ConfirmCfg, project::Project};
+use yazi_proxy::{AppProxy, ConfirmProxy, ManagerProxy};
+use yazi_shared::{RoCell, escape_os_str, unescape_os_str};
+
+use super::Persist;
+
+pub static PROJECTS: RoCell<RwLock<Projects>> = RoCell::new();
+
+/// The `.yazi/config.toml` of projects, loaded once they're trusted, where the
+/// trust is remembered across sessions by the hash of the content, so it's
+/// asked for again once the content changes.
+#[derive(Default)]
+pub struct Projects {
+	/// The project of the active tab, whose keymap is in effect.
+	pub active: Option<Arc<Project>>,
+
+	loaded:  HashMap<PathBuf, Arc<Project>>,
+	trusted: HashMap<PathBuf, u128>,
+	/// The hash of the content last loaded or declined for each root.
+	seen:    HashMap<PathBuf, u128>,
+}
+
+impl Projects {
+	/// Each line is the hash of the trusted content, followed by the root, where
+	/// the lines of the roots trusted without a hash are dropped.
+	pub(crate) fn load() -> Self {
+		let Ok(s) = std::fs::read_to_string(Self::path()) else { return Self::default() };
+		Self {
+			trusted: s
+				.lines()
+				.filter_map(|l| l.split_once(' '))
+				.filter_map(|(h, p)| {
+					Some((PathBuf::from(unescape_os_str(p)), u128::from_str_radix(h, 16).ok()?))
+				})
+				.collect(),
+			..Default::default()
+		}
+	}
+
+	/// The loaded project with the deepest root covering `path`.
+	pub fn get(&self, path: &Path) -> Option<Arc<Project>> {
+		path.ancestors().find_map(|p| self.loaded.get(p).cloned())
+	}
+
+	/// Openers of the rules of the project covering the file, falling back to the
+	/// global ones if none of them match.
+	pub fn openers<P, M>(&self, path: P, mime: M) -> Option<IndexSet<Opener>>
+	where
+		P: AsRef<Path>,
+		M: AsRef<str>,
+	{
+		let path = path.as_ref();
+		self.get(path).and_then(|p| p.openers(path, &mime)).or_else(|| OPEN.load().openers(path, mime))
+	}
+
+	pub fn common_openers(&self, targets: &[(impl AsRef<Path>, impl AsRef<str>)]) -> Vec<Opener> {
+		let grouped: Vec<_> = targets.iter().filter_map(|(p, m)| self.openers(p, m)).collect();
+		let flat: IndexSet<_> = grouped.iter().flatten().collect();
+		flat.into_iter().filter(|&o| grouped.iter().all(|g| g.contains(o))).cloned().collect()
+	}
+
+	/// Look for the project at or above `cwd`, asking to trust it the first time
+	/// it's encountered, or its content has changed since it was trusted, and
+	/// refresh once it's loaded.
+	pub(super) fn discover(cwd: PathBuf) {
+		tokio::spawn(async move {
+			let found = tokio::task::spawn_blocking(move || {
+				let root = Project::find(&cwd)?;
+				let s = Project::read(&root).ok()?;
+				Some((root, s))
+			});
+			let Ok(Some((root, s))) = found.await else { return };
+
+			let hash = XxHash3_128::oneshot(s.as_bytes());
+			let trusted = {
+				let mut me = PROJECTS.write();
+				if me.seen.insert(root.clone(), hash) == Some(hash) {
+					return;
+				}
+				me.trusted.get(&root) == Some(&hash)
+			};
+
+			if !trusted {
+				if !ConfirmProxy::show(ConfirmCfg::project(&root.join(Project::FILE))).await {
+					// What's changed isn't trusted, so the content loaded before isn't kept
+					if PROJECTS.write().loaded.remove(&root).is_some() {
+						ManagerProxy::refresh();
+					}
+					return;
+				}
+				PROJECTS.write().trust(root.clone(), hash);
+			}
+
+			match Project::load(root, &s) {
+				Ok(project) => {
+					PROJECTS.write().loaded.insert(project.root.clone(), Arc::new(project));
+					ManagerProxy::refresh();
+				}
+				Err(e) => AppProxy::notify_warn("Project config", format!("{e:#}")),
+			}
+		});
+	}
+
+	fn trust(&mut self, root: PathBuf, hash: u128) {
+		if self.trusted.insert(root, hash) == Some(hash) {
+			return;
+		}
+
+		let content = self
+			.trusted
+			.iter()
+			.map(|(p, h)| format!("{h:032x} {}\n", escape_os_str(p.as_os_str())))
+			.collect();
+		Persist { path: Self::path(), content: Some(content), what: "trusted projects" }.queue();
+	}
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join("projects") }
+}
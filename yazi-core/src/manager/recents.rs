@@ -0,0 +1,127 @@
+use std::{borrow::Cow, cmp::Reverse, collections::HashSet, path::{Path, PathBuf}, sync::atomic::{AtomicBool, Ordering}, time::{Duration, SystemTime, UNIX_EPOCH}};
+
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use yazi_boot::BOOT;
+use yazi_config::RECENT;
+use yazi_shared::{MIME_DIR, RoCell, url::Url};
+
+use super::Xbel;
+
+pub static RECENTS: RoCell<RwLock<Recents>> = RoCell::new();
+
+static SAVING: AtomicBool = AtomicBool::new(false);
+
+/// The recently opened files and visited directories, the most recent first.
+#[derive(Default, Deserialize, Serialize)]
+pub struct Recents {
+	#[serde(default)]
+	files: Vec<Recent>,
+	#[serde(default)]
+	dirs:  Vec<Recent>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct Recent {
+	path: PathBuf,
+	time: i64,
+}
+
+impl Recents {
+	pub(crate) fn load() -> Self {
+		let Ok(s) = std::fs::read_to_string(Self::path()) else { return Self::default() };
+		toml::from_str(&s).unwrap_or_default()
+	}
+
+	#[inline]
+	pub fn dirs(&self) -> impl Iterator<Item = &Path> { self.dirs.iter().map(|r| r.path.as_path()) }
+
+	/// The files opened in Yazi, along with those in `recently-used.xbel` if
+	/// it's shared, the most recent first.
+	pub fn files() -> Vec<PathBuf> {
+		let mut files: Vec<_> = RECENTS.read().files.iter().map(|r| (r.path.clone(), r.time)).collect();
+		if RECENT.load().xbel {
+			match Xbel::read() {
+				Ok(v) => files.extend(v),
+				Err(e) => error!("Failed to read the recently-used.xbel: {e}"),
+			}
+			files.sort_unstable_by_key(|&(_, t)| Reverse(t));
+		}
+
+		let mut seen = HashSet::new();
+		files
+			.into_iter()
+			.filter(|(p, _)| seen.insert(p.clone()))
+			.take(RECENT.load().max_files)
+			.map(|(p, _)| p)
+			.collect()
+	}
+
+	pub fn open(&mut self, targets: &[(Url, Cow<str>)]) {
+		let files: Vec<_> = targets
+			.iter()
+			.filter(|(u, m)| u.is_regular() && m != MIME_DIR)
+			.map(|(u, m)| (u.to_path(), m.clone().into_owned()))
+			.collect();
+		if files.is_empty() {
+			return;
+		}
+
+		let time = Self::now();
+		for (path, _) in &files {
+			Self::push(&mut self.files, path, time, RECENT.load().max_files);
+		}
+		self.save();
+
+		if RECENT.load().xbel {
+			tokio::task::spawn_blocking(move || {
+				if let Err(e) = Xbel::write(files) {
+					error!("Failed to write the recently-used.xbel: {e}");
+				}
+			});
+		}
+	}
+
+	pub fn visit(&mut self, url: &Url) {
+		if url.is_regular() && Self::push(&mut self.dirs, url, Self::now(), RECENT.load().max_dirs) {
+			self.save();
+		}
+	}
+
+	fn push(list: &mut Vec<Recent>, path: &Path, time: i64, max: usize) -> bool {
+		if max == 0 {
+			return false;
+		}
+
+		if let Some(i) = list.iter().position(|r| r.path == path) {
+			list.remove(i);
+		}
+		list.insert(0, Recent { path: path.to_owned(), time });
+		list.truncate(max);
+		true
+	}
+
+	/// Save them in a moment, as it's called on every directory change.
+	fn save(&self) {
+		if SAVING.swap(true, Ordering::Relaxed) {
+			return;
+		}
+
+		tokio::spawn(async {
+			tokio::time::sleep(Duration::from_secs(1)).await;
+			SAVING.store(false, Ordering::Relaxed);
+
+			let Ok(s) = toml::to_string(&*RECENTS.read()) else { return };
+			if let Err(e) = tokio::fs::write(Self::path(), s).await {
+				error!("Failed to save the recent files: {e}");
+			}
+		});
+	}
+
+	#[inline]
+	fn now() -> i64 { SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() as i64) }
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join("recent.toml") }
+}
@@ -0,0 +1,134 @@
+use std::{collections::{BTreeMap, HashMap}, ops::Deref, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use yazi_boot::BOOT;
+use yazi_config::REGISTER;
+use yazi_fs::FilesOp;
+use yazi_shared::url::Url;
+
+use super::Yanked;
+
+/// The named yank registers, each a clipboard of its own besides the unnamed
+/// one, so that interleaved copy jobs don't clobber each other.
+#[derive(Default)]
+pub struct Registers {
+	inner:       BTreeMap<char, Yanked>,
+	/// The register chosen for the next yank, paste or unyank.
+	pub pending: Option<char>,
+}
+
+impl Deref for Registers {
+	type Target = BTreeMap<char, Yanked>;
+
+	fn deref(&self) -> &Self::Target { &self.inner }
+}
+
+impl Registers {
+	pub(super) fn load() -> Self {
+		if !REGISTER.load().persist {
+			return Self::default();
+		}
+
+		let saved = std::fs::read_to_string(Self::path())
+			.ok()
+			.and_then(|s| toml::from_str::<Saved>(&s).ok())
+			.unwrap_or_default();
+
+		let inner = saved
+			.registers
+			.into_iter()
+			.filter_map(|(name, r)| {
+				let urls = r.paths.into_iter().map(Url::from).collect();
+				Some((Self::name(&name)?, Yanked::new(r.cut, urls)))
+			})
+			.collect();
+
+		Self { inner, pending: None }
+	}
+
+	/// The name of a register, which is a single letter or digit.
+	pub fn name(s: &str) -> Option<char> {
+		let mut it = s.chars();
+		match (it.next(), it.next()) {
+			(Some(c), None) if c.is_ascii_alphanumeric() => Some(c),
+			_ => None,
+		}
+	}
+
+	/// The register to use, either the one given or the pending one, which is
+	/// consumed either way.
+	#[inline]
+	pub fn resolve(&mut self, name: Option<char>) -> Option<char> {
+		let pending = self.pending.take();
+		name.or(pending)
+	}
+
+	pub fn set(&mut self, name: char, yanked: Yanked) {
+		if yanked.is_empty() {
+			if self.inner.remove(&name).is_none() {
+				return;
+			}
+		} else {
+			self.inner.insert(name, yanked);
+		}
+		self.save();
+	}
+
+	pub fn remove(&mut self, url: &Url) {
+		let mut changed = false;
+		for yanked in self.inner.values_mut() {
+			changed |= yanked.remove(url);
+		}
+		if changed {
+			self.save();
+		}
+	}
+
+	pub fn apply_op(&mut self, op: &FilesOp) {
+		let mut changed = false;
+		for yanked in self.inner.values_mut() {
+			changed |= yanked.apply_op(op);
+		}
+		if changed {
+			self.save();
+		}
+	}
+
+	fn save(&self) {
+		if !REGISTER.load().persist {
+			return;
+		}
+
+		let registers: HashMap<_, _> = self
+			.inner
+			.iter()
+			.map(|(name, yanked)| {
+				let paths = yanked.iter().filter(|u| u.is_regular()).map(|u| u.to_path()).collect();
+				(name.to_string(), SavedRegister { cut: yanked.cut, paths })
+			})
+			.collect();
+
+		let Ok(s) = toml::to_string(&Saved { registers }) else { return };
+		tokio::spawn(async move {
+			if let Err(e) = tokio::fs::write(Self::path(), s).await {
+				error!("Failed to save the registers: {e}");
+			}
+		});
+	}
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join("registers.toml") }
+}
+
+#[derive(Default, Deserialize, Serialize)]
+struct Saved {
+	#[serde(default)]
+	registers: HashMap<String, SavedRegister>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct SavedRegister {
+	cut:   bool,
+	paths: Vec<PathBuf>,
+}
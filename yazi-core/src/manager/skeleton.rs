@@ -0,0 +1,177 @@
+use std::{collections::HashMap, fmt::Write, io, path::{Path, PathBuf}};
+
+use chrono::{Local, format::{Item, StrftimeItems}};
+use tokio::fs;
+use yazi_config::TEMPLATE;
+
+/// The contents of a template, i.e. a file or the entries under a directory,
+/// with `{{token}}`s in their names and texts expanded on writing.
+pub(super) struct Skeleton {
+	is_dir:  bool,
+	entries: Vec<(PathBuf, Option<Vec<u8>>)>,
+}
+
+impl Skeleton {
+	/// Find the template `id`, either mapped by a rule, or an entry under the
+	/// templates directory named after it, with or without an extension.
+	pub(super) async fn find(id: &str) -> Option<PathBuf> {
+		if let Some(p) = TEMPLATE.by_id(id) {
+			return Some(p);
+		}
+
+		let mut it = fs::read_dir(&TEMPLATE.dir).await.ok()?;
+		while let Ok(Some(entry)) = it.next_entry().await {
+			let path = entry.path();
+			if path.file_name().is_some_and(|n| n == id) || path.file_stem().is_some_and(|s| s == id) {
+				return Some(path);
+			}
+		}
+		None
+	}
+
+	pub(super) async fn load(source: PathBuf) -> io::Result<Self> {
+		tokio::task::spawn_blocking(move || {
+			let meta = std::fs::metadata(&source)?;
+			if !meta.is_dir() {
+				return Ok(Self {
+					is_dir:  false,
+					entries: vec![(PathBuf::new(), Some(std::fs::read(&source)?))],
+				});
+			}
+
+			let (mut entries, mut stack) = (vec![], vec![source.clone()]);
+			while let Some(dir) = stack.pop() {
+				for entry in std::fs::read_dir(dir)?.flatten() {
+					let path = entry.path();
+					let rel = path.strip_prefix(&source).unwrap().to_owned();
+					if entry.file_type()?.is_dir() {
+						stack.push(path);
+						entries.push((rel, None));
+					} else {
+						entries.push((rel, Some(std::fs::read(&path)?)));
+					}
+				}
+			}
+
+			entries.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+			Ok(Self { is_dir: true, entries })
+		})
+		.await?
+	}
+
+	#[inline]
+	pub(super) fn is_dir(&self) -> bool { self.is_dir }
+
+	/// Tokens that are not built-in, which have to be given by the user, in the
+	/// order they first appear.
+	pub(super) fn variables(&self) -> Vec<String> {
+		let texts = self.entries.iter().flat_map(|(rel, data)| {
+			[rel.to_str(), data.as_deref().and_then(|d| std::str::from_utf8(d).ok())]
+		});
+
+		let mut vars: Vec<String> = vec![];
+		for token in texts.flatten().flat_map(tokens) {
+			if !is_builtin(token) && !vars.iter().any(|v| v == token) {
+				vars.push(token.to_owned());
+			}
+		}
+		vars
+	}
+
+	/// Write the expanded contents to `to`, where `vars` contains the values of
+	/// the variables, besides the built-in `{{name}}`, `{{stem}}` and `{{cwd}}`.
+	pub(super) async fn write(self, to: &Path, mut vars: HashMap<String, String>) -> io::Result<()> {
+		let name = to.file_name().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+		let stem = to.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+		let cwd = to.parent().map(|p| p.to_string_lossy().into_owned()).unwrap_or_default();
+		vars.extend([("name".to_owned(), name), ("stem".to_owned(), stem), ("cwd".to_owned(), cwd)]);
+
+		if self.is_dir {
+			fs::create_dir_all(to).await?;
+		} else if let Some(parent) = to.parent() {
+			fs::create_dir_all(parent).await.ok();
+		}
+
+		for (rel, data) in self.entries {
+			let path = match rel.to_str() {
+				Some("") => to.to_owned(),
+				Some(s) => to.join(expand(s, &vars)),
+				None => to.join(rel),
+			};
+
+			match data {
+				None => fs::create_dir_all(&path).await?,
+				Some(data) => match String::from_utf8(data) {
+					Ok(s) => fs::write(&path, expand(&s, &vars)).await?,
+					Err(e) => fs::write(&path, e.into_bytes()).await?,
+				},
+			}
+		}
+		Ok(())
+	}
+}
+
+/// The trimmed contents of each `{{...}}` in `s`.
+pub(super) fn tokens(s: &str) -> impl Iterator<Item = &str> {
+	s.split("{{").skip(1).filter_map(|s| Some(s.split_once("}}")?.0.trim()))
+}
+
+#[inline]
+fn is_builtin(token: &str) -> bool {
+	matches!(token, "date" | "time" | "name" | "stem" | "cwd") || token.starts_with("date:")
+}
+
+pub(super) fn expand(s: &str, vars: &HashMap<String, String>) -> String {
+	let mut out = String::with_capacity(s.len());
+	let mut rest = s;
+	while let Some(start) = rest.find("{{") {
+		let Some(len) = rest[start + 2..].find("}}") else { break };
+		let token = rest[start + 2..start + 2 + len].trim();
+
+		out.push_str(&rest[..start]);
+		match resolve(token, vars) {
+			Some(v) => out.push_str(&v),
+			None => out.push_str(&rest[start..start + len + 4]),
+		}
+		rest = &rest[start + len + 4..];
+	}
+	out.push_str(rest);
+	out
+}
+
+fn resolve(token: &str, vars: &HashMap<String, String>) -> Option<String> {
+	let format = match token {
+		"date" => "%Y-%m-%d",
+		"time" => "%H:%M",
+		_ => match token.strip_prefix("date:") {
+			Some(f) => f,
+			None => return vars.get(token).cloned(),
+		},
+	};
+
+	// An invalid format would fail to display, leave the token as-is then
+	let items: Vec<_> = StrftimeItems::new(format).collect();
+	if items.contains(&Item::Error) {
+		return None;
+	}
+
+	let mut s = String::new();
+	write!(s, "{}", Local::now().format_with_items(items.into_iter())).ok()?;
+	Some(s)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_expand() {
+		let vars = HashMap::from_iter([("title".to_owned(), "Weekly".to_owned())]);
+		assert_eq!(tokens("# {{ title }} {{date}} {{x").collect::<Vec<_>>(), ["title", "date"]);
+
+		assert_eq!(expand("# {{ title }}", &vars), "# Weekly");
+		assert_eq!(expand("{{unknown}} {{title", &vars), "{{unknown}} {{title");
+		assert_eq!(expand("{{date:%Q}}", &vars), "{{date:%Q}}");
+		assert_eq!(expand("{{date:%Y}}", &vars), Local::now().format("%Y").to_string());
+	}
+}
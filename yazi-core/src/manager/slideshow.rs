@@ -0,0 +1,51 @@
+use std::{collections::hash_map::RandomState, hash::BuildHasher, time::Duration};
+
+use tokio::{select, time::sleep};
+use tokio_util::sync::CancellationToken;
+use yazi_macro::emit;
+use yazi_shared::{Layer, event::Cmd, url::Url};
+
+/// The images cycled through in the quick look, one every `interval`.
+pub struct Slideshow {
+	urls:   Vec<Url>,
+	cursor: usize,
+
+	ct: CancellationToken,
+}
+
+impl Slideshow {
+	pub fn new(mut urls: Vec<Url>, interval: Duration, shuffle: bool) -> Self {
+		if shuffle {
+			let state = RandomState::new();
+			urls.sort_by_cached_key(|u| state.hash_one(u));
+		}
+
+		let ct = CancellationToken::new();
+		let token = ct.clone();
+		tokio::spawn(async move {
+			loop {
+				select! {
+					_ = token.cancelled() => break,
+					_ = sleep(interval) => emit!(Call(Cmd::args("slideshow", &[1]), Layer::Manager)),
+				}
+			}
+		});
+
+		Self { urls, cursor: 0, ct }
+	}
+
+	#[inline]
+	pub fn current(&self) -> &Url { &self.urls[self.cursor] }
+
+	#[inline]
+	pub fn next(&self) -> &Url { &self.urls[(self.cursor + 1) % self.urls.len()] }
+
+	#[inline]
+	pub fn step(&mut self, step: isize) {
+		self.cursor = (self.cursor as isize + step).rem_euclid(self.urls.len() as isize) as usize;
+	}
+}
+
+impl Drop for Slideshow {
+	fn drop(&mut self) { self.ct.cancel(); }
+}
@@ -0,0 +1,112 @@
+use std::{future::Future, path::{Path, PathBuf}, pin::Pin};
+
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+use twox_hash::XxHash3_128;
+use yazi_boot::BOOT;
+
+use super::{entries, same};
+
+/// An entry to transfer in a sync, relative to the directories synced.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SyncEntry {
+	pub path: PathBuf,
+	/// Whether it's missing on the destination, rather than differing from it.
+	pub new:  bool,
+}
+
+/// The entries planned for the sync from one directory to another, kept until
+/// all of them are transferred, so an interrupted sync can be resumed without
+/// comparing the directories again.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SyncJournal {
+	pub from:    PathBuf,
+	pub to:      PathBuf,
+	pub by_hash: bool,
+	pub entries: Vec<SyncEntry>,
+}
+
+impl SyncJournal {
+	pub fn new(from: PathBuf, to: PathBuf, by_hash: bool, entries: Vec<SyncEntry>) -> Self {
+		Self { from, to, by_hash, entries }
+	}
+
+	/// The journal of an earlier sync from `from` to `to`, if it was interrupted.
+	pub async fn load(from: &Path, to: &Path) -> Option<Self> {
+		let s = fs::read_to_string(Self::path(from, to)).await.ok()?;
+		toml::from_str(&s).ok()
+	}
+
+	pub async fn save(&self) -> std::io::Result<()> {
+		let p = Self::path(&self.from, &self.to);
+		fs::create_dir_all(p.parent().unwrap()).await?;
+		fs::write(p, toml::to_string(self).map_err(std::io::Error::other)?).await
+	}
+
+	pub async fn remove(from: &Path, to: &Path) { fs::remove_file(Self::path(from, to)).await.ok(); }
+
+	/// Drops the entries that have been transferred since the journal was saved.
+	pub async fn resume(mut self) -> Self {
+		let mut left = Vec::with_capacity(self.entries.len());
+		for e in self.entries {
+			let (a, b) = (self.from.join(&e.path), self.to.join(&e.path));
+			if !synced(&a, &b, self.by_hash).await {
+				left.push(e);
+			}
+		}
+		self.entries = left;
+		self
+	}
+
+	fn path(from: &Path, to: &Path) -> PathBuf {
+		let mut h = XxHash3_128::new();
+		h.write(from.as_os_str().as_encoded_bytes());
+		h.write(&[0]);
+		h.write(to.as_os_str().as_encoded_bytes());
+		BOOT.state_dir.join(format!("sync/{:x}.toml", h.finish_128()))
+	}
+}
+
+/// Compares `from` with `to` recursively, for the entries that are missing on
+/// `to`, or differ by size and mtime, or by content if `by_hash`.
+///
+/// A missing directory is transferred as a whole, and entries of different
+/// types, e.g. a file in place of a directory, are left alone.
+pub fn plan<'a>(
+	from: &'a Path,
+	to: &'a Path,
+	rel: &'a Path,
+	by_hash: bool,
+) -> Pin<Box<dyn Future<Output = Vec<SyncEntry>> + Send + 'a>> {
+	Box::pin(async move {
+		let (a, b) = (entries(&from.join(rel)).await, entries(&to.join(rel)).await);
+
+		let mut names: Vec<_> = a.keys().collect();
+		names.sort_unstable();
+
+		let mut result = vec![];
+		for name in names {
+			let (m, path) = (&a[name], rel.join(name));
+			let Some(n) = b.get(name) else {
+				result.push(SyncEntry { path, new: true });
+				continue;
+			};
+
+			if m.is_dir() != n.is_dir() {
+				continue;
+			} else if m.is_dir() {
+				result.extend(plan(from, to, &path, by_hash).await);
+			} else if !same(&from.join(&path), &to.join(&path), m, n, by_hash).await {
+				result.push(SyncEntry { path, new: false });
+			}
+		}
+		result
+	})
+}
+
+pub(super) async fn synced(a: &Path, b: &Path, by_hash: bool) -> bool {
+	let (Ok(m), Ok(n)) = (fs::symlink_metadata(a).await, fs::symlink_metadata(b).await) else {
+		return false;
+	};
+	same(a, b, &m, &n, by_hash).await
+}
@@ -1,12 +1,16 @@
 use std::ops::{Deref, DerefMut};
 
 use yazi_boot::BOOT;
+use yazi_config::t;
 use yazi_dds::Pubsub;
-use yazi_proxy::ManagerProxy;
+use yazi_fs::Filter;
+use yazi_proxy::{AppProxy, ManagerProxy};
 use yazi_shared::{Id, url::Url};
 
 use crate::tab::Tab;
 
+pub(super) const MAX_TABS: usize = 9;
+
 pub struct Tabs {
 	pub cursor:       usize,
 	pub(super) items: Vec<Tab>,
@@ -14,10 +18,24 @@ pub struct Tabs {
 
 impl Tabs {
 	pub fn make() -> Self {
+		let len = BOOT.cwds.len();
+		if len > MAX_TABS {
+			AppProxy::notify_warn(
+				"Too many tabs",
+				t!(
+					"Only the first {max} of the {len} entries are opened as tabs.",
+					max = MAX_TABS,
+					len = len
+				),
+			);
+		}
+
 		let mut tabs =
-			Self { cursor: 0, items: (0..BOOT.cwds.len()).map(|_| Tab::default()).collect() };
+			Self { cursor: 0, items: (0..len.min(MAX_TABS)).map(|_| Tab::default()).collect() };
 
 		for (i, tab) in tabs.iter_mut().enumerate() {
+			tab.pref.layout = BOOT.layouts[i].clone();
+
 			let file = &BOOT.files[i];
 			if file.is_empty() {
 				tab.cd(Url::from(&BOOT.cwds[i]));
@@ -25,6 +43,13 @@ impl Tabs {
 				tab.reveal(Url::from(BOOT.cwds[i].join(file)));
 			}
 		}
+
+		let selected: Vec<_> = BOOT.selected.iter().map(Url::from).collect();
+		tabs.items[0].selected.add_many(&selected, false);
+
+		if let Some(filter) = BOOT.filter.as_deref() {
+			tabs.items[0].current.files.set_filter(Filter::glob(filter).ok());
+		}
 		tabs
 	}
 
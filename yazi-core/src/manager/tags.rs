@@ -0,0 +1,233 @@
+use std::{collections::HashMap, io, ops::Deref, path::{Path, PathBuf}};
+
+use serde::{Deserialize, Serialize};
+use tracing::error;
+use yazi_boot::BOOT;
+use yazi_config::TAG;
+use yazi_fs::FilesOp;
+use yazi_shared::url::Url;
+
+use super::Persist;
+
+#[derive(Default)]
+pub struct Tags {
+	inner: HashMap<PathBuf, Vec<String>>,
+}
+
+impl Deref for Tags {
+	type Target = HashMap<PathBuf, Vec<String>>;
+
+	fn deref(&self) -> &Self::Target { &self.inner }
+}
+
+impl Tags {
+	pub(super) fn load() -> Self {
+		let saved = std::fs::read_to_string(Self::path())
+			.ok()
+			.and_then(|s| toml::from_str::<Saved>(&s).ok())
+			.unwrap_or_default();
+
+		Self { inner: saved.files }
+	}
+
+	#[inline]
+	pub fn get(&self, path: &Path) -> &[String] {
+		self.inner.get(path).map(Vec::as_slice).unwrap_or_default()
+	}
+
+	#[inline]
+	pub fn has(&self, path: &Path, name: &str) -> bool { self.get(path).iter().any(|t| t == name) }
+
+	pub fn add<'a>(&mut self, urls: impl IntoIterator<Item = &'a Url>, name: &str) -> bool {
+		let mut changed = vec![];
+		for url in urls {
+			let tags = self.inner.entry(url.to_path()).or_default();
+			if !tags.iter().any(|t| t == name) {
+				tags.push(name.to_owned());
+				changed.push(url.to_path());
+			}
+		}
+		self.commit(changed)
+	}
+
+	pub fn remove<'a>(
+		&mut self,
+		urls: impl IntoIterator<Item = &'a Url>,
+		name: Option<&str>,
+	) -> bool {
+		let mut changed = vec![];
+		for url in urls {
+			let Some(tags) = self.inner.get_mut(url.as_ref() as &Path) else { continue };
+
+			let len = tags.len();
+			tags.retain(|t| name.is_some_and(|n| n != t));
+			if tags.len() != len {
+				changed.push(url.to_path());
+			}
+			if tags.is_empty() {
+				self.inner.remove(url.as_ref() as &Path);
+			}
+		}
+		self.commit(changed)
+	}
+
+	/// Paths tagged with `name`, or with any tag if `name` is `None`.
+	pub fn tagged<'a>(&'a self, name: Option<&'a str>) -> impl Iterator<Item = &'a PathBuf> {
+		self
+			.inner
+			.iter()
+			.filter(move |(_, tags)| name.is_none_or(|n| tags.iter().any(|t| t == n)))
+			.map(|(p, _)| p)
+	}
+
+	/// Carries the tags of the moved paths, and of everything in them, over to
+	/// where they're moved to.
+	pub fn rename<'a>(&mut self, moved: impl IntoIterator<Item = (&'a Url, &'a Url)>) -> bool {
+		let mut changed = vec![];
+		for (from, to) in moved.into_iter().filter(|(f, t)| f.is_regular() && t.is_regular()) {
+			changed.extend(self.move_(&from.to_path(), &to.to_path()));
+		}
+		self.commit(changed)
+	}
+
+	/// Drops the tags of the deleted paths, and of everything in them.
+	pub fn apply_op(&mut self, op: &FilesOp) -> bool {
+		let FilesOp::Deleting(cwd, urns) = op else { return false };
+		if !cwd.is_regular() {
+			return false;
+		}
+
+		let mut forgotten = false;
+		for urn in urns {
+			forgotten |= self.forget(&cwd.join(urn));
+		}
+		if forgotten {
+			self.save(vec![]);
+		}
+		forgotten
+	}
+
+	fn move_(&mut self, from: &Path, to: &Path) -> Vec<PathBuf> {
+		let moved: Vec<_> = self.inner.keys().filter(|p| p.starts_with(from)).cloned().collect();
+		moved
+			.into_iter()
+			.filter_map(|old| {
+				let new = to.join(old.strip_prefix(from).ok()?);
+				let tags = self.inner.remove(&old)?;
+				self.inner.insert(new.clone(), tags);
+				Some(new)
+			})
+			.collect()
+	}
+
+	fn forget(&mut self, path: &Path) -> bool {
+		let len = self.inner.len();
+		self.inner.retain(|p, _| !p.starts_with(path));
+		self.inner.len() != len
+	}
+
+	fn commit(&self, changed: Vec<PathBuf>) -> bool {
+		if changed.is_empty() {
+			return false;
+		}
+
+		self.save(changed);
+		true
+	}
+
+	/// Saves the tags, and writes them to the xattrs of the `changed` paths.
+	fn save(&self, changed: Vec<PathBuf>) {
+		let content = if self.inner.is_empty() {
+			None
+		} else {
+			toml::to_string(&Saved { files: self.inner.clone() }).ok()
+		};
+		Persist { path: Self::path(), content, what: "tags" }.queue();
+
+		if !TAG.xattr || changed.is_empty() {
+			return;
+		}
+
+		let xattrs: Vec<_> = changed.into_iter().map(|p| (self.get(&p).join(","), p)).collect();
+		tokio::task::spawn_blocking(move || {
+			for (value, path) in xattrs {
+				if let Err(e) = write_xattr(&path, &value) {
+					error!("Failed to write the tags xattr: {e}");
+				}
+			}
+		});
+	}
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join("tags.toml") }
+}
+
+#[derive(Default, Deserialize, Serialize)]
+struct Saved {
+	#[serde(default)]
+	files: HashMap<PathBuf, Vec<String>>,
+}
+
+#[cfg(target_os = "linux")]
+fn write_xattr(path: &Path, value: &str) -> io::Result<()> {
+	use std::{ffi::CString, os::unix::ffi::OsStrExt};
+
+	let path = CString::new(path.as_os_str().as_bytes())?;
+	let name = c"user.xdg.tags";
+
+	let ret = if value.is_empty() {
+		unsafe { libc::removexattr(path.as_ptr(), name.as_ptr()) }
+	} else {
+		unsafe { libc::setxattr(path.as_ptr(), name.as_ptr(), value.as_ptr().cast(), value.len(), 0) }
+	};
+
+	match ret {
+		0 => Ok(()),
+		_ if value.is_empty() && io::Error::last_os_error().raw_os_error() == Some(libc::ENODATA) => {
+			Ok(())
+		}
+		_ => Err(io::Error::last_os_error()),
+	}
+}
+
+#[cfg(not(target_os = "linux"))]
+fn write_xattr(_: &Path, _: &str) -> io::Result<()> { Ok(()) }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn tags(paths: &[&str]) -> Tags {
+		Tags { inner: paths.iter().map(|&p| (PathBuf::from(p), vec!["red".to_owned()])).collect() }
+	}
+
+	#[test]
+	fn test_move() {
+		let mut t = tags(&["/a/b", "/a/b/c", "/a/bc", "/d"]);
+
+		let mut moved = t.move_(Path::new("/a/b"), Path::new("/e/f"));
+		moved.sort();
+		assert_eq!(moved, [PathBuf::from("/e/f"), PathBuf::from("/e/f/c")]);
+
+		assert!(t.has(Path::new("/e/f"), "red"));
+		assert!(t.has(Path::new("/e/f/c"), "red"));
+		assert!(!t.has(Path::new("/a/b"), "red"));
+		assert!(!t.has(Path::new("/a/b/c"), "red"));
+
+		// Only whole components are matched
+		assert!(t.has(Path::new("/a/bc"), "red"));
+		assert!(t.move_(Path::new("/x"), Path::new("/y")).is_empty());
+	}
+
+	#[test]
+	fn test_forget() {
+		let mut t = tags(&["/a/b", "/a/b/c", "/a/bc", "/d"]);
+
+		assert!(t.forget(Path::new("/a/b")));
+		assert_eq!(t.len(), 2);
+		assert!(t.has(Path::new("/a/bc"), "red"));
+		assert!(t.has(Path::new("/d"), "red"));
+
+		assert!(!t.forget(Path::new("/a/b")));
+	}
+}
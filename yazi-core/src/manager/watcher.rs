@@ -3,14 +3,15 @@ use std::{collections::{HashMap, HashSet}, time::Duration};
 use anyhow::Result;
 use notify::{PollWatcher, RecommendedWatcher, RecursiveMode, Watcher as _Watcher};
 use parking_lot::RwLock;
-use tokio::{fs, pin, sync::{mpsc::{self, UnboundedReceiver}, watch}};
+use tokio::{pin, sync::{mpsc::{self, UnboundedReceiver}, watch}};
 use tokio_stream::{StreamExt, wrappers::UnboundedReceiverStream};
 use tracing::error;
-use yazi_fs::{Cha, File, Files, FilesOp, realname_unchecked};
-use yazi_proxy::WATCHER;
+use yazi_config::{NETWORK, t, watcher::WatcherBackend};
+use yazi_fs::{Cha, File, Files, FilesOp, canonicalize, realname_unchecked};
+use yazi_proxy::{AppProxy, WATCHER};
 use yazi_shared::{RoCell, url::Url};
 
-use super::Linked;
+use super::{INDEX, Index, Linked};
 use crate::tab::Folder;
 
 pub(crate) static WATCHED: RoCell<RwLock<HashSet<Url>>> = RoCell::new();
@@ -35,12 +36,7 @@ impl Watcher {
 			Self::push_files_impl(&out_tx_, event.paths.into_iter().map(Url::from));
 		};
 
-		let config = notify::Config::default().with_poll_interval(Duration::from_millis(500));
-		if yazi_adapter::WSL.get() {
-			tokio::spawn(Self::fan_in(in_rx, PollWatcher::new(handler, config).unwrap()));
-		} else {
-			tokio::spawn(Self::fan_in(in_rx, RecommendedWatcher::new(handler, config).unwrap()));
-		}
+		tokio::spawn(Self::fan_in(in_rx, Backend::new(handler)));
 
 		#[cfg(any(target_os = "linux", target_os = "macos"))]
 		yazi_fs::mounts::Partitions::monitor(
@@ -49,11 +45,12 @@ impl Watcher {
 		);
 
 		tokio::spawn(Self::fan_out(out_rx));
+		Index::serve();
 		Self { in_tx, out_tx }
 	}
 
 	pub(super) fn watch(&mut self, mut new: HashSet<&Url>) {
-		new.retain(|&u| u.is_regular());
+		new.retain(|&u| u.is_regular() && NETWORK.load().at(u).is_none_or(|m| m.watch));
 		self.in_tx.send(new.into_iter().cloned().collect()).ok();
 	}
 
@@ -96,10 +93,7 @@ impl Watcher {
 		});
 	}
 
-	async fn fan_in(
-		mut rx: watch::Receiver<HashSet<Url>>,
-		mut watcher: impl notify::Watcher + Send + 'static,
-	) {
+	async fn fan_in(mut rx: watch::Receiver<HashSet<Url>>, mut watcher: Backend) {
 		loop {
 			let (to_unwatch, to_watch): (HashSet<_>, HashSet<_>) = {
 				let (new, old) = (&*rx.borrow_and_update(), &*WATCHED.read());
@@ -120,7 +114,10 @@ impl Watcher {
 
 	async fn fan_out(rx: UnboundedReceiver<Url>) {
 		// TODO: revert this once a new notification is implemented
-		let rx = UnboundedReceiverStream::new(rx).chunks_timeout(1000, Duration::from_millis(100));
+		let rx = UnboundedReceiverStream::new(rx).chunks_timeout(
+			yazi_config::WATCHER.batch,
+			Duration::from_millis(yazi_config::WATCHER.debounce),
+		);
 		pin!(rx);
 
 		while let Some(chunk) = rx.next().await {
@@ -138,7 +135,7 @@ impl Watcher {
 				};
 
 				let u = &file.url;
-				let eq = (!file.is_link() && fs::canonicalize(u).await.is_ok_and(|p| p == ***u))
+				let eq = (!file.is_link() && canonicalize(u).await.is_ok_and(|p| p == ***u))
 					|| realname_unchecked(u, &mut cached).await.is_ok_and(|s| urn.as_urn() == s);
 
 				if !eq {
@@ -149,14 +146,16 @@ impl Watcher {
 				ops.push(FilesOp::Upserting(parent, HashMap::from_iter([(urn, file)])));
 			}
 
+			INDEX.write().apply(&ops);
 			FilesOp::mutate(ops);
 		}
 	}
 
-	async fn sync_watched<W>(mut watcher: W, to_unwatch: HashSet<Url>, to_watch: HashSet<Url>) -> W
-	where
-		W: notify::Watcher + Send + 'static,
-	{
+	async fn sync_watched(
+		mut watcher: Backend,
+		to_unwatch: HashSet<Url>,
+		to_watch: HashSet<Url>,
+	) -> Backend {
 		use notify::ErrorKind::WatchNotFound;
 
 		if to_unwatch.is_empty() && to_watch.is_empty() {
@@ -172,7 +171,7 @@ impl Watcher {
 				}
 			}
 			for u in to_watch {
-				if watcher.watch(&u, RecursiveMode::NonRecursive).is_ok() {
+				if watcher.watch(&u) {
 					WATCHED.write().insert(u);
 				}
 			}
@@ -193,7 +192,7 @@ impl Watcher {
 
 		async fn go(todo: HashSet<Url>) {
 			for from in todo {
-				let Ok(to) = fs::canonicalize(&from).await else { continue };
+				let Ok(to) = canonicalize(&from).await else { continue };
 
 				if to != **from && WATCHED.read().contains(&from) {
 					LINKED.write().insert(from, Url::from(to));
@@ -205,3 +204,80 @@ impl Watcher {
 		go(old).await;
 	}
 }
+
+// --- Backend
+struct Backend {
+	native: Option<RecommendedWatcher>,
+	poll:   PollWatcher,
+	polled: HashSet<Url>,
+	warned: bool,
+}
+
+impl Backend {
+	fn new<H>(handler: H) -> Self
+	where
+		H: notify::EventHandler + Clone,
+	{
+		let config = notify::Config::default()
+			.with_poll_interval(Duration::from_millis(yazi_config::WATCHER.poll_interval));
+
+		let native = if yazi_config::WATCHER.backend == WatcherBackend::Poll || yazi_adapter::WSL.get()
+		{
+			None
+		} else {
+			RecommendedWatcher::new(handler.clone(), config)
+				.inspect_err(|e| {
+					error!("Failed to create the native watcher, falling back to polling: {e}")
+				})
+				.ok()
+		};
+
+		Self {
+			native,
+			poll: PollWatcher::new(handler, config).unwrap(),
+			polled: Default::default(),
+			warned: false,
+		}
+	}
+
+	fn watch(&mut self, url: &Url) -> bool {
+		let auto = yazi_config::WATCHER.backend == WatcherBackend::Auto;
+		if let Some(native) = self.native.as_mut().filter(|_| !auto || NETWORK.load().at(url).is_none())
+		{
+			match native.watch(url, RecursiveMode::NonRecursive) {
+				Ok(()) => return true,
+				Err(e) if auto && matches!(e.kind, notify::ErrorKind::MaxFilesWatch) => self.warn(),
+				Err(_) => return false,
+			}
+		}
+
+		if self.poll.watch(url, RecursiveMode::NonRecursive).is_err() {
+			return false;
+		}
+		self.polled.insert(url.clone());
+		true
+	}
+
+	fn unwatch(&mut self, url: &Url) -> notify::Result<()> {
+		if self.polled.remove(url) {
+			self.poll.unwatch(url)
+		} else if let Some(native) = &mut self.native {
+			native.unwatch(url)
+		} else {
+			Ok(())
+		}
+	}
+
+	fn warn(&mut self) {
+		if !self.warned {
+			self.warned = true;
+			AppProxy::notify_warn(
+				"File watcher",
+				t!(
+					"Ran out of native file watches, polling every {interval}ms instead. Raise `fs.inotify.max_user_watches` to fix it.",
+					interval = yazi_config::WATCHER.poll_interval
+				),
+			);
+		}
+	}
+}
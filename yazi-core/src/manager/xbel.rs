@@ -0,0 +1,175 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use chrono::{DateTime, SecondsFormat, Utc};
+use quick_xml::{Reader, Writer, events::{BytesEnd, BytesStart, BytesText, Event}};
+
+const SKELETON: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xbel version="1.0"
+      xmlns:bookmark="http://www.freedesktop.org/standards/desktop-bookmarks"
+      xmlns:mime="http://www.freedesktop.org/standards/shared-mime-info"
+>
+</xbel>
+"#;
+
+/// The `recently-used.xbel` of the XDG recent files spec, which is shared by
+/// the desktop applications.
+pub(super) struct Xbel;
+
+impl Xbel {
+	/// Local files in it along with the time they were last used.
+	pub(super) fn read() -> Result<Vec<(PathBuf, i64)>> {
+		let s = match std::fs::read_to_string(Self::path()) {
+			Ok(s) => s,
+			Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+			Err(e) => Err(e)?,
+		};
+
+		let mut reader = Reader::from_str(&s);
+		let mut files = vec![];
+		loop {
+			match reader.read_event()? {
+				Event::Start(e) | Event::Empty(e) if e.name().as_ref() == b"bookmark" => {
+					let (mut path, mut time) = (None, 0);
+					for attr in e.attributes().flatten() {
+						let value = attr.unescape_value()?;
+						match attr.key.as_ref() {
+							b"href" => path = url::Url::parse(&value).ok().and_then(|u| u.to_file_path().ok()),
+							b"visited" | b"modified" => {
+								let t = DateTime::parse_from_rfc3339(&value).map_or(0, |t| t.timestamp());
+								time = time.max(t);
+							}
+							_ => {}
+						}
+					}
+					files.extend(path.map(|p| (p, time)));
+				}
+				Event::Eof => break,
+				_ => {}
+			}
+		}
+		Ok(files)
+	}
+
+	/// Record the files as used by Yazi, updating the times of those already in
+	/// it and keeping everything else as is.
+	pub(super) fn write(files: Vec<(PathBuf, String)>) -> Result<()> {
+		let s = match std::fs::read_to_string(Self::path()) {
+			Ok(s) => s,
+			Err(e) if e.kind() == std::io::ErrorKind::NotFound => SKELETON.to_owned(),
+			Err(e) => Err(e)?,
+		};
+
+		let now = Utc::now().to_rfc3339_opts(SecondsFormat::Micros, true);
+		let mut todo: Vec<_> =
+			files.into_iter().filter_map(|(p, mime)| Some((Self::href(&p)?, mime))).collect();
+
+		let mut reader = Reader::from_str(&s);
+		let mut writer = Writer::new(Vec::with_capacity(s.len() + 1024));
+		loop {
+			match reader.read_event()? {
+				Event::Start(e) if e.name().as_ref() == b"bookmark" => {
+					let href = e.try_get_attribute("href")?.map(|a| a.unescape_value()).transpose()?;
+					match todo.iter().position(|(h, _)| Some(h.as_str()) == href.as_deref()) {
+						Some(i) => {
+							todo.swap_remove(i);
+							writer.write_event(Event::Start(Self::touch(&e, &now)))?;
+						}
+						None => writer.write_event(Event::Start(e))?,
+					}
+				}
+				Event::End(e) if e.name().as_ref() == b"xbel" => {
+					for (href, mime) in todo.drain(..) {
+						Self::bookmark(&mut writer, &href, &mime, &now)?;
+					}
+					writer.write_event(Event::End(e))?;
+				}
+				Event::Eof => break,
+				e => writer.write_event(e)?,
+			}
+		}
+
+		let path = Self::path();
+		if let Some(parent) = path.parent() {
+			std::fs::create_dir_all(parent)?;
+		}
+
+		// Replace it at once, as other applications may read it at any time
+		let tmp = path.with_extension(format!("xbel.{}", std::process::id()));
+		std::fs::write(&tmp, writer.into_inner())?;
+		Ok(std::fs::rename(tmp, path)?)
+	}
+
+	fn touch<'a>(e: &'a BytesStart, now: &str) -> BytesStart<'a> {
+		let mut new = BytesStart::new("bookmark");
+		new.extend_attributes(
+			e.attributes().flatten().filter(|a| !matches!(a.key.as_ref(), b"modified" | b"visited")),
+		);
+		new.push_attribute(("modified", now));
+		new.push_attribute(("visited", now));
+		new
+	}
+
+	fn bookmark(writer: &mut Writer<Vec<u8>>, href: &str, mime: &str, now: &str) -> Result<()> {
+		let mime = if mime.is_empty() { "application/octet-stream" } else { mime };
+
+		let mut start = BytesStart::new("bookmark");
+		start.push_attribute(("href", href));
+		start.push_attribute(("added", now));
+		start.push_attribute(("modified", now));
+		start.push_attribute(("visited", now));
+
+		let mut metadata = BytesStart::new("metadata");
+		metadata.push_attribute(("owner", "http://freedesktop.org"));
+
+		let mut app = BytesStart::new("bookmark:application");
+		app.push_attribute(("name", "yazi"));
+		app.push_attribute(("exec", "'yazi %u'"));
+		app.push_attribute(("modified", now));
+		app.push_attribute(("count", "1"));
+
+		writer.write_event(Event::Text(BytesText::new("  ")))?;
+		writer.write_event(Event::Start(start))?;
+		writer.write_event(Event::Start(BytesStart::new("info")))?;
+		writer.write_event(Event::Start(metadata))?;
+		writer.write_event(Event::Empty(
+			BytesStart::new("mime:mime-type").with_attributes([("type", mime)]),
+		))?;
+		writer.write_event(Event::Start(BytesStart::new("bookmark:applications")))?;
+		writer.write_event(Event::Empty(app))?;
+		writer.write_event(Event::End(BytesEnd::new("bookmark:applications")))?;
+		writer.write_event(Event::End(BytesEnd::new("metadata")))?;
+		writer.write_event(Event::End(BytesEnd::new("info")))?;
+		writer.write_event(Event::End(BytesEnd::new("bookmark")))?;
+		writer.write_event(Event::Text(BytesText::new("\n")))?;
+		Ok(())
+	}
+
+	#[inline]
+	fn href(path: &Path) -> Option<String> { url::Url::from_file_path(path).ok().map(String::from) }
+
+	#[inline]
+	fn path() -> PathBuf {
+		dirs::data_dir()
+			.or_else(|| dirs::home_dir().map(|h| h.join(".local/share")))
+			.unwrap_or_default()
+			.join("recently-used.xbel")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_href() {
+		let href = Xbel::href(Path::new("/tmp/a b/c#d.txt")).unwrap();
+		assert_eq!(href, "file:///tmp/a%20b/c%23d.txt");
+		assert_eq!(
+			url::Url::parse(&href).unwrap().to_file_path().unwrap(),
+			Path::new("/tmp/a b/c#d.txt")
+		);
+
+		assert!(Xbel::href(Path::new("relative")).is_none());
+	}
+}
@@ -22,10 +22,10 @@ impl Deref for Yanked {
 impl Yanked {
 	pub fn new(cut: bool, urls: HashSet<Url>) -> Self { Self { cut, urls, ..Default::default() } }
 
-	pub fn remove(&mut self, url: &Url) {
-		if self.urls.remove(url) {
-			self.revision += 1;
-		}
+	pub fn remove(&mut self, url: &Url) -> bool {
+		let removed = self.urls.remove(url);
+		self.revision += removed as u64;
+		removed
 	}
 
 	pub fn clear(&mut self) {
@@ -44,7 +44,8 @@ impl Yanked {
 		})
 	}
 
-	pub fn apply_op(&mut self, op: &FilesOp) {
+	pub fn apply_op(&mut self, op: &FilesOp) -> bool {
+		let revision = self.revision;
 		let (removal, addition) = op.diff_recoverable(|u| self.contains(u));
 		if !removal.is_empty() {
 			let old = self.urls.len();
@@ -57,6 +58,7 @@ impl Yanked {
 			self.urls.extend(addition);
 			self.revision += (old != self.urls.len()) as u64;
 		}
+		revision != self.revision
 	}
 
 	pub fn catchup_revision(&mut self, force: bool) -> bool {
@@ -0,0 +1,34 @@
+use std::str::FromStr;
+
+use yazi_macro::emit;
+use yazi_proxy::AppProxy;
+use yazi_shared::{Layer, event::{Cmd, CmdCow, Data}};
+
+use crate::notify::Notify;
+
+struct Opt {
+	index: usize,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self {
+		Self { index: c.first().and_then(Data::as_usize).unwrap_or(1).saturating_sub(1) }
+	}
+}
+
+impl Notify {
+	#[yazi_codegen::command]
+	pub fn act(&mut self, opt: Opt) {
+		let Some(action) = self.history.hovered().and_then(|r| r.actions.get(opt.index)) else {
+			return;
+		};
+
+		match Cmd::from_str(&action.run) {
+			Ok(cmd) => {
+				self.toggle(());
+				emit!(Call(cmd, Layer::Manager));
+			}
+			Err(e) => AppProxy::notify_warn("Notification action", e),
+		}
+	}
+}
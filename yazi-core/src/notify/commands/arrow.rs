@@ -0,0 +1,33 @@
+use yazi_macro::render;
+use yazi_shared::event::{CmdCow, Data};
+
+use crate::notify::Notify;
+
+struct Opt {
+	step: isize,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self { Self { step: c.first().and_then(Data::as_isize).unwrap_or(0) } }
+}
+
+impl From<isize> for Opt {
+	fn from(step: isize) -> Self { Self { step } }
+}
+
+impl Notify {
+	#[yazi_codegen::command]
+	pub fn arrow(&mut self, opt: Opt) {
+		let history = &mut self.history;
+		let old = history.cursor;
+
+		if opt.step > 0 {
+			history.cursor += 1;
+		} else {
+			history.cursor = history.cursor.saturating_sub(1);
+		}
+
+		history.cursor = history.cursor.min(history.len().saturating_sub(1));
+		render!(history.cursor != old);
+	}
+}
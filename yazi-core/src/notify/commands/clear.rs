@@ -0,0 +1,11 @@
+use yazi_macro::render;
+use yazi_shared::event::CmdCow;
+
+use crate::notify::Notify;
+
+impl Notify {
+	pub fn clear(&mut self, _: CmdCow) {
+		self.history.clear();
+		render!();
+	}
+}
@@ -0,0 +1,36 @@
+use yazi_macro::render;
+use yazi_shared::event::CmdCow;
+
+use crate::notify::Notify;
+
+struct Opt {
+	state: Option<bool>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self {
+		Self {
+			state: match c.first_str() {
+				Some("on") => Some(true),
+				Some("off") => Some(false),
+				_ => None,
+			},
+		}
+	}
+}
+
+impl Notify {
+	#[yazi_codegen::command]
+	pub fn dnd(&mut self, opt: Opt) {
+		let dnd = opt.state.unwrap_or(!self.dnd);
+		if dnd == self.dnd {
+			return;
+		}
+
+		self.dnd = dnd;
+		if dnd {
+			self.messages.clear();
+		}
+		render!();
+	}
+}
@@ -0,0 +1,40 @@
+use yazi_macro::render;
+use yazi_proxy::options::NotifyLevel;
+use yazi_shared::event::CmdCow;
+
+use crate::notify::Notify;
+
+struct Opt {
+	level: Option<Option<NotifyLevel>>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self {
+		Self {
+			level: match c.first_str() {
+				Some("all") => Some(None),
+				Some("info") => Some(Some(NotifyLevel::Info)),
+				Some("warn") => Some(Some(NotifyLevel::Warn)),
+				Some("error") => Some(Some(NotifyLevel::Error)),
+				_ => None,
+			},
+		}
+	}
+}
+
+impl Notify {
+	#[yazi_codegen::command]
+	pub fn filter(&mut self, opt: Opt) {
+		let history = &mut self.history;
+
+		// Without an explicit level, cycle through: all -> warn -> error -> all
+		history.level = opt.level.unwrap_or(match history.level {
+			None | Some(NotifyLevel::Info) => Some(NotifyLevel::Warn),
+			Some(NotifyLevel::Warn) => Some(NotifyLevel::Error),
+			Some(NotifyLevel::Error) => None,
+		});
+
+		history.cursor = history.cursor.min(history.len().saturating_sub(1));
+		render!();
+	}
+}
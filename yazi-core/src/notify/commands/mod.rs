@@ -1 +1 @@
-yazi_macro::mod_flat!(push tick);
+yazi_macro::mod_flat!(act arrow clear dnd filter push tick toggle);
@@ -1,17 +1,28 @@
 use std::time::Instant;
 
-use yazi_macro::emit;
+use yazi_dds::Pubsub;
+use yazi_macro::{emit, render};
 use yazi_shared::{Layer, event::Cmd};
 
-use crate::notify::{Message, Notify};
+use crate::notify::{Message, Notify, Record};
 
 impl Notify {
 	pub fn push(&mut self, msg: impl Into<Message>) {
 		let mut msg = msg.into() as Message;
+		if let Some(report) = &msg.report {
+			Pubsub::pub_from_error(&msg.title, report);
+		}
 
 		let instant = Instant::now();
-		msg.timeout += instant - self.messages.first().map_or(instant, |m| m.instant);
+		let suppressed = self.dnd || self.throttled(&msg.title, instant);
 
+		self.history.push(Record { suppressed, ..Record::from(&msg) });
+		render!(self.history.visible);
+		if suppressed {
+			return;
+		}
+
+		msg.timeout += instant - self.messages.first().map_or(instant, |m| m.instant);
 		if self.messages.iter().all(|m| m != &msg) {
 			self.messages.push(msg);
 			emit!(Call(Cmd::args("update_notify", &[0]), Layer::App));
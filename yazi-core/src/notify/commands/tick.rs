@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use ratatui::layout::Rect;
+use yazi_config::ACCESSIBILITY;
 use yazi_macro::emit;
 use yazi_shared::{Layer, event::{Cmd, CmdCow, Data}};
 
@@ -41,11 +42,13 @@ impl Notify {
 			return;
 		}
 
+		// Slide in and out by 20% per tick, or at once if animations are off
+		let step = if ACCESSIBILITY.enabled { 100 } else { 20 };
 		for m in &mut self.messages[..limit] {
 			if m.timeout.is_zero() {
-				m.percent = m.percent.saturating_sub(20);
+				m.percent = m.percent.saturating_sub(step);
 			} else if m.percent < 100 {
-				m.percent += 20;
+				m.percent = (m.percent + step).min(100);
 			} else {
 				m.timeout = m.timeout.saturating_sub(opt.interval);
 			}
@@ -0,0 +1,27 @@
+use yazi_macro::render;
+use yazi_shared::event::CmdCow;
+
+use crate::notify::Notify;
+
+struct Opt;
+
+impl From<CmdCow> for Opt {
+	fn from(_: CmdCow) -> Self { Self }
+}
+impl From<()> for Opt {
+	fn from(_: ()) -> Self { Self }
+}
+
+impl Notify {
+	#[yazi_codegen::command]
+	pub fn toggle(&mut self, _: Opt) {
+		let history = &mut self.history;
+		history.visible = !history.visible;
+
+		if history.visible {
+			history.cursor = 0;
+		}
+
+		render!();
+	}
+}
@@ -0,0 +1,151 @@
+use std::{collections::VecDeque, path::PathBuf, time::{Duration, Instant, SystemTime, UNIX_EPOCH}};
+
+use serde::{Deserialize, Serialize};
+use yazi_boot::BOOT;
+use yazi_proxy::options::{NotifyAction, NotifyLevel};
+use yazi_shared::errors::ErrorReport;
+
+use super::{HISTORY_LIMIT, Message};
+use crate::manager::Persist;
+
+pub struct Record {
+	pub title:   String,
+	pub content: String,
+	pub level:   NotifyLevel,
+	pub actions: Vec<NotifyAction>,
+	pub report:  Option<ErrorReport>,
+
+	pub instant:    Instant,
+	pub suppressed: bool,
+}
+
+impl From<&Message> for Record {
+	fn from(msg: &Message) -> Self {
+		Self {
+			title:   msg.title.clone(),
+			content: msg.content.clone(),
+			level:   msg.level,
+			actions: msg.actions.clone(),
+			report:  msg.report.clone(),
+
+			instant:    msg.instant,
+			suppressed: false,
+		}
+	}
+}
+
+#[derive(Default)]
+pub struct History {
+	pub visible: bool,
+	pub cursor:  usize,
+	pub level:   Option<NotifyLevel>,
+
+	pub(super) records: VecDeque<Record>,
+}
+
+impl History {
+	/// The records of the previous sessions, without their actions or reports,
+	/// which don't outlive the session they're from.
+	pub(super) fn load() -> Self {
+		let saved = std::fs::read_to_string(Self::path())
+			.ok()
+			.and_then(|s| toml::from_str::<Saved>(&s).ok())
+			.unwrap_or_default();
+
+		let (now, instant) = (SystemTime::now(), Instant::now());
+		let records = saved
+			.records
+			.into_iter()
+			.take(HISTORY_LIMIT)
+			.map(|r| {
+				let ago = now.duration_since(UNIX_EPOCH + Duration::from_secs(r.time)).unwrap_or_default();
+				Record {
+					title:   r.title,
+					content: r.content,
+					level:   r.level,
+					actions: vec![],
+					report:  None,
+
+					instant:    instant.checked_sub(ago).unwrap_or(instant),
+					suppressed: r.suppressed,
+				}
+			})
+			.collect();
+
+		Self { records, ..Default::default() }
+	}
+
+	pub(super) fn push(&mut self, record: Record) {
+		if self.records.len() >= HISTORY_LIMIT {
+			self.records.pop_back();
+		}
+		self.records.push_front(record);
+		self.save();
+	}
+
+	pub(super) fn clear(&mut self) {
+		self.records.clear();
+		self.cursor = 0;
+		self.save();
+	}
+
+	/// Records matching the severity filter, newest first.
+	pub fn records(&self) -> impl Iterator<Item = &Record> {
+		self.records.iter().filter(|r| self.level.is_none_or(|l| r.level >= l))
+	}
+
+	#[inline]
+	pub fn len(&self) -> usize { self.records().count() }
+
+	#[inline]
+	pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+	#[inline]
+	pub fn hovered(&self) -> Option<&Record> { self.records().nth(self.cursor) }
+}
+
+impl History {
+	fn save(&self) {
+		let content = if self.records.is_empty() {
+			None
+		} else {
+			let now = SystemTime::now();
+			let records = self
+				.records
+				.iter()
+				.map(|r| SavedRecord {
+					title:      r.title.clone(),
+					content:    r.content.clone(),
+					level:      r.level,
+					time:       now
+						.checked_sub(r.instant.elapsed())
+						.and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+						.map_or(0, |d| d.as_secs()),
+					suppressed: r.suppressed,
+				})
+				.collect();
+			toml::to_string(&Saved { records }).ok()
+		};
+		Persist { path: Self::path(), content, what: "notification history" }.queue();
+	}
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join("notify.toml") }
+}
+
+#[derive(Default, Deserialize, Serialize)]
+struct Saved {
+	#[serde(default)]
+	records: Vec<SavedRecord>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct SavedRecord {
+	title:      String,
+	content:    String,
+	level:      NotifyLevel,
+	/// Seconds since the Unix epoch.
+	time:       u64,
+	#[serde(default)]
+	suppressed: bool,
+}
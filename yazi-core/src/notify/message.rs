@@ -1,7 +1,7 @@
 use std::time::{Duration, Instant};
 
-use unicode_width::UnicodeWidthStr;
-use yazi_proxy::options::{NotifyLevel, NotifyOpt};
+use yazi_proxy::options::{NotifyAction, NotifyLevel, NotifyOpt};
+use yazi_shared::{Widths, errors::ErrorReport};
 
 use super::NOTIFY_BORDER;
 
@@ -10,6 +10,8 @@ pub struct Message {
 	pub content: String,
 	pub level:   NotifyLevel,
 	pub timeout: Duration,
+	pub actions: Vec<NotifyAction>,
+	pub report:  Option<ErrorReport>,
 
 	pub instant:   Instant,
 	pub percent:   u8,
@@ -19,15 +21,19 @@ pub struct Message {
 impl From<NotifyOpt> for Message {
 	fn from(opt: NotifyOpt) -> Self {
 		let title = opt.title.lines().next().unwrap_or_default();
-		let title_width = title.width() + (opt.level.icon().width() + /* Space */ 1);
+		let title_width = Widths::str(title)
+			+ (Widths::str(&opt.level.icon()) + /* Space */ 1)
+			+ opt.report.as_ref().map_or(0, |r| r.code.as_str().len() + /* " []" */ 3);
 
-		let max_width = opt.content.lines().map(|s| s.width()).max().unwrap_or(0).max(title_width);
+		let max_width = opt.content.lines().map(Widths::str).max().unwrap_or(0).max(title_width);
 
 		Self {
 			title:   title.to_owned(),
 			content: opt.content,
 			level:   opt.level,
 			timeout: opt.timeout,
+			actions: opt.actions,
+			report:  opt.report,
 
 			instant:   Instant::now(),
 			percent:   0,
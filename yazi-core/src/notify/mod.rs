@@ -1,6 +1,13 @@
+use std::time::Duration;
+
 yazi_macro::mod_pub!(commands);
 
-yazi_macro::mod_flat!(message notify);
+yazi_macro::mod_flat!(history message notify);
 
 pub const NOTIFY_BORDER: u16 = 2;
 pub const NOTIFY_SPACING: u16 = 1;
+
+pub const HISTORY_LIMIT: usize = 200;
+
+pub const RATE_LIMIT: usize = 3;
+pub const RATE_WINDOW: Duration = Duration::from_secs(5);
@@ -1,17 +1,24 @@
-use std::ops::ControlFlow;
+use std::{collections::{HashMap, VecDeque}, ops::ControlFlow, time::Instant};
 
 use ratatui::layout::Rect;
 use tokio::task::JoinHandle;
 
-use super::{Message, NOTIFY_SPACING};
+use super::{History, Message, NOTIFY_SPACING, RATE_LIMIT, RATE_WINDOW};
 
 #[derive(Default)]
 pub struct Notify {
 	pub(super) tick_handle: Option<JoinHandle<()>>,
 	pub messages:           Vec<Message>,
+
+	pub history: History,
+	pub dnd:     bool,
+
+	pub(super) rates: HashMap<String, VecDeque<Instant>>,
 }
 
 impl Notify {
+	pub fn make() -> Self { Self { history: History::load(), ..Default::default() } }
+
 	pub fn limit(&self, area: Rect) -> usize {
 		if self.messages.is_empty() {
 			return 0;
@@ -33,4 +40,44 @@ impl Notify {
 			ControlFlow::Break(i) => i,
 		})
 	}
+
+	/// Whether a message with the given title exceeds the rate limit,
+	/// i.e. more than [`RATE_LIMIT`] of them were shown within [`RATE_WINDOW`].
+	pub(super) fn throttled(&mut self, title: &str, now: Instant) -> bool {
+		if self.rates.len() > 100 {
+			self.rates.retain(|_, v| v.back().is_some_and(|&t| now - t <= RATE_WINDOW));
+		}
+
+		let times = self.rates.entry(title.to_owned()).or_default();
+		while times.front().is_some_and(|&t| now - t > RATE_WINDOW) {
+			times.pop_front();
+		}
+
+		if times.len() >= RATE_LIMIT {
+			return true;
+		}
+
+		times.push_back(now);
+		false
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_throttled() {
+		let mut notify = Notify::default();
+		let now = Instant::now();
+
+		for _ in 0..RATE_LIMIT {
+			assert!(!notify.throttled("spam", now));
+		}
+		assert!(notify.throttled("spam", now));
+		assert!(!notify.throttled("other", now));
+
+		let later = now + RATE_WINDOW + std::time::Duration::from_millis(1);
+		assert!(!notify.throttled("spam", later));
+	}
 }
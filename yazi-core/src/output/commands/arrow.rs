@@ -0,0 +1,24 @@
+use yazi_fs::Step;
+use yazi_macro::render;
+use yazi_shared::event::CmdCow;
+
+use crate::output::Output;
+
+struct Opt {
+	step: Step,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self {
+		Self { step: c.first().and_then(|d| d.try_into().ok()).unwrap_or_default() }
+	}
+}
+
+impl Output {
+	#[yazi_codegen::command]
+	pub fn arrow(&mut self, opt: Opt) {
+		let old = self.offset;
+		self.offset = opt.step.add(self.offset, Self::limit()).min(self.bottom());
+		render!(self.offset != old);
+	}
+}
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use tokio::pin;
+use tokio_stream::{StreamExt, wrappers::UnboundedReceiverStream};
+use yazi_config::popup::InputCfg;
+use yazi_macro::emit;
+use yazi_proxy::InputProxy;
+use yazi_shared::{Debounce, Layer, errors::InputError, event::{Cmd, CmdCow}};
+
+use crate::output::Output;
+
+struct Opt {
+	prev: bool,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self { Self { prev: c.bool("previous") } }
+}
+
+impl Output {
+	#[yazi_codegen::command]
+	pub fn find(&mut self, opt: Opt) {
+		tokio::spawn(async move {
+			let rx = InputProxy::show(InputCfg::find(opt.prev));
+
+			let rx = Debounce::new(UnboundedReceiverStream::new(rx), Duration::from_millis(50));
+			pin!(rx);
+
+			while let Some(Ok(s)) | Some(Err(InputError::Typed(s))) = rx.next().await {
+				emit!(Call(Cmd::args("find_do", &[s]).with_bool("previous", opt.prev), Layer::Output));
+			}
+		});
+	}
+}
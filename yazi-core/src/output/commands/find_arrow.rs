@@ -0,0 +1,25 @@
+use yazi_macro::render;
+use yazi_shared::event::CmdCow;
+
+use crate::output::Output;
+
+struct Opt {
+	prev: bool,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self { Self { prev: c.bool("previous") } }
+}
+
+impl Output {
+	#[yazi_codegen::command]
+	pub fn find_arrow(&mut self, opt: Opt) {
+		let Some(i) = self.search(opt.prev, false) else {
+			return;
+		};
+
+		let old = self.found;
+		self.reveal(i);
+		render!(self.found != old);
+	}
+}
@@ -0,0 +1,44 @@
+use std::borrow::Cow;
+
+use yazi_fs::{Filter, FilterCase};
+use yazi_macro::render;
+use yazi_shared::event::CmdCow;
+
+use crate::output::Output;
+
+struct Opt {
+	query: Cow<'static, str>,
+	prev:  bool,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(mut c: CmdCow) -> Self {
+		Self { query: c.take_first_str().unwrap_or_default(), prev: c.bool("previous") }
+	}
+}
+
+impl Output {
+	#[yazi_codegen::command]
+	pub fn find_do(&mut self, opt: Opt) {
+		if opt.query.is_empty() {
+			self.finder = None;
+			self.found = None;
+			return render!();
+		}
+
+		let Ok(finder) = Filter::new(&opt.query, FilterCase::Smart) else {
+			return;
+		};
+		if self.finder.as_ref().is_some_and(|f| f == &finder) {
+			return;
+		}
+
+		// Typing on refines the current match, rather than skipping past it
+		self.finder = Some(finder);
+		match self.search(opt.prev, true) {
+			Some(i) => self.reveal(i),
+			None => self.found = None,
+		}
+		render!();
+	}
+}
@@ -0,0 +1 @@
+yazi_macro::mod_flat!(arrow find find_arrow find_do push switch toggle);
@@ -0,0 +1,16 @@
+use yazi_macro::render;
+use yazi_proxy::options::OutputOpt;
+
+use crate::output::{Output, Record};
+
+impl Output {
+	pub fn push(&mut self, opt: OutputOpt) {
+		self.push_record(Record::from(opt));
+
+		self.visible = true;
+		self.cursor = 0;
+		self.offset = self.bottom();
+		self.found = None;
+		render!();
+	}
+}
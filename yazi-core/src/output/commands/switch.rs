@@ -0,0 +1,33 @@
+use yazi_macro::render;
+use yazi_shared::event::{CmdCow, Data};
+
+use crate::output::Output;
+
+struct Opt {
+	step: isize,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self { Self { step: c.first().and_then(Data::as_isize).unwrap_or(0) } }
+}
+
+impl Output {
+	/// Switch to an older output with a positive step, or a newer one with a
+	/// negative step.
+	#[yazi_codegen::command]
+	pub fn switch(&mut self, opt: Opt) {
+		let old = self.cursor;
+		if opt.step > 0 {
+			self.cursor += 1;
+		} else {
+			self.cursor = self.cursor.saturating_sub(1);
+		}
+
+		self.cursor = self.cursor.min(self.len().saturating_sub(1));
+		if self.cursor != old {
+			self.offset = self.bottom();
+			self.found = None;
+			render!();
+		}
+	}
+}
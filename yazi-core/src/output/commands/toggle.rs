@@ -0,0 +1,37 @@
+use yazi_macro::render;
+use yazi_proxy::AppProxy;
+use yazi_shared::event::CmdCow;
+
+use crate::output::Output;
+
+struct Opt;
+
+impl From<CmdCow> for Opt {
+	fn from(_: CmdCow) -> Self { Self }
+}
+impl From<()> for Opt {
+	fn from(_: ()) -> Self { Self }
+}
+
+impl Output {
+	#[yazi_codegen::command]
+	pub fn toggle(&mut self, _: Opt) {
+		if self.visible {
+			self.visible = false;
+			return render!();
+		}
+
+		if self.is_empty() {
+			return AppProxy::notify_warn(
+				"Output",
+				"No output of the commands run with `block-with-output` yet",
+			);
+		}
+
+		self.visible = true;
+		self.cursor = 0;
+		self.offset = self.bottom();
+		self.found = None;
+		render!();
+	}
+}
@@ -0,0 +1,6 @@
+yazi_macro::mod_pub!(commands);
+
+yazi_macro::mod_flat!(output record);
+
+pub const OUTPUT_LIMIT: usize = 20;
+pub const OUTPUT_LINES: usize = 10000;
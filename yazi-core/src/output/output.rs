@@ -0,0 +1,70 @@
+use std::{collections::VecDeque, ffi::OsStr};
+
+use yazi_adapter::Dimension;
+use yazi_fs::Filter;
+
+use super::{OUTPUT_LIMIT, Record};
+use crate::tasks::{TASKS_BORDER, TASKS_PADDING, TASKS_PERCENT};
+
+/// The output of the commands run in the `block-with-output` mode, newest
+/// first, and where it's scrolled to.
+#[derive(Default)]
+pub struct Output {
+	pub visible: bool,
+	pub cursor:  usize,
+	pub offset:  usize,
+	pub finder:  Option<Filter>,
+	/// The line of the current match.
+	pub found:   Option<usize>,
+
+	pub(super) records: VecDeque<Record>,
+}
+
+impl Output {
+	#[inline]
+	pub fn limit() -> usize {
+		(Dimension::available().rows * TASKS_PERCENT / 100).saturating_sub(TASKS_BORDER + TASKS_PADDING)
+			as usize
+	}
+
+	#[inline]
+	pub fn len(&self) -> usize { self.records.len() }
+
+	#[inline]
+	pub fn is_empty(&self) -> bool { self.records.is_empty() }
+
+	#[inline]
+	pub fn current(&self) -> Option<&Record> { self.records.get(self.cursor) }
+
+	/// The offset that scrolls to the end of the current record.
+	pub(super) fn bottom(&self) -> usize {
+		self.current().map_or(0, |r| r.lines.len().saturating_sub(Self::limit()))
+	}
+
+	/// The next line matching the finder after the current match, or from the
+	/// top line if there's none, wrapping around.
+	pub(super) fn search(&self, prev: bool, inclusive: bool) -> Option<usize> {
+		let (finder, record) = (self.finder.as_ref()?, self.current()?);
+		let (n, from) = (record.lines.len(), self.found.unwrap_or(self.offset));
+
+		(!inclusive as usize..n + !inclusive as usize)
+			.map(|i| if prev { (from + n - i % n) % n } else { (from + i) % n })
+			.find(|&i| finder.matches(OsStr::new(&record.lines[i])))
+	}
+
+	/// Move to a match, scrolling it to the middle if it's out of sight.
+	pub(super) fn reveal(&mut self, line: usize) {
+		let limit = Self::limit();
+		if line < self.offset || line >= self.offset + limit {
+			self.offset = line.saturating_sub(limit / 2).min(self.bottom());
+		}
+		self.found = Some(line);
+	}
+
+	pub(super) fn push_record(&mut self, record: Record) {
+		if self.records.len() >= OUTPUT_LIMIT {
+			self.records.pop_back();
+		}
+		self.records.push_front(record);
+	}
+}
@@ -0,0 +1,72 @@
+use std::time::Instant;
+
+use yazi_proxy::options::OutputOpt;
+
+use super::OUTPUT_LINES;
+
+/// What a command printed, kept after the TUI has been redrawn over it.
+pub struct Record {
+	pub title:  String,
+	pub lines:  Vec<String>,
+	pub status: Option<i32>,
+
+	pub instant: Instant,
+}
+
+impl From<OutputOpt> for Record {
+	fn from(opt: OutputOpt) -> Self {
+		let content = String::from_utf8_lossy(&opt.content);
+		let mut lines: Vec<_> = content.lines().map(plain).collect();
+		if lines.len() > OUTPUT_LINES {
+			lines.drain(..lines.len() - OUTPUT_LINES);
+		}
+
+		Self { title: opt.title, lines, status: opt.status, instant: Instant::now() }
+	}
+}
+
+/// The text of a line as it ended up on the screen: without the escape
+/// sequences, and only what's after the last carriage return, for progress bars
+/// that redraw themselves.
+fn plain(line: &str) -> String {
+	let line = line.rsplit('\r').find(|s| !s.is_empty()).unwrap_or_default();
+
+	let mut s = String::with_capacity(line.len());
+	let mut it = line.chars().peekable();
+	while let Some(c) = it.next() {
+		if c != '\x1b' {
+			if c == '\t' || !c.is_control() {
+				s.push(c);
+			}
+			continue;
+		}
+		match it.next() {
+			// CSI, ended by a byte in `@` to `~`
+			Some('[') => while it.next().is_some_and(|c| !('@'..='~').contains(&c)) {},
+			// OSC, ended by BEL or ST
+			Some(']') => {
+				while let Some(c) = it.next() {
+					if c == '\x07' || (c == '\x1b' && it.next_if_eq(&'\\').is_some()) {
+						break;
+					}
+				}
+			}
+			_ => {}
+		}
+	}
+	s
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_plain() {
+		assert_eq!(plain("abc"), "abc");
+		assert_eq!(plain("\x1b[1;31merror\x1b[0m: x"), "error: x");
+		assert_eq!(plain("\x1b]8;;http://a\x07link\x1b]8;;\x1b\\"), "link");
+		assert_eq!(plain(" 10%\r 50%\r100%"), "100%");
+		assert_eq!(plain("done\r"), "done");
+	}
+}
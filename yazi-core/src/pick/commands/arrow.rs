@@ -14,7 +14,16 @@ impl From<CmdCow> for Opt {
 impl Pick {
 	#[yazi_codegen::command]
 	pub fn arrow(&mut self, opt: Opt) {
-		if opt.step > 0 { self.next(opt.step as usize) } else { self.prev(opt.step.unsigned_abs()) }
+		let old = self.cursor;
+		if opt.step > 0 {
+			self.next(opt.step as usize)
+		} else {
+			self.prev(opt.step.unsigned_abs())
+		}
+
+		if let Some(tx) = self.hover.as_ref().filter(|_| old != self.cursor) {
+			tx.send(self.cursor).ok();
+		}
 	}
 
 	fn next(&mut self, step: usize) {
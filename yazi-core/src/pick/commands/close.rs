@@ -21,7 +21,15 @@ impl Pick {
 		if let Some(cb) = self.callback.take() {
 			_ = cb.send(if opt.submit { Ok(self.cursor) } else { Err(anyhow!("canceled")) });
 		}
+		if let Some((checked, cb)) = self.checklist.take() {
+			_ = cb.send(if opt.submit {
+				Ok(checked.into_iter().collect())
+			} else {
+				Err(anyhow!("canceled"))
+			});
+		}
 
+		self.hover = None;
 		self.cursor = 0;
 		self.offset = 0;
 		self.visible = false;
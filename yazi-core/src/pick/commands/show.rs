@@ -1,4 +1,4 @@
-use tokio::sync::oneshot;
+use tokio::sync::{mpsc, oneshot};
 use yazi_config::popup::PickCfg;
 use yazi_macro::render;
 use yazi_shared::event::CmdCow;
@@ -6,15 +6,28 @@ use yazi_shared::event::CmdCow;
 use crate::pick::Pick;
 
 pub struct Opt {
-	cfg: PickCfg,
-	tx:  oneshot::Sender<anyhow::Result<usize>>,
+	cfg:   PickCfg,
+	tx:    Tx,
+	hover: Option<mpsc::UnboundedSender<usize>>,
+}
+
+enum Tx {
+	One(oneshot::Sender<anyhow::Result<usize>>),
+	Many(oneshot::Sender<anyhow::Result<Vec<usize>>>),
 }
 
 impl TryFrom<CmdCow> for Opt {
 	type Error = ();
 
 	fn try_from(mut c: CmdCow) -> Result<Self, Self::Error> {
-		Ok(Self { cfg: c.take_any("cfg").ok_or(())?, tx: c.take_any("tx").ok_or(())? })
+		Ok(Self {
+			cfg:   c.take_any("cfg").ok_or(())?,
+			tx:    match c.take_any("tx") {
+				Some(tx) => Tx::One(tx),
+				None => Tx::Many(c.take_any("checklist").ok_or(())?),
+			},
+			hover: c.take_any("hover"),
+		})
 	}
 }
 
@@ -29,7 +42,11 @@ impl Pick {
 		self.items = opt.cfg.items;
 		self.position = opt.cfg.position;
 
-		self.callback = Some(opt.tx);
+		match opt.tx {
+			Tx::One(tx) => self.callback = Some(tx),
+			Tx::Many(tx) => self.checklist = Some(((0..self.items.len()).collect(), tx)),
+		}
+		self.hover = opt.hover;
 		self.visible = true;
 		render!();
 	}
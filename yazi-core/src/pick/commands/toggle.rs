@@ -0,0 +1,14 @@
+use yazi_macro::render;
+use yazi_shared::event::CmdCow;
+
+use crate::pick::Pick;
+
+impl Pick {
+	pub fn toggle(&mut self, _: CmdCow) {
+		let Some((checked, _)) = &mut self.checklist else { return };
+		if !checked.remove(&self.cursor) {
+			checked.insert(self.cursor);
+		}
+		render!();
+	}
+}
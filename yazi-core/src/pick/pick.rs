@@ -1,7 +1,12 @@
+use std::collections::BTreeSet;
+
 use anyhow::Result;
-use tokio::sync::oneshot::Sender;
+use tokio::sync::{mpsc::UnboundedSender, oneshot::Sender};
 use yazi_config::{PICK, popup::Position};
 
+/// The checked items of a checklist, and where they go once it's closed.
+pub(super) type Checklist = (BTreeSet<usize>, Sender<Result<Vec<usize>>>);
+
 #[derive(Default)]
 pub struct Pick {
 	pub(super) title: String,
@@ -11,6 +16,10 @@ pub struct Pick {
 	pub(super) offset:   usize,
 	pub(super) cursor:   usize,
 	pub(super) callback: Option<Sender<Result<usize>>>,
+	pub(super) hover:    Option<UnboundedSender<usize>>,
+
+	/// Set if it's a checklist.
+	pub(super) checklist: Option<Checklist>,
 
 	pub visible: bool,
 }
@@ -24,7 +33,7 @@ impl Pick {
 
 	#[inline]
 	pub(super) fn limit(&self) -> usize {
-		self.position.offset.height.saturating_sub(PICK.border()) as usize
+		self.position.offset.height.saturating_sub(PICK.load().border()) as usize
 	}
 }
 
@@ -34,4 +43,10 @@ impl Pick {
 
 	#[inline]
 	pub fn rel_cursor(&self) -> usize { self.cursor - self.offset }
+
+	/// Whether the item at `rel` of the window is checked, if it's a checklist.
+	#[inline]
+	pub fn checked(&self, rel: usize) -> Option<bool> {
+		self.checklist.as_ref().map(|(s, _)| s.contains(&(self.offset + rel)))
+	}
 }
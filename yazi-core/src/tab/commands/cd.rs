@@ -1,15 +1,16 @@
-use std::{mem, time::Duration};
+use std::{mem, path::{Path, PathBuf}, time::Duration};
 
 use tokio::{fs, pin};
 use tokio_stream::{StreamExt, wrappers::UnboundedReceiverStream};
-use yazi_config::popup::InputCfg;
+use yazi_boot::BOOT;
+use yazi_config::{popup::InputCfg, t};
 use yazi_dds::Pubsub;
 use yazi_fs::expand_path;
 use yazi_macro::render;
-use yazi_proxy::{CompletionProxy, InputProxy, ManagerProxy, TabProxy};
+use yazi_proxy::{AppProxy, CompletionProxy, InputProxy, ManagerProxy, TabProxy};
 use yazi_shared::{Debounce, errors::InputError, event::CmdCow, url::Url};
 
-use crate::tab::Tab;
+use crate::{manager::RECENTS, tab::Tab};
 
 struct Opt {
 	target:      Url,
@@ -47,6 +48,15 @@ impl Tab {
 
 		if opt.target == *self.cwd() {
 			return;
+		} else if !Self::confined(&opt.target) {
+			let root = Url::from(BOOT.root.as_ref().unwrap());
+			AppProxy::notify_warn("Restricted", t!("Cannot leave `{root}`", root = root.display()));
+
+			// A new tab that's nowhere yet starts at the root instead
+			if opt.target != root && !Self::confined(self.cwd()) {
+				self.cd(root);
+			}
+			return;
 		}
 
 		// Take parent to history
@@ -69,6 +79,7 @@ impl Tab {
 		// Backstack
 		if opt.target.is_regular() {
 			self.backstack.push(opt.target.clone());
+			RECENTS.write().visit(&opt.target);
 		}
 
 		Pubsub::pub_from_cd(self.id, self.cwd());
@@ -76,6 +87,25 @@ impl Tab {
 		render!();
 	}
 
+	/// Whether the URL is within the root directory in restricted mode, after
+	/// resolving the `..` and symlinks in it, so neither of them can escape it.
+	pub(super) fn confined(url: &Url) -> bool {
+		BOOT.root.as_ref().is_none_or(|root| within(url, root))
+	}
+
+	/// Whether all the `paths` to write to are in the root, if it's restricted to
+	/// one, where those that don't exist yet are taken as where they'd be
+	/// created, and warns that it can't be left if not.
+	pub(crate) fn confine(paths: impl IntoIterator<Item = impl AsRef<Path>>) -> bool {
+		let Some(root) = BOOT.root.as_ref() else { return true };
+		if paths.into_iter().all(|p| resolve(p.as_ref()).is_some_and(|p| p.starts_with(root))) {
+			return true;
+		}
+
+		AppProxy::notify_warn("Restricted", t!("Cannot leave `{root}`", root = root.display()));
+		false
+	}
+
 	fn cd_interactive(&mut self) {
 		tokio::spawn(async move {
 			let rx = InputProxy::show(InputCfg::cd());
@@ -106,3 +136,49 @@ impl Tab {
 		});
 	}
 }
+
+fn within(path: &Path, root: &Path) -> bool {
+	std::fs::canonicalize(path).is_ok_and(|p| p.starts_with(root))
+}
+
+/// The path with its symlinks resolved as far as it exists, and the rest that
+/// doesn't joined as is, or `None` if the rest has a `..` in it.
+fn resolve(path: &Path) -> Option<PathBuf> {
+	if let Ok(p) = std::fs::canonicalize(path) {
+		return Some(p);
+	}
+	Some(resolve(path.parent()?)?.join(path.file_name()?))
+}
+
+#[cfg(unix)]
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_within() {
+		let dir = std::env::temp_dir().join("yazi-within");
+		std::fs::remove_dir_all(&dir).ok();
+		std::fs::create_dir_all(dir.join("root/sub")).unwrap();
+		std::fs::create_dir_all(dir.join("rootless")).unwrap();
+		std::os::unix::fs::symlink(&dir, dir.join("root/escape")).unwrap();
+
+		let root = std::fs::canonicalize(dir.join("root")).unwrap();
+		assert!(within(&dir.join("root"), &root));
+		assert!(within(&dir.join("root/sub"), &root));
+		assert!(!within(&dir.join("root/sub/../.."), &root));
+		assert!(!within(&dir.join("root/escape"), &root));
+		assert!(!within(&dir.join("root/escape/rootless"), &root));
+		assert!(!within(&dir.join("rootless"), &root));
+		assert!(!within(&dir.join("root/missing"), &root));
+
+		let resolve = |p: &str| resolve(&dir.join(p)).is_some_and(|p| p.starts_with(&root));
+		assert!(resolve("root/missing"));
+		assert!(resolve("root/sub/missing/deeper"));
+		assert!(!resolve("root/missing/../../rootless"));
+		assert!(!resolve("root/../rootless/missing"));
+		assert!(!resolve("root/escape/missing"));
+
+		std::fs::remove_dir_all(&dir).ok();
+	}
+}
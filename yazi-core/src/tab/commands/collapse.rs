@@ -0,0 +1,33 @@
+use yazi_fs::GroupBy;
+use yazi_proxy::ManagerProxy;
+use yazi_shared::event::CmdCow;
+
+use crate::tab::Tab;
+
+impl Tab {
+	/// Collapses the group of the hovered file to its first file, or expands it.
+	pub fn collapse(&mut self, _: CmdCow) {
+		let files = &self.current.files;
+		let group = files.sorter().group;
+		if group == GroupBy::None {
+			return;
+		}
+
+		let Some(label) = self.hovered().and_then(|h| group.label(h)) else { return };
+
+		// Stay on the first file of the group, the one left when it's collapsed
+		let first = files[..=self.current.cursor]
+			.iter()
+			.rev()
+			.take_while(|f| group.label(f).as_ref() == Some(&label))
+			.last()
+			.map(|f| f.urn_owned());
+
+		self.current.files.toggle_collapsed(label);
+		self.current.files.catchup_revision();
+		self.current.repos(first);
+
+		ManagerProxy::hover(None, self.id);
+		ManagerProxy::update_paged();
+	}
+}
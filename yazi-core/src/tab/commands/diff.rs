@@ -0,0 +1,29 @@
+use yazi_proxy::{AppProxy, ManagerProxy};
+use yazi_shared::event::CmdCow;
+
+use crate::tab::{PreviewDiff, Tab};
+
+struct Opt {
+	unified: bool,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self { Self { unified: c.bool("unified") } }
+}
+
+impl Tab {
+	#[yazi_codegen::command]
+	pub fn diff(&mut self, opt: Opt) {
+		if self.preview.diff.take().is_some() {
+			return ManagerProxy::peek(true);
+		}
+
+		let [old, new] = match self.selected.keys().collect::<Vec<_>>()[..] {
+			[old, new] => [old.clone(), new.clone()],
+			_ => return AppProxy::notify_warn("Diff", "Select exactly two files to compare"),
+		};
+
+		self.preview.diff = Some(PreviewDiff::new(old, new, opt.unified));
+		ManagerProxy::peek(true);
+	}
+}
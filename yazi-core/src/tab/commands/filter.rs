@@ -44,6 +44,7 @@ impl Tab {
 					Cmd::args("filter_do", &[s])
 						.with_bool("smart", opt.case == FilterCase::Smart)
 						.with_bool("insensitive", opt.case == FilterCase::Insensitive)
+						.with_bool("fuzzy", opt.case == FilterCase::Fuzzy)
 						.with_bool("done", done),
 					Layer::Manager
 				));
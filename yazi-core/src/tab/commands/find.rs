@@ -36,7 +36,8 @@ impl Tab {
 					Cmd::args("find_do", &[s])
 						.with_bool("previous", opt.prev)
 						.with_bool("smart", opt.case == FilterCase::Smart)
-						.with_bool("insensitive", opt.case == FilterCase::Insensitive),
+						.with_bool("insensitive", opt.case == FilterCase::Insensitive)
+						.with_bool("fuzzy", opt.case == FilterCase::Fuzzy),
 					Layer::Manager
 				));
 			}
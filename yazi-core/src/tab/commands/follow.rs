@@ -0,0 +1,21 @@
+use yazi_config::t;
+use yazi_proxy::{AppProxy, TabProxy};
+use yazi_shared::event::CmdCow;
+
+use crate::tab::Tab;
+
+impl Tab {
+	pub fn follow(&mut self, _: CmdCow) {
+		let Some(hovered) = self.hovered() else { return };
+		let Some(to) = &hovered.link_to else {
+			return AppProxy::notify_warn("Follow", "The hovered file is not a symlink");
+		};
+
+		if hovered.cha.is_orphan() {
+			return AppProxy::notify_warn("Follow", t!("The target `{to}` does not exist", to = to));
+		}
+
+		let Some(parent) = hovered.url.parent_url() else { return };
+		TabProxy::reveal(&parent.join(to));
+	}
+}
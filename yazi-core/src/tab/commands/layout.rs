@@ -0,0 +1,23 @@
+use yazi_config::{MANAGER, t};
+use yazi_proxy::AppProxy;
+use yazi_shared::event::CmdCow;
+
+use crate::tab::Tab;
+
+impl Tab {
+	pub fn layout(&mut self, mut c: CmdCow) {
+		let layout = match c.take_first_str() {
+			None => None,
+			Some(s) if s == "reset" => None,
+			Some(s) if MANAGER.load().layouts.contains_key(s.as_ref()) => Some(s.into_owned()),
+			Some(s) => {
+				return AppProxy::notify_warn("`layout` command", t!("Unknown layout `{s}`", s = s));
+			}
+		};
+
+		if layout != self.pref.layout {
+			self.pref.layout = layout;
+			AppProxy::resize();
+		}
+	}
+}
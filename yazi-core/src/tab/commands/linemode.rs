@@ -1,3 +1,4 @@
+use yazi_config::MANAGER;
 use yazi_macro::render;
 use yazi_shared::event::CmdCow;
 
@@ -5,7 +6,19 @@ use crate::tab::Tab;
 
 impl Tab {
 	pub fn linemode(&mut self, mut c: CmdCow) {
+		let step = if c.bool("next") {
+			1
+		} else if c.bool("prev") {
+			-1
+		} else {
+			0
+		};
+
 		render!(self.pref.patch(|new| {
+			if step != 0 {
+				return Self::cycle_linemode(new, step);
+			}
+
 			let Some(mode) = c.take_first_str() else {
 				return;
 			};
@@ -14,4 +27,19 @@ impl Tab {
 			}
 		}));
 	}
+
+	fn cycle_linemode(pref: &mut crate::tab::Preference, step: isize) {
+		let cycle = &MANAGER.load().linemode_cycle;
+		if cycle.is_empty() {
+			return;
+		}
+
+		let len = cycle.len() as isize;
+		let next = match cycle.iter().position(|m| *m == pref.linemode) {
+			Some(i) => (i as isize + step).rem_euclid(len),
+			None if step > 0 => 0,
+			None => len - 1,
+		};
+		pref.linemode = cycle[next as usize].clone();
+	}
 }
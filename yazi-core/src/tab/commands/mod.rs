@@ -2,7 +2,9 @@ yazi_macro::mod_flat!(
 	arrow
 	back
 	cd
+	collapse
 	copy
+	diff
 	enter
 	escape
 	filter
@@ -10,8 +12,10 @@ yazi_macro::mod_flat!(
 	find
 	find_arrow
 	find_do
+	follow
 	forward
 	hidden
+	layout
 	leave
 	linemode
 	reveal
@@ -22,5 +26,6 @@ yazi_macro::mod_flat!(
 	toggle_all
 	update_peeked
 	update_spotted
+	view
 	visual_mode
 );
@@ -28,6 +28,9 @@ impl Tab {
 		let Some(parent) = opt.target.parent_url() else {
 			return;
 		};
+		if !Self::confined(&parent) {
+			return self.cd(parent);
+		}
 
 		self.cd(parent.clone());
 		FilesOp::Creating(parent, vec![File::from_dummy(opt.target.clone(), None)]).emit();
@@ -1,14 +1,16 @@
 use std::{borrow::Cow, mem, time::Duration};
 
-use tokio::pin;
+use anyhow::Result;
+use tokio::{pin, sync::mpsc::{self, UnboundedReceiver}};
 use tokio_stream::{StreamExt, wrappers::UnboundedReceiverStream};
 use tracing::error;
-use yazi_config::popup::InputCfg;
-use yazi_fs::{Cha, FilesOp};
+use yazi_config::{INDEXER, popup::InputCfg};
+use yazi_fs::{Cha, File, FilesOp};
 use yazi_plugin::external;
 use yazi_proxy::{AppProxy, InputProxy, ManagerProxy, TabProxy, options::{SearchOpt, SearchOptVia}};
+use yazi_shared::url::Url;
 
-use crate::tab::Tab;
+use crate::{manager::INDEX, tab::Tab};
 
 impl Tab {
 	pub fn search(&mut self, opt: impl TryInto<SearchOpt>) {
@@ -18,6 +20,13 @@ impl Tab {
 
 		if opt.via == SearchOptVia::None {
 			return self.search_stop();
+		} else if opt.via == SearchOptVia::Index && !INDEXER.enabled {
+			return AppProxy::notify_warn(
+				"Search",
+				"The indexer is disabled, enable it in the [indexer] section of your yazi.toml",
+			);
+		} else if opt.via == SearchOptVia::Index && INDEX.read().len() == 0 {
+			AppProxy::notify_warn("Search", "The index is still being built, results may be incomplete");
 		}
 
 		if let Some(handle) = self.search.take() {
@@ -48,7 +57,9 @@ impl Tab {
 		let hidden = self.pref.show_hidden;
 
 		self.search = Some(tokio::spawn(async move {
-			let rx = if opt.via == SearchOptVia::Rg {
+			let rx = if opt.via == SearchOptVia::Index {
+				Self::search_index(cwd.clone(), opt.subject.into_owned())
+			} else if opt.via == SearchOptVia::Rg {
 				external::rg(external::RgOpt {
 					cwd: cwd.clone(),
 					hidden,
@@ -77,6 +88,24 @@ impl Tab {
 		}));
 	}
 
+	fn search_index(cwd: Url, subject: String) -> Result<UnboundedReceiver<File>> {
+		let (tx, rx) = mpsc::unbounded_channel();
+
+		tokio::spawn(async move {
+			let Ok(paths) = tokio::task::spawn_blocking(move || INDEX.read().search(&subject)).await
+			else {
+				return;
+			};
+			for path in paths {
+				let Ok(file) = File::from(cwd.join(path)).await else { continue };
+				if tx.send(file).is_err() {
+					break;
+				}
+			}
+		});
+		Ok(rx)
+	}
+
 	pub(super) fn search_stop(&mut self) {
 		if let Some(handle) = self.search.take() {
 			handle.abort();
@@ -1,7 +1,7 @@
 use std::{borrow::Cow, fmt::Display};
 
 use anyhow::bail;
-use yazi_config::{open::Opener, popup::InputCfg};
+use yazi_config::{open::{Opener, OpenerMode}, popup::InputCfg};
 use yazi_proxy::{AppProxy, InputProxy, TasksProxy};
 use yazi_shared::{event::{CmdCow, Data}, url::Url};
 
@@ -12,6 +12,7 @@ pub struct Opt {
 	cwd: Option<Url>,
 
 	block:       bool,
+	capture:     bool,
 	orphan:      bool,
 	interactive: bool,
 
@@ -27,6 +28,7 @@ impl TryFrom<CmdCow> for Opt {
 			cwd: c.take_url("cwd"),
 
 			block:       c.bool("block"),
+			capture:     c.bool("capture"),
 			orphan:      c.bool("orphan"),
 			interactive: c.bool("interactive"),
 
@@ -69,12 +71,20 @@ impl Tab {
 
 			TasksProxy::open_with(
 				Cow::Owned(Opener {
-					run:    opt.run.into_owned(),
-					block:  opt.block,
-					orphan: opt.orphan,
-					desc:   Default::default(),
-					for_:   None,
-					spread: true,
+					run:     opt.run.into_owned(),
+					mode:    match (opt.block, opt.capture, opt.orphan) {
+						(true, true, _) => OpenerMode::BlockWithOutput,
+						(true, false, _) => OpenerMode::Block,
+						(_, _, true) => OpenerMode::Orphan,
+						_ => OpenerMode::BackgroundWithLog,
+					},
+					input:   Default::default(),
+					env:     Default::default(),
+					cwd:     None,
+					desc:    Default::default(),
+					for_:    None,
+					spread:  true,
+					resolve: false,
 				}),
 				cwd,
 				selected,
@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use yazi_fs::SortBy;
+use yazi_fs::{GroupBy, SortBy};
 use yazi_proxy::ManagerProxy;
 use yazi_shared::event::CmdCow;
 
@@ -17,6 +17,9 @@ impl Tab {
 		pref.sort_dir_first = c.maybe_bool("dir-first").unwrap_or(pref.sort_dir_first);
 		pref.sort_sensitive = c.maybe_bool("sensitive").unwrap_or(pref.sort_sensitive);
 		pref.sort_translit = c.maybe_bool("translit").unwrap_or(pref.sort_translit);
+		if let Some(group) = c.str("group-by") {
+			pref.group_by = GroupBy::from_str(group).unwrap_or_default();
+		}
 
 		self.apply_files_attrs();
 
@@ -31,6 +31,7 @@ impl Tab {
 		}
 
 		self.preview.lock = Some(opt.lock);
+		self.preview.targets = Default::default();
 		render!();
 	}
 }
@@ -0,0 +1,30 @@
+use yazi_config::manager::ManagerView;
+use yazi_proxy::{AppProxy, ManagerProxy};
+use yazi_shared::event::CmdCow;
+
+use crate::tab::Tab;
+
+impl Tab {
+	pub fn view(&mut self, mut c: CmdCow) {
+		let view = match c.take_first_str().as_deref() {
+			Some("list") => ManagerView::List,
+			Some("details") => ManagerView::Details,
+			Some("timeline") => ManagerView::Timeline,
+			_ => self.pref.view.toggle(),
+		};
+
+		if view == self.pref.view {
+			return;
+		}
+
+		// The timeline has its own sorting
+		let resort = [view, self.pref.view].contains(&ManagerView::Timeline);
+		self.pref.view = view;
+		if resort {
+			self.apply_files_attrs();
+			ManagerProxy::hover(None, self.id);
+			ManagerProxy::update_paged();
+		}
+		AppProxy::resize();
+	}
+}
@@ -24,7 +24,7 @@ impl Default for Folder {
 		Self {
 			url:    Default::default(),
 			cha:    Default::default(),
-			files:  Files::new(MANAGER.show_hidden),
+			files:  Files::new(MANAGER.load().show_hidden),
 			stage:  Default::default(),
 			offset: Default::default(),
 			cursor: Default::default(),
@@ -140,7 +140,7 @@ impl Folder {
 		let len = self.files.len();
 
 		let limit = LAYOUT.get().current.height as usize;
-		let scrolloff = (limit / 2).min(MANAGER.scrolloff as usize);
+		let scrolloff = (limit / 2).min(MANAGER.load().scrolloff as usize);
 
 		self.cursor = step.add(self.cursor, limit).min(len.saturating_sub(1));
 		self.offset = if self.cursor < (self.offset + limit).min(len).saturating_sub(scrolloff) {
@@ -157,7 +157,7 @@ impl Folder {
 		let max = self.files.len().saturating_sub(1);
 
 		let limit = LAYOUT.get().current.height as usize;
-		let scrolloff = (limit / 2).min(MANAGER.scrolloff as usize);
+		let scrolloff = (limit / 2).min(MANAGER.load().scrolloff as usize);
 
 		self.cursor = step.add(self.cursor, limit).min(max);
 		self.offset = if self.cursor < self.offset + scrolloff {
@@ -174,7 +174,7 @@ impl Folder {
 		let len = self.files.len();
 
 		let limit = LAYOUT.get().current.height as usize;
-		let scrolloff = (limit / 2).min(MANAGER.scrolloff as usize);
+		let scrolloff = (limit / 2).min(MANAGER.load().scrolloff as usize);
 
 		self.offset = if self.cursor < (self.offset + limit).min(len).saturating_sub(scrolloff) {
 			len.saturating_sub(limit).min(self.offset)
@@ -198,4 +198,9 @@ impl Folder {
 		let end = ((page + 2) * limit).min(len);
 		&self.files[start..end]
 	}
+
+	pub fn window(&self) -> &[File] {
+		let end = (self.offset + LAYOUT.get().current.height as usize).min(self.files.len());
+		&self.files[self.offset.min(end)..end]
+	}
 }
@@ -1,27 +1,120 @@
-use std::{collections::HashMap, ops::{Deref, DerefMut}};
+use std::{cmp::Reverse, collections::HashMap, ops::{Deref, DerefMut}};
 
-use yazi_shared::url::Url;
+use indexmap::IndexMap;
+use yazi_shared::url::{Url, UrnBuf};
 
 use super::Folder;
 
+/// How many of the evicted folders to remember the hovered file of.
+const TRACES: usize = 1000;
+
 #[derive(Default)]
-pub struct History(HashMap<Url, Folder>);
+pub struct History {
+	folders: HashMap<Url, Folder>,
+	/// The files hovered in the evicted folders, to bring back on revisit.
+	traces:  IndexMap<Url, UrnBuf>,
+	/// The estimated bytes of each folder, by the revision of its files.
+	bytes:   HashMap<Url, (u64, usize)>,
+}
 
 impl Deref for History {
 	type Target = HashMap<Url, Folder>;
 
 	#[inline]
-	fn deref(&self) -> &Self::Target { &self.0 }
+	fn deref(&self) -> &Self::Target { &self.folders }
 }
 
 impl DerefMut for History {
 	#[inline]
-	fn deref_mut(&mut self) -> &mut Self::Target { &mut self.0 }
+	fn deref_mut(&mut self) -> &mut Self::Target { &mut self.folders }
 }
 
 impl History {
 	#[inline]
 	pub fn remove_or(&mut self, url: &Url) -> Folder {
-		self.0.remove(url).unwrap_or_else(|| Folder::from(url))
+		self.folders.remove(url).unwrap_or_else(|| self.revive(url))
+	}
+
+	#[inline]
+	pub fn get_or_insert(&mut self, url: &Url) -> &mut Folder {
+		if !self.folders.contains_key(url) {
+			let folder = self.revive(url);
+			self.folders.insert(url.clone(), folder);
+		}
+		self.folders.get_mut(url).unwrap()
+	}
+
+	/// Evicts the largest folders until the total number of cached entries fits
+	/// in `limit`, and their estimated bytes in `bytes` if any, except `keep`.
+	/// The `reserved` entries and bytes are those of the folders not in the
+	/// history, i.e. the current and parent ones, which count towards the limits
+	/// as well.
+	///
+	/// Evicted folders are read from the disk again on revisit, where the file
+	/// hovered before is hovered again.
+	pub fn shrink(
+		&mut self,
+		keep: Option<&Url>,
+		reserved: (usize, usize),
+		limit: usize,
+		bytes: Option<usize>,
+	) {
+		let mut total: usize = reserved.0 + self.folders.values().map(|f| f.files.len()).sum::<usize>();
+		let mut size = if bytes.is_some() { reserved.1 + self.measure() } else { 0 };
+
+		let bytes = bytes.unwrap_or(usize::MAX);
+		if total <= limit && size <= bytes {
+			return;
+		}
+
+		let mut largest: Vec<_> = self
+			.folders
+			.iter()
+			.filter(|&(u, _)| Some(u) != keep)
+			.map(|(u, f)| (u.clone(), f.files.len(), self.bytes.get(u).map_or(0, |&(_, b)| b)))
+			.collect();
+		largest.sort_unstable_by_key(|&(_, len, b)| Reverse((len, b)));
+
+		for (url, len, b) in largest {
+			if total <= limit && size <= bytes {
+				break;
+			}
+			self.evict(url);
+			(total, size) = (total - len, size.saturating_sub(b));
+		}
+	}
+
+	/// The estimated bytes of all the folders, measuring only the ones that have
+	/// changed since they were last measured.
+	fn measure(&mut self) -> usize {
+		self.bytes.retain(|u, _| self.folders.contains_key(u));
+		self
+			.folders
+			.iter()
+			.map(|(u, f)| match self.bytes.get(u) {
+				Some(&(rev, b)) if rev == f.files.revision => b,
+				_ => {
+					let b = f.files.bytes();
+					self.bytes.insert(u.clone(), (f.files.revision, b));
+					b
+				}
+			})
+			.sum()
+	}
+
+	fn evict(&mut self, url: Url) {
+		let Some(folder) = self.folders.remove(&url) else { return };
+		self.bytes.remove(&url);
+
+		if let Some(urn) = folder.hovered().map(|h| h.urn_owned()).or(folder.trace) {
+			self.traces.insert(url, urn);
+			if self.traces.len() > TRACES {
+				self.traces.shift_remove_index(0);
+			}
+		}
+	}
+
+	fn revive(&mut self, url: &Url) -> Folder {
+		Folder { trace: self.traces.shift_remove(url), ..Folder::from(url) }
 	}
 }
@@ -1,3 +1,3 @@
 yazi_macro::mod_pub!(commands);
 
-yazi_macro::mod_flat!(backstack finder folder history mode preference preview selected tab);
+yazi_macro::mod_flat!(backstack finder folder history mode prelist preference preview preview_targets selected tab);
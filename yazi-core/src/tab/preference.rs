@@ -1,5 +1,5 @@
-use yazi_config::MANAGER;
-use yazi_fs::{FilesSorter, SortBy};
+use yazi_config::{MANAGER, manager::ManagerView};
+use yazi_fs::{FilesSorter, GroupBy, SortBy};
 
 #[derive(Clone, PartialEq)]
 pub struct Preference {
@@ -9,30 +9,49 @@ pub struct Preference {
 	pub sort_reverse:   bool,
 	pub sort_dir_first: bool,
 	pub sort_translit:  bool,
+	pub group_by:       GroupBy,
 
 	// Display
 	pub linemode:    String,
 	pub show_hidden: bool,
+	pub view:        ManagerView,
+	pub layout:      Option<String>,
 }
 
 impl Default for Preference {
 	fn default() -> Self {
 		Self {
 			// Sorting
-			sort_by:        MANAGER.sort_by,
-			sort_sensitive: MANAGER.sort_sensitive,
-			sort_reverse:   MANAGER.sort_reverse,
-			sort_dir_first: MANAGER.sort_dir_first,
-			sort_translit:  MANAGER.sort_translit,
+			sort_by:        MANAGER.load().sort_by,
+			sort_sensitive: MANAGER.load().sort_sensitive,
+			sort_reverse:   MANAGER.load().sort_reverse,
+			sort_dir_first: MANAGER.load().sort_dir_first,
+			sort_translit:  MANAGER.load().sort_translit,
+			group_by:       MANAGER.load().group_by,
 
 			// Display
-			linemode:    MANAGER.linemode.to_owned(),
-			show_hidden: MANAGER.show_hidden,
+			linemode:    MANAGER.load().linemode.to_owned(),
+			show_hidden: MANAGER.load().show_hidden,
+			view:        MANAGER.load().view,
+			layout:      None,
 		}
 	}
 }
 
 impl Preference {
+	/// Follow the new defaults once the config is reloaded, except for the ones
+	/// that differ from the `old` defaults, i.e. have been changed in the tab.
+	pub fn rebase(&mut self, old: &Self) {
+		let new = Self::default();
+		macro_rules! follow {
+			($($field:ident),+) => {
+				$(if self.$field == old.$field { self.$field = new.$field.clone(); })+
+			};
+		}
+		follow!(sort_by, sort_sensitive, sort_reverse, sort_dir_first, sort_translit, group_by);
+		follow!(linemode, show_hidden, view);
+	}
+
 	pub(super) fn patch<F: FnOnce(&mut Self)>(&mut self, f: F) -> bool {
 		let old = self.clone();
 		f(self);
@@ -42,11 +61,21 @@ impl Preference {
 
 impl From<&Preference> for FilesSorter {
 	fn from(value: &Preference) -> Self {
+		if value.view == ManagerView::Timeline {
+			return FilesSorter {
+				by: SortBy::Mtime,
+				reverse: true,
+				group: GroupBy::Mtime,
+				..Default::default()
+			};
+		}
+
 		FilesSorter {
 			by:        value.sort_by,
 			sensitive: value.sort_sensitive,
 			reverse:   value.sort_reverse,
 			dir_first: value.sort_dir_first,
+			group:     value.group_by,
 			translit:  value.sort_translit,
 		}
 	}
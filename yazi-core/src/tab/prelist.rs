@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use yazi_config::{MANAGER, NETWORK, manager::ManagerPrelist};
+use yazi_fs::{Cha, Files, FilesOp};
+
+use super::Tab;
+
+impl Tab {
+	/// List the directories around the hovered file once the cursor rests for a
+	/// moment: the hovered one, those right before and after it, and the parent
+	/// of the parent, so that moving to them or leaving is instant.
+	pub fn prelist(&mut self) {
+		self.prelister.take().map(|h| h.abort());
+		match MANAGER.load().prelist {
+			ManagerPrelist::All => {}
+			ManagerPrelist::Local if NETWORK.load().at(self.cwd()).is_none() => {}
+			_ => return,
+		}
+
+		let (cursor, files) = (self.current.cursor, &self.current.files);
+		let around = [Some(cursor), cursor.checked_sub(1), cursor.checked_add(1)]
+			.into_iter()
+			.flatten()
+			.filter_map(|i| files.get(i))
+			.filter(|f| f.is_dir())
+			.map(|f| &f.url);
+
+		let grand = self.parent.as_ref().and_then(|p| p.url.parent_url());
+		let todo: Vec<_> = around
+			.chain(&grand)
+			.filter(|u| u.is_regular())
+			.map(|u| (u.clone(), self.history.get(u).map_or(Cha::dummy(), |f| f.cha)))
+			.collect();
+		if todo.is_empty() {
+			return;
+		}
+
+		self.prelister = Some(tokio::spawn(async move {
+			tokio::time::sleep(Duration::from_millis(200)).await;
+			for (url, cha) in todo {
+				let Some(cha) = Files::assert_stale(&url, cha).await else { continue };
+				if let Ok(files) = Files::from_dir_bulk(&url).await {
+					FilesOp::Full(url, files, cha).emit();
+				}
+			}
+		}));
+	}
+}
@@ -1,22 +1,68 @@
-use std::{borrow::Cow, ops::Not, time::Duration};
+use std::{borrow::Cow, num::NonZeroUsize, ops::Not, time::Duration};
 
+use lru::LruCache;
+use ratatui::{layout::Rect, style::Stylize, text::Line};
 use tokio::{pin, task::JoinHandle};
 use tokio_stream::{StreamExt, wrappers::UnboundedReceiverStream};
 use tokio_util::sync::CancellationToken;
-use yazi_adapter::ADAPTOR;
-use yazi_config::PLUGIN;
+use yazi_adapter::{ADAPTOR, ImageCompare, ImageFormat};
+use yazi_config::{LAYOUT, PLUGIN};
 use yazi_fs::{Cha, File, Files, FilesOp};
-use yazi_macro::render;
-use yazi_plugin::{external::Highlighter, isolate, utils::PreviewLock};
-use yazi_shared::{MIME_DIR, url::Url};
+use yazi_macro::{emit, render};
+use yazi_plugin::{elements::{Area, Renderable, Text, WRAP, WRAP_NO}, external::{Differ, Highlighter, ImageDiffer}, isolate, utils::PreviewLock};
+use yazi_shared::{Layer, MIME_DIR, errors::PeekError, event::Cmd, url::Url};
+
+use super::PreviewTargets;
 
-#[derive(Default)]
 pub struct Preview {
-	pub lock: Option<PreviewLock>,
-	pub skip: usize,
+	pub lock:    Option<PreviewLock>,
+	pub skip:    usize,
+	pub diff:    Option<PreviewDiff>,
+	pub targets: PreviewTargets,
+
+	/// Where the preview of each file was scrolled to, for the session.
+	positions: LruCache<Url, usize>,
 
 	previewer_ct:  Option<CancellationToken>,
 	folder_loader: Option<JoinHandle<()>>,
+	differ:        Option<JoinHandle<()>>,
+}
+
+impl Default for Preview {
+	fn default() -> Self {
+		Self {
+			lock:      None,
+			skip:      0,
+			diff:      None,
+			targets:   Default::default(),
+			positions: LruCache::new(NonZeroUsize::new(1000).unwrap()),
+
+			previewer_ct:  None,
+			folder_loader: None,
+			differ:        None,
+		}
+	}
+}
+
+/// Two files compared in the preview, in place of the hovered one.
+pub struct PreviewDiff {
+	pub old:     Url,
+	pub new:     Url,
+	pub unified: bool,
+	/// Whether both are images, which are compared side by side, or with a wipe
+	/// at `wipe` percent of the width if `unified`.
+	pub images:  bool,
+	pub wipe:    u8,
+}
+
+impl PreviewDiff {
+	pub fn new(old: Url, new: Url, unified: bool) -> Self {
+		let images = ImageFormat::from_path(&old).is_ok() && ImageFormat::from_path(&new).is_ok();
+		Self { old, new, unified, images, wipe: 50 }
+	}
+
+	#[inline]
+	pub fn mime(&self) -> &'static str { if self.unified { "diff/unified" } else { "diff/split" } }
 }
 
 impl Preview {
@@ -32,10 +78,12 @@ impl Preview {
 		};
 
 		self.abort();
-		if previewer.sync {
-			isolate::peek_sync(&previewer.run, file, mime, self.skip);
+		if previewer.exec.is_some() {
+			self.previewer_ct = Some(isolate::peek_exec(previewer, file, mime, self.skip));
+		} else if previewer.sync {
+			isolate::peek_sync(previewer, file, mime, self.skip);
 		} else {
-			self.previewer_ct = Some(isolate::peek(&previewer.run, file, mime, self.skip));
+			self.previewer_ct = Some(isolate::peek(previewer, file, mime, self.skip));
 		}
 	}
 
@@ -61,9 +109,76 @@ impl Preview {
 		}));
 	}
 
+	pub fn go_diff(&mut self, file: File, force: bool) {
+		let Some(diff) = &self.diff else { return };
+		let mime = diff.mime();
+		if !force && self.same_lock(&file, mime) {
+			return;
+		}
+
+		if diff.images {
+			return self.go_compare(file);
+		}
+
+		let (differ, skip) = (Differ::new(&diff.old, &diff.new).unified(diff.unified), self.skip);
+		self.abort();
+
+		self.differ = Some(tokio::spawn(async move {
+			let area = LAYOUT.get().preview;
+			let (inner, wrap) = match differ.render(skip, area.as_size()).await {
+				Ok(text) => (text, WRAP_NO),
+				Err(PeekError::Exceed(max)) => {
+					let cmd = Cmd::args("peek", &[max]).with("only-if", file.url_owned());
+					return emit!(Call(cmd.with_bool("upper-bound", true), Layer::Manager));
+				}
+				Err(e) => (Line::from(e.to_string()).reversed().into(), WRAP),
+			};
+
+			let lock = PreviewLock {
+				url: file.url_owned(),
+				cha: file.cha,
+				mime: mime.to_owned(),
+				skip,
+				area: area.into(),
+				data: vec![Renderable::Text(Text { area: Area::Rect(area.into()), inner, wrap })],
+			};
+			emit!(Call(Cmd::new("update_peeked").with_any("lock", lock), Layer::Manager));
+		}));
+	}
+
+	fn go_compare(&mut self, file: File) {
+		let Some(diff) = &self.diff else { return };
+		let mode = if diff.unified { ImageCompare::Wipe(diff.wipe) } else { ImageCompare::SideBySide };
+
+		let (differ, mime) = (ImageDiffer::new(&diff.old, &diff.new, mode), diff.mime());
+		self.abort();
+
+		self.differ = Some(tokio::spawn(async move {
+			let area = LAYOUT.get().preview;
+			let (inner, wrap) = match differ.render(area).await {
+				Ok(text) => (text, WRAP_NO),
+				Err(e) => (Line::from(e.to_string()).reversed().into(), WRAP),
+			};
+
+			// The differences go below the images
+			let height = (inner.lines.len() as u16).min(area.height);
+			let rect = Rect { y: area.bottom() - height, height, ..area };
+			let lock = PreviewLock {
+				url:  file.url_owned(),
+				cha:  file.cha,
+				mime: mime.to_owned(),
+				skip: 0,
+				area: area.into(),
+				data: vec![Renderable::Text(Text { area: Area::Rect(rect.into()), inner, wrap })],
+			};
+			emit!(Call(Cmd::new("update_peeked").with_any("lock", lock), Layer::Manager));
+		}));
+	}
+
 	#[inline]
 	pub fn abort(&mut self) {
 		self.previewer_ct.take().map(|ct| ct.cancel());
+		self.differ.take().map(|h| h.abort());
 		Highlighter::abort();
 	}
 
@@ -80,6 +195,26 @@ impl Preview {
 		ADAPTOR.get().image_hide().ok();
 	}
 
+	/// Where the preview of `url` was last scrolled to.
+	#[inline]
+	pub fn position(&mut self, url: &Url) -> usize { self.positions.get(url).copied().unwrap_or(0) }
+
+	/// How many files have their scroll position remembered, and at most.
+	#[inline]
+	pub fn positions(&self) -> (usize, usize) { (self.positions.len(), self.positions.cap().get()) }
+
+	/// Scrolls the preview to `skip`, remembering it for `url`.
+	pub fn scroll(&mut self, url: &Url, skip: usize) {
+		self.skip = skip;
+		if skip == 0 {
+			self.positions.pop(url);
+		} else if let Some(s) = self.positions.get_mut(url) {
+			*s = skip;
+		} else {
+			self.positions.put(url.clone(), skip);
+		}
+	}
+
 	#[inline]
 	pub fn same_url(&self, url: &Url) -> bool { self.lock.as_ref().is_some_and(|l| *url == l.url) }
 
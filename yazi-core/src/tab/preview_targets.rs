@@ -0,0 +1,167 @@
+use std::{ops::Range, path::Path, sync::OnceLock};
+
+use ratatui::{style::Modifier, text::{Line, Span}};
+use regex::Regex;
+use yazi_fs::{clean_path, expand_home};
+use yazi_plugin::{elements::Renderable, utils::PreviewLock};
+use yazi_shared::url::Url;
+
+static RE: OnceLock<Regex> = OnceLock::new();
+
+/// A file path or URL in the text of the preview, which can be opened.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PreviewTarget {
+	Path(Url),
+	Link(String),
+}
+
+struct Item {
+	data:   usize,
+	line:   usize,
+	range:  Range<usize>,
+	target: PreviewTarget,
+}
+
+/// The targets found in the text of the current preview, and the highlighted
+/// one of them.
+#[derive(Default)]
+pub struct PreviewTargets {
+	scanned: bool,
+	items:   Vec<Item>,
+	cursor:  Option<usize>,
+	/// The highlighted line before it was highlighted, to put it back.
+	origin:  Option<Line<'static>>,
+}
+
+impl PreviewTargets {
+	#[inline]
+	pub fn current(&self) -> Option<&PreviewTarget> { self.cursor.map(|i| &self.items[i].target) }
+
+	/// Highlights the target `step` away from the current one, wrapping around,
+	/// or returns `false` if there's none in the preview.
+	pub fn cycle(&mut self, lock: &mut PreviewLock, step: isize) -> bool {
+		if !self.scanned {
+			self.scan(lock);
+		}
+		if self.items.is_empty() {
+			return false;
+		}
+
+		let len = self.items.len() as isize;
+		let next = match self.cursor {
+			Some(i) => (i as isize + step).rem_euclid(len) as usize,
+			None if step < 0 => self.items.len() - 1,
+			None => 0,
+		};
+
+		if let Some(origin) = self.origin.take() {
+			let old = &self.items[self.cursor.unwrap()];
+			if let Some(line) = Self::line_mut(lock, old.data, old.line) {
+				*line = origin;
+			}
+		}
+
+		let new = &self.items[next];
+		if let Some(line) = Self::line_mut(lock, new.data, new.line) {
+			self.origin = Some(std::mem::replace(line, highlight(line, &new.range)));
+		}
+		self.cursor = Some(next);
+		true
+	}
+
+	fn scan(&mut self, lock: &PreviewLock) {
+		self.scanned = true;
+		let Some(base) = lock.url.parent_url() else { return };
+
+		for (data, r) in lock.data.iter().enumerate() {
+			let Renderable::Text(text) = r else { continue };
+			for (line, l) in text.inner.lines.iter().enumerate() {
+				let s: String = l.spans.iter().map(|s| &*s.content).collect();
+				self.items.extend(
+					candidates(&s)
+						.into_iter()
+						.filter_map(|range| Some((resolve(&base, &s[range.clone()])?, range)))
+						.map(|(target, range)| Item { data, line, range, target }),
+				);
+			}
+		}
+	}
+
+	fn line_mut(lock: &mut PreviewLock, data: usize, line: usize) -> Option<&mut Line<'static>> {
+		match lock.data.get_mut(data)? {
+			Renderable::Text(text) => text.inner.lines.get_mut(line),
+			_ => None,
+		}
+	}
+}
+
+/// The byte ranges of what look like URLs and paths in `s`.
+fn candidates(s: &str) -> Vec<Range<usize>> {
+	let re = RE.get_or_init(|| {
+		Regex::new(concat!(
+			r#"\b(?:https?|ftp|file)://[^\s<>"'`]+"#,
+			r"|(?:~|\.{1,2})?/[\w.~+@%-]+(?:/[\w.~+@%-]*)*",
+			r"|[\w.+@-]+(?:/[\w.+@-]+)+/?",
+		))
+		.unwrap()
+	});
+
+	re.find_iter(s)
+		.map(|m| {
+			let trimmed = m.as_str().trim_end_matches(|c| ".,;:!?)]}'\"".contains(c));
+			m.start()..m.start() + trimmed.len()
+		})
+		.filter(|r| r.len() > 1)
+		.collect()
+}
+
+/// The URL as it is, or the path relative to `base` if it exists.
+fn resolve(base: &Url, s: &str) -> Option<PreviewTarget> {
+	if s.contains("://") {
+		return Some(PreviewTarget::Link(s.to_owned()));
+	}
+
+	let p = Path::new(s);
+	let p = clean_path(expand_home(p).unwrap_or_else(|| base.join(p).into_path()));
+	p.exists().then(|| PreviewTarget::Path(Url::from(p)))
+}
+
+fn highlight(line: &Line<'static>, range: &Range<usize>) -> Line<'static> {
+	let mut spans = Vec::with_capacity(line.spans.len() + 2);
+	let mut start = 0;
+	for span in &line.spans {
+		let (s, end) = (&*span.content, start + span.content.len());
+		let (a, b) = (range.start.clamp(start, end) - start, range.end.clamp(start, end) - start);
+
+		for (r, on) in [(0..a, false), (a..b, true), (b..s.len(), false)] {
+			if !r.is_empty() {
+				let style = if on { span.style.add_modifier(Modifier::REVERSED) } else { span.style };
+				spans.push(Span::styled(s[r].to_owned(), style));
+			}
+		}
+		start = end;
+	}
+	Line { spans, ..line.clone() }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_candidates() {
+		let cases = [
+			("see https://example.com/a?b=1.", vec!["https://example.com/a?b=1"]),
+			("[docs](https://yazi-rs.github.io/docs)", vec!["https://yazi-rs.github.io/docs"]),
+			("error at /var/log/syslog:42:7", vec!["/var/log/syslog"]),
+			("cd ~/Downloads, then ./run.sh", vec!["~/Downloads", "./run.sh"]),
+			("edit src/main.rs and ../README.md", vec!["src/main.rs", "../README.md"]),
+			("a / b and nothing else", vec![]),
+		];
+
+		for (s, expected) in cases {
+			let found: Vec<_> = candidates(s).into_iter().map(|r| &s[r]).collect();
+			assert_eq!(found, expected, "{s}");
+		}
+	}
+}
@@ -1,10 +1,10 @@
-use std::iter;
+use std::{iter, sync::Arc};
 
 use anyhow::Result;
 use ratatui::layout::Rect;
 use tokio::task::JoinHandle;
 use yazi_adapter::Dimension;
-use yazi_config::{LAYOUT, popup::{Origin, Position}};
+use yazi_config::{LAYOUT, MANAGER, MEMORY, popup::{Origin, Position}, project::Project};
 use yazi_fs::{File, FolderStage};
 use yazi_macro::render;
 use yazi_shared::{Id, Ids, url::Url};
@@ -27,6 +27,11 @@ pub struct Tab {
 	pub preview: Preview,
 	pub finder:  Option<Finder>,
 	pub search:  Option<JoinHandle<Result<()>>>,
+
+	pub(super) prelister: Option<JoinHandle<()>>,
+
+	pub project:      Option<Arc<Project>>,
+	pub(super) outer: Option<Preference>,
 }
 
 impl Default for Tab {
@@ -48,6 +53,11 @@ impl Default for Tab {
 			preview: Default::default(),
 			finder:  Default::default(),
 			search:  Default::default(),
+
+			prelister: Default::default(),
+
+			project: Default::default(),
+			outer:   Default::default(),
 		}
 	}
 }
@@ -55,6 +65,7 @@ impl Default for Tab {
 impl Tab {
 	pub fn shutdown(&mut self) {
 		self.search.take().map(|h| h.abort());
+		self.prelister.take().map(|h| h.abort());
 		self.preview.reset();
 	}
 }
@@ -108,9 +119,24 @@ impl Tab {
 		self.hovered().filter(|&h| h.is_dir()).and_then(|h| self.history.get(&h.url))
 	}
 
+	pub fn shrink_history(&mut self) {
+		let bytes = MEMORY.load().folders_bytes();
+		let reserved = [Some(&self.current), self.parent.as_ref()]
+			.into_iter()
+			.flatten()
+			.fold((0, 0), |(n, b), f| {
+				(n + f.files.len(), b + if bytes.is_some() { f.files.bytes() } else { 0 })
+			});
+
+		let keep = self.current.hovered().map(|h| &h.url);
+		self.history.shrink(keep, reserved, MANAGER.load().history_limit, bytes);
+	}
+
 	pub fn apply_files_attrs(&mut self) {
 		let apply = |f: &mut Folder| {
 			if f.stage == FolderStage::Loading {
+				f.files.set_sorter(<_>::from(&self.pref));
+				f.files.catchup_prefix(f.offset + LAYOUT.get().current.height as usize);
 				return render!();
 			}
 
@@ -135,4 +161,40 @@ impl Tab {
 			.and_then(|h| self.history.get_mut(&h.url))
 			.map(apply);
 	}
+
+	/// Switch to the overrides of `project`, restoring the preference from before
+	/// entering the previous one, returns whether it's changed.
+	pub fn enter_project(&mut self, project: Option<Arc<Project>>) -> bool {
+		if self.project.as_ref().map(Arc::as_ptr) == project.as_ref().map(Arc::as_ptr) {
+			return false;
+		}
+
+		if let Some(pref) = self.outer.take() {
+			self.pref = pref;
+		}
+		if let Some(p) = project.as_ref().map(|p| &p.manager) {
+			self.outer = Some(self.pref.clone());
+			let pref = &mut self.pref;
+			pref.sort_by = p.sort_by.unwrap_or(pref.sort_by);
+			pref.sort_sensitive = p.sort_sensitive.unwrap_or(pref.sort_sensitive);
+			pref.sort_reverse = p.sort_reverse.unwrap_or(pref.sort_reverse);
+			pref.sort_dir_first = p.sort_dir_first.unwrap_or(pref.sort_dir_first);
+			pref.sort_translit = p.sort_translit.unwrap_or(pref.sort_translit);
+			pref.group_by = p.group_by.unwrap_or(pref.group_by);
+			if let Some(mode) = &p.linemode {
+				pref.linemode = mode.clone();
+			}
+		}
+
+		self.project = project;
+		true
+	}
+
+	/// Follow the new defaults of the reloaded config, see
+	/// [`Preference::rebase`].
+	pub fn rebase_pref(&mut self, old: &Preference) {
+		self.pref.rebase(old);
+		self.outer.as_mut().map(|p| p.rebase(old));
+		self.apply_files_attrs();
+	}
 }
@@ -1,11 +1,27 @@
+use yazi_config::popup::ConfirmCfg;
 use yazi_macro::render;
-use yazi_shared::event::CmdCow;
+use yazi_proxy::ConfirmProxy;
+use yazi_scheduler::TaskKind;
+use yazi_shared::{Id, event::{CmdCow, Data}};
 
 use crate::tasks::Tasks;
 
+struct Opt {
+	tab: Option<Id>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self { Self { tab: c.get("tab").and_then(Data::as_id) } }
+}
+
 impl Tasks {
-	pub fn cancel(&mut self, _: CmdCow) {
-		let id = self.ongoing().lock().get_id(self.cursor);
+	#[yazi_codegen::command]
+	pub fn cancel(&mut self, opt: Opt) {
+		if let Some(tab) = opt.tab {
+			return self.cancel_tab(tab);
+		}
+
+		let id = self.summaries.get(self.cursor).map(|s| s.id);
 		if id.map(|id| self.scheduler.cancel(id)) != Some(true) {
 			return;
 		}
@@ -14,4 +30,28 @@ impl Tasks {
 		self.arrow(0);
 		render!();
 	}
+
+	fn cancel_tab(&self, tab: Id) {
+		let (ids, names): (Vec<_>, Vec<_>) = self
+			.ongoing()
+			.lock()
+			.values()
+			.filter(|t| t.tab == Some(tab) && t.kind != TaskKind::Preload)
+			.map(|t| (t.id, t.name.clone()))
+			.unzip();
+
+		if ids.is_empty() {
+			return;
+		}
+
+		let scheduler = self.scheduler.clone();
+		tokio::spawn(async move {
+			if !ConfirmProxy::show(ConfirmCfg::cancel_tab(names)).await {
+				return;
+			}
+			for id in ids {
+				scheduler.cancel(id);
+			}
+		});
+	}
 }
@@ -0,0 +1,38 @@
+use yazi_macro::render;
+use yazi_scheduler::{TaskKind, TaskStatus};
+use yazi_shared::event::CmdCow;
+
+use crate::tasks::{Tasks, TasksFilter};
+
+struct Opt {
+	by: Option<String>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self { Self { by: c.first_str().map(ToOwned::to_owned) } }
+}
+
+impl Tasks {
+	#[yazi_codegen::command]
+	pub fn filter(&mut self, opt: Opt) {
+		let old = self.filter;
+		match opt.by.as_deref() {
+			Some("tab") => {
+				self.filter.tab =
+					if self.filter.tab.is_some() { None } else { self.ongoing().lock().origin };
+			}
+			Some("kind") => self.filter.kind = TasksFilter::cycle(self.filter.kind, &TaskKind::ALL),
+			Some("status") => {
+				self.filter.status = TasksFilter::cycle(self.filter.status, &TaskStatus::ALL)
+			}
+			None => self.filter = TasksFilter::default(),
+			Some(_) => return,
+		}
+
+		if self.filter != old {
+			self.summaries = self.paginate();
+			self.arrow(0);
+			render!();
+		}
+	}
+}
@@ -11,7 +11,7 @@ use crate::tasks::Tasks;
 impl Tasks {
 	pub fn inspect(&self, _: CmdCow) {
 		let ongoing = self.ongoing().clone();
-		let Some(id) = ongoing.lock().get_id(self.cursor) else {
+		let Some(id) = self.summaries.get(self.cursor).map(|s| s.id) else {
 			return;
 		};
 
@@ -1 +1 @@
-yazi_macro::mod_flat!(arrow cancel inspect open_with process_exec toggle);
+yazi_macro::mod_flat!(arrow cancel filter inspect open_with peek process_exec toggle update_trash);
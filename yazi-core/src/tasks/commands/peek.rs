@@ -0,0 +1,37 @@
+use yazi_macro::render;
+use yazi_shared::event::CmdCow;
+
+use crate::tasks::Tasks;
+
+struct Opt {
+	state: Option<bool>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self {
+		Self {
+			state: match c.first_str() {
+				Some("on") => Some(true),
+				Some("off") => Some(false),
+				_ => None,
+			},
+		}
+	}
+}
+
+impl Tasks {
+	#[yazi_codegen::command]
+	pub fn peek(&mut self, opt: Opt) {
+		let peeking = opt.state.unwrap_or(!self.peeking);
+		if peeking == self.peeking {
+			return;
+		}
+
+		self.peeking = peeking;
+		if peeking {
+			self.summaries = self.paginate();
+		}
+
+		render!();
+	}
+}
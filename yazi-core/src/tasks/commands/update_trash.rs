@@ -0,0 +1,30 @@
+use yazi_macro::render;
+use yazi_scheduler::file::TrashStat;
+use yazi_shared::event::CmdCow;
+
+use crate::tasks::Tasks;
+
+pub struct Opt {
+	stat: TrashStat,
+}
+
+impl TryFrom<CmdCow> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: CmdCow) -> Result<Self, Self::Error> {
+		Ok(Self { stat: c.take_any("stat").ok_or(())? })
+	}
+}
+
+impl Tasks {
+	pub fn update_trash(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+
+		if self.trash != Some(opt.stat) {
+			self.trash = Some(opt.stat);
+			render!();
+		}
+	}
+}
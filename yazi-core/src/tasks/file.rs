@@ -28,6 +28,20 @@ impl Tasks {
 		}
 	}
 
+	/// Paste each file to the very path it's paired with, rather than into a
+	/// directory under its own name.
+	pub fn file_paste_as(&self, targets: Vec<(Url, Url)>, cut: bool, force: bool, follow: bool) {
+		for (from, to) in targets {
+			if force && from == to {
+				debug!("file_paste_as: same file, skipping {:?}", to);
+			} else if cut {
+				self.scheduler.file_cut(from, to, force);
+			} else {
+				self.scheduler.file_copy(from, to, force, follow);
+			}
+		}
+	}
+
 	pub fn file_link(&self, src: &HashSet<Url>, dest: &Url, relative: bool, force: bool) {
 		for u in src {
 			let to = dest.join(u.file_name().unwrap());
@@ -50,13 +64,16 @@ impl Tasks {
 		}
 	}
 
-	pub fn file_remove(&self, targets: Vec<Url>, permanently: bool) {
+	pub fn file_remove(&self, targets: Vec<Url>, permanently: bool, secure: bool) {
 		for u in targets {
 			if permanently {
-				self.scheduler.file_delete(u);
+				self.scheduler.file_delete(u, secure);
 			} else {
 				self.scheduler.file_trash(u);
 			}
 		}
 	}
+
+	#[inline]
+	pub fn trash_empty(&self) { self.scheduler.trash_empty(); }
 }
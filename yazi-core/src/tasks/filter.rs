@@ -0,0 +1,28 @@
+use yazi_scheduler::{Task, TaskKind, TaskStatus};
+use yazi_shared::Id;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TasksFilter {
+	pub tab:    Option<Id>,
+	pub kind:   Option<TaskKind>,
+	pub status: Option<TaskStatus>,
+}
+
+impl TasksFilter {
+	pub fn matches(&self, task: &Task) -> bool {
+		self.tab.is_none_or(|t| task.tab == Some(t))
+			&& self.kind.is_none_or(|k| task.kind == k)
+			&& self.status.is_none_or(|s| task.status() == s)
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool { *self == Self::default() }
+
+	/// Moves to the next of `all` after `cur`, or to none after the last one.
+	pub(super) fn cycle<T: Copy + PartialEq>(cur: Option<T>, all: &[T]) -> Option<T> {
+		match cur {
+			None => all.first().copied(),
+			Some(c) => all.iter().position(|&a| a == c).and_then(|i| all.get(i + 1)).copied(),
+		}
+	}
+}
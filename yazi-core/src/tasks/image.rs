@@ -0,0 +1,11 @@
+use yazi_adapter::Transform;
+use yazi_shared::url::Url;
+
+use super::Tasks;
+
+impl Tasks {
+	#[inline]
+	pub fn image_transform(&self, targets: Vec<Url>, transform: Transform) {
+		self.scheduler.image_transform(targets, transform);
+	}
+}
@@ -1,11 +1,26 @@
-use yazi_config::{PLUGIN, plugin::MAX_PREWORKERS};
+use yazi_config::{PLUGIN, Priority, TASKS, plugin::MAX_PREWORKERS};
 use yazi_fs::{File, Files, SortBy};
+use yazi_shared::url::Url;
 
 use super::Tasks;
 use crate::manager::Mimetype;
 
 impl Tasks {
-	pub fn fetch_paged(&self, paged: &[File], mimetype: &Mimetype) {
+	#[inline]
+	pub fn fetch_paged(&self, cwd: &Url, paged: &[File], mimetype: &Mimetype) {
+		self.fetch(cwd, paged, mimetype, Priority::Normal);
+	}
+
+	/// Fetch the files on the screen ahead of the rest of the page, which would
+	/// otherwise be taken in no particular order.
+	#[inline]
+	pub fn fetch_visible(&self, cwd: &Url, visible: &[File], mimetype: &Mimetype) {
+		self.fetch(cwd, visible, mimetype, Priority::High);
+	}
+
+	fn fetch(&self, cwd: &Url, paged: &[File], mimetype: &Mimetype, prio: Priority) {
+		self.scheduler.fetch_cancel(cwd);
+
 		let mut loaded = self.scheduler.prework.loaded.lock();
 		let mut tasks: [Vec<_>; MAX_PREWORKERS as usize] = Default::default();
 		for f in paged {
@@ -22,8 +37,8 @@ impl Tasks {
 
 		drop(loaded);
 		for (i, tasks) in tasks.into_iter().enumerate() {
-			if !tasks.is_empty() {
-				self.scheduler.fetch_paged(&PLUGIN.fetchers[i], tasks);
+			for chunk in tasks.chunks(TASKS.fetch_batch) {
+				self.scheduler.fetch_paged(&PLUGIN.fetchers[i], cwd, chunk.to_vec(), prio);
 			}
 		}
 	}
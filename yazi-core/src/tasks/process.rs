@@ -1,23 +1,26 @@
 use std::{borrow::Cow, collections::HashMap, ffi::OsString, mem};
 
-use yazi_config::{OPEN, open::Opener};
+use yazi_config::open::Opener;
 use yazi_proxy::options::ProcessExecOpt;
 use yazi_shared::url::Url;
 
 use super::Tasks;
+use crate::manager::{CHOICES, PROJECTS};
 
 impl Tasks {
 	pub fn process_from_files(&self, cwd: Url, hovered: Url, targets: Vec<(Url, Cow<str>)>) {
 		let mut openers = HashMap::new();
 		for (url, mime) in targets {
-			if let Some(opener) = OPEN.openers(&url, mime).and_then(|o| o.first().copied()) {
-				openers.entry(opener).or_insert_with(|| vec![hovered.clone()]).push(url);
+			let Some(candidates) = PROJECTS.read().openers(&url, mime) else { continue };
+			let candidates: Vec<_> = candidates.into_iter().collect();
+			if let Some(opener) = CHOICES.read().find(&url, &candidates).or(candidates.first()) {
+				openers.entry(opener.clone()).or_insert_with(|| vec![hovered.clone()]).push(url);
 			}
 		}
 		for (opener, args) in openers {
 			self.process_from_opener(
 				cwd.clone(),
-				Cow::Borrowed(opener),
+				Cow::Owned(opener),
 				args.into_iter().map(|u| u.into_path().into_os_string()).collect(),
 			);
 		}
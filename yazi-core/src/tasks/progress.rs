@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use serde::Serialize;
 use yazi_scheduler::Ongoing;
 
@@ -9,6 +11,10 @@ pub struct TasksProgress {
 
 	pub found:     u64,
 	pub processed: u64,
+
+	pub active: u32,
+	pub speed:  u64,
+	pub eta:    Option<u64>,
 }
 
 impl From<&Ongoing> for TasksProgress {
@@ -25,7 +31,48 @@ impl From<&Ongoing> for TasksProgress {
 
 			progress.found += task.found;
 			progress.processed += task.processed;
+
+			progress.active += 1;
 		}
 		progress
 	}
 }
+
+impl TasksProgress {
+	/// Estimates the speed and ETA from the progress made since `last`,
+	/// which was sampled `elapsed` ago.
+	pub fn estimate(&mut self, last: &Self, elapsed: Duration) {
+		if self.active == 0 {
+			return;
+		}
+
+		let ms = elapsed.as_millis().max(1) as u64;
+		let speed = self.processed.saturating_sub(last.processed) * 1000 / ms;
+
+		// Smooth the speed out, so that the ETA doesn't jump around on every tick
+		self.speed = if last.speed == 0 { speed } else { (last.speed + speed) / 2 };
+		self.eta = (self.speed > 0).then(|| self.found.saturating_sub(self.processed) / self.speed);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_estimate() {
+		let mut last = TasksProgress { active: 1, found: 1000, ..Default::default() };
+		let mut new = TasksProgress { processed: 100, ..last };
+		new.estimate(&last, Duration::from_millis(500));
+		assert_eq!((new.speed, new.eta), (200, Some(4)));
+
+		last = new;
+		new = TasksProgress { processed: 400, ..last };
+		new.estimate(&last, Duration::from_millis(500));
+		assert_eq!((new.speed, new.eta), (400, Some(1)));
+
+		let mut idle = TasksProgress::default();
+		idle.estimate(&new, Duration::from_millis(500));
+		assert_eq!((idle.speed, idle.eta), (0, None));
+	}
+}
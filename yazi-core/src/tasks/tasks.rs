@@ -1,22 +1,25 @@
-use std::{sync::Arc, time::Duration};
+use std::{sync::Arc, time::{Duration, Instant}};
 
 use parking_lot::Mutex;
 use tokio::{task::JoinHandle, time::sleep};
 use yazi_adapter::Dimension;
 use yazi_macro::emit;
-use yazi_scheduler::{Ongoing, Scheduler, TaskSummary};
+use yazi_scheduler::{Ongoing, Scheduler, TaskSummary, file::TrashStat};
 use yazi_shared::{Layer, event::Cmd};
 
-use super::{TASKS_BORDER, TASKS_PADDING, TASKS_PERCENT, TasksProgress};
+use super::{TASKS_BORDER, TASKS_PADDING, TASKS_PERCENT, TasksFilter, TasksProgress};
 
 pub struct Tasks {
 	pub(super) scheduler: Arc<Scheduler>,
 	handle:               JoinHandle<()>,
 
 	pub visible:   bool,
+	pub peeking:   bool,
 	pub cursor:    usize,
+	pub filter:    TasksFilter,
 	pub progress:  TasksProgress,
 	pub summaries: Vec<TaskSummary>,
+	pub trash:     Option<TrashStat>,
 }
 
 impl Tasks {
@@ -25,11 +28,14 @@ impl Tasks {
 		let ongoing = scheduler.ongoing.clone();
 
 		let handle = tokio::spawn(async move {
-			let mut last = TasksProgress::default();
+			let (mut last, mut at) = (TasksProgress::default(), Instant::now());
 			loop {
 				sleep(Duration::from_millis(500)).await;
 
-				let new = TasksProgress::from(&*ongoing.lock());
+				let mut new = TasksProgress::from(&*ongoing.lock());
+				new.estimate(&last, at.elapsed());
+				at = Instant::now();
+
 				if last != new {
 					last = new;
 					emit!(Call(Cmd::new("update_progress").with_any("progress", new), Layer::App));
@@ -42,9 +48,12 @@ impl Tasks {
 			handle,
 
 			visible: false,
+			peeking: false,
 			cursor: 0,
+			filter: Default::default(),
 			progress: Default::default(),
 			summaries: Default::default(),
+			trash: None,
 		}
 	}
 
@@ -60,7 +69,12 @@ impl Tasks {
 	}
 
 	pub fn paginate(&self) -> Vec<TaskSummary> {
-		self.ongoing().lock().values().take(Self::limit()).map(Into::into).collect()
+		let ongoing = self.ongoing().lock();
+		let mut tasks: Vec<_> = ongoing.values().filter(|&t| self.filter.matches(t)).collect();
+
+		// Group them by the tab they were spawned from, in the order of creation
+		tasks.sort_unstable_by_key(|t| (t.tab.map(|t| t.get()), t.id));
+		tasks.into_iter().take(Self::limit()).map(Into::into).collect()
 	}
 
 	#[inline]
@@ -0,0 +1,28 @@
+use yazi_macro::render;
+use yazi_shared::event::{CmdCow, Data};
+
+use crate::terminal::Terminal;
+
+struct Opt {
+	ticket: Option<usize>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(c: CmdCow) -> Self { Self { ticket: c.get("ticket").and_then(Data::as_usize) } }
+}
+
+impl Terminal {
+	/// Once the shell has exited, it's gone along with its output, and a new one
+	/// is started the next time.
+	#[yazi_codegen::command]
+	pub fn exit(&mut self, opt: Opt) {
+		if opt.ticket.is_some_and(|t| t != self.ticket) {
+			return;
+		}
+
+		self.pty = None;
+		self.cwd = None;
+		self.visible = false;
+		render!();
+	}
+}
@@ -0,0 +1,32 @@
+use yazi_macro::render;
+use yazi_shared::event::{CmdCow, Data};
+
+use crate::terminal::Terminal;
+
+pub struct Opt {
+	ticket: usize,
+	data:   Vec<u8>,
+}
+
+impl TryFrom<CmdCow> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: CmdCow) -> Result<Self, Self::Error> {
+		Ok(Self {
+			ticket: c.get("ticket").and_then(Data::as_usize).ok_or(())?,
+			data:   c.take_any("data").ok_or(())?,
+		})
+	}
+}
+
+impl Terminal {
+	pub fn feed(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else { return };
+		if opt.ticket != self.ticket {
+			return;
+		}
+
+		self.screen.feed(&opt.data);
+		render!(self.visible);
+	}
+}
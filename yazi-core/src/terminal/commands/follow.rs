@@ -0,0 +1,21 @@
+use yazi_shared::{event::CmdCow, url::Url};
+
+use crate::terminal::Terminal;
+
+struct Opt {
+	cwd: Option<Url>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(mut c: CmdCow) -> Self { Self { cwd: c.take_first_url() } }
+}
+
+impl Terminal {
+	/// Keep the shell in the directory of the active tab while it's shown.
+	#[yazi_codegen::command]
+	pub fn follow(&mut self, opt: Opt) {
+		if let Some(cwd) = opt.cwd.filter(|_| self.visible) {
+			self.sync(&cwd);
+		}
+	}
+}
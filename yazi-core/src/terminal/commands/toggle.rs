@@ -0,0 +1,42 @@
+use yazi_macro::render;
+use yazi_proxy::AppProxy;
+use yazi_shared::{event::CmdCow, url::Url};
+
+use crate::terminal::Terminal;
+
+struct Opt {
+	cwd: Option<Url>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(_: CmdCow) -> Self { Self { cwd: None } }
+}
+impl From<Option<Url>> for Opt {
+	fn from(cwd: Option<Url>) -> Self { Self { cwd } }
+}
+
+impl Terminal {
+	/// Show the shell, starting one in `cwd` if there's none yet, or hide it
+	/// while keeping it running.
+	#[yazi_codegen::command]
+	pub fn toggle(&mut self, opt: Opt) {
+		if self.visible {
+			self.visible = false;
+			return render!();
+		}
+
+		match (&self.pty, opt.cwd) {
+			(Some(_), Some(cwd)) => self.sync(&cwd),
+			(None, Some(cwd)) => {
+				if let Err(e) = self.start(&cwd) {
+					return AppProxy::notify_error("Terminal", format!("Failed to start the shell: {e}"));
+				}
+			}
+			(_, None) => {}
+		}
+
+		self.resize();
+		self.visible = self.running();
+		render!();
+	}
+}
@@ -0,0 +1,44 @@
+use crossterm::event::KeyCode;
+use yazi_config::keymap::Key;
+
+use crate::terminal::Terminal;
+
+impl Terminal {
+	/// Pass a key that isn't bound to the shell, as the bytes a terminal sends
+	/// for it.
+	pub fn type_(&mut self, key: &Key) -> bool {
+		let Some(pty) = &mut self.pty else { return false };
+
+		let mut buf = vec![];
+		if key.alt {
+			buf.push(0x1b);
+		}
+		match key.code {
+			KeyCode::Char(c) if key.ctrl && c.is_ascii_alphabetic() => {
+				buf.push(c.to_ascii_lowercase() as u8 & 0x1f)
+			}
+			KeyCode::Char(c @ ('@' | '[' | '\\' | ']' | '^' | '_')) if key.ctrl => {
+				buf.push(c as u8 & 0x1f)
+			}
+			KeyCode::Char(' ') if key.ctrl => buf.push(0),
+			KeyCode::Char(c) => buf.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+			KeyCode::Enter => buf.push(b'\r'),
+			KeyCode::Backspace => buf.push(0x7f),
+			KeyCode::Tab => buf.push(b'\t'),
+			KeyCode::BackTab => buf.extend_from_slice(b"\x1b[Z"),
+			KeyCode::Esc => buf.push(0x1b),
+			KeyCode::Up => buf.extend_from_slice(b"\x1b[A"),
+			KeyCode::Down => buf.extend_from_slice(b"\x1b[B"),
+			KeyCode::Right => buf.extend_from_slice(b"\x1b[C"),
+			KeyCode::Left => buf.extend_from_slice(b"\x1b[D"),
+			KeyCode::Home => buf.extend_from_slice(b"\x1b[H"),
+			KeyCode::End => buf.extend_from_slice(b"\x1b[F"),
+			KeyCode::Delete => buf.extend_from_slice(b"\x1b[3~"),
+			KeyCode::PageUp => buf.extend_from_slice(b"\x1b[5~"),
+			KeyCode::PageDown => buf.extend_from_slice(b"\x1b[6~"),
+			_ => return false,
+		}
+
+		pty.write(&buf).is_ok()
+	}
+}
@@ -0,0 +1,6 @@
+yazi_macro::mod_pub!(commands);
+
+yazi_macro::mod_flat!(pty screen terminal);
+
+pub const TERMINAL_PERCENT: u16 = 40;
+pub const TERMINAL_SCROLLBACK: usize = 1000;
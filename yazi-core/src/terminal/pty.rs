@@ -0,0 +1,89 @@
+use std::{fs::File, io::{self, Read, Write}, path::Path, process::Child};
+
+/// A shell running in a pseudo terminal, killed once dropped.
+pub(super) struct Pty {
+	master: File,
+	child:  Child,
+}
+
+impl Pty {
+	#[cfg(unix)]
+	pub(super) fn spawn(cwd: &Path, rows: u16, cols: u16) -> io::Result<Self> {
+		use std::{os::{fd::{FromRawFd, OwnedFd}, unix::process::CommandExt}, process::{Command, Stdio}};
+
+		let (mut master, mut slave) = (0, 0);
+		let mut size = libc::winsize { ws_row: rows, ws_col: cols, ws_xpixel: 0, ws_ypixel: 0 };
+		if unsafe {
+			libc::openpty(
+				&mut master,
+				&mut slave,
+				std::ptr::null_mut(),
+				std::ptr::null_mut(),
+				&raw mut size,
+			)
+		} != 0
+		{
+			return Err(io::Error::last_os_error());
+		}
+
+		let (master, slave) = unsafe { (OwnedFd::from_raw_fd(master), OwnedFd::from_raw_fd(slave)) };
+		let shell = std::env::var_os("SHELL").filter(|s| !s.is_empty()).unwrap_or_else(|| "sh".into());
+
+		let mut cmd = Command::new(shell);
+		cmd
+			.current_dir(cwd)
+			.env("TERM", "dumb")
+			.stdin(Stdio::from(slave.try_clone()?))
+			.stdout(Stdio::from(slave.try_clone()?))
+			.stderr(Stdio::from(slave));
+		unsafe {
+			cmd.pre_exec(|| {
+				if libc::setsid() < 0 || libc::ioctl(0, libc::TIOCSCTTY as _, 0) < 0 {
+					return Err(io::Error::last_os_error());
+				}
+				Ok(())
+			});
+		}
+
+		Ok(Self { child: cmd.spawn()?, master: File::from(master) })
+	}
+
+	#[cfg(windows)]
+	pub(super) fn spawn(_: &Path, _: u16, _: u16) -> io::Result<Self> {
+		Err(io::Error::new(io::ErrorKind::Unsupported, "Pseudo terminals aren't supported on Windows"))
+	}
+
+	#[inline]
+	pub(super) fn reader(&self) -> io::Result<impl Read + Send + 'static> { self.master.try_clone() }
+
+	#[inline]
+	pub(super) fn write(&mut self, data: &[u8]) -> io::Result<()> { self.master.write_all(data) }
+
+	/// Whether the shell is waiting for a command, rather than running one in
+	/// the foreground.
+	#[cfg(unix)]
+	pub(super) fn idle(&self) -> bool {
+		use std::os::fd::AsRawFd;
+		unsafe { libc::tcgetpgrp(self.master.as_raw_fd()) == self.child.id() as libc::pid_t }
+	}
+
+	#[cfg(windows)]
+	pub(super) fn idle(&self) -> bool { false }
+
+	#[cfg(unix)]
+	pub(super) fn resize(&self, rows: u16, cols: u16) {
+		use std::os::fd::AsRawFd;
+		let size = libc::winsize { ws_row: rows, ws_col: cols, ws_xpixel: 0, ws_ypixel: 0 };
+		unsafe { libc::ioctl(self.master.as_raw_fd(), libc::TIOCSWINSZ, &size) };
+	}
+
+	#[cfg(windows)]
+	pub(super) fn resize(&self, _: u16, _: u16) {}
+}
+
+impl Drop for Pty {
+	fn drop(&mut self) {
+		self.child.kill().ok();
+		self.child.wait().ok();
+	}
+}
@@ -0,0 +1,211 @@
+use std::collections::VecDeque;
+
+use super::TERMINAL_SCROLLBACK;
+
+/// The lines a shell has printed, where only what's needed by a prompt and
+/// the output of one-off commands is understood: moving the cursor within the
+/// line, and erasing it or the screen. Styles are dropped.
+#[derive(Default)]
+pub struct Screen {
+	lines: VecDeque<Vec<char>>,
+	col:   usize,
+
+	pending: Vec<u8>,
+	state:   State,
+}
+
+#[derive(Default)]
+enum State {
+	#[default]
+	Ground,
+	Escape,
+	Csi(String),
+	Osc(bool),
+	Charset,
+}
+
+impl Screen {
+	pub fn feed(&mut self, data: &[u8]) {
+		self.pending.extend_from_slice(data);
+		let valid = match std::str::from_utf8(&self.pending) {
+			Ok(s) => s.len(),
+			Err(e) if e.error_len().is_none() => e.valid_up_to(),
+			Err(_) => {
+				let s = String::from_utf8_lossy(&self.pending).into_owned();
+				self.pending.clear();
+				return s.chars().for_each(|c| self.put(c));
+			}
+		};
+
+		let rest = self.pending.split_off(valid);
+		let s = String::from_utf8(std::mem::replace(&mut self.pending, rest)).unwrap_or_default();
+		s.chars().for_each(|c| self.put(c));
+	}
+
+	#[inline]
+	pub fn lines(&self) -> impl ExactSizeIterator<Item = String> + '_ {
+		self.lines.iter().map(|l| l.iter().collect())
+	}
+
+	/// The lines wrapped at `width`, so they're laid out again once it changes,
+	/// where the last one has room for the cursor.
+	pub fn rows(&self, width: usize) -> impl Iterator<Item = String> + '_ {
+		let width = width.max(1);
+		self.lines.iter().enumerate().flat_map(move |(i, l)| {
+			(0..self.height(i, width))
+				.map(move |j| l[(j * width).min(l.len())..((j + 1) * width).min(l.len())].iter().collect())
+		})
+	}
+
+	/// The number of [`Self::rows`].
+	pub fn len(&self, width: usize) -> usize {
+		(0..self.lines.len()).map(|i| self.height(i, width.max(1))).sum()
+	}
+
+	/// The row and column of the cursor in [`Self::rows`].
+	pub fn cursor(&self, width: usize) -> (usize, usize) {
+		let width = width.max(1);
+		let above: usize = (0..self.lines.len().saturating_sub(1)).map(|i| self.height(i, width)).sum();
+		(above + self.col / width, self.col % width)
+	}
+
+	fn height(&self, i: usize, width: usize) -> usize {
+		let mut len = self.lines[i].len();
+		if i + 1 == self.lines.len() {
+			len = len.max(self.col + 1);
+		}
+		len.div_ceil(width).max(1)
+	}
+
+	fn put(&mut self, c: char) {
+		match std::mem::take(&mut self.state) {
+			State::Ground => self.ground(c),
+			State::Escape => {
+				self.state = match c {
+					'[' => State::Csi(String::new()),
+					']' => State::Osc(false),
+					'(' | ')' => State::Charset,
+					_ => State::Ground,
+				}
+			}
+			State::Csi(mut params) => {
+				if ('@'..='~').contains(&c) {
+					self.csi(&params, c);
+				} else {
+					params.push(c);
+					self.state = State::Csi(params);
+				}
+			}
+			State::Osc(esc) => {
+				if !(c == '\x07' || (esc && c == '\\')) {
+					self.state = State::Osc(c == '\x1b');
+				}
+			}
+			State::Charset => {}
+		}
+	}
+
+	fn ground(&mut self, c: char) {
+		match c {
+			'\x1b' => self.state = State::Escape,
+			'\n' => self.newline(),
+			'\r' => self.col = 0,
+			'\x08' => self.col = self.col.saturating_sub(1),
+			'\t' => (0..8 - self.col % 8).for_each(|_| self.print(' ')),
+			c if c.is_control() => {}
+			c => self.print(c),
+		}
+	}
+
+	fn csi(&mut self, params: &str, action: char) {
+		let n = params.trim_start_matches('?').split(';').next().and_then(|s| s.parse().ok());
+		match action {
+			'C' => self.col += n.unwrap_or(1).max(1),
+			'D' => self.col = self.col.saturating_sub(n.unwrap_or(1).max(1)),
+			'G' => self.col = n.unwrap_or(1).saturating_sub(1),
+			'K' => {
+				let col = self.col;
+				let line = self.line();
+				match n.unwrap_or(0) {
+					0 => line.truncate(col),
+					1 => line.iter_mut().take(col + 1).for_each(|c| *c = ' '),
+					_ => line.clear(),
+				}
+			}
+			'J' if n == Some(2) || n == Some(3) => {
+				self.lines.clear();
+				self.col = 0;
+			}
+			_ => {}
+		}
+	}
+
+	fn print(&mut self, c: char) {
+		let col = self.col;
+		let line = self.line();
+		if line.len() <= col {
+			line.resize(col, ' ');
+			line.push(c);
+		} else {
+			line[col] = c;
+		}
+		self.col += 1;
+	}
+
+	fn newline(&mut self) {
+		self.line();
+		if self.lines.len() >= TERMINAL_SCROLLBACK {
+			self.lines.pop_front();
+		}
+		self.lines.push_back(vec![]);
+		self.col = 0;
+	}
+
+	fn line(&mut self) -> &mut Vec<char> {
+		if self.lines.is_empty() {
+			self.lines.push_back(vec![]);
+		}
+		self.lines.back_mut().unwrap()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn screen(data: &[u8]) -> Vec<String> {
+		let mut s = Screen::default();
+		s.feed(data);
+		s.lines().collect()
+	}
+
+	#[test]
+	fn test_rows() {
+		let mut s = Screen::default();
+		s.feed(b"abcde\r\nfg");
+		assert_eq!(s.rows(2).collect::<Vec<_>>(), ["ab", "cd", "e", "fg", ""]);
+		assert_eq!((s.len(2), s.cursor(2)), (5, (4, 0)));
+		assert_eq!(s.rows(3).collect::<Vec<_>>(), ["abc", "de", "fg"]);
+		assert_eq!((s.len(3), s.cursor(3)), (3, (2, 2)));
+	}
+
+	#[test]
+	fn test_feed() {
+		assert_eq!(screen(b"a\r\nb"), ["a", "b"]);
+		assert_eq!(screen(b"\x1b[1;32m$\x1b[0m ls\r\n"), ["$ ls", ""]);
+		assert_eq!(screen(b"abc\x08\x08x"), ["axc"]);
+		assert_eq!(screen(b"abc\rx\x1b[K"), ["x"]);
+		assert_eq!(screen(b"\x1b]0;title\x07$ "), ["$ "]);
+		assert_eq!(screen(b"a\nb\x1b[2Jc"), ["c"]);
+		assert_eq!(screen("\u{e4}".as_bytes()), ["\u{e4}"]);
+	}
+
+	#[test]
+	fn test_feed_split() {
+		let mut s = Screen::default();
+		let b = "\u{e4}".as_bytes();
+		s.feed(&b[..1]);
+		s.feed(&b[1..]);
+		assert_eq!(s.lines().collect::<Vec<_>>(), ["\u{e4}"]);
+	}
+}
@@ -0,0 +1,100 @@
+use std::io::Read;
+
+use ratatui::layout::Rect;
+use yazi_adapter::Dimension;
+use yazi_macro::emit;
+use yazi_shared::{Layer, event::Cmd, url::Url};
+
+use super::{Pty, Screen, TERMINAL_PERCENT};
+
+/// A shell at the bottom of the screen, for the one-off commands that don't
+/// need Yazi to be suspended.
+#[derive(Default)]
+pub struct Terminal {
+	pub visible: bool,
+	pub screen:  Screen,
+	/// Where the shell was last sent to.
+	pub cwd:     Option<Url>,
+
+	pub(super) pty:    Option<Pty>,
+	pub(super) ticket: usize,
+}
+
+impl Terminal {
+	pub fn area() -> Rect {
+		let ws = Dimension::available();
+		let height = (ws.rows * TERMINAL_PERCENT / 100).max(3);
+		Rect {
+			x:      0,
+			y:      ws.rows.saturating_sub(height),
+			width:  ws.columns,
+			height: height.min(ws.rows),
+		}
+	}
+
+	/// Where the cursor of the shell is on the screen.
+	pub fn cursor(&self) -> Option<(u16, u16)> {
+		let inner = Self::inner();
+		let (row, col) = self.screen.cursor(inner.width as usize);
+
+		let skip = self.screen.len(inner.width as usize).saturating_sub(inner.height as usize);
+		Some((inner.x + col as u16, inner.y + row.checked_sub(skip)? as u16))
+	}
+
+	#[inline]
+	pub fn running(&self) -> bool { self.pty.is_some() }
+
+	/// Inside the top border.
+	fn inner() -> Rect {
+		let area = Self::area();
+		Rect { y: area.y + 1, height: area.height.saturating_sub(1), ..area }
+	}
+
+	pub(super) fn start(&mut self, cwd: &Url) -> std::io::Result<()> {
+		let inner = Self::inner();
+		let pty = Pty::spawn(cwd, inner.height, inner.width)?;
+		let mut reader = pty.reader()?;
+
+		self.ticket += 1;
+		let ticket = self.ticket;
+		std::thread::spawn(move || {
+			let mut buf = [0; 4096];
+			while let Ok(n @ 1..) = reader.read(&mut buf) {
+				emit!(Call(
+					Cmd::new("feed").with("ticket", ticket).with_any("data", buf[..n].to_vec()),
+					Layer::Terminal
+				));
+			}
+			emit!(Call(Cmd::new("exit").with("ticket", ticket), Layer::Terminal));
+		});
+
+		self.screen = Screen::default();
+		self.cwd = Some(cwd.clone());
+		self.pty = Some(pty);
+		Ok(())
+	}
+
+	/// Send the shell to `cwd` if it's not running anything, clearing what's
+	/// been typed at the prompt so far.
+	pub(super) fn sync(&mut self, cwd: &Url) {
+		let Some(pty) = &mut self.pty else { return };
+		if self.cwd.as_ref() == Some(cwd) || !cwd.is_regular() || !pty.idle() {
+			return;
+		}
+
+		let mut line = b"\x15 cd -- ".to_vec();
+		line.extend_from_slice(yazi_shared::shell::escape_os_str(cwd.as_os_str()).as_encoded_bytes());
+		line.push(b'\r');
+		if pty.write(&line).is_ok() {
+			self.cwd = Some(cwd.clone());
+		}
+	}
+
+	/// Fit the shell to the area, which follows the size of the screen.
+	pub fn resize(&self) {
+		if let Some(pty) = &self.pty {
+			let inner = Self::inner();
+			pty.resize(inner.height, inner.width);
+		}
+	}
+}
@@ -0,0 +1,13 @@
+use yazi_macro::render;
+use yazi_shared::event::CmdCow;
+
+use crate::watch::Watch;
+
+impl Watch {
+	pub fn clear(&mut self, _: CmdCow) {
+		self.events.clear();
+		self.pending.clear();
+		self.cursor = 0;
+		render!();
+	}
+}
@@ -0,0 +1,46 @@
+use std::str::FromStr;
+
+use yazi_config::{Pattern, popup::InputCfg};
+use yazi_macro::{emit, render};
+use yazi_proxy::{AppProxy, InputProxy};
+use yazi_shared::{Layer, event::{Cmd, CmdCow}};
+
+use crate::watch::Watch;
+
+struct Opt {
+	glob: Option<String>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(mut c: CmdCow) -> Self { Self { glob: c.take_first_str().map(|s| s.into_owned()) } }
+}
+
+impl Watch {
+	/// Show only the events of the file names matching a glob, or all of them
+	/// if it's empty, where the glob is asked for if not given.
+	#[yazi_codegen::command]
+	pub fn filter(&mut self, opt: Opt) {
+		let Some(glob) = opt.glob else {
+			let value = self.filter.as_ref().map(|(s, _)| s.clone()).unwrap_or_default();
+			tokio::spawn(async move {
+				let mut rx = InputProxy::show(InputCfg::filter().with_value(value));
+				if let Some(Ok(s)) = rx.recv().await {
+					emit!(Call(Cmd::args("filter", &[s]), Layer::Watch));
+				}
+			});
+			return;
+		};
+
+		if glob.is_empty() {
+			self.filter = None;
+		} else {
+			match Pattern::from_str(&glob) {
+				Ok(p) => self.filter = Some((glob, p)),
+				Err(e) => return AppProxy::notify_warn("Watch", format!("Invalid glob `{glob}`: {e}")),
+			}
+		}
+
+		self.cursor = self.cursor.min(self.len().saturating_sub(1));
+		render!();
+	}
+}
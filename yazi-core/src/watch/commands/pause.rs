@@ -0,0 +1,15 @@
+use yazi_macro::render;
+use yazi_shared::event::CmdCow;
+
+use crate::watch::Watch;
+
+impl Watch {
+	pub fn pause(&mut self, _: CmdCow) {
+		self.paused = !self.paused;
+		if !self.paused {
+			let pending = std::mem::take(&mut self.pending);
+			self.append(pending);
+		}
+		render!();
+	}
+}
@@ -0,0 +1,40 @@
+use yazi_macro::render;
+use yazi_shared::event::{CmdCow, Data};
+
+use crate::watch::{WATCH_LIMIT, Watch, WatchEvent};
+
+pub struct Opt {
+	ticket: usize,
+	events: Vec<WatchEvent>,
+}
+
+impl TryFrom<CmdCow> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: CmdCow) -> Result<Self, Self::Error> {
+		Ok(Self {
+			ticket: c.get("ticket").and_then(Data::as_usize).ok_or(())?,
+			events: c.take_any("events").ok_or(())?,
+		})
+	}
+}
+
+impl Watch {
+	pub fn push(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else { return };
+
+		// Left over from a directory that's no longer watched
+		if opt.ticket != self.ticket || self.root.is_none() {
+			return;
+		}
+
+		if self.paused {
+			self.pending.extend(opt.events);
+			let n = self.pending.len().saturating_sub(WATCH_LIMIT);
+			self.pending.drain(..n);
+		} else {
+			self.append(opt.events);
+		}
+		render!();
+	}
+}
@@ -0,0 +1,38 @@
+use yazi_macro::render;
+use yazi_proxy::AppProxy;
+use yazi_shared::{event::CmdCow, url::Url};
+
+use crate::watch::Watch;
+
+struct Opt {
+	root: Option<Url>,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(_: CmdCow) -> Self { Self { root: None } }
+}
+impl From<Option<Url>> for Opt {
+	fn from(root: Option<Url>) -> Self { Self { root } }
+}
+
+impl Watch {
+	#[yazi_codegen::command]
+	pub fn toggle(&mut self, opt: Opt) {
+		if self.visible {
+			self.visible = false;
+			self.stop();
+			return render!();
+		}
+
+		let Some(root) = opt.root.filter(|u| u.is_regular()) else {
+			return AppProxy::notify_warn("Watch", "Only regular directories can be watched");
+		};
+
+		if let Err(e) = self.start(root) {
+			return AppProxy::notify_error("Watch", e.to_string());
+		}
+
+		self.visible = true;
+		render!();
+	}
+}
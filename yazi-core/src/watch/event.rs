@@ -0,0 +1,80 @@
+use std::{collections::HashSet, path::Path};
+
+use chrono::{DateTime, Local};
+use notify::{EventKind, event::{ModifyKind, RenameMode}};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WatchKind {
+	Created,
+	Modified,
+	Deleted,
+	Renamed,
+}
+
+impl WatchKind {
+	fn from_kind(kind: EventKind) -> Option<Self> {
+		Some(match kind {
+			EventKind::Create(_) => Self::Created,
+			// Moved in or out, when the other side isn't in the directory
+			EventKind::Modify(ModifyKind::Name(RenameMode::To)) => Self::Created,
+			EventKind::Modify(ModifyKind::Name(RenameMode::From)) => Self::Deleted,
+			EventKind::Modify(ModifyKind::Name(_)) => Self::Renamed,
+			EventKind::Modify(_) => Self::Modified,
+			EventKind::Remove(_) => Self::Deleted,
+			EventKind::Any | EventKind::Access(_) | EventKind::Other => return None,
+		})
+	}
+
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Created => "created",
+			Self::Modified => "modified",
+			Self::Deleted => "deleted",
+			Self::Renamed => "renamed",
+		}
+	}
+}
+
+#[derive(Clone, Debug)]
+pub struct WatchEvent {
+	pub kind: WatchKind,
+	pub name: String,
+	pub time: DateTime<Local>,
+}
+
+impl WatchEvent {
+	/// The events of a batch of raw ones, with the paths relative to `root`,
+	/// where a rename with both sides known is kept as one, and replaces the
+	/// halves of it reported separately.
+	pub(super) fn from_raw(events: Vec<notify::Event>, root: &Path) -> Vec<Self> {
+		let renamed: HashSet<_> = events
+			.iter()
+			.filter(|e| e.kind == EventKind::Modify(ModifyKind::Name(RenameMode::Both)))
+			.flat_map(|e| e.paths.iter().cloned())
+			.collect();
+
+		let time = Local::now();
+		let mut result = vec![];
+		for event in events {
+			let Some(kind) = WatchKind::from_kind(event.kind) else { continue };
+			let half = matches!(
+				event.kind,
+				EventKind::Modify(ModifyKind::Name(RenameMode::From | RenameMode::To))
+			);
+
+			let names: Vec<_> = event
+				.paths
+				.iter()
+				.filter(|&p| !half || !renamed.contains(p))
+				.map(|p| p.strip_prefix(root).unwrap_or(p).to_string_lossy().into_owned())
+				.collect();
+
+			if kind == WatchKind::Renamed && names.len() == 2 {
+				result.push(Self { kind, name: format!("{} -> {}", names[0], names[1]), time });
+			} else {
+				result.extend(names.into_iter().map(|name| Self { kind, name, time }));
+			}
+		}
+		result
+	}
+}
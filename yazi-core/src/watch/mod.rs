@@ -0,0 +1,5 @@
+yazi_macro::mod_pub!(commands);
+
+yazi_macro::mod_flat!(event watch);
+
+pub const WATCH_LIMIT: usize = 1000;
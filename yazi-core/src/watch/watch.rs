@@ -0,0 +1,106 @@
+use std::{collections::VecDeque, time::Duration};
+
+use notify::{PollWatcher, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::{pin, sync::mpsc};
+use tokio_stream::{StreamExt, wrappers::UnboundedReceiverStream};
+use yazi_config::{Pattern, WATCHER, watcher::WatcherBackend};
+use yazi_macro::emit;
+use yazi_shared::{Layer, event::Cmd, url::Url};
+
+use super::{WATCH_LIMIT, WatchEvent};
+
+/// A live log of the filesystem events of a directory, independent of the
+/// watcher that keeps the file list up to date, as that one only reports the
+/// paths that changed.
+#[derive(Default)]
+pub struct Watch {
+	pub visible: bool,
+	pub paused:  bool,
+	pub cursor:  usize,
+	pub root:    Option<Url>,
+	pub filter:  Option<(String, Pattern)>,
+
+	pub(super) events:  VecDeque<WatchEvent>,
+	pub(super) pending: Vec<WatchEvent>,
+	pub(super) ticket:  usize,
+	backend:            Option<Box<dyn Watcher + Send>>,
+}
+
+impl Watch {
+	/// Events matching the filter, newest first.
+	pub fn events(&self) -> impl Iterator<Item = &WatchEvent> {
+		self
+			.events
+			.iter()
+			.filter(|e| self.filter.as_ref().is_none_or(|(_, p)| p.match_path(&e.name, false)))
+	}
+
+	#[inline]
+	pub fn len(&self) -> usize { self.events().count() }
+
+	#[inline]
+	pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+	/// Number of the events arrived while paused.
+	#[inline]
+	pub fn pending(&self) -> usize { self.pending.len() }
+
+	pub(super) fn start(&mut self, root: Url) -> notify::Result<()> {
+		self.ticket += 1;
+		let ticket = self.ticket;
+
+		let (tx, rx) = mpsc::unbounded_channel();
+		let handler = move |res: notify::Result<notify::Event>| {
+			if let Ok(event) = res {
+				tx.send(event).ok();
+			}
+		};
+
+		let config =
+			notify::Config::default().with_poll_interval(Duration::from_millis(WATCHER.poll_interval));
+		let mut backend: Box<dyn Watcher + Send> = if WATCHER.backend == WatcherBackend::Poll {
+			Box::new(PollWatcher::new(handler, config)?)
+		} else {
+			Box::new(RecommendedWatcher::new(handler, config)?)
+		};
+		backend.watch(&root, RecursiveMode::NonRecursive)?;
+
+		let path = root.to_path();
+		tokio::spawn(async move {
+			let rx = UnboundedReceiverStream::new(rx).chunks_timeout(100, Duration::from_millis(100));
+			pin!(rx);
+
+			while let Some(chunk) = rx.next().await {
+				let events = WatchEvent::from_raw(chunk, &path);
+				if !events.is_empty() {
+					emit!(Call(
+						Cmd::new("push").with("ticket", ticket).with_any("events", events),
+						Layer::Watch
+					));
+				}
+			}
+		});
+
+		self.root = Some(root);
+		self.backend = Some(backend);
+		Ok(())
+	}
+
+	pub(super) fn stop(&mut self) {
+		self.backend = None;
+		self.root = None;
+		self.events.clear();
+		self.pending.clear();
+		self.paused = false;
+		self.cursor = 0;
+	}
+
+	pub(super) fn append(&mut self, events: Vec<WatchEvent>) {
+		for event in events {
+			if self.events.len() >= WATCH_LIMIT {
+				self.events.pop_back();
+			}
+			self.events.push_front(event);
+		}
+	}
+}
@@ -1,10 +1,10 @@
 use std::str::FromStr;
 
-use yazi_config::{KEYMAP, keymap::{Chord, Key}};
+use yazi_config::keymap::{Chord, Key};
 use yazi_macro::render;
 use yazi_shared::{Layer, event::CmdCow};
 
-use crate::which::{Which, WhichSorter};
+use crate::{manager::CONTEXTS, which::{Which, WhichSorter}};
 
 pub struct Opt {
 	cands:  Vec<Chord>,
@@ -46,9 +46,10 @@ impl Which {
 	pub fn show_with(&mut self, key: Key, layer: Layer) {
 		self.layer = layer;
 		self.times = 1;
-		self.cands = KEYMAP
-			.get(layer)
-			.iter()
+		self.cands = CONTEXTS
+			.read()
+			.chords(layer)
+			.into_iter()
 			.filter(|c| c.on.len() > 1 && c.on[0] == key)
 			.map(|c| c.into())
 			.collect();
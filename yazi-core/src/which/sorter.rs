@@ -1,7 +1,7 @@
 use std::{borrow::Cow, mem};
 
 use yazi_config::{WHICH, keymap::ChordCow, which::SortBy};
-use yazi_shared::{natsort, translit::Transliterator};
+use yazi_shared::{natsort, natsort_key, translit::Transliterator};
 
 #[derive(Clone, Copy, PartialEq)]
 pub struct WhichSorter {
@@ -14,10 +14,10 @@ pub struct WhichSorter {
 impl Default for WhichSorter {
 	fn default() -> Self {
 		Self {
-			by:        WHICH.sort_by,
-			sensitive: WHICH.sort_sensitive,
-			reverse:   WHICH.sort_reverse,
-			translit:  WHICH.sort_translit,
+			by:        WHICH.load().sort_by,
+			sensitive: WHICH.load().sort_sensitive,
+			reverse:   WHICH.load().sort_reverse,
+			translit:  WHICH.load().sort_translit,
 		}
 	}
 }
@@ -39,17 +39,19 @@ impl WhichSorter {
 			});
 		}
 
-		indices.sort_unstable_by(|&a, &b| {
-			let ordering = if !self.translit {
-				natsort(entities[a].as_bytes(), entities[b].as_bytes(), !self.sensitive)
-			} else {
-				natsort(
-					entities[a].as_bytes().transliterate().as_bytes(),
-					entities[b].as_bytes().transliterate().as_bytes(),
-					!self.sensitive,
-				)
-			};
+		let keys: Vec<_> = entities
+			.iter()
+			.map(|e| {
+				if self.translit {
+					Cow::Owned(natsort_key(e.as_bytes().transliterate().as_bytes()).into_owned())
+				} else {
+					natsort_key(e.as_bytes())
+				}
+			})
+			.collect();
 
+		indices.sort_unstable_by(|&a, &b| {
+			let ordering = natsort(&keys[a], &keys[b], !self.sensitive);
 			if self.reverse { ordering.reverse() } else { ordering }
 		});
 
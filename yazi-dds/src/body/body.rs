@@ -2,7 +2,7 @@ use anyhow::{Result, bail};
 use mlua::{ExternalResult, IntoLua, Lua, Value};
 use serde::Serialize;
 
-use super::{BodyBulk, BodyBye, BodyCd, BodyCustom, BodyDelete, BodyHey, BodyHi, BodyHover, BodyLoad, BodyMount, BodyMove, BodyRename, BodyTab, BodyTrash, BodyYank};
+use super::{BodyBulk, BodyBye, BodyCd, BodyCustom, BodyDelete, BodyError, BodyHey, BodyHi, BodyHover, BodyLoad, BodyMount, BodyMove, BodyRename, BodyTab, BodyTrash, BodyYank};
 use crate::Payload;
 
 #[derive(Debug, Serialize)]
@@ -22,6 +22,7 @@ pub enum Body<'a> {
 	Trash(BodyTrash<'a>),
 	Delete(BodyDelete<'a>),
 	Mount(BodyMount),
+	Error(BodyError<'a>),
 	Custom(BodyCustom),
 }
 
@@ -42,6 +43,7 @@ impl Body<'static> {
 			"trash" => Self::Trash(serde_json::from_str(body)?),
 			"delete" => Self::Delete(serde_json::from_str(body)?),
 			"mount" => Self::Mount(serde_json::from_str(body)?),
+			"error" => Self::Error(serde_json::from_str(body)?),
 			_ => BodyCustom::from_str(kind, body)?,
 		})
 	}
@@ -68,6 +70,7 @@ impl Body<'static> {
 				| "trash"
 				| "delete"
 				| "mount"
+				| "error"
 		) {
 			bail!("Cannot construct system event");
 		}
@@ -102,6 +105,7 @@ impl<'a> Body<'a> {
 			Self::Trash(_) => "trash",
 			Self::Delete(_) => "delete",
 			Self::Mount(_) => "mount",
+			Self::Error(_) => "error",
 			Self::Custom(b) => b.kind.as_str(),
 		}
 	}
@@ -132,6 +136,7 @@ impl IntoLua for Body<'static> {
 			Self::Trash(b) => b.into_lua(lua),
 			Self::Delete(b) => b.into_lua(lua),
 			Self::Mount(b) => b.into_lua(lua),
+			Self::Error(b) => b.into_lua(lua),
 			Self::Custom(b) => b.into_lua(lua),
 		}
 	}
@@ -0,0 +1,47 @@
+use std::borrow::Cow;
+
+use mlua::{IntoLua, Lua, Value};
+use serde::{Deserialize, Serialize};
+use yazi_shared::errors::ErrorReport;
+
+use super::Body;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BodyError<'a> {
+	pub title:  Cow<'a, str>,
+	#[serde(flatten)]
+	pub report: Cow<'a, ErrorReport>,
+}
+
+impl<'a> BodyError<'a> {
+	#[inline]
+	pub fn borrowed(title: &'a str, report: &'a ErrorReport) -> Body<'a> {
+		Self { title: Cow::Borrowed(title), report: Cow::Borrowed(report) }.into()
+	}
+}
+
+impl BodyError<'static> {
+	#[inline]
+	pub fn dummy(title: &str, report: &ErrorReport) -> Body<'static> {
+		Self { title: Cow::Owned(title.to_owned()), report: Cow::Owned(report.clone()) }.into()
+	}
+}
+
+impl<'a> From<BodyError<'a>> for Body<'a> {
+	fn from(value: BodyError<'a>) -> Self { Self::Error(value) }
+}
+
+impl IntoLua for BodyError<'static> {
+	fn into_lua(self, lua: &Lua) -> mlua::Result<Value> {
+		let report = self.report.into_owned();
+		lua
+			.create_table_from([
+				("title", self.title.into_lua(lua)?),
+				("code", report.code.as_str().into_lua(lua)?),
+				("message", report.message.into_lua(lua)?),
+				("path", report.path.map(|p| p.to_string_lossy().into_owned()).into_lua(lua)?),
+				("os", report.os.into_lua(lua)?),
+			])?
+			.into_lua(lua)
+	}
+}
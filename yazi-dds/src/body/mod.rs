@@ -1,5 +1,5 @@
 #![allow(clippy::module_inception)]
 
 yazi_macro::mod_flat!(
-	body bulk bye cd custom delete hey hi hover load mount move_ rename tab trash yank
+	body bulk bye cd custom delete error hey hi hover load mount move_ rename tab trash yank
 );
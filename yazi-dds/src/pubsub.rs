@@ -4,9 +4,9 @@ use mlua::Function;
 use parking_lot::RwLock;
 use yazi_boot::BOOT;
 use yazi_fs::FolderStage;
-use yazi_shared::{Id, RoCell, url::Url};
+use yazi_shared::{Id, RoCell, errors::ErrorReport, url::Url};
 
-use crate::{Client, ID, PEERS, body::{Body, BodyBulk, BodyCd, BodyDelete, BodyHi, BodyHover, BodyLoad, BodyMount, BodyMove, BodyMoveItem, BodyRename, BodyTab, BodyTrash, BodyYank}};
+use crate::{Client, ID, PEERS, body::{Body, BodyBulk, BodyCd, BodyDelete, BodyError, BodyHi, BodyHover, BodyLoad, BodyMount, BodyMove, BodyMoveItem, BodyRename, BodyTab, BodyTrash, BodyYank}};
 
 pub static LOCAL: RoCell<RwLock<HashMap<String, HashMap<String, Function>>>> = RoCell::new();
 
@@ -205,6 +205,18 @@ impl Pubsub {
 		}
 	}
 
+	pub fn pub_from_error(title: &str, report: &ErrorReport) {
+		if LOCAL.read().contains_key("error") {
+			Self::pub_(BodyError::dummy(title, report));
+		}
+		if PEERS.read().values().any(|p| p.able("error")) {
+			Client::push(BodyError::borrowed(title, report));
+		}
+		if BOOT.local_events.contains("error") {
+			BodyError::borrowed(title, report).with_receiver(*ID).flush();
+		}
+	}
+
 	pub fn pub_from_mount() {
 		if LOCAL.read().contains_key("mount") {
 			Self::pub_(BodyMount::owned());
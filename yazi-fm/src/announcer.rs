@@ -0,0 +1,167 @@
+use std::{process::Stdio, time::Instant};
+
+use tokio::{io::AsyncWriteExt, process::{Child, Command}, sync::mpsc};
+use tracing::error;
+use yazi_config::{ACCESSIBILITY, t};
+use yazi_shared::Layer;
+
+use crate::Ctx;
+
+/// Tells the changes of the state in text, by comparing what's announceable
+/// each time it's rendered, to the announcement line and the screen reader
+/// bridge in `[accessibility]`.
+#[derive(Default)]
+pub(crate) struct Announcer {
+	pub(crate) line: String,
+
+	facts:    Vec<String>,
+	notified: Option<Instant>,
+	bridge:   Option<mpsc::UnboundedSender<String>>,
+}
+
+impl Announcer {
+	pub(crate) fn new() -> Self {
+		if !ACCESSIBILITY.enabled || ACCESSIBILITY.bridge.is_empty() {
+			return Self::default();
+		}
+
+		let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+		tokio::spawn(async move {
+			let mut child: Option<Child> = None;
+			while let Some(s) = rx.recv().await {
+				if child.as_mut().is_none_or(|c| !matches!(c.try_wait(), Ok(None))) {
+					child = Self::spawn();
+				}
+				let Some(stdin) = child.as_mut().and_then(|c| c.stdin.as_mut()) else { continue };
+				if let Err(e) = stdin.write_all(format!("{s}\n").as_bytes()).await {
+					error!("Failed to write to the screen reader bridge: {e}");
+				}
+			}
+		});
+
+		Self { bridge: Some(tx), ..Default::default() }
+	}
+
+	/// Announce what has changed since the last time, if anything.
+	pub(crate) fn observe(&mut self, cx: &Ctx) {
+		let facts = Self::facts(cx);
+		let mut said: Vec<_> = facts
+			.iter()
+			.enumerate()
+			.filter(|&(i, (now, _))| self.facts.get(i) != Some(now))
+			.filter_map(|(_, (now, gone))| match now.is_empty() {
+				true => gone.clone(),
+				false => Some(now.clone()),
+			})
+			.collect();
+
+		let records: Vec<_> = cx
+			.notify
+			.history
+			.records()
+			.take_while(|r| self.notified.is_none_or(|t| r.instant > t))
+			.filter(|r| !r.suppressed)
+			.collect();
+		if let Some(r) = cx.notify.history.records().next() {
+			self.notified = Some(r.instant);
+		}
+		said.extend(records.into_iter().rev().map(|r| format!("{}: {}", r.title, r.content)));
+
+		self.facts = facts.into_iter().map(|(now, _)| now).collect();
+		if said.is_empty() {
+			return;
+		}
+
+		self.line = said.join(". ");
+		if let Some(tx) = &self.bridge {
+			tx.send(self.line.replace('\n', " ")).ok();
+		}
+	}
+
+	/// What's announced when it changes, along with what's said once it's gone.
+	fn facts(cx: &Ctx) -> Vec<(String, Option<String>)> {
+		let (tabs, tab) = (&cx.manager.tabs, cx.active());
+		let folder = &tab.current;
+
+		let popup = match cx.layer() {
+			Layer::App | Layer::Manager => String::new(),
+			Layer::Tasks => t!("Tasks"),
+			Layer::Notify => t!("Notifications"),
+			Layer::Watch => t!("Watch"),
+			Layer::Output => t!("Output"),
+			Layer::Excerpt => t!("Excerpt of {name}", name = cx.excerpt.name()),
+			Layer::Terminal => t!("Terminal"),
+			Layer::Quicklook => t!("Quick look"),
+			Layer::Spot => t!("Spot"),
+			Layer::Pick => t!("Pick: {title}", title = cx.pick.title()),
+			Layer::Input => t!("Input: {title}", title = cx.input.title),
+			Layer::Confirm => t!("Confirm: {title}", title = cx.confirm.title),
+			Layer::Help => t!("Help for {layer}", layer = cx.help.layer),
+			Layer::Completion => t!("Completion"),
+			Layer::Which => t!("Waiting for the next key"),
+		};
+
+		let item = match cx.layer() {
+			Layer::Help => cx.help.hovered().map(|b| format!("{}: {}", b.on(), b.desc_or_run())),
+			Layer::Pick => cx.pick.window().get(cx.pick.rel_cursor()).cloned(),
+			_ => folder.hovered().map(|f| {
+				let kind = if f.is_dir() {
+					t!(", folder")
+				} else if f.is_link() {
+					t!(", link")
+				} else {
+					String::new()
+				};
+				let selected =
+					if tab.selected.contains_key(&f.url) { t!(", selected") } else { String::new() };
+				let name = f.name().to_string_lossy();
+				let nth = t!("{i} of {n}", i = folder.cursor + 1, n = folder.files.len());
+				format!("{name}{kind}{selected}, {nth}")
+			}),
+		};
+
+		let tab_of = if tabs.len() > 1 {
+			t!("Tab {i} of {n}", i = tabs.cursor + 1, n = tabs.len())
+		} else {
+			String::new()
+		};
+		let mode =
+			if tab.mode.is_visual() { t!("Visual {mode} mode", mode = tab.mode) } else { String::new() };
+		let filter =
+			folder.files.filter().map(|f| t!("Filter: {filter}", filter = f)).unwrap_or_default();
+		let selected = match tab.selected.len() {
+			0 => String::new(),
+			n => t!("{n} selected", n = n),
+		};
+
+		vec![
+			(t!("In {cwd}", cwd = tab.cwd()), None),
+			(tab_of, None),
+			(mode, Some(t!("Normal mode"))),
+			(filter, Some(t!("Filter cleared"))),
+			(selected, Some(t!("Selection cleared"))),
+			(popup, Some(t!("Closed"))),
+			(item.unwrap_or_default(), Some(t!("Nothing here"))),
+		]
+	}
+
+	fn spawn() -> Option<Child> {
+		let args = yazi_shared::shell::split_native(&ACCESSIBILITY.bridge).ok()?;
+		let (program, args) = args.split_first()?;
+		let child = Command::new(program)
+			.args(args)
+			.stdin(Stdio::piped())
+			.stdout(Stdio::null())
+			.stderr(Stdio::null())
+			.kill_on_drop(true)
+			.spawn();
+
+		match child {
+			Ok(c) => Some(c),
+			Err(e) => {
+				error!("Failed to spawn the screen reader bridge `{}`: {e}", ACCESSIBILITY.bridge);
+				None
+			}
+		}
+	}
+}
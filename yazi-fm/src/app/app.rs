@@ -1,18 +1,19 @@
-use std::sync::atomic::Ordering;
+use std::{sync::atomic::Ordering, time::Instant};
 
 use anyhow::Result;
 use crossterm::event::KeyEvent;
-use yazi_config::keymap::Key;
-use yazi_core::input::InputMode;
+use yazi_config::{MANAGER, keymap::Key};
+use yazi_core::manager::Journal;
 use yazi_macro::emit;
 use yazi_shared::{Layer, event::{CmdCow, Event, NEED_RENDER}};
 
-use crate::{Ctx, Executor, Router, Signals, Term, lives::Lives};
+use crate::{Announcer, Ctx, Executor, Profile, Reloader, Router, Signals, Term, lives::Lives};
 
 pub(crate) struct App {
-	pub(crate) cx:      Ctx,
-	pub(crate) term:    Option<Term>,
-	pub(crate) signals: Signals,
+	pub(crate) cx:        Ctx,
+	pub(crate) term:      Option<Term>,
+	pub(crate) signals:   Signals,
+	pub(crate) announcer: Announcer,
 }
 
 impl App {
@@ -21,8 +22,18 @@ impl App {
 		let (mut rx, signals) = (Event::take(), Signals::start()?);
 
 		Lives::register()?;
-		let mut app = Self { cx: Ctx::make(), term: Some(term), signals };
+		let mut app = Self { cx: Ctx::make(), term: Some(term), signals, announcer: Announcer::new() };
 		app.render();
+		Profile::mark("first paint");
+
+		// Not needed for the first frame, so defer them until it's on the screen
+		yazi_dds::serve();
+		Reloader::serve();
+		Journal::serve();
+
+		let mut loading = true;
+
+		let (mut last_frame, mut deferred) = (Instant::now(), false);
 
 		let mut times = 0;
 		let mut events = Vec::with_capacity(200);
@@ -32,20 +43,40 @@ impl App {
 				app.dispatch(event)?;
 			}
 
+			if loading && !app.cx.manager.current().stage.is_loading() {
+				loading = false;
+				Profile::mark("first load");
+			}
+
 			if !NEED_RENDER.swap(false, Ordering::Relaxed) {
 				continue;
 			}
 
-			if times >= 50 {
-				times = 0;
-				app.render();
-			} else if let Ok(event) = rx.try_recv() {
-				events.push(event);
-				emit!(Render);
-			} else {
-				times = 0;
-				app.render();
+			// Too soon after the last frame, put it off until `max_fps` allows
+			if let Some(wait) =
+				MANAGER.load().frame_interval().and_then(|d| d.checked_sub(last_frame.elapsed()))
+			{
+				if !deferred {
+					deferred = true;
+					tokio::spawn(async move {
+						tokio::time::sleep(wait).await;
+						emit!(Render);
+					});
+				}
+				continue;
+			}
+
+			if times < 50 {
+				if let Ok(event) = rx.try_recv() {
+					events.push(event);
+					emit!(Render);
+					continue;
+				}
 			}
+
+			times = 0;
+			(last_frame, deferred) = (Instant::now(), false);
+			app.render();
 		}
 		Ok(())
 	}
@@ -88,13 +119,11 @@ impl App {
 
 	#[inline]
 	fn dispatch_paste(&mut self, str: String) {
-		if self.cx.input.visible {
-			let input = &mut self.cx.input;
-			if input.mode() == InputMode::Insert {
-				input.type_str(&str);
-			} else if input.mode() == InputMode::Replace {
-				input.replace_str(&str);
-			}
+		// Only the text fields take it, the other layers would run it as keys
+		match self.cx.layer() {
+			Layer::Input | Layer::Completion if self.cx.input.visible => self.cx.input.paste_str(&str),
+			Layer::Help => self.cx.help.paste_str(&str),
+			_ => {}
 		}
 	}
 }
@@ -0,0 +1,25 @@
+use yazi_adapter::{ADAPTOR, Adapter};
+use yazi_shared::event::CmdCow;
+
+use crate::app::App;
+
+struct Opt;
+
+impl From<CmdCow> for Opt {
+	fn from(_: CmdCow) -> Self { Self }
+}
+
+impl App {
+	/// Falls back to chafa once Überzug++ is unusable, which is done here on the
+	/// main thread since the adapter is read by it without any synchronization.
+	#[yazi_codegen::command]
+	pub fn fallback(&mut self, _: Opt) {
+		if ADAPTOR.get() == Adapter::Chafa {
+			return;
+		}
+
+		self.cx.active_mut().preview.reset_image();
+		ADAPTOR.set(Adapter::Chafa);
+		self.cx.manager.peek(true);
+	}
+}
@@ -1,14 +1,18 @@
 yazi_macro::mod_flat!(
 	accept_payload
+	fallback
 	mouse
 	notify
+	output
 	plugin
 	quit
 	reflow
+	reload
 	render
 	resize
 	resume
 	stop
+	theme
 	update_notify
 	update_progress
 );
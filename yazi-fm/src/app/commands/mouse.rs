@@ -24,7 +24,7 @@ impl App {
 			let area = yazi_plugin::elements::Rect::from(size);
 			let root = LUA.globals().raw_get::<Table>("Root")?.call_method::<Table>("new", area)?;
 
-			if matches!(event.kind, MouseEventKind::Down(_) if MANAGER.mouse_events.draggable()) {
+			if matches!(event.kind, MouseEventKind::Down(_) if MANAGER.load().mouse_events.draggable()) {
 				root.raw_set("_drag_start", event)?;
 			}
 
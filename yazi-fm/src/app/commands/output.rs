@@ -0,0 +1,13 @@
+use yazi_proxy::options::OutputOpt;
+
+use crate::app::App;
+
+impl App {
+	pub(crate) fn output(&mut self, opt: impl TryInto<OutputOpt>) {
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+
+		self.cx.output.push(opt);
+	}
+}
@@ -1,12 +1,14 @@
 use std::ffi::OsString;
 
 use yazi_boot::ARGS;
+use yazi_core::manager::JOURNAL;
 use yazi_shared::event::EventQuit;
 
 use crate::{Term, app::App};
 
 impl App {
 	pub(crate) fn quit(&mut self, opt: EventQuit) -> ! {
+		JOURNAL.lock().close(self.cx.tasks.ongoing().lock().is_empty());
 		self.cx.tasks.shutdown();
 		self.cx.manager.shutdown();
 		futures::executor::block_on(yazi_dds::shutdown());
@@ -15,11 +17,12 @@ impl App {
 		if !opt.no_cwd_file {
 			self.cwd_to_file();
 		}
-		if let Some(selected) = opt.selected {
-			self.selected_to_file(selected);
-		}
 
-		Term::goodbye(|| false);
+		Term::goodbye(|| match opt.selected {
+			Some(selected) => Self::selected_to_file(selected),
+			None if ARGS.chooser_file.is_some() => 130,
+			None => 0,
+		});
 	}
 
 	fn cwd_to_file(&self) {
@@ -29,9 +32,14 @@ impl App {
 		}
 	}
 
-	fn selected_to_file(&self, selected: OsString) {
-		if let Some(p) = &ARGS.chooser_file {
-			std::fs::write(p, selected.as_encoded_bytes()).ok();
+	fn selected_to_file(selected: OsString) -> i32 {
+		let Some(p) = &ARGS.chooser_file else { return 0 };
+		match std::fs::write(p, selected.as_encoded_bytes()) {
+			Ok(()) => 0,
+			Err(e) => {
+				eprintln!("Failed to write the chosen files to {p:?}: {e}");
+				1
+			}
 		}
 	}
 }
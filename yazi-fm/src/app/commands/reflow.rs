@@ -1,6 +1,7 @@
 use mlua::Value;
 use ratatui::layout::Position;
 use tracing::error;
+use yazi_adapter::ADAPTOR;
 use yazi_config::LAYOUT;
 use yazi_macro::render;
 use yazi_shared::event::CmdCow;
@@ -44,8 +45,15 @@ impl App {
 		});
 
 		if layout != LAYOUT.get() {
+			let moved = layout.preview != LAYOUT.get().preview;
 			LAYOUT.set(layout);
 			render!();
+
+			// An image sized for the old preview pane would be left over the new layout
+			if moved && ADAPTOR.get().shown_load().is_some() {
+				self.cx.active_mut().preview.reset_image();
+				self.cx.manager.peek(true);
+			}
 		}
 
 		if let Err(e) = result {
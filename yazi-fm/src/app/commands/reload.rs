@@ -0,0 +1,40 @@
+use yazi_core::tab::Preference;
+use yazi_macro::render;
+use yazi_plugin::LUA;
+use yazi_proxy::{AppProxy, ManagerProxy};
+use yazi_shared::event::CmdCow;
+
+use crate::app::App;
+
+struct Opt;
+
+impl From<CmdCow> for Opt {
+	fn from(_: CmdCow) -> Self { Self }
+}
+
+impl App {
+	#[yazi_codegen::command]
+	pub fn reload(&mut self, _: Opt) {
+		let old = Preference::default();
+		if let Err(e) = yazi_config::reload() {
+			return AppProxy::notify_error("Failed to reload the config", format!("{e:#}"));
+		}
+
+		let result =
+			yazi_plugin::config::Config::new(&LUA).install_manager().and_then(|c| c.install_preview());
+		if let Err(e) = result {
+			return AppProxy::notify_error("Failed to reload the config", e.to_string());
+		}
+
+		for tab in self.cx.manager.tabs.iter_mut() {
+			tab.rebase_pref(&old);
+		}
+
+		ManagerProxy::refresh();
+		AppProxy::notify_info(
+			"Config reloaded",
+			"Changes to yazi.toml and keymap.toml have been applied",
+		);
+		render!();
+	}
+}
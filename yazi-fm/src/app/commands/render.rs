@@ -1,25 +1,33 @@
 use std::{io::{BufWriter, stderr}, sync::atomic::Ordering};
 
-use crossterm::{execute, queue, terminal::{BeginSynchronizedUpdate, EndSynchronizedUpdate}};
 use ratatui::{CompletedFrame, backend::{Backend, CrosstermBackend}, buffer::Buffer};
 use scopeguard::defer;
-use yazi_plugin::elements::COLLISION;
+use yazi_adapter::REGIONS;
+use yazi_config::{ACCESSIBILITY, MEMORY};
+use yazi_plugin::{LUA, elements::COLLISION};
 use yazi_shared::event::NEED_RENDER;
 
-use crate::{app::App, lives::Lives, root::Root};
+use crate::{Term, app::App, lives::Lives, root::Root};
 
 impl App {
 	pub(crate) fn render(&mut self) {
 		NEED_RENDER.store(false, Ordering::Relaxed);
 		let Some(term) = &mut self.term else { return };
+		if ACCESSIBILITY.enabled {
+			self.announcer.observe(&self.cx);
+		}
 
-		queue!(stderr(), BeginSynchronizedUpdate).ok();
-		defer! { execute!(stderr(), EndSynchronizedUpdate).ok(); }
+		Term::begin_sync();
+		defer! { Term::end_sync(); }
 
-		let collision = COLLISION.swap(false, Ordering::Relaxed);
+		COLLISION.store(false, Ordering::Relaxed);
+		REGIONS.lock().begin(false);
+		yazi_adapter::CURSOR.set(self.cx.cursor().is_some());
 		let frame = term
 			.draw(|f| {
-				_ = Lives::scope(&self.cx, || Ok(f.render_widget(Root::new(&self.cx), f.area())));
+				_ = Lives::scope(&self.cx, || {
+					Ok(f.render_widget(Root::new(&self.cx, &self.announcer.line), f.area()))
+				});
 
 				if let Some(pos) = self.cx.cursor() {
 					f.set_cursor_position(pos);
@@ -31,13 +39,20 @@ impl App {
 			Self::patch(frame, self.cx.cursor());
 		}
 		if !self.cx.notify.messages.is_empty() {
-			self.render_partially();
+			self.draw_partially();
 		}
 
-		// Reload preview if collision is resolved
-		if collision && !COLLISION.load(Ordering::Relaxed) {
+		// Draw the image again if an overlay over it is gone
+		let revealed = REGIONS.lock().settle();
+		if revealed {
 			self.cx.manager.peek(true);
 		}
+
+		// Each frame leaves garbage behind, so don't wait for the collector past the
+		// cap
+		if MEMORY.load().lua_bytes().is_some_and(|cap| LUA.used_memory() > cap) {
+			LUA.gc_collect().ok();
+		}
 	}
 
 	pub(crate) fn render_partially(&mut self) {
@@ -46,6 +61,21 @@ impl App {
 			return self.render();
 		}
 
+		Term::begin_sync();
+		defer! { Term::end_sync(); }
+		self.draw_partially();
+
+		let revealed = REGIONS.lock().settle();
+		if revealed {
+			self.cx.manager.peek(true);
+		}
+	}
+
+	/// Draws only the floating parts over the last frame, where the cells that
+	/// stay the same aren't written to the terminal again.
+	fn draw_partially(&mut self) {
+		let Some(term) = &mut self.term else { return };
+		REGIONS.lock().begin(true);
 		let frame = term
 			.draw_partial(|f| {
 				_ = Lives::scope(&self.cx, || {
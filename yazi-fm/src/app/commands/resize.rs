@@ -15,6 +15,8 @@ impl From<()> for Opt {
 impl App {
 	#[yazi_codegen::command]
 	pub fn resize(&mut self, _: Opt) {
+		yazi_adapter::Nested::refresh();
+		self.cx.terminal.resize();
 		self.cx.active_mut().preview.reset();
 		self.reflow(());
 
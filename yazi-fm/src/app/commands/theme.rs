@@ -0,0 +1,121 @@
+use tokio::sync::{mpsc, oneshot};
+use yazi_adapter::{EMULATOR, Emulator};
+use yazi_config::{popup::PickCfg, t, theme::Flavor};
+use yazi_macro::{emit, render};
+use yazi_plugin::LUA;
+use yazi_proxy::{AppProxy, PickProxy};
+use yazi_shared::{Layer, event::{Cmd, CmdCow}};
+
+use crate::app::App;
+
+struct Opt {
+	light:   Option<bool>,
+	preview: bool,
+	flavor:  Option<String>,
+	reload:  bool,
+	resume:  bool,
+}
+
+impl From<CmdCow> for Opt {
+	fn from(mut c: CmdCow) -> Self {
+		Self {
+			light:   match c.first_str() {
+				Some("light") => Some(true),
+				Some("dark") => Some(false),
+				_ => None,
+			},
+			preview: c.first_str() == Some("preview"),
+			flavor:  c.take_str("flavor").map(|s| s.into_owned()),
+			reload:  c.bool("reload"),
+			resume:  c.bool("resume"),
+		}
+	}
+}
+
+impl App {
+	#[yazi_codegen::command]
+	pub fn theme(&mut self, opt: Opt) {
+		if opt.resume {
+			self.signals.resume(None);
+		}
+		if opt.preview {
+			return Self::preview_flavors();
+		}
+
+		let current = EMULATOR.get().light;
+		let light = match opt.light {
+			Some(b) => b,
+			None if opt.reload || opt.flavor.is_some() => current,
+			None if !opt.resume => return self.query_theme(),
+			None => return,
+		};
+
+		if light == current && !opt.reload && opt.flavor.is_none() {
+			return;
+		}
+
+		let result = yazi_config::reload_flavor(light, opt.flavor.as_deref()).and_then(|()| {
+			yazi_plugin::config::Config::new(&LUA).install_theme()?;
+			Ok(())
+		});
+		if let Err(e) = result {
+			return AppProxy::notify_warn("Failed to reload the theme", e.to_string());
+		}
+
+		EMULATOR.set(Emulator { light, ..EMULATOR.get() });
+		render!();
+	}
+
+	fn query_theme(&mut self) {
+		// Pause the terminal event stream so that the response to the query
+		// can be read from stdin without being swallowed as key events.
+		let (tx, rx) = oneshot::channel();
+		self.signals.stop(Some(tx));
+
+		tokio::spawn(async move {
+			rx.await.ok();
+
+			let light = Emulator::query_light().await;
+			emit!(Call(
+				Cmd::new("theme")
+					.with_opt(0, light.map(|b| if b { "light" } else { "dark" }))
+					.with_bool("resume", true),
+				Layer::App
+			));
+		});
+	}
+
+	fn preview_flavors() {
+		let flavors = Flavor::installed();
+		if flavors.is_empty() {
+			return AppProxy::notify_warn("Preview flavor", "No flavors are installed");
+		}
+
+		tokio::spawn(async move {
+			let (tx, mut rx) = mpsc::unbounded_channel();
+			let names = flavors.clone();
+			let hover = tokio::spawn(async move {
+				while let Some(i) = rx.recv().await {
+					emit!(Call(Cmd::new("theme").with("flavor", &names[i]), Layer::App));
+				}
+			});
+
+			let result = PickProxy::show_live(PickCfg::flavor(flavors.clone()), tx).await;
+			hover.abort();
+
+			match result {
+				Ok(i) => {
+					emit!(Call(Cmd::new("theme").with("flavor", &flavors[i]), Layer::App));
+					AppProxy::notify_info(
+						"Preview flavor",
+						t!(
+							"Set `{flavor}` in the [flavor] section of theme.toml to keep it",
+							flavor = flavors[i]
+						),
+					);
+				}
+				Err(_) => emit!(Call(Cmd::new("theme").with_bool("reload", true), Layer::App)),
+			}
+		});
+	}
+}
@@ -25,8 +25,9 @@ impl App {
 		let progressed = tasks.progress != opt.progress;
 		tasks.progress = opt.progress;
 
-		// If the task manager is visible, update the summaries with a complete render.
-		if tasks.visible {
+		// If the task manager or the mini task list is visible,
+		// update the summaries with a complete render.
+		if tasks.visible || tasks.peeking {
 			let new = tasks.paginate();
 			if tasks.summaries != new {
 				tasks.summaries = new;
@@ -23,17 +23,11 @@ impl Widget for Completion<'_> {
 			.iter()
 			.enumerate()
 			.map(|(i, x)| {
-				let icon = if x.ends_with(MAIN_SEPARATOR) {
-					&THEME.completion.icon_folder
-				} else {
-					&THEME.completion.icon_file
-				};
-
-				let mut item = ListItem::new(format!(" {icon} {x}"));
+				let mut item = ListItem::new(format!(" {} {x}", icon(x)));
 				if i == self.cx.completion.rel_cursor() {
-					item = item.style(THEME.completion.active);
+					item = item.style(THEME.load().completion.active);
 				} else {
-					item = item.style(THEME.completion.inactive);
+					item = item.style(THEME.load().completion.inactive);
 				}
 
 				item
@@ -58,8 +52,64 @@ impl Widget for Completion<'_> {
 		yazi_plugin::elements::Clear::default().render(area, buf);
 		List::new(items)
 			.block(
-				Block::bordered().border_type(BorderType::Rounded).border_style(THEME.completion.border),
+				Block::bordered()
+					.border_type(BorderType::Rounded)
+					.border_style(THEME.load().completion.border),
 			)
 			.render(area, buf);
+
+		self.render_peek(rect, area, buf);
+	}
+}
+
+impl Completion<'_> {
+	/// What's in the selected directory, beside the candidates on whichever side
+	/// has more room.
+	fn render_peek(&self, rect: Rect, list: Rect, buf: &mut Buffer) {
+		let Some(entries) = self.cx.completion.peek() else { return };
+
+		let (left, right) = (list.x, rect.right().saturating_sub(list.right()));
+		let width = left.max(right).min(PEEK_WIDTH);
+		if width < 10 {
+			return;
+		}
+
+		let x = if right >= left { list.right() } else { list.x - width };
+		let height = (entries.len().max(1) as u16 + 2).clamp(list.height, PEEK_HEIGHT);
+		let area = Rect { x, width, height: height.min(rect.bottom() - list.y), ..list };
+
+		let limit = area.height.saturating_sub(2) as usize;
+		let mut items: Vec<_> = entries
+			.iter()
+			.take(limit)
+			.map(|x| ListItem::new(format!(" {} {x}", icon(x))).style(THEME.load().completion.inactive))
+			.collect();
+		if entries.is_empty() {
+			items.push(ListItem::new(" (empty)").style(THEME.load().completion.inactive));
+		} else if entries.len() > limit && limit > 0 {
+			items[limit - 1] = ListItem::new(format!(" … {} more", entries.len() - limit + 1))
+				.style(THEME.load().completion.inactive);
+		}
+
+		yazi_plugin::elements::Clear::default().render(area, buf);
+		List::new(items)
+			.block(
+				Block::bordered()
+					.border_type(BorderType::Rounded)
+					.border_style(THEME.load().completion.border),
+			)
+			.render(area, buf);
+	}
+}
+
+const PEEK_WIDTH: u16 = 30;
+const PEEK_HEIGHT: u16 = 12;
+
+fn icon(name: &str) -> String {
+	let completion = &THEME.load().completion;
+	if name.ends_with(MAIN_SEPARATOR) {
+		completion.icon_folder.clone()
+	} else {
+		completion.icon_file.clone()
 	}
 }
@@ -8,11 +8,15 @@ impl Widget for Buttons {
 		let chunks =
 			ratatui::layout::Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).split(area);
 
-		Paragraph::new(Span::raw(&THEME.confirm.btn_labels[0]).style(THEME.confirm.btn_yes))
-			.centered()
-			.render(chunks[0], buf);
-		Paragraph::new(Span::raw(&THEME.confirm.btn_labels[1]).style(THEME.confirm.btn_no))
-			.centered()
-			.render(chunks[1], buf);
+		Paragraph::new(
+			Span::raw(&THEME.load().confirm.btn_labels[0]).style(THEME.load().confirm.btn_yes),
+		)
+		.centered()
+		.render(chunks[0], buf);
+		Paragraph::new(
+			Span::raw(&THEME.load().confirm.btn_labels[1]).style(THEME.load().confirm.btn_no),
+		)
+		.centered()
+		.render(chunks[1], buf);
 	}
 }
@@ -20,8 +20,8 @@ impl Widget for Confirm<'_> {
 
 		Block::bordered()
 			.border_type(BorderType::Rounded)
-			.border_style(THEME.confirm.border)
-			.title(confirm.title.clone().style(THEME.confirm.title.derive(confirm.title.style)))
+			.border_style(THEME.load().confirm.border)
+			.title(confirm.title.clone().style(THEME.load().confirm.title.derive(confirm.title.style)))
 			.title_alignment(Alignment::Center)
 			.render(area, buf);
 
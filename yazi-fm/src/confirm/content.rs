@@ -21,7 +21,7 @@ impl Widget for Content<'_> {
 
 		// Border
 		let block = if self.border {
-			Block::new().borders(Borders::BOTTOM).border_style(THEME.confirm.border)
+			Block::new().borders(Borders::BOTTOM).border_style(THEME.load().confirm.border)
 		} else {
 			Block::new()
 		};
@@ -31,7 +31,7 @@ impl Widget for Content<'_> {
 			.clone()
 			.alignment(ratatui::layout::Alignment::Center)
 			.block(block)
-			.style(THEME.confirm.content.derive(Styled::style(&confirm.content)))
+			.style(THEME.load().confirm.content.derive(Styled::style(&confirm.content)))
 			.render(inner, buf);
 	}
 }
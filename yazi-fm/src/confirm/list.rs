@@ -17,7 +17,7 @@ impl Widget for List<'_> {
 		let inner = area.inner(Margin::new(2, 0));
 
 		// Bottom border
-		let block = Block::new().borders(Borders::BOTTOM).border_style(THEME.confirm.border);
+		let block = Block::new().borders(Borders::BOTTOM).border_style(THEME.load().confirm.border);
 		block.clone().render(area.inner(Margin::new(1, 0)), buf);
 
 		let list = self
@@ -27,7 +27,7 @@ impl Widget for List<'_> {
 			.clone()
 			.scroll((self.cx.confirm.offset as u16, 0))
 			.block(block)
-			.style(THEME.confirm.list)
+			.style(THEME.load().confirm.list)
 			.wrap(Wrap { trim: false });
 
 		// Vertical scrollbar
@@ -1,5 +1,5 @@
 use ratatui::layout::Rect;
-use yazi_core::{completion::Completion, confirm::Confirm, help::Help, input::Input, manager::Manager, notify::Notify, pick::Pick, tab::{Folder, Tab}, tasks::Tasks, which::Which};
+use yazi_core::{completion::Completion, confirm::Confirm, excerpt::Excerpt, help::Help, input::Input, manager::Manager, notify::Notify, output::Output, pick::Pick, tab::{Folder, Tab}, tasks::Tasks, terminal::Terminal, watch::Watch, which::Which};
 use yazi_shared::Layer;
 
 pub struct Ctx {
@@ -12,6 +12,10 @@ pub struct Ctx {
 	pub completion: Completion,
 	pub which:      Which,
 	pub notify:     Notify,
+	pub watch:      Watch,
+	pub output:     Output,
+	pub excerpt:    Excerpt,
+	pub terminal:   Terminal,
 }
 
 impl Ctx {
@@ -25,7 +29,11 @@ impl Ctx {
 			help:       Default::default(),
 			completion: Default::default(),
 			which:      Default::default(),
-			notify:     Default::default(),
+			notify:     Notify::make(),
+			watch:      Default::default(),
+			output:     Default::default(),
+			excerpt:    Default::default(),
+			terminal:   Default::default(),
 		}
 	}
 
@@ -38,6 +46,9 @@ impl Ctx {
 		if let Some((x, y)) = self.help.cursor() {
 			return Some((x, y));
 		}
+		if self.terminal.visible {
+			return self.terminal.cursor();
+		}
 		None
 	}
 
@@ -57,6 +68,18 @@ impl Ctx {
 			Layer::Pick
 		} else if self.active().spot.visible() {
 			Layer::Spot
+		} else if self.terminal.visible {
+			Layer::Terminal
+		} else if self.output.visible {
+			Layer::Output
+		} else if self.excerpt.visible {
+			Layer::Excerpt
+		} else if self.notify.history.visible {
+			Layer::Notify
+		} else if self.watch.visible {
+			Layer::Watch
+		} else if self.manager.panes.quicklook {
+			Layer::Quicklook
 		} else if self.tasks.visible {
 			Layer::Tasks
 		} else {
@@ -0,0 +1,53 @@
+use ratatui::{buffer::Buffer, layout::Rect, text::{Line, Span}, widgets::{Paragraph, Widget}};
+use yazi_config::{LAYOUT, PREVIEW, THEME};
+
+use crate::Ctx;
+
+pub(crate) struct Excerpt<'a> {
+	cx: &'a Ctx,
+}
+
+impl<'a> Excerpt<'a> {
+	#[inline]
+	pub(crate) fn new(cx: &'a Ctx) -> Self { Self { cx } }
+}
+
+impl Widget for Excerpt<'_> {
+	fn render(self, _: Rect, buf: &mut Buffer) {
+		let area = LAYOUT.get().preview;
+		yazi_plugin::elements::Clear::default().render(area, buf);
+
+		let excerpt = &self.cx.excerpt;
+		let (gutter, selected, indent) =
+			(excerpt.gutter(), excerpt.selected(), PREVIEW.load().indent());
+
+		let lines: Vec<_> = excerpt
+			.lines
+			.iter()
+			.enumerate()
+			.skip(excerpt.offset)
+			.take(area.height as usize)
+			.map(|(i, s)| {
+				let mut spans = Vec::with_capacity(2);
+				if gutter > 0 {
+					spans.push(Span::styled(
+						format!("{:>1$} ", i + 1, gutter - 1),
+						THEME.load().manager.border_style,
+					));
+				}
+				spans.push(Span::raw(s.replace('\t', &indent)));
+
+				let mut line = Line::from(spans);
+				if excerpt.anchor.is_some() && selected.contains(&i) {
+					line = line.style(THEME.load().manager.hovered);
+				}
+				if i == excerpt.cursor {
+					line = line.patch_style(THEME.load().manager.preview_hovered);
+				}
+				line
+			})
+			.collect();
+
+		Paragraph::new(lines).render(area, buf);
+	}
+}
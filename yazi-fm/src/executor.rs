@@ -1,8 +1,105 @@
-use yazi_core::input::InputMode;
-use yazi_shared::{Layer, event::CmdCow};
+use yazi_boot::{ARGS, Args};
+use yazi_config::{COMMAND, command::Alias, t};
+use yazi_core::{input::InputMode, manager::CONTEXTS};
+use yazi_proxy::AppProxy;
+use yazi_shared::{Layer, event::{CmdCow, Data}};
 
 use crate::app::App;
 
+/// Commands of the manager that leave the filesystem as it is, i.e. moving
+/// around, viewing, selecting and yanking, the only ones in read-only mode.
+const READ_ONLY: &[&str] = &[
+	"update_tasks",
+	"update_files",
+	"update_mimes",
+	"update_paged",
+	"update_yanked",
+	"update_diff",
+	"update_info",
+	"update_journal",
+	"update_tags",
+	"update_peeked",
+	"update_spotted",
+	"hover",
+	"peek",
+	"seek",
+	"spot",
+	"preview_target",
+	"refresh",
+	"quit",
+	"close",
+	"toggle_preview",
+	"maximize_preview",
+	"zen",
+	"quicklook",
+	"slideshow",
+	"keymap",
+	"ratio",
+	"column_width",
+	"escape",
+	"arrow",
+	"leave",
+	"enter",
+	"back",
+	"forward",
+	"cd",
+	"reveal",
+	"follow",
+	"toggle",
+	"toggle_all",
+	"view",
+	"collapse",
+	"layout",
+	"visual_mode",
+	"diff",
+	"diff_dirs",
+	"yank",
+	"unyank",
+	"register",
+	"copy",
+	"recent",
+	"hidden",
+	"linemode",
+	"search",
+	"search_do",
+	"filter",
+	"filter_do",
+	"find",
+	"find_do",
+	"find_arrow",
+	"sort",
+	"tab_create",
+	"tab_close",
+	"tab_switch",
+	"tab_swap",
+	"tasks_show",
+	"tasks_peek",
+	"notify_show",
+	"notify_dnd",
+	"watch",
+	"excerpt",
+	"theme",
+	"help",
+];
+
+/// Those that change the files in the directory of the tab, which restricted
+/// mode runs on top, as it only keeps to the root and spawns nothing.
+const RESTRICTED: &[&str] = &[
+	"paste",
+	"paste_do",
+	"link",
+	"hardlink",
+	"remove",
+	"remove_do",
+	"create",
+	"rename",
+	"retarget",
+	"cleanup",
+	"transform",
+	"tag",
+	"untag",
+];
+
 pub(super) struct Executor<'a> {
 	app: &'a mut App,
 }
@@ -13,10 +110,30 @@ impl<'a> Executor<'a> {
 
 	#[inline]
 	pub(super) fn execute(&mut self, cmd: CmdCow, layer: Layer) {
+		// Each step of an alias is checked on its own
+		if layer != Layer::App {
+			let command = COMMAND.load_full();
+			if let Some(alias) = command.get(&cmd.name) {
+				return self.alias(alias, layer);
+			}
+		}
+		if let Some(mode) = Self::forbidden(&cmd, layer) {
+			return AppProxy::notify_warn(
+				mode,
+				t!("`{cmd}` is disabled in {mode} mode", cmd = cmd.name, mode = mode.to_lowercase()),
+			);
+		}
+
 		match layer {
 			Layer::App => self.app(cmd),
 			Layer::Manager => self.manager(cmd),
 			Layer::Tasks => self.tasks(cmd),
+			Layer::Notify => self.notify(cmd),
+			Layer::Watch => self.watch(cmd),
+			Layer::Output => self.output(cmd),
+			Layer::Excerpt => self.excerpt(cmd),
+			Layer::Terminal => self.terminal(cmd),
+			Layer::Quicklook => self.quicklook(cmd),
 			Layer::Spot => self.spot(cmd),
 			Layer::Pick => self.pick(cmd),
 			Layer::Input => self.input(cmd),
@@ -27,6 +144,33 @@ impl<'a> Executor<'a> {
 		}
 	}
 
+	/// Run the steps of the alias one by one, where the contexts each of them is
+	/// conditional on are checked right before it, so they can follow the steps
+	/// before.
+	fn alias(&mut self, alias: &Alias, layer: Layer) {
+		for step in &alias.run {
+			let active = {
+				let mut cx = CONTEXTS.write();
+				cx.sync(self.app.cx.manager.active());
+				step.active(|w| cx.has(w))
+			};
+			if active {
+				self.execute(step.run.clone().into(), layer);
+			}
+		}
+	}
+
+	fn forbidden(cmd: &CmdCow, layer: Layer) -> Option<&'static str> {
+		// The app layer is only used internally, e.g. for plugin callbacks
+		if layer == Layer::App || allowed(&ARGS, layer, &cmd.name, cmd.bool("interactive")) {
+			None
+		} else if ARGS.read_only {
+			Some("Read-only")
+		} else {
+			Some("Restricted")
+		}
+	}
+
 	fn app(&mut self, cmd: CmdCow) {
 		macro_rules! on {
 			($name:ident) => {
@@ -37,7 +181,9 @@ impl<'a> Executor<'a> {
 		}
 
 		on!(accept_payload);
+		on!(fallback);
 		on!(notify);
+		on!(output);
 		on!(plugin);
 		on!(plugin_do);
 		on!(update_notify);
@@ -45,6 +191,8 @@ impl<'a> Executor<'a> {
 		on!(resize);
 		on!(stop);
 		on!(resume);
+		on!(theme);
+		on!(reload);
 	}
 
 	fn manager(&mut self, cmd: CmdCow) {
@@ -77,14 +225,29 @@ impl<'a> Executor<'a> {
 		on!(MANAGER, update_mimes, &self.app.cx.tasks);
 		on!(MANAGER, update_paged, &self.app.cx.tasks);
 		on!(MANAGER, update_yanked);
+		on!(MANAGER, update_diff);
+		on!(MANAGER, update_info);
+		on!(MANAGER, update_journal, &self.app.cx.tasks);
+		on!(MANAGER, update_tags);
+		on!(MANAGER, recover, &self.app.cx.tasks);
 		on!(MANAGER, hover);
 		on!(MANAGER, peek);
 		on!(MANAGER, seek);
 		on!(MANAGER, spot);
+		on!(MANAGER, preview_target, &self.app.cx.tasks);
 		on!(MANAGER, refresh, &self.app.cx.tasks);
 		on!(MANAGER, quit, &self.app.cx.tasks);
 		on!(MANAGER, close, &self.app.cx.tasks);
 		on!(MANAGER, suspend);
+		on!(MANAGER, toggle_preview);
+		on!(MANAGER, maximize_preview);
+		on!(MANAGER, zen);
+		on!(MANAGER, quicklook);
+		on!(MANAGER, slideshow, &self.app.cx.tasks);
+		on!(MANAGER, keymap);
+		on!(MANAGER, ratio);
+		on!(MANAGER, column_width);
+		on!(MANAGER, debug);
 		on!(ACTIVE, escape);
 		on!(ACTIVE, update_peeked);
 		on!(ACTIVE, update_spotted);
@@ -97,24 +260,44 @@ impl<'a> Executor<'a> {
 		on!(ACTIVE, forward);
 		on!(ACTIVE, cd);
 		on!(ACTIVE, reveal);
+		on!(ACTIVE, follow);
 
 		// Toggle
 		on!(ACTIVE, toggle);
 		on!(ACTIVE, toggle_all);
+		on!(ACTIVE, view);
+		on!(ACTIVE, collapse);
+		on!(ACTIVE, layout);
 		on!(ACTIVE, visual_mode);
+		on!(ACTIVE, diff);
 
 		// Operation
 		on!(MANAGER, open, &self.app.cx.tasks);
 		on!(MANAGER, open_do, &self.app.cx.tasks);
 		on!(MANAGER, yank);
 		on!(MANAGER, unyank);
+		on!(MANAGER, register);
+		on!(MANAGER, tag);
+		on!(MANAGER, untag);
+		on!(MANAGER, tag_browse);
+		on!(MANAGER, recent);
+		on!(MANAGER, remotes);
 		on!(MANAGER, paste, &self.app.cx.tasks);
+		on!(MANAGER, paste_do, &self.app.cx.tasks);
 		on!(MANAGER, link, &self.app.cx.tasks);
 		on!(MANAGER, hardlink, &self.app.cx.tasks);
 		on!(MANAGER, remove, &self.app.cx.tasks);
 		on!(MANAGER, remove_do, &self.app.cx.tasks);
+		on!(MANAGER, empty_trash, &self.app.cx.tasks);
+		on!(MANAGER, cleanup);
+		on!(MANAGER, diff_dirs);
+		on!(MANAGER, diff_sync, &self.app.cx.tasks);
+		on!(MANAGER, sync);
+		on!(MANAGER, transform, &self.app.cx.tasks);
+		on!(MANAGER, organize_photos);
 		on!(MANAGER, create);
 		on!(MANAGER, rename);
+		on!(MANAGER, retarget);
 		on!(ACTIVE, copy);
 		on!(ACTIVE, shell);
 		on!(ACTIVE, hidden);
@@ -143,6 +326,21 @@ impl<'a> Executor<'a> {
 		match cmd.name.as_str() {
 			// Tasks
 			"tasks_show" => self.app.cx.tasks.toggle(()),
+			"tasks_peek" => self.app.cx.tasks.peek(cmd),
+			// Notify
+			"notify_show" => self.app.cx.notify.toggle(()),
+			"notify_dnd" => self.app.cx.notify.dnd(cmd),
+			// Watch
+			"watch" => self.app.cx.watch.toggle(Some(self.app.cx.manager.cwd().clone())),
+			// Terminal
+			"terminal" => self.app.cx.terminal.toggle(Some(self.app.cx.manager.cwd().clone())),
+			// Excerpt
+			"excerpt" => {
+				let cx = &mut self.app.cx;
+				cx.excerpt.show(cmd, cx.manager.hovered(), cx.manager.active().preview.skip);
+			}
+			// Theme
+			"theme" => self.app.theme(cmd),
 			// Help
 			"help" => self.app.cx.help.toggle(Layer::Manager),
 			// Plugin
@@ -169,10 +367,14 @@ impl<'a> Executor<'a> {
 		on!(arrow);
 		on!(inspect);
 		on!(cancel);
+		on!(filter);
 		on!(open_with);
 		on!(process_exec);
+		on!(update_trash);
 
 		match cmd.name.as_str() {
+			// Output
+			"output_show" => self.app.cx.output.toggle(()),
 			// Help
 			"help" => self.app.cx.help.toggle(Layer::Tasks),
 			// Plugin
@@ -181,6 +383,166 @@ impl<'a> Executor<'a> {
 		}
 	}
 
+	fn notify(&mut self, cmd: CmdCow) {
+		macro_rules! on {
+			($name:ident) => {
+				if cmd.name == stringify!($name) {
+					return self.app.cx.notify.$name(cmd);
+				}
+			};
+			($name:ident, $alias:literal) => {
+				if cmd.name == $alias {
+					return self.app.cx.notify.$name(cmd);
+				}
+			};
+		}
+
+		on!(toggle, "close");
+		on!(arrow);
+		on!(filter);
+		on!(act);
+		on!(clear);
+		on!(dnd);
+
+		match cmd.name.as_str() {
+			// Help
+			"help" => self.app.cx.help.toggle(Layer::Notify),
+			// Plugin
+			"plugin" => self.app.plugin(cmd),
+			_ => {}
+		}
+	}
+
+	fn watch(&mut self, cmd: CmdCow) {
+		macro_rules! on {
+			($name:ident) => {
+				if cmd.name == stringify!($name) {
+					return self.app.cx.watch.$name(cmd);
+				}
+			};
+			($name:ident, $alias:literal) => {
+				if cmd.name == $alias {
+					return self.app.cx.watch.$name(cmd);
+				}
+			};
+		}
+
+		on!(toggle, "close");
+		on!(arrow);
+		on!(pause);
+		on!(filter);
+		on!(clear);
+		on!(push);
+
+		match cmd.name.as_str() {
+			// Help
+			"help" => self.app.cx.help.toggle(Layer::Watch),
+			// Plugin
+			"plugin" => self.app.plugin(cmd),
+			_ => {}
+		}
+	}
+
+	fn output(&mut self, cmd: CmdCow) {
+		macro_rules! on {
+			($name:ident) => {
+				if cmd.name == stringify!($name) {
+					return self.app.cx.output.$name(cmd);
+				}
+			};
+			($name:ident, $alias:literal) => {
+				if cmd.name == $alias {
+					return self.app.cx.output.$name(cmd);
+				}
+			};
+		}
+
+		on!(toggle, "close");
+		on!(arrow);
+		on!(switch);
+		on!(find);
+		on!(find_do);
+		on!(find_arrow);
+
+		match cmd.name.as_str() {
+			// Help
+			"help" => self.app.cx.help.toggle(Layer::Output),
+			// Plugin
+			"plugin" => self.app.plugin(cmd),
+			_ => {}
+		}
+	}
+
+	fn terminal(&mut self, cmd: CmdCow) {
+		macro_rules! on {
+			($name:ident) => {
+				if cmd.name == stringify!($name) {
+					return self.app.cx.terminal.$name(cmd);
+				}
+			};
+			($name:ident, $alias:literal) => {
+				if cmd.name == $alias {
+					return self.app.cx.terminal.$name(cmd);
+				}
+			};
+		}
+
+		on!(toggle, "close");
+		on!(feed);
+		on!(exit);
+		on!(follow);
+
+		match cmd.name.as_str() {
+			// Help
+			"help" => self.app.cx.help.toggle(Layer::Terminal),
+			// Plugin
+			"plugin" => self.app.plugin(cmd),
+			_ => {}
+		}
+	}
+
+	fn excerpt(&mut self, cmd: CmdCow) {
+		macro_rules! on {
+			($name:ident) => {
+				if cmd.name == stringify!($name) {
+					return self.app.cx.excerpt.$name(cmd);
+				}
+			};
+		}
+
+		on!(escape);
+		on!(close);
+		on!(arrow);
+		on!(visual);
+		on!(yank);
+		on!(numbers);
+
+		match cmd.name.as_str() {
+			// Help
+			"help" => self.app.cx.help.toggle(Layer::Excerpt),
+			// Plugin
+			"plugin" => self.app.plugin(cmd),
+			_ => {}
+		}
+	}
+
+	fn quicklook(&mut self, cmd: CmdCow) {
+		match cmd.name.as_str() {
+			"close" => self.app.cx.manager.quicklook(false),
+			"arrow" if self.app.cx.manager.slideshow.is_some() => {
+				let step = cmd.first().and_then(Data::as_isize).unwrap_or(0);
+				self.app.cx.manager.slideshow(step, &self.app.cx.tasks)
+			}
+			"arrow" => self.app.cx.manager.active_mut().arrow(cmd),
+			"seek" => self.app.cx.manager.seek(cmd),
+			// Help
+			"help" => self.app.cx.help.toggle(Layer::Quicklook),
+			// Plugin
+			"plugin" => self.app.plugin(cmd),
+			_ => {}
+		}
+	}
+
 	fn spot(&mut self, cmd: CmdCow) {
 		macro_rules! on {
 			($name:ident) => {
@@ -216,6 +578,7 @@ impl<'a> Executor<'a> {
 		on!(show);
 		on!(close);
 		on!(arrow);
+		on!(toggle);
 
 		match cmd.name.as_str() {
 			// Help
@@ -310,6 +673,9 @@ impl<'a> Executor<'a> {
 		on!(escape);
 		on!(arrow);
 		on!(filter);
+		on!(layer);
+		on!(locate);
+		on!(run);
 
 		match cmd.name.as_str() {
 			"close" => self.app.cx.help.toggle(Layer::Help),
@@ -330,6 +696,7 @@ impl<'a> Executor<'a> {
 
 		on!(trigger);
 		on!(show);
+		on!(load);
 		on!(close);
 		on!(arrow);
 
@@ -356,3 +723,67 @@ impl<'a> Executor<'a> {
 		on!(callback);
 	}
 }
+
+/// Whether the command is allowed by the launch mode, where the manager only
+/// runs those known to be harmless, so one added later stays disabled until
+/// it's vetted, and the other layers only can't spawn processes.
+fn allowed(args: &Args, layer: Layer, name: &str, interactive: bool) -> bool {
+	if !args.read_only && !args.restricted {
+		return true;
+	}
+
+	match (layer, name) {
+		// Plugins and openers can do anything
+		(_, "plugin") => false,
+		(Layer::Tasks, "open_with" | "process_exec") => false,
+		// Only kept in sync internally, as it can't be shown in the first place
+		(Layer::Terminal, _) => matches!(name, "follow" | "close" | "help"),
+		// Opening only picks the files in the chooser mode
+		(Layer::Manager, "open") => args.chooser_file.is_some() && !interactive,
+		// Suspending drops to the shell, and tags are browsed wherever they are
+		(Layer::Manager, "suspend" | "tag_browse") => !args.restricted,
+		(Layer::Manager, _) if args.read_only => READ_ONLY.contains(&name),
+		(Layer::Manager, _) => READ_ONLY.contains(&name) || RESTRICTED.contains(&name),
+		_ => true,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_allowed() {
+		let read_only = Args { read_only: true, ..Default::default() };
+		for name in
+			["paste_do", "remove_do", "open_do", "recover", "sync", "organize_photos", "remotes"]
+		{
+			assert!(!allowed(&read_only, Layer::Manager, name, false), "{name}");
+		}
+		for name in ["tag", "debug", "shell", "terminal", "some_new_command"] {
+			assert!(!allowed(&read_only, Layer::Manager, name, false), "{name}");
+		}
+		assert!(!allowed(&read_only, Layer::Tasks, "process_exec", false));
+		assert!(!allowed(&read_only, Layer::Input, "plugin", false));
+		assert!(allowed(&read_only, Layer::Manager, "cd", false));
+		assert!(allowed(&read_only, Layer::Manager, "suspend", false));
+		assert!(allowed(&read_only, Layer::Input, "paste", false));
+
+		let restricted = Args { restricted: true, ..Default::default() };
+		for name in ["terminal", "open_do", "shell", "suspend", "tag_browse", "recover", "remotes"] {
+			assert!(!allowed(&restricted, Layer::Manager, name, false), "{name}");
+		}
+		assert!(!allowed(&restricted, Layer::Terminal, "feed", false));
+		assert!(!allowed(&restricted, Layer::Manager, "open", false));
+		assert!(allowed(&restricted, Layer::Manager, "paste_do", false));
+
+		let chooser = Args { chooser_file: Some("/tmp/chosen".into()), ..restricted };
+		assert!(allowed(&chooser, Layer::Manager, "open", false));
+		assert!(!allowed(&chooser, Layer::Manager, "open", true));
+
+		let both = Args { read_only: true, restricted: true, ..Default::default() };
+		assert!(!allowed(&both, Layer::Manager, "paste_do", false));
+		assert!(!allowed(&both, Layer::Manager, "suspend", false));
+		assert!(allowed(&Args::default(), Layer::Manager, "shell", false));
+	}
+}
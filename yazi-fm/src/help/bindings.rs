@@ -1,5 +1,6 @@
 use ratatui::{buffer::Buffer, layout::{self, Constraint, Rect}, widgets::{List, ListItem, Widget}};
-use yazi_config::THEME;
+use yazi_config::{THEME, t};
+use yazi_core::help::HelpBinding;
 
 use crate::Ctx;
 
@@ -18,18 +19,37 @@ impl Widget for Bindings<'_> {
 			return;
 		}
 
+		let style =
+			|b: &HelpBinding, s| if b.shadowed.is_some() { THEME.load().help.shadowed } else { s };
+
 		// On
-		let col1: Vec<_> =
-			bindings.iter().map(|c| ListItem::new(c.on()).style(THEME.help.on)).collect();
+		let col1: Vec<_> = bindings
+			.iter()
+			.map(|b| ListItem::new(b.on()).style(style(b, THEME.load().help.on)))
+			.collect();
 
 		// Run
-		let col2: Vec<_> =
-			bindings.iter().map(|c| ListItem::new(c.run()).style(THEME.help.run)).collect();
+		let col2: Vec<_> = bindings
+			.iter()
+			.map(|b| ListItem::new(b.run()).style(style(b, THEME.load().help.run)))
+			.collect();
 
 		// Desc
 		let col3: Vec<_> = bindings
 			.iter()
-			.map(|c| ListItem::new(c.desc().unwrap_or("-".into())).style(THEME.help.desc))
+			.map(|b| {
+				let desc = b.desc().map_or("-".to_owned(), |d| t!(&d));
+				match &b.shadowed {
+					Some(by) => ListItem::new(t!(
+						"{desc} (shadowed by `{on}`: {run})",
+						desc = desc,
+						on = by.on(),
+						run = by.run()
+					)),
+					None => ListItem::new(desc),
+				}
+				.style(style(b, THEME.load().help.desc))
+			})
 			.collect();
 
 		let chunks = layout::Layout::horizontal([
@@ -42,7 +62,7 @@ impl Widget for Bindings<'_> {
 		let cursor = self.cx.help.rel_cursor() as u16;
 		buf.set_style(
 			Rect { x: area.x, y: area.y + cursor, width: area.width, height: 1 },
-			THEME.help.hovered,
+			THEME.load().help.hovered,
 		);
 
 		List::new(col1).render(chunks[0], buf);
@@ -1,5 +1,5 @@
 use ratatui::{buffer::Buffer, layout::{self, Constraint, Rect}, text::Line, widgets::Widget};
-use yazi_config::{KEYMAP, THEME};
+use yazi_config::{KEYMAP, THEME, t};
 
 use super::Bindings;
 use crate::Ctx;
@@ -12,8 +12,8 @@ impl<'a> Help<'a> {
 	pub fn new(cx: &'a Ctx) -> Self { Self { cx } }
 
 	fn tips() -> String {
-		match KEYMAP.help.iter().find(|&c| c.run.iter().any(|c| c.name == "filter")) {
-			Some(c) => format!(" (Press `{}` to filter)", c.on()),
+		match KEYMAP.load().help.iter().find(|&c| c.run.iter().any(|c| c.name == "filter")) {
+			Some(c) => t!(" (Press `{on}` to filter)", on = c.on()),
 			None => String::new(),
 		}
 	}
@@ -27,7 +27,7 @@ impl Widget for Help<'_> {
 		let chunks = layout::Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).split(area);
 		Line::styled(
 			help.keyword().unwrap_or_else(|| format!("{}.help{}", help.layer, Self::tips())),
-			THEME.help.footer,
+			THEME.load().help.footer,
 		)
 		.render(chunks[1], buf);
 
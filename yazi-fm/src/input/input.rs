@@ -23,9 +23,9 @@ impl<'a> Input<'a> {
 
 		let (theme, syntaxes) = Highlighter::init();
 		if let Some(syntax) = syntaxes.find_syntax_by_name("Bourne Again Shell (bash)") {
-			let mut h = HighlightLines::new(syntax, theme);
+			let mut h = HighlightLines::new(syntax, &theme);
 			let regions = h.highlight_line(self.cx.input.value(), syntaxes)?;
-			return Ok(Highlighter::to_line_widget(regions, &PREVIEW.indent()));
+			return Ok(Highlighter::to_line_widget(regions, &PREVIEW.load().indent()));
 		}
 		bail!("Failed to find syntax")
 	}
@@ -41,10 +41,10 @@ impl Widget for Input<'_> {
 			.block(
 				Block::bordered()
 					.border_type(BorderType::Rounded)
-					.border_style(THEME.input.border)
-					.title(Line::styled(&input.title, THEME.input.title)),
+					.border_style(THEME.load().input.border)
+					.title(Line::styled(&input.title, THEME.load().input.title)),
 			)
-			.style(THEME.input.value)
+			.style(THEME.load().input.value)
 			.render(area, buf);
 
 		if let Some(Range { start, end }) = input.selected() {
@@ -53,7 +53,7 @@ impl Widget for Input<'_> {
 
 			buf.set_style(
 				Rect { x, y, width: (end - start).min(win.width - x), height: 1.min(win.height - y) },
-				THEME.input.selected,
+				THEME.load().input.selected,
 			)
 		}
 
@@ -27,6 +27,7 @@ impl UserData for Ctx {
 			match key.as_bytes().as_ref() {
 				b"active" => super::Tab::make(me.active())?,
 				b"tabs" => super::Tabs::make(&me.manager.tabs)?,
+				b"panes" => super::Panes::make(&me.manager)?,
 				b"tasks" => super::Tasks::make(&me.tasks)?,
 				b"yanked" => super::Yanked::make(&me.manager.yanked)?,
 				b"layer" => return yazi_plugin::bindings::Layer::from(me.layer()).into_lua(lua),
@@ -1,7 +1,7 @@
-use std::ops::Deref;
+use std::{ops::Deref, ptr};
 
 use mlua::{AnyUserData, IntoLua, UserData, UserDataFields, UserDataMethods};
-use yazi_config::THEME;
+use yazi_config::{MANAGER, THEME, manager::ManagerView};
 use yazi_plugin::{bindings::Range, elements::Style};
 
 use super::Lives;
@@ -70,9 +70,29 @@ impl UserData for File {
 		methods.add_method("style", |lua, me, ()| {
 			lua.named_registry_value::<AnyUserData>("cx")?.borrow_scoped(|cx: &Ctx| {
 				let mime = cx.manager.mimetype.by_file(me).unwrap_or_default();
-				THEME.filetypes.iter().find(|&x| x.matches(me, mime)).map(|x| Style::from(x.style))
+				THEME.load().filetypes.iter().find(|&x| x.matches(me, mime)).map(|x| Style::from(x.style))
 			})
 		});
+		methods.add_method("group_header", |_, me, ()| {
+			let (files, tab) = (&me.folder().files, me.tab());
+			if !ptr::eq(me.folder(), &tab.current)
+				|| !(MANAGER.load().group_headers || tab.pref.view == ManagerView::Timeline)
+			{
+				return Ok((None, None));
+			}
+
+			let group = files.sorter().group;
+			let Some(label) = group.label(me) else { return Ok((None, None)) };
+
+			// Only the first file of each group in the current directory is labeled
+			let prev = me.idx.checked_sub(1).map(|i| &files[i]);
+			if prev.is_some_and(|p| group.cmp(p, me).is_eq()) {
+				return Ok((None, None));
+			}
+
+			let folded = files.folded(&label);
+			Ok((Some(label), folded))
+		});
 		methods.add_method("is_hovered", |_, me, ()| Ok(me.idx == me.folder().cursor));
 		methods.add_method("is_yanked", |lua, me, ()| {
 			lua.named_registry_value::<AnyUserData>("cx")?.borrow_scoped(|cx: &Ctx| {
@@ -98,6 +118,39 @@ impl UserData for File {
 			})
 		});
 		methods.add_method("is_selected", |_, me, ()| Ok(me.tab().selected.contains_key(&me.url)));
+		methods.add_method("tags", |lua, me, ()| {
+			lua.named_registry_value::<AnyUserData>("cx")?.borrow_scoped(|cx: &Ctx| {
+				lua.create_sequence_from(cx.manager.tags.get(&me.url).iter().map(String::as_str))
+			})?
+		});
+		methods.add_method("diff", |lua, me, ()| {
+			lua.named_registry_value::<AnyUserData>("cx")?.borrow_scoped(|cx: &Ctx| {
+				cx.manager.diff.as_ref().and_then(|d| d.state(me.tab().id, &me.url))
+			})
+		});
+		methods.add_method("info", |lua, me, ()| {
+			lua.named_registry_value::<AnyUserData>("cx")?.borrow_scoped(|cx: &Ctx| {
+				let Some(info) = cx.manager.hover_info.get(&me.url) else {
+					return Ok(None);
+				};
+
+				let t = lua.create_table()?;
+				t.raw_set("perm", info.perm.as_deref())?;
+				t.raw_set("owner", info.owner.as_deref())?;
+				t.raw_set("group", info.group.as_deref())?;
+				t.raw_set("nlink", info.nlink)?;
+				t.raw_set("ino", info.ino)?;
+				t.raw_set(
+					"target",
+					info
+						.target
+						.as_ref()
+						.map(|p| lua.create_string(p.as_os_str().as_encoded_bytes()))
+						.transpose()?,
+				)?;
+				Ok(Some(t))
+			})?
+		});
 		methods.add_method("in_parent", |_, me, ()| {
 			Ok(me.tab().parent.as_ref().is_some_and(|f| me.folder().url == f.url))
 		});
@@ -1,6 +1,6 @@
 use std::ops::{Deref, Range};
 
-use mlua::{AnyUserData, Lua, UserData, UserDataFields};
+use mlua::{AnyUserData, Lua, UserData, UserDataFields, UserDataMethods};
 use yazi_config::LAYOUT;
 use yazi_plugin::url::Url;
 
@@ -61,4 +61,28 @@ impl UserData for Folder {
 			me.hovered().map(|_| File::make(me.cursor, me, me.tab())).transpose()
 		});
 	}
+
+	fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
+		// Marks of each row in a scrollbar of the given height: 1 for found, 2 for
+		// selected
+		methods.add_method("minimap", |lua, me, height: usize| {
+			let (len, tab) = (me.files.len(), me.tab());
+			let mut rows = vec![0u8; height];
+
+			let finder = tab.finder.as_ref().filter(|_| me.url == tab.current.url);
+			if len == 0 || (tab.selected.is_empty() && finder.is_none()) {
+				return lua.create_sequence_from(rows);
+			}
+
+			for (i, f) in me.files.iter().enumerate() {
+				let row = &mut rows[i * height / len];
+				if tab.selected.contains_key(&f.url) {
+					*row = 2;
+				} else if *row == 0 && finder.is_some_and(|x| x.matched_idx(&f.url).is_some()) {
+					*row = 1;
+				}
+			}
+			lua.create_sequence_from(rows)
+		});
+	}
 }
@@ -1,3 +1,3 @@
 #![allow(clippy::module_inception)]
 
-yazi_macro::mod_flat!(context file files filter finder folder iter lives mode preference preview selected tab tabs tasks yanked);
+yazi_macro::mod_flat!(context file files filter finder folder iter lives mode panes preference preview selected tab tabs tasks yanked);
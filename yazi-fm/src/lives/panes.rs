@@ -0,0 +1,46 @@
+use std::ops::Deref;
+
+use mlua::{AnyUserData, LuaSerdeExt, UserData, UserDataFields, UserDataMethods};
+use yazi_config::manager::ManagerZen;
+use yazi_plugin::config::SER_OPTS;
+
+use super::Lives;
+
+pub(super) struct Panes {
+	inner: *const yazi_core::manager::Manager,
+}
+
+impl Deref for Panes {
+	type Target = yazi_core::manager::Panes;
+
+	fn deref(&self) -> &Self::Target { unsafe { &(*self.inner).panes } }
+}
+
+impl Panes {
+	#[inline]
+	pub(super) fn make(inner: &yazi_core::manager::Manager) -> mlua::Result<AnyUserData> {
+		Lives::scoped_userdata(Self { inner })
+	}
+
+	#[inline]
+	fn layout(&self) -> Option<&str> { unsafe { (*self.inner).active().pref.layout.as_deref() } }
+}
+
+impl UserData for Panes {
+	fn add_fields<F: UserDataFields<Self>>(fields: &mut F) {
+		fields.add_field_method_get("ratio", |lua, me| {
+			lua.to_value_with(&me.effective(me.layout()), SER_OPTS)
+		});
+		fields.add_field_method_get("hidden", |_, me| Ok(me.hidden));
+		fields.add_field_method_get("maximized", |_, me| Ok(me.maximized));
+		fields.add_field_method_get("quicklook", |_, me| Ok(me.quicklook));
+		fields.add_field_method_get("zen", |_, me| Ok(me.zen));
+		fields.add_field_method_get("widths", |lua, me| lua.create_table_from(me.widths.clone()));
+	}
+
+	fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
+		methods.add_method("hides", |_, me, element: mlua::String| {
+			Ok(ManagerZen::try_from(&*element.to_str()?).is_ok_and(|e| me.hides(e)))
+		});
+	}
+}
@@ -1,6 +1,6 @@
 use std::ops::Deref;
 
-use mlua::{AnyUserData, UserData, UserDataFields};
+use mlua::{AnyUserData, LuaSerdeExt, UserData, UserDataFields};
 
 use super::Lives;
 
@@ -28,8 +28,11 @@ impl UserData for Preference {
 		fields.add_field_method_get("sort_reverse", |_, me| Ok(me.sort_reverse));
 		fields.add_field_method_get("sort_dir_first", |_, me| Ok(me.sort_dir_first));
 		fields.add_field_method_get("sort_translit", |_, me| Ok(me.sort_translit));
+		fields.add_field_method_get("group_by", |_, me| Ok(me.group_by.to_string()));
 
 		fields.add_field_method_get("linemode", |_, me| Ok(me.linemode.to_owned()));
 		fields.add_field_method_get("show_hidden", |_, me| Ok(me.show_hidden));
+		fields.add_field_method_get("view", |lua, me| lua.to_value(&me.view));
+		fields.add_field_method_get("layout", |_, me| Ok(me.layout.clone()));
 	}
 }
@@ -1,6 +1,7 @@
 use std::ops::Deref;
 
-use mlua::{AnyUserData, LuaSerdeExt, UserData, UserDataFields};
+use mlua::{AnyUserData, IntoLua, LuaSerdeExt, UserData, UserDataFields};
+use yazi_plugin::config::SER_OPTS;
 
 use super::Lives;
 
@@ -23,6 +24,30 @@ impl Tasks {
 
 impl UserData for Tasks {
 	fn add_fields<F: UserDataFields<Self>>(fields: &mut F) {
-		fields.add_field_method_get("progress", |lua, me| lua.to_value(&me.progress))
+		fields.add_field_method_get("progress", |lua, me| lua.to_value_with(&me.progress, SER_OPTS));
+		fields.add_field_method_get("trash", |lua, me| lua.to_value_with(&me.trash, SER_OPTS));
+		fields.add_field_method_get("peeking", |_, me| Ok(me.peeking));
+		fields.add_field_method_get("summaries", |lua, me| {
+			lua.create_sequence_from(
+				me.summaries
+					.iter()
+					.map(|s| {
+						lua.create_table_from([
+							("id", s.id.into_lua(lua)?),
+							("tab", s.tab.map(|t| t.get()).into_lua(lua)?),
+							("kind", s.kind.to_string().into_lua(lua)?),
+							("status", s.status.to_string().into_lua(lua)?),
+							("name", s.name.as_str().into_lua(lua)?),
+							("total", s.total.into_lua(lua)?),
+							("succ", s.succ.into_lua(lua)?),
+							("fail", s.fail.into_lua(lua)?),
+							("found", s.found.into_lua(lua)?),
+							("processed", s.processed.into_lua(lua)?),
+							("retries", s.retries.into_lua(lua)?),
+						])
+					})
+					.collect::<mlua::Result<Vec<_>>>()?,
+			)
+		});
 	}
 }
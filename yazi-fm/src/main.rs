@@ -5,12 +5,13 @@
 #[global_allocator]
 static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 
-yazi_macro::mod_pub!(app completion confirm help input lives manager notify pick spot tasks which);
+yazi_macro::mod_pub!(app completion confirm excerpt help input lives manager notify output pick spot tasks terminal watch which);
 
-yazi_macro::mod_flat!(context executor logs panic root router signals term);
+yazi_macro::mod_flat!(announcer context executor logs panic profile reloader root router signals term);
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+	Profile::start();
 	Panic::install();
 	yazi_shared::init();
 
@@ -20,19 +21,24 @@ async fn main() -> anyhow::Result<()> {
 	yazi_fs::init();
 
 	yazi_config::init()?;
+	Profile::mark("config");
 
 	yazi_adapter::init()?;
+	Profile::mark("adapter");
 
-	yazi_boot::init();
+	yazi_boot::init()?;
+	Profile::enable(yazi_boot::ARGS.start_profile);
 
 	yazi_proxy::init();
 
 	yazi_dds::init();
 
+	// Needed for the first frame, which is drawn by the Lua components, while the
+	// plugins are only read once they're used
 	yazi_plugin::init()?;
+	Profile::mark("lua");
 
 	yazi_core::init();
 
-	yazi_dds::serve();
 	app::App::serve().await
 }
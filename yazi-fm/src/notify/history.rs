@@ -0,0 +1,150 @@
+use ratatui::{buffer::Buffer, layout::{self, Alignment, Constraint, Rect}, text::{Line, Span, Text}, widgets::{Block, BorderType, List, Padding, Widget}};
+use yazi_config::{THEME, t};
+use yazi_core::{notify::Record, tasks::TASKS_PERCENT};
+use yazi_shared::{Widths, errors::ErrorReport};
+
+use crate::Ctx;
+
+pub(crate) struct History<'a> {
+	cx: &'a Ctx,
+}
+
+impl<'a> History<'a> {
+	pub(crate) fn new(cx: &'a Ctx) -> Self { Self { cx } }
+
+	fn area(area: Rect) -> Rect {
+		let chunk = layout::Layout::vertical([
+			Constraint::Percentage((100 - TASKS_PERCENT) / 2),
+			Constraint::Percentage(TASKS_PERCENT),
+			Constraint::Percentage((100 - TASKS_PERCENT) / 2),
+		])
+		.split(area)[1];
+
+		layout::Layout::horizontal([
+			Constraint::Percentage((100 - TASKS_PERCENT) / 2),
+			Constraint::Percentage(TASKS_PERCENT),
+			Constraint::Percentage((100 - TASKS_PERCENT) / 2),
+		])
+		.split(chunk)[1]
+	}
+
+	fn title(&self) -> String {
+		let notify = &self.cx.notify;
+
+		let mut flags = vec![];
+		if let Some(level) = notify.history.level {
+			flags.push(format!("{level:?}+").to_lowercase());
+		}
+		if notify.dnd {
+			flags.push(t!("do not disturb"));
+		}
+
+		if flags.is_empty() {
+			t!("Notifications")
+		} else {
+			t!("Notifications ({flags})", flags = flags.join(", "))
+		}
+	}
+
+	fn item(record: &Record, width: usize, hovered: bool) -> Text<'_> {
+		let secs = record.instant.elapsed().as_secs();
+		let age = match secs {
+			0..60 => format!("{secs}s"),
+			60..3600 => format!("{}m", secs / 60),
+			_ => format!("{}h", secs / 3600),
+		};
+
+		let mut head = Line::from_iter([
+			Span::styled(format!("{} ", record.level.icon()), record.level.style()),
+			Span::raw(format!("{age:>3} ")),
+			Span::styled(&record.title, record.level.style()),
+		]);
+		if let Some(r) = &record.report {
+			head.push_span(Span::styled(format!(" [{}]", r.code), record.level.style()));
+		}
+		if record.suppressed {
+			head.push_span(Span::styled(t!(" (muted)"), THEME.load().tasks.border));
+		}
+
+		if !hovered {
+			let first = record.content.lines().next().unwrap_or_default();
+			head.push_span(Span::raw(format!(": {first}")));
+			return Text::from(head);
+		}
+
+		let mut text = Text::from(head);
+		text.extend(
+			textwrap::wrap(&record.content, width).into_iter().map(|s| Line::from(format!("  {s}"))),
+		);
+		if let Some(r) = &record.report {
+			text.extend(Self::details(r, width));
+		}
+		if !record.actions.is_empty() {
+			text.push_line(Line::from_iter(
+				record
+					.actions
+					.iter()
+					.enumerate()
+					.map(|(i, a)| Span::raw(format!("  [{}] {}", i + 1, a.label))),
+			));
+		}
+		text.style(THEME.load().tasks.hovered)
+	}
+
+	/// The path, the error of the system, and how to fix it, of an error.
+	fn details(report: &ErrorReport, width: usize) -> Vec<Line<'static>> {
+		let mut rows = vec![];
+		if let Some(p) = &report.path {
+			rows.push((t!("Path"), p.display().to_string()));
+		}
+		if let Some(n) = report.os {
+			rows.push((t!("OS error"), n.to_string()));
+		}
+		if let Some(h) = report.hint() {
+			rows.push((t!("Fix"), t!(h)));
+		}
+
+		let pad = rows.iter().map(|(k, _)| Widths::str(k)).max().unwrap_or(0);
+		let mut lines = vec![];
+		for (k, v) in rows {
+			let indent = " ".repeat(pad - Widths::str(&k) + 2);
+			for (i, s) in textwrap::wrap(&v, width.saturating_sub(pad + 4).max(1)).into_iter().enumerate()
+			{
+				let key = if i == 0 { format!("  {k}{indent}") } else { " ".repeat(pad + 4) };
+				lines.push(Line::from_iter([
+					Span::styled(key, THEME.load().tasks.border),
+					Span::raw(s.into_owned()),
+				]));
+			}
+		}
+		lines
+	}
+}
+
+impl Widget for History<'_> {
+	fn render(self, area: Rect, buf: &mut Buffer) {
+		let area = Self::area(area);
+
+		yazi_plugin::elements::Clear::default().render(area, buf);
+		let block = Block::bordered()
+			.title(Line::styled(self.title(), THEME.load().tasks.title))
+			.title_alignment(Alignment::Center)
+			.padding(Padding::symmetric(1, 1))
+			.border_type(BorderType::Rounded)
+			.border_style(THEME.load().tasks.border);
+
+		let inner = block.inner(area);
+		block.render(area, buf);
+
+		let history = &self.cx.notify.history;
+		let skip = history.cursor.saturating_sub(inner.height as usize / 2);
+		let items = history
+			.records()
+			.enumerate()
+			.skip(skip)
+			.take(inner.height as usize)
+			.map(|(i, r)| Self::item(r, inner.width.saturating_sub(2) as usize, i == history.cursor));
+
+		List::new(items).render(inner, buf);
+	}
+}
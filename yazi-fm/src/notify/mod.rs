@@ -1 +1 @@
-yazi_macro::mod_flat!(notify);
+yazi_macro::mod_flat!(history notify);
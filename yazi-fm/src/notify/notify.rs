@@ -60,9 +60,12 @@ impl Widget for Notify<'_> {
 				.block(
 					Block::bordered()
 						.border_type(BorderType::Rounded)
-						.title(format!("{} {}", m.level.icon(), m.title))
-						.title_style(*m.level.style())
-						.border_style(*m.level.style()),
+						.title(match &m.report {
+							Some(r) => format!("{} {} [{}]", m.level.icon(), m.title, r.code),
+							None => format!("{} {}", m.level.icon(), m.title),
+						})
+						.title_style(m.level.style())
+						.border_style(m.level.style()),
 				)
 				.render(rect, buf);
 		}
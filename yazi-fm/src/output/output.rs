@@ -0,0 +1,101 @@
+use std::ffi::OsStr;
+
+use ratatui::{buffer::Buffer, layout::{self, Alignment, Constraint, Rect}, text::{Line, Span}, widgets::{Block, BorderType, Padding, Paragraph, Widget}};
+use yazi_config::{THEME, t};
+use yazi_core::tasks::TASKS_PERCENT;
+
+use crate::Ctx;
+
+pub(crate) struct Output<'a> {
+	cx: &'a Ctx,
+}
+
+impl<'a> Output<'a> {
+	pub(crate) fn new(cx: &'a Ctx) -> Self { Self { cx } }
+
+	fn area(area: Rect) -> Rect {
+		let chunk = layout::Layout::vertical([
+			Constraint::Percentage((100 - TASKS_PERCENT) / 2),
+			Constraint::Percentage(TASKS_PERCENT),
+			Constraint::Percentage((100 - TASKS_PERCENT) / 2),
+		])
+		.split(area)[1];
+
+		layout::Layout::horizontal([
+			Constraint::Percentage((100 - TASKS_PERCENT) / 2),
+			Constraint::Percentage(TASKS_PERCENT),
+			Constraint::Percentage((100 - TASKS_PERCENT) / 2),
+		])
+		.split(chunk)[1]
+	}
+
+	fn title(&self) -> String {
+		let output = &self.cx.output;
+		let Some(record) = output.current() else { return t!("Output") };
+
+		let mut flags = vec![format!("{}/{}", output.cursor + 1, output.len())];
+		match record.status {
+			Some(0) => {}
+			Some(code) => flags.push(t!("exit code {code}", code = code)),
+			None => flags.push(t!("killed")),
+		}
+		if let Some(finder) = &output.finder {
+			flags.push(format!("/{finder}"));
+		}
+
+		t!("Output of `{title}` ({flags})", title = record.title, flags = flags.join(", "))
+	}
+
+	fn line<'b>(&self, i: usize, s: &'b str) -> Line<'b> {
+		let output = &self.cx.output;
+		let Some(ranges) = output.finder.as_ref().and_then(|f| f.highlighted(OsStr::new(s))) else {
+			return Line::raw(s);
+		};
+
+		let (mut spans, mut last) = (vec![], 0);
+		for r in ranges {
+			spans.push(Span::raw(&s[last..r.start]));
+			spans.push(Span::styled(&s[r.clone()], THEME.load().manager.find_keyword));
+			last = r.end;
+		}
+		spans.push(Span::raw(&s[last..]));
+
+		let line = Line::from(spans);
+		if output.found == Some(i) { line.style(THEME.load().tasks.hovered) } else { line }
+	}
+}
+
+impl Widget for Output<'_> {
+	fn render(self, area: Rect, buf: &mut Buffer) {
+		let area = Self::area(area);
+
+		yazi_plugin::elements::Clear::default().render(area, buf);
+		let block = Block::bordered()
+			.title(Line::styled(self.title(), THEME.load().tasks.title))
+			.title_alignment(Alignment::Center)
+			.padding(Padding::symmetric(1, 1))
+			.border_type(BorderType::Rounded)
+			.border_style(THEME.load().tasks.border);
+
+		let inner = block.inner(area);
+		block.render(area, buf);
+
+		let output = &self.cx.output;
+		let Some(record) = output.current() else { return };
+		if record.lines.is_empty() {
+			Line::styled(t!("No output"), THEME.load().tasks.border).render(inner, buf);
+			return;
+		}
+
+		let lines: Vec<_> = record
+			.lines
+			.iter()
+			.enumerate()
+			.skip(output.offset)
+			.take(inner.height as usize)
+			.map(|(i, s)| self.line(i, s))
+			.collect();
+
+		Paragraph::new(lines).render(inner, buf);
+	}
+}
@@ -21,11 +21,16 @@ impl Widget for Pick<'_> {
 			.iter()
 			.enumerate()
 			.map(|(i, v)| {
+				let mark = match pick.checked(i) {
+					Some(true) => "[x] ",
+					Some(false) => "[ ] ",
+					None => "",
+				};
 				if i != pick.rel_cursor() {
-					return ListItem::new(format!("  {v}")).style(THEME.pick.inactive);
+					return ListItem::new(format!("  {mark}{v}")).style(THEME.load().pick.inactive);
 				}
 
-				ListItem::new(format!(" {v}")).style(THEME.pick.active)
+				ListItem::new(format!(" {mark}{v}")).style(THEME.load().pick.active)
 			})
 			.collect();
 
@@ -35,7 +40,7 @@ impl Widget for Pick<'_> {
 				Block::bordered()
 					.title(pick.title())
 					.border_type(BorderType::Rounded)
-					.border_style(THEME.pick.border),
+					.border_style(THEME.load().pick.border),
 			)
 			.render(area, buf);
 	}
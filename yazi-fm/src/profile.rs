@@ -0,0 +1,39 @@
+use std::{io::{Write, stderr}, sync::atomic::{AtomicBool, Ordering}, time::{Duration, Instant}};
+
+use parking_lot::Mutex;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static STAGES: Mutex<Vec<(&'static str, Instant)>> = Mutex::new(Vec::new());
+
+pub(super) struct Profile;
+
+impl Profile {
+	#[inline]
+	pub(super) fn start() { Self::mark("start") }
+
+	#[inline]
+	pub(super) fn enable(b: bool) { ENABLED.store(b, Ordering::Relaxed) }
+
+	pub(super) fn mark(stage: &'static str) { STAGES.lock().push((stage, Instant::now())); }
+
+	pub(super) fn report() {
+		if !ENABLED.load(Ordering::Relaxed) {
+			return;
+		}
+		let stages = STAGES.lock();
+		let Some(&(_, start)) = stages.first() else { return };
+
+		let mut s = String::from("Startup profile:\n");
+		for w in stages.windows(2) {
+			let (stage, at) = w[1];
+			s.push_str(&format!(
+				"  {stage:<20} {:>10} {:>10}\n",
+				Self::fmt(at - w[0].1),
+				Self::fmt(at - start)
+			));
+		}
+		stderr().write_all(s.as_bytes()).ok();
+	}
+
+	fn fmt(d: Duration) -> String { format!("{:.2}ms", d.as_secs_f64() * 1000.0) }
+}
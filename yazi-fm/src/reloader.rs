@@ -0,0 +1,67 @@
+use std::time::Duration;
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::{pin, sync::mpsc};
+use tokio_stream::{StreamExt, wrappers::UnboundedReceiverStream};
+use tracing::error;
+use yazi_fs::Xdg;
+use yazi_proxy::AppProxy;
+
+// Watches `yazi.toml`, `keymap.toml` and `remotes.toml` to reload the config,
+// and `theme.toml` and the installed flavors to reload the theme, when any of
+// them changes.
+pub(super) struct Reloader;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Target {
+	Config,
+	Theme,
+}
+
+impl Reloader {
+	pub(super) fn serve() {
+		let (tx, rx) = mpsc::unbounded_channel();
+		let handler = move |res: Result<notify::Event, notify::Error>| {
+			let Ok(event) = res else { return };
+			if event.kind.is_access() {
+				return;
+			}
+			for p in &event.paths {
+				if p.ends_with("yazi.toml") || p.ends_with("keymap.toml") || p.ends_with("remotes.toml") {
+					tx.send(Target::Config).ok();
+				} else if p.ends_with("theme.toml") || p.ends_with("flavor.toml") {
+					tx.send(Target::Theme).ok();
+				}
+			}
+		};
+
+		let dir = Xdg::config_dir();
+		let watcher = RecommendedWatcher::new(handler, notify::Config::default()).and_then(|mut w| {
+			w.watch(&dir, RecursiveMode::NonRecursive)?;
+			if dir.join("flavors").is_dir() {
+				w.watch(&dir.join("flavors"), RecursiveMode::Recursive)?;
+			}
+			Ok(w)
+		});
+		if let Err(e) = &watcher {
+			error!("Failed to watch the config for changes: {e}");
+		}
+
+		tokio::spawn(Self::debounce(rx, watcher.ok()));
+	}
+
+	// The watcher is moved in here to keep it alive for as long as the app runs.
+	async fn debounce(rx: mpsc::UnboundedReceiver<Target>, _watcher: Option<RecommendedWatcher>) {
+		let rx = UnboundedReceiverStream::new(rx).chunks_timeout(100, Duration::from_millis(200));
+		pin!(rx);
+
+		while let Some(targets) = rx.next().await {
+			if targets.contains(&Target::Config) {
+				AppProxy::reload();
+			}
+			if targets.contains(&Target::Theme) {
+				AppProxy::theme_reload();
+			}
+		}
+	}
+}
@@ -1,29 +1,47 @@
 use mlua::{ObjectLike, Table};
-use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::Widget};
 use tracing::error;
+use yazi_config::ACCESSIBILITY;
 use yazi_plugin::{LUA, elements::render_once};
 
-use super::{completion, confirm, help, input, manager, pick, spot, tasks, which};
+use super::{completion, confirm, excerpt, help, input, manager, notify, output, pick, spot, tasks, terminal, watch, which};
 use crate::Ctx;
 
 pub(super) struct Root<'a> {
-	cx: &'a Ctx,
+	cx:           &'a Ctx,
+	announcement: &'a str,
 }
 
 impl<'a> Root<'a> {
-	pub(super) fn new(cx: &'a Ctx) -> Self { Self { cx } }
+	pub(super) fn new(cx: &'a Ctx, announcement: &'a str) -> Self { Self { cx, announcement } }
 
 	pub(super) fn reflow(area: Rect) -> mlua::Result<Table> {
-		let area = yazi_plugin::elements::Rect::from(area);
+		let area = yazi_plugin::elements::Rect::from(Self::split(area).0);
 		let root = LUA.globals().raw_get::<Table>("Root")?.call_method::<Table>("new", area)?;
 		root.call_method("reflow", ())
 	}
+
+	/// The area left for the components, and the announcement line at the bottom
+	/// in the accessibility mode.
+	fn split(area: Rect) -> (Rect, Option<Rect>) {
+		if !ACCESSIBILITY.enabled || area.height < 2 {
+			return (area, None);
+		}
+
+		let line = Rect { y: area.bottom() - 1, height: 1, ..area };
+		(Rect { height: area.height - 1, ..area }, Some(line))
+	}
 }
 
 impl Widget for Root<'_> {
 	fn render(self, area: Rect, buf: &mut Buffer) {
+		let (main, line) = Self::split(area);
+		if let Some(line) = line {
+			Line::raw(self.announcement).render(line, buf);
+		}
+
 		let mut f = || {
-			let area = yazi_plugin::elements::Rect::from(area);
+			let area = yazi_plugin::elements::Rect::from(main);
 			let root = LUA.globals().raw_get::<Table>("Root")?.call_method::<Table>("new", area)?;
 
 			render_once(root.call_method("redraw", ())?, buf, |p| self.cx.manager.area(p));
@@ -36,10 +54,30 @@ impl Widget for Root<'_> {
 		manager::Preview::new(self.cx).render(area, buf);
 		manager::Modal::new(self.cx).render(area, buf);
 
+		if self.cx.excerpt.visible {
+			excerpt::Excerpt::new(self.cx).render(area, buf);
+		}
+
 		if self.cx.tasks.visible {
 			tasks::Tasks::new(self.cx).render(area, buf);
 		}
 
+		if self.cx.notify.history.visible {
+			notify::History::new(self.cx).render(area, buf);
+		}
+
+		if self.cx.watch.visible {
+			watch::Watch::new(self.cx).render(area, buf);
+		}
+
+		if self.cx.terminal.visible {
+			terminal::Terminal::new(self.cx).render(area, buf);
+		}
+
+		if self.cx.output.visible {
+			output::Output::new(self.cx).render(area, buf);
+		}
+
 		if self.cx.active().spot.visible() {
 			spot::Spot::new(self.cx).render(area, buf);
 		}
@@ -1,4 +1,5 @@
-use yazi_config::{KEYMAP, keymap::{Chord, ChordCow, Key}};
+use yazi_config::keymap::{ChordCow, Key};
+use yazi_core::manager::CONTEXTS;
 use yazi_macro::emit;
 use yazi_shared::Layer;
 
@@ -16,6 +17,17 @@ impl<'a> Router<'a> {
 	pub(super) fn route(&mut self, key: Key) -> bool {
 		let cx = &mut self.app.cx;
 		let layer = cx.layer();
+		CONTEXTS.write().sync(cx.manager.active());
+
+		use Layer as L;
+		if key.release {
+			// Only what's bound to a release runs, it doesn't type or cancel anything
+			return match layer {
+				L::App | L::Which => false,
+				L::Completion => self.matches(L::Completion, key) || self.matches(L::Input, key),
+				_ => self.matches(layer, key),
+			};
+		}
 
 		if cx.help.visible && cx.help.type_(&key) {
 			return true;
@@ -24,31 +36,39 @@ impl<'a> Router<'a> {
 			return true;
 		}
 
-		use Layer as L;
 		match layer {
 			L::App => unreachable!(),
-			L::Manager | L::Tasks | L::Spot | L::Pick | L::Input | L::Confirm | L::Help => {
-				self.matches(layer, key)
-			}
+			L::Manager
+			| L::Tasks
+			| L::Notify
+			| L::Watch
+			| L::Output
+			| L::Excerpt
+			| L::Quicklook
+			| L::Spot
+			| L::Pick
+			| L::Input
+			| L::Confirm
+			| L::Help => self.matches(layer, key),
 			L::Completion => self.matches(L::Completion, key) || self.matches(L::Input, key),
+			L::Terminal => self.matches(L::Terminal, key) || self.app.cx.terminal.type_(&key),
 			L::Which => cx.which.type_(key),
 		}
 	}
 
 	#[inline]
 	fn matches(&mut self, layer: Layer, key: Key) -> bool {
-		for chord @ Chord { on, .. } in KEYMAP.get(layer) {
-			if on.is_empty() || on[0] != key {
-				continue;
-			}
-
-			if on.len() > 1 {
-				self.app.cx.which.show_with(key, layer);
-			} else {
-				emit!(Seq(ChordCow::from(chord).into_seq(), layer));
-			}
-			return true;
+		let Some(chord) =
+			CONTEXTS.read().chords(layer).into_iter().find(|c| c.on.first() == Some(&key))
+		else {
+			return false;
+		};
+
+		if chord.on.len() > 1 {
+			self.app.cx.which.show_with(key, layer);
+		} else {
+			emit!(Seq(ChordCow::from(chord).into_seq(), layer));
 		}
-		false
+		true
 	}
 }
@@ -1,8 +1,10 @@
+use std::{pin::Pin, time::Duration};
+
 use anyhow::Result;
 use crossterm::event::{Event as CrosstermEvent, EventStream, KeyEvent, KeyEventKind};
 use futures::StreamExt;
-use tokio::{select, sync::{mpsc, oneshot}};
-use yazi_config::MANAGER;
+use tokio::{select, sync::{mpsc, oneshot}, time::{Sleep, sleep}};
+use yazi_config::{KEYMAP, MANAGER};
 use yazi_shared::event::Event;
 
 pub(super) struct Signals {
@@ -58,16 +60,20 @@ impl Signals {
 	#[inline]
 	fn handle_term(event: CrosstermEvent) {
 		match event {
-			CrosstermEvent::Key(key @ KeyEvent { kind: KeyEventKind::Press, .. }) => {
+			CrosstermEvent::Key(
+				key @ KeyEvent { kind: KeyEventKind::Press | KeyEventKind::Repeat, .. },
+			) => Event::Key(KeyEvent { kind: KeyEventKind::Press, ..key }).emit(),
+			CrosstermEvent::Key(key @ KeyEvent { kind: KeyEventKind::Release, .. })
+				if KEYMAP.load().releases() =>
+			{
 				Event::Key(key).emit()
 			}
-			CrosstermEvent::Mouse(mouse) => {
-				if MANAGER.mouse_events.contains(mouse.kind.into()) {
-					Event::Mouse(mouse).emit();
-				}
+			CrosstermEvent::Mouse(mouse) if MANAGER.load().mouse_events.contains(mouse.kind.into()) => {
+				Event::Mouse(mouse).emit()
 			}
 			CrosstermEvent::Paste(str) => Event::Paste(str).emit(),
 			CrosstermEvent::Resize(..) => Event::Resize.emit(),
+			CrosstermEvent::FocusGained => yazi_proxy::AppProxy::theme(),
 			_ => {}
 		}
 	}
@@ -92,6 +98,11 @@ impl Signals {
 
 		let mut term = Some(EventStream::new());
 
+		// Ask for the color scheme and the background again on focus gain, and once a
+		// resize has settled, since a theme switch often comes with one, e.g. of a
+		// terminal following the system.
+		let mut requery: Option<Pin<Box<Sleep>>> = None;
+
 		tokio::spawn(async move {
 			loop {
 				if let Some(t) = &mut term {
@@ -102,7 +113,16 @@ impl Signals {
 							callback.take().map(|cb| cb.send(()));
 						},
 						Some(n) = sys.next() => if !Self::handle_sys(n) { return },
-						Some(Ok(e)) = t.next() => Self::handle_term(e)
+						Some(Ok(e)) = t.next() => {
+							if matches!(e, CrosstermEvent::Resize(..)) {
+								requery = Some(Box::pin(sleep(Duration::from_millis(300))));
+							}
+							Self::handle_term(e)
+						},
+						_ = async { requery.as_mut().unwrap().await }, if requery.is_some() => {
+							requery = None;
+							yazi_proxy::AppProxy::theme();
+						}
 					}
 				} else {
 					select! {
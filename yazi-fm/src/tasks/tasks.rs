@@ -1,6 +1,7 @@
 use ratatui::{buffer::Buffer, layout::{self, Alignment, Constraint, Rect}, text::{Line, Text}, widgets::{Block, BorderType, List, Padding, Widget}};
-use yazi_config::THEME;
+use yazi_config::{THEME, t};
 use yazi_core::tasks::TASKS_PERCENT;
+use yazi_shared::Id;
 
 use crate::Ctx;
 
@@ -26,6 +27,30 @@ impl<'a> Tasks<'a> {
 		])
 		.split(chunk)[1]
 	}
+
+	fn title(&self) -> String {
+		let filter = &self.cx.tasks.filter;
+		let mut flags = vec![];
+		if let Some(tab) = filter.tab {
+			flags.push(self.group(Some(tab)));
+		}
+		if let Some(kind) = filter.kind {
+			flags.push(kind.to_string());
+		}
+		if let Some(status) = filter.status {
+			flags.push(status.to_string());
+		}
+
+		if flags.is_empty() { t!("Tasks") } else { format!("{} ({})", t!("Tasks"), flags.join(", ")) }
+	}
+
+	fn group(&self, tab: Option<Id>) -> String {
+		match tab.map(|id| self.cx.manager.tabs.iter().position(|t| t.id == id)) {
+			Some(Some(idx)) => t!("Tab {n}", n = idx + 1),
+			Some(None) => t!("Closed tab"),
+			None => t!("Other"),
+		}
+	}
 }
 
 impl Widget for Tasks<'_> {
@@ -34,24 +59,32 @@ impl Widget for Tasks<'_> {
 
 		yazi_plugin::elements::Clear::default().render(area, buf);
 		let block = Block::bordered()
-			.title(Line::styled("Tasks", THEME.tasks.title))
+			.title(Line::styled(self.title(), THEME.load().tasks.title))
 			.title_alignment(Alignment::Center)
 			.padding(Padding::symmetric(1, 1))
 			.border_type(BorderType::Rounded)
-			.border_style(THEME.tasks.border);
+			.border_style(THEME.load().tasks.border);
 
 		let inner = block.inner(area);
 		block.render(area, buf);
 
 		let tasks = &self.cx.tasks;
-		let items = tasks.summaries.iter().take(inner.height as usize).enumerate().map(|(i, v)| {
+		let mut items = Vec::with_capacity(tasks.summaries.len());
+		for (i, v) in tasks.summaries.iter().take(inner.height as usize).enumerate() {
+			if i == 0 || tasks.summaries[i - 1].tab != v.tab {
+				items.push(Text::from(Line::from(self.group(v.tab)).style(THEME.load().tasks.title)));
+			}
+
 			let mut item =
 				Text::from_iter(textwrap::wrap(&v.name, inner.width as usize).into_iter().map(Line::from));
+			if v.retries > 0 {
+				item.push_span(format!(" ({})", t!("retried {n}x", n = v.retries)));
+			}
 			if i == tasks.cursor {
-				item = item.style(THEME.tasks.hovered);
+				item = item.style(THEME.load().tasks.hovered);
 			}
-			item
-		});
+			items.push(item);
+		}
 
 		List::new(items).render(inner, buf);
 	}
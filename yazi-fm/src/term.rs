@@ -1,15 +1,27 @@
 use std::{io::{self, BufWriter, Stderr, stderr}, ops::{Deref, DerefMut}, sync::atomic::{AtomicBool, AtomicU8, Ordering}};
 
 use anyhow::Result;
-use crossterm::{event::{DisableBracketedPaste, EnableBracketedPaste, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags}, execute, queue, style::Print, terminal::{LeaveAlternateScreen, SetTitle, disable_raw_mode, enable_raw_mode}};
+use crossterm::{cursor::{RestorePosition, SavePosition}, event::{DisableBracketedPaste, DisableFocusChange, EnableBracketedPaste, EnableFocusChange, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags}, execute, queue, style::Print, terminal::{BeginSynchronizedUpdate, EndSynchronizedUpdate, LeaveAlternateScreen, SetTitle, disable_raw_mode, enable_raw_mode}};
 use cursor::RestoreCursor;
 use ratatui::{CompletedFrame, Frame, Terminal, backend::CrosstermBackend, buffer::Buffer, layout::Rect};
-use yazi_adapter::{Emulator, Mux};
-use yazi_config::{INPUT, MANAGER};
+use unicode_width::UnicodeWidthStr;
+use yazi_adapter::{EMULATOR, Emulator, Mux};
+use yazi_config::{INPUT, KEYMAP, MANAGER};
+use yazi_shared::{WIDTHS, Widths};
 
 static CSI_U: AtomicBool = AtomicBool::new(false);
+
+// Tells `<C-i>` from `<Tab>` and `<C-[>` from `<Esc>`, and reports `Super` and
+// `Hyper`, on the terminals with the Kitty keyboard protocol
+const KEYBOARD: KeyboardEnhancementFlags = KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+	.union(KeyboardEnhancementFlags::REPORT_ALTERNATE_KEYS);
 static BLINK: AtomicBool = AtomicBool::new(false);
 static SHAPE: AtomicU8 = AtomicU8::new(0);
+static SYNC: AtomicBool = AtomicBool::new(false);
+
+/// Big enough for a whole frame of a large terminal, so that it's written at
+/// once rather than in pieces that a slow connection shows one by one.
+const FRAME_BUFFER: usize = 1 << 18;
 
 pub(super) struct Term {
 	inner:       Terminal<CrosstermBackend<BufWriter<Stderr>>>,
@@ -20,7 +32,10 @@ pub(super) struct Term {
 impl Term {
 	pub(super) fn start() -> Result<Self> {
 		let mut term = Self {
-			inner:       Terminal::new(CrosstermBackend::new(BufWriter::new(stderr())))?,
+			inner:       Terminal::new(CrosstermBackend::new(BufWriter::with_capacity(
+				FRAME_BUFFER,
+				stderr(),
+			)))?,
 			last_area:   Default::default(),
 			last_buffer: Default::default(),
 		};
@@ -35,18 +50,32 @@ impl Term {
 			screen::SetScreen(true),
 			Print(Mux::csi("\x1bP$q q\x1b\\")), // Request cursor shape (DECRQSS query for DECSCUSR)
 			Print(Mux::csi("\x1b[?12$p")),      // Request cursor blink status (DECSET)
+			Print("\x1b[?2026$p"),              // Request synchronized output status (DECRQM)
 			Print("\x1b[?u"),                   // Request keyboard enhancement flags (CSI u)
-			Print(Mux::csi("\x1b[0c")),         // Request device attributes
+			SavePosition,
+			Print("\r…\x1b[6n"),         // Probe the width of ambiguous characters (CPR)
+			Print("\r❤\u{fe0f}\x1b[6n"), // Probe the width of emoji presentations (CPR)
+			Print("\r\x1b[K"),           // Clear the probes
+			RestorePosition,
+			Print(Mux::csi("\x1b[0c")), // Request device attributes
 			screen::SetScreen(false),
 			EnableBracketedPaste,
+			EnableFocusChange,
 			mouse::SetMouse(true),
 		)?;
 
 		let da = futures::executor::block_on(Emulator::read_until_da1());
 		Mux::tmux_drain()?;
 
+		WIDTHS.set(Widths::from_cpr(&da).unwrap_or(WIDTHS.get()));
 		CSI_U.store(da.contains("\x1b[?0u"), Ordering::Relaxed);
 		BLINK.store(da.contains("\x1b[?12;1$y"), Ordering::Relaxed);
+		SYNC.store(
+			da.contains("\x1b[?2026;1$y")
+				|| da.contains("\x1b[?2026;2$y")
+				|| EMULATOR.get().kind.is_left_and(|b| b.synchronized()),
+			Ordering::Relaxed,
+		);
 		SHAPE.store(
 			da.split_once("\x1bP1$r")
 				.and_then(|(_, s)| s.bytes().next())
@@ -58,10 +87,11 @@ impl Term {
 		if CSI_U.load(Ordering::Relaxed) {
 			queue!(
 				stderr(),
-				PushKeyboardEnhancementFlags(
-					KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
-						| KeyboardEnhancementFlags::REPORT_ALTERNATE_KEYS
-				)
+				PushKeyboardEnhancementFlags(if KEYMAP.load().releases() {
+					KEYBOARD | KeyboardEnhancementFlags::REPORT_EVENT_TYPES
+				} else {
+					KEYBOARD
+				})
 			)?;
 		}
 
@@ -81,6 +111,7 @@ impl Term {
 			mouse::SetMouse(false),
 			RestoreCursor,
 			DisableBracketedPaste,
+			DisableFocusChange,
 			LeaveAlternateScreen,
 		)?;
 
@@ -88,12 +119,12 @@ impl Term {
 		Ok(disable_raw_mode()?)
 	}
 
-	pub(super) fn goodbye(f: impl FnOnce() -> bool) -> ! {
+	pub(super) fn goodbye(f: impl FnOnce() -> i32) -> ! {
 		if CSI_U.swap(false, Ordering::Relaxed) {
 			execute!(stderr(), PopKeyboardEnhancementFlags).ok();
 		}
 
-		if !MANAGER.title_format.is_empty() {
+		if !MANAGER.load().title_format.is_empty() {
 			execute!(stderr(), SetTitle("")).ok();
 		}
 
@@ -103,6 +134,7 @@ impl Term {
 			RestoreCursor,
 			SetTitle(""),
 			DisableBracketedPaste,
+			DisableFocusChange,
 			LeaveAlternateScreen,
 			crossterm::cursor::Show
 		)
@@ -110,14 +142,20 @@ impl Term {
 
 		disable_raw_mode().ok();
 
-		std::process::exit(f() as i32);
+		crate::Profile::report();
+		std::process::exit(f());
 	}
 
 	pub(super) fn draw(&mut self, f: impl FnOnce(&mut Frame)) -> io::Result<CompletedFrame> {
-		let last = self.inner.draw(f)?;
+		let last = self.inner.draw(|frame| {
+			f(frame);
+			Self::widen(frame.buffer_mut());
+		})?;
 
+		// Reuse the cells of the last frame, instead of allocating a new one each time
 		self.last_area = last.area;
-		self.last_buffer = last.buffer.clone();
+		self.last_buffer.area = last.buffer.area;
+		self.last_buffer.content.clone_from(&last.buffer.content);
 		Ok(last)
 	}
 
@@ -133,18 +171,57 @@ impl Term {
 			}
 
 			f(frame);
+			Self::widen(frame.buffer_mut());
 		})
 	}
 
+	/// Keeps the columns in place on terminals that draw the ambiguous characters
+	/// wide while ratatui takes them as narrow, by emptying the cells they spill
+	/// into, like those behind a wide character.
+	fn widen(buf: &mut Buffer) {
+		if WIDTHS.get().ambiguous != 2 {
+			return;
+		}
+
+		let area = buf.area;
+		for y in area.top()..area.bottom() {
+			let mut x = area.left();
+			while x < area.right() {
+				let sym = buf[(x, y)].symbol();
+				let spill = Widths::str(sym).saturating_sub(sym.width()) as u16;
+				for x in x + 1..(x + 1 + spill).min(area.right()) {
+					buf[(x, y)].set_symbol("");
+				}
+				x += 1 + spill;
+			}
+		}
+	}
+
 	#[inline]
 	pub(super) fn can_partial(&mut self) -> bool {
 		self.inner.autoresize().is_ok() && self.last_area == self.inner.get_frame().area()
 	}
 
+	/// Holds the frame back until [`Self::end_sync`], so the terminal shows it
+	/// as a whole instead of tearing, if it supports synchronized output.
+	#[inline]
+	pub(super) fn begin_sync() {
+		if SYNC.load(Ordering::Relaxed) {
+			queue!(stderr(), BeginSynchronizedUpdate).ok();
+		}
+	}
+
+	#[inline]
+	pub(super) fn end_sync() {
+		if SYNC.load(Ordering::Relaxed) {
+			execute!(stderr(), EndSynchronizedUpdate).ok();
+		}
+	}
+
 	#[inline]
 	pub(super) fn set_cursor_block() -> Result<()> {
 		use crossterm::cursor::SetCursorStyle;
-		Ok(if INPUT.cursor_blink {
+		Ok(if INPUT.load().cursor_blink {
 			queue!(stderr(), SetCursorStyle::BlinkingBlock)?
 		} else {
 			queue!(stderr(), SetCursorStyle::SteadyBlock)?
@@ -154,7 +231,7 @@ impl Term {
 	#[inline]
 	pub(super) fn set_cursor_bar() -> Result<()> {
 		use crossterm::cursor::SetCursorStyle;
-		Ok(if INPUT.cursor_blink {
+		Ok(if INPUT.load().cursor_blink {
 			queue!(stderr(), SetCursorStyle::BlinkingBar)?
 		} else {
 			queue!(stderr(), SetCursorStyle::SteadyBar)?
@@ -164,7 +241,7 @@ impl Term {
 	#[inline]
 	pub(super) fn set_cursor_underscore() -> Result<()> {
 		use crossterm::cursor::SetCursorStyle;
-		Ok(if INPUT.cursor_blink {
+		Ok(if INPUT.load().cursor_blink {
 			queue!(stderr(), SetCursorStyle::BlinkingUnderScore)?
 		} else {
 			queue!(stderr(), SetCursorStyle::SteadyUnderScore)?
@@ -195,7 +272,7 @@ mod mouse {
 
 	impl crossterm::Command for SetMouse {
 		fn write_ansi(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
-			if MANAGER.mouse_events.is_empty() {
+			if MANAGER.load().mouse_events.is_empty() {
 				Ok(())
 			} else if self.0 {
 				EnableMouseCapture.write_ansi(f)
@@ -206,7 +283,7 @@ mod mouse {
 
 		#[cfg(windows)]
 		fn execute_winapi(&self) -> std::io::Result<()> {
-			if MANAGER.mouse_events.is_empty() {
+			if MANAGER.load().mouse_events.is_empty() {
 				Ok(())
 			} else if self.0 {
 				EnableMouseCapture.execute_winapi()
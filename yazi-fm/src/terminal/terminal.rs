@@ -0,0 +1,38 @@
+use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::{Block, Borders, Paragraph, Widget}};
+use yazi_config::{THEME, t};
+
+use crate::Ctx;
+
+pub(crate) struct Terminal<'a> {
+	cx: &'a Ctx,
+}
+
+impl<'a> Terminal<'a> {
+	pub(crate) fn new(cx: &'a Ctx) -> Self { Self { cx } }
+}
+
+impl Widget for Terminal<'_> {
+	fn render(self, _: Rect, buf: &mut Buffer) {
+		let area = yazi_core::terminal::Terminal::area();
+		let terminal = &self.cx.terminal;
+
+		let title = match &terminal.cwd {
+			Some(cwd) => t!("Terminal {cwd}", cwd = cwd),
+			None => t!("Terminal"),
+		};
+
+		yazi_plugin::elements::Clear::default().render(area, buf);
+		let block = Block::new()
+			.borders(Borders::TOP)
+			.title(Line::styled(title, THEME.load().tasks.title))
+			.border_style(THEME.load().tasks.border);
+
+		let inner = block.inner(area);
+		block.render(area, buf);
+
+		let width = inner.width as usize;
+		let skip = terminal.screen.len(width).saturating_sub(inner.height as usize);
+		let lines: Vec<_> = terminal.screen.rows(width).skip(skip).map(Line::from).collect();
+		Paragraph::new(lines).render(inner, buf);
+	}
+}
@@ -0,0 +1,101 @@
+use ratatui::{buffer::Buffer, layout::{self, Alignment, Constraint, Rect}, style::Style, text::{Line, Span}, widgets::{Block, BorderType, List, ListItem, Padding, Widget}};
+use yazi_config::{THEME, t};
+use yazi_core::{tasks::TASKS_PERCENT, watch::{WatchEvent, WatchKind}};
+
+use crate::Ctx;
+
+pub(crate) struct Watch<'a> {
+	cx: &'a Ctx,
+}
+
+impl<'a> Watch<'a> {
+	pub(crate) fn new(cx: &'a Ctx) -> Self { Self { cx } }
+
+	fn area(area: Rect) -> Rect {
+		let chunk = layout::Layout::vertical([
+			Constraint::Percentage((100 - TASKS_PERCENT) / 2),
+			Constraint::Percentage(TASKS_PERCENT),
+			Constraint::Percentage((100 - TASKS_PERCENT) / 2),
+		])
+		.split(area)[1];
+
+		layout::Layout::horizontal([
+			Constraint::Percentage((100 - TASKS_PERCENT) / 2),
+			Constraint::Percentage(TASKS_PERCENT),
+			Constraint::Percentage((100 - TASKS_PERCENT) / 2),
+		])
+		.split(chunk)[1]
+	}
+
+	fn title(&self) -> String {
+		let watch = &self.cx.watch;
+		let root = watch.root.as_ref().map(|u| u.to_string()).unwrap_or_default();
+
+		let mut flags = vec![];
+		if watch.paused {
+			flags.push(t!("paused, {n} pending", n = watch.pending()));
+		}
+		if let Some((glob, _)) = &watch.filter {
+			flags.push(glob.clone());
+		}
+
+		if flags.is_empty() {
+			t!("Watch {root}", root = root)
+		} else {
+			t!("Watch {root} ({flags})", root = root, flags = flags.join(", "))
+		}
+	}
+
+	fn item(event: &WatchEvent, hovered: bool) -> ListItem<'_> {
+		let style: Style = match event.kind {
+			WatchKind::Created => THEME.load().notify.title_info.into(),
+			WatchKind::Modified => Style::default(),
+			WatchKind::Deleted => THEME.load().notify.title_error.into(),
+			WatchKind::Renamed => THEME.load().notify.title_warn.into(),
+		};
+
+		let line = Line::from_iter([
+			Span::raw(event.time.format("%H:%M:%S ").to_string()),
+			Span::styled(format!("{:<9}", t!(event.kind.as_str())), style),
+			Span::raw(&event.name),
+		]);
+		if hovered {
+			ListItem::new(line).style(THEME.load().tasks.hovered)
+		} else {
+			ListItem::new(line)
+		}
+	}
+}
+
+impl Widget for Watch<'_> {
+	fn render(self, area: Rect, buf: &mut Buffer) {
+		let area = Self::area(area);
+
+		yazi_plugin::elements::Clear::default().render(area, buf);
+		let block = Block::bordered()
+			.title(Line::styled(self.title(), THEME.load().tasks.title))
+			.title_alignment(Alignment::Center)
+			.padding(Padding::symmetric(1, 1))
+			.border_type(BorderType::Rounded)
+			.border_style(THEME.load().tasks.border);
+
+		let inner = block.inner(area);
+		block.render(area, buf);
+
+		let watch = &self.cx.watch;
+		if watch.is_empty() {
+			Line::styled(t!("No changes yet"), THEME.load().tasks.border).render(inner, buf);
+			return;
+		}
+
+		let skip = watch.cursor.saturating_sub(inner.height as usize / 2);
+		let items = watch
+			.events()
+			.enumerate()
+			.skip(skip)
+			.take(inner.height as usize)
+			.map(|(i, e)| Self::item(e, i == watch.cursor));
+
+		List::new(items).render(inner, buf);
+	}
+}
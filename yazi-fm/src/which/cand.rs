@@ -23,16 +23,17 @@ impl Widget for Cand<'_> {
 		spans.push(Span::raw(" ".repeat(10usize.saturating_sub(keys.join("").len()))));
 
 		// First key
-		spans.push(Span::styled(keys[0].clone(), THEME.which.cand));
+		spans.push(Span::styled(keys[0].clone(), THEME.load().which.cand));
 
 		// Rest keys
-		spans.extend(keys.iter().skip(1).map(|k| Span::styled(k, THEME.which.rest)));
+		spans.extend(keys.iter().skip(1).map(|k| Span::styled(k, THEME.load().which.rest)));
 
 		// Separator
-		spans.push(Span::styled(&THEME.which.separator, THEME.which.separator_style));
+		spans
+			.push(Span::styled(THEME.load().which.separator.clone(), THEME.load().which.separator_style));
 
 		// Description
-		spans.push(Span::styled(self.cand.desc_or_run(), THEME.which.desc));
+		spans.push(Span::styled(self.cand.desc_or_run(), THEME.load().which.desc));
 
 		Line::from(spans).render(area, buf);
 	}
@@ -0,0 +1,53 @@
+//! Times the natural sorting of a large directory, run with `cargo bench -p
+//! yazi-fs`.
+
+use std::{hint::black_box, time::Instant};
+
+use yazi_fs::{File, Files, FilesSorter, SortBy};
+use yazi_shared::url::Url;
+
+const N: usize = 100_000;
+
+fn files() -> Vec<File> {
+	let arabic = ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'];
+
+	(0..N)
+		.map(|i| {
+			let n = i * 7919 % N;
+			let name = match i % 4 {
+				0 => format!("IMG_{n:05}.jpg"),
+				1 => format!("Chapter {n} - The Long Title of It.md"),
+				2 => format!("прогулка-{n}.mp4"),
+				_ => n.to_string().bytes().map(|b| arabic[(b - b'0') as usize]).collect(),
+			};
+			File::from_dummy(Url::from(format!("/tmp/{name}")), None)
+		})
+		.collect()
+}
+
+fn bench(name: &str, sorter: FilesSorter, f: impl Fn(&mut Files) -> bool) {
+	let items = files();
+	let mut best = f64::MAX;
+	for _ in 0..5 {
+		let mut files = Files::new(true);
+		files.set_sorter(sorter);
+		files.update_full(items.clone());
+
+		let now = Instant::now();
+		black_box(f(&mut files));
+		best = best.min(now.elapsed().as_secs_f64());
+	}
+	println!("{name:<28} {:>8.2} ms", best * 1000.0);
+}
+
+fn main() {
+	let natural = FilesSorter { by: SortBy::Natural, ..Default::default() };
+
+	bench("natural", natural, |f| f.catchup_revision());
+	bench("natural, sensitive", FilesSorter { sensitive: true, ..natural }, |f| f.catchup_revision());
+	bench("natural, translit", FilesSorter { translit: true, ..natural }, |f| f.catchup_revision());
+	bench("natural, first 100", natural, |f| f.catchup_prefix(100));
+	bench("alphabetical", FilesSorter { by: SortBy::Alphabetical, ..natural }, |f| {
+		f.catchup_revision()
+	});
+}
@@ -0,0 +1,83 @@
+use std::{collections::HashMap, ffi::OsStr, path::Path};
+
+use tokio::fs;
+use yazi_shared::url::Url;
+
+use crate::realname_unchecked;
+
+/// Whether the names in the directory are case-sensitive, probed by looking up
+/// one of its entries with the case flipped, since it can vary per mount on
+/// macOS and Linux, and even per directory on Windows.
+pub async fn case_sensitive(dir: &Path) -> bool {
+	let Ok(mut it) = fs::read_dir(dir).await else { return !CASE_INSENSITIVE };
+
+	for _ in 0..100 {
+		let Ok(Some(entry)) = it.next_entry().await else { break };
+		let name = entry.file_name();
+
+		let upper = name.to_ascii_uppercase();
+		let flipped = if upper != name { upper } else { name.to_ascii_lowercase() };
+		if flipped != name {
+			return !same_entry(&entry.path(), &dir.join(flipped)).await;
+		}
+	}
+	!CASE_INSENSITIVE
+}
+
+/// Whether the two names are the same in the directory.
+pub async fn same_name_in(dir: &Path, a: &OsStr, b: &OsStr) -> bool {
+	a == b || (a.eq_ignore_ascii_case(b) && !case_sensitive(dir).await)
+}
+
+/// Turn the name of `url` into the one on disk if they only differ by case in a
+/// case-insensitive directory, so that overwriting it won't end up with two
+/// entries referring to the same file.
+pub async fn case_aligned(url: Url) -> Url {
+	let Some(parent) = url.parent() else { return url };
+	if case_sensitive(parent).await {
+		return url;
+	}
+
+	match realname_unchecked(&url, &mut HashMap::new()).await {
+		Ok(real) if Some(real.as_ref()) != url.file_name() => url.parent_url().unwrap().join(real),
+		_ => url,
+	}
+}
+
+#[cfg(any(target_os = "macos", windows))]
+const CASE_INSENSITIVE: bool = true;
+#[cfg(not(any(target_os = "macos", windows)))]
+const CASE_INSENSITIVE: bool = false;
+
+#[cfg(unix)]
+async fn same_entry(a: &Path, b: &Path) -> bool {
+	use std::os::unix::fs::MetadataExt;
+
+	match (fs::symlink_metadata(a).await, fs::symlink_metadata(b).await) {
+		(Ok(a), Ok(b)) => a.ino() == b.ino() && a.dev() == b.dev(),
+		_ => false,
+	}
+}
+
+#[cfg(windows)]
+async fn same_entry(a: &Path, b: &Path) -> bool { crate::paths_to_same_file(a, b).await }
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_case_sensitive() {
+	fs::remove_dir_all("/tmp/yazi-case").await.ok();
+	fs::create_dir_all("/tmp/yazi-case").await.unwrap();
+	fs::File::create("/tmp/yazi-case/Foo").await.unwrap();
+
+	// Usually case-sensitive on Linux, unless it's a casefolded ext4 or the like
+	#[cfg(target_os = "linux")]
+	{
+		assert!(case_sensitive(Path::new("/tmp/yazi-case")).await);
+		assert!(!same_name_in(Path::new("/tmp/yazi-case"), OsStr::new("Foo"), OsStr::new("foo")).await);
+		assert_eq!(
+			case_aligned(Url::from("/tmp/yazi-case/foo")).await,
+			Url::from("/tmp/yazi-case/foo")
+		);
+	}
+	assert!(same_name_in(Path::new("/tmp/yazi-case"), OsStr::new("Foo"), OsStr::new("Foo")).await);
+}
@@ -0,0 +1,140 @@
+use std::{ffi::OsString, future::Future, io};
+
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+use yazi_shared::url::{Loc, Url};
+
+use crate::{case_aligned, unique_name};
+
+/// What to do when a file is pasted or created where there's one already.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Conflict {
+	/// Ask for a new name, or whether to overwrite it.
+	Ask,
+	/// Overwrite the existing one.
+	Overwrite,
+	/// Keep the existing one, and leave the new one out.
+	Skip,
+	/// Number the new one, e.g. `photo_1.jpg`.
+	#[default]
+	Rename,
+	/// Number the new one, e.g. `photo (1).jpg`.
+	RenameSuffix,
+	/// Stamp the new one with the current time, e.g. `photo 20250211-093000.jpg`.
+	RenameTimestamp,
+}
+
+impl Conflict {
+	/// Where `u` goes, or `None` if it's skipped. There's no one to ask at this
+	/// point, so `Ask` numbers it like `Rename`, and it's up to the caller
+	/// to ask beforehand.
+	///
+	/// `dir` tells whether it's a directory, whose name is tagged as a whole
+	/// rather than before the extension.
+	pub async fn settle<F>(self, u: Url, dir: F) -> io::Result<Option<Url>>
+	where
+		F: Future<Output = bool>,
+	{
+		Ok(match self {
+			Self::Overwrite => Some(case_aligned(u).await),
+			Self::Skip if exists(&u).await? => None,
+			Self::Skip => Some(u),
+			Self::Ask | Self::Rename | Self::RenameSuffix | Self::RenameTimestamp => {
+				Some(self.rename(u, dir).await?)
+			}
+		})
+	}
+
+	/// A name for `u` that isn't taken yet, in the way of renaming of it.
+	pub async fn rename<F>(self, u: Url, dir: F) -> io::Result<Url>
+	where
+		F: Future<Output = bool>,
+	{
+		if !matches!(self, Self::RenameSuffix | Self::RenameTimestamp) {
+			return unique_name(u, dir).await;
+		} else if !exists(&u).await? {
+			return Ok(u);
+		}
+
+		let dir = dir.await;
+		if self == Self::RenameTimestamp {
+			let stamped = tagged(&u, &Local::now().format("%Y%m%d-%H%M%S").to_string(), dir)?;
+			if !exists(&stamped).await? {
+				return Ok(stamped);
+			}
+		}
+
+		for i in 1u64.. {
+			let numbered = numbered(&u, i, dir)?;
+			if !exists(&numbered).await? {
+				return Ok(numbered);
+			}
+		}
+		unreachable!()
+	}
+}
+
+async fn exists(u: &Url) -> io::Result<bool> {
+	match fs::symlink_metadata(u).await {
+		Ok(_) => Ok(true),
+		Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+		Err(e) => Err(e),
+	}
+}
+
+/// `u` numbered as the `n`-th of its name, e.g. `photo (2).jpg`.
+#[inline]
+pub fn numbered(u: &Url, n: u64, dir: bool) -> io::Result<Url> { tagged(u, &format!("({n})"), dir) }
+
+/// `u` with ` {tag}` added to its name, before the extension unless `dir`.
+fn tagged(u: &Url, tag: &str, dir: bool) -> io::Result<Url> {
+	let Some(stem) = u.file_stem() else {
+		return Err(io::Error::new(io::ErrorKind::InvalidInput, "empty file stem"));
+	};
+
+	let mut name = OsString::with_capacity(u.as_os_str().len() + tag.len() + 2);
+	match u.extension() {
+		Some(ext) if !dir => {
+			name.push(stem);
+			name.push(" ");
+			name.push(tag);
+			name.push(".");
+			name.push(ext);
+		}
+		_ => {
+			name.push(u.file_name().unwrap_or(stem));
+			name.push(" ");
+			name.push(tag);
+		}
+	}
+
+	let mut p = u.to_path();
+	p.set_file_name(name);
+
+	let mut u = u.clone();
+	u.set_loc(Loc::from(u.base(), p));
+	Ok(u)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::path::Path;
+
+	use super::*;
+
+	#[cfg(unix)]
+	#[test]
+	fn test_tagged() {
+		fn assert(path: &str, dir: bool, res: &str) {
+			let u = tagged(&Url::from(Path::new(path)), "(1)", dir).unwrap();
+			assert_eq!(u.to_path(), Path::new(res));
+		}
+
+		assert("/a/photo.jpg", false, "/a/photo (1).jpg");
+		assert("/a/photo.jpg", true, "/a/photo.jpg (1)");
+		assert("/a/.bashrc", false, "/a/.bashrc (1)");
+		assert("/a/b", false, "/a/b (1)");
+	}
+}
@@ -0,0 +1,176 @@
+use std::{borrow::Cow, env, ffi::OsStr, path::{Path, PathBuf}};
+
+/// Expands what's typed into a path prompt: the braces first, then the
+/// environment variables and the `~` of each of the results.
+///
+/// Unlike [`crate::expand_path`], the paths aren't made absolute or cleaned,
+/// so they can be joined with the directory the prompt is about.
+pub fn expand_input(s: &str) -> Vec<PathBuf> {
+	expand_braces(s)
+		.into_iter()
+		.map(|s| {
+			let b = expand_vars(s.as_bytes());
+			// SAFETY: only the ASCII-delimited variables are replaced, with bytes from
+			// another `OsStr`
+			let p = Path::new(unsafe { OsStr::from_encoded_bytes_unchecked(&b) });
+			expand_home(p).unwrap_or_else(|| p.to_owned())
+		})
+		.collect()
+}
+
+/// Replaces the environment variables in `s`, `$VAR` or `${VAR}` on Unix and
+/// `%VAR%` on Windows, leaving the ones that aren't set as they are.
+pub fn expand_vars(s: &[u8]) -> Cow<'_, [u8]> {
+	#[cfg(unix)]
+	let re = regex::bytes::Regex::new(r"\$(?:\{([^}]+)\}|([a-zA-Z\d_]+))").unwrap();
+
+	#[cfg(windows)]
+	let re = regex::bytes::Regex::new(r"%([^%]+)%").unwrap();
+
+	re.replace_all(s, |caps: &regex::bytes::Captures| {
+		let name = caps.get(2).or_else(|| caps.get(1)).unwrap();
+		std::str::from_utf8(name.as_bytes())
+			.ok()
+			.and_then(env::var_os)
+			.map_or_else(|| caps[0].to_vec(), |v| v.into_encoded_bytes())
+	})
+}
+
+/// Replaces a leading `~` with the home directory, or `~user` with that of
+/// the user, returning `None` if `p` doesn't start with either.
+pub fn expand_home(p: &Path) -> Option<PathBuf> {
+	let mut it = p.components();
+	let first = it.next()?.as_os_str().to_str()?;
+	let name = first.strip_prefix('~')?;
+
+	let home = if name.is_empty() {
+		dirs::home_dir()?
+	} else {
+		#[cfg(unix)]
+		{
+			use uzers::{Users, os::unix::UserExt};
+			yazi_shared::USERS_CACHE.get_user_by_name(name)?.home_dir().to_owned()
+		}
+		#[cfg(windows)]
+		return None;
+	};
+	Some(home.join(it.as_path()))
+}
+
+/// Expands the braces in `s` like a shell does, `a{b,c}` into `ab` and `ac`,
+/// and `{1..3}` into `1`, `2` and `3`, with `\` escaping the next character.
+///
+/// Braces that aren't balanced, or have neither a comma nor a range in them,
+/// are kept as they are.
+pub fn expand_braces(s: &str) -> Vec<String> {
+	let Some((open, close, parts)) = brace(s) else {
+		return vec![s.to_owned()];
+	};
+
+	let (head, tail) = (&s[..open], &s[close + 1..]);
+	let tails = expand_braces(tail);
+	parts
+		.iter()
+		.flat_map(|p| expand_braces(&format!("{head}{p}")))
+		.flat_map(|h| tails.iter().map(move |t| format!("{h}{t}")))
+		.collect()
+}
+
+/// The first expandable pair of braces in `s`: where it opens and closes, and
+/// what's in between.
+fn brace(s: &str) -> Option<(usize, usize, Vec<String>)> {
+	let b = s.as_bytes();
+	let mut i = 0;
+	while i < b.len() {
+		match b[i] {
+			b'\\' => i += 1,
+			b'{' => {
+				if let Some((close, parts)) = group(s, i) {
+					return Some((i, close, parts));
+				}
+			}
+			_ => {}
+		}
+		i += 1;
+	}
+	None
+}
+
+fn group(s: &str, open: usize) -> Option<(usize, Vec<String>)> {
+	let b = s.as_bytes();
+	let (mut depth, mut start, mut parts) = (0, open + 1, vec![]);
+
+	let mut i = open + 1;
+	while i < b.len() {
+		match b[i] {
+			b'\\' => i += 1,
+			b'{' => depth += 1,
+			b'}' if depth > 0 => depth -= 1,
+			b',' if depth == 0 => {
+				parts.push(s[start..i].to_owned());
+				start = i + 1;
+			}
+			b'}' => {
+				let inner = &s[start..i];
+				if !parts.is_empty() {
+					parts.push(inner.to_owned());
+					return Some((i, parts));
+				}
+				return range(inner).map(|r| (i, r));
+			}
+			_ => {}
+		}
+		i += 1;
+	}
+	None
+}
+
+fn range(s: &str) -> Option<Vec<String>> {
+	let (a, b) = s.split_once("..")?;
+	let width = if a.starts_with('0') || b.starts_with('0') { a.len().max(b.len()) } else { 0 };
+
+	let (a, b): (i64, i64) = (a.parse().ok()?, b.parse().ok()?);
+	if a.abs_diff(b) > 10000 {
+		return None;
+	}
+
+	let it: Box<dyn Iterator<Item = i64>> =
+		if a <= b { Box::new(a..=b) } else { Box::new((b..=a).rev()) };
+	Some(it.map(|n| format!("{n:0width$}")).collect())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[cfg(unix)]
+	#[test]
+	fn test_expand_input() {
+		env::set_var("YAZI_TEST_EXPAND", "x");
+		let home = dirs::home_dir().unwrap();
+
+		assert_eq!(expand_input("a/$YAZI_TEST_EXPAND"), [Path::new("a/x")]);
+		assert_eq!(expand_input("${YAZI_TEST_EXPAND}{1,2}"), [Path::new("x1"), Path::new("x2")]);
+		assert_eq!(expand_input("~/a"), [home.join("a")]);
+		assert_eq!(expand_input("$YAZI_TEST_UNSET/~"), [Path::new("$YAZI_TEST_UNSET/~")]);
+	}
+
+	#[test]
+	fn test_expand_braces() {
+		fn assert(s: &str, res: &[&str]) {
+			assert_eq!(expand_braces(s), res);
+		}
+
+		assert("a", &["a"]);
+		assert("a{b,c}d", &["abd", "acd"]);
+		assert("{a,b}{1,2}", &["a1", "a2", "b1", "b2"]);
+		assert("a{b,{c,d}e}", &["ab", "ace", "ade"]);
+		assert("{1..3}.txt", &["1.txt", "2.txt", "3.txt"]);
+		assert("{3..1}", &["3", "2", "1"]);
+		assert("{08..10}", &["08", "09", "10"]);
+		assert("a{b}c", &["a{b}c"]);
+		assert("a{b,c", &["a{b,c"]);
+		assert(r"a\{b,c}", &[r"a\{b,c}"]);
+		assert("{a..c}", &["{a..c}"]);
+	}
+}
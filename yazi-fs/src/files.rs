@@ -4,7 +4,7 @@ use tokio::{fs::{self, DirEntry}, select, sync::mpsc::{self, UnboundedReceiver}}
 use yazi_shared::{Id, url::{Url, Urn, UrnBuf}};
 
 use super::{FilesSorter, Filter};
-use crate::{Cha, FILES_TICKET, File, FilesOp, SortBy, maybe_exists, mounts::PARTITIONS};
+use crate::{Cha, FILES_TICKET, File, FilesOp, GroupBy, SortBy, maybe_exists, mounts::PARTITIONS};
 
 #[derive(Default)]
 pub struct Files {
@@ -12,6 +12,7 @@ pub struct Files {
 	items:        Vec<File>,
 	ticket:       Id,
 	version:      u64,
+	partial:      u64,
 	pub revision: u64,
 
 	pub sizes: HashMap<UrnBuf, u64>,
@@ -19,6 +20,10 @@ pub struct Files {
 	sorter:      FilesSorter,
 	filter:      Option<Filter>,
 	show_hidden: bool,
+
+	// The groups collapsed to their first file, and the rest of them
+	collapsed: HashSet<String>,
+	folded:    HashMap<String, Vec<File>>,
 }
 
 impl Deref for Files {
@@ -110,6 +115,7 @@ impl Files {
 		}
 
 		(self.hidden, self.items) = (hidden, items);
+		self.folded.clear();
 	}
 
 	pub fn update_part(&mut self, files: Vec<File>, ticket: Id) {
@@ -130,6 +136,7 @@ impl Files {
 
 		self.ticket = ticket;
 		self.hidden.clear();
+		self.folded.clear();
 		if !self.items.is_empty() {
 			self.revision += 1;
 			self.items.clear();
@@ -151,6 +158,7 @@ impl Files {
 		self.ticket = FILES_TICKET.next();
 		self.hidden.clear();
 		self.items.clear();
+		self.folded.clear();
 	}
 
 	pub fn update_creating(&mut self, files: Vec<File>) {
@@ -214,6 +222,9 @@ impl Files {
 		if !hidden.is_empty() {
 			self.hidden.retain(|f| !hidden.remove(f.urn()));
 		}
+		if !items.is_empty() {
+			self.folded.values_mut().for_each(|v| v.retain(|f| !items.remove(f.urn())));
+		}
 
 		self.revision += deleted.is_empty().not() as u64;
 		deleted
@@ -236,6 +247,9 @@ impl Files {
 		if !urns.is_empty() {
 			self.hidden.retain(|f| !urns.remove(f.urn()));
 		}
+		if !urns.is_empty() {
+			self.folded.values_mut().for_each(|v| v.retain(|f| !urns.remove(f.urn())));
+		}
 
 		self.revision += deleted.is_empty().not() as u64;
 		deleted
@@ -280,6 +294,12 @@ impl Files {
 		if !items.is_empty() {
 			go!(self.items, items, 1);
 		}
+		for v in self.folded.values_mut() {
+			if items.is_empty() {
+				break;
+			}
+			go!(v, items, 1);
+		}
 		if !hidden.is_empty() {
 			go!(self.hidden, hidden, 0);
 		}
@@ -315,10 +335,56 @@ impl Files {
 		}
 
 		self.version = self.revision;
-		self.sorter.sort(&mut self.items, &self.sizes);
+		self.sort();
 		true
 	}
 
+	/// Sorts only the first `n` items, so that the first screen of a folder that
+	/// is still loading shows in order without waiting for a full sort of all
+	/// items.
+	pub fn catchup_prefix(&mut self, n: usize) -> bool {
+		if self.version == self.revision || self.partial == self.revision {
+			return false;
+		}
+
+		self.partial = self.revision;
+		self.sorter.sort_prefix(&mut self.items, n, &self.sizes)
+	}
+
+	fn sort(&mut self) {
+		for v in mem::take(&mut self.folded).into_values() {
+			self.items.extend(v);
+		}
+		self.sorter.sort(&mut self.items, &self.sizes);
+
+		let group = self.sorter.group;
+		if self.collapsed.is_empty() || group == GroupBy::None {
+			return;
+		}
+
+		// Keep the first file of each collapsed group, which carries its header
+		let mut last: Option<String> = None;
+		for f in mem::take(&mut self.items) {
+			let label = group.label(&f).unwrap_or_default();
+			if last.as_ref() == Some(&label) && self.collapsed.contains(&label) {
+				self.folded.entry(label).or_default().push(f);
+			} else {
+				last = Some(label);
+				self.items.push(f);
+			}
+		}
+	}
+
+	fn unfold(&mut self) {
+		if self.folded.is_empty() {
+			return;
+		}
+		for v in mem::take(&mut self.folded).into_values() {
+			self.items.extend(v);
+		}
+		self.revision += 1;
+	}
+
 	fn split_files(&self, files: impl IntoIterator<Item = File>) -> (Vec<File>, Vec<File>) {
 		if let Some(filter) = &self.filter {
 			files
@@ -334,6 +400,23 @@ impl Files {
 
 impl Files {
 	// --- Items
+	/// An estimate of the bytes taken by all the files, hidden and folded ones
+	/// included, which is what a cap on the memory of folders goes by.
+	pub fn bytes(&self) -> usize {
+		let url = |u: &Url| mem::size_of::<Url>() + u.as_os_str().len();
+		let file = |f: &File| mem::size_of::<File>() + url(&f.url) + f.link_to.as_ref().map_or(0, url);
+
+		let size = |u: &UrnBuf| mem::size_of::<(UrnBuf, u64)>() + u.as_urn().encoded_bytes().len();
+
+		self.all().map(file).sum::<usize>() + self.sizes.keys().map(size).sum::<usize>()
+	}
+
+	/// All the files, hidden and folded ones included.
+	#[inline]
+	pub fn all(&self) -> impl Iterator<Item = &File> {
+		self.items.iter().chain(&self.hidden).chain(self.folded.values().flatten())
+	}
+
 	#[inline]
 	pub fn position(&self, urn: &Urn) -> Option<usize> { self.iter().position(|f| urn == f.urn()) }
 
@@ -369,17 +452,33 @@ impl Files {
 			self.hidden = hidden;
 			if !items.is_empty() {
 				self.items.extend(items);
-				self.sorter.sort(&mut self.items, &self.sizes);
+				self.sort();
 			}
 			return true;
 		}
 
+		self.unfold();
 		let it = mem::take(&mut self.items).into_iter().chain(mem::take(&mut self.hidden));
 		(self.hidden, self.items) = self.split_files(it);
-		self.sorter.sort(&mut self.items, &self.sizes);
+		self.sort();
 		true
 	}
 
+	// --- Collapsed
+	/// Collapses the group named `label` to its first file, or expands it back.
+	pub fn toggle_collapsed(&mut self, label: String) {
+		if !self.collapsed.remove(&label) {
+			self.collapsed.insert(label);
+		}
+		self.revision += 1;
+	}
+
+	/// How many files of the group named `label` are folded, if it's collapsed.
+	#[inline]
+	pub fn folded(&self, label: &str) -> Option<usize> {
+		self.collapsed.contains(label).then(|| self.folded.get(label).map_or(0, |v| v.len()))
+	}
+
 	// --- Show hidden
 	pub fn set_show_hidden(&mut self, state: bool) {
 		if self.show_hidden == state {
@@ -387,6 +486,9 @@ impl Files {
 		}
 
 		self.show_hidden = state;
+		if !self.show_hidden {
+			self.unfold();
+		}
 		if self.show_hidden && self.hidden.is_empty() {
 			return;
 		} else if !self.show_hidden && self.items.is_empty() {
@@ -1,6 +1,7 @@
 use std::{ffi::OsStr, fmt::Display, ops::Range};
 
 use anyhow::Result;
+use globset::GlobBuilder;
 use regex::bytes::{Regex, RegexBuilder};
 use yazi_shared::event::Cmd;
 
@@ -18,10 +19,22 @@ impl Filter {
 			}
 			FilterCase::Sensitive => Regex::new(s)?,
 			FilterCase::Insensitive => RegexBuilder::new(s).case_insensitive(true).build()?,
+			FilterCase::Fuzzy => {
+				let uppercase = s.chars().any(|c| c.is_uppercase());
+				let pattern: Vec<_> =
+					s.chars().map(|c| regex::escape(c.encode_utf8(&mut [0; 4]))).collect();
+				RegexBuilder::new(&pattern.join(".*?")).case_insensitive(!uppercase).build()?
+			}
 		};
 		Ok(Self { raw: s.to_owned(), regex })
 	}
 
+	/// Create a filter from a glob, e.g. `*.rs`, which matches the whole name.
+	pub fn glob(s: &str) -> Result<Self> {
+		let glob = GlobBuilder::new(s).literal_separator(true).backslash_escape(false).build()?;
+		Ok(Self { raw: s.to_owned(), regex: Regex::new(glob.regex())? })
+	}
+
 	#[inline]
 	pub fn matches(&self, name: &OsStr) -> bool { self.regex.is_match(name.as_encoded_bytes()) }
 
@@ -45,10 +58,15 @@ pub enum FilterCase {
 	#[default]
 	Sensitive,
 	Insensitive,
+	/// The characters in order, but not necessarily next to each other.
+	Fuzzy,
 }
 
 impl From<&Cmd> for FilterCase {
 	fn from(c: &Cmd) -> Self {
+		if c.bool("fuzzy") {
+			return Self::Fuzzy;
+		}
 		match (c.bool("smart"), c.bool("insensitive")) {
 			(true, _) => Self::Smart,
 			(_, false) => Self::Sensitive,
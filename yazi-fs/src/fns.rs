@@ -1,9 +1,9 @@
-use std::{borrow::Cow, collections::{HashMap, HashSet, VecDeque}, ffi::{OsStr, OsString}, path::{Path, PathBuf}};
+use std::{borrow::Cow, collections::{HashMap, HashSet, VecDeque}, ffi::{OsStr, OsString}, path::{Path, PathBuf}, sync::Arc};
 
 use anyhow::{Result, bail};
 use tokio::{fs, io::{self, AsyncWriteExt}, select, sync::{mpsc, oneshot}, time};
 
-use super::Cha;
+use super::{Cha, Throttle, copy_throttled};
 
 #[inline]
 pub async fn must_exists(p: impl AsRef<Path>) -> bool { fs::symlink_metadata(p).await.is_ok() }
@@ -61,20 +61,25 @@ async fn _paths_to_same_file(a: &Path, b: &Path) -> std::io::Result<bool> {
 			.await?;
 
 		tokio::task::spawn_blocking(move || {
-			let mut buf = [0u16; MAX_PATH as usize];
-			let len = unsafe {
-				GetFinalPathNameByHandleW(
-					file.as_raw_handle() as HANDLE,
-					buf.as_mut_ptr(),
-					buf.len() as u32,
-					VOLUME_NAME_DOS,
-				)
-			};
-
-			if len == 0 {
-				Err(std::io::Error::last_os_error())
-			} else {
-				Ok(PathBuf::from(OsString::from_wide(&buf[0..len as usize])))
+			let mut buf = vec![0u16; MAX_PATH as usize];
+			loop {
+				let len = unsafe {
+					GetFinalPathNameByHandleW(
+						file.as_raw_handle() as HANDLE,
+						buf.as_mut_ptr(),
+						buf.len() as u32,
+						VOLUME_NAME_DOS,
+					)
+				} as usize;
+
+				if len == 0 {
+					return Err(std::io::Error::last_os_error());
+				} else if len > buf.len() {
+					// Long paths don't fit in `MAX_PATH`, retry with the required size
+					buf.resize(len, 0);
+				} else {
+					return Ok(PathBuf::from(OsString::from_wide(&buf[..len])));
+				}
 			}
 		})
 		.await?
@@ -83,6 +88,85 @@ async fn _paths_to_same_file(a: &Path, b: &Path) -> std::io::Result<bool> {
 	Ok(final_name(a).await? == final_name(b).await?)
 }
 
+/// Whether `a` and `b` end up at the very same file, with either of them
+/// followed if it's a symlink, i.e. on the same device with the same inode, or
+/// the same file ID on Windows, be it through another path or a hardlink.
+///
+/// Unlike [`paths_to_same_file`], it's also true for two hardlinks, so writing
+/// to or removing `b` would affect `a`.
+#[inline]
+pub async fn same_file(a: impl AsRef<Path>, b: impl AsRef<Path>) -> bool {
+	_same_file(a.as_ref(), b.as_ref()).await.unwrap_or(false)
+}
+
+#[cfg(unix)]
+async fn _same_file(a: &Path, b: &Path) -> io::Result<bool> {
+	use std::{fs::Metadata, os::unix::fs::MetadataExt};
+
+	let id = |m: Metadata| (m.dev(), m.ino());
+	let b = [Some(id(fs::symlink_metadata(b).await?)), fs::metadata(b).await.ok().map(id)];
+
+	Ok(
+		fs::symlink_metadata(a).await.is_ok_and(|m| b.contains(&Some(id(m))))
+			|| fs::metadata(a).await.is_ok_and(|m| b.contains(&Some(id(m)))),
+	)
+}
+
+#[cfg(windows)]
+async fn _same_file(a: &Path, b: &Path) -> io::Result<bool> {
+	use std::os::windows::{fs::OpenOptionsExt, io::AsRawHandle};
+
+	use windows_sys::Win32::{Foundation::HANDLE, Storage::FileSystem::{BY_HANDLE_FILE_INFORMATION, FILE_FLAG_BACKUP_SEMANTICS, FILE_FLAG_OPEN_REPARSE_POINT, GetFileInformationByHandle}};
+
+	fn id(p: &Path, follow: bool) -> io::Result<(u32, u64)> {
+		let flags = if follow { 0 } else { FILE_FLAG_OPEN_REPARSE_POINT };
+		let file = std::fs::OpenOptions::new()
+			.access_mode(0)
+			.custom_flags(FILE_FLAG_BACKUP_SEMANTICS | flags)
+			.open(p)?;
+
+		let mut info: BY_HANDLE_FILE_INFORMATION = unsafe { std::mem::zeroed() };
+		if unsafe { GetFileInformationByHandle(file.as_raw_handle() as HANDLE, &mut info) } == 0 {
+			return Err(io::Error::last_os_error());
+		}
+		Ok((info.dwVolumeSerialNumber, (info.nFileIndexHigh as u64) << 32 | info.nFileIndexLow as u64))
+	}
+
+	let (a, b) = (a.to_owned(), b.to_owned());
+	tokio::task::spawn_blocking(move || {
+		let b = [Some(id(&b, false)?), id(&b, true).ok()];
+		Ok(
+			id(&a, false).is_ok_and(|a| b.contains(&Some(a)))
+				|| id(&a, true).is_ok_and(|a| b.contains(&Some(a))),
+		)
+	})
+	.await?
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_same_file() {
+	fs::remove_dir_all("/tmp/yazi-same").await.ok();
+	fs::create_dir_all("/tmp/yazi-same/dir").await.unwrap();
+	fs::File::create("/tmp/yazi-same/dir/a").await.unwrap();
+	fs::File::create("/tmp/yazi-same/dir/b").await.unwrap();
+	fs::hard_link("/tmp/yazi-same/dir/a", "/tmp/yazi-same/dir/hard").await.unwrap();
+	fs::symlink("/tmp/yazi-same/dir/a", "/tmp/yazi-same/dir/soft").await.unwrap();
+	fs::symlink("dir", "/tmp/yazi-same/link-dir").await.unwrap();
+
+	let a = "/tmp/yazi-same/dir/a";
+	assert!(same_file(a, "/tmp/yazi-same/link-dir/a").await);
+	assert!(same_file(a, "/tmp/yazi-same/dir/hard").await);
+	assert!(same_file("/tmp/yazi-same/dir/soft", a).await);
+	assert!(same_file(a, "/tmp/yazi-same/dir/soft").await);
+	assert!(!same_file(a, "/tmp/yazi-same/dir/b").await);
+	assert!(!same_file(a, "/tmp/yazi-same/dir/none").await);
+
+	assert!(paths_to_same_file(a, "/tmp/yazi-same/link-dir/a").await);
+	assert!(!paths_to_same_file(a, "/tmp/yazi-same/dir/hard").await);
+	assert!(!paths_to_same_file(a, "/tmp/yazi-same/dir/soft").await);
+}
+
 pub async fn copy_and_seal(from: &Path, to: &Path) -> io::Result<()> {
 	let b = fs::read(from).await?;
 	ok_or_not_found(remove_sealed(to).await)?;
@@ -111,7 +195,7 @@ pub async fn remove_sealed(p: &Path) -> io::Result<()> {
 
 pub async fn realname(p: &Path) -> Option<OsString> {
 	let name = p.file_name()?;
-	if p == fs::canonicalize(p).await.ok()? {
+	if p == crate::canonicalize(p).await.ok()? {
 		return None;
 	}
 
@@ -205,10 +289,13 @@ pub async fn calculate_size(path: &Path) -> u64 {
 	total
 }
 
+/// Copies `from` to `to`, reporting the bytes written since the last report,
+/// and `0` once done, with the bandwidth limited by all the `throttles`.
 pub fn copy_with_progress(
 	from: &Path,
 	to: &Path,
 	cha: Cha,
+	throttles: Vec<Arc<Throttle>>,
 ) -> mpsc::Receiver<Result<u64, io::Error>> {
 	let (tx, rx) = mpsc::channel(1);
 	let (tick_tx, mut tick_rx) = oneshot::channel();
@@ -217,7 +304,7 @@ pub fn copy_with_progress(
 		let (from, to) = (from.to_owned(), to.to_owned());
 
 		async move {
-			tick_tx.send(_copy_with_progress(from, to, cha).await).ok();
+			tick_tx.send(_copy_with_progress(from, to, cha, throttles).await).ok();
 		}
 	});
 
@@ -262,7 +349,12 @@ pub fn copy_with_progress(
 	rx
 }
 
-async fn _copy_with_progress(from: PathBuf, to: PathBuf, cha: Cha) -> io::Result<u64> {
+async fn _copy_with_progress(
+	from: PathBuf,
+	to: PathBuf,
+	cha: Cha,
+	throttles: Vec<Arc<Throttle>>,
+) -> io::Result<u64> {
 	let mut ft = std::fs::FileTimes::new();
 	cha.atime.map(|t| ft = ft.set_accessed(t));
 	cha.mtime.map(|t| ft = ft.set_modified(t));
@@ -290,7 +382,11 @@ async fn _copy_with_progress(from: PathBuf, to: PathBuf, cha: Cha) -> io::Result
 				.truncate(true)
 				.open(to)?;
 
-			let written = std::io::copy(&mut reader, &mut writer)?;
+			let written = if throttles.is_empty() {
+				std::io::copy(&mut reader, &mut writer)?
+			} else {
+				copy_throttled(&mut reader, &mut writer, &throttles)?
+			};
 			unsafe { libc::fchmod(writer.as_raw_fd(), cha.mode) };
 			writer.set_times(ft).ok();
 
@@ -302,7 +398,14 @@ async fn _copy_with_progress(from: PathBuf, to: PathBuf, cha: Cha) -> io::Result
 	#[cfg(not(any(target_os = "linux", target_os = "android")))]
 	{
 		tokio::task::spawn_blocking(move || {
-			let written = std::fs::copy(from, &to)?;
+			let written = if throttles.is_empty() {
+				std::fs::copy(from, &to)?
+			} else {
+				let mut reader = std::fs::File::open(from)?;
+				let written = copy_throttled(&mut reader, &mut std::fs::File::create(&to)?, &throttles)?;
+				std::fs::set_permissions(&to, reader.metadata()?.permissions())?;
+				written
+			};
 			std::fs::File::options().write(true).open(to).and_then(|f| f.set_times(ft)).ok();
 			Ok(written)
 		})
@@ -0,0 +1,77 @@
+use std::{io, path::{Path, PathBuf}};
+
+/// What the status bar shows about the hovered file beyond its [`crate::Cha`],
+/// fetched on demand since resolving the owner and group may be slow, e.g.
+/// with LDAP.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Info {
+	/// The permissions and their octal form, e.g. `-rwsr-xr-x 4755`.
+	pub perm:   Option<String>,
+	/// The names of the owner and group, or their IDs if they have none.
+	pub owner:  Option<String>,
+	pub group:  Option<String>,
+	pub nlink:  u64,
+	/// The inode on Unix, and the file index on Windows.
+	pub ino:    u64,
+	/// Where it points to, if it's a symlink.
+	pub target: Option<PathBuf>,
+}
+
+impl Info {
+	pub async fn fetch(path: &Path) -> io::Result<Self> {
+		let path = path.to_owned();
+		tokio::task::spawn_blocking(move || Self::fetch_sync(&path)).await?
+	}
+
+	#[cfg(unix)]
+	fn fetch_sync(path: &Path) -> io::Result<Self> {
+		use std::os::unix::fs::MetadataExt;
+
+		use uzers::{Groups, Users};
+		use yazi_shared::USERS_CACHE;
+
+		let meta = std::fs::symlink_metadata(path)?;
+		let mode = meta.mode() as libc::mode_t;
+
+		Ok(Self {
+			perm:   Some(format!("{} {:04o}", crate::permissions(mode, false), mode & 0o7777)),
+			owner:  Some(
+				USERS_CACHE
+					.get_user_by_uid(meta.uid())
+					.map_or_else(|| meta.uid().to_string(), |u| u.name().to_string_lossy().into_owned()),
+			),
+			group:  Some(
+				USERS_CACHE
+					.get_group_by_gid(meta.gid())
+					.map_or_else(|| meta.gid().to_string(), |g| g.name().to_string_lossy().into_owned()),
+			),
+			nlink:  meta.nlink(),
+			ino:    meta.ino(),
+			target: meta.is_symlink().then(|| std::fs::read_link(path).ok()).flatten(),
+		})
+	}
+
+	#[cfg(windows)]
+	fn fetch_sync(path: &Path) -> io::Result<Self> {
+		use std::os::windows::{fs::OpenOptionsExt, io::AsRawHandle};
+
+		use windows_sys::Win32::{Foundation::HANDLE, Storage::FileSystem::{BY_HANDLE_FILE_INFORMATION, FILE_FLAG_BACKUP_SEMANTICS, FILE_FLAG_OPEN_REPARSE_POINT, GetFileInformationByHandle}};
+
+		let file = std::fs::OpenOptions::new()
+			.access_mode(0)
+			.custom_flags(FILE_FLAG_BACKUP_SEMANTICS | FILE_FLAG_OPEN_REPARSE_POINT)
+			.open(path)?;
+
+		let mut info: BY_HANDLE_FILE_INFORMATION = unsafe { std::mem::zeroed() };
+		if unsafe { GetFileInformationByHandle(file.as_raw_handle() as HANDLE, &mut info) } == 0 {
+			return Err(io::Error::last_os_error());
+		}
+
+		Ok(Self {
+			nlink: info.nNumberOfLinks as u64,
+			ino: (info.nFileIndexHigh as u64) << 32 | info.nFileIndexLow as u64,
+			target: std::fs::read_link(path).ok(),
+			..Default::default()
+		})
+	}
+}
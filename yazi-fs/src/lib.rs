@@ -2,7 +2,7 @@
 
 yazi_macro::mod_pub!(mounts);
 
-yazi_macro::mod_flat!(cha cwd file files filter fns op path sorter sorting stage step xdg);
+yazi_macro::mod_flat!(case cha conflict cwd expand file files filter fns info op path shortcut sorter sorting stage step throttle xattr xdg);
 
 pub fn init() {
 	CWD.init(<_>::default());
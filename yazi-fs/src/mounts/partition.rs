@@ -17,6 +17,23 @@ impl Partition {
 		!matches!(b, b"exfat" | b"fuse.rclone")
 	}
 
+	pub fn remote(&self) -> bool {
+		let b: &[u8] = self.fstype.as_ref().map_or(b"", |s| s.as_encoded_bytes());
+		matches!(
+			b,
+			b"nfs"
+				| b"nfs4"
+				| b"cifs"
+				| b"smb3"
+				| b"smbfs"
+				| b"9p"
+				| b"afpfs"
+				| b"davfs"
+				| b"fuse.sshfs"
+				| b"fuse.rclone"
+		)
+	}
+
 	#[rustfmt::skip]
 	pub fn systemic(&self) -> bool {
 		let _b: &[u8] = self.fstype.as_ref().map_or(b"", |s| s.as_encoded_bytes());
@@ -1,4 +1,4 @@
-use std::{ops::Deref, sync::Arc};
+use std::{borrow::Cow, ops::Deref, path::Path, sync::Arc};
 
 use parking_lot::RwLock;
 use yazi_shared::RoCell;
@@ -42,4 +42,81 @@ impl Partitions {
 			true
 		}
 	}
+
+	/// The partition mounted at the closest ancestor of `path`, which unlike
+	/// [`Self::by_dev`] also finds the ones without a block device, e.g. NFS.
+	pub fn by_path(&self, path: &Path) -> Option<&Partition> {
+		self
+			.inner
+			.iter()
+			.filter(|p| p.dist.as_ref().is_some_and(|d| path.starts_with(d)))
+			.max_by_key(|p| p.dist.as_ref().map_or(0, |d| d.as_os_str().len()))
+	}
+
+	#[inline]
+	pub fn remote(&self, path: &Path) -> bool { self.network(path).is_some() }
+
+	/// The type of the network filesystem `path` is on, e.g. `nfs`, or `None`
+	/// if it's a local one.
+	pub fn network(&self, path: &Path) -> Option<Cow<'_, str>> {
+		#[cfg(windows)]
+		{
+			Self::remote_windows(path).then_some(Cow::Borrowed("smb"))
+		}
+		#[cfg(not(windows))]
+		{
+			if let Some(p) = self.by_path(path).filter(|p| p.remote()) {
+				return p.fstype.as_ref().map(|s| s.to_string_lossy());
+			}
+
+			#[cfg(target_os = "linux")]
+			return Self::network_linux(path).map(Cow::Borrowed);
+			#[cfg(not(target_os = "linux"))]
+			None
+		}
+	}
+
+	/// By the magic number of the filesystem, for the mounts that aren't known
+	/// yet, or when `/proc/mounts` can't be read.
+	#[cfg(target_os = "linux")]
+	fn network_linux(path: &Path) -> Option<&'static str> {
+		use std::os::unix::ffi::OsStrExt;
+
+		let c = std::ffi::CString::new(path.as_os_str().as_bytes()).ok()?;
+		let mut s: libc::statfs = unsafe { std::mem::zeroed() };
+		if unsafe { libc::statfs(c.as_ptr(), &mut s) } != 0 {
+			return None;
+		}
+
+		Some(match s.f_type as u32 {
+			0x6969 => "nfs",
+			0x517b => "smbfs",
+			0xff53_4d42 => "cifs",
+			0xfe53_4d42 => "smb3",
+			0x0102_1997 => "9p",
+			0x00c3_6400 => "ceph",
+			0x7375_7245 => "coda",
+			0x5346_414f => "afs",
+			0x564c => "ncpfs",
+			_ => return None,
+		})
+	}
+
+	/// UNC shares, or drive letters mapped to them.
+	#[cfg(windows)]
+	fn remote_windows(path: &Path) -> bool {
+		use std::path::{Component, Prefix};
+
+		use windows_sys::Win32::{Storage::FileSystem::GetDriveTypeW, System::WindowsProgramming::DRIVE_REMOTE};
+
+		let Some(Component::Prefix(prefix)) = path.components().next() else { return false };
+		match prefix.kind() {
+			Prefix::UNC(..) | Prefix::VerbatimUNC(..) => true,
+			Prefix::Disk(d) | Prefix::VerbatimDisk(d) => {
+				let root: Vec<u16> = format!("{}:\\", d as char).encode_utf16().chain([0]).collect();
+				unsafe { GetDriveTypeW(root.as_ptr()) == DRIVE_REMOTE }
+			}
+			_ => false,
+		}
+	}
 }
@@ -1,9 +1,9 @@
-use std::{borrow::Cow, env, ffi::OsString, future::Future, io, path::{Component, Path, PathBuf}};
+use std::{borrow::Cow, ffi::{OsStr, OsString}, future::Future, io, path::{Component, Path, PathBuf}};
 
 use tokio::fs;
 use yazi_shared::url::{Loc, Url};
 
-use crate::CWD;
+use crate::{CWD, expand_home, expand_vars};
 
 #[inline]
 pub fn clean_path(path: impl AsRef<Path>) -> PathBuf { _clean_path(path.as_ref()) }
@@ -32,32 +32,22 @@ fn _clean_path(path: &Path) -> PathBuf {
 pub fn expand_path(p: impl AsRef<Path>) -> PathBuf { _expand_path(p.as_ref()) }
 
 fn _expand_path(p: &Path) -> PathBuf {
-	// ${HOME} or $HOME
-	#[cfg(unix)]
-	let re = regex::Regex::new(r"\$(?:\{([^}]+)\}|([a-zA-Z\d_]+))").unwrap();
-
-	// %USERPROFILE%
-	#[cfg(windows)]
-	let re = regex::Regex::new(r"%([^%]+)%").unwrap();
-
-	let s = p.to_string_lossy();
-	let s = re.replace_all(&s, |caps: &regex::Captures| {
-		let name = caps.get(2).or_else(|| caps.get(1)).unwrap();
-		env::var(name.as_str()).unwrap_or_else(|_| caps.get(0).unwrap().as_str().to_owned())
-	});
+	let b = expand_vars(p.as_os_str().as_encoded_bytes());
 
 	// Windows paths that only have a drive letter but no root, e.g. "D:"
 	#[cfg(windows)]
-	if s.len() == 2 {
-		let b = s.as_bytes();
-		if b[1] == b':' && b[0].is_ascii_alphabetic() {
-			return PathBuf::from(s.to_uppercase() + "\\");
-		}
+	if b.len() == 2 && b[1] == b':' && b[0].is_ascii_alphabetic() {
+		return PathBuf::from(format!("{}:\\", b[0].to_ascii_uppercase() as char));
 	}
 
-	let p = Path::new(s.as_ref());
-	if let Ok(rest) = p.strip_prefix("~") {
-		clean_path(dirs::home_dir().unwrap_or_default().join(rest))
+	// SAFETY: only the ASCII-delimited variables are replaced, with bytes from
+	// another `OsStr`
+	let p = Path::new(unsafe { OsStr::from_encoded_bytes_unchecked(&b) });
+
+	#[cfg(windows)]
+	let p = &*strip_verbatim(p);
+	if let Some(p) = expand_home(p) {
+		clean_path(p)
 	} else if p.is_absolute() {
 		clean_path(p)
 	} else {
@@ -192,6 +182,53 @@ pub fn backslash_to_slash(p: &Path) -> Cow<Path> {
 	Cow::Owned(PathBuf::from(unsafe { OsString::from_encoded_bytes_unchecked(out) }))
 }
 
+/// Like [`fs::canonicalize`], but on Windows the `\\?\` prefix is stripped
+/// when the path can be represented without it, so that it's comparable with
+/// the paths we got from elsewhere.
+pub async fn canonicalize(p: impl AsRef<Path>) -> io::Result<PathBuf> {
+	let p = fs::canonicalize(p).await?;
+
+	#[cfg(windows)]
+	let p = match strip_verbatim(&p) {
+		Cow::Borrowed(_) => p,
+		Cow::Owned(p) => p,
+	};
+	Ok(p)
+}
+
+#[cfg(windows)]
+pub fn strip_verbatim(p: &Path) -> Cow<'_, Path> {
+	use std::path::Prefix;
+
+	let mut it = p.components();
+	let Some(Component::Prefix(prefix)) = it.next() else { return Cow::Borrowed(p) };
+
+	let mut out = match prefix.kind() {
+		Prefix::VerbatimDisk(d) => PathBuf::from(format!("{}:\\", d as char)),
+		Prefix::VerbatimUNC(server, share) => {
+			let mut s = OsString::from(r"\\");
+			s.push(server);
+			s.push(r"\");
+			s.push(share);
+			s.push(r"\");
+			PathBuf::from(s)
+		}
+		_ => return Cow::Borrowed(p),
+	};
+
+	for c in it {
+		match c {
+			Component::RootDir => {}
+			// Names ending with a dot or space can only be represented with the prefix
+			Component::Normal(s) if !matches!(s.as_encoded_bytes().last(), Some(b'.' | b' ')) => {
+				out.push(s)
+			}
+			_ => return Cow::Borrowed(p),
+		}
+	}
+	Cow::Owned(out)
+}
+
 #[cfg(test)]
 mod tests {
 	use std::{borrow::Cow, path::Path};
@@ -225,4 +262,22 @@ mod tests {
 		assert("C:\\a", "C:\\a\\b\\c", "..\\..\\");
 		assert("C:\\a\\a\\b", "C:\\a\\b\\b", "..\\..\\a\\b");
 	}
+
+	#[cfg(windows)]
+	#[test]
+	fn test_strip_verbatim() {
+		use super::strip_verbatim;
+
+		fn assert(path: &str, res: &str) {
+			assert_eq!(strip_verbatim(Path::new(path)), Path::new(res));
+		}
+
+		assert(r"C:\a\b", r"C:\a\b");
+		assert(r"\\?\C:\a\b", r"C:\a\b");
+		assert(r"\\?\C:\", r"C:\");
+		assert(r"\\?\UNC\server\share\a", r"\\server\share\a");
+		assert(r"\\?\C:\a.", r"\\?\C:\a.");
+		assert(r"\\?\C:\a\..\b", r"\\?\C:\a\..\b");
+		assert(r"\\.\pipe\a", r"\\.\pipe\a");
+	}
 }
@@ -0,0 +1,73 @@
+use std::{ffi::OsString, path::Path};
+
+use tokio::fs;
+
+/// Resolve the target of a shortcut file, i.e. `.url` (Windows Internet
+/// Shortcut), `.webloc` (macOS Website Location), or `.desktop` (freedesktop
+/// `Type=Link` entry), returns `None` if it's not a shortcut.
+pub async fn shortcut_target(path: impl AsRef<Path>) -> Option<OsString> {
+	let path = path.as_ref();
+	let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+	if !matches!(ext.as_str(), "url" | "webloc" | "desktop") {
+		return None;
+	}
+
+	let content = fs::read(path).await.ok().filter(|b| b.len() <= 64 * 1024)?;
+	parse_shortcut(&ext, &String::from_utf8_lossy(&content)).map(OsString::from)
+}
+
+fn parse_shortcut(ext: &str, content: &str) -> Option<String> {
+	match ext {
+		"url" => ini_value(content, "InternetShortcut", "URL"),
+		"desktop" => ini_value(content, "Desktop Entry", "URL"),
+		"webloc" => {
+			let (_, rest) = content.split_once("<key>URL</key>")?;
+			let rest = rest.trim_start().strip_prefix("<string>")?;
+			let (url, _) = rest.split_once("</string>")?;
+			Some(url.trim().replace("&amp;", "&")).filter(|s| !s.is_empty())
+		}
+		_ => None,
+	}
+}
+
+fn ini_value(content: &str, section: &str, key: &str) -> Option<String> {
+	let mut inside = false;
+	for line in content.lines().map(str::trim) {
+		if let Some(s) = line.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+			inside = s.eq_ignore_ascii_case(section);
+		} else if let Some((k, v)) = line.split_once('=').filter(|_| inside) {
+			if k.trim().eq_ignore_ascii_case(key) && !v.trim().is_empty() {
+				return Some(v.trim().to_owned());
+			}
+		}
+	}
+	None
+}
+
+#[cfg(test)]
+mod tests {
+	use super::parse_shortcut;
+
+	#[test]
+	fn test_parse_shortcut() {
+		let url = "[InternetShortcut]\r\nIDList=\r\nURL=https://example.com/?a=1\r\n";
+		assert_eq!(parse_shortcut("url", url).as_deref(), Some("https://example.com/?a=1"));
+
+		let desktop = "[Desktop Entry]\nType=Link\nName=Example\nURL=https://example.com\n";
+		assert_eq!(parse_shortcut("desktop", desktop).as_deref(), Some("https://example.com"));
+
+		let app = "[Desktop Entry]\nType=Application\nExec=foo %U\n";
+		assert_eq!(parse_shortcut("desktop", app), None);
+
+		let webloc = r#"<?xml version="1.0" encoding="UTF-8"?>
+<plist version="1.0">
+<dict>
+	<key>URL</key>
+	<string>https://example.com/?a=1&amp;b=2</string>
+</dict>
+</plist>"#;
+		assert_eq!(parse_shortcut("webloc", webloc).as_deref(), Some("https://example.com/?a=1&b=2"));
+
+		assert_eq!(parse_shortcut("url", "[Other]\nURL=https://example.com\n"), None);
+	}
+}
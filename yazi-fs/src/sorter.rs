@@ -1,8 +1,8 @@
-use std::{cmp::Ordering, collections::HashMap, mem};
+use std::{borrow::Cow, cmp::Ordering, collections::HashMap, mem};
 
-use yazi_shared::{LcgRng, natsort, translit::Transliterator, url::UrnBuf};
+use yazi_shared::{LcgRng, natsort, natsort_key, translit::Transliterator, url::UrnBuf};
 
-use crate::{File, SortBy};
+use crate::{File, GroupBy, SortBy};
 
 #[derive(Clone, Copy, Default, PartialEq)]
 pub struct FilesSorter {
@@ -10,6 +10,7 @@ pub struct FilesSorter {
 	pub sensitive: bool,
 	pub reverse:   bool,
 	pub dir_first: bool,
+	pub group:     GroupBy,
 	pub translit:  bool,
 }
 
@@ -19,7 +20,65 @@ impl FilesSorter {
 			return;
 		}
 
-		let by_alphabetical = |a: &File, b: &File| {
+		match self.by {
+			SortBy::None => {}
+			SortBy::Natural => self.sort_naturally(items),
+			SortBy::Random => {
+				let mut rng = LcgRng::default();
+				items.sort_unstable_by(|a, b| self.cmp(rng.next(), rng.next(), self.promote(a, b)))
+			}
+			_ => items.sort_unstable_by(|a, b| self.compare(a, b, sizes)),
+		}
+	}
+
+	/// Moves the first `n` items of the sorted order to the front in order,
+	/// leaving the rest unordered, which is much cheaper than a full sort for a
+	/// large `items`.
+	pub(super) fn sort_prefix(
+		&self,
+		items: &mut [File],
+		n: usize,
+		sizes: &HashMap<UrnBuf, u64>,
+	) -> bool {
+		if n == 0 || n >= items.len() || matches!(self.by, SortBy::None | SortBy::Random) {
+			return false;
+		} else if self.by != SortBy::Natural {
+			items.select_nth_unstable_by(n, |a, b| self.compare(a, b, sizes));
+			items[..n].sort_unstable_by(|a, b| self.compare(a, b, sizes));
+			return true;
+		}
+
+		let keys: Vec<_> = items.iter().map(|f| self.natural_key(f)).collect();
+		let cmp = |&a: &usize, &b: &usize| self.cmp_naturally(&items[a], &items[b], &keys[a], &keys[b]);
+
+		let mut indices: Vec<usize> = (0..items.len()).collect();
+		indices.select_nth_unstable_by(n, cmp);
+		indices[..n].sort_unstable_by(cmp);
+		drop(keys);
+
+		// Take out the first `n`, and move the ones in their place to the holes they
+		// left
+		let mut taken = vec![false; items.len()];
+		let first: Vec<_> = indices[..n]
+			.iter()
+			.map(|&i| {
+				taken[i] = true;
+				mem::take(&mut items[i])
+			})
+			.collect();
+
+		let holes = (n..items.len()).filter(|&i| taken[i]);
+		let displaced = (0..n).filter(|&i| !taken[i]);
+		for (h, d) in holes.zip(displaced) {
+			items.swap(h, d);
+		}
+
+		items.iter_mut().zip(first).for_each(|(slot, f)| *slot = f);
+		true
+	}
+
+	fn compare(&self, a: &File, b: &File, sizes: &HashMap<UrnBuf, u64>) -> Ordering {
+		let by_alphabetical = || {
 			if self.sensitive {
 				self.cmp(a.urn().encoded_bytes(), b.urn().encoded_bytes(), self.promote(a, b))
 			} else {
@@ -27,18 +86,16 @@ impl FilesSorter {
 			}
 		};
 
-		match self.by {
-			SortBy::None => {}
-			SortBy::Mtime => items.sort_unstable_by(|a, b| {
-				let ord = self.cmp(a.mtime, b.mtime, self.promote(a, b));
-				if ord == Ordering::Equal { by_alphabetical(a, b) } else { ord }
-			}),
-			SortBy::Btime => items.sort_unstable_by(|a, b| {
-				let ord = self.cmp(a.btime, b.btime, self.promote(a, b));
-				if ord == Ordering::Equal { by_alphabetical(a, b) } else { ord }
-			}),
-			SortBy::Extension => items.sort_unstable_by(|a, b| {
-				let ord = if self.sensitive {
+		let ord = match self.by {
+			SortBy::None | SortBy::Random => return Ordering::Equal,
+			SortBy::Alphabetical => return by_alphabetical(),
+			SortBy::Natural => {
+				return self.cmp_naturally(a, b, &self.natural_key(a), &self.natural_key(b));
+			}
+			SortBy::Mtime => self.cmp(a.mtime, b.mtime, self.promote(a, b)),
+			SortBy::Btime => self.cmp(a.btime, b.btime, self.promote(a, b)),
+			SortBy::Extension => {
+				if self.sensitive {
 					self.cmp(a.url.extension(), b.url.extension(), self.promote(a, b))
 				} else {
 					self.cmp_insensitive(
@@ -46,48 +103,48 @@ impl FilesSorter {
 						b.url.extension().map_or(&[], |s| s.as_encoded_bytes()),
 						self.promote(a, b),
 					)
-				};
-				if ord == Ordering::Equal { by_alphabetical(a, b) } else { ord }
-			}),
-			SortBy::Alphabetical => items.sort_unstable_by(by_alphabetical),
-			SortBy::Natural => self.sort_naturally(items),
-			SortBy::Size => items.sort_unstable_by(|a, b| {
+				}
+			}
+			SortBy::Size => {
 				let aa = if a.is_dir() { sizes.get(a.urn()).copied() } else { None };
 				let bb = if b.is_dir() { sizes.get(b.urn()).copied() } else { None };
-				let ord = self.cmp(aa.unwrap_or(a.len), bb.unwrap_or(b.len), self.promote(a, b));
-				if ord == Ordering::Equal { by_alphabetical(a, b) } else { ord }
-			}),
-			SortBy::Random => {
-				let mut rng = LcgRng::default();
-				items.sort_unstable_by(|a, b| self.cmp(rng.next(), rng.next(), self.promote(a, b)))
+				self.cmp(aa.unwrap_or(a.len), bb.unwrap_or(b.len), self.promote(a, b))
 			}
-		}
+		};
+		if ord == Ordering::Equal { by_alphabetical() } else { ord }
 	}
 
 	fn sort_naturally(&self, items: &mut Vec<File>) {
+		// The keys are computed once for each item, rather than in each comparison
+		let keys: Vec<_> = items.iter().map(|f| self.natural_key(f)).collect();
+
 		let mut indices: Vec<usize> = (0..items.len()).collect();
-		indices.sort_unstable_by(|&a, &b| {
-			let (a, b) = (&items[a], &items[b]);
+		indices.sort_unstable_by(|&a, &b| self.cmp_naturally(&items[a], &items[b], &keys[a], &keys[b]));
 
-			let promote = self.promote(a, b);
-			if promote != Ordering::Equal {
-				return promote;
-			}
+		drop(keys);
+		*items = indices.into_iter().map(|i| mem::take(&mut items[i])).collect();
+	}
 
-			let ordering = if self.translit {
-				natsort(
-					a.urn().encoded_bytes().transliterate().as_bytes(),
-					b.urn().encoded_bytes().transliterate().as_bytes(),
-					!self.sensitive,
-				)
-			} else {
-				natsort(a.urn().encoded_bytes(), b.urn().encoded_bytes(), !self.sensitive)
-			};
+	fn natural_key<'a>(&self, file: &'a File) -> Cow<'a, [u8]> {
+		let name = file.urn().encoded_bytes();
+		if self.translit && !name.is_ascii() {
+			Cow::Owned(natsort_key(name.transliterate().as_bytes()).into_owned())
+		} else {
+			natsort_key(name)
+		}
+	}
 
-			if self.reverse { ordering.reverse() } else { ordering }
-		});
+	fn cmp_naturally(&self, a: &File, b: &File, ka: &[u8], kb: &[u8]) -> Ordering {
+		let promote = self.promote(a, b);
+		if promote != Ordering::Equal {
+			return promote;
+		}
 
-		*items = indices.into_iter().map(|i| mem::take(&mut items[i])).collect();
+		// Fall back to the names for a stable order of the ones with the same key
+		let ordering = natsort(ka, kb, !self.sensitive)
+			.then_with(|| a.urn().encoded_bytes().cmp(b.urn().encoded_bytes()));
+
+		if self.reverse { ordering.reverse() } else { ordering }
 	}
 
 	#[inline(always)]
@@ -121,6 +178,51 @@ impl FilesSorter {
 
 	#[inline(always)]
 	fn promote(&self, a: &File, b: &File) -> Ordering {
-		if self.dir_first { b.is_dir().cmp(&a.is_dir()) } else { Ordering::Equal }
+		let dir_first = if self.dir_first { b.is_dir().cmp(&a.is_dir()) } else { Ordering::Equal };
+		dir_first.then_with(|| self.group.cmp(a, b))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use yazi_shared::url::Url;
+
+	use super::*;
+
+	#[test]
+	fn test_sort_prefix() {
+		let names = ["f9", "a1", "c3", "b2", "e10", "d4", "a10", "z", "b1"];
+		let files = || -> Vec<_> {
+			names.iter().map(|n| File::from_dummy(Url::from(format!("/tmp/{n}")), None)).collect()
+		};
+
+		for by in [SortBy::Alphabetical, SortBy::Natural] {
+			let sorter = FilesSorter { by, ..Default::default() };
+			let mut full = files();
+			sorter.sort(&mut full, &Default::default());
+
+			let mut prefix = files();
+			assert!(sorter.sort_prefix(&mut prefix, 4, &Default::default()));
+			assert_eq!(
+				prefix[..4].iter().map(|f| f.urn()).collect::<Vec<_>>(),
+				full[..4].iter().map(|f| f.urn()).collect::<Vec<_>>()
+			);
+		}
+	}
+
+	#[test]
+	fn test_group() {
+		let names = ["b.rs", "A.md", "c.RS", "a.rs", "1.md", "z"];
+		let sorted = |group| {
+			let mut files: Vec<_> =
+				names.iter().map(|n| File::from_dummy(Url::from(format!("/tmp/{n}")), None)).collect();
+			let sorter = FilesSorter { by: SortBy::Natural, reverse: true, group, ..Default::default() };
+			sorter.sort(&mut files, &Default::default());
+			files.iter().map(|f| f.name().to_str().unwrap().to_owned()).collect::<Vec<_>>()
+		};
+
+		// The groups aren't reversed, only the files in them
+		assert_eq!(sorted(GroupBy::Extension), ["z", "A.md", "1.md", "c.RS", "b.rs", "a.rs"]);
+		assert_eq!(sorted(GroupBy::FirstLetter), ["1.md", "a.rs", "A.md", "b.rs", "c.RS", "z"]);
 	}
 }
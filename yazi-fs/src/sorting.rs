@@ -1,7 +1,10 @@
-use std::{fmt::Display, str::FromStr};
+use std::{cmp::Ordering, fmt::Display, str::FromStr, time::SystemTime};
 
+use chrono::{DateTime, Datelike, Local, NaiveDate};
 use serde::{Deserialize, Serialize};
 
+use crate::File;
+
 #[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 pub enum SortBy {
@@ -38,3 +41,119 @@ impl Display for SortBy {
 		})
 	}
 }
+
+/// How the files are grouped, before they're sorted within each group.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum GroupBy {
+	#[default]
+	None,
+	DirFirst,
+	Extension,
+	FirstLetter,
+	Mtime,
+}
+
+impl GroupBy {
+	/// The order of the groups of `a` and `b`, which isn't reversed with the
+	/// sorting.
+	pub fn cmp(self, a: &File, b: &File) -> Ordering {
+		match self {
+			Self::None => Ordering::Equal,
+			Self::DirFirst => b.is_dir().cmp(&a.is_dir()),
+			Self::Extension => {
+				fn ext(f: &File) -> impl Iterator<Item = u8> + '_ {
+					f.url
+						.extension()
+						.map_or(&[][..], |s| s.as_encoded_bytes())
+						.iter()
+						.map(u8::to_ascii_lowercase)
+				}
+				ext(a).cmp(ext(b))
+			}
+			Self::FirstLetter => Self::first_letter(a).cmp(&Self::first_letter(b)),
+			Self::Mtime => {
+				let today = Local::now().date_naive();
+				Self::date_bucket(a.mtime, today).cmp(&Self::date_bucket(b.mtime, today))
+			}
+		}
+	}
+
+	/// The name of the group of `file`, or `None` if not grouped.
+	pub fn label(self, file: &File) -> Option<String> {
+		Some(match self {
+			Self::None => return None,
+			Self::DirFirst => if file.is_dir() { "Directories" } else { "Files" }.to_owned(),
+			Self::Extension => match file.url.extension() {
+				Some(s) => format!(".{}", s.to_string_lossy().to_lowercase()),
+				None => "No extension".to_owned(),
+			},
+			Self::FirstLetter => Self::first_letter(file).to_string(),
+			Self::Mtime => {
+				let today = Local::now().date_naive();
+				match Self::date_bucket(file.mtime, today) {
+					0 => "Today".to_owned(),
+					1 => "Yesterday".to_owned(),
+					2 => "This week".to_owned(),
+					u32::MAX => "Unknown".to_owned(),
+					n => {
+						let months = today.year() as u32 * 12 + today.month0() - (n - 3);
+						let date = NaiveDate::from_ymd_opt((months / 12) as i32, months % 12 + 1, 1);
+						date.map_or_else(|| "Unknown".to_owned(), |d| d.format("%B %Y").to_string())
+					}
+				}
+			}
+		})
+	}
+
+	// Newer ones come first: today, yesterday, this week, and then the months
+	// back from this one, where those without a time are the last
+	fn date_bucket(mtime: Option<SystemTime>, today: NaiveDate) -> u32 {
+		let Some(date) = mtime.map(|t| DateTime::<Local>::from(t).date_naive()) else {
+			return u32::MAX;
+		};
+
+		let days = (today - date).num_days();
+		if days <= 0 {
+			0
+		} else if days == 1 {
+			1
+		} else if days <= today.weekday().num_days_from_monday() as i64 {
+			2
+		} else {
+			let months = |d: NaiveDate| d.year() as i64 * 12 + d.month0() as i64;
+			3 + (months(today) - months(date)).clamp(0, u32::MAX as i64 - 4) as u32
+		}
+	}
+
+	// Those not starting with a letter are all under `#`, which comes first
+	fn first_letter(file: &File) -> char {
+		file
+			.name()
+			.to_str()
+			.and_then(|s| s.chars().next())
+			.filter(|c| c.is_alphabetic())
+			.and_then(|c| c.to_uppercase().next())
+			.unwrap_or('#')
+	}
+}
+
+impl FromStr for GroupBy {
+	type Err = serde::de::value::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::deserialize(serde::de::value::StrDeserializer::new(s))
+	}
+}
+
+impl Display for GroupBy {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(match self {
+			Self::None => "none",
+			Self::DirFirst => "dir-first",
+			Self::Extension => "extension",
+			Self::FirstLetter => "first-letter",
+			Self::Mtime => "mtime",
+		})
+	}
+}
@@ -0,0 +1,82 @@
+use std::{io::{self, Read, Write}, sync::Arc, thread, time::{Duration, Instant}};
+
+use parking_lot::Mutex;
+
+/// A limit of bytes per second, shared by all the transfers it's passed to.
+#[derive(Debug)]
+pub struct Throttle {
+	rate:  u64,
+	// When it was last taken from, and the bytes still allowed until then,
+	// which goes negative when taken ahead
+	state: Mutex<(Instant, f64)>,
+}
+
+impl Throttle {
+	pub fn new(rate: u64) -> Self {
+		Self { rate: rate.max(1), state: Mutex::new((Instant::now(), rate as f64)) }
+	}
+
+	#[inline]
+	pub fn rate(&self) -> u64 { self.rate }
+
+	/// Takes `n` bytes, blocking the thread until they are within the limit.
+	pub fn take(&self, n: usize) {
+		let rate = self.rate as f64;
+		let wait = {
+			let mut state = self.state.lock();
+			let now = Instant::now();
+
+			// Allows a burst of up to a second
+			state.1 = (state.1 + now.duration_since(state.0).as_secs_f64() * rate).min(rate);
+			state.0 = now;
+			state.1 -= n as f64;
+
+			if state.1 >= 0.0 { Duration::ZERO } else { Duration::from_secs_f64(-state.1 / rate) }
+		};
+
+		if !wait.is_zero() {
+			thread::sleep(wait);
+		}
+	}
+}
+
+/// Like [`io::copy`], but takes each chunk from all the `throttles` before
+/// writing it.
+pub(super) fn copy_throttled(
+	reader: &mut impl Read,
+	writer: &mut impl Write,
+	throttles: &[Arc<Throttle>],
+) -> io::Result<u64> {
+	let mut buf = vec![0; 64 * 1024];
+	let mut written = 0;
+	loop {
+		let n = match reader.read(&mut buf) {
+			Ok(0) => return Ok(written),
+			Ok(n) => n,
+			Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+			Err(e) => return Err(e),
+		};
+
+		throttles.iter().for_each(|t| t.take(n));
+		writer.write_all(&buf[..n])?;
+		written += n as u64;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_throttle() {
+		let t = Throttle::new(100_000);
+		let now = Instant::now();
+
+		// The first second is a burst, the rest are paced
+		t.take(100_000);
+		t.take(20_000);
+		let elapsed = now.elapsed();
+		assert!(elapsed >= Duration::from_millis(190), "{elapsed:?}");
+		assert!(elapsed < Duration::from_millis(1000), "{elapsed:?}");
+	}
+}
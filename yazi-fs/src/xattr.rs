@@ -0,0 +1,110 @@
+use std::{io, path::PathBuf};
+
+/// Copy the extended attributes of `from` to `to`, which includes the POSIX
+/// ACLs as well, since they're stored as `system.posix_acl_*` attributes.
+///
+/// Returns the names of the attributes that couldn't be preserved, along with
+/// the reason, e.g. the destination filesystem doesn't support them.
+///
+/// It's only implemented on Linux and Android, and does nothing elsewhere. On
+/// macOS and Windows, a file copied without a bandwidth limit still gets them
+/// from the system copy API, but a throttled copy or a directory doesn't.
+pub async fn copy_xattrs(from: PathBuf, to: PathBuf) -> Vec<(String, io::Error)> {
+	#[cfg(any(target_os = "linux", target_os = "android"))]
+	{
+		tokio::task::spawn_blocking(move || linux::copy(&from, &to))
+			.await
+			.unwrap_or_else(|e| vec![(String::new(), e.into())])
+	}
+	#[cfg(not(any(target_os = "linux", target_os = "android")))]
+	{
+		_ = (from, to);
+		vec![]
+	}
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod linux {
+	use std::{ffi::CString, io, os::unix::ffi::OsStrExt, path::Path};
+
+	use libc::{c_char, c_void, lgetxattr, llistxattr, lsetxattr};
+
+	pub(super) fn copy(from: &Path, to: &Path) -> Vec<(String, io::Error)> {
+		let (Ok(from), Ok(to)) =
+			(CString::new(from.as_os_str().as_bytes()), CString::new(to.as_os_str().as_bytes()))
+		else {
+			return vec![];
+		};
+
+		// Nothing to preserve if the source filesystem doesn't support xattrs
+		let Ok(names) = read(|buf, len| unsafe { llistxattr(from.as_ptr(), buf, len) }) else {
+			return vec![];
+		};
+
+		let mut failed = vec![];
+		for name in names.split(|&b| b == 0).filter(|n| !n.is_empty()) {
+			let Ok(name) = CString::new(name) else { continue };
+
+			let result = read(|buf, len| unsafe {
+				lgetxattr(from.as_ptr(), name.as_ptr(), buf as *mut c_void, len)
+			})
+			.and_then(|value| {
+				let n = unsafe {
+					lsetxattr(to.as_ptr(), name.as_ptr(), value.as_ptr() as *const c_void, value.len(), 0)
+				};
+				if n == 0 { Ok(()) } else { Err(io::Error::last_os_error()) }
+			});
+
+			if let Err(e) = result {
+				failed.push((name.to_string_lossy().into_owned(), e));
+			}
+		}
+		failed
+	}
+
+	/// Call `f` with a buffer large enough for the value, following the usual
+	/// "query the size first" convention of the xattr syscalls.
+	fn read(f: impl Fn(*mut c_char, usize) -> isize) -> io::Result<Vec<u8>> {
+		loop {
+			let len = f(std::ptr::null_mut(), 0);
+			if len < 0 {
+				return Err(io::Error::last_os_error());
+			}
+
+			let mut buf = vec![0u8; len as usize];
+			let n = f(buf.as_mut_ptr() as *mut c_char, buf.len());
+			if n >= 0 {
+				buf.truncate(n as usize);
+				return Ok(buf);
+			}
+
+			// The value grew in between, try again
+			let e = io::Error::last_os_error();
+			if e.raw_os_error() != Some(libc::ERANGE) {
+				return Err(e);
+			}
+		}
+	}
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn test_copy_xattrs() {
+	tokio::fs::remove_dir_all("/tmp/yazi-xattr").await.ok();
+	tokio::fs::create_dir_all("/tmp/yazi-xattr").await.unwrap();
+	tokio::fs::File::create("/tmp/yazi-xattr/a").await.unwrap();
+	tokio::fs::File::create("/tmp/yazi-xattr/b").await.unwrap();
+
+	let (a, b) = (c"/tmp/yazi-xattr/a", c"/tmp/yazi-xattr/b");
+	let value = b"foo";
+	if unsafe { libc::lsetxattr(a.as_ptr(), c"user.yazi".as_ptr(), value.as_ptr() as _, 3, 0) } != 0 {
+		return; // Not supported by the filesystem
+	}
+
+	let failed = copy_xattrs("/tmp/yazi-xattr/a".into(), "/tmp/yazi-xattr/b".into()).await;
+	assert!(failed.is_empty());
+
+	let mut buf = [0u8; 8];
+	let n = unsafe { libc::lgetxattr(b.as_ptr(), c"user.yazi".as_ptr(), buf.as_mut_ptr() as _, 8) };
+	assert_eq!(&buf[..n.max(0) as usize], b"foo");
+}
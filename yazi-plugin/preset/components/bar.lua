@@ -0,0 +1,67 @@
+Bar = {}
+
+-- The built-in children numbered from `base + 1`, so their ids stay fixed for `children_remove()`
+function Bar.children(segments, base)
+	local children = {}
+	for i, s in ipairs(segments) do
+		local fn = s.name
+		if s.plugin then
+			local plugin = s.plugin
+			fn = function(self) return require(plugin):segment(self) end
+		end
+		children[i] = { fn, id = base + i, order = i * 1000, when = s.when }
+	end
+	return children
+end
+
+function Bar.visible(child, tab)
+	for _, cond in ipairs(child.when or {}) do
+		if not Bar.satisfy(cond, tab) then
+			return false
+		end
+	end
+	return true
+end
+
+function Bar.satisfy(cond, tab)
+	if cond == "normal" then
+		return not tab.mode.is_visual
+	elseif cond == "select" then
+		return tab.mode.is_select
+	elseif cond == "unset" then
+		return tab.mode.is_unset
+	elseif cond == "hovered" then
+		return tab.current.hovered ~= nil
+	elseif cond == "selected" then
+		return #tab.selected > 0
+	elseif cond == "yanked" then
+		return #cx.yanked > 0
+	elseif cond == "filter" then
+		return tab.current.files.filter ~= nil
+	elseif cond == "find" then
+		return tab.finder ~= nil
+	elseif cond == "search" then
+		return tab.current.cwd.is_search
+	elseif cond == "tasks" then
+		return cx.tasks.progress.total > 0
+	end
+	return false
+end
+
+function Bar.join(lines, sep)
+	if sep == "" then
+		return ui.Line(lines)
+	end
+
+	local joined = {}
+	for _, l in ipairs(lines) do
+		local line = ui.Line(l)
+		if line:visible() then
+			if #joined > 0 then
+				joined[#joined + 1] = ui.Line(sep)
+			end
+			joined[#joined + 1] = line
+		end
+	end
+	return ui.Line(joined)
+end
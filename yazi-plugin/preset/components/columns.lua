@@ -0,0 +1,77 @@
+Columns = {
+	_id = "columns",
+	_sorts = { name = "natural", size = "size", mtime = "mtime", btime = "btime" },
+}
+
+function Columns:new(area, tab)
+	return setmetatable({
+		_area = area,
+		_tab = tab,
+		_rects = Columns.split(area),
+	}, { __index = self })
+end
+
+function Columns.split(area)
+	local constraints = {}
+	for i, col in ipairs(MANAGER.columns) do
+		local width = cx.panes.widths[col.name] or col.width
+		if width then
+			constraints[i] = ui.Constraint.Length(i == 1 and width or width + 1)
+		else
+			constraints[i] = ui.Constraint.Fill(1)
+		end
+	end
+	return ui.Layout():direction(ui.Layout.HORIZONTAL):constraints(constraints):split(area)
+end
+
+function Columns:title(col)
+	local title = col.title or col.name
+	local pref = self._tab.pref
+	local by = self._sorts[col.name]
+	if by and (pref.sort_by == by or (by == "natural" and pref.sort_by == "alphabetical")) then
+		title = title .. (pref.sort_reverse and " ↑" or " ↓")
+	end
+	return title
+end
+
+function Columns:reflow() return { self } end
+
+function Columns:redraw()
+	local elements = {}
+	for i, col in ipairs(MANAGER.columns) do
+		local rect = self._rects[i]
+		if i > 1 then
+			rect = rect:pad(ui.Pad.left(1))
+		end
+
+		local align = ui.Text.LEFT
+		if col.align == "right" then
+			align = ui.Text.RIGHT
+		elseif col.align == "center" then
+			align = ui.Text.CENTER
+		end
+		elements[#elements + 1] = ui.Text(self:title(col)):area(rect):align(align):style(THEME.manager.column_header)
+	end
+	return elements
+end
+
+-- Mouse events
+function Columns:click(event, up)
+	if up or not event.is_left then
+		return
+	end
+
+	for i, col in ipairs(MANAGER.columns) do
+		local by = self._sorts[col.name]
+		if by and self._rects[i]:contains(ui.Rect { x = event.x, y = event.y }) then
+			local pref = self._tab.pref
+			local same = pref.sort_by == by or (by == "natural" and pref.sort_by == "alphabetical")
+			local reverse = same and not pref.sort_reverse
+			return ya.manager_emit("sort", { same and pref.sort_by or by, reverse = reverse })
+		end
+	end
+end
+
+function Columns:scroll(event, step) end
+
+function Columns:touch(event, step) end
@@ -31,6 +31,10 @@ function Current:redraw()
 		return self:empty()
 	end
 
+	if self._tab.pref.view == "details" then
+		return self:details()
+	end
+
 	local entities, linemodes = {}, {}
 	for _, f in ipairs(files) do
 		entities[#entities + 1] = Entity:new(f):redraw()
@@ -43,6 +47,45 @@ function Current:redraw()
 	}
 end
 
+function Current:details()
+	local rects = Columns.split(self._area)
+	local elements, entities = {}, {}
+	for i, f in ipairs(self._folder.window) do
+		entities[i] = Entity:new(f)
+	end
+
+	for i, col in ipairs(MANAGER.columns) do
+		if col.name == "name" then
+			local lines = {}
+			for j, e in ipairs(entities) do
+				lines[j] = e:redraw()
+			end
+			elements[#elements + 1] = ui.List(lines):area(rects[i])
+		else
+			elements[#elements + 1] = self:column(col, rects[i], entities)
+		end
+	end
+	return elements
+end
+
+function Current:column(col, rect, entities)
+	local field = {
+		name = col.name,
+		width = math.max(0, rect.w - 1),
+		align = col.align,
+		format = col.format,
+	}
+
+	local lines = {}
+	for i, e in ipairs(entities) do
+		lines[i] = ui.Line { " ", Linemode:new(e._file):field(field) }
+		if e._file:is_hovered() then
+			lines[i] = lines[i]:style(e:style())
+		end
+	end
+	return ui.Text(lines):area(rect)
+end
+
 -- Mouse events
 function Current:click(event, up)
 	if up or event.is_middle then
@@ -7,6 +7,10 @@ Entity = {
 		{ "highlights", id = 4, order = 4000 },
 		{ "found", id = 5, order = 5000 },
 		{ "symlink", id = 6, order = 6000 },
+		{ "tags", id = 7, order = 7000 },
+		{ "diff", id = 8, order = 8000 },
+		{ "marks", id = 9, order = 9000 },
+		{ "group", id = 10, order = 10000 },
 	},
 }
 
@@ -16,7 +20,7 @@ function Entity:spacer() return " " end
 
 function Entity:icon()
 	local icon = self._file:icon()
-	if not icon then
+	if not icon or ACCESSIBILITY.enabled then
 		return ""
 	elseif self._file:is_hovered() then
 		return icon.text .. " "
@@ -32,13 +36,8 @@ end
 
 function Entity:highlights()
 	local name = self._file.name:gsub("\r", "?", 1)
-	local highlights = self._file:highlights()
-	if not highlights or #highlights == 0 then
-		return name
-	end
-
 	local spans, last = {}, 0
-	for _, h in ipairs(highlights) do
+	for _, h in ipairs(self._file:highlights() or {}) do
 		if h[1] > last then
 			spans[#spans + 1] = name:sub(last + 1, h[1])
 		end
@@ -48,7 +47,9 @@ function Entity:highlights()
 	if last < #name then
 		spans[#spans + 1] = name:sub(last + 1)
 	end
-	return ui.Line(spans)
+
+	local line = ui.Line(spans)
+	return MANAGER.bidi and line:visual() or line
 end
 
 function Entity:found()
@@ -76,6 +77,66 @@ function Entity:symlink()
 	return to and ui.Span(string.format(" -> %s", to)):italic() or ""
 end
 
+function Entity:tags()
+	local tags = self._file:tags()
+	if #tags == 0 then
+		return ""
+	end
+
+	local spans = { " " }
+	for _, name in ipairs(tags) do
+		local style = ui.Style()
+		for _, label in ipairs(TAG.labels) do
+			if label.name == name then
+				style = label.style
+				break
+			end
+		end
+		spans[#spans + 1] = ui.Span(ACCESSIBILITY.enabled and string.format("[%s]", name) or "●"):style(style)
+	end
+	return ui.Line(spans)
+end
+
+function Entity:diff()
+	local state = self._file:diff()
+	if not state then
+		return ""
+	end
+
+	local symbols = { unique = " +", changed = " ~", same = " =" }
+	return ui.Span(symbols[state]):style(THEME.manager["diff_" .. state])
+end
+
+-- The markers in text, which are otherwise only told apart by colors
+function Entity:marks()
+	if not ACCESSIBILITY.enabled then
+		return ""
+	end
+
+	local f, marks = self._file, {}
+	if f:is_marked() == 1 then
+		marks[#marks + 1] = "marked"
+	elseif f:is_selected() then
+		marks[#marks + 1] = "selected"
+	end
+	if f:is_yanked() == 1 then
+		marks[#marks + 1] = "copied"
+	elseif f:is_yanked() == 2 then
+		marks[#marks + 1] = "cut"
+	end
+	return #marks > 0 and string.format(" (%s)", table.concat(marks, ", ")) or ""
+end
+
+function Entity:group()
+	local group, folded = self._file:group_header()
+	if not group then
+		return ""
+	elseif folded then
+		group = string.format("▸ %s (+%d)", group, folded)
+	end
+	return ui.Span("  " .. group):style(THEME.manager.group_header)
+end
+
 function Entity:redraw()
 	local lines = {}
 	for _, c in ipairs(self._children) do
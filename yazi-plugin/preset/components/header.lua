@@ -1,16 +1,13 @@
 Header = {
 	LEFT = 0,
 	RIGHT = 1,
+	CENTER = 2,
 
 	_id = "header",
 	_inc = 1000,
-	_left = {
-		{ "cwd", id = 1, order = 1000 },
-	},
-	_right = {
-		{ "count", id = 1, order = 1000 },
-		{ "tabs", id = 2, order = 2000 },
-	},
+	_left = Bar.children(BARS.header.left, 0),
+	_center = Bar.children(BARS.header.center, 0),
+	_right = Bar.children(BARS.header.right, 0),
 }
 
 function Header:new(area, tab)
@@ -102,9 +99,11 @@ function Header:redraw()
 	self._right_width = right:width()
 
 	local left = self:children_redraw(self.LEFT)
+	local center = self:children_redraw(self.CENTER)
 
 	return {
 		ui.Text(left):area(self._area),
+		ui.Text(center):area(self._area):align(ui.Text.CENTER),
 		ui.Text(right):area(self._area):align(ui.Text.RIGHT),
 	}
 end
@@ -119,7 +118,7 @@ function Header:touch(event, step) end
 -- Children
 function Header:children_add(fn, order, side)
 	self._inc = self._inc + 1
-	local children = side == self.RIGHT and self._right or self._left
+	local children = self:children_of(side)
 
 	children[#children + 1] = { fn, id = self._inc, order = order }
 	table.sort(children, function(a, b) return a.order < b.order end)
@@ -128,7 +127,7 @@ function Header:children_add(fn, order, side)
 end
 
 function Header:children_remove(id, side)
-	local children = side == self.RIGHT and self._right or self._left
+	local children = self:children_of(side)
 	for i, child in ipairs(children) do
 		if child.id == id then
 			table.remove(children, i)
@@ -137,10 +136,22 @@ function Header:children_remove(id, side)
 	end
 end
 
+function Header:children_of(side)
+	if side == self.RIGHT then
+		return self._right
+	elseif side == self.CENTER then
+		return self._center
+	else
+		return self._left
+	end
+end
+
 function Header:children_redraw(side)
 	local lines = {}
-	for _, c in ipairs(side == self.RIGHT and self._right or self._left) do
-		lines[#lines + 1] = (type(c[1]) == "string" and self[c[1]] or c[1])(self)
+	for _, c in ipairs(self:children_of(side)) do
+		if Bar.visible(c, self._tab) then
+			lines[#lines + 1] = (type(c[1]) == "string" and self[c[1]] or c[1])(self)
+		end
 	end
-	return ui.Line(lines)
+	return Bar.join(lines, BARS.header.separator)
 end
@@ -12,13 +12,52 @@ function Linemode:spacer() return " " end
 
 function Linemode:solo()
 	local mode = cx.active.pref.linemode
+	local line
 	if mode == "none" or mode == "solo" then
 		return ""
+	elseif MANAGER.linemodes[mode] then
+		line = self:template(MANAGER.linemodes[mode])
 	elseif not self[mode] then
 		return " " .. mode
 	else
-		local line = ui.Line(self[mode](self))
-		return line:visible() and ui.Line { " ", line } or line
+		line = ui.Line(self[mode](self))
+	end
+	return line:visible() and ui.Line { " ", line } or line
+end
+
+function Linemode:template(segments)
+	local lines = {}
+	for i, s in ipairs(segments) do
+		lines[i] = s.type == "text" and ui.Line(s.text) or self:field(s)
+	end
+	return ui.Line(lines)
+end
+
+function Linemode:field(s)
+	local value = ""
+	if s.format and (s.name == "btime" or s.name == "mtime") then
+		local time = math.floor(self._file.cha[s.name] or 0)
+		value = time == 0 and "" or os.date(s.format, time)
+	elseif self[s.name] then
+		value = self[s.name](self, s.format)
+	end
+
+	if not s.width then
+		return ui.Line(value)
+	elseif type(value) == "string" then
+		value = ya.truncate(value, { max = s.width + 1 })
+	end
+
+	local line = ui.Line(value)
+	local pad = s.width - line:width()
+	if pad <= 0 then
+		return line
+	elseif s.align == "right" then
+		return ui.Line { string.rep(" ", pad), line }
+	elseif s.align == "center" then
+		return ui.Line { string.rep(" ", pad // 2), line, string.rep(" ", pad - pad // 2) }
+	else
+		return ui.Line { line, string.rep(" ", pad) }
 	end
 end
 
@@ -62,6 +101,8 @@ function Linemode:owner()
 	return string.format("%s:%s", user or "-", group or "-")
 end
 
+function Linemode:tags() return table.concat(self._file:tags(), ",") end
+
 function Linemode:redraw()
 	local lines = {}
 	for _, c in ipairs(self._children) do
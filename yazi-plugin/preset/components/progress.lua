@@ -12,9 +12,9 @@ function Progress:use(area) return setmetatable({ _area = area }, { __index = se
 
 function Progress:layout()
 	self._area = ui.Rect {
-		x = math.max(0, self._area.w - self._offset - 21),
+		x = math.max(0, self._area.w - self._offset - 31),
 		y = self._area.y,
-		w = ya.clamp(0, self._area.w - self._offset - 1, 20),
+		w = ya.clamp(0, self._area.w - self._offset - 1, 30),
 		h = math.min(1, self._area.h),
 	}
 end
@@ -39,10 +39,57 @@ function Progress:redraw()
 		percent = math.min(99, ya.round(progress.processed * 100 / progress.found))
 	end
 
-	local left = progress.total - progress.succ
+	local label = string.format("%3d%%, %d left", percent, progress.total - progress.succ)
+	if progress.speed > 0 then
+		label = string.format("%s, %s/s", label, ya.readable_size(progress.speed))
+	end
+	if progress.eta then
+		label = string.format("%s, %s", label, self:eta(progress.eta))
+	end
+
+	return ya.list_merge({
+		gauge:percent(percent):label(ui.Span(label):style(THEME.status.progress_label)),
+	}, self:peek())
+end
+
+function Progress:eta(secs)
+	if secs >= 3600 then
+		return string.format("%d:%02d:%02d", secs // 3600, secs % 3600 // 60, secs % 60)
+	else
+		return string.format("%d:%02d", secs // 60, secs % 60)
+	end
+end
+
+function Progress:peek()
+	local summaries = cx.tasks.summaries
+	if not cx.tasks.peeking or #summaries == 0 then
+		return {}
+	end
+
+	local w = math.min(self._area.x + self._area.w, 50)
+	local h = math.min(self._area.y, #summaries + 2)
+	local area = ui.Rect {
+		x = self._area.x + self._area.w - w,
+		y = self._area.y - h,
+		w = w,
+		h = h,
+	}
+
+	local lines = {}
+	for i, s in ipairs(summaries) do
+		local percent = s.found == 0 and 0 or math.min(99, ya.round(s.processed * 100 / s.found))
+		lines[i] = ui.Line(string.format(" %3d%% %s", percent, s.name))
+		if s.fail > 0 then
+			lines[i] = lines[i]:style(THEME.status.progress_error)
+		end
+	end
+
 	return {
-		gauge
-			:percent(percent)
-			:label(ui.Span(string.format("%3d%%, %d left", percent, left)):style(THEME.status.progress_label)),
+		ui.Clear(area),
+		ui.Border(ui.Border.ALL)
+			:area(area)
+			:type(ui.Border.ROUNDED)
+			:title(string.format(" Tasks (%d) ", cx.tasks.progress.active)),
+		ui.List(lines):area(area:padding(ui.Padding.xy(1, 1))),
 	}
 end
@@ -9,7 +9,7 @@ function Rail:new(chunks, tab)
 end
 
 function Rail:build()
-	self._base = {
+	self._base = cx.panes:hides("borders") and {} or {
 		ui.Bar(ui.Bar.RIGHT):area(self._chunks[1]):symbol(THEME.manager.border_symbol):style(THEME.manager.border_style),
 		ui.Bar(ui.Bar.LEFT):area(self._chunks[3]):symbol(THEME.manager.border_symbol):style(THEME.manager.border_style),
 	}
@@ -1,6 +1,7 @@
 Root = {
 	_id = "root",
 	_drag_start = ui.Rect.default,
+	_status_hovered = false,
 }
 
 function Root:new(area)
@@ -14,9 +15,9 @@ function Root:layout()
 	self._chunks = ui.Layout()
 		:direction(ui.Layout.VERTICAL)
 		:constraints({
-			ui.Constraint.Length(1),
+			ui.Constraint.Length(cx.panes:hides("header") and 0 or 1),
 			ui.Constraint.Fill(1),
-			ui.Constraint.Length(1),
+			ui.Constraint.Length(cx.panes:hides("status") and 0 or 1),
 		})
 		:split(self._area)
 end
@@ -71,6 +72,16 @@ function Root:touch(event, step)
 	return c and c:touch(event, step)
 end
 
-function Root:move(event) end
+function Root:move(event)
+	local hovered = self._chunks[3]:contains(ui.Rect { x = event.x, y = event.y })
+	if hovered == Root._status_hovered then
+		return
+	end
+
+	Root._status_hovered = hovered
+	if cx.tasks.progress.total > 0 or not hovered then
+		ya.manager_emit("tasks_peek", { hovered and "on" or "off" })
+	end
+end
 
 function Root:drag(event) end
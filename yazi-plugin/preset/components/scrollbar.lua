@@ -0,0 +1,53 @@
+Scrollbar = {
+	_id = "scrollbar",
+}
+
+function Scrollbar:new(area, folder)
+	return setmetatable({
+		_area = area,
+		_folder = folder,
+	}, { __index = self })
+end
+
+function Scrollbar.visible(folder, area) return MANAGER.scrollbar and #folder.files > area.h end
+
+function Scrollbar:reflow() return { self } end
+
+function Scrollbar:redraw()
+	local h, len = self._area.h, #self._folder.files
+	if h == 0 or len == 0 then
+		return {}
+	end
+
+	local thumb = math.max(1, h * h // len)
+	local start = math.min(h - thumb, self._folder.offset * h // len)
+
+	local lines, marks = {}, self._folder:minimap(h)
+	for i = 1, h do
+		if marks[i] == 2 then
+			lines[i] = ui.Line("━"):style(THEME.manager.scrollbar_selected)
+		elseif marks[i] == 1 then
+			lines[i] = ui.Line("━"):style(THEME.manager.scrollbar_found)
+		elseif i > start and i <= start + thumb then
+			lines[i] = ui.Line("┃"):style(THEME.manager.scrollbar_thumb)
+		else
+			lines[i] = ui.Line("│"):style(THEME.manager.scrollbar_track)
+		end
+	end
+	return { ui.Text(lines):area(self._area) }
+end
+
+-- Mouse events
+function Scrollbar:click(event, up)
+	local f = self._folder
+	if up or not f.hovered then
+		return
+	end
+
+	local target = (event.y - self._area.y) * #f.files // self._area.h
+	ya.manager_emit("arrow", { target - f.cursor })
+end
+
+function Scrollbar:scroll(event, step) ya.manager_emit("arrow", { step }) end
+
+function Scrollbar:touch(event, step) end
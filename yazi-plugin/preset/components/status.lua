@@ -1,19 +1,13 @@
 Status = {
 	LEFT = 0,
 	RIGHT = 1,
+	CENTER = 2,
 
 	_id = "status",
 	_inc = 1000,
-	_left = {
-		{ "mode", id = 1, order = 1000 },
-		{ "size", id = 2, order = 2000 },
-		{ "name", id = 3, order = 3000 },
-	},
-	_right = {
-		{ "perm", id = 4, order = 1000 },
-		{ "percent", id = 5, order = 2000 },
-		{ "position", id = 6, order = 3000 },
-	},
+	_left = Bar.children(BARS.status.left, 0),
+	_center = Bar.children(BARS.status.center, #BARS.status.left + #BARS.status.right),
+	_right = Bar.children(BARS.status.right, #BARS.status.left),
 }
 
 function Status:new(area, tab)
@@ -65,7 +59,8 @@ function Status:name()
 		return ""
 	end
 
-	return " " .. h.name:gsub("\r", "?", 1)
+	local line = ui.Line((h.name:gsub("\r", "?", 1)))
+	return ui.Line { " ", MANAGER.bidi and line:visual() or line }
 end
 
 function Status:perm()
@@ -97,6 +92,61 @@ function Status:perm()
 	return ui.Line(spans)
 end
 
+function Status:info()
+	local h = self._current.hovered
+	local info = h and h:info()
+	if not info then
+		return ""
+	end
+
+	local parts = {}
+	for _, field in ipairs(BARS.status.info) do
+		if field == "perm" and info.perm then
+			parts[#parts + 1] = info.perm
+		elseif field == "owner" and info.owner then
+			parts[#parts + 1] = ya.truncate(info.owner, { max = 17 }) .. ":" .. ya.truncate(info.group or "", { max = 17 })
+		elseif field == "nlink" then
+			parts[#parts + 1] = string.format("%d link%s", info.nlink, info.nlink == 1 and "" or "s")
+		elseif field == "ino" then
+			parts[#parts + 1] = string.format("#%d", info.ino)
+		elseif field == "target" and info.target then
+			-- Keep the end of the target, where its name is
+			local max = math.max(10, math.floor(self._area.w / 4))
+			local target = info.target
+			if ui.Line(target):width() > max then
+				target = "…" .. ya.truncate(target, { max = max, rtl = true })
+			end
+			parts[#parts + 1] = "-> " .. target
+		end
+	end
+
+	return ui.Line {
+		ui.Span(table.concat(parts, "  ") .. " "):style(THEME.status.perm_sep),
+	}
+end
+
+function Status:trash()
+	local trash = cx.tasks.trash
+	if not trash or trash.count == 0 then
+		return ""
+	end
+
+	return ui.Line {
+		ui.Span(string.format("Trash %s ", ya.readable_size(trash.size))):style(THEME.status.perm_sep),
+	}
+end
+
+function Status:network()
+	local net = fs.network(self._current.cwd)
+	if not net then
+		return ""
+	end
+
+	return ui.Line {
+		ui.Span(string.format("%s ", net.kind)):style(THEME.status.perm_sep),
+	}
+end
+
 function Status:percent()
 	local percent = 0
 	local cursor = self._current.cursor
@@ -136,6 +186,7 @@ function Status:reflow() return { self } end
 
 function Status:redraw()
 	local left = self:children_redraw(self.LEFT)
+	local center = self:children_redraw(self.CENTER)
 
 	local right = self:children_redraw(self.RIGHT)
 	local right_width = right:width()
@@ -143,6 +194,7 @@ function Status:redraw()
 	return {
 		ui.Text(""):area(self._area):style(THEME.status.overall),
 		ui.Text(left):area(self._area),
+		ui.Text(center):area(self._area):align(ui.Text.CENTER),
 		ui.Text(right):area(self._area):align(ui.Text.RIGHT),
 		table.unpack(ya.redraw_with(Progress:new(self._area, right_width))),
 	}
@@ -158,7 +210,7 @@ function Status:touch(event, step) end
 -- Children
 function Status:children_add(fn, order, side)
 	self._inc = self._inc + 1
-	local children = side == self.RIGHT and self._right or self._left
+	local children = self:children_of(side)
 
 	children[#children + 1] = { fn, id = self._inc, order = order }
 	table.sort(children, function(a, b) return a.order < b.order end)
@@ -167,7 +219,7 @@ function Status:children_add(fn, order, side)
 end
 
 function Status:children_remove(id, side)
-	local children = side == self.RIGHT and self._right or self._left
+	local children = self:children_of(side)
 	for i, child in ipairs(children) do
 		if child.id == id then
 			table.remove(children, i)
@@ -176,10 +228,22 @@ function Status:children_remove(id, side)
 	end
 end
 
+function Status:children_of(side)
+	if side == self.RIGHT then
+		return self._right
+	elseif side == self.CENTER then
+		return self._center
+	else
+		return self._left
+	end
+end
+
 function Status:children_redraw(side)
 	local lines = {}
-	for _, c in ipairs(side == self.RIGHT and self._right or self._left) do
-		lines[#lines + 1] = (type(c[1]) == "string" and self[c[1]] or c[1])(self)
+	for _, c in ipairs(self:children_of(side)) do
+		if Bar.visible(c, self._tab) then
+			lines[#lines + 1] = (type(c[1]) == "string" and self[c[1]] or c[1])(self)
+		end
 	end
-	return ui.Line(lines)
+	return Bar.join(lines, BARS.status.separator)
 end
@@ -10,14 +10,34 @@ function Tab:new(area, tab)
 end
 
 function Tab:layout()
+	local ratio = cx.panes.ratio
 	self._chunks = ui.Layout()
 		:direction(ui.Layout.HORIZONTAL)
 		:constraints({
-			ui.Constraint.Ratio(MANAGER.ratio.parent, MANAGER.ratio.all),
-			ui.Constraint.Ratio(MANAGER.ratio.current, MANAGER.ratio.all),
-			ui.Constraint.Ratio(MANAGER.ratio.preview, MANAGER.ratio.all),
+			ui.Constraint.Ratio(ratio.parent, ratio.all),
+			ui.Constraint.Ratio(ratio.current, ratio.all),
+			ui.Constraint.Ratio(ratio.preview, ratio.all),
 		})
 		:split(self._area)
+
+	if Scrollbar.visible(self._tab.current, self._chunks[2]) then
+		local chunks = ui.Layout()
+			:direction(ui.Layout.HORIZONTAL)
+			:constraints({ ui.Constraint.Fill(1), ui.Constraint.Length(1) })
+			:split(self._chunks[2])
+		self._chunks[2], self._scrollbar = chunks[1], chunks[2]
+	end
+
+	if self._tab.pref.view == "details" then
+		self._columns = ui.Layout()
+			:direction(ui.Layout.VERTICAL)
+			:constraints({ ui.Constraint.Length(1), ui.Constraint.Fill(1) })
+			:split(self._chunks[2])
+		self._chunks[2] = self._columns[2]
+		if self._scrollbar then
+			self._scrollbar = ui.Rect { x = self._scrollbar.x, y = self._chunks[2].y, w = 1, h = self._chunks[2].h }
+		end
+	end
 end
 
 function Tab:build()
@@ -27,6 +47,12 @@ function Tab:build()
 		Preview:new(self._chunks[3]:pad(ui.Pad.x(1)), self._tab),
 		Rail:new(self._chunks, self._tab),
 	}
+	if self._columns then
+		table.insert(self._children, 2, Columns:new(self._columns[1], self._tab))
+	end
+	if self._scrollbar then
+		self._children[#self._children + 1] = Scrollbar:new(self._scrollbar, self._tab.current)
+	end
 end
 
 function Tab:reflow()
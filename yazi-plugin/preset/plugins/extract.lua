@@ -93,9 +93,10 @@ function M:tidy(from, to, tmp)
 		target = to:join(self.trim_ext(from:name()))
 	end
 
-	target = fs.unique_name(target)
+	target = self:settle(from, target)
 	if not target then
-		fail("Failed to determine a target for '%s'", from)
+		fs.remove("dir_all", tmp)
+		return
 	end
 
 	target = tostring(target)
@@ -107,6 +108,41 @@ function M:tidy(from, to, tmp)
 	fs.remove("dir", tmp)
 end
 
+-- Where the extraction goes by the conflict policy if `target` exists, or nil to skip it
+function M:settle(from, target)
+	local cha = fs.cha(target)
+	if not cha then
+		return target
+	end
+
+	local conflict = MANAGER.conflict
+	if conflict == "skip" then
+		return nil
+	elseif conflict == "overwrite" then
+		local ok, err = fs.remove(cha.is_dir and "dir_all" or "file", target)
+		if not ok then
+			fail("Failed to overwrite '%s' when extracting '%s', error: %s", target, from, err)
+		end
+		return target
+	elseif conflict == "ask" then
+		local value, event = ya.input {
+			title = string.format('"%s" already exists, extract as:', target.name),
+			value = target.name,
+			position = { "center", w = 50 },
+		}
+		if event ~= 1 or value == "" or value:find("[/\\]") then
+			return nil
+		end
+		target = target:parent():join(value)
+	end
+
+	local unique, err = fs.conflict_name(target)
+	if not unique then
+		fail("Failed to determine a target for '%s', error: %s", from, err)
+	end
+	return unique
+end
+
 function M.tmp_name(url) return ".tmp_" .. ya.hash(string.format("extract//%s//%.10f", url, ya.time())) end
 
 function M.trim_ext(name)
@@ -1,5 +1,24 @@
 local SUPPORTED_TYPES = "application/audio/biosig/chemical/font/image/inode/message/model/rinex/text/vector/video/x-epoc/"
 
+-- Going by the extension, for the files on network filesystems that aren't sniffed
+local EXTENSIONS = {
+	avif = "image/avif", bmp = "image/bmp", gif = "image/gif", heic = "image/heic", jpeg = "image/jpeg",
+	jpg = "image/jpeg", png = "image/png", svg = "image/svg+xml", tif = "image/tiff", tiff = "image/tiff",
+	webp = "image/webp",
+	avi = "video/x-msvideo", m4v = "video/mp4", mkv = "video/x-matroska", mov = "video/quicktime",
+	mp4 = "video/mp4", webm = "video/webm",
+	aac = "audio/aac", flac = "audio/flac", m4a = "audio/mp4", mp3 = "audio/mpeg", ogg = "audio/ogg",
+	opus = "audio/ogg", wav = "audio/x-wav",
+	["7z"] = "application/x-7z-compressed", bz2 = "application/x-bzip2", gz = "application/gzip",
+	rar = "application/vnd.rar", tar = "application/x-tar", xz = "application/x-xz", zip = "application/zip",
+	zst = "application/zstd",
+	epub = "application/epub+zip", json = "application/json", pdf = "application/pdf",
+	c = "text/x-c", css = "text/css", csv = "text/csv", go = "text/x-go", h = "text/x-c", html = "text/html",
+	js = "text/javascript", log = "text/plain", lua = "text/x-lua", md = "text/markdown", py = "text/x-python",
+	rs = "text/x-rust", sh = "text/x-shellscript", toml = "text/x-toml", ts = "text/x-typescript",
+	txt = "text/plain", xml = "text/xml", yaml = "text/x-yaml", yml = "text/x-yaml",
+}
+
 local M = {}
 
 local function match_mimetype(s)
@@ -9,10 +28,34 @@ local function match_mimetype(s)
 	end
 end
 
+local function guess(file)
+	local net = fs.network(file.url)
+	if not net or net.sniff then
+		return
+	end
+
+	local ext = file.url:ext()
+	local mime = ext and EXTENSIONS[ext:lower()] or "application/octet-stream"
+	return match_mimetype(mime)
+end
+
 function M:fetch(job)
-	local urls = {}
-	for _, file in ipairs(job.files) do
-		urls[#urls + 1] = tostring(file.url)
+	local urls, indices, guessed, state = {}, {}, {}, {}
+	for i, file in ipairs(job.files) do
+		local mime = guess(file)
+		if mime then
+			guessed[tostring(file.url)], state[i] = mime, true
+		else
+			urls[#urls + 1] = tostring(file.url)
+			indices[#urls] = i
+		end
+	end
+
+	if next(guessed) then
+		ya.manager_emit("update_mimes", { updates = guessed })
+	end
+	if #urls == 0 then
+		return state
 	end
 
 	local cmd = os.getenv("YAZI_FILE_ONE") or "file"
@@ -21,7 +64,8 @@ function M:fetch(job)
 		return true, Err("Failed to start `%s`, error: %s", cmd, err)
 	end
 
-	local updates, last = {}, ya.time()
+	-- The first result is flushed at once, so the first paint doesn't wait for the throttle
+	local updates, last = {}, 0
 	local flush = function(force)
 		if not force and ya.time() - last < 0.3 then
 			return
@@ -32,7 +76,7 @@ function M:fetch(job)
 		end
 	end
 
-	local i, valid, state = 1, nil, {}
+	local i, valid = 1, nil
 	repeat
 		local line, event = child:read_line_with { timeout = 300 }
 		if event == 3 then
@@ -44,10 +88,10 @@ function M:fetch(job)
 
 		valid = match_mimetype(line)
 		if valid then
-			updates[urls[i]], state[i] = valid, true
+			updates[urls[i]], state[indices[i]] = valid, true
 			flush(false)
 		else
-			state[i] = false
+			state[indices[i]] = false
 		end
 
 		i = i + 1
@@ -1,6 +1,10 @@
 local M = {}
 
 function M:peek(job)
+	if ACCESSIBILITY.enabled then
+		return require("file"):peek(job)
+	end
+
 	local start, cache = os.clock(), ya.file_cache(job)
 	if not cache then
 		return
@@ -4,16 +4,16 @@ use mlua::{UserData, UserDataFields};
 
 use crate::elements::Style;
 
-pub struct Icon(&'static yazi_shared::theme::Icon);
+pub struct Icon(yazi_shared::theme::Icon);
 
 impl Deref for Icon {
 	type Target = yazi_shared::theme::Icon;
 
-	fn deref(&self) -> &Self::Target { self.0 }
+	fn deref(&self) -> &Self::Target { &self.0 }
 }
 
-impl From<&'static yazi_shared::theme::Icon> for Icon {
-	fn from(icon: &'static yazi_shared::theme::Icon) -> Self { Self(icon) }
+impl From<yazi_shared::theme::Icon> for Icon {
+	fn from(icon: yazi_shared::theme::Icon) -> Self { Self(icon) }
 }
 
 impl UserData for Icon {
@@ -1,6 +1,6 @@
 use mlua::{IntoLua, Lua, LuaSerdeExt, SerializeOptions, Value};
 use yazi_boot::BOOT;
-use yazi_config::{MANAGER, PREVIEW, THEME};
+use yazi_config::{ACCESSIBILITY, BARS, MANAGER, PREVIEW, TAG, THEME};
 
 use super::Plugin;
 use crate::Composer;
@@ -21,17 +21,35 @@ impl<'a> Config<'a> {
 	}
 
 	pub fn install_manager(self) -> mlua::Result<Self> {
-		self.lua.globals().raw_set("MANAGER", self.lua.to_value_with(&*MANAGER, SER_OPTS)?)?;
+		self.lua.globals().raw_set("MANAGER", self.lua.to_value_with(&*MANAGER.load(), SER_OPTS)?)?;
+		Ok(self)
+	}
+
+	pub fn install_accessibility(self) -> mlua::Result<Self> {
+		self
+			.lua
+			.globals()
+			.raw_set("ACCESSIBILITY", self.lua.to_value_with(&*ACCESSIBILITY, SER_OPTS)?)?;
+		Ok(self)
+	}
+
+	pub fn install_bars(self) -> mlua::Result<Self> {
+		self.lua.globals().raw_set("BARS", self.lua.to_value_with(&*BARS, SER_OPTS)?)?;
+		Ok(self)
+	}
+
+	pub fn install_tag(self) -> mlua::Result<Self> {
+		self.lua.globals().raw_set("TAG", self.lua.to_value_with(&*TAG, SER_OPTS)?)?;
 		Ok(self)
 	}
 
 	pub fn install_theme(self) -> mlua::Result<Self> {
-		self.lua.globals().raw_set("THEME", self.lua.to_value_with(&*THEME, SER_OPTS)?)?;
+		self.lua.globals().raw_set("THEME", self.lua.to_value_with(&*THEME.load(), SER_OPTS)?)?;
 		Ok(self)
 	}
 
 	pub fn install_preview(self) -> mlua::Result<Self> {
-		self.lua.globals().raw_set("PREVIEW", self.lua.to_value_with(&*PREVIEW, SER_OPTS)?)?;
+		self.lua.globals().raw_set("PREVIEW", self.lua.to_value_with(&*PREVIEW.load(), SER_OPTS)?)?;
 		Ok(self)
 	}
 
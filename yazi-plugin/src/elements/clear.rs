@@ -1,7 +1,7 @@
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use mlua::{Lua, MetaMethod, Table, UserData};
-use yazi_adapter::ADAPTOR;
+use yazi_adapter::REGIONS;
 
 use super::Area;
 
@@ -38,11 +38,10 @@ impl ratatui::widgets::Widget for Clear {
 	{
 		ratatui::widgets::Clear.render(area, buf);
 
-		let Some(r) = ADAPTOR.get().shown_load().and_then(|r| overlap(area, r)) else {
+		let Some(r) = REGIONS.lock().cover(area) else {
 			return;
 		};
 
-		ADAPTOR.get().image_erase(r).ok();
 		COLLISION.store(true, Ordering::Relaxed);
 		for y in r.top()..r.bottom() {
 			for x in r.left()..r.right() {
@@ -57,20 +56,3 @@ impl UserData for Clear {
 		crate::impl_area_method!(methods);
 	}
 }
-
-#[inline]
-const fn is_overlapping(a: ratatui::layout::Rect, b: ratatui::layout::Rect) -> bool {
-	a.x < b.x + b.width && a.x + a.width > b.x && a.y < b.y + b.height && a.y + a.height > b.y
-}
-
-fn overlap(a: ratatui::layout::Rect, b: ratatui::layout::Rect) -> Option<ratatui::layout::Rect> {
-	if !is_overlapping(a, b) {
-		return None;
-	}
-
-	let x = a.x.max(b.x);
-	let y = a.y.max(b.y);
-	let width = (a.x + a.width).min(b.x + b.width) - x;
-	let height = (a.y + a.height).min(b.y + b.height) - y;
-	Some(ratatui::layout::Rect { x, y, width, height })
-}
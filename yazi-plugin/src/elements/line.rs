@@ -2,7 +2,9 @@ use std::mem;
 
 use ansi_to_tui::IntoText;
 use mlua::{AnyUserData, ExternalError, ExternalResult, IntoLua, Lua, MetaMethod, Table, UserData, UserDataMethods, Value};
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthChar;
+use yazi_shared::{Widths, bidi, escape_bytes};
 
 use super::Span;
 
@@ -51,7 +53,7 @@ impl TryFrom<Value> for Line {
 	fn try_from(value: Value) -> Result<Self, Self::Error> {
 		Ok(Self(match value {
 			Value::Table(tb) => return Self::try_from(tb),
-			Value::String(s) => s.to_string_lossy().into(),
+			Value::String(s) => escape_bytes(&s.as_bytes()).into_owned().into(),
 			Value::UserData(ud) => {
 				if let Ok(Span(span)) = ud.take() {
 					span.into()
@@ -74,7 +76,7 @@ impl TryFrom<Table> for Line {
 		let mut spans = Vec::with_capacity(tb.raw_len());
 		for v in tb.sequence_values() {
 			match v? {
-				Value::String(s) => spans.push(s.to_string_lossy().into()),
+				Value::String(s) => spans.push(escape_bytes(&s.as_bytes()).into_owned().into()),
 				Value::UserData(ud) => {
 					if let Ok(Span(span)) = ud.take() {
 						spans.push(span);
@@ -101,7 +103,9 @@ impl UserData for Line {
 		crate::impl_style_method!(methods, 0.style);
 		crate::impl_style_shorthands!(methods, 0.style);
 
-		methods.add_method("width", |_, Line(me), ()| Ok(me.width()));
+		methods.add_method("width", |_, Line(me), ()| {
+			Ok(me.iter().map(|s| Widths::str(&s.content)).sum::<usize>())
+		});
 		methods.add_function_mut("align", |_, (ud, align): (AnyUserData, u8)| {
 			ud.borrow_mut::<Self>()?.0.alignment = Some(match align {
 				CENTER => ratatui::layout::Alignment::Center,
@@ -113,5 +117,34 @@ impl UserData for Line {
 		methods.add_method("visible", |_, Line(me), ()| {
 			Ok(me.iter().flat_map(|s| s.content.chars()).any(|c| c.width().unwrap_or(0) > 0))
 		});
+		methods.add_function_mut("visual", |_, ud: AnyUserData| {
+			{
+				let mut me = ud.borrow_mut::<Self>()?;
+				let graphemes: Vec<_> = me
+					.0
+					.spans
+					.iter()
+					.enumerate()
+					.flat_map(|(i, s)| s.content.graphemes(true).map(move |g| (i, g)))
+					.collect();
+
+				let Some(order) = bidi::reorder(&graphemes.iter().map(|&(_, g)| g).collect::<Vec<_>>())
+				else {
+					return Ok(ud.clone());
+				};
+
+				let mut spans: Vec<ratatui::text::Span> = Vec::with_capacity(me.0.spans.len());
+				for (i, rtl) in order {
+					let (n, g) = graphemes[i];
+					let g = if rtl { bidi::mirror(g) } else { g };
+					match spans.last_mut() {
+						Some(last) if last.style == me.0.spans[n].style => last.content.to_mut().push_str(g),
+						_ => spans.push(ratatui::text::Span::styled(g.to_owned(), me.0.spans[n].style)),
+					}
+				}
+				me.0.spans = spans;
+			}
+			Ok(ud)
+		});
 	}
 }
@@ -1,5 +1,6 @@
 use mlua::{ExternalError, Lua, MetaMethod, Table, UserData, UserDataMethods, Value};
 use unicode_width::UnicodeWidthChar;
+use yazi_shared::escape_bytes;
 
 const EXPECTED: &str = "expected a string or Span";
 
@@ -21,7 +22,7 @@ impl TryFrom<Value> for Span {
 
 	fn try_from(value: Value) -> Result<Self, Self::Error> {
 		Ok(Self(match value {
-			Value::String(s) => s.to_string_lossy().into(),
+			Value::String(s) => escape_bytes(&s.as_bytes()).into_owned().into(),
 			Value::UserData(ud) => {
 				if let Ok(Span(span)) = ud.take() {
 					span
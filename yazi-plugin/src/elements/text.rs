@@ -3,6 +3,7 @@ use std::mem;
 use ansi_to_tui::IntoText;
 use mlua::{ExternalError, ExternalResult, IntoLua, Lua, MetaMethod, Table, UserData, Value};
 use ratatui::widgets::Widget;
+use yazi_shared::{Widths, escape_bytes};
 
 use super::{Area, Line, Span};
 
@@ -72,7 +73,7 @@ impl TryFrom<Value> for Text {
 	fn try_from(value: Value) -> mlua::Result<Self> {
 		let inner = match value {
 			Value::Table(tb) => return Self::try_from(tb),
-			Value::String(s) => s.to_string_lossy().into(),
+			Value::String(s) => escape_bytes(&s.as_bytes()).into_owned().into(),
 			Value::UserData(ud) => {
 				if let Ok(Line(line)) = ud.take() {
 					line.into()
@@ -97,7 +98,7 @@ impl TryFrom<Table> for Text {
 		let mut lines = Vec::with_capacity(tb.raw_len());
 		for v in tb.sequence_values() {
 			match v? {
-				Value::String(s) => lines.push(s.to_string_lossy().into()),
+				Value::String(s) => lines.push(escape_bytes(&s.as_bytes()).into_owned().into()),
 				Value::UserData(ud) => {
 					if let Ok(Span(span)) = ud.take() {
 						lines.push(span.into());
@@ -156,7 +157,14 @@ impl UserData for Text {
 			Ok(ud)
 		});
 		methods.add_method("max_width", |_, me, ()| {
-			Ok(me.inner.lines.iter().take(me.area.size().height as usize).map(|l| l.width()).max())
+			Ok(
+				me.inner
+					.lines
+					.iter()
+					.take(me.area.size().height as usize)
+					.map(|l| l.iter().map(|s| Widths::str(&s.content)).sum::<usize>())
+					.max(),
+			)
 		});
 	}
 }
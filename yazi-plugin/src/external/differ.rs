@@ -0,0 +1,393 @@
+use std::{borrow::Cow, ops::Range, path::{Path, PathBuf}};
+
+use ratatui::{layout::Size, style::Style, text::{Line, Span, Text}};
+use unicode_width::UnicodeWidthChar;
+use yazi_config::{PREVIEW, THEME};
+use yazi_shared::{errors::PeekError, replace_to_printable};
+
+/// Beyond this many edits, the rest of the two files are considered entirely
+/// different, to keep the memory of backtracking bounded.
+const MAX_EDITS: usize = 2000;
+
+/// A line-based diff of two text files, rendered either side by side or
+/// unified, with the changed words within a modified line highlighted.
+pub struct Differ {
+	old:     PathBuf,
+	new:     PathBuf,
+	unified: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+	Same,
+	Delete,
+	Insert,
+}
+
+/// A row of the output, referring to the lines by their index. The partner of
+/// a deleted or inserted line is the one it's replaced by or replaces, if any.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Row {
+	Same(usize, usize),
+	Delete(usize, Option<usize>),
+	Insert(Option<usize>, usize),
+}
+
+impl Differ {
+	#[inline]
+	pub fn new(old: &Path, new: &Path) -> Self {
+		Self { old: old.to_owned(), new: new.to_owned(), unified: false }
+	}
+
+	#[inline]
+	pub fn unified(mut self, unified: bool) -> Self {
+		self.unified = unified;
+		self
+	}
+
+	pub async fn render(&self, skip: usize, size: Size) -> Result<Text<'static>, PeekError> {
+		let (old, new) = tokio::try_join!(tokio::fs::read(&self.old), tokio::fs::read(&self.new))?;
+		if Self::is_binary(&old) || Self::is_binary(&new) {
+			return Err(
+				if old == new { "Binary files are identical" } else { "Binary files differ" }.into(),
+			);
+		}
+
+		let unified = self.unified;
+		tokio::task::spawn_blocking(move || {
+			let (old, new) = (String::from_utf8_lossy(&old), String::from_utf8_lossy(&new));
+			let (a, b): (Vec<_>, Vec<_>) = (Self::lines(&old), Self::lines(&new));
+
+			let rows = Self::rows(&a, &b, unified);
+			let height = size.height as usize;
+			if skip > 0 && rows.len() < skip + height {
+				return Err(PeekError::Exceed(rows.len().saturating_sub(height)));
+			}
+
+			let lines = rows.into_iter().skip(skip).take(height).map(|row| {
+				if unified {
+					Self::unified_line(row, &a, &b)
+				} else {
+					Self::split_line(row, &a, &b, size.width)
+				}
+			});
+			Ok(Text::from(lines.collect::<Vec<_>>()))
+		})
+		.await?
+	}
+
+	fn rows(a: &[&str], b: &[&str], unified: bool) -> Vec<Row> {
+		let ops = diff(a, b);
+		let mut rows = Vec::with_capacity(ops.len());
+
+		let (mut i, mut j, mut n) = (0, 0, 0);
+		while n < ops.len() {
+			if ops[n] == Op::Same {
+				rows.push(Row::Same(i, j));
+				(i, j, n) = (i + 1, j + 1, n + 1);
+				continue;
+			}
+
+			// A run of deletions and insertions replaces the one with the other
+			let dels = ops[n..].iter().take_while(|&&o| o == Op::Delete).count();
+			let ins = ops[n + dels..].iter().take_while(|&&o| o == Op::Insert).count();
+			let (del, ins) = (i..i + dels, j..j + ins);
+
+			if unified {
+				rows.extend(del.clone().map(|x| Row::Delete(x, Self::partner(x, &del, &ins))));
+				rows.extend(ins.clone().map(|y| Row::Insert(Self::partner(y, &ins, &del), y)));
+			} else {
+				for k in 0..dels.max(ins.len()) {
+					rows.push(match (del.start + k < del.end, ins.start + k < ins.end) {
+						(true, true) => Row::Delete(del.start + k, Some(ins.start + k)),
+						(true, false) => Row::Delete(del.start + k, None),
+						_ => Row::Insert(None, ins.start + k),
+					});
+				}
+			}
+
+			n += dels + ins.len();
+			(i, j) = (del.end, ins.end);
+		}
+		rows
+	}
+
+	#[inline]
+	fn partner(x: usize, from: &Range<usize>, to: &Range<usize>) -> Option<usize> {
+		let y = to.start + x - from.start;
+		(y < to.end).then_some(y)
+	}
+
+	fn unified_line(row: Row, a: &[&str], b: &[&str]) -> Line<'static> {
+		let m = &THEME.load().manager;
+		let (sign, style, spans) = match row {
+			Row::Same(x, _) => (" ", Style::default(), vec![(Cow::Borrowed(a[x]), false)]),
+			Row::Delete(x, y) => ("-", m.diff_delete.into(), Self::words(a[x], y.map(|y| b[y]))),
+			Row::Insert(x, y) => ("+", m.diff_insert.into(), Self::words(b[y], x.map(|x| a[x]))),
+		};
+
+		let word = if sign == "+" { m.diff_insert_word } else { m.diff_delete_word };
+		let mut line = vec![Span::raw(format!("{sign} "))];
+		line.extend(spans.into_iter().map(|(s, changed)| {
+			let s = Self::printable(&s);
+			if changed { Span::styled(s, word) } else { Span::raw(s) }
+		}));
+		Line::from(line).style(style)
+	}
+
+	fn split_line(row: Row, a: &[&str], b: &[&str], width: u16) -> Line<'static> {
+		let m = &THEME.load().manager;
+		let half = width.saturating_sub(1) as usize / 2;
+
+		let (left, right) = match row {
+			Row::Same(x, y) => (Some((a[x], None, Op::Same)), Some((b[y], None, Op::Same))),
+			Row::Delete(x, None) => (Some((a[x], None, Op::Delete)), None),
+			Row::Insert(_, y) => (None, Some((b[y], None, Op::Insert))),
+			Row::Delete(x, Some(y)) => {
+				(Some((a[x], Some(b[y]), Op::Delete)), Some((b[y], Some(a[x]), Op::Insert)))
+			}
+		};
+
+		let side = |s: Option<(&str, Option<&str>, Op)>| -> Vec<Span<'static>> {
+			let Some((s, other, op)) = s else { return vec![Span::raw(" ".repeat(half))] };
+			let (sign, style, word) = match op {
+				Op::Same => (" ", Style::default(), Style::default()),
+				Op::Delete => ("-", m.diff_delete.into(), m.diff_delete_word.into()),
+				Op::Insert => ("+", m.diff_insert.into(), m.diff_insert_word.into()),
+			};
+
+			let words =
+				if op == Op::Same { vec![(Cow::Borrowed(s), false)] } else { Self::words(s, other) };
+			let mut spans = vec![Span::styled(sign, style)];
+			let mut room = half.saturating_sub(1);
+			for (s, changed) in words {
+				let (s, w) = Self::truncate(&Self::printable(&s), room);
+				room -= w;
+				spans.push(Span::styled(s, if changed { style.patch(word) } else { style }));
+			}
+			spans.push(Span::styled(" ".repeat(room), style));
+			spans
+		};
+
+		let mut spans = side(left);
+		spans.push(Span::styled("│", THEME.load().manager.border_style));
+		spans.extend(side(right));
+		Line::from(spans)
+	}
+
+	/// Split `s` into words, marking the ones that don't appear in `other`.
+	fn words<'a>(s: &'a str, other: Option<&str>) -> Vec<(Cow<'a, str>, bool)> {
+		let Some(other) = other else { return vec![(Cow::Borrowed(s), false)] };
+
+		let (x, y) = (Self::tokens(s), Self::tokens(other));
+		let mut words: Vec<(Cow<str>, bool)> = vec![];
+		let mut i = 0;
+		for op in diff(&x, &y) {
+			let changed = match op {
+				Op::Insert => continue,
+				Op::Same => false,
+				Op::Delete => true,
+			};
+			match words.last_mut() {
+				Some((w, c)) if *c == changed => w.to_mut().push_str(x[i]),
+				_ => words.push((Cow::Borrowed(x[i]), changed)),
+			}
+			i += 1;
+		}
+		words
+	}
+
+	/// Runs of alphanumerics and of whitespaces, or single other characters.
+	fn tokens(s: &str) -> Vec<&str> {
+		let kind = |c: char| {
+			if c.is_alphanumeric() || c == '_' {
+				1
+			} else if c.is_whitespace() {
+				2
+			} else {
+				0
+			}
+		};
+
+		let mut tokens = vec![];
+		let mut start = 0;
+		let mut prev = None;
+		for (i, c) in s.char_indices() {
+			let k = kind(c);
+			if i > start && (k == 0 || prev != Some(k)) {
+				tokens.push(&s[start..i]);
+				start = i;
+			}
+			prev = Some(k);
+		}
+		if start < s.len() {
+			tokens.push(&s[start..]);
+		}
+		tokens
+	}
+
+	fn lines(s: &str) -> Vec<&str> {
+		s.split_inclusive('\n').map(|l| l.trim_end_matches(['\n', '\r'])).collect()
+	}
+
+	#[inline]
+	fn printable(s: &str) -> String { replace_to_printable(&[s.to_owned()], PREVIEW.load().tab_size) }
+
+	fn truncate(s: &str, max: usize) -> (String, usize) {
+		let mut width = 0;
+		let mut end = s.len();
+		for (i, c) in s.char_indices() {
+			let w = c.width().unwrap_or(0);
+			if width + w > max {
+				end = i;
+				break;
+			}
+			width += w;
+		}
+		(s[..end].to_owned(), width)
+	}
+
+	#[inline]
+	fn is_binary(buf: &[u8]) -> bool { buf.iter().take(1024).any(|&b| b == 0) }
+}
+
+/// Myers' O(ND) difference algorithm, with the common prefix and suffix
+/// stripped beforehand since they're usually the bulk of it.
+fn diff<T: PartialEq>(a: &[T], b: &[T]) -> Vec<Op> {
+	let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+	let (a, b) = (&a[prefix..], &b[prefix..]);
+	let suffix = a.iter().rev().zip(b.iter().rev()).take_while(|(x, y)| x == y).count();
+	let (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+
+	let mut ops = vec![Op::Same; prefix];
+	ops.extend(myers(a, b).unwrap_or_else(|| {
+		let mut v = vec![Op::Delete; a.len()];
+		v.resize(a.len() + b.len(), Op::Insert);
+		v
+	}));
+	ops.resize(ops.len() + suffix, Op::Same);
+	ops
+}
+
+fn myers<T: PartialEq>(a: &[T], b: &[T]) -> Option<Vec<Op>> {
+	let (n, m) = (a.len() as isize, b.len() as isize);
+	let max = (n + m) as usize;
+	let off = max as isize + 1;
+
+	// The furthest `x` reached on each diagonal `k`, for each number of edits `d`
+	let mut v = vec![0isize; 2 * max + 3];
+	let mut trace = vec![];
+	'outer: for d in 0..=max as isize {
+		if d as usize > MAX_EDITS {
+			return None;
+		}
+
+		trace.push(v[(off - d) as usize..=(off + d) as usize].to_vec());
+		for k in (-d..=d).step_by(2) {
+			let i = (off + k) as usize;
+			let mut x = if k == -d || (k != d && v[i - 1] < v[i + 1]) { v[i + 1] } else { v[i - 1] + 1 };
+			let mut y = x - k;
+			while x < n && y < m && a[x as usize] == b[y as usize] {
+				(x, y) = (x + 1, y + 1);
+			}
+			v[i] = x;
+			if x >= n && y >= m {
+				break 'outer;
+			}
+		}
+	}
+
+	let mut ops = Vec::with_capacity(max);
+	let (mut x, mut y) = (n, m);
+	for (d, v) in trace.iter().enumerate().rev() {
+		let (d, k) = (d as isize, x - y);
+		if d == 0 {
+			ops.extend((0..x).map(|_| Op::Same));
+			break;
+		}
+
+		// `v` holds the state before the `d`-th edit, indexed by `k + d`
+		let get = |k: isize| v.get((k + d) as usize).copied().unwrap_or(-1);
+		let prev = if k == -d || (k != d && get(k - 1) < get(k + 1)) { k + 1 } else { k - 1 };
+		let px = get(prev);
+		let py = px - prev;
+
+		while x > px && y > py {
+			ops.push(Op::Same);
+			(x, y) = (x - 1, y - 1);
+		}
+		ops.push(if x == px { Op::Insert } else { Op::Delete });
+		(x, y) = (px, py);
+	}
+
+	ops.reverse();
+	Some(ops)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn apply(a: &str, b: &str) -> String {
+		let (a, b): (Vec<_>, Vec<_>) = (a.chars().collect(), b.chars().collect());
+		let (mut i, mut j, mut s) = (0, 0, String::new());
+		for op in diff(&a, &b) {
+			match op {
+				Op::Same => {
+					assert_eq!(a[i], b[j]);
+					s.push(a[i]);
+					(i, j) = (i + 1, j + 1);
+				}
+				Op::Delete => i += 1,
+				Op::Insert => {
+					s.push(b[j]);
+					j += 1;
+				}
+			}
+		}
+		assert_eq!((i, j), (a.len(), b.len()));
+		s
+	}
+
+	#[test]
+	fn test_diff() {
+		for (a, b) in [
+			("", ""),
+			("abc", ""),
+			("", "abc"),
+			("abcabba", "cbabac"),
+			("kitten", "sitting"),
+			("the quick brown fox", "the quack brown box"),
+		] {
+			assert_eq!(apply(a, b), b);
+		}
+
+		let ops = diff(&['a', 'b', 'c'], &['a', 'x', 'c']);
+		assert_eq!(ops, [Op::Same, Op::Delete, Op::Insert, Op::Same]);
+	}
+
+	#[test]
+	fn test_rows() {
+		let (a, b) = (["foo", "bar", "baz"], ["foo", "qux", "baz", "end"]);
+		assert_eq!(Differ::rows(&a, &b, false), [
+			Row::Same(0, 0),
+			Row::Delete(1, Some(1)),
+			Row::Same(2, 2),
+			Row::Insert(None, 3)
+		]);
+		assert_eq!(Differ::rows(&a, &b, true), [
+			Row::Same(0, 0),
+			Row::Delete(1, Some(1)),
+			Row::Insert(Some(1), 1),
+			Row::Same(2, 2),
+			Row::Insert(None, 3)
+		]);
+	}
+
+	#[test]
+	fn test_words() {
+		let words = Differ::words("let foo = 1;", Some("let bar = 1;"));
+		let changed: Vec<_> = words.iter().filter(|(_, c)| *c).map(|(w, _)| w.as_ref()).collect();
+		assert_eq!(changed, ["foo"]);
+	}
+}
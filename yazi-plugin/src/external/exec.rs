@@ -0,0 +1,166 @@
+use std::{path::{Path, PathBuf}, process::Stdio, time::SystemTime};
+
+use ansi_to_tui::IntoText;
+use ratatui::{layout::Rect, style::Stylize, text::{Line, Span, Text}};
+use tokio::{io::AsyncReadExt, process::Command};
+use twox_hash::XxHash3_128;
+use yazi_adapter::ADAPTOR;
+use yazi_config::{PREVIEW, plugin::{PreviewOutput, Previewer}};
+use yazi_shared::{errors::PeekError, replace_to_printable};
+
+use crate::process::Sandbox;
+
+/// The most bytes of output read from a command, if the rule has no
+/// `max_bytes` of its own.
+const MAX_BYTES: u64 = 10 << 20;
+
+/// An `exec` previewer, the external command of the rule run on a file.
+///
+/// Its output is cached by the file and its mtime, so the command only runs
+/// again once the file has changed, and not while scrolling.
+pub struct Exec {
+	previewer: &'static Previewer,
+	path:      PathBuf,
+	cache:     PathBuf,
+}
+
+impl Exec {
+	pub fn new(previewer: &'static Previewer, path: &Path, mtime: Option<SystemTime>) -> Self {
+		let hex = {
+			let mut h = XxHash3_128::new();
+			h.write(path.as_os_str().as_encoded_bytes());
+			h.write(format!("//{mtime:?}//{:?}", previewer.exec).as_bytes());
+			format!("{:x}", h.finish_128())
+		};
+
+		Self { previewer, path: path.to_owned(), cache: PREVIEW.load().cache_dir.join(hex) }
+	}
+
+	/// The output to show in `area`, scrolled by `skip` lines. An image is shown
+	/// by itself, and comes with no text.
+	pub async fn render(&self, skip: usize, area: Rect) -> Result<Text<'static>, PeekError> {
+		if self.previewer.output == PreviewOutput::Image {
+			if tokio::fs::metadata(&self.cache).await.is_err() {
+				self.run().await?;
+				tokio::fs::metadata(&self.cache).await.map_err(|_| "The preview command wrote no image")?;
+			}
+			ADAPTOR.get().image_show(&self.cache, area, self.previewer.limits).await?;
+			return Ok(Text::default());
+		}
+
+		let output = match tokio::fs::read(&self.cache).await {
+			Ok(b) => b,
+			Err(_) => {
+				let b = self.run().await?;
+				tokio::fs::write(&self.cache, &b).await.ok();
+				b
+			}
+		};
+
+		let text = if self.previewer.output == PreviewOutput::Json {
+			Self::fields(&output)?
+		} else {
+			output.into_text().map_err(|e| PeekError::Unexpected(e.to_string()))?
+		};
+
+		let height = area.height as usize;
+		if skip > 0 && text.lines.len() < skip + height {
+			return Err(PeekError::Exceed(text.lines.len().saturating_sub(height)));
+		}
+		Ok(Text::from(text.lines.into_iter().skip(skip).take(height).collect::<Vec<_>>()))
+	}
+
+	/// Runs the command, returning what it wrote to stdout, up to the limit.
+	async fn run(&self) -> Result<Vec<u8>, PeekError> {
+		let exec = self.previewer.exec.as_deref().unwrap_or_default();
+		let mut child = self
+			.command(exec)?
+			.stdin(Stdio::null())
+			.stdout(Stdio::piped())
+			.stderr(Stdio::piped())
+			.kill_on_drop(true)
+			.spawn()?;
+
+		let max = self.previewer.limits.max_bytes.unwrap_or(MAX_BYTES);
+		let mut stdout = Vec::new();
+		child.stdout.take().unwrap().take(max).read_to_end(&mut stdout).await?;
+		if stdout.len() as u64 >= max {
+			return Ok(stdout); // Truncated, the rest of it isn't waited for
+		}
+
+		let mut stderr = Vec::new();
+		child.stderr.take().unwrap().take(1024).read_to_end(&mut stderr).await.ok();
+		if child.wait().await?.success() {
+			return Ok(stdout);
+		}
+
+		let stderr = String::from_utf8_lossy(&stderr);
+		Err(match stderr.lines().next() {
+			Some(line) => line.trim().to_owned().into(),
+			None => "The preview command failed".into(),
+		})
+	}
+
+	/// The command to run `exec` with the file and the cache, which is confined
+	/// if the rule is sandboxed.
+	#[cfg(unix)]
+	fn command(&self, exec: &str) -> Result<Command, PeekError> {
+		let mut cmd = if self.previewer.sandbox {
+			let mut cmd = Sandbox::new([self.path.clone()].into_iter())
+				.command()
+				.map_err(|e| PeekError::Unexpected(e.to_string()))?;
+			cmd.args(["--", "sh"]);
+			cmd
+		} else {
+			Command::new("sh")
+		};
+
+		cmd.args(["-c", exec, "sh"]).arg(&self.path).arg(&self.cache);
+		Ok(cmd)
+	}
+
+	#[cfg(windows)]
+	fn command(&self, exec: &str) -> Result<Command, PeekError> {
+		if self.previewer.sandbox {
+			return Err("Sandboxing is only supported on Linux via bubblewrap".into());
+		}
+
+		// `%1` and `%2` are the file and the cache, as `$1` and `$2` on Unix, passed
+		// through variables, as what they expand to isn't expanded again, and quoted
+		// if they aren't yet
+		let mut exec = exec.to_owned();
+		for (n, var) in [("%1", "\"%YAZI_PREVIEW_FILE%\""), ("%2", "\"%YAZI_PREVIEW_CACHE%\"")] {
+			exec = exec.replace(&format!("\"{n}\""), n).replace(n, var);
+		}
+
+		let mut cmd = Command::new("cmd.exe");
+		cmd
+			.env("YAZI_PREVIEW_FILE", &self.path)
+			.env("YAZI_PREVIEW_CACHE", &self.cache)
+			.raw_arg("/S /C")
+			.raw_arg(format!("\"{exec}\""));
+		Ok(cmd)
+	}
+
+	/// The fields of a JSON object, a key and its value on each line.
+	fn fields(b: &[u8]) -> Result<Text<'static>, PeekError> {
+		let value: serde_json::Value =
+			serde_json::from_slice(b).map_err(|e| PeekError::Unexpected(format!("Invalid JSON: {e}")))?;
+		let serde_json::Value::Object(map) = value else {
+			return Err("The JSON isn't an object".into());
+		};
+
+		let width = map.keys().map(|k| k.chars().count()).max().unwrap_or(0);
+		let lines = map.into_iter().map(|(k, v)| {
+			let v = match v {
+				serde_json::Value::String(s) => s,
+				v => v.to_string(),
+			};
+			Line::from(vec![
+				Span::raw(format!("{k:width$}  ")).bold(),
+				Span::raw(replace_to_printable(&[v], PREVIEW.load().tab_size)),
+			])
+		});
+		Ok(Text::from(lines.collect::<Vec<_>>()))
+	}
+}
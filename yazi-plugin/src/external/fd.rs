@@ -3,7 +3,7 @@ use std::process::Stdio;
 use anyhow::Result;
 use tokio::{io::{AsyncBufReadExt, BufReader}, process::{Child, Command}, sync::mpsc::{self, UnboundedReceiver}};
 use yazi_fs::File;
-use yazi_shared::url::Url;
+use yazi_shared::{bytes_to_os_str, url::Url};
 
 pub struct FdOpt {
 	pub cwd:     Url,
@@ -15,11 +15,13 @@ pub struct FdOpt {
 pub fn fd(opt: FdOpt) -> Result<UnboundedReceiver<File>> {
 	let mut child = spawn("fd", &opt).or_else(|_| spawn("fdfind", &opt))?;
 
-	let mut it = BufReader::new(child.stdout.take().unwrap()).lines();
+	let mut it = BufReader::new(child.stdout.take().unwrap()).split(b'\n');
 	let (tx, rx) = mpsc::unbounded_channel();
 
 	tokio::spawn(async move {
-		while let Ok(Some(line)) = it.next_line().await {
+		while let Ok(Some(line)) = it.next_segment().await {
+			let line = line.strip_suffix(b"\r").unwrap_or(&line);
+			let Ok(line) = bytes_to_os_str(line) else { continue };
 			if let Ok(file) = File::from(opt.cwd.join(line)).await {
 				tx.send(file).ok();
 			}
@@ -1,44 +1,61 @@
-use std::{borrow::Cow, io::Cursor, mem, path::{Path, PathBuf}, sync::OnceLock};
+use std::{borrow::Cow, io::Cursor, mem, path::{Path, PathBuf}, sync::{Arc, OnceLock}};
 
 use anyhow::{Result, anyhow};
+use parking_lot::Mutex;
 use ratatui::{layout::Size, text::{Line, Span, Text}};
 use syntect::{LoadingError, dumps, easy::HighlightLines, highlighting::{self, Theme, ThemeSet}, parsing::{SyntaxReference, SyntaxSet}};
-use tokio::{fs::File, io::{AsyncBufReadExt, BufReader}};
+use tokio::{fs::File, io::{AsyncBufReadExt, AsyncReadExt, BufReader}};
 use yazi_config::{PREVIEW, THEME, preview::PreviewWrap};
 use yazi_shared::{Ids, errors::PeekError, replace_to_printable};
 
 static INCR: Ids = Ids::new();
-static SYNTECT: OnceLock<(Theme, SyntaxSet)> = OnceLock::new();
+static SYNTAXES: OnceLock<SyntaxSet> = OnceLock::new();
+// The theme is loaded again once the path to it changes, e.g. on a flavor
+// switch
+static SYNTECT: Mutex<Option<(PathBuf, Arc<Theme>)>> = Mutex::new(None);
 
 pub struct Highlighter {
-	path: PathBuf,
+	path:      PathBuf,
+	max_bytes: Option<u64>,
 }
 
 impl Highlighter {
 	#[inline]
-	pub fn new(path: &Path) -> Self { Self { path: path.to_owned() } }
+	pub fn new(path: &Path) -> Self { Self { path: path.to_owned(), max_bytes: None } }
 
-	pub fn init() -> (&'static Theme, &'static SyntaxSet) {
-		let f = || {
-			let theme = std::fs::File::open(&THEME.manager.syntect_theme)
-				.map_err(LoadingError::Io)
-				.and_then(|f| ThemeSet::load_from_reader(&mut std::io::BufReader::new(f)))
-				.or_else(|_| ThemeSet::load_from_reader(&mut Cursor::new(yazi_prebuild::ansi_theme())));
-
-			let syntaxes = dumps::from_uncompressed_data(yazi_prebuild::syntaxes());
-
-			(theme.unwrap(), syntaxes.unwrap())
-		};
+	#[inline]
+	pub fn max_bytes(mut self, max: Option<u64>) -> Self {
+		self.max_bytes = max;
+		self
+	}
 
-		let (theme, syntaxes) = SYNTECT.get_or_init(f);
-		(theme, syntaxes)
+	pub fn init() -> (Arc<Theme>, &'static SyntaxSet) {
+		let syntaxes =
+			SYNTAXES.get_or_init(|| dumps::from_uncompressed_data(yazi_prebuild::syntaxes()).unwrap());
+
+		let path = THEME.load().manager.syntect_theme.clone();
+		let mut syntect = SYNTECT.lock();
+		match &*syntect {
+			Some((p, theme)) if *p == path => (theme.clone(), syntaxes),
+			_ => {
+				let theme = std::fs::File::open(&path)
+					.map_err(LoadingError::Io)
+					.and_then(|f| ThemeSet::load_from_reader(&mut std::io::BufReader::new(f)))
+					.or_else(|_| ThemeSet::load_from_reader(&mut Cursor::new(yazi_prebuild::ansi_theme())));
+
+				let theme = Arc::new(theme.unwrap());
+				*syntect = Some((path, theme.clone()));
+				(theme, syntaxes)
+			}
+		}
 	}
 
 	#[inline]
 	pub fn abort() { INCR.next(); }
 
 	pub async fn highlight(&self, skip: usize, size: Size) -> Result<Text<'static>, PeekError> {
-		let mut reader = BufReader::new(File::open(&self.path).await?);
+		let file = File::open(&self.path).await?;
+		let mut reader = BufReader::new(file.take(self.max_bytes.unwrap_or(u64::MAX)));
 
 		let syntax = Self::find_syntax(&self.path).await;
 		let mut plain = syntax.is_err();
@@ -72,7 +89,7 @@ impl Highlighter {
 			} else if !plain {
 				before.push(String::from_utf8_lossy(&buf).into_owned());
 				Self::line_height(&before[before.len() - 1], size.width)
-			} else if PREVIEW.wrap == PreviewWrap::Yes {
+			} else if PREVIEW.load().wrap == PreviewWrap::Yes {
 				Self::line_height(&String::from_utf8_lossy(&buf), size.width)
 			} else {
 				1
@@ -89,7 +106,7 @@ impl Highlighter {
 		}
 
 		Ok(if plain {
-			Text::from(replace_to_printable(&after, PREVIEW.tab_size))
+			Text::from(replace_to_printable(&after, PREVIEW.load().tab_size))
 		} else {
 			Self::highlight_with(before, after, syntax.unwrap()).await?
 		})
@@ -104,7 +121,7 @@ impl Highlighter {
 
 		tokio::task::spawn_blocking(move || {
 			let (theme, syntaxes) = Self::init();
-			let mut h = HighlightLines::new(syntax, theme);
+			let mut h = HighlightLines::new(syntax, &theme);
 
 			for line in before {
 				if ticket != INCR.current() {
@@ -113,7 +130,7 @@ impl Highlighter {
 				h.highlight_line(&line, syntaxes).map_err(|e| anyhow!(e))?;
 			}
 
-			let indent = PREVIEW.indent();
+			let indent = PREVIEW.load().indent();
 			let mut lines = Vec::with_capacity(after.len());
 			for line in after {
 				if ticket != INCR.current() {
@@ -158,11 +175,12 @@ impl Highlighter {
 	}
 
 	fn line_height(s: &str, width: u16) -> usize {
-		if PREVIEW.wrap != PreviewWrap::Yes {
+		if PREVIEW.load().wrap != PreviewWrap::Yes {
 			return 1;
 		}
 
 		let pad = PREVIEW
+			.load()
 			.tab_size
 			.checked_sub(1)
 			.map(|n| s.bytes().filter(|&b| b == b'\t').count() * n as usize)
@@ -0,0 +1,140 @@
+use std::path::{Path, PathBuf};
+
+use ratatui::{layout::Rect, style::Stylize, text::{Line, Text}};
+use yazi_adapter::{ADAPTOR, ImageCompare, ImageInfo, ImageOrientation};
+use yazi_config::plugin::Limits;
+use yazi_shared::errors::PeekError;
+
+/// Two images compared side by side or with a wipe, and how their dimensions,
+/// sizes and EXIF differ.
+pub struct ImageDiffer {
+	old:  PathBuf,
+	new:  PathBuf,
+	mode: ImageCompare,
+}
+
+impl ImageDiffer {
+	#[inline]
+	pub fn new(old: &Path, new: &Path, mode: ImageCompare) -> Self {
+		Self { old: old.to_owned(), new: new.to_owned(), mode }
+	}
+
+	/// Shows the images in `area`, above the differences returned, which are
+	/// to be rendered in the rest of it, see [`Self::height`].
+	pub async fn render(&self, area: Rect) -> Result<Text<'static>, PeekError> {
+		let (a, b) = tokio::try_join!(ImageInfo::new(&self.old), ImageInfo::new(&self.new))
+			.map_err(|e| PeekError::Unexpected(e.to_string()))?;
+		let (la, lb) =
+			tokio::try_join!(tokio::fs::metadata(&self.old), tokio::fs::metadata(&self.new))?;
+
+		let mut lines = vec![
+			Self::row(
+				"Dimensions",
+				format!("{}×{}", a.width, a.height),
+				format!("{}×{}", b.width, b.height),
+				Self::delta_dimensions((a.width, a.height), (b.width, b.height)),
+			),
+			Self::row(
+				"Size",
+				Self::readable(la.len()),
+				Self::readable(lb.len()),
+				Self::delta_size(la.len(), lb.len()),
+			),
+			Self::row("Format", format!("{:?}", a.format), format!("{:?}", b.format), None),
+		];
+		if a.taken.is_some() || b.taken.is_some() {
+			let (ta, tb) = (a.taken.map(|t| t.to_string()), b.taken.map(|t| t.to_string()));
+			let delta = a.taken.zip(b.taken).and_then(|(x, y)| Self::delta_time((y - x).num_seconds()));
+			lines.push(Self::row("Taken", ta.unwrap_or("-".into()), tb.unwrap_or("-".into()), delta));
+		}
+		if a.orientation != b.orientation {
+			let f = |o: Option<ImageOrientation>| match o {
+				None | Some(ImageOrientation::NoTransforms) => "Upright".to_owned(),
+				Some(o) => format!("{o:?}"),
+			};
+			lines.push(Self::row("Orientation", f(a.orientation), f(b.orientation), None));
+		}
+
+		let height = lines.len() as u16 + 1;
+		let rect = Rect { height: area.height.saturating_sub(height), ..area };
+		// The differences are still worth showing if the images can't be
+		let shown =
+			ADAPTOR.get().image_compare(&self.old, &self.new, rect, Limits::default(), self.mode);
+		match shown.await {
+			Ok(_) => lines.insert(0, Line::default()),
+			Err(e) => lines.insert(0, Line::from(e.to_string()).reversed()),
+		}
+		Ok(Text::from(lines))
+	}
+
+	fn row(name: &str, a: String, b: String, delta: Option<String>) -> Line<'static> {
+		let same = if a == b { " (same)" } else { "" };
+		match delta {
+			Some(d) => Line::from(format!("{name}: {a} → {b} ({d})")),
+			None => Line::from(format!("{name}: {a} → {b}{same}")),
+		}
+	}
+
+	fn delta_dimensions((w1, h1): (u32, u32), (w2, h2): (u32, u32)) -> Option<String> {
+		if (w1, h1) == (w2, h2) {
+			return None;
+		}
+		let (dw, dh) = (w2 as i64 - w1 as i64, h2 as i64 - h1 as i64);
+		Some(format!("{dw:+}×{dh:+}"))
+	}
+
+	fn delta_size(a: u64, b: u64) -> Option<String> {
+		if a == b {
+			return None;
+		}
+		let sign = if b > a { '+' } else { '-' };
+		Some(format!("{sign}{}", Self::readable(a.abs_diff(b))))
+	}
+
+	fn delta_time(secs: i64) -> Option<String> {
+		if secs == 0 {
+			return None;
+		}
+
+		let (sign, mut n) = (if secs > 0 { '+' } else { '-' }, secs.unsigned_abs());
+		let mut parts = vec![];
+		for (unit, size) in [("d", 86400), ("h", 3600), ("m", 60), ("s", 1)] {
+			if n >= size {
+				parts.push(format!("{}{unit}", n / size));
+				n %= size;
+			}
+		}
+		Some(format!("{sign}{}", parts[..parts.len().min(2)].join(" ")))
+	}
+
+	// The same as `ya.readable_size()`
+	fn readable(size: u64) -> String {
+		const UNITS: [&str; 7] = ["B", "K", "M", "G", "T", "P", "E"];
+		let (mut size, mut i) = (size as f64, 0);
+		while size > 1024.0 && i < UNITS.len() - 1 {
+			size /= 1024.0;
+			i += 1;
+		}
+		format!("{size:.1}{}", UNITS[i]).replacen(".0", "", 1)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_deltas() {
+		assert_eq!(ImageDiffer::delta_time(0), None);
+		assert_eq!(ImageDiffer::delta_time(2).as_deref(), Some("+2s"));
+		assert_eq!(ImageDiffer::delta_time(-3725).as_deref(), Some("-1h 2m"));
+		assert_eq!(ImageDiffer::delta_time(90061).as_deref(), Some("+1d 1h"));
+
+		assert_eq!(ImageDiffer::delta_size(1024, 1024), None);
+		assert_eq!(ImageDiffer::delta_size(2048, 1024).as_deref(), Some("-1024B"));
+		assert_eq!(ImageDiffer::delta_size(0, 1536).as_deref(), Some("+1.5K"));
+
+		assert_eq!(ImageDiffer::delta_dimensions((10, 10), (10, 10)), None);
+		assert_eq!(ImageDiffer::delta_dimensions((10, 10), (8, 12)).as_deref(), Some("-2×+2"));
+	}
+}
@@ -1 +1 @@
-yazi_macro::mod_flat!(fd highlighter rg);
+yazi_macro::mod_flat!(differ exec fd highlighter image_differ rg);
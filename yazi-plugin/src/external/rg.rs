@@ -1,9 +1,9 @@
-use std::process::Stdio;
+use std::{path::{Path, PathBuf}, process::Stdio};
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use tokio::{io::{AsyncBufReadExt, BufReader}, process::Command, sync::mpsc::{self, UnboundedReceiver}};
 use yazi_fs::File;
-use yazi_shared::url::Url;
+use yazi_shared::{bytes_to_os_str, url::Url};
 
 pub struct RgOpt {
 	pub cwd:     Url,
@@ -24,11 +24,13 @@ pub fn rg(opt: RgOpt) -> Result<UnboundedReceiver<File>> {
 		.stderr(Stdio::null())
 		.spawn()?;
 
-	let mut it = BufReader::new(child.stdout.take().unwrap()).lines();
+	let mut it = BufReader::new(child.stdout.take().unwrap()).split(b'\n');
 	let (tx, rx) = mpsc::unbounded_channel();
 
 	tokio::spawn(async move {
-		while let Ok(Some(line)) = it.next_line().await {
+		while let Ok(Some(line)) = it.next_segment().await {
+			let line = line.strip_suffix(b"\r").unwrap_or(&line);
+			let Ok(line) = bytes_to_os_str(line) else { continue };
 			if let Ok(file) = File::from(opt.cwd.join(line)).await {
 				tx.send(file).ok();
 			}
@@ -37,3 +39,54 @@ pub fn rg(opt: RgOpt) -> Result<UnboundedReceiver<File>> {
 	});
 	Ok(rx)
 }
+
+/// A line containing the subject, with its number starting from 1.
+pub struct RgLine {
+	pub path: PathBuf,
+	pub line: usize,
+	pub text: String,
+}
+
+/// Lines of the files under `cwd` that contain `subject` as is.
+pub async fn rg_literal(cwd: &Path, subject: &str) -> Result<Vec<RgLine>> {
+	let output = Command::new("rg")
+		.args([
+			"--color=never",
+			"--fixed-strings",
+			"--line-number",
+			"--with-filename",
+			"--no-heading",
+			"--null",
+		])
+		.arg("--")
+		.arg(subject)
+		.arg(cwd)
+		.kill_on_drop(true)
+		.stdin(Stdio::null())
+		.stderr(Stdio::piped())
+		.output()
+		.await?;
+
+	// Exit code 1 means nothing is found
+	if !output.status.success() && output.status.code() != Some(1) {
+		bail!("ripgrep failed: {}", String::from_utf8_lossy(&output.stderr).trim());
+	}
+
+	let mut lines = vec![];
+	for row in output.stdout.split(|&b| b == b'\n') {
+		let Some(i) = row.iter().position(|&b| b == 0) else { continue };
+		let (path, rest) = (&row[..i], &row[i + 1..]);
+
+		let Some(j) = rest.iter().position(|&b| b == b':') else { continue };
+		let Some(line) = str::from_utf8(&rest[..j]).ok().and_then(|s| s.parse().ok()) else { continue };
+		let Ok(path) = bytes_to_os_str(path) else { continue };
+
+		let text = String::from_utf8_lossy(&rest[j + 1..]);
+		lines.push(RgLine {
+			path: PathBuf::from(path),
+			line,
+			text: text.trim_end_matches('\r').to_owned(),
+		});
+	}
+	Ok(lines)
+}
@@ -1,12 +1,13 @@
 use globset::GlobBuilder;
 use mlua::{ExternalError, ExternalResult, Function, IntoLua, IntoLuaMulti, Lua, Table, Value};
 use tokio::fs;
+use yazi_config::{MANAGER, NETWORK};
 use yazi_fs::{mounts::PARTITIONS, remove_dir_clean};
 
-use crate::{Composer, Error, bindings::Cha, file::File, url::{Url, UrlRef}};
+use crate::{Composer, Error, bindings::Cha, file::File, process::Sandbox, url::{Url, UrlRef}};
 
 pub fn compose(lua: &Lua) -> mlua::Result<Value> {
-	Composer::make(lua, 10, |lua, key| {
+	Composer::make(lua, 11, |lua, key| {
 		match key {
 			b"cwd" => cwd(lua)?,
 			b"cha" => cha(lua)?,
@@ -15,7 +16,9 @@ pub fn compose(lua: &Lua) -> mlua::Result<Value> {
 			b"remove" => remove(lua)?,
 			b"read_dir" => read_dir(lua)?,
 			b"unique_name" => unique_name(lua)?,
+			b"conflict_name" => conflict_name(lua)?,
 			b"partitions" => partitions(lua)?,
+			b"network" => network(lua)?,
 			_ => return Ok(Value::Nil),
 		}
 		.into_lua(lua)
@@ -46,6 +49,7 @@ fn cha(lua: &Lua) -> mlua::Result<Function> {
 
 fn write(lua: &Lua) -> mlua::Result<Function> {
 	lua.create_async_function(|lua, (url, data): (UrlRef, mlua::String)| async move {
+		Sandbox::check(&lua, &url, false)?;
 		match fs::write(&*url, data.as_bytes()).await {
 			Ok(()) => (true, Value::Nil).into_lua_multi(&lua),
 			Err(e) => (false, Error::Io(e)).into_lua_multi(&lua),
@@ -55,6 +59,7 @@ fn write(lua: &Lua) -> mlua::Result<Function> {
 
 fn create(lua: &Lua) -> mlua::Result<Function> {
 	lua.create_async_function(|lua, (type_, url): (mlua::String, UrlRef)| async move {
+		Sandbox::check(&lua, &url, false)?;
 		let result = match type_.as_bytes().as_ref() {
 			b"dir" => fs::create_dir(&*url).await,
 			b"dir_all" => fs::create_dir_all(&*url).await,
@@ -70,6 +75,7 @@ fn create(lua: &Lua) -> mlua::Result<Function> {
 
 fn remove(lua: &Lua) -> mlua::Result<Function> {
 	lua.create_async_function(|lua, (type_, url): (mlua::String, UrlRef)| async move {
+		Sandbox::check(&lua, &url, false)?;
 		let result = match type_.as_bytes().as_ref() {
 			b"file" => fs::remove_file(&*url).await,
 			b"dir" => fs::remove_dir(&*url).await,
@@ -150,6 +156,15 @@ fn unique_name(lua: &Lua) -> mlua::Result<Function> {
 	})
 }
 
+fn conflict_name(lua: &Lua) -> mlua::Result<Function> {
+	lua.create_async_function(|lua, url: UrlRef| async move {
+		match MANAGER.load().conflict.rename(url.clone(), async { false }).await {
+			Ok(u) => (Url(u), Value::Nil).into_lua_multi(&lua),
+			Err(e) => (Value::Nil, Error::Io(e)).into_lua_multi(&lua),
+		}
+	})
+}
+
 fn partitions(lua: &Lua) -> mlua::Result<Function> {
 	lua.create_async_function(|lua, ()| async move {
 		PARTITIONS
@@ -167,3 +182,19 @@ fn partitions(lua: &Lua) -> mlua::Result<Function> {
 			.collect::<mlua::Result<Vec<Table>>>()
 	})
 }
+
+fn network(lua: &Lua) -> mlua::Result<Function> {
+	lua.create_function(|lua, url: UrlRef| {
+		let Some(m) = NETWORK.load().at(&url) else {
+			return Ok(Value::Nil);
+		};
+		lua
+			.create_table_from([
+				("kind", m.kind.into_lua(lua)?),
+				("watch", m.watch.into_lua(lua)?),
+				("sniff", m.sniff.into_lua(lua)?),
+				("timeout", m.timeout.into_lua(lua)?),
+			])?
+			.into_lua(lua)
+	})
+}
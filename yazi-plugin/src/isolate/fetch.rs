@@ -4,11 +4,12 @@ use yazi_dds::Sendable;
 use yazi_shared::event::CmdCow;
 
 use super::slim_lua;
-use crate::{Error, file::File, loader::LOADER};
+use crate::{Error, file::File, loader::LOADER, process::Sandbox};
 
 pub async fn fetch(
 	cmd: CmdCow,
 	files: Vec<yazi_fs::File>,
+	sandbox: bool,
 ) -> mlua::Result<(FetchState, Option<Error>)> {
 	if files.is_empty() {
 		return Ok((FetchState::Bool(true), None));
@@ -17,6 +18,7 @@ pub async fn fetch(
 
 	tokio::task::spawn_blocking(move || {
 		let lua = slim_lua(&cmd.name)?;
+		Sandbox::install(&lua, sandbox, files.iter().map(|f| f.url.to_path()))?;
 		let plugin: Table = if let Some(b) = LOADER.read().get(&cmd.name) {
 			lua.load(b.as_bytes()).set_name(&cmd.name).call(())?
 		} else {
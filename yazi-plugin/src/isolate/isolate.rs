@@ -1,4 +1,5 @@
-use mlua::Lua;
+use mlua::{Lua, Table};
+use yazi_config::plugin::Limits;
 use yazi_macro::plugin_preset as preset;
 
 use crate::runtime::Runtime;
@@ -6,7 +7,7 @@ use crate::runtime::Runtime;
 pub fn slim_lua(name: &str) -> mlua::Result<Lua> {
 	let lua = Lua::new();
 	lua.set_named_registry_value("rt", Runtime::new(name))?;
-	crate::config::Config::new(&lua).install_preview()?.install_plugin()?;
+	crate::config::Config::new(&lua).install_accessibility()?.install_preview()?.install_plugin()?;
 
 	// Base
 	let globals = lua.globals();
@@ -27,3 +28,17 @@ pub fn slim_lua(name: &str) -> mlua::Result<Lua> {
 
 	Ok(lua)
 }
+
+/// Make the limits of the running rule visible to the `ya` functions, as well
+/// as to plugins that read `PREVIEW` directly, e.g. `video` and `pdf`.
+pub(super) fn limit(lua: &Lua, limits: Limits) -> mlua::Result<()> {
+	// Not there in the main Lua, where the sync previewers are run
+	if let Ok(preview) = lua.globals().raw_get::<Table>("PREVIEW") {
+		preview.raw_set("max_width", limits.max_width())?;
+		preview.raw_set("max_height", limits.max_height())?;
+		preview.raw_set("image_quality", limits.image_quality())?;
+	}
+
+	lua.set_app_data(limits);
+	Ok(())
+}
@@ -1,19 +1,21 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, time::Duration};
 
 use mlua::{ExternalError, ExternalResult, HookTriggers, IntoLua, ObjectLike, Table, VmState};
-use tokio::{runtime::Handle, select};
+use ratatui::{style::Stylize, text::Line};
+use tokio::{runtime::Handle, select, time::sleep};
 use tokio_util::sync::CancellationToken;
-use tracing::error;
-use yazi_config::LAYOUT;
+use tracing::{error, warn};
+use yazi_config::{LAYOUT, NETWORK, plugin::{Limits, Previewer}};
 use yazi_dds::Sendable;
+use yazi_macro::emit;
 use yazi_proxy::{AppProxy, options::{PluginCallback, PluginOpt}};
-use yazi_shared::event::Cmd;
+use yazi_shared::{Layer, errors::PeekError, event::Cmd};
 
-use super::slim_lua;
-use crate::{elements::Rect, file::File, loader::LOADER};
+use super::{limit, slim_lua};
+use crate::{elements::{Area, Rect, Renderable, Text, WRAP, WRAP_NO}, external::Exec, file::File, loader::LOADER, process::Sandbox, utils::PreviewLock};
 
 pub fn peek(
-	cmd: &'static Cmd,
+	previewer: &'static Previewer,
 	file: yazi_fs::File,
 	mime: Cow<'static, str>,
 	skip: usize,
@@ -21,11 +23,17 @@ pub fn peek(
 	let ct = CancellationToken::new();
 	let (ct1, ct2) = (ct.clone(), ct.clone());
 
+	let (cmd, timeout) =
+		(&previewer.run, NETWORK.load().timeout(&file.url, previewer.limits.timeout()));
+	let (file_, mime_) = (file.clone(), mime.clone());
+
 	tokio::task::spawn_blocking(move || {
 		let future = async {
 			LOADER.ensure(&cmd.name).await.into_lua_err()?;
 
 			let lua = slim_lua(&cmd.name)?;
+			limit(&lua, previewer.limits)?;
+			Sandbox::install(&lua, previewer.sandbox, [file.url.to_path()].into_iter())?;
 			lua.set_hook(
 				HookTriggers::new().on_calls().on_returns().every_nth_instruction(2000),
 				move |_, dbg| {
@@ -57,6 +65,11 @@ pub fn peek(
 		let result = Handle::current().block_on(async {
 			select! {
 				_ = ct2.cancelled() => Ok(()),
+				_ = expire(timeout) => {
+					ct2.cancel();
+					timed_out(file_, mime_, skip, timeout.unwrap_or_default());
+					Ok(())
+				},
 				r = future => r,
 			}
 		});
@@ -71,7 +84,56 @@ pub fn peek(
 	ct
 }
 
-pub fn peek_sync(cmd: &'static Cmd, file: yazi_fs::File, mime: Cow<'static, str>, skip: usize) {
+/// Previews with the `exec` command of the rule, instead of a plugin.
+pub fn peek_exec(
+	previewer: &'static Previewer,
+	file: yazi_fs::File,
+	mime: Cow<'static, str>,
+	skip: usize,
+) -> CancellationToken {
+	let ct = CancellationToken::new();
+	let (ct_, timeout) = (ct.clone(), NETWORK.load().timeout(&file.url, previewer.limits.timeout()));
+
+	tokio::spawn(async move {
+		let area = LAYOUT.get().preview;
+		let exec = Exec::new(previewer, &file.url, file.cha.mtime);
+
+		let result = select! {
+			_ = ct_.cancelled() => return,
+			_ = expire(timeout) => return timed_out(file, mime, skip, timeout.unwrap_or_default()),
+			r = exec.render(skip, area) => r,
+		};
+
+		let (inner, wrap) = match result {
+			Ok(text) => (text, WRAP_NO),
+			Err(PeekError::Exceed(max)) => {
+				let cmd = Cmd::args("peek", &[max]).with("only-if", file.url_owned());
+				return emit!(Call(cmd.with_bool("upper-bound", true), Layer::Manager));
+			}
+			Err(e) => (Line::from(e.to_string()).reversed().into(), WRAP),
+		};
+
+		let lock = PreviewLock {
+			url: file.url_owned(),
+			cha: file.cha,
+			mime: mime.into_owned(),
+			skip,
+			area: area.into(),
+			data: vec![Renderable::Text(Text { area: Area::Rect(area.into()), inner, wrap })],
+		};
+		emit!(Call(Cmd::new("update_peeked").with_any("lock", lock), Layer::Manager));
+	});
+
+	ct
+}
+
+pub fn peek_sync(
+	previewer: &'static Previewer,
+	file: yazi_fs::File,
+	mime: Cow<'static, str>,
+	skip: usize,
+) {
+	let cmd = &previewer.run;
 	let cb: PluginCallback = Box::new(move |lua, plugin| {
 		let job = lua.create_table_from([
 			("area", Rect::from(LAYOUT.get().preview).into_lua(lua)?),
@@ -81,8 +143,40 @@ pub fn peek_sync(cmd: &'static Cmd, file: yazi_fs::File, mime: Cow<'static, str>
 			("skip", skip.into_lua(lua)?),
 		])?;
 
-		plugin.call_method("peek", job)
+		limit(lua, previewer.limits)?;
+		let result = plugin.call_method("peek", job);
+		limit(lua, Limits::default())?;
+		result
 	});
 
 	AppProxy::plugin(PluginOpt::new_callback(&cmd.name, cb));
 }
+
+async fn expire(timeout: Option<Duration>) {
+	match timeout {
+		Some(d) => sleep(d).await,
+		None => std::future::pending().await,
+	}
+}
+
+fn timed_out(file: yazi_fs::File, mime: Cow<'static, str>, skip: usize, timeout: Duration) {
+	warn!("Previewing {:?} timed out after {timeout:?}", file.url);
+
+	let area = Rect::from(LAYOUT.get().preview);
+	let lock = PreviewLock {
+		url: file.url_owned(),
+		cha: file.cha,
+		mime: mime.into_owned(),
+		skip,
+		area,
+		data: vec![Renderable::Text(Text {
+			area:  Area::Rect(area),
+			inner: Line::from(format!("Preview timed out after {}ms", timeout.as_millis()))
+				.reversed()
+				.into(),
+			wrap:  WRAP,
+		})],
+	};
+
+	emit!(Call(Cmd::new("update_peeked").with_any("lock", lock), Layer::Manager));
+}
@@ -1,20 +1,23 @@
 use mlua::{ExternalError, ExternalResult, IntoLua, ObjectLike, Table, Value};
-use tokio::runtime::Handle;
-use yazi_config::LAYOUT;
+use tokio::{runtime::Handle, time::timeout};
+use yazi_config::{LAYOUT, NETWORK, plugin::Preloader};
 use yazi_dds::Sendable;
-use yazi_shared::event::Cmd;
 
-use super::slim_lua;
-use crate::{Error, elements::Rect, file::File, loader::LOADER};
+use super::{limit, slim_lua};
+use crate::{Error, elements::Rect, file::File, loader::LOADER, process::Sandbox};
 
 pub async fn preload(
-	cmd: &'static Cmd,
+	preloader: &'static Preloader,
 	file: yazi_fs::File,
 ) -> mlua::Result<(bool, Option<Error>)> {
+	let (cmd, expiry) =
+		(&preloader.run, NETWORK.load().timeout(&file.url, preloader.limits.timeout()));
 	LOADER.ensure(&cmd.name).await.into_lua_err()?;
 
 	tokio::task::spawn_blocking(move || {
 		let lua = slim_lua(&cmd.name)?;
+		limit(&lua, preloader.limits)?;
+		Sandbox::install(&lua, preloader.sandbox, [file.url.to_path()].into_iter())?;
 		let plugin: Table = if let Some(b) = LOADER.read().get(&cmd.name) {
 			lua.load(b.as_bytes()).set_name(&cmd.name).call(())?
 		} else {
@@ -28,8 +31,13 @@ pub async fn preload(
 			("skip", 0.into_lua(&lua)?),
 		])?;
 
-		let (ok, mut err): (Value, Option<Error>) =
-			Handle::current().block_on(plugin.call_async_method("preload", job))?;
+		let future = plugin.call_async_method("preload", job);
+		let (ok, mut err): (Value, Option<Error>) = match expiry {
+			Some(d) => Handle::current().block_on(timeout(d, future)).map_err(|_| {
+				format!("Timed out after {}ms", d.as_millis()).into_lua_err()
+			})??,
+			None => Handle::current().block_on(future)?,
+		};
 
 		// TODO: remove this
 		let ok = match ok {
@@ -12,6 +12,7 @@ use super::Chunk;
 
 pub static LOADER: RoCell<Loader> = RoCell::new();
 
+#[derive(Default)]
 pub struct Loader {
 	cache: RwLock<HashMap<String, Chunk>>,
 }
@@ -23,38 +24,17 @@ impl Deref for Loader {
 	fn deref(&self) -> &Self::Target { &self.cache }
 }
 
-impl Default for Loader {
-	fn default() -> Self {
-		let cache = HashMap::from_iter([
-			("archive".to_owned(), preset!("plugins/archive").into()),
-			("code".to_owned(), preset!("plugins/code").into()),
-			("dds".to_owned(), preset!("plugins/dds").into()),
-			("empty".to_owned(), preset!("plugins/empty").into()),
-			("extract".to_owned(), preset!("plugins/extract").into()),
-			("file".to_owned(), preset!("plugins/file").into()),
-			("folder".to_owned(), preset!("plugins/folder").into()),
-			("font".to_owned(), preset!("plugins/font").into()),
-			("fzf".to_owned(), preset!("plugins/fzf").into()),
-			("image".to_owned(), preset!("plugins/image").into()),
-			("json".to_owned(), preset!("plugins/json").into()),
-			("magick".to_owned(), preset!("plugins/magick").into()),
-			("mime".to_owned(), preset!("plugins/mime").into()),
-			("noop".to_owned(), preset!("plugins/noop").into()),
-			("pdf".to_owned(), preset!("plugins/pdf").into()),
-			("session".to_owned(), preset!("plugins/session").into()),
-			("video".to_owned(), preset!("plugins/video").into()),
-			("zoxide".to_owned(), preset!("plugins/zoxide").into()),
-		]);
-		Self { cache: RwLock::new(cache) }
-	}
-}
-
 impl Loader {
 	pub async fn ensure(&self, name: &str) -> Result<()> {
 		if let Some(chunk) = self.cache.read().get(name) {
 			return Self::compatible_or_error(name, chunk);
 		}
 
+		if let Some(chunk) = Self::preset(name) {
+			self.cache.write().entry(name.to_owned()).or_insert(chunk);
+			return Ok(());
+		}
+
 		// TODO: remove this
 		let p = BOOT.plugin_dir.join(format!("{name}.yazi/main.lua"));
 		let chunk = match fs::read(&p).await {
@@ -71,6 +51,7 @@ Please run `ya pack -m` to automatically migrate all plugins, or manually rename
 						),
 						level:   yazi_proxy::options::NotifyLevel::Warn,
 						timeout: std::time::Duration::from_secs(25),
+						..Default::default()
 					});
 				}
 
@@ -118,6 +99,31 @@ Please run `ya pack -m` to automatically migrate all plugins, or manually rename
 		Ok(t)
 	}
 
+	/// The built-in plugins, read on first use rather than all at startup.
+	fn preset(name: &str) -> Option<Chunk> {
+		Some(match name {
+			"archive" => preset!("plugins/archive").into(),
+			"code" => preset!("plugins/code").into(),
+			"dds" => preset!("plugins/dds").into(),
+			"empty" => preset!("plugins/empty").into(),
+			"extract" => preset!("plugins/extract").into(),
+			"file" => preset!("plugins/file").into(),
+			"folder" => preset!("plugins/folder").into(),
+			"font" => preset!("plugins/font").into(),
+			"fzf" => preset!("plugins/fzf").into(),
+			"image" => preset!("plugins/image").into(),
+			"json" => preset!("plugins/json").into(),
+			"magick" => preset!("plugins/magick").into(),
+			"mime" => preset!("plugins/mime").into(),
+			"noop" => preset!("plugins/noop").into(),
+			"pdf" => preset!("plugins/pdf").into(),
+			"session" => preset!("plugins/session").into(),
+			"video" => preset!("plugins/video").into(),
+			"zoxide" => preset!("plugins/zoxide").into(),
+			_ => return None,
+		})
+	}
+
 	pub fn compatible_or_error(name: &str, chunk: &Chunk) -> Result<()> {
 		if chunk.compatible() {
 			return Ok(());
@@ -18,7 +18,13 @@ pub(super) fn init_lua() -> Result<()> {
 
 fn stage_1(lua: &'static Lua) -> Result<()> {
 	lua.set_named_registry_value("rt", Runtime::default())?;
-	crate::config::Config::new(lua).install_boot()?.install_manager()?.install_theme()?;
+	crate::config::Config::new(lua)
+		.install_boot()?
+		.install_accessibility()?
+		.install_manager()?
+		.install_bars()?
+		.install_tag()?
+		.install_theme()?;
 
 	// Base
 	let globals = lua.globals();
@@ -37,6 +43,8 @@ fn stage_1(lua: &'static Lua) -> Result<()> {
 	lua.load(preset!("ya")).set_name("ya.lua").exec()?;
 
 	// Components
+	lua.load(preset!("components/bar")).set_name("bar.lua").exec()?;
+	lua.load(preset!("components/columns")).set_name("columns.lua").exec()?;
 	lua.load(preset!("components/current")).set_name("current.lua").exec()?;
 	lua.load(preset!("components/entity")).set_name("entity.lua").exec()?;
 	lua.load(preset!("components/header")).set_name("header.lua").exec()?;
@@ -49,6 +57,7 @@ fn stage_1(lua: &'static Lua) -> Result<()> {
 	lua.load(preset!("components/progress")).set_name("progress.lua").exec()?;
 	lua.load(preset!("components/rail")).set_name("rail.lua").exec()?;
 	lua.load(preset!("components/root")).set_name("root.lua").exec()?;
+	lua.load(preset!("components/scrollbar")).set_name("scrollbar.lua").exec()?;
 	lua.load(preset!("components/status")).set_name("status.lua").exec()?;
 	lua.load(preset!("components/tab")).set_name("tab.lua").exec()?;
 
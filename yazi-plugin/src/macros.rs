@@ -105,18 +105,9 @@ macro_rules! impl_file_methods {
 		$methods.add_method("hash", |_, me, ()| Ok(me.hash()));
 
 		$methods.add_method("icon", |_, me, ()| {
-			use yazi_shared::theme::IconCache;
 			use $crate::bindings::Icon;
 
-			Ok(match me.icon.get() {
-				IconCache::Missing => {
-					let matched = yazi_config::THEME.icons.matches(me);
-					me.icon.set(matched.map_or(IconCache::Undefined, IconCache::Icon));
-					matched.map(Icon::from)
-				}
-				IconCache::Undefined => None,
-				IconCache::Icon(cached) => Some(Icon::from(cached)),
-			})
+			Ok(yazi_config::THEME.load().icons.matches(me).cloned().map(Icon::from))
 		});
 	};
 }
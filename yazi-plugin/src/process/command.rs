@@ -1,13 +1,17 @@
-use std::process::Stdio;
+use std::{ffi::{OsStr, OsString}, process::Stdio};
 
-use mlua::{AnyUserData, ExternalError, IntoLuaMulti, Lua, MetaMethod, Table, UserData, Value};
+use mlua::{AnyUserData, ExternalError, ExternalResult, IntoLuaMulti, Lua, MetaMethod, Table, UserData, Value};
 use tokio::process::{ChildStderr, ChildStdin, ChildStdout};
+use yazi_shared::bytes_to_os_str;
 
-use super::{Child, output::Output};
+use super::{Child, Sandbox, output::Output};
 use crate::{Error, process::Status};
 
 pub struct Command {
 	inner: tokio::process::Command,
+	/// The program and its arguments when it's sandboxed, which go after the
+	/// options of `bwrap` once it's spawned.
+	argv:  Option<Vec<OsString>>,
 }
 
 const NULL: u8 = 0;
@@ -16,11 +20,14 @@ const INHERIT: u8 = 2;
 
 impl Command {
 	pub fn install(lua: &Lua) -> mlua::Result<()> {
-		let new = lua.create_function(|_, (_, program): (Table, String)| {
-			let mut inner = tokio::process::Command::new(program);
+		let new = lua.create_function(|lua, (_, program): (Table, String)| {
+			let (mut inner, argv) = match lua.app_data_ref::<Sandbox>() {
+				Some(sandbox) => (sandbox.command()?, Some(vec![program.into()])),
+				None => (tokio::process::Command::new(program), None),
+			};
 			inner.kill_on_drop(true).stdin(Stdio::null()).stdout(Stdio::null()).stderr(Stdio::null());
 
-			Ok(Self { inner })
+			Ok(Self { inner, argv })
 		})?;
 
 		let command = lua.create_table_from([
@@ -34,6 +41,21 @@ impl Command {
 
 		lua.globals().raw_set("Command", command)
 	}
+
+	fn arg(&mut self, arg: &OsStr) {
+		match &mut self.argv {
+			Some(argv) => argv.push(arg.to_owned()),
+			None => _ = self.inner.arg(arg),
+		}
+	}
+
+	/// The command ready to spawn, with the sandboxed program put in place.
+	fn take(&mut self) -> &mut tokio::process::Command {
+		if let Some(argv) = self.argv.take() {
+			self.inner.arg("--").args(argv);
+		}
+		&mut self.inner
+	}
 }
 
 impl UserData for Command {
@@ -66,26 +88,39 @@ impl UserData for Command {
 		}
 
 		methods.add_function_mut("arg", |_, (ud, arg): (AnyUserData, mlua::String)| {
-			ud.borrow_mut::<Self>()?.inner.arg(arg.to_string_lossy());
+			ud.borrow_mut::<Self>()?.arg(bytes_to_os_str(&arg.as_bytes()).into_lua_err()?);
 			Ok(ud)
 		});
 		methods.add_function_mut("args", |_, (ud, args): (AnyUserData, Vec<mlua::String>)| {
 			{
 				let mut me = ud.borrow_mut::<Self>()?;
 				for arg in args {
-					me.inner.arg(arg.to_string_lossy());
+					me.arg(bytes_to_os_str(&arg.as_bytes()).into_lua_err()?);
 				}
 			}
 			Ok(ud)
 		});
 		methods.add_function_mut("cwd", |_, (ud, dir): (AnyUserData, mlua::String)| {
-			ud.borrow_mut::<Self>()?.inner.current_dir(dir.to_str()?.as_ref());
+			{
+				let mut me = ud.borrow_mut::<Self>()?;
+				let dir = dir.as_bytes();
+				let dir = bytes_to_os_str(&dir).into_lua_err()?;
+				// Within the sandbox, rather than where `bwrap` itself runs
+				if me.argv.is_some() {
+					me.inner.arg("--chdir").arg(dir);
+				} else {
+					me.inner.current_dir(dir);
+				}
+			}
 			Ok(ud)
 		});
 		methods.add_function_mut(
 			"env",
 			|_, (ud, key, value): (AnyUserData, mlua::String, mlua::String)| {
-				ud.borrow_mut::<Self>()?.inner.env(key.to_string_lossy(), value.to_string_lossy());
+				ud.borrow_mut::<Self>()?.inner.env(
+					bytes_to_os_str(&key.as_bytes()).into_lua_err()?,
+					bytes_to_os_str(&value.as_bytes()).into_lua_err()?,
+				);
 				Ok(ud)
 			},
 		);
@@ -101,18 +136,18 @@ impl UserData for Command {
 			ud.borrow_mut::<Self>()?.inner.stderr(make_stdio(stdio)?);
 			Ok(ud)
 		});
-		methods.add_method_mut("spawn", |lua, me, ()| match me.inner.spawn() {
+		methods.add_method_mut("spawn", |lua, me, ()| match me.take().spawn() {
 			Ok(child) => (Child::new(child), Value::Nil).into_lua_multi(lua),
 			Err(e) => (Value::Nil, Error::Io(e)).into_lua_multi(lua),
 		});
 		methods.add_async_method_mut("output", |lua, mut me, ()| async move {
-			match me.inner.output().await {
+			match me.take().output().await {
 				Ok(output) => (Output::new(output), Value::Nil).into_lua_multi(&lua),
 				Err(e) => (Value::Nil, Error::Io(e)).into_lua_multi(&lua),
 			}
 		});
 		methods.add_async_method_mut("status", |lua, mut me, ()| async move {
-			match me.inner.status().await {
+			match me.take().status().await {
 				Ok(status) => (Status::new(status), Value::Nil).into_lua_multi(&lua),
 				Err(e) => (Value::Nil, Error::Io(e)).into_lua_multi(&lua),
 			}
@@ -1,3 +1,3 @@
 #![allow(clippy::module_inception)]
 
-yazi_macro::mod_flat!(child command output process status);
+yazi_macro::mod_flat!(child command output process sandbox status);
@@ -0,0 +1,166 @@
+use std::path::{Path, PathBuf};
+
+use mlua::{ExternalError, Function, Lua, MultiValue, Table, Value};
+use yazi_config::PREVIEW;
+use yazi_fs::clean_path;
+use yazi_shared::bytes_to_os_str;
+
+/// The confinement of a previewer, preloader or fetcher rule with `sandbox =
+/// true`, which can only read the system and the files it works on, write to
+/// the cache directory, and has no network.
+///
+/// The processes it spawns are run by bubblewrap on Linux, and refused
+/// elsewhere rather than running anything unconfined, while the Lua functions
+/// that'd reach further, e.g. `os.execute` and `io.popen`, are taken away, and
+/// those that write, e.g. `fs.write` and `os.remove`, only work on the cache.
+/// Those that reach the main app, e.g. `ya.manager_emit()`, refuse to run.
+pub(crate) struct Sandbox {
+	files: Vec<PathBuf>,
+}
+
+impl Sandbox {
+	pub(crate) fn install(
+		lua: &Lua,
+		enabled: bool,
+		files: impl Iterator<Item = PathBuf>,
+	) -> mlua::Result<()> {
+		if !enabled {
+			return Ok(());
+		}
+
+		lua.set_app_data(Self::new(files));
+		Self::confine(lua)
+	}
+
+	#[inline]
+	pub(crate) fn new(files: impl Iterator<Item = PathBuf>) -> Self {
+		Self { files: files.collect() }
+	}
+
+	/// The `bwrap` to run a program in the sandbox, where the options, e.g.
+	/// `--chdir`, can still be added, before the program and its arguments after
+	/// a `--`.
+	pub(crate) fn command(&self) -> mlua::Result<tokio::process::Command> {
+		if !cfg!(target_os = "linux") {
+			return Err("Sandboxing is only supported on Linux via bubblewrap".into_lua_err());
+		}
+
+		let mut cmd = tokio::process::Command::new("bwrap");
+		cmd.args(["--unshare-all", "--die-with-parent", "--new-session"]);
+		for dir in ["/usr", "/bin", "/sbin", "/lib", "/lib32", "/lib64", "/etc", "/opt", "/nix/store"] {
+			cmd.args(["--ro-bind-try", dir, dir]);
+		}
+		cmd.args(["--dev", "/dev", "--proc", "/proc", "--tmpfs", "/tmp"]);
+
+		for file in &self.files {
+			cmd.arg("--ro-bind-try").args([file, file]);
+		}
+		cmd.arg("--bind-try").args([&PREVIEW.load().cache_dir, &PREVIEW.load().cache_dir]);
+		Ok(cmd)
+	}
+
+	/// Fails if the Lua state is sandboxed and `path` isn't in the cache
+	/// directory, or one of the files it works on when only `read`.
+	pub(crate) fn check(lua: &Lua, path: &Path, read: bool) -> mlua::Result<()> {
+		let Some(me) = lua.app_data_ref::<Self>() else { return Ok(()) };
+
+		let path = resolve(path);
+		if path.starts_with(resolve(&PREVIEW.load().cache_dir))
+			|| read && me.files.iter().any(|f| resolve(f) == path)
+		{
+			Ok(())
+		} else {
+			Err(format!("{} is out of the sandbox", path.display()).into_lua_err())
+		}
+	}
+
+	/// Fails if the Lua state is sandboxed, for `what` that reaches the main app,
+	/// e.g. emitting its commands, which would run them out of the sandbox.
+	pub(crate) fn refuse(lua: &Lua, what: &str) -> mlua::Result<()> {
+		match lua.app_data_ref::<Self>() {
+			Some(_) => Err(format!("{what} is not available in a sandbox").into_lua_err()),
+			None => Ok(()),
+		}
+	}
+
+	fn confine(lua: &Lua) -> mlua::Result<()> {
+		let globals = lua.globals();
+		for name in ["dofile", "loadfile"] {
+			globals.raw_set(name, Value::Nil)?;
+		}
+
+		let os: Table = globals.raw_get("os")?;
+		for name in ["execute", "exit", "tmpname"] {
+			os.raw_set(name, Value::Nil)?;
+		}
+
+		let remove: Function = os.raw_get("remove")?;
+		os.raw_set(
+			"remove",
+			lua.create_function(move |lua, path: mlua::String| {
+				Self::check(lua, &to_path(&path)?, false)?;
+				remove.call::<MultiValue>(path)
+			})?,
+		)?;
+
+		let rename: Function = os.raw_get("rename")?;
+		os.raw_set(
+			"rename",
+			lua.create_function(move |lua, (from, to): (mlua::String, mlua::String)| {
+				Self::check(lua, &to_path(&from)?, false)?;
+				Self::check(lua, &to_path(&to)?, false)?;
+				rename.call::<MultiValue>((from, to))
+			})?,
+		)?;
+
+		// Only `io.open` is left, for the files it's allowed to
+		let io: Table = globals.raw_get("io")?;
+		let open: Function = io.raw_get("open")?;
+		let open =
+			lua.create_function(move |lua, (path, mode): (mlua::String, Option<mlua::String>)| {
+				let read = mode.as_ref().is_none_or(|m| matches!(m.as_bytes().as_ref(), b"r" | b"rb"));
+				Self::check(lua, &to_path(&path)?, read)?;
+				open.call::<MultiValue>((path, mode))
+			})?;
+		globals.raw_set("io", lua.create_table_from([("open", open)])?)
+	}
+}
+
+/// The path with its symlinks resolved as far as it exists, and the `..` in the
+/// rest that doesn't.
+fn resolve(path: &Path) -> PathBuf {
+	if let Ok(p) = std::fs::canonicalize(path) {
+		return p;
+	}
+	match (path.parent(), path.file_name()) {
+		(Some(parent), Some(name)) => clean_path(resolve(parent).join(name)),
+		_ => clean_path(path),
+	}
+}
+
+fn to_path(s: &mlua::String) -> mlua::Result<PathBuf> {
+	Ok(bytes_to_os_str(&s.as_bytes()).map_err(|e| e.into_lua_err())?.into())
+}
+
+#[cfg(test)]
+mod tests {
+	use mlua::Lua;
+
+	use super::*;
+
+	#[test]
+	fn test_refuse_emit() {
+		let lua = Lua::new();
+		lua.globals().raw_set("ya", crate::utils::compose(&lua, true).unwrap()).unwrap();
+		Sandbox::install(&lua, true, std::iter::empty()).unwrap();
+
+		for code in [
+			r#"ya.manager_emit("shell", { "touch /tmp/escaped" })"#,
+			r#"ya.app_emit("plugin", { "escaped" })"#,
+			r#"ya.input_emit("insert", {})"#,
+		] {
+			let e = lua.load(code).exec().unwrap_err();
+			assert!(e.to_string().contains("not available in a sandbox"), "{code}: {e}");
+		}
+	}
+}
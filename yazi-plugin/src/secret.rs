@@ -0,0 +1,210 @@
+use std::io;
+
+/// The secrets kept in the keyring of the system under the `yazi` service, one
+/// for each key, so that passwords and tokens never have to be written in the
+/// config files.
+///
+/// It's the Secret Service via `secret-tool` on Linux and BSD, the Keychain
+/// via `security` on macOS, and the Credential Manager on Windows.
+pub struct Secret;
+
+const SERVICE: &str = "yazi";
+
+impl Secret {
+	/// The secret stored for `key`, or `None` if there isn't one.
+	#[cfg(all(unix, not(target_os = "macos")))]
+	pub async fn get(key: &str) -> io::Result<Option<String>> {
+		let output =
+			Self::command("secret-tool", &["lookup", "service", SERVICE, "account", key]).await?;
+		if output.status.success() {
+			Ok(Some(String::from_utf8_lossy(&output.stdout).into_owned()))
+		} else if output.stderr.is_empty() {
+			Ok(None)
+		} else {
+			Err(Self::error(&output.stderr))
+		}
+	}
+
+	#[cfg(target_os = "macos")]
+	pub async fn get(key: &str) -> io::Result<Option<String>> {
+		let output =
+			Self::command("security", &["find-generic-password", "-s", SERVICE, "-a", key, "-w"]).await?;
+		match output.status.code() {
+			Some(0) => {
+				let s = String::from_utf8_lossy(&output.stdout);
+				Ok(Some(s.strip_suffix('\n').unwrap_or(&s).to_owned()))
+			}
+			Some(44) => Ok(None), // errSecItemNotFound
+			_ => Err(Self::error(&output.stderr)),
+		}
+	}
+
+	#[cfg(windows)]
+	pub async fn get(key: &str) -> io::Result<Option<String>> {
+		let target = format!("{SERVICE}:{key}");
+		tokio::task::spawn_blocking(move || wincred::read(&target)).await?
+	}
+
+	/// Stores `value` for `key`, replacing the old one, or removes it if `value`
+	/// is `None`.
+	#[cfg(all(unix, not(target_os = "macos")))]
+	pub async fn set(key: &str, value: Option<&str>) -> io::Result<()> {
+		let output = match value {
+			// The secret is written to its stdin, so it never shows up in the arguments
+			Some(v) => {
+				let label = format!("--label={SERVICE}: {key}");
+				Self::piped("secret-tool", &["store", &label, "service", SERVICE, "account", key], v)
+					.await?
+			}
+			None => Self::command("secret-tool", &["clear", "service", SERVICE, "account", key]).await?,
+		};
+
+		if output.status.success() { Ok(()) } else { Err(Self::error(&output.stderr)) }
+	}
+
+	/// On macOS, `security` only takes the secret as an argument, so it's given
+	/// in a command written to its stdin instead, which it reads in the
+	/// interactive mode.
+	#[cfg(target_os = "macos")]
+	pub async fn set(key: &str, value: Option<&str>) -> io::Result<()> {
+		let Some(value) = value else {
+			let output =
+				Self::command("security", &["delete-generic-password", "-s", SERVICE, "-a", key]).await?;
+			return match output.status.code() {
+				Some(0 | 44) => Ok(()),
+				_ => Err(Self::error(&output.stderr)),
+			};
+		};
+
+		if value.contains(['\n', '\r']) {
+			return Err(io::Error::new(
+				io::ErrorKind::InvalidInput,
+				"A secret cannot span multiple lines",
+			));
+		}
+
+		let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+		let line = format!(
+			"add-generic-password -U -s {} -a {} -w {}\n",
+			quote(SERVICE),
+			quote(key),
+			quote(value)
+		);
+
+		// It goes on after a command fails, which is only told by what it prints
+		let output = Self::piped("security", &["-i"], &line).await?;
+		if output.status.success() && output.stderr.is_empty() {
+			Ok(())
+		} else {
+			Err(Self::error(&output.stderr))
+		}
+	}
+
+	#[cfg(windows)]
+	pub async fn set(key: &str, value: Option<&str>) -> io::Result<()> {
+		let (target, key) = (format!("{SERVICE}:{key}"), key.to_owned());
+		let value = value.map(ToOwned::to_owned);
+		tokio::task::spawn_blocking(move || match value {
+			Some(v) => wincred::write(&target, &key, &v),
+			None => wincred::delete(&target),
+		})
+		.await?
+	}
+
+	#[cfg(unix)]
+	async fn command(bin: &str, args: &[&str]) -> io::Result<std::process::Output> {
+		use std::process::Stdio;
+
+		tokio::process::Command::new(bin)
+			.args(args)
+			.stdin(Stdio::null())
+			.kill_on_drop(true)
+			.output()
+			.await
+			.map_err(|e| Self::not_installed(bin, e))
+	}
+
+	#[cfg(unix)]
+	async fn piped(bin: &str, args: &[&str], input: &str) -> io::Result<std::process::Output> {
+		use std::process::Stdio;
+
+		use tokio::io::AsyncWriteExt;
+
+		let mut child = tokio::process::Command::new(bin)
+			.args(args)
+			.stdin(Stdio::piped())
+			.stdout(Stdio::null())
+			.stderr(Stdio::piped())
+			.kill_on_drop(true)
+			.spawn()
+			.map_err(|e| Self::not_installed(bin, e))?;
+
+		let mut stdin = child.stdin.take().unwrap();
+		stdin.write_all(input.as_bytes()).await?;
+		drop(stdin);
+
+		child.wait_with_output().await
+	}
+
+	#[cfg(unix)]
+	fn not_installed(bin: &str, e: io::Error) -> io::Error {
+		match e.kind() {
+			io::ErrorKind::NotFound => io::Error::new(e.kind(), format!("`{bin}` isn't installed")),
+			_ => e,
+		}
+	}
+
+	#[cfg(unix)]
+	fn error(stderr: &[u8]) -> io::Error {
+		io::Error::other(String::from_utf8_lossy(stderr).trim().to_owned())
+	}
+}
+
+#[cfg(windows)]
+mod wincred {
+	use std::{io, os::windows::ffi::OsStrExt, ptr, slice};
+
+	use windows_sys::Win32::{Foundation::ERROR_NOT_FOUND, Security::Credentials::{CRED_PERSIST_LOCAL_MACHINE, CRED_TYPE_GENERIC, CREDENTIALW, CredDeleteW, CredFree, CredReadW, CredWriteW}};
+
+	fn wide(s: &str) -> Vec<u16> { std::ffi::OsStr::new(s).encode_wide().chain(Some(0)).collect() }
+
+	pub(super) fn read(target: &str) -> io::Result<Option<String>> {
+		let mut cred: *mut CREDENTIALW = ptr::null_mut();
+		if unsafe { CredReadW(wide(target).as_ptr(), CRED_TYPE_GENERIC, 0, &mut cred) } == 0 {
+			let e = io::Error::last_os_error();
+			return if e.raw_os_error() == Some(ERROR_NOT_FOUND as _) { Ok(None) } else { Err(e) };
+		}
+
+		let s = unsafe {
+			let c = &*cred;
+			let blob = slice::from_raw_parts(c.CredentialBlob, c.CredentialBlobSize as usize);
+			String::from_utf8_lossy(blob).into_owned()
+		};
+		unsafe { CredFree(cred as _) };
+		Ok(Some(s))
+	}
+
+	pub(super) fn write(target: &str, user: &str, value: &str) -> io::Result<()> {
+		let (mut target, mut user) = (wide(target), wide(user));
+		let cred = CREDENTIALW {
+			Type: CRED_TYPE_GENERIC,
+			TargetName: target.as_mut_ptr(),
+			CredentialBlobSize: value.len() as u32,
+			CredentialBlob: value.as_ptr() as *mut u8,
+			Persist: CRED_PERSIST_LOCAL_MACHINE,
+			UserName: user.as_mut_ptr(),
+			..unsafe { std::mem::zeroed() }
+		};
+
+		if unsafe { CredWriteW(&cred, 0) } == 0 { Err(io::Error::last_os_error()) } else { Ok(()) }
+	}
+
+	pub(super) fn delete(target: &str) -> io::Result<()> {
+		if unsafe { CredDeleteW(wide(target).as_ptr(), CRED_TYPE_GENERIC, 0) } != 0 {
+			return Ok(());
+		}
+
+		let e = io::Error::last_os_error();
+		if e.raw_os_error() == Some(ERROR_NOT_FOUND as _) { Ok(()) } else { Err(e) }
+	}
+}
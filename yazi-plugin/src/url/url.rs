@@ -1,4 +1,7 @@
-use mlua::{ExternalError, IntoLua, Lua, MetaMethod, UserDataFields, UserDataMethods, UserDataRef, Value};
+use std::path::Path;
+
+use mlua::{ExternalError, ExternalResult, IntoLua, Lua, MetaMethod, UserDataFields, UserDataMethods, UserDataRef, Value};
+use yazi_shared::bytes_to_os_str;
 
 pub type UrlRef = UserDataRef<yazi_shared::url::Url>;
 
@@ -25,7 +28,7 @@ impl Url {
 			});
 			reg.add_method("join", |_, me, other: Value| {
 				Ok(Self(match other {
-					Value::String(s) => me.join(s.to_str()?.as_ref()),
+					Value::String(s) => me.join(bytes_to_os_str(&s.as_bytes()).into_lua_err()?),
 					Value::UserData(ud) => me.join(&*ud.borrow::<yazi_shared::url::Url>()?),
 					_ => Err("must be a string or a Url".into_lua_err())?,
 				}))
@@ -33,21 +36,21 @@ impl Url {
 			reg.add_method("parent", |_, me, ()| Ok(me.parent_url().map(Self)));
 			reg.add_method("starts_with", |_, me, base: Value| {
 				Ok(match base {
-					Value::String(s) => me.starts_with(s.to_str()?.as_ref()),
+					Value::String(s) => me.starts_with(bytes_to_os_str(&s.as_bytes()).into_lua_err()?),
 					Value::UserData(ud) => me.starts_with(&*ud.borrow::<yazi_shared::url::Url>()?),
 					_ => Err("must be a string or a Url".into_lua_err())?,
 				})
 			});
 			reg.add_method("ends_with", |_, me, child: Value| {
 				Ok(match child {
-					Value::String(s) => me.ends_with(s.to_str()?.as_ref()),
+					Value::String(s) => me.ends_with(bytes_to_os_str(&s.as_bytes()).into_lua_err()?),
 					Value::UserData(ud) => me.ends_with(&*ud.borrow::<yazi_shared::url::Url>()?),
 					_ => Err("must be a string or a Url".into_lua_err())?,
 				})
 			});
 			reg.add_method("strip_prefix", |_, me, base: Value| {
 				let path = match base {
-					Value::String(s) => me.strip_prefix(s.to_str()?.as_ref()),
+					Value::String(s) => me.strip_prefix(bytes_to_os_str(&s.as_bytes()).into_lua_err()?),
 					Value::UserData(ud) => me.strip_prefix(&*ud.borrow::<yazi_shared::url::Url>()?),
 					_ => Err("must be a string or a Url".into_lua_err())?,
 				};
@@ -67,7 +70,12 @@ impl Url {
 	pub fn install(lua: &Lua) -> mlua::Result<()> {
 		lua.globals().raw_set(
 			"Url",
-			lua.create_function(|_, url: mlua::String| Ok(Self::from(url.to_str()?.as_ref())))?,
+			lua.create_function(|_, url: mlua::String| {
+				Ok(match url.to_str() {
+					Ok(s) => Self::from(s.as_ref()),
+					Err(_) => Self::from(Path::new(bytes_to_os_str(&url.as_bytes()).into_lua_err()?)),
+				})
+			})?,
 		)
 	}
 }
@@ -9,7 +9,7 @@ impl Utils {
 	pub(super) fn file_cache(lua: &Lua) -> mlua::Result<Function> {
 		lua.create_function(|_, t: Table| {
 			let file: FileRef = t.raw_get("file")?;
-			if file.url.parent() == Some(&PREVIEW.cache_dir) {
+			if file.url.parent() == Some(&PREVIEW.load().cache_dir) {
 				return Ok(None);
 			}
 
@@ -20,7 +20,7 @@ impl Utils {
 				format!("{:x}", h.finish_128())
 			};
 
-			Ok(Some(Url::from(PREVIEW.cache_dir.join(hex))))
+			Ok(Some(Url::from(PREVIEW.load().cache_dir.join(hex))))
 		})
 	}
 }
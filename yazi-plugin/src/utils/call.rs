@@ -6,6 +6,7 @@ use yazi_macro::{emit, render};
 use yazi_shared::{Layer, event::Cmd};
 
 use super::Utils;
+use crate::process::Sandbox;
 
 impl Utils {
 	pub(super) fn render(lua: &Lua) -> mlua::Result<Function> {
@@ -39,21 +40,24 @@ impl Utils {
 	}
 
 	pub(super) fn app_emit(lua: &Lua) -> mlua::Result<Function> {
-		lua.create_function(|_, (name, args): (String, Table)| {
+		lua.create_function(|lua, (name, args): (String, Table)| {
+			Sandbox::refuse(lua, "`ya.app_emit()`")?;
 			emit!(Call(Cmd { name, args: Sendable::table_to_args(args)? }, Layer::App));
 			Ok(())
 		})
 	}
 
 	pub(super) fn manager_emit(lua: &Lua) -> mlua::Result<Function> {
-		lua.create_function(|_, (name, args): (String, Table)| {
+		lua.create_function(|lua, (name, args): (String, Table)| {
+			Sandbox::refuse(lua, "`ya.manager_emit()`")?;
 			emit!(Call(Cmd { name, args: Sendable::table_to_args(args)? }, Layer::Manager));
 			Ok(())
 		})
 	}
 
 	pub(super) fn input_emit(lua: &Lua) -> mlua::Result<Function> {
-		lua.create_function(|_, (name, args): (String, Table)| {
+		lua.create_function(|lua, (name, args): (String, Table)| {
+			Sandbox::refuse(lua, "`ya.input_emit()`")?;
 			emit!(Call(Cmd { name, args: Sendable::table_to_args(args)? }, Layer::Input));
 			Ok(())
 		})
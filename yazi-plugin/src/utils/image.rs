@@ -1,5 +1,6 @@
 use mlua::{Function, IntoLua, Lua, Value};
 use yazi_adapter::{ADAPTOR, Image};
+use yazi_config::{ACCESSIBILITY, plugin::Limits};
 
 use super::Utils;
 use crate::{bindings::ImageInfo, elements::Rect, url::UrlRef};
@@ -17,7 +18,12 @@ impl Utils {
 
 	pub(super) fn image_show(lua: &Lua) -> mlua::Result<Function> {
 		lua.create_async_function(|lua, (url, rect): (UrlRef, Rect)| async move {
-			if let Ok(area) = ADAPTOR.get().image_show(&url, *rect).await {
+			if ACCESSIBILITY.enabled {
+				return Value::Nil.into_lua(&lua);
+			}
+
+			let limits = Self::limits(&lua);
+			if let Ok(area) = ADAPTOR.get().image_show(&url, *rect, limits).await {
 				Rect::from(area).into_lua(&lua)
 			} else {
 				Value::Nil.into_lua(&lua)
@@ -26,8 +32,12 @@ impl Utils {
 	}
 
 	pub(super) fn image_precache(lua: &Lua) -> mlua::Result<Function> {
-		lua.create_async_function(|_, (src, dist): (UrlRef, UrlRef)| async move {
-			Ok(Image::precache(&src, dist.to_path_buf()).await.is_ok())
+		lua.create_async_function(|lua, (src, dist): (UrlRef, UrlRef)| async move {
+			Ok(Image::precache(&src, dist.to_path_buf(), Self::limits(&lua)).await.is_ok())
 		})
 	}
+
+	/// Limits of the preloader or previewer rule that is running.
+	#[inline]
+	pub(super) fn limits(lua: &Lua) -> Limits { lua.app_data_ref().map(|l| *l).unwrap_or_default() }
 }
@@ -1,4 +1,4 @@
-use std::{str::FromStr, time::Duration};
+use std::{str::FromStr, sync::Arc, time::Duration};
 
 use mlua::{ExternalError, ExternalResult, Function, IntoLuaMulti, Lua, Table, Value};
 use tokio::sync::mpsc;
@@ -21,8 +21,9 @@ impl Utils {
 				let cand = cand?;
 				cands.push(Chord {
 					on:   Self::parse_keys(cand.raw_get("on")?)?,
-					run:  vec![Cmd::args("callback", &[i]).with_any("tx", tx.clone())],
+					run:  vec![Arc::new(Cmd::args("callback", &[i]).with_any("tx", tx.clone()))],
 					desc: cand.raw_get("desc").ok(),
+					when: vec![],
 				});
 			}
 
@@ -1,5 +1,5 @@
 #![allow(clippy::module_inception)]
 
 yazi_macro::mod_flat!(
-	app cache call image json layer log preview spot sync target text time user utils
+	app cache call image json layer log preview secret spot sync target text time user utils
 );
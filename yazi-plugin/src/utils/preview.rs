@@ -40,7 +40,11 @@ impl Utils {
 			let area: Area = t.raw_get("area")?;
 			let mut lock = PreviewLock::try_from(t)?;
 
-			let inner = match Highlighter::new(&lock.url).highlight(lock.skip, area.size()).await {
+			let inner = match Highlighter::new(&lock.url)
+				.max_bytes(Self::limits(&lua).max_bytes)
+				.highlight(lock.skip, area.size())
+				.await
+			{
 				Ok(text) => text,
 				Err(e @ PeekError::Exceed(max)) => return (e.to_string(), max).into_lua_multi(&lua),
 				Err(e @ PeekError::Unexpected(_)) => {
@@ -51,7 +55,7 @@ impl Utils {
 			lock.data = vec![Renderable::Text(Text {
 				area,
 				inner,
-				wrap: if PREVIEW.wrap == PreviewWrap::Yes { WRAP } else { WRAP_NO },
+				wrap: if PREVIEW.load().wrap == PreviewWrap::Yes { WRAP } else { WRAP_NO },
 			})];
 
 			emit!(Call(Cmd::new("update_peeked").with_any("lock", lock), Layer::Manager));
@@ -0,0 +1,55 @@
+use mlua::{ExternalError, IntoLuaMulti, Lua, Table, Value};
+use yazi_config::PLUGIN;
+
+use super::Utils;
+use crate::{Error, RtRef, Secret, process::Sandbox};
+
+impl Utils {
+	/// `ya.secret.get(key)` and `ya.secret.set(key, value)`, to the keyring of
+	/// the system, where the keys of each plugin are its own.
+	///
+	/// Only the plugins listed in `secrets` of the `[plugin]` section can use
+	/// them, and never the sandboxed previewers, preloaders and fetchers.
+	pub(super) fn secret(lua: &Lua) -> mlua::Result<Table> {
+		let get = lua.create_async_function(|lua, key: mlua::String| async move {
+			let key = namespaced(&lua, &key.to_str()?)?;
+			match Secret::get(&key).await {
+				Ok(Some(s)) => (s, Value::Nil).into_lua_multi(&lua),
+				Ok(None) => Value::Nil.into_lua_multi(&lua),
+				Err(e) => (Value::Nil, Error::Io(e)).into_lua_multi(&lua),
+			}
+		})?;
+
+		let set = lua.create_async_function(
+			|lua, (key, value): (mlua::String, Option<mlua::String>)| async move {
+				let key = namespaced(&lua, &key.to_str()?)?;
+				let value = value.as_ref().map(|v| v.to_str()).transpose()?;
+				match Secret::set(&key, value.as_deref()).await {
+					Ok(()) => (true, Value::Nil).into_lua_multi(&lua),
+					Err(e) => (false, Error::Io(e)).into_lua_multi(&lua),
+				}
+			},
+		)?;
+
+		lua.create_table_from([("get", get), ("set", set)])
+	}
+}
+
+fn namespaced(lua: &Lua, key: &str) -> mlua::Result<String> {
+	if lua.app_data_ref::<Sandbox>().is_some() {
+		return Err("Secrets are not available in a sandbox".into_lua_err());
+	} else if key.is_empty() {
+		return Err("The key of a secret cannot be empty".into_lua_err());
+	}
+
+	match lua.named_registry_value::<RtRef>("rt")?.current() {
+		Some(id) if PLUGIN.secrets.contains(id) => Ok(format!("plugin/{id}/{key}")),
+		Some(id) => Err(
+			format!(
+				"Plugin `{id}` isn't allowed to use secrets, add it to `secrets` in the [plugin] section"
+			)
+			.into_lua_err(),
+		),
+		None => Err("Secrets can only be used by plugins".into_lua_err()),
+	}
+}
@@ -70,12 +70,12 @@ impl Utils {
 					area,
 					position: ratatui::widgets::Borders::ALL,
 					type_: ratatui::widgets::BorderType::Rounded,
-					style: THEME.spot.border.into(),
+					style: THEME.load().spot.border.into(),
 					titles: vec![(
 						ratatui::widgets::block::Position::Top,
-						ratatui::text::Line::raw(lock.url.name().to_string_lossy().into_owned())
+						ratatui::text::Line::raw(yazi_shared::escape_os_str(lock.url.name()).into_owned())
 							.centered()
-							.style(THEME.spot.title),
+							.style(THEME.load().spot.title),
 					)],
 				}),
 				Renderable::Table(table),
@@ -3,10 +3,11 @@ use std::ops::ControlFlow;
 use md5::{Digest, Md5};
 use mlua::{Function, Lua, Table};
 use twox_hash::XxHash3_128;
-use unicode_width::UnicodeWidthChar;
+use unicode_segmentation::UnicodeSegmentation;
+use yazi_shared::Widths;
 
 use super::Utils;
-use crate::CLIPBOARD;
+use crate::{CLIPBOARD, process::Sandbox};
 
 impl Utils {
 	pub(super) fn hash(lua: &Lua, deprecated: bool) -> mlua::Result<Function> {
@@ -36,12 +37,12 @@ impl Utils {
 	}
 
 	pub(super) fn truncate(lua: &Lua) -> mlua::Result<Function> {
-		fn truncate_impl(mut chars: impl Iterator<Item = char>, max: usize) -> Vec<char> {
+		fn truncate_impl<'a>(mut graphemes: impl Iterator<Item = &'a str>, max: usize) -> Vec<&'a str> {
 			let mut width = 0;
-			let flow = chars.try_fold(Vec::with_capacity(max), |mut v, c| {
-				width += c.width().unwrap_or(0);
+			let flow = graphemes.try_fold(Vec::with_capacity(max), |mut v, g| {
+				width += Widths::str(g);
 				if width < max {
-					v.push(c);
+					v.push(g);
 					ControlFlow::Continue(v)
 				} else {
 					ControlFlow::Break(v)
@@ -58,15 +59,16 @@ impl Utils {
 			let (max, text) = (t.raw_get("max")?, text.to_string_lossy());
 
 			Ok(if t.raw_get("rtl").unwrap_or(false) {
-				truncate_impl(text.chars().rev(), max).into_iter().rev().collect()
+				truncate_impl(text.graphemes(true).rev(), max).into_iter().rev().collect()
 			} else {
-				truncate_impl(text.chars(), max).into_iter().collect::<String>()
+				truncate_impl(text.graphemes(true), max).into_iter().collect::<String>()
 			})
 		})
 	}
 
 	pub(super) fn clipboard(lua: &Lua) -> mlua::Result<Function> {
 		lua.create_async_function(|lua, text: Option<String>| async move {
+			Sandbox::refuse(&lua, "`ya.clipboard()`")?;
 			if let Some(text) = text {
 				CLIPBOARD.set(text).await;
 				Ok(None)
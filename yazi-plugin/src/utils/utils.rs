@@ -44,6 +44,9 @@ pub fn compose(lua: &Lua, isolate: bool) -> mlua::Result<Value> {
 			b"preview_code" => Utils::preview_code(lua)?,
 			b"preview_widgets" => Utils::preview_widgets(lua)?,
 
+			// Secret
+			b"secret" => return Utils::secret(lua)?.into_lua(lua),
+
 			// Spot
 			b"spot_table" => Utils::spot_table(lua)?,
 			b"spot_widgets" => Utils::spot_widgets(lua)?,
@@ -1,10 +1,11 @@
 use std::time::Duration;
 
 use tokio::sync::oneshot;
+use yazi_config::t;
 use yazi_macro::emit;
-use yazi_shared::{Layer, event::Cmd};
+use yazi_shared::{Layer, errors::ErrorReport, event::Cmd};
 
-use crate::options::{NotifyLevel, NotifyOpt, PluginOpt};
+use crate::options::{NotifyLevel, NotifyOpt, OutputOpt, PluginOpt};
 
 pub struct AppProxy;
 
@@ -22,30 +23,87 @@ impl AppProxy {
 	}
 
 	#[inline]
-	pub fn notify(opt: NotifyOpt) {
+	pub fn resize() {
+		emit!(Call(Cmd::new("resize"), Layer::App));
+	}
+
+	#[inline]
+	pub fn theme() {
+		emit!(Call(Cmd::new("theme"), Layer::App));
+	}
+
+	#[inline]
+	pub fn theme_reload() {
+		emit!(Call(Cmd::new("theme").with_bool("reload", true), Layer::App));
+	}
+
+	#[inline]
+	pub fn reload() {
+		emit!(Call(Cmd::new("reload"), Layer::App));
+	}
+
+	/// Show a notification, with its title and content translated if the
+	/// catalogs of `[i18n]` have them.
+	#[inline]
+	pub fn notify(mut opt: NotifyOpt) {
+		opt.title = t!(&opt.title);
+		opt.content = t!(&opt.content);
 		emit!(Call(Cmd::new("notify").with_any("option", opt), Layer::App));
 	}
 
+	#[inline]
+	pub fn notify_info(title: &str, content: impl ToString) {
+		Self::notify(NotifyOpt {
+			title: title.to_owned(),
+			content: content.to_string(),
+			level: NotifyLevel::Info,
+			timeout: Duration::from_secs(5),
+			..Default::default()
+		});
+	}
+
 	#[inline]
 	pub fn notify_warn(title: &str, content: impl ToString) {
 		Self::notify(NotifyOpt {
-			title:   title.to_owned(),
+			title: title.to_owned(),
 			content: content.to_string(),
-			level:   NotifyLevel::Warn,
+			level: NotifyLevel::Warn,
 			timeout: Duration::from_secs(5),
+			..Default::default()
 		});
 	}
 
 	#[inline]
 	pub fn notify_error(title: &str, content: impl ToString) {
 		Self::notify(NotifyOpt {
-			title:   title.to_owned(),
+			title: title.to_owned(),
 			content: content.to_string(),
-			level:   NotifyLevel::Error,
+			level: NotifyLevel::Error,
+			timeout: Duration::from_secs(10),
+			..Default::default()
+		});
+	}
+
+	/// Show an error with its code, and the details of it to expand in the
+	/// notification history.
+	#[inline]
+	pub fn notify_report(title: &str, report: ErrorReport) {
+		Self::notify(NotifyOpt {
+			title: title.to_owned(),
+			content: report.message.clone(),
+			level: NotifyLevel::Error,
 			timeout: Duration::from_secs(10),
+			report: Some(report),
+			..Default::default()
 		});
 	}
 
+	/// Keep what a command printed, and show it once the TUI is back.
+	#[inline]
+	pub fn output(opt: OutputOpt) {
+		emit!(Call(Cmd::new("output").with_any("option", opt), Layer::App));
+	}
+
 	#[inline]
 	pub fn plugin(opt: PluginOpt) {
 		emit!(Call(Cmd::new("plugin").with_any("opt", opt), Layer::App));
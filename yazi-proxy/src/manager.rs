@@ -35,18 +35,67 @@ impl ManagerProxy {
 	}
 
 	#[inline]
-	pub fn remove_do(targets: Vec<Url>, permanently: bool) {
+	pub fn paste(force: bool, follow: bool, register: Option<char>, dest: Option<Url>, jump: bool) {
 		emit!(Call(
-			Cmd::new("remove_do").with_bool("permanently", permanently).with_any("targets", targets),
+			Cmd::new("paste")
+				.with_bool("force", force)
+				.with_bool("follow", follow)
+				.with_opt("register", register)
+				.with_opt("dest", dest)
+				.with_bool("jump", jump)
+				.with_bool("confirmed", true),
 			Layer::Manager
 		));
 	}
 
+	#[inline]
+	pub fn paste_do(targets: Vec<(Url, Url)>, cut: bool, force: bool, follow: bool) {
+		emit!(Call(
+			Cmd::new("paste_do")
+				.with_bool("cut", cut)
+				.with_bool("force", force)
+				.with_bool("follow", follow)
+				.with_any("targets", targets),
+			Layer::Manager
+		));
+	}
+
+	#[inline]
+	pub fn remove_do(targets: Vec<Url>, permanently: bool, secure: bool) {
+		emit!(Call(
+			Cmd::new("remove_do")
+				.with_bool("permanently", permanently)
+				.with_bool("secure", secure)
+				.with_any("targets", targets),
+			Layer::Manager
+		));
+	}
+
+	#[inline]
+	pub fn empty_trash() {
+		emit!(Call(Cmd::new("empty_trash").with_bool("force", true), Layer::Manager));
+	}
+
 	#[inline]
 	pub fn update_tasks(url: &Url) {
 		emit!(Call(Cmd::new("update_tasks").with_any("urls", vec![url.clone()]), Layer::Manager));
 	}
 
+	#[inline]
+	pub fn update_journal(from: &Url, to: &Url, cut: bool) {
+		emit!(Call(
+			Cmd::new("update_journal")
+				.with_any("paste", (from.clone(), to.clone()))
+				.with_bool("cut", cut),
+			Layer::Manager
+		));
+	}
+
+	#[inline]
+	pub fn update_tags(moved: Vec<(Url, Url)>) {
+		emit!(Call(Cmd::new("update_tags").with_any("moved", moved), Layer::Manager));
+	}
+
 	#[inline]
 	pub fn update_paged() {
 		emit!(Call(Cmd::new("update_paged"), Layer::Manager));
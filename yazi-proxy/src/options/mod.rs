@@ -1 +1 @@
-yazi_macro::mod_flat!(notify open plugin process search);
+yazi_macro::mod_flat!(notify open output plugin process search);
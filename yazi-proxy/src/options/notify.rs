@@ -1,15 +1,18 @@
 use std::{str::FromStr, time::Duration};
 
 use mlua::{ExternalError, ExternalResult};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use yazi_config::THEME;
-use yazi_shared::{event::CmdCow, theme::Style};
+use yazi_shared::{errors::ErrorReport, event::{Cmd, CmdCow}, theme::Style};
 
+#[derive(Default)]
 pub struct NotifyOpt {
 	pub title:   String,
 	pub content: String,
 	pub level:   NotifyLevel,
 	pub timeout: Duration,
+	pub actions: Vec<NotifyAction>,
+	pub report:  Option<ErrorReport>,
 }
 
 impl TryFrom<CmdCow> for NotifyOpt {
@@ -33,16 +36,43 @@ impl TryFrom<mlua::Table> for NotifyOpt {
 			Default::default()
 		};
 
+		let actions = match t.raw_get::<Option<mlua::Table>>("actions")? {
+			Some(t) => {
+				t.sequence_values::<mlua::Table>().map(|v| v?.try_into()).collect::<mlua::Result<_>>()?
+			}
+			None => vec![],
+		};
+
 		Ok(Self {
 			title: t.raw_get("title")?,
 			content: t.raw_get("content")?,
 			level,
 			timeout: Duration::from_secs_f64(timeout),
+			actions,
+			report: None,
 		})
 	}
 }
 
-#[derive(Clone, Copy, Default, Deserialize, Eq, PartialEq)]
+// --- Action
+#[derive(Clone, Debug)]
+pub struct NotifyAction {
+	pub label: String,
+	pub run:   String,
+}
+
+impl TryFrom<mlua::Table> for NotifyAction {
+	type Error = mlua::Error;
+
+	fn try_from(t: mlua::Table) -> Result<Self, Self::Error> {
+		let run: String = t.raw_get("run")?;
+		Cmd::from_str(&run).into_lua_err()?;
+
+		Ok(Self { label: t.raw_get("label")?, run })
+	}
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, PartialOrd, Ord, Serialize)]
 pub enum NotifyLevel {
 	#[default]
 	Info,
@@ -52,20 +82,22 @@ pub enum NotifyLevel {
 
 impl NotifyLevel {
 	#[inline]
-	pub fn icon(self) -> &'static str {
+	pub fn icon(self) -> String {
+		let notify = &THEME.load().notify;
 		match self {
-			Self::Info => &THEME.notify.icon_info,
-			Self::Warn => &THEME.notify.icon_warn,
-			Self::Error => &THEME.notify.icon_error,
+			Self::Info => notify.icon_info.clone(),
+			Self::Warn => notify.icon_warn.clone(),
+			Self::Error => notify.icon_error.clone(),
 		}
 	}
 
 	#[inline]
-	pub fn style(self) -> &'static Style {
+	pub fn style(self) -> Style {
+		let notify = &THEME.load().notify;
 		match self {
-			Self::Info => &THEME.notify.title_info,
-			Self::Warn => &THEME.notify.title_warn,
-			Self::Error => &THEME.notify.title_error,
+			Self::Info => notify.title_info,
+			Self::Warn => notify.title_warn,
+			Self::Error => notify.title_error,
 		}
 	}
 }
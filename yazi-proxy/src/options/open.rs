@@ -10,6 +10,9 @@ pub struct OpenDoOpt {
 	pub hovered:     Url,
 	pub targets:     Vec<(Url, Cow<'static, str>)>,
 	pub interactive: bool,
+	pub remember:    bool,
+	pub trusted:     bool,
+	pub with:        Option<Opener>,
 }
 
 impl From<CmdCow> for OpenDoOpt {
@@ -0,0 +1,13 @@
+use yazi_shared::event::CmdCow;
+
+pub struct OutputOpt {
+	pub title:   String,
+	pub content: Vec<u8>,
+	pub status:  Option<i32>,
+}
+
+impl TryFrom<CmdCow> for OutputOpt {
+	type Error = ();
+
+	fn try_from(mut c: CmdCow) -> Result<Self, Self::Error> { c.take_any("option").ok_or(()) }
+}
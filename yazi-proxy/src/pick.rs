@@ -1,4 +1,4 @@
-use tokio::sync::oneshot;
+use tokio::sync::{mpsc, oneshot};
 use yazi_config::popup::PickCfg;
 use yazi_macro::emit;
 use yazi_shared::{Layer, event::Cmd};
@@ -12,4 +12,27 @@ impl PickProxy {
 		emit!(Call(Cmd::new("show").with_any("tx", tx).with_any("cfg", cfg), Layer::Pick));
 		rx.await?
 	}
+
+	/// Like [`Self::show`], but also reports the cursor to `hover` as it moves.
+	#[inline]
+	pub async fn show_live(
+		cfg: PickCfg,
+		hover: mpsc::UnboundedSender<usize>,
+	) -> anyhow::Result<usize> {
+		let (tx, rx) = oneshot::channel();
+		emit!(Call(
+			Cmd::new("show").with_any("tx", tx).with_any("cfg", cfg).with_any("hover", hover),
+			Layer::Pick
+		));
+		rx.await?
+	}
+
+	/// Show the items as a checklist, all checked at first, and get the indices
+	/// of those still checked on submit.
+	#[inline]
+	pub async fn show_checklist(cfg: PickCfg) -> anyhow::Result<Vec<usize>> {
+		let (tx, rx) = oneshot::channel();
+		emit!(Call(Cmd::new("show").with_any("checklist", tx).with_any("cfg", cfg), Layer::Pick));
+		rx.await?
+	}
 }
@@ -1,18 +1,27 @@
-use std::{borrow::Cow, collections::VecDeque, path::Path};
+use std::{borrow::Cow, collections::{HashMap, VecDeque}, hash::{BuildHasher, RandomState}, path::{Path, PathBuf}, sync::Arc};
 
 use anyhow::{Result, anyhow};
-use tokio::{fs::{self, DirEntry}, io::{self, ErrorKind::{AlreadyExists, NotFound}}, sync::mpsc};
+use parking_lot::Mutex;
+use tokio::{fs::{self, DirEntry}, io::{self, AsyncSeekExt, AsyncWriteExt, ErrorKind::{AlreadyExists, NotFound}, SeekFrom}, sync::{Semaphore, mpsc}, time};
 use tracing::warn;
-use yazi_config::TASKS;
-use yazi_fs::{Cha, calculate_size, copy_with_progress, maybe_exists, ok_or_not_found, path_relative_to, skip_path};
+use yazi_config::{NETWORK, TASKS, network::NetworkMount};
+use yazi_fs::{Cha, Throttle, canonicalize, copy_with_progress, copy_xattrs, maybe_exists, ok_or_not_found, path_relative_to, paths_to_same_file, same_file, skip_path};
 use yazi_shared::url::Url;
 
-use super::{FileOp, FileOpDelete, FileOpHardlink, FileOpLink, FileOpPaste, FileOpTrash};
+use super::{FileOp, FileOpDelete, FileOpHardlink, FileOpLink, FileOpPaste, FileOpTrash, Trash};
 use crate::{LOW, NORMAL, TaskOp, TaskProg};
 
+/// Number of the files of a directory being deleted that can be queued ahead
+/// of the workers.
+pub(crate) const DELETE_AHEAD: usize = 1000;
+
 pub struct File {
 	macro_: async_priority_channel::Sender<TaskOp, u8>,
 	prog:   mpsc::UnboundedSender<TaskProg>,
+
+	// The bandwidth limits of network filesystems, by the mount of their rule,
+	// or `None` for the global one
+	throttles: Mutex<HashMap<Option<PathBuf>, Arc<Throttle>>>,
 }
 
 impl File {
@@ -20,28 +29,58 @@ impl File {
 		macro_: async_priority_channel::Sender<TaskOp, u8>,
 		prog: mpsc::UnboundedSender<TaskProg>,
 	) -> Self {
-		Self { macro_, prog }
+		Self { macro_, prog, throttles: Default::default() }
 	}
 
 	pub async fn work(&self, op: FileOp) -> Result<()> {
 		match op {
 			FileOp::Paste(mut task) => {
+				if same_file(&task.from, &task.to).await {
+					return self.paste_same(task).await;
+				}
+
 				ok_or_not_found(fs::remove_file(&task.to).await)?;
-				let mut it = copy_with_progress(&task.from, &task.to, task.cha.unwrap());
+				let mounts: Vec<_> = [Some(task.from.as_path()), task.to.parent()]
+					.into_iter()
+					.filter_map(|p| NETWORK.load().at(p?))
+					.collect();
 
+				let mut it =
+					copy_with_progress(&task.from, &task.to, task.cha.unwrap(), self.throttles(&mounts));
+
+				let mut copied = 0;
 				while let Some(res) = it.recv().await {
 					match res {
 						Ok(0) => {
+							if TASKS.preserve_xattrs {
+								self.preserve_xattrs(task.id, &task.from, &task.to).await;
+							}
 							if task.cut {
 								fs::remove_file(&task.from).await.ok();
 							}
 							break;
 						}
-						Ok(n) => self.prog.send(TaskProg::Adv(task.id, 0, n))?,
+						Ok(n) => {
+							copied += n;
+							self.prog.send(TaskProg::Adv(task.id, 0, n))?;
+						}
 						Err(e) if e.kind() == NotFound => {
 							warn!("Paste task partially done: {task:?}");
 							break;
 						}
+						Err(e) if !mounts.is_empty() && task.retry < NETWORK.load().retry && transient(&e) => {
+							let delay = NETWORK.load().backoff(task.retry);
+							task.retry += 1;
+							self.prog.send(TaskProg::Retry(task.id, copied))?;
+							self.log(task.id, format!("Paste task retry in {delay:?}: {task:?}, {e}"))?;
+
+							let macro_ = self.macro_.clone();
+							tokio::spawn(async move {
+								time::sleep(delay).await;
+								macro_.send(FileOp::Paste(task).into(), LOW).await.ok();
+							});
+							return Ok(());
+						}
 						// Operation not permitted (os error 1)
 						// Attribute not found (os error 93)
 						Err(e)
@@ -49,6 +88,7 @@ impl File {
 								&& matches!(e.raw_os_error(), Some(1) | Some(93)) =>
 						{
 							task.retry += 1;
+							self.prog.send(TaskProg::Retry(task.id, copied))?;
 							self.log(task.id, format!("Paste task retry: {:?}", task))?;
 							self.queue(FileOp::Paste(task), LOW).await?;
 							return Ok(());
@@ -75,11 +115,17 @@ impl File {
 				};
 
 				let src = if task.relative {
-					path_relative_to(&src, &fs::canonicalize(task.to.parent().unwrap()).await?)
+					path_relative_to(&src, &canonicalize(task.to.parent().unwrap()).await?)
 				} else {
 					src
 				};
 
+				// Replacing it with a link to itself would lose it
+				if same_file(&task.from, &task.to).await && !is_symlink(&task.to).await {
+					let to = task.to.display();
+					return self.fail(task.id, format!("Cannot link a file onto itself: {to}"));
+				}
+
 				ok_or_not_found(fs::remove_file(&task.to).await)?;
 				#[cfg(unix)]
 				{
@@ -103,12 +149,18 @@ impl File {
 				let cha = task.cha.unwrap();
 				let src = if !task.follow {
 					Cow::Borrowed(task.from.as_path())
-				} else if let Ok(p) = fs::canonicalize(&task.from).await {
+				} else if let Ok(p) = canonicalize(&task.from).await {
 					Cow::Owned(p)
 				} else {
 					Cow::Borrowed(task.from.as_path())
 				};
 
+				if same_file(&src, &task.to).await && !is_symlink(&task.to).await {
+					let (from, to) = (task.from.display(), task.to.display());
+					self.log(task.id, format!("Skipped, {to} is already a hardlink of {from}"))?;
+					return Ok(self.prog.send(TaskProg::Adv(task.id, 1, cha.len))?);
+				}
+
 				ok_or_not_found(fs::remove_file(&task.to).await)?;
 				match fs::hard_link(src, &task.to).await {
 					Err(e) if e.kind() == NotFound => {
@@ -120,13 +172,26 @@ impl File {
 				self.prog.send(TaskProg::Adv(task.id, 1, cha.len))?;
 			}
 			FileOp::Delete(task) => {
+				// Canceled while it was queued
+				if task.permit.as_ref().is_some_and(|p| p.semaphore().is_closed()) {
+					return Ok(());
+				}
+				if task.secure {
+					if let Err(e) = self.shred(&task).await {
+						if e.kind() != NotFound {
+							self.fail(task.id, format!("Shred task failed: {:?}, {e}", task))?;
+							Err(e)?
+						}
+					}
+				}
 				if let Err(e) = fs::remove_file(&task.target).await {
 					if e.kind() != NotFound && maybe_exists(&task.target).await {
 						self.fail(task.id, format!("Delete task failed: {:?}, {e}", task))?;
 						Err(e)?
 					}
 				}
-				self.prog.send(TaskProg::Adv(task.id, 1, task.length))?
+				// The bytes of a secure one are counted while overwriting them
+				self.prog.send(TaskProg::Adv(task.id, 1, if task.secure { 0 } else { task.length }))?
 			}
 			FileOp::Trash(task) => {
 				tokio::task::spawn_blocking(move || {
@@ -151,6 +216,18 @@ impl File {
 	}
 
 	pub async fn paste(&self, mut task: FileOpPaste) -> Result<()> {
+		// Renaming onto a hardlink of itself does nothing, and onto the target of
+		// itself as a symlink loses the target, while either is already in place.
+		// A symlink to itself is instead replaced by the rename below
+		if task.cut
+			&& same_file(&task.from, &task.to).await
+			&& !paths_to_same_file(&task.from, &task.to).await
+			&& !is_symlink(&task.to).await
+		{
+			fs::remove_file(&task.from).await?;
+			return self.succ(task.id);
+		}
+
 		if task.cut && ok_or_not_found(fs::rename(&task.from, &task.to).await).is_ok() {
 			return self.succ(task.id);
 		}
@@ -193,7 +270,9 @@ impl File {
 			let dest = root.join(skip_path(&src, skip));
 			continue_unless_ok!(match fs::create_dir(&dest).await {
 				Err(e) if e.kind() != AlreadyExists => Err(e),
-				_ => Ok(()),
+				Err(_) => Ok(()),
+				Ok(()) if TASKS.preserve_xattrs => Ok(self.preserve_xattrs(task.id, &src, &dest).await),
+				Ok(()) => Ok(()),
 			});
 
 			let mut it = continue_unless_ok!(fs::read_dir(&src).await);
@@ -285,16 +364,22 @@ impl File {
 		self.succ(task.id)
 	}
 
-	pub async fn delete(&self, mut task: FileOpDelete) -> Result<()> {
+	pub async fn delete(&self, mut task: FileOpDelete, ahead: Arc<Semaphore>) -> Result<()> {
+		// Only regular files are overwritten, never the targets of symlinks
+		let secure = task.secure;
 		let meta = fs::symlink_metadata(&task.target).await?;
 		if !meta.is_dir() {
 			let id = task.id;
 			task.length = meta.len();
-			self.prog.send(TaskProg::New(id, meta.len()))?;
+			task.secure = secure && meta.is_file();
+			self.prog.send(TaskProg::New(id, task.size()))?;
 			self.queue(FileOp::Delete(task), NORMAL).await?;
 			return self.succ(id);
 		}
 
+		// The files are queued as they're found, but only so many ahead of the
+		// workers unlinking them, where it's closed once canceled, to stop finding
+		// them and drop the ones queued
 		let mut dirs = VecDeque::from([task.target]);
 		while let Some(target) = dirs.pop_front() {
 			let Ok(mut it) = fs::read_dir(target).await else { continue };
@@ -307,9 +392,13 @@ impl File {
 					continue;
 				}
 
+				let Ok(permit) = ahead.clone().acquire_owned().await else { return Ok(()) };
+
 				task.target = Url::from(entry.path());
 				task.length = meta.len();
-				self.prog.send(TaskProg::New(task.id, meta.len()))?;
+				task.secure = secure && meta.is_file();
+				task.permit = Some(Arc::new(permit));
+				self.prog.send(TaskProg::New(task.id, task.size()))?;
 				self.queue(FileOp::Delete(task.clone()), NORMAL).await?;
 			}
 		}
@@ -318,13 +407,47 @@ impl File {
 
 	pub async fn trash(&self, mut task: FileOpTrash) -> Result<()> {
 		let id = task.id;
-		task.length = calculate_size(&task.target).await;
+
+		// It's moved as a whole, so the files in a directory aren't counted
+		let meta = fs::symlink_metadata(&task.target).await?;
+		task.length = if meta.is_dir() { 0 } else { meta.len() };
 
 		self.prog.send(TaskProg::New(id, task.length))?;
 		self.queue(FileOp::Trash(task), LOW).await?;
 		self.succ(id)
 	}
 
+	pub async fn empty_trash(&self, id: usize) -> Result<()> {
+		let prog = self.prog.clone();
+		tokio::task::spawn_blocking(move || Trash::empty(id, &prog)).await??;
+		self.succ(id)
+	}
+
+	/// Overwrite the file with random data `shred_passes` times, flushing each
+	/// pass to the disk, which only destroys the old contents if the filesystem
+	/// writes in place.
+	async fn shred(&self, task: &FileOpDelete) -> io::Result<()> {
+		let mut file = fs::OpenOptions::new().write(true).open(&task.target).await?;
+		let mut buf = vec![0; 64 * 1024];
+		let mut state = RandomState::new().hash_one(&task.target) | 1;
+
+		for _ in 0..TASKS.shred_passes {
+			file.seek(SeekFrom::Start(0)).await?;
+			let mut left = task.length;
+			while left > 0 {
+				let n = left.min(buf.len() as u64) as usize;
+				noise(&mut buf[..n], &mut state);
+				file.write_all(&buf[..n]).await?;
+				self.prog.send(TaskProg::Adv(task.id, 0, n as u64)).ok();
+				left -= n as u64;
+			}
+			file.sync_data().await?;
+		}
+
+		file.set_len(0).await?;
+		file.sync_all().await
+	}
+
 	#[inline]
 	async fn cha(path: &Path, follow: bool) -> io::Result<Cha> {
 		let meta = fs::symlink_metadata(path).await?;
@@ -342,6 +465,28 @@ impl File {
 }
 
 impl File {
+	/// Pastes a file onto itself, through another path or a hardlink, which is
+	/// refused for a copy as it'd be truncated, and otherwise already done but
+	/// for removing the source of a move if it's another hardlink.
+	async fn paste_same(&self, task: FileOpPaste) -> Result<()> {
+		let (from, to) = (task.from.display(), task.to.display());
+		if paths_to_same_file(&task.from, &task.to).await {
+			if !task.cut {
+				return self.fail(task.id, format!("Cannot copy a file onto itself: {to}"));
+			}
+		} else if is_symlink(&task.to).await {
+			// Writing through it would empty the source, and removing the source leaves
+			// it dangling
+			return self.fail(task.id, format!("Cannot paste {from} onto a symlink to itself: {to}"));
+		} else if task.cut {
+			fs::remove_file(&task.from).await?;
+		} else {
+			self.log(task.id, format!("Skipped, {to} is already a hardlink of {from}"))?;
+		}
+
+		Ok(self.prog.send(TaskProg::Adv(task.id, 1, task.cha.map_or(0, |c| c.len)))?)
+	}
+
 	#[inline]
 	fn succ(&self, id: usize) -> Result<()> { Ok(self.prog.send(TaskProg::Succ(id))?) }
 
@@ -355,8 +500,86 @@ impl File {
 		Ok(self.prog.send(TaskProg::Log(id, line))?)
 	}
 
+	async fn preserve_xattrs(&self, id: usize, from: &Path, to: &Path) {
+		for (name, e) in copy_xattrs(from.to_owned(), to.to_owned()).await {
+			self.log(id, format!("Cannot preserve `{name}` of {}: {e}", to.display())).ok();
+		}
+	}
+
 	#[inline]
 	async fn queue(&self, op: impl Into<TaskOp>, priority: u8) -> Result<()> {
 		self.macro_.send(op.into(), priority).await.map_err(|_| anyhow!("Failed to send task"))
 	}
+
+	/// The bandwidth limits that apply to a transfer between the `mounts`, the
+	/// global one and those of their rules.
+	fn throttles(&self, mounts: &[NetworkMount]) -> Vec<Arc<Throttle>> {
+		if mounts.is_empty() {
+			return vec![];
+		}
+
+		let limits = mounts.iter().filter_map(|m| m.limit.clone()).map(|(p, b)| (Some(p), b));
+		let mut throttles = self.throttles.lock();
+
+		let mut v: Vec<Arc<Throttle>> = vec![];
+		for (key, rate) in [(None, NETWORK.load().bandwidth)].into_iter().chain(limits) {
+			if rate == 0 {
+				continue;
+			}
+
+			let t = throttles.entry(key).or_insert_with(|| Arc::new(Throttle::new(rate)));
+			if t.rate() != rate {
+				*t = Arc::new(Throttle::new(rate));
+			}
+			if !v.iter().any(|u| Arc::ptr_eq(u, t)) {
+				v.push(t.clone());
+			}
+		}
+		v
+	}
+}
+
+/// Whether the error is likely to go away by itself on network filesystems,
+/// such as a dropped connection, or a request that has timed out.
+fn transient(e: &io::Error) -> bool {
+	use io::ErrorKind::*;
+
+	if matches!(
+		e.kind(),
+		TimedOut
+			| Interrupted
+			| WouldBlock
+			| BrokenPipe
+			| ConnectionReset
+			| ConnectionAborted
+			| NotConnected
+			| HostUnreachable
+			| NetworkUnreachable
+			| NetworkDown
+			| StaleNetworkFileHandle
+	) {
+		return true;
+	}
+
+	// Input/output error (os error 5), which soft-mounted NFS returns on timeouts
+	#[cfg(unix)]
+	return e.raw_os_error() == Some(libc::EIO);
+	#[cfg(not(unix))]
+	false
+}
+
+/// Fill `buf` with pseudo-random bytes by xorshift, which is plenty to
+/// overwrite the contents, as it's not about the unpredictability.
+fn noise(buf: &mut [u8], state: &mut u64) {
+	for chunk in buf.chunks_mut(8) {
+		*state ^= *state << 13;
+		*state ^= *state >> 7;
+		*state ^= *state << 17;
+		chunk.copy_from_slice(&state.to_le_bytes()[..chunk.len()]);
+	}
+}
+
+#[inline]
+async fn is_symlink(path: &Path) -> bool {
+	fs::symlink_metadata(path).await.is_ok_and(|m| m.is_symlink())
 }
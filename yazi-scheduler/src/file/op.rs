@@ -1,3 +1,7 @@
+use std::sync::Arc;
+
+use tokio::sync::OwnedSemaphorePermit;
+use yazi_config::TASKS;
 use yazi_fs::Cha;
 use yazi_shared::url::Url;
 
@@ -96,6 +100,17 @@ pub struct FileOpDelete {
 	pub id:     usize,
 	pub target: Url,
 	pub length: u64,
+	pub secure: bool,
+	/// Held until it's done, to bound the ones queued ahead of the workers.
+	pub permit: Option<Arc<OwnedSemaphorePermit>>,
+}
+
+impl FileOpDelete {
+	/// The bytes to process, where a secure one is written over for each pass.
+	#[inline]
+	pub(super) fn size(&self) -> u64 {
+		if self.secure { self.length * TASKS.shred_passes as u64 } else { self.length }
+	}
 }
 
 // --- Trash
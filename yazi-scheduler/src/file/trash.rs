@@ -0,0 +1,155 @@
+use std::{sync::atomic::{AtomicBool, Ordering}, time::Duration};
+
+use anyhow::Result;
+use serde::Serialize;
+use tokio::sync::mpsc;
+use tracing::warn;
+use yazi_macro::emit;
+use yazi_shared::{Layer, event::Cmd};
+
+use crate::TaskProg;
+
+/// The number of items in the trash, and their total size in bytes.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct TrashStat {
+	pub count: usize,
+	pub size:  u64,
+}
+
+static REFRESHING: AtomicBool = AtomicBool::new(false);
+
+pub struct Trash;
+
+impl Trash {
+	/// Purge what's beyond the retention of `[trash]`, and report what's left to
+	/// the tasks, debounced since it's called after each trashed file.
+	pub fn refresh() {
+		if REFRESHING.swap(true, Ordering::Relaxed) {
+			return;
+		}
+
+		tokio::spawn(async {
+			tokio::time::sleep(Duration::from_millis(500)).await;
+			REFRESHING.store(false, Ordering::Relaxed);
+
+			match tokio::task::spawn_blocking(Self::sweep).await {
+				Ok(Ok(Some(stat))) => {
+					emit!(Call(Cmd::new("update_trash").with_any("stat", stat), Layer::Tasks))
+				}
+				Ok(Err(e)) => warn!("Failed to sweep the trash: {e}"),
+				Ok(Ok(None)) | Err(_) => {}
+			}
+		});
+	}
+}
+
+#[cfg(any(windows, all(unix, not(target_os = "macos"), not(target_os = "android"))))]
+impl Trash {
+	/// Purge everything in the trash, one item at a time to report the progress
+	/// of task `id`.
+	pub(crate) fn empty(id: usize, prog: &mpsc::UnboundedSender<TaskProg>) -> Result<()> {
+		let items = Self::items()?;
+		for &(_, n) in &items {
+			prog.send(TaskProg::New(id, n))?;
+		}
+
+		for (item, n) in items {
+			match trash::os_limited::purge_all([&item]) {
+				Ok(()) => prog.send(TaskProg::Adv(id, 1, n))?,
+				Err(e) => prog
+					.send(TaskProg::Fail(id, format!("Cannot purge {}: {e}", item.name.to_string_lossy())))?,
+			}
+		}
+		Ok(())
+	}
+
+	fn sweep() -> Result<Option<TrashStat>> {
+		use std::time::{SystemTime, UNIX_EPOCH};
+
+		use yazi_config::TRASH;
+
+		let mut items = Self::items()?;
+		let mut size: u64 = items.iter().map(|&(_, n)| n).sum();
+
+		let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() as i64);
+		let expired = TRASH.load().max_age().map_or(i64::MIN, |d| now - d.as_secs() as i64);
+
+		// The oldest ones go first, until neither limit is exceeded
+		let n = items
+			.iter()
+			.take_while(|(item, n)| {
+				let purge =
+					item.time_deleted < expired || TRASH.load().max_bytes().is_some_and(|max| size > max);
+				if purge {
+					size -= n;
+				}
+				purge
+			})
+			.count();
+
+		if n > 0 {
+			trash::os_limited::purge_all(items.drain(..n).map(|(item, _)| item))?;
+		}
+		Ok(Some(TrashStat { count: items.len(), size }))
+	}
+
+	/// Items in the trash along with their sizes, the oldest first.
+	fn items() -> Result<Vec<(trash::TrashItem, u64)>> {
+		let mut items: Vec<_> = trash::os_limited::list()?
+			.into_iter()
+			.map(|item| {
+				let size = Self::size(&item);
+				(item, size)
+			})
+			.collect();
+
+		items.sort_unstable_by_key(|(item, _)| item.time_deleted);
+		Ok(items)
+	}
+
+	fn size(item: &trash::TrashItem) -> u64 {
+		use trash::TrashItemSize;
+
+		match trash::os_limited::metadata(item).map(|m| m.size) {
+			Ok(TrashItemSize::Bytes(n)) => n,
+			Ok(TrashItemSize::Entries(_)) => Self::path(item).map_or(0, Self::dir_size),
+			Err(_) => 0,
+		}
+	}
+
+	/// Where the item is stored, which is the `files` counterpart of its
+	/// `.trashinfo` under `info` on freedesktop systems.
+	fn path(item: &trash::TrashItem) -> Option<std::path::PathBuf> {
+		let id = std::path::Path::new(&item.id);
+		if cfg!(windows) {
+			return Some(id.to_owned());
+		}
+		Some(id.parent()?.parent()?.join("files").join(id.file_stem()?))
+	}
+
+	fn dir_size(root: std::path::PathBuf) -> u64 {
+		let (mut size, mut stack) = (0, vec![root]);
+		while let Some(dir) = stack.pop() {
+			let Ok(it) = std::fs::read_dir(dir) else { continue };
+			for entry in it.flatten() {
+				let Ok(meta) = entry.metadata() else { continue };
+				if meta.is_dir() {
+					stack.push(entry.path());
+				} else {
+					size += meta.len();
+				}
+			}
+		}
+		size
+	}
+}
+
+#[cfg(not(any(windows, all(unix, not(target_os = "macos"), not(target_os = "android")))))]
+impl Trash {
+	pub(crate) fn empty(_: usize, _: &mpsc::UnboundedSender<TaskProg>) -> Result<()> {
+		anyhow::bail!("Emptying the trash is not supported on this platform")
+	}
+
+	/// Nothing to report, as the trash can't be listed here.
+	fn sweep() -> Result<Option<TrashStat>> { Ok(None) }
+}
@@ -0,0 +1,87 @@
+use anyhow::{Result, anyhow};
+use tokio::{fs, sync::mpsc};
+use yazi_adapter::Transform;
+use yazi_fs::unique_name;
+use yazi_shared::url::Url;
+
+use super::{ImageOp, ImageOpTransform};
+use crate::{LOW, TaskOp, TaskProg};
+
+pub struct Image {
+	macro_: async_priority_channel::Sender<TaskOp, u8>,
+	prog:   mpsc::UnboundedSender<TaskProg>,
+}
+
+impl Image {
+	pub fn new(
+		macro_: async_priority_channel::Sender<TaskOp, u8>,
+		prog: mpsc::UnboundedSender<TaskProg>,
+	) -> Self {
+		Self { macro_, prog }
+	}
+
+	pub async fn work(&self, op: ImageOp) -> Result<()> {
+		match op {
+			ImageOp::Transform(task) => {
+				match yazi_adapter::Image::transform(&task.from, &task.to, task.transform).await {
+					Ok(true) => self.log(task.id, format!("{}: {}", task.transform, task.to))?,
+					Ok(false) => {
+						self.log(task.id, format!("{}: {} (unchanged)", task.transform, task.from))?
+					}
+					Err(e) => {
+						self.fail(task.id, format!("Failed to transform {}:\n{e}", task.from))?;
+						return Ok(());
+					}
+				}
+				self.prog.send(TaskProg::Adv(task.id, 1, task.length))?;
+			}
+		}
+		Ok(())
+	}
+
+	pub async fn transform(&self, id: usize, targets: Vec<Url>, transform: Transform) -> Result<()> {
+		for from in targets {
+			let meta = match fs::metadata(&from).await {
+				Ok(m) if m.is_file() => m,
+				Ok(_) => continue,
+				Err(e) => {
+					self.prog.send(TaskProg::New(id, 0))?;
+					self.fail(id, format!("Failed to read {from}:\n{e}"))?;
+					continue;
+				}
+			};
+
+			let to = match transform.extension() {
+				Some(ext) => unique_name(Url::from(from.with_extension(ext)), async { false }).await?,
+				None => from.clone(),
+			};
+
+			self.prog.send(TaskProg::New(id, meta.len()))?;
+			self.queue(
+				ImageOp::Transform(ImageOpTransform { id, from, to, transform, length: meta.len() }),
+				LOW,
+			)?;
+		}
+		self.succ(id)
+	}
+}
+
+impl Image {
+	#[inline]
+	fn succ(&self, id: usize) -> Result<()> { Ok(self.prog.send(TaskProg::Succ(id))?) }
+
+	#[inline]
+	fn fail(&self, id: usize, reason: String) -> Result<()> {
+		Ok(self.prog.send(TaskProg::Fail(id, reason))?)
+	}
+
+	#[inline]
+	fn log(&self, id: usize, line: String) -> Result<()> {
+		Ok(self.prog.send(TaskProg::Log(id, line))?)
+	}
+
+	#[inline]
+	fn queue(&self, op: impl Into<TaskOp>, priority: u8) -> Result<()> {
+		self.macro_.try_send(op.into(), priority).map_err(|_| anyhow!("Failed to send task"))
+	}
+}
@@ -0,0 +1,24 @@
+use yazi_adapter::Transform;
+use yazi_shared::url::Url;
+
+#[derive(Debug)]
+pub enum ImageOp {
+	Transform(ImageOpTransform),
+}
+
+impl ImageOp {
+	pub fn id(&self) -> usize {
+		match self {
+			Self::Transform(op) => op.id,
+		}
+	}
+}
+
+#[derive(Clone, Debug)]
+pub struct ImageOpTransform {
+	pub id:        usize,
+	pub from:      Url,
+	pub to:        Url,
+	pub transform: Transform,
+	pub length:    u64,
+}
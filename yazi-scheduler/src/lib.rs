@@ -1,6 +1,6 @@
 #![allow(clippy::option_map_unit_fn, clippy::unit_arg)]
 
-yazi_macro::mod_pub!(file plugin prework process);
+yazi_macro::mod_pub!(file image plugin prework process);
 
 yazi_macro::mod_flat!(ongoing op scheduler task);
 
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use futures::future::BoxFuture;
 use yazi_config::TASKS;
+use yazi_shared::Id;
 
 use super::{Task, TaskStage};
 use crate::TaskKind;
@@ -10,6 +11,9 @@ use crate::TaskKind;
 pub struct Ongoing {
 	incr: usize,
 
+	/// The tab that new tasks are spawned from, i.e. the active one.
+	pub origin: Option<Id>,
+
 	pub(super) hooks: HashMap<usize, Box<dyn (FnOnce(bool) -> BoxFuture<'static, ()>) + Send + Sync>>,
 	pub(super) all:   HashMap<usize, Task>,
 }
@@ -17,7 +21,7 @@ pub struct Ongoing {
 impl Ongoing {
 	pub fn add(&mut self, kind: TaskKind, name: String) -> usize {
 		self.incr += 1;
-		self.all.insert(self.incr, Task::new(self.incr, kind, name));
+		self.all.insert(self.incr, Task::new(self.incr, kind, name, self.origin));
 		self.incr
 	}
 
@@ -27,9 +31,6 @@ impl Ongoing {
 	#[inline]
 	pub fn get_mut(&mut self, id: usize) -> Option<&mut Task> { self.all.get_mut(&id) }
 
-	#[inline]
-	pub fn get_id(&self, idx: usize) -> Option<usize> { self.values().nth(idx).map(|t| t.id) }
-
 	#[inline]
 	pub fn len(&self) -> usize {
 		if TASKS.suppress_preload {
@@ -1,8 +1,9 @@
-use crate::{file::FileOp, plugin::PluginOp, prework::PreworkOp};
+use crate::{file::FileOp, image::ImageOp, plugin::PluginOp, prework::PreworkOp};
 
 #[derive(Debug)]
 pub enum TaskOp {
 	File(Box<FileOp>),
+	Image(Box<ImageOp>),
 	Plugin(Box<PluginOp>),
 	Prework(Box<PreworkOp>),
 }
@@ -11,6 +12,7 @@ impl TaskOp {
 	pub fn id(&self) -> usize {
 		match self {
 			TaskOp::File(op) => op.id(),
+			TaskOp::Image(op) => op.id(),
 			TaskOp::Plugin(op) => op.id(),
 			TaskOp::Prework(op) => op.id(),
 		}
@@ -21,6 +23,10 @@ impl From<FileOp> for TaskOp {
 	fn from(op: FileOp) -> Self { Self::File(Box::new(op)) }
 }
 
+impl From<ImageOp> for TaskOp {
+	fn from(op: ImageOp) -> Self { Self::Image(Box::new(op)) }
+}
+
 impl From<PluginOp> for TaskOp {
 	fn from(op: PluginOp) -> Self { Self::Plugin(Box::new(op)) }
 }
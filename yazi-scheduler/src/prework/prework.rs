@@ -5,7 +5,7 @@ use lru::LruCache;
 use parking_lot::{Mutex, RwLock};
 use tokio::sync::mpsc;
 use tracing::error;
-use yazi_config::Priority;
+use yazi_config::{Priority, plugin::Fetcher};
 use yazi_fs::{FilesOp, calculate_size};
 use yazi_plugin::isolate;
 use yazi_shared::{event::CmdCow, url::Url};
@@ -19,6 +19,7 @@ pub struct Prework {
 
 	pub loaded:       Mutex<LruCache<u64, u32>>,
 	pub size_loading: RwLock<HashSet<Url>>,
+	pub fetching:     Mutex<HashMap<usize, Url>>,
 }
 
 impl Prework {
@@ -31,6 +32,7 @@ impl Prework {
 			prog,
 			loaded: Mutex::new(LruCache::new(NonZeroUsize::new(4096).unwrap())),
 			size_loading: Default::default(),
+			fetching: Default::default(),
 		}
 	}
 
@@ -38,7 +40,8 @@ impl Prework {
 		match op {
 			PreworkOp::Fetch(task) => {
 				let hashes: Vec<_> = task.targets.iter().map(|f| f.hash()).collect();
-				let result = isolate::fetch(CmdCow::from(&task.plugin.run), task.targets).await;
+				let result =
+					isolate::fetch(CmdCow::from(&task.plugin.run), task.targets, task.plugin.sandbox).await;
 				if let Err(e) = result {
 					self.fail(task.id, format!("Failed to run fetcher `{}`:\n{e}", task.plugin.run.name))?;
 					return Err(e.into());
@@ -56,7 +59,7 @@ impl Prework {
 			}
 			PreworkOp::Load(task) => {
 				let hash = task.target.hash();
-				let result = isolate::preload(&task.plugin.run, task.target).await;
+				let result = isolate::preload(task.plugin, task.target).await;
 				if let Err(e) = result {
 					self
 						.fail(task.id, format!("Failed to run preloader `{}`:\n{e}", task.plugin.run.name))?;
@@ -137,6 +140,14 @@ impl Prework {
 		Ok(self.prog.send(TaskProg::Fail(id, reason))?)
 	}
 
+	#[inline]
+	pub fn unload(&self, fetcher: &Fetcher, targets: &[yazi_fs::File]) {
+		let mut loaded = self.loaded.lock();
+		for f in targets {
+			loaded.get_mut(&f.hash()).map(|x| *x &= !(1 << fetcher.idx));
+		}
+	}
+
 	#[inline]
 	async fn queue(&self, op: impl Into<TaskOp>, priority: u8) -> Result<()> {
 		self.macro_.send(op.into(), priority).await.map_err(|_| anyhow!("Failed to send task"))
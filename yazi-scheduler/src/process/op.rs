@@ -1,4 +1,4 @@
-use std::ffi::OsString;
+use std::{collections::BTreeMap, ffi::OsString};
 
 use tokio::sync::mpsc;
 use yazi_shared::url::Url;
@@ -8,30 +8,53 @@ use super::ShellOpt;
 // --- Block
 #[derive(Debug)]
 pub struct ProcessOpBlock {
-	pub id:   usize,
-	pub cwd:  Url,
-	pub cmd:  OsString,
-	pub args: Vec<OsString>,
+	pub id:      usize,
+	pub cwd:     Url,
+	pub cmd:     OsString,
+	pub args:    Vec<OsString>,
+	pub env:     BTreeMap<String, String>,
+	pub stdin:   Option<Vec<u8>>,
+	pub capture: bool,
 }
 
 impl From<ProcessOpBlock> for ShellOpt {
 	fn from(op: ProcessOpBlock) -> Self {
-		Self { cwd: op.cwd, cmd: op.cmd, args: op.args, piped: false, orphan: false }
+		Self {
+			cwd:     op.cwd,
+			cmd:     op.cmd,
+			args:    op.args,
+			env:     op.env,
+			stdin:   op.stdin,
+			piped:   false,
+			capture: op.capture,
+			orphan:  false,
+		}
 	}
 }
 
 // --- Orphan
 #[derive(Debug)]
 pub struct ProcessOpOrphan {
-	pub id:   usize,
-	pub cwd:  Url,
-	pub cmd:  OsString,
-	pub args: Vec<OsString>,
+	pub id:    usize,
+	pub cwd:   Url,
+	pub cmd:   OsString,
+	pub args:  Vec<OsString>,
+	pub env:   BTreeMap<String, String>,
+	pub stdin: Option<Vec<u8>>,
 }
 
 impl From<ProcessOpOrphan> for ShellOpt {
 	fn from(op: ProcessOpOrphan) -> Self {
-		Self { cwd: op.cwd, cmd: op.cmd, args: op.args, piped: false, orphan: true }
+		Self {
+			cwd:     op.cwd,
+			cmd:     op.cmd,
+			args:    op.args,
+			env:     op.env,
+			stdin:   op.stdin,
+			piped:   false,
+			capture: false,
+			orphan:  true,
+		}
 	}
 }
 
@@ -42,11 +65,23 @@ pub struct ProcessOpBg {
 	pub cwd:    Url,
 	pub cmd:    OsString,
 	pub args:   Vec<OsString>,
+	pub env:    BTreeMap<String, String>,
+	pub stdin:  Option<Vec<u8>>,
+	pub log:    bool,
 	pub cancel: mpsc::Receiver<()>,
 }
 
 impl From<ProcessOpBg> for ShellOpt {
 	fn from(op: ProcessOpBg) -> Self {
-		Self { cwd: op.cwd, cmd: op.cmd, args: op.args, piped: true, orphan: false }
+		Self {
+			cwd:     op.cwd,
+			cmd:     op.cmd,
+			args:    op.args,
+			env:     op.env,
+			stdin:   op.stdin,
+			piped:   true,
+			capture: false,
+			orphan:  false,
+		}
 	}
 }
@@ -1,7 +1,8 @@
 use anyhow::Result;
 use scopeguard::defer;
-use tokio::{io::{AsyncBufReadExt, BufReader}, select, sync::mpsc};
-use yazi_proxy::{AppProxy, HIDER};
+use tokio::{io::{self, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader}, process::Child, select, sync::mpsc};
+use yazi_config::t;
+use yazi_proxy::{AppProxy, HIDER, options::OutputOpt};
 
 use super::{ProcessOpBg, ProcessOpBlock, ProcessOpOrphan, ShellOpt};
 use crate::TaskProg;
@@ -18,14 +19,26 @@ impl Process {
 		defer!(AppProxy::resume());
 		AppProxy::stop().await;
 
-		let (id, cmd) = (task.id, task.cmd.clone());
+		let (id, cmd, capture) = (task.id, task.cmd.clone(), task.capture);
 		let result = super::shell(task.into());
 		if let Err(e) = result {
-			AppProxy::notify_warn(&cmd.to_string_lossy(), format!("Failed to start process: {e}"));
+			AppProxy::notify_warn(&cmd.to_string_lossy(), t!("Failed to start process: {e}", e = e));
 			return self.succ(id);
 		}
 
-		let status = result.unwrap().wait().await?;
+		let mut child = result.unwrap();
+		if capture {
+			let content = Self::capture(&mut child).await;
+			let status = child.wait().await?;
+			AppProxy::output(OutputOpt {
+				title: cmd.to_string_lossy().into_owned(),
+				content,
+				status: status.code(),
+			});
+			return self.succ(id);
+		}
+
+		let status = child.wait().await?;
 		if !status.success() {
 			let content = match status.code() {
 				Some(130) => return self.succ(id), // Ctrl-C pressed by user
@@ -54,11 +67,14 @@ impl Process {
 	pub async fn bg(&self, task: ProcessOpBg) -> Result<()> {
 		self.prog.send(TaskProg::New(task.id, 0))?;
 		let mut child = super::shell(ShellOpt {
-			cwd:    task.cwd,
-			cmd:    task.cmd,
-			args:   task.args,
-			piped:  true,
-			orphan: false,
+			cwd:     task.cwd,
+			cmd:     task.cmd,
+			args:    task.args,
+			env:     task.env,
+			stdin:   task.stdin,
+			piped:   true,
+			capture: false,
+			orphan:  false,
 		})?;
 
 		let mut stdout = BufReader::new(child.stdout.take().unwrap()).lines();
@@ -72,10 +88,10 @@ impl Process {
 					break;
 				}
 				Ok(Some(line)) = stdout.next_line() => {
-					self.log(task.id, line)?;
+					if task.log { self.log(task.id, line)?; }
 				}
 				Ok(Some(line)) = stderr.next_line() => {
-					self.log(task.id, line)?;
+					if task.log { self.log(task.id, line)?; }
 				}
 				Ok(status) = child.wait() => {
 					self.log(task.id, match status.code() {
@@ -96,6 +112,36 @@ impl Process {
 }
 
 impl Process {
+	/// Pass the output through to the terminal as it comes, while keeping a copy
+	/// of it.
+	async fn capture(child: &mut Child) -> Vec<u8> {
+		let (mut stdout, mut stderr) = (child.stdout.take().unwrap(), child.stderr.take().unwrap());
+		let (mut out, mut err) = (io::stdout(), io::stderr());
+
+		let (mut content, mut buf1, mut buf2) = (vec![], [0; 4096], [0; 4096]);
+		let (mut done1, mut done2) = (false, false);
+		while !done1 || !done2 {
+			select! {
+				r = stdout.read(&mut buf1), if !done1 => match r {
+					Ok(n) if n > 0 => {
+						out.write_all(&buf1[..n]).await.ok();
+						out.flush().await.ok();
+						content.extend_from_slice(&buf1[..n]);
+					}
+					_ => done1 = true,
+				},
+				r = stderr.read(&mut buf2), if !done2 => match r {
+					Ok(n) if n > 0 => {
+						err.write_all(&buf2[..n]).await.ok();
+						content.extend_from_slice(&buf2[..n]);
+					}
+					_ => done2 = true,
+				},
+			}
+		}
+		content
+	}
+
 	#[inline]
 	fn succ(&self, id: usize) -> Result<()> { Ok(self.prog.send(TaskProg::Succ(id))?) }
 
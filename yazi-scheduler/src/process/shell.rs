@@ -1,15 +1,19 @@
-use std::{ffi::OsString, process::Stdio};
+use std::{collections::BTreeMap, ffi::OsString, process::Stdio};
 
 use anyhow::Result;
-use tokio::process::{Child, Command};
+use tokio::{io::AsyncWriteExt, process::{Child, Command}};
 use yazi_shared::url::Url;
 
 pub struct ShellOpt {
-	pub cwd:    Url,
-	pub cmd:    OsString,
-	pub args:   Vec<OsString>,
-	pub piped:  bool,
-	pub orphan: bool,
+	pub cwd:     Url,
+	pub cmd:     OsString,
+	pub args:    Vec<OsString>,
+	pub env:     BTreeMap<String, String>,
+	pub stdin:   Option<Vec<u8>>,
+	pub piped:   bool,
+	/// Pipe the stdout and stderr only, leaving the stdin to the terminal.
+	pub capture: bool,
+	pub orphan:  bool,
 }
 
 impl ShellOpt {
@@ -23,43 +27,54 @@ impl ShellOpt {
 			Stdio::inherit()
 		}
 	}
+
+	#[inline]
+	fn stdin(&self) -> Stdio { if self.stdin.is_some() { Stdio::piped() } else { self.stdio() } }
+
+	#[inline]
+	fn stdout(&self) -> Stdio { if self.capture { Stdio::piped() } else { self.stdio() } }
 }
 
 pub fn shell(opt: ShellOpt) -> Result<Child> {
 	#[cfg(unix)]
-	return Ok(unsafe {
+	let mut child = unsafe {
+		let orphan = opt.orphan;
 		Command::new("sh")
 			.arg("-c")
-			.stdin(opt.stdio())
-			.stdout(opt.stdio())
-			.stderr(opt.stdio())
+			.stdin(opt.stdin())
+			.stdout(opt.stdout())
+			.stderr(opt.stdout())
 			.arg(opt.cmd)
 			.args(opt.args)
+			.envs(opt.env)
 			.current_dir(opt.cwd)
-			.kill_on_drop(!opt.orphan)
+			.kill_on_drop(!orphan)
 			.pre_exec(move || {
-				if opt.orphan && libc::setpgid(0, 0) < 0 {
+				if orphan && libc::setpgid(0, 0) < 0 {
 					return Err(std::io::Error::last_os_error());
 				}
 				Ok(())
 			})
 			.spawn()?
-	});
+	};
 
 	#[cfg(windows)]
-	{
-		Ok(
-			Command::new("cmd.exe")
-				.raw_arg("/C")
-				.raw_arg(parser::parse(&opt.cmd, &opt.args))
-				.stdin(opt.stdio())
-				.stdout(opt.stdio())
-				.stderr(opt.stdio())
-				.current_dir(opt.cwd)
-				.kill_on_drop(!opt.orphan)
-				.spawn()?,
-		)
+	let mut child = Command::new("cmd.exe")
+		.raw_arg("/C")
+		.raw_arg(parser::parse(&opt.cmd, &opt.args))
+		.stdin(opt.stdin())
+		.stdout(opt.stdout())
+		.stderr(opt.stdout())
+		.envs(opt.env)
+		.current_dir(opt.cwd)
+		.kill_on_drop(!opt.orphan)
+		.spawn()?;
+
+	// Written in the background, so a process that doesn't read it won't block us
+	if let (Some(data), Some(mut stdin)) = (opt.stdin, child.stdin.take()) {
+		tokio::spawn(async move { stdin.write_all(&data).await });
 	}
+	Ok(child)
 }
 
 #[cfg(windows)]
@@ -1,20 +1,22 @@
-use std::{ffi::OsString, future::Future, sync::Arc, time::Duration};
+use std::{ffi::OsString, future::Future, io, path::PathBuf, sync::Arc, time::Duration};
 
 use anyhow::Result;
 use futures::{FutureExt, future::BoxFuture};
 use parking_lot::Mutex;
-use tokio::{fs, select, sync::mpsc::{self, UnboundedReceiver}, task::JoinHandle};
-use yazi_config::{TASKS, plugin::{Fetcher, Preloader}};
+use tokio::{fs, io::AsyncWriteExt, select, sync::{Semaphore, mpsc::{self, UnboundedReceiver}}, task::JoinHandle};
+use yazi_adapter::Transform;
+use yazi_config::{MANAGER, PREVIEW, Priority, TASKS, open::{OpenerInput, OpenerMode}, plugin::{Fetcher, Preloader}};
 use yazi_dds::Pump;
-use yazi_fs::{must_be_dir, remove_dir_clean, unique_name};
+use yazi_fs::{Conflict, must_be_dir, remove_dir_clean, shortcut_target};
 use yazi_proxy::{ManagerProxy, options::{PluginOpt, ProcessExecOpt}};
 use yazi_shared::{Throttle, url::Url};
 
 use super::{Ongoing, TaskProg, TaskStage};
-use crate::{HIGH, LOW, NORMAL, TaskKind, TaskOp, file::{File, FileOpDelete, FileOpHardlink, FileOpLink, FileOpPaste, FileOpTrash}, plugin::{Plugin, PluginOpEntry}, prework::{Prework, PreworkOpFetch, PreworkOpLoad, PreworkOpSize}, process::{Process, ProcessOpBg, ProcessOpBlock, ProcessOpOrphan}};
+use crate::{HIGH, LOW, NORMAL, TaskKind, TaskOp, file::{DELETE_AHEAD, File, FileOpDelete, FileOpHardlink, FileOpLink, FileOpPaste, FileOpTrash, Trash}, image::Image, plugin::{Plugin, PluginOpEntry}, prework::{Prework, PreworkOpFetch, PreworkOpLoad, PreworkOpSize}, process::{Process, ProcessOpBg, ProcessOpBlock, ProcessOpOrphan}};
 
 pub struct Scheduler {
 	pub file:    Arc<File>,
+	pub image:   Arc<Image>,
 	pub plugin:  Arc<Plugin>,
 	pub prework: Arc<Prework>,
 	pub process: Arc<Process>,
@@ -33,6 +35,7 @@ impl Scheduler {
 
 		let mut scheduler = Self {
 			file:    Arc::new(File::new(macro_tx.clone(), prog_tx.clone())),
+			image:   Arc::new(Image::new(macro_tx.clone(), prog_tx.clone())),
 			plugin:  Arc::new(Plugin::new(macro_tx.clone(), prog_tx.clone())),
 			prework: Arc::new(Prework::new(macro_tx.clone(), prog_tx.clone())),
 			process: Arc::new(Process::new(prog_tx.clone())),
@@ -50,6 +53,8 @@ impl Scheduler {
 			scheduler.handles.push(scheduler.schedule_macro(micro_rx.clone(), macro_rx.clone()));
 		}
 		scheduler.progress(prog_rx);
+
+		Trash::refresh();
 		scheduler
 	}
 
@@ -70,9 +75,9 @@ impl Scheduler {
 		}
 	}
 
-	pub fn file_cut(&self, from: Url, mut to: Url, force: bool) {
+	pub fn file_cut(&self, from: Url, to: Url, force: bool) {
 		let mut ongoing = self.ongoing.lock();
-		let id = ongoing.add(TaskKind::User, format!("Cut {from} to {to}"));
+		let id = ongoing.add(TaskKind::File, format!("Cut {from} to {to}"));
 
 		if to.starts_with(&from) && to != from {
 			self.new_and_fail(id, "Cannot cut directory into itself").ok();
@@ -95,72 +100,76 @@ impl Scheduler {
 			})
 		});
 
-		let file = self.file.clone();
+		let (file, skip) = (self.file.clone(), self.skipper(id));
 		self.send_micro(id, LOW, async move {
-			if !force {
-				to = unique_name(to, must_be_dir(&from)).await?;
-			}
+			let Some(to) = settle(&from, to, force).await? else { return Ok(skip()) };
+			ManagerProxy::update_journal(&from, &to, true);
+			ManagerProxy::update_tags(vec![(from.clone(), to.clone())]);
 			file.paste(FileOpPaste { id, from, to, cha: None, cut: true, follow: false, retry: 0 }).await
 		});
 	}
 
-	pub fn file_copy(&self, from: Url, mut to: Url, force: bool, follow: bool) {
-		let id = self.ongoing.lock().add(TaskKind::User, format!("Copy {from} to {to}"));
+	pub fn file_copy(&self, from: Url, to: Url, force: bool, follow: bool) {
+		let id = self.ongoing.lock().add(TaskKind::File, format!("Copy {from} to {to}"));
 
 		if to.starts_with(&from) && to != from {
 			self.new_and_fail(id, "Cannot copy directory into itself").ok();
 			return;
 		}
 
-		let file = self.file.clone();
+		let (file, skip) = (self.file.clone(), self.skipper(id));
 		self.send_micro(id, LOW, async move {
-			if !force {
-				to = unique_name(to, must_be_dir(&from)).await?;
-			}
+			let Some(to) = settle(&from, to, force).await? else { return Ok(skip()) };
+			ManagerProxy::update_journal(&from, &to, false);
 			file.paste(FileOpPaste { id, from, to, cha: None, cut: false, follow, retry: 0 }).await
 		});
 	}
 
-	pub fn file_link(&self, from: Url, mut to: Url, relative: bool, force: bool) {
-		let id = self.ongoing.lock().add(TaskKind::User, format!("Link {from} to {to}"));
+	pub fn file_link(&self, from: Url, to: Url, relative: bool, force: bool) {
+		let id = self.ongoing.lock().add(TaskKind::File, format!("Link {from} to {to}"));
 
-		let file = self.file.clone();
+		let (file, skip) = (self.file.clone(), self.skipper(id));
 		self.send_micro(id, LOW, async move {
-			if !force {
-				to = unique_name(to, must_be_dir(&from)).await?;
-			}
+			let Some(to) = settle(&from, to, force).await? else { return Ok(skip()) };
 			file
 				.link(FileOpLink { id, from, to, cha: None, resolve: false, relative, delete: false })
 				.await
 		});
 	}
 
-	pub fn file_hardlink(&self, from: Url, mut to: Url, force: bool, follow: bool) {
-		let id = self.ongoing.lock().add(TaskKind::User, format!("Hardlink {from} to {to}"));
+	pub fn file_hardlink(&self, from: Url, to: Url, force: bool, follow: bool) {
+		let id = self.ongoing.lock().add(TaskKind::File, format!("Hardlink {from} to {to}"));
 
 		if to.starts_with(&from) && to != from {
 			self.new_and_fail(id, "Cannot hardlink directory into itself").ok();
 			return;
 		}
 
-		let file = self.file.clone();
+		let (file, skip) = (self.file.clone(), self.skipper(id));
 		self.send_micro(id, LOW, async move {
-			if !force {
-				to = unique_name(to, must_be_dir(&from)).await?;
-			}
+			let Some(to) = settle(&from, to, force).await? else { return Ok(skip()) };
 			file.hardlink(FileOpHardlink { id, from, to, cha: None, follow }).await
 		});
 	}
 
-	pub fn file_delete(&self, target: Url) {
+	pub fn file_delete(&self, target: Url, secure: bool) {
+		let ahead = Arc::new(Semaphore::new(DELETE_AHEAD));
 		let mut ongoing = self.ongoing.lock();
-		let id = ongoing.add(TaskKind::User, format!("Delete {target}"));
+		let id = ongoing.add(
+			TaskKind::File,
+			if secure { format!("Securely delete {target}") } else { format!("Delete {target}") },
+		);
 
 		ongoing.hooks.insert(id, {
 			let target = target.clone();
 			let ongoing = self.ongoing.clone();
+			let ahead = ahead.clone();
 
 			Box::new(move |canceled: bool| {
+				// What's deleted so far stays deleted, the rest is left as is
+				if canceled {
+					ahead.close();
+				}
 				async move {
 					if !canceled {
 						fs::remove_dir_all(&target).await.ok();
@@ -174,16 +183,14 @@ impl Scheduler {
 		});
 
 		let file = self.file.clone();
-		self.send_micro(
-			id,
-			LOW,
-			async move { file.delete(FileOpDelete { id, target, length: 0 }).await },
-		);
+		self.send_micro(id, LOW, async move {
+			file.delete(FileOpDelete { id, target, length: 0, secure, permit: None }, ahead).await
+		});
 	}
 
 	pub fn file_trash(&self, target: Url) {
 		let mut ongoing = self.ongoing.lock();
-		let id = ongoing.add(TaskKind::User, format!("Trash {target}"));
+		let id = ongoing.add(TaskKind::File, format!("Trash {target}"));
 
 		ongoing.hooks.insert(id, {
 			let target = target.clone();
@@ -194,6 +201,7 @@ impl Scheduler {
 					if !canceled {
 						ManagerProxy::update_tasks(&target);
 						Pump::push_trash(target);
+						Trash::refresh();
 					}
 					ongoing.lock().try_remove(id, TaskStage::Hooked);
 				}
@@ -207,31 +215,82 @@ impl Scheduler {
 		})
 	}
 
+	pub fn trash_empty(&self) {
+		let mut ongoing = self.ongoing.lock();
+		let id = ongoing.add(TaskKind::File, "Empty the trash".to_owned());
+
+		ongoing.hooks.insert(id, {
+			let ongoing = self.ongoing.clone();
+			Box::new(move |_canceled: bool| {
+				async move {
+					Trash::refresh();
+					ongoing.lock().try_remove(id, TaskStage::Hooked);
+				}
+				.boxed()
+			})
+		});
+
+		let file = self.file.clone();
+		self.send_micro(id, LOW, async move { file.empty_trash(id).await });
+	}
+
+	pub fn image_transform(&self, targets: Vec<Url>, transform: Transform) {
+		let id =
+			self.ongoing.lock().add(TaskKind::Image, format!("{transform}: {} image(s)", targets.len()));
+
+		let image = self.image.clone();
+		self.send_micro(id, LOW, async move { image.transform(id, targets, transform).await });
+	}
+
 	pub fn plugin_micro(&self, opt: PluginOpt) {
-		let id = self.ongoing.lock().add(TaskKind::User, format!("Run micro plugin `{}`", opt.id));
+		let id = self.ongoing.lock().add(TaskKind::Plugin, format!("Run micro plugin `{}`", opt.id));
 
 		let plugin = self.plugin.clone();
 		self.send_micro(id, NORMAL, async move { plugin.micro(PluginOpEntry { id, opt }).await });
 	}
 
 	pub fn plugin_macro(&self, opt: PluginOpt) {
-		let id = self.ongoing.lock().add(TaskKind::User, format!("Run macro plugin `{}`", opt.id));
+		let id = self.ongoing.lock().add(TaskKind::Plugin, format!("Run macro plugin `{}`", opt.id));
 
 		self.plugin.macro_(PluginOpEntry { id, opt }).ok();
 	}
 
-	pub fn fetch_paged(&self, fetcher: &'static Fetcher, targets: Vec<yazi_fs::File>) {
+	pub fn fetch_paged(
+		&self,
+		fetcher: &'static Fetcher,
+		cwd: &Url,
+		targets: Vec<yazi_fs::File>,
+		prio: Priority,
+	) {
 		let id = self.ongoing.lock().add(
 			TaskKind::Preload,
 			format!("Run fetcher `{}` with {} target(s)", fetcher.run.name, targets.len()),
 		);
+		self.prework.fetching.lock().insert(id, cwd.clone());
+
+		let (prework, ongoing) = (self.prework.clone(), self.ongoing.clone());
+		self.send_micro(id, prio as u8, async move {
+			// Canceled before it started, e.g. by leaving the directory
+			if !ongoing.lock().exists(id) {
+				prework.fetching.lock().remove(&id);
+				prework.unload(fetcher, &targets);
+				return Ok(());
+			}
 
-		let prework = self.prework.clone();
-		self.send_micro(id, NORMAL, async move {
-			prework.fetch(PreworkOpFetch { id, plugin: fetcher, targets }).await
+			let result = prework.fetch(PreworkOpFetch { id, plugin: fetcher, targets }).await;
+			prework.fetching.lock().remove(&id);
+			result
 		});
 	}
 
+	pub fn fetch_cancel(&self, cwd: &Url) {
+		let stale: Vec<_> =
+			self.prework.fetching.lock().iter().filter(|&(_, u)| u != cwd).map(|(&id, _)| id).collect();
+		for id in stale {
+			self.cancel(id);
+		}
+	}
+
 	pub fn preload_paged(&self, preloader: &'static Preloader, target: &yazi_fs::File) {
 		let id =
 			self.ongoing.lock().add(TaskKind::Preload, format!("Run preloader `{}`", preloader.run.name));
@@ -259,7 +318,7 @@ impl Scheduler {
 		}
 	}
 
-	pub fn process_open(&self, ProcessExecOpt { cwd, opener, args, done }: ProcessExecOpt) {
+	pub fn process_open(&self, ProcessExecOpt { cwd, opener, mut args, done }: ProcessExecOpt) {
 		let name = {
 			let args = args.iter().map(|a| a.to_string_lossy()).collect::<Vec<_>>().join(" ");
 			if args.is_empty() {
@@ -272,7 +331,7 @@ impl Scheduler {
 		let (cancel_tx, cancel_rx) = mpsc::channel(1);
 		let mut ongoing = self.ongoing.lock();
 
-		let id = ongoing.add(TaskKind::User, name);
+		let id = ongoing.add(TaskKind::Process, name);
 		ongoing.hooks.insert(id, {
 			let ongoing = self.ongoing.clone();
 			Box::new(move |canceled: bool| {
@@ -293,16 +352,71 @@ impl Scheduler {
 		let cmd = OsString::from(&opener.run);
 		let process = self.process.clone();
 		self.send_micro(id, NORMAL, async move {
-			if opener.block {
-				process.block(ProcessOpBlock { id, cwd, cmd, args }).await
-			} else if opener.orphan {
-				process.orphan(ProcessOpOrphan { id, cwd, cmd, args }).await
-			} else {
-				process.bg(ProcessOpBg { id, cwd, cmd, args, cancel: cancel_rx }).await
+			if opener.resolve {
+				for arg in &mut args {
+					if let Some(target) = shortcut_target(&arg).await {
+						*arg = target;
+					}
+				}
+			}
+
+			let cwd = opener.cwd(&cwd).map(Url::from).unwrap_or(cwd);
+			let env = opener.env.clone();
+
+			// Only `$0`, the hovered file, is kept as the argument if the targets are
+			// piped, each of them ending with a NUL, since a name can contain a newline
+			let (mut stdin, mut tmp) = (None, None);
+			if opener.input != OpenerInput::Args && !args.is_empty() {
+				let mut buf = Vec::new();
+				for arg in args.drain(1..) {
+					buf.extend_from_slice(arg.as_encoded_bytes());
+					buf.push(b'\0');
+				}
+				if opener.input == OpenerInput::Stdin {
+					stdin = Some(buf);
+				} else {
+					let path = Self::tempfile(&buf).await?;
+					args.push(path.clone().into_os_string());
+					tmp = Some(path);
+				}
 			}
+
+			let result = match opener.mode {
+				OpenerMode::Block | OpenerMode::BlockWithOutput => {
+					let capture = opener.mode == OpenerMode::BlockWithOutput;
+					process.block(ProcessOpBlock { id, cwd, cmd, args, env, stdin, capture }).await
+				}
+				OpenerMode::Orphan => {
+					// The detached process may still be reading it, leave it to the system
+					tmp = None;
+					process.orphan(ProcessOpOrphan { id, cwd, cmd, args, env, stdin }).await
+				}
+				OpenerMode::Background | OpenerMode::BackgroundWithLog => {
+					let log = opener.mode == OpenerMode::BackgroundWithLog;
+					process.bg(ProcessOpBg { id, cwd, cmd, args, env, stdin, log, cancel: cancel_rx }).await
+				}
+			};
+
+			if let Some(path) = tmp {
+				fs::remove_file(path).await.ok();
+			}
+			result
 		});
 	}
 
+	/// A new file only the user can access, so it can't be one planted in
+	/// advance, e.g. a link to somewhere else, nor be read by anyone else.
+	async fn tempfile(buf: &[u8]) -> io::Result<PathBuf> {
+		let path = PREVIEW.load().tmpfile("open");
+		let mut options = fs::OpenOptions::new();
+		options.write(true).create_new(true);
+		#[cfg(unix)]
+		options.mode(0o600);
+
+		options.open(&path).await?.write_all(buf).await?;
+		Ok(path)
+	}
+
 	fn schedule_micro(
 		&self,
 		rx: async_priority_channel::Receiver<BoxFuture<'static, ()>, u8>,
@@ -322,6 +436,7 @@ impl Scheduler {
 		macro_: async_priority_channel::Receiver<TaskOp, u8>,
 	) -> JoinHandle<()> {
 		let file = self.file.clone();
+		let image = self.image.clone();
 		let plugin = self.plugin.clone();
 		let prework = self.prework.clone();
 
@@ -342,6 +457,7 @@ impl Scheduler {
 
 						let result = match op {
 							TaskOp::File(op) => file.work(*op).await,
+							TaskOp::Image(op) => image.work(*op).await,
 							TaskOp::Plugin(op) => plugin.work(*op).await,
 							TaskOp::Prework(op) => prework.work(*op).await,
 						};
@@ -396,6 +512,12 @@ impl Scheduler {
 							}
 						}
 					}
+					TaskProg::Retry(id, processed) => {
+						if let Some(task) = ongoing.lock().get_mut(id) {
+							task.retries += 1;
+							task.processed = task.processed.saturating_sub(processed);
+						}
+					}
 					TaskProg::Log(id, line) => {
 						if let Some(task) = ongoing.lock().get_mut(id) {
 							task.logs.push_str(&line);
@@ -428,9 +550,25 @@ impl Scheduler {
 		);
 	}
 
+	/// Finishes task `id` without running its hook, for when it's skipped.
+	fn skipper(&self, id: usize) -> impl FnOnce() + Send + 'static {
+		let (ongoing, prog) = (self.ongoing.clone(), self.prog.clone());
+		move || {
+			ongoing.lock().hooks.remove(&id);
+			prog.send(TaskProg::Succ(id)).ok();
+		}
+	}
+
 	fn new_and_fail(&self, id: usize, reason: &str) -> Result<()> {
 		self.prog.send(TaskProg::New(id, 0))?;
 		self.prog.send(TaskProg::Fail(id, reason.to_owned()))?;
 		Ok(())
 	}
 }
+
+/// Where `from` goes to by the conflict policy, or overwriting the existing
+/// file if `force`, or `None` if it's skipped.
+async fn settle(from: &Url, to: Url, force: bool) -> std::io::Result<Option<Url>> {
+	let conflict = if force { Conflict::Overwrite } else { MANAGER.load().conflict };
+	conflict.settle(to, must_be_dir(from)).await
+}
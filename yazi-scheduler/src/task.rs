@@ -1,4 +1,7 @@
+use std::fmt::{self, Display};
+
 use tokio::sync::mpsc;
+use yazi_shared::Id;
 
 #[derive(Debug, Default)]
 pub struct Task {
@@ -6,6 +9,7 @@ pub struct Task {
 	pub kind:  TaskKind,
 	pub name:  String,
 	pub stage: TaskStage,
+	pub tab:   Option<Id>,
 
 	pub total: u32,
 	pub succ:  u32,
@@ -13,27 +17,84 @@ pub struct Task {
 
 	pub found:     u64,
 	pub processed: u64,
+	pub retries:   u32,
 
 	pub logs:   String,
 	pub logger: Option<mpsc::UnboundedSender<String>>,
 }
 
 impl Task {
-	pub fn new(id: usize, kind: TaskKind, name: String) -> Self {
-		Self { id, kind, name, ..Default::default() }
+	pub fn new(id: usize, kind: TaskKind, name: String, tab: Option<Id>) -> Self {
+		Self { id, kind, name, tab, ..Default::default() }
+	}
+
+	pub fn status(&self) -> TaskStatus {
+		if self.fail > 0 {
+			TaskStatus::Failed
+		} else if self.total == 0 {
+			TaskStatus::Queued
+		} else {
+			TaskStatus::Running
+		}
 	}
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum TaskKind {
 	#[default]
-	User,
+	File,
+	Image,
+	Plugin,
+	Process,
 	Preload,
 }
 
+impl TaskKind {
+	pub const ALL: [Self; 5] = [Self::File, Self::Image, Self::Plugin, Self::Process, Self::Preload];
+}
+
+impl Display for TaskKind {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::File => "file",
+			Self::Image => "image",
+			Self::Plugin => "plugin",
+			Self::Process => "process",
+			Self::Preload => "preload",
+		})
+	}
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TaskStatus {
+	// Nothing has been reported yet, e.g. still waiting in the queue
+	Queued,
+	Running,
+	// At least one of its works has failed
+	Failed,
+}
+
+impl TaskStatus {
+	pub const ALL: [Self; 3] = [Self::Queued, Self::Running, Self::Failed];
+}
+
+impl Display for TaskStatus {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Queued => "queued",
+			Self::Running => "running",
+			Self::Failed => "failed",
+		})
+	}
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct TaskSummary {
-	pub name: String,
+	pub id:     usize,
+	pub tab:    Option<Id>,
+	pub kind:   TaskKind,
+	pub status: TaskStatus,
+	pub name:   String,
 
 	pub total: u32,
 	pub succ:  u32,
@@ -41,12 +102,17 @@ pub struct TaskSummary {
 
 	pub found:     u64,
 	pub processed: u64,
+	pub retries:   u32,
 }
 
 impl From<&Task> for TaskSummary {
 	fn from(task: &Task) -> Self {
 		TaskSummary {
-			name: task.name.clone(),
+			id:     task.id,
+			tab:    task.tab,
+			kind:   task.kind,
+			status: task.status(),
+			name:   task.name.clone(),
 
 			total: task.total,
 			succ:  task.succ,
@@ -54,6 +120,7 @@ impl From<&Task> for TaskSummary {
 
 			found:     task.found,
 			processed: task.processed,
+			retries:   task.retries,
 		}
 	}
 }
@@ -70,6 +137,8 @@ pub enum TaskProg {
 	Fail(usize, String),
 	// id, line
 	Log(usize, String),
+	// id, processed to rewind
+	Retry(usize, u64),
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd)]
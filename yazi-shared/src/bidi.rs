@@ -0,0 +1,222 @@
+use std::borrow::Cow;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use self::Class::*;
+
+/// The bidirectional character types, where the ones for explicit embeddings
+/// and isolates are treated as other neutrals, since file names and status
+/// texts don't use them in practice.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Class {
+	L,
+	R,
+	AL,
+	EN,
+	AN,
+	ES,
+	ET,
+	CS,
+	WS,
+	ON,
+}
+
+impl Class {
+	/// The class of a grapheme is the one of its base character, so the
+	/// combining marks (NSM) are always resolved as it.
+	fn of(g: &str) -> Self {
+		let Some(c) = g.chars().next() else { return ON };
+		match c as u32 {
+			0x30..=0x39 | 0x6f0..=0x6f9 => EN,
+			0x660..=0x669 | 0x66b..=0x66c => AN,
+			0x2b | 0x2d => ES,
+			0x23..=0x25 | 0xa2..=0xa5 | 0xb0 | 0x2030..=0x2034 | 0x20a0..=0x20cf => ET,
+			0x2c | 0x2e | 0x2f | 0x3a | 0xa0 => CS,
+			0x590..=0x5ff | 0x7c0..=0x85f | 0xfb1d..=0xfb4f | 0x10800..=0x10fff | 0x1e800..=0x1edff => R,
+			0x600..=0x7bf | 0x860..=0x8ff | 0xfb50..=0xfdff | 0xfe70..=0xfeff | 0x1ee00..=0x1eeff => AL,
+			_ if c.is_whitespace() => WS,
+			_ if c.is_alphanumeric() => L,
+			_ => ON,
+		}
+	}
+}
+
+/// Reorder a line of graphemes from the logical order to the visual order,
+/// following the implicit rules of the Unicode Bidirectional Algorithm, with
+/// the paragraph direction taken from the first strong character.
+///
+/// Returns the index of each grapheme from left to right, and whether it's in
+/// a right-to-left run, where it should be mirrored, or `None` if the line has
+/// nothing right-to-left in it so can be displayed as-is.
+pub fn reorder(graphemes: &[&str]) -> Option<Vec<(usize, bool)>> {
+	let mut classes: Vec<_> = graphemes.iter().map(|g| Class::of(g)).collect();
+	if !classes.iter().any(|c| matches!(c, R | AL | AN)) {
+		return None;
+	}
+
+	// P2, P3
+	let base = match classes.iter().find(|c| matches!(c, L | R | AL)) {
+		Some(L) | None => 0,
+		Some(_) => 1,
+	};
+	let sot = if base == 0 { L } else { R };
+
+	// W2, W3
+	let mut strong = sot;
+	for c in &mut classes {
+		match *c {
+			L | R => strong = *c,
+			AL => (strong, *c) = (AL, R),
+			EN if strong == AL => *c = AN,
+			_ => {}
+		}
+	}
+
+	// W4
+	for i in 1..classes.len().saturating_sub(1) {
+		classes[i] = match (classes[i - 1], classes[i], classes[i + 1]) {
+			(EN, ES | CS, EN) => EN,
+			(AN, CS, AN) => AN,
+			(_, c, _) => c,
+		};
+	}
+
+	// W5
+	for i in 0..classes.len() {
+		if classes[i] != EN {
+			continue;
+		}
+		let (mut j, mut k) = (i, i + 1);
+		while j > 0 && classes[j - 1] == ET {
+			j -= 1;
+		}
+		while k < classes.len() && classes[k] == ET {
+			k += 1;
+		}
+		classes[j..k].fill(EN);
+	}
+
+	// W6, W7
+	let mut strong = sot;
+	for c in &mut classes {
+		match *c {
+			L | R => strong = *c,
+			EN if strong == L => *c = L,
+			ES | ET | CS => *c = ON,
+			_ => {}
+		}
+	}
+
+	// N1, N2
+	let mut i = 0;
+	while i < classes.len() {
+		let start = i;
+		while i < classes.len() && matches!(classes[i], WS | ON) {
+			i += 1;
+		}
+		if start == i {
+			i += 1;
+			continue;
+		}
+
+		let side = |c: Option<&Class>| match c {
+			None => sot,
+			Some(L) => L,
+			Some(_) => R,
+		};
+		let (before, after) = (side(start.checked_sub(1).map(|j| &classes[j])), side(classes.get(i)));
+		classes[start..i].fill(if before == after { before } else { sot });
+	}
+
+	// I1, I2
+	let mut levels: Vec<u8> = classes
+		.into_iter()
+		.map(|c| match (base, c) {
+			(0, R) => 1,
+			(0, EN | AN) => 2,
+			(0, _) => 0,
+			(_, L | EN | AN) => 2,
+			(..) => 1,
+		})
+		.collect();
+
+	// L1
+	for (level, _) in
+		levels.iter_mut().zip(graphemes).rev().take_while(|(_, g)| g.chars().all(char::is_whitespace))
+	{
+		*level = base;
+	}
+
+	// L2
+	let (lowest, highest) =
+		(levels.iter().min().copied().unwrap_or(0) | 1, levels.iter().max().copied().unwrap_or(0));
+	let mut visual: Vec<_> = levels.drain(..).enumerate().collect();
+	for level in (lowest..=highest).rev() {
+		let mut i = 0;
+		while i < visual.len() {
+			let start = i;
+			while i < visual.len() && visual[i].1 >= level {
+				i += 1;
+			}
+			visual[start..i].reverse();
+			i += 1;
+		}
+	}
+
+	Some(visual.into_iter().map(|(i, level)| (i, level % 2 == 1)).collect())
+}
+
+/// The mirrored glyph of a grapheme in a right-to-left run, per rule L4.
+pub fn mirror(g: &str) -> &str {
+	match g {
+		"(" => ")",
+		")" => "(",
+		"[" => "]",
+		"]" => "[",
+		"{" => "}",
+		"}" => "{",
+		"<" => ">",
+		">" => "<",
+		"«" => "»",
+		"»" => "«",
+		_ => g,
+	}
+}
+
+/// Reorder a string from the logical order to the visual order, see
+/// [`reorder`] for details.
+pub fn visual(s: &str) -> Cow<'_, str> {
+	if s.is_ascii() {
+		return Cow::Borrowed(s);
+	}
+
+	let graphemes: Vec<_> = s.graphemes(true).collect();
+	match reorder(&graphemes) {
+		None => Cow::Borrowed(s),
+		Some(order) => Cow::Owned(
+			order
+				.into_iter()
+				.map(|(i, rtl)| if rtl { mirror(graphemes[i]) } else { graphemes[i] })
+				.collect(),
+		),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_visual() {
+		assert_eq!(visual("foo.txt"), "foo.txt");
+		assert_eq!(visual("日本語.txt"), "日本語.txt");
+
+		assert_eq!(visual("שלום.md"), "md.םולש");
+		assert_eq!(visual("a שלום 12.5 b"), "a 12.5 םולש b");
+		assert_eq!(visual("ملف (١).pdf"), "pdf.(١) فلم");
+		assert_eq!(visual("report שנה 2024 "), "report 2024 הנש ");
+
+		// Combining marks stay with their base characters
+		assert_eq!(visual("שָׁלוֹם"), "םוֹלשָׁ");
+	}
+}
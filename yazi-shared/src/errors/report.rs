@@ -0,0 +1,160 @@
+use std::{fmt::{self, Display}, io, path::{Path, PathBuf}};
+
+use serde::{Deserialize, Serialize};
+
+/// The stable code of an error, for users to look up and for tools to match
+/// on, which never changes once given out.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum ErrorCode {
+	#[serde(rename = "E100")]
+	Io,
+	#[serde(rename = "E101")]
+	NotFound,
+	#[serde(rename = "E102")]
+	PermissionDenied,
+	#[serde(rename = "E103")]
+	AlreadyExists,
+	#[serde(rename = "E104")]
+	NotEmpty,
+	#[serde(rename = "E105")]
+	CrossesDevices,
+	#[serde(rename = "E106")]
+	ReadOnly,
+	#[serde(rename = "E107")]
+	NoSpace,
+	#[serde(rename = "E108")]
+	InvalidName,
+	#[serde(rename = "E109")]
+	Busy,
+	#[serde(rename = "E110")]
+	TimedOut,
+	#[serde(rename = "E200")]
+	Command,
+	#[serde(rename = "E201")]
+	CommandNotFound,
+	#[serde(rename = "E300")]
+	Remote,
+}
+
+impl ErrorCode {
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Io => "E100",
+			Self::NotFound => "E101",
+			Self::PermissionDenied => "E102",
+			Self::AlreadyExists => "E103",
+			Self::NotEmpty => "E104",
+			Self::CrossesDevices => "E105",
+			Self::ReadOnly => "E106",
+			Self::NoSpace => "E107",
+			Self::InvalidName => "E108",
+			Self::Busy => "E109",
+			Self::TimedOut => "E110",
+			Self::Command => "E200",
+			Self::CommandNotFound => "E201",
+			Self::Remote => "E300",
+		}
+	}
+
+	/// What can be done about it, if there's anything.
+	pub fn hint(self) -> Option<&'static str> {
+		Some(match self {
+			Self::Io | Self::Command => return None,
+			Self::NotFound => "It may have been moved or deleted, refresh with `R` and try again",
+			Self::PermissionDenied => "Check the owner and mode of it and its parent directory",
+			Self::AlreadyExists => "Choose another name, or allow overwriting it",
+			Self::NotEmpty => "Remove what's in the directory first, or move it to the trash",
+			Self::CrossesDevices => "Copy it to the other filesystem and then remove the original",
+			Self::ReadOnly => "The filesystem is mounted read-only, remount it as read-write",
+			Self::NoSpace => "Free up some space on the device, e.g. by emptying the trash",
+			Self::InvalidName => {
+				"Use a shorter name, without the characters this filesystem doesn't allow"
+			}
+			Self::Busy => "Close the programs using it and try again",
+			Self::TimedOut => "Check the connection, or raise the timeout in `[network]`",
+			Self::CommandNotFound => "Install it, or make sure it's in your `PATH`",
+			Self::Remote => "Check the remote in your remotes.toml and that the host is reachable",
+		})
+	}
+
+	fn from_io(kind: io::ErrorKind) -> Self {
+		use io::ErrorKind as K;
+		match kind {
+			K::NotFound => Self::NotFound,
+			K::PermissionDenied => Self::PermissionDenied,
+			K::AlreadyExists => Self::AlreadyExists,
+			K::DirectoryNotEmpty => Self::NotEmpty,
+			K::CrossesDevices => Self::CrossesDevices,
+			K::ReadOnlyFilesystem => Self::ReadOnly,
+			K::StorageFull | K::QuotaExceeded => Self::NoSpace,
+			K::InvalidFilename => Self::InvalidName,
+			K::ResourceBusy | K::ExecutableFileBusy => Self::Busy,
+			K::TimedOut => Self::TimedOut,
+			_ => Self::Io,
+		}
+	}
+}
+
+impl Display for ErrorCode {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str(self.as_str()) }
+}
+
+/// An error to show to the user, with its code and the details to expand.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ErrorReport {
+	pub code:    ErrorCode,
+	pub message: String,
+	pub path:    Option<PathBuf>,
+	/// The `errno` of the system, or the code on Windows.
+	pub os:      Option<i32>,
+}
+
+impl ErrorReport {
+	#[inline]
+	pub fn new(code: ErrorCode, message: impl ToString) -> Self {
+		Self { code, message: message.to_string(), path: None, os: None }
+	}
+
+	#[inline]
+	pub fn with_path(mut self, path: impl AsRef<Path>) -> Self {
+		self.path = Some(path.as_ref().to_owned());
+		self
+	}
+
+	#[inline]
+	pub fn hint(&self) -> Option<&'static str> { self.code.hint() }
+}
+
+impl From<&io::Error> for ErrorReport {
+	fn from(e: &io::Error) -> Self {
+		Self { os: e.raw_os_error(), ..Self::new(ErrorCode::from_io(e.kind()), e) }
+	}
+}
+
+impl From<&anyhow::Error> for ErrorReport {
+	/// Classified by the first I/O error in the chain, if any.
+	fn from(e: &anyhow::Error) -> Self {
+		match e.chain().find_map(|e| e.downcast_ref::<io::Error>()) {
+			Some(io) => Self { message: format!("{e:#}"), ..Self::from(io) },
+			None => Self::new(ErrorCode::Io, format!("{e:#}")),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_from_io() {
+		let e = io::Error::from(io::ErrorKind::PermissionDenied);
+		let report = ErrorReport::from(&e).with_path("/root/a");
+		assert_eq!(report.code, ErrorCode::PermissionDenied);
+		assert_eq!(report.path.as_deref(), Some(Path::new("/root/a")));
+
+		let e = anyhow::Error::from(io::Error::from(io::ErrorKind::NotFound)).context("Reading a.txt");
+		let report = ErrorReport::from(&e);
+		assert_eq!(report.code, ErrorCode::NotFound);
+		assert!(report.message.starts_with("Reading a.txt: "));
+	}
+}
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, ops::Deref};
+use std::{borrow::Cow, ops::Deref, sync::Arc};
 
 use super::{Cmd, Data, DataKey};
 use crate::url::Url;
@@ -7,6 +7,7 @@ use crate::url::Url;
 pub enum CmdCow {
 	Owned(Cmd),
 	Borrowed(&'static Cmd),
+	Shared(Arc<Cmd>),
 }
 
 impl From<Cmd> for CmdCow {
@@ -17,6 +18,10 @@ impl From<&'static Cmd> for CmdCow {
 	fn from(c: &'static Cmd) -> Self { Self::Borrowed(c) }
 }
 
+impl From<Arc<Cmd>> for CmdCow {
+	fn from(c: Arc<Cmd>) -> Self { Self::Shared(c) }
+}
+
 impl Deref for CmdCow {
 	type Target = Cmd;
 
@@ -24,6 +29,7 @@ impl Deref for CmdCow {
 		match self {
 			Self::Owned(c) => c,
 			Self::Borrowed(c) => c,
+			Self::Shared(c) => c,
 		}
 	}
 }
@@ -33,7 +39,7 @@ impl CmdCow {
 	pub fn try_take(&mut self, name: impl Into<DataKey>) -> Option<Data> {
 		match self {
 			Self::Owned(c) => c.take(name),
-			Self::Borrowed(_) => None,
+			Self::Borrowed(_) | Self::Shared(_) => None,
 		}
 	}
 
@@ -42,6 +48,7 @@ impl CmdCow {
 		match self {
 			Self::Owned(c) => c.take_str(name).map(Cow::Owned),
 			Self::Borrowed(c) => c.str(name).map(Cow::Borrowed),
+			Self::Shared(c) => c.str(name).map(|s| Cow::Owned(s.to_owned())),
 		}
 	}
 
@@ -50,6 +57,7 @@ impl CmdCow {
 		match self {
 			Self::Owned(c) => c.take(name).and_then(Data::into_url),
 			Self::Borrowed(c) => c.get(name).and_then(Data::to_url),
+			Self::Shared(c) => c.get(name).and_then(Data::to_url),
 		}
 	}
 
@@ -58,6 +66,7 @@ impl CmdCow {
 		match self {
 			Self::Owned(c) => c.take_first_str().map(Cow::Owned),
 			Self::Borrowed(c) => c.first_str().map(Cow::Borrowed),
+			Self::Shared(c) => c.first_str().map(|s| Cow::Owned(s.to_owned())),
 		}
 	}
 
@@ -65,6 +74,7 @@ impl CmdCow {
 		match self {
 			Self::Owned(c) => c.take_first_url(),
 			Self::Borrowed(c) => c.first().and_then(Data::to_url),
+			Self::Shared(c) => c.first().and_then(Data::to_url),
 		}
 	}
 
@@ -72,7 +82,7 @@ impl CmdCow {
 	pub fn take_any<T: 'static>(&mut self, name: impl Into<DataKey>) -> Option<T> {
 		match self {
 			Self::Owned(c) => c.take_any(name),
-			Self::Borrowed(_) => None,
+			Self::Borrowed(_) | Self::Shared(_) => None,
 		}
 	}
 }
@@ -9,6 +9,12 @@ pub enum Layer {
 	App,
 	Manager,
 	Tasks,
+	Notify,
+	Watch,
+	Output,
+	Excerpt,
+	Terminal,
+	Quicklook,
 	Spot,
 	Pick,
 	Input,
@@ -24,6 +30,12 @@ impl Display for Layer {
 			Self::App => "app",
 			Self::Manager => "manager",
 			Self::Tasks => "tasks",
+			Self::Notify => "notify",
+			Self::Watch => "watch",
+			Self::Output => "output",
+			Self::Excerpt => "excerpt",
+			Self::Terminal => "terminal",
+			Self::Quicklook => "quicklook",
 			Self::Spot => "spot",
 			Self::Pick => "pick",
 			Self::Input => "input",
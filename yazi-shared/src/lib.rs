@@ -1,8 +1,8 @@
 #![allow(clippy::option_map_unit_fn)]
 
-yazi_macro::mod_pub!(errors event shell theme translit url);
+yazi_macro::mod_pub!(bidi errors event shell theme translit url);
 
-yazi_macro::mod_flat!(chars condition debounce either env id layer natsort number os rand ro_cell sync_cell terminal throttle time);
+yazi_macro::mod_flat!(chars condition debounce either env id layer natsort number os osstr rand ro_cell swap_cell sync_cell terminal throttle time width);
 
 pub fn init() {
 	LOG_LEVEL.replace(<_>::from(std::env::var("YAZI_LOG").unwrap_or_default()));
@@ -4,7 +4,7 @@
 // This is a port of the C version of Martin Pool's `strnatcmp.c`:
 // http://sourcefrog.net/projects/natsort/
 
-use std::cmp::Ordering;
+use std::{borrow::Cow, cmp::Ordering};
 
 macro_rules! return_unless_equal {
 	($ord:expr) => {
@@ -65,8 +65,13 @@ fn compare_right(left: &[u8], right: &[u8], li: &mut usize, ri: &mut usize) -> O
 }
 
 pub fn natsort(left: &[u8], right: &[u8], insensitive: bool) -> Ordering {
-	let mut li = 0;
-	let mut ri = 0;
+	// Skip the common prefix, back to the start of the number it ends in if any,
+	// where both sides are in the same state
+	let mut li = mismatch(left, right, insensitive);
+	while li > 0 && left[li - 1].is_ascii_digit() {
+		li -= 1;
+	}
+	let mut ri = li;
 
 	let mut l = left.get(li);
 	let mut r = right.get(ri);
@@ -123,6 +128,79 @@ pub fn natsort(left: &[u8], right: &[u8], insensitive: bool) -> Ordering {
 	}
 }
 
+/// The key to sort `s` naturally by with [`natsort`], computed once for each
+/// entry instead of in each comparison, where the Unicode decimal digits are
+/// turned into ASCII ones, so they're compared as numbers too.
+pub fn natsort_key(s: &[u8]) -> Cow<'_, [u8]> {
+	// All the non-ASCII digits are from U+0660, led by one of these bytes in UTF-8,
+	// so most names can be told apart without decoding them
+	let lead = |&b: &u8| matches!(b, 0xd9 | 0xdb | 0xdf) || b >= 0xe0;
+	if !s.iter().any(lead)
+		|| !s.utf8_chunks().any(|c| c.valid().chars().any(|c| unicode_digit(c).is_some()))
+	{
+		return Cow::Borrowed(s);
+	}
+
+	let mut key = Vec::with_capacity(s.len());
+	for chunk in s.utf8_chunks() {
+		for c in chunk.valid().chars() {
+			match unicode_digit(c) {
+				Some(d) => key.push(b'0' + d),
+				None => key.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+			}
+		}
+		key.extend_from_slice(chunk.invalid());
+	}
+	Cow::Owned(key)
+}
+
+/// The length of the common prefix of `a` and `b`, compared in chunks so it
+/// can be vectorized.
+#[inline]
+fn mismatch(a: &[u8], b: &[u8], insensitive: bool) -> usize {
+	const CHUNK: usize = 16;
+
+	let len = a.len().min(b.len());
+	let mut i = 0;
+	while i + CHUNK <= len {
+		let (x, y) = (&a[i..i + CHUNK], &b[i..i + CHUNK]);
+		if if insensitive { !x.eq_ignore_ascii_case(y) } else { x != y } {
+			break;
+		}
+		i += CHUNK;
+	}
+
+	while i < len {
+		if if insensitive { !a[i].eq_ignore_ascii_case(&b[i]) } else { a[i] != b[i] } {
+			break;
+		}
+		i += 1;
+	}
+	i
+}
+
+/// The value of `c` if it's a decimal digit, i.e. of the `Nd` category, which
+/// are all in runs of ten from a zero, as of Unicode 15.0.
+fn unicode_digit(c: char) -> Option<u8> {
+	const ZEROS: [u32; 67] = [
+		0x0660, 0x06f0, 0x07c0, 0x0966, 0x09e6, 0x0a66, 0x0ae6, 0x0b66, 0x0be6, 0x0c66, 0x0ce6, 0x0d66,
+		0x0de6, 0x0e50, 0x0ed0, 0x0f20, 0x1040, 0x1090, 0x17e0, 0x1810, 0x1946, 0x19d0, 0x1a80, 0x1a90,
+		0x1b50, 0x1bb0, 0x1c40, 0x1c50, 0xa620, 0xa8d0, 0xa900, 0xa9d0, 0xa9f0, 0xaa50, 0xabf0, 0xff10,
+		0x104a0, 0x10d30, 0x11066, 0x110f0, 0x11136, 0x111d0, 0x112f0, 0x11450, 0x114d0, 0x11650,
+		0x116c0, 0x11730, 0x118e0, 0x11950, 0x11c50, 0x11d50, 0x11da0, 0x11f50, 0x16a60, 0x16ac0,
+		0x16b50, 0x1d7ce, 0x1d7d8, 0x1d7e2, 0x1d7ec, 0x1d7f6, 0x1e140, 0x1e2f0, 0x1e4f0, 0x1e950,
+		0x1fbf0,
+	];
+
+	let c = c as u32;
+	let zero = match ZEROS.binary_search(&c) {
+		Ok(_) => return Some(0),
+		Err(0) => return None,
+		Err(i) => ZEROS[i - 1],
+	};
+	if c - zero < 10 { Some((c - zero) as u8) } else { None }
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -176,4 +254,15 @@ mod tests {
 		cmp(&fractions);
 		cmp(&words);
 	}
+
+	#[test]
+	fn test_natsort_key() {
+		let mut names = ["File10", "file٢", "file1", "FILE۳", "file２０"];
+		names.sort_by(|a, b| natsort(&natsort_key(a.as_bytes()), &natsort_key(b.as_bytes()), true));
+		assert_eq!(names, ["file1", "file٢", "FILE۳", "File10", "file２０"]);
+
+		assert_eq!(natsort_key("a\u{0E51}\u{1D7D9}\u{FF}".as_bytes()), "a11\u{FF}".as_bytes());
+		assert_eq!(natsort_key(b"\xff\xd9\xa3"), &b"\xff3"[..]);
+		assert!(matches!(natsort_key("прогулка".as_bytes()), Cow::Borrowed(_)));
+	}
 }
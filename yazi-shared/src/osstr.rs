@@ -0,0 +1,142 @@
+use std::{borrow::Cow, ffi::{OsStr, OsString}};
+
+/// Escape the bytes as a string, keeping invalid UTF-8 sequences visible
+/// in the form of `\xNN` instead of replacing them with `U+FFFD`.
+pub fn escape_bytes(b: &[u8]) -> Cow<'_, str> {
+	if let Ok(s) = std::str::from_utf8(b) {
+		return Cow::Borrowed(s);
+	}
+
+	let mut s = String::with_capacity(b.len() + 8);
+	for chunk in b.utf8_chunks() {
+		s.push_str(&chunk.valid().replace('\\', "\\\\"));
+		for &b in chunk.invalid() {
+			s.push_str(&format!("\\x{b:02x}"));
+		}
+	}
+	Cow::Owned(s)
+}
+
+/// Escape the OS string as a string that can be turned back by
+/// [`unescape_os_str`], it's borrowed as is if the OS string is valid UTF-8.
+pub fn escape_os_str(s: &OsStr) -> Cow<'_, str> {
+	#[cfg(unix)]
+	{
+		use std::os::unix::ffi::OsStrExt;
+		escape_bytes(s.as_bytes())
+	}
+
+	#[cfg(windows)]
+	{
+		use std::os::windows::ffi::OsStrExt;
+		if let Some(s) = s.to_str() {
+			return Cow::Borrowed(s);
+		}
+
+		let mut out = String::new();
+		for c in char::decode_utf16(s.encode_wide()) {
+			match c {
+				Ok('\\') => out.push_str("\\\\"),
+				Ok(c) => out.push(c),
+				Err(e) => out.push_str(&format!("\\u{{{:04x}}}", e.unpaired_surrogate())),
+			}
+		}
+		Cow::Owned(out)
+	}
+}
+
+/// The reverse of [`escape_os_str`], unknown escapes are kept literally.
+pub fn unescape_os_str(s: &str) -> OsString {
+	#[cfg(unix)]
+	let mut out: Vec<u8> = Vec::with_capacity(s.len());
+	#[cfg(windows)]
+	let mut out: Vec<u16> = Vec::with_capacity(s.len());
+
+	let mut rest = s;
+	while let Some(i) = rest.find('\\') {
+		push_str(&mut out, &rest[..i]);
+		rest = &rest[i..];
+
+		if let Some(r) = rest.strip_prefix("\\\\") {
+			push_str(&mut out, "\\");
+			rest = r;
+		} else if let Some((n, r)) = unescape_unit(rest) {
+			out.push(n);
+			rest = r;
+		} else {
+			push_str(&mut out, "\\");
+			rest = &rest[1..];
+		}
+	}
+	push_str(&mut out, rest);
+
+	#[cfg(unix)]
+	{
+		use std::os::unix::ffi::OsStringExt;
+		OsString::from_vec(out)
+	}
+	#[cfg(windows)]
+	{
+		use std::os::windows::ffi::OsStringExt;
+		OsString::from_wide(&out)
+	}
+}
+
+/// Convert the bytes, e.g. from a Lua string, to an OS string.
+/// Any bytes are accepted on Unix, while they must be valid UTF-8 on Windows.
+pub fn bytes_to_os_str(b: &[u8]) -> Result<&OsStr, std::str::Utf8Error> {
+	#[cfg(unix)]
+	{
+		use std::os::unix::ffi::OsStrExt;
+		Ok(OsStr::from_bytes(b))
+	}
+	#[cfg(windows)]
+	{
+		std::str::from_utf8(b).map(OsStr::new)
+	}
+}
+
+#[cfg(unix)]
+#[inline]
+fn push_str(out: &mut Vec<u8>, s: &str) { out.extend_from_slice(s.as_bytes()) }
+
+#[cfg(windows)]
+#[inline]
+fn push_str(out: &mut Vec<u16>, s: &str) { out.extend(s.encode_utf16()) }
+
+#[cfg(unix)]
+fn unescape_unit(s: &str) -> Option<(u8, &str)> {
+	let hex = s.strip_prefix("\\x")?.get(..2)?;
+	Some((u8::from_str_radix(hex, 16).ok()?, &s[4..]))
+}
+
+#[cfg(windows)]
+fn unescape_unit(s: &str) -> Option<(u16, &str)> {
+	let (hex, rest) = s.strip_prefix("\\u{")?.split_once('}')?;
+	Some((u16::from_str_radix(hex, 16).ok()?, rest))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[cfg(unix)]
+	#[test]
+	fn test_escape() {
+		use std::os::unix::ffi::OsStrExt;
+
+		fn check(b: &[u8], escaped: &str) {
+			let s = OsStr::from_bytes(b);
+			assert_eq!(escape_os_str(s), escaped);
+			assert_eq!(unescape_os_str(escaped), s);
+		}
+
+		check(b"foo.txt", "foo.txt");
+		check(b"a\\b", "a\\b");
+		check(b"\xff.txt", "\\xff.txt");
+		check(b"a\\b\xfe", "a\\\\b\\xfe");
+		check(&["中".as_bytes(), b"\x80"].concat(), "中\\x80");
+
+		assert_eq!(unescape_os_str("\\y\\x1"), OsStr::new("\\y\\x1"));
+	}
+}
@@ -0,0 +1,62 @@
+use std::{fmt::{self, Display}, ops::Deref, sync::Arc};
+
+use arc_swap::{ArcSwapOption, Guard};
+
+/// A cell whose value can be replaced as a whole while it's read from any
+/// thread, e.g. a config that can be reloaded.
+///
+/// Readers [`load`](Self::load) a guard of the value at that moment, which
+/// keeps it alive until the guard is dropped, even if a new value has been
+/// stored in the meantime, so no reader ever sees a freed or half-written one.
+pub struct SwapCell<T>(ArcSwapOption<T>);
+
+impl<T> SwapCell<T> {
+	#[inline]
+	pub const fn new() -> Self { Self(ArcSwapOption::const_empty()) }
+
+	#[inline]
+	pub fn init(&self, value: T) {
+		debug_assert!(self.0.load().is_none());
+		self.0.store(Some(Arc::new(value)));
+	}
+
+	#[inline]
+	pub fn load(&self) -> SwapGuard<T> { SwapGuard(self.0.load()) }
+
+	/// The current value, which can be held for a long time, or across threads.
+	#[inline]
+	pub fn load_full(&self) -> Arc<T> {
+		self.0.load_full().expect("SwapCell is used before it's initialized")
+	}
+
+	/// Replace the value, and the previous one is dropped once the last reader
+	/// of it is done.
+	#[inline]
+	pub fn store(&self, value: T) {
+		debug_assert!(self.0.load().is_some());
+		self.0.store(Some(Arc::new(value)));
+	}
+}
+
+impl<T> Default for SwapCell<T> {
+	fn default() -> Self { Self::new() }
+}
+
+// --- Guard
+pub struct SwapGuard<T>(Guard<Option<Arc<T>>>);
+
+impl<T> Deref for SwapGuard<T> {
+	type Target = T;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.0.as_deref().expect("SwapCell is used before it's initialized")
+	}
+}
+
+impl<T> Display for SwapGuard<T>
+where
+	T: Display,
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.deref().fmt(f) }
+}
@@ -6,10 +6,12 @@ pub struct Icon {
 	pub style: Style,
 }
 
+/// The icon matched for a file, as its index in the icons of the theme with
+/// the given id, so it's matched again once the theme has been reloaded.
 #[derive(Clone, Copy, Debug, Default)]
 pub enum IconCache {
 	#[default]
 	Missing,
-	Undefined,
-	Icon(&'static Icon),
+	Undefined(u32),
+	Icon(u32, u32),
 }
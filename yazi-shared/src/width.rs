@@ -0,0 +1,60 @@
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::SyncCell;
+
+pub static WIDTHS: SyncCell<Widths> = SyncCell::new(Widths { ambiguous: 1, emoji: 2 });
+
+/// How wide the terminal draws the characters that terminals don't agree on,
+/// probed with the cursor position at startup.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Widths {
+	/// The East Asian ambiguous ones, e.g. `…`, either 1 or 2.
+	pub ambiguous: u8,
+	/// The emoji made wide by a variation selector, e.g. `❤️`, either 2 or 1.
+	pub emoji:     u8,
+}
+
+impl Widths {
+	/// The width of `s` on this terminal, where a narrow emoji presentation is
+	/// counted as the characters it's made of.
+	pub fn str(s: &str) -> usize {
+		match WIDTHS.get() {
+			Self { ambiguous: 1, emoji: 2 } => s.width(),
+			Self { ambiguous: 2, emoji: 2 } => s.width_cjk(),
+			_ => s.chars().map(Self::char).sum(),
+		}
+	}
+
+	pub fn char(c: char) -> usize {
+		if WIDTHS.get().ambiguous == 2 { c.width_cjk() } else { c.width() }.unwrap_or(0)
+	}
+
+	/// From the column reported after drawing `…` and `❤️` each at the start of
+	/// a line, in the order of the probes.
+	pub fn from_cpr(resp: &str) -> Option<Self> {
+		let mut cols = resp.split("\x1b[").filter_map(|s| {
+			let (row, col) = s.split_once('R')?.0.split_once(';')?;
+			if row.bytes().all(|b| b.is_ascii_digit()) { col.parse::<u8>().ok() } else { None }
+		});
+
+		let ambiguous = cols.next()?.checked_sub(1).filter(|w| matches!(w, 1 | 2))?;
+		let emoji = cols.next()?.checked_sub(1).filter(|w| matches!(w, 1 | 2))?;
+		Some(Self { ambiguous, emoji })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_from_cpr() {
+		let resp = "\x1b[?12;1$y\x1b[12;2R\x1b[12;3R\x1b[?0u\x1b[?62;4c";
+		assert_eq!(Widths::from_cpr(resp), Some(Widths { ambiguous: 1, emoji: 2 }));
+
+		let resp = "\x1b[1;3R\x1b[1;2R\x1b[?62c";
+		assert_eq!(Widths::from_cpr(resp), Some(Widths { ambiguous: 2, emoji: 1 }));
+
+		assert_eq!(Widths::from_cpr("\x1b[?62c"), None);
+	}
+}